@@ -0,0 +1,8 @@
+"""Caldera command-line interface.
+
+Usage: ``.venv/bin/python -m caldera_cli <group> <command> [options]``
+
+Subcommands are registered in :mod:`caldera_cli.main`; each command lives in
+its own module under :mod:`caldera_cli.commands` and delegates the heavy
+lifting to shared subsystems under ``src/shared/``.
+"""
@@ -0,0 +1,186 @@
+"""Annotated source rendering for code-review walkthroughs.
+
+``caldera annotate <file>`` prints a file with a gutter that layers the
+stored evidence for it: finding markers (severity initial) from the
+unified findings view, per-function CCN/NLOC headers from lizard, and
+duplication shading from pmd-cpd occurrences. The same annotations can
+be rendered as a standalone HTML page for embedding in the report.
+"""
+
+from __future__ import annotations
+
+import html as html_module
+from dataclasses import dataclass
+
+import duckdb
+
+from caldera_cli.query import _SEVERITY_RANK, _unified_view_sql
+
+
+@dataclass(frozen=True)
+class LineFinding:
+    """One finding pinned to a line of the annotated file."""
+
+    tool: str
+    rule: str | None
+    severity: str | None
+    message: str | None
+
+
+@dataclass(frozen=True)
+class FunctionSpan:
+    """One lizard function with its complexity figures."""
+
+    name: str
+    ccn: int | None
+    nloc: int | None
+    line_start: int
+    line_end: int
+
+
+@dataclass(frozen=True)
+class FileAnnotations:
+    """Everything the store knows about one file, keyed by line."""
+
+    findings: dict[int, tuple[LineFinding, ...]]
+    functions: tuple[FunctionSpan, ...]
+    duplicate_lines: frozenset[int]
+
+    def functions_starting_at(self, line: int) -> tuple[FunctionSpan, ...]:
+        return tuple(f for f in self.functions if f.line_start == line)
+
+
+def load_annotations(
+    conn: duckdb.DuckDBPyConnection, collection_run_id: str, relative_path: str
+) -> FileAnnotations:
+    """Collect findings, function metrics, and duplication for one file."""
+    finding_rows = conn.execute(
+        f"""SELECT tool, rule, severity, line, message
+            FROM ({_unified_view_sql()}) findings
+            WHERE collection_run_id = ? AND path = ?
+            ORDER BY line, tool, rule""",
+        [collection_run_id, relative_path],
+    ).fetchall()
+    findings: dict[int, list[LineFinding]] = {}
+    for tool, rule, severity, line, message in finding_rows:
+        findings.setdefault(line or 1, []).append(LineFinding(tool, rule, severity, message))
+
+    function_rows = conn.execute(
+        """SELECT fn.function_name, fn.ccn, fn.nloc, fn.line_start, fn.line_end
+           FROM lz_lizard_function_metrics fn
+           JOIN lz_lizard_file_metrics fm
+             ON fm.run_pk = fn.run_pk AND fm.file_id = fn.file_id
+           JOIN lz_tool_runs t ON t.run_pk = fn.run_pk
+           WHERE t.collection_run_id = ? AND fm.relative_path = ?
+           ORDER BY fn.line_start""",
+        [collection_run_id, relative_path],
+    ).fetchall()
+    functions = tuple(FunctionSpan(*row) for row in function_rows)
+
+    occurrence_rows = conn.execute(
+        """SELECT o.line_start, o.line_end
+           FROM lz_pmd_cpd_occurrences o
+           JOIN lz_tool_runs t ON t.run_pk = o.run_pk
+           WHERE t.collection_run_id = ? AND o.relative_path = ?""",
+        [collection_run_id, relative_path],
+    ).fetchall()
+    duplicate_lines: set[int] = set()
+    for line_start, line_end in occurrence_rows:
+        duplicate_lines.update(range(line_start, line_end + 1))
+
+    return FileAnnotations(
+        findings={line: tuple(entries) for line, entries in findings.items()},
+        functions=functions,
+        duplicate_lines=frozenset(duplicate_lines),
+    )
+
+
+def _severity_marker(entries: tuple[LineFinding, ...]) -> str:
+    """Initial of the most severe finding on the line (C/H/M/L), or ``!``."""
+    best = max(entries, key=lambda e: _SEVERITY_RANK.get((e.severity or "").upper(), 0))
+    severity = (best.severity or "").upper()
+    return severity[0] if severity in _SEVERITY_RANK else "!"
+
+
+def render_text(source_lines: list[str], annotations: FileAnnotations) -> str:
+    """Gutter-annotated plain-text rendering.
+
+    Column one carries the severity initial of the worst finding on the
+    line, column two a ``D`` for duplicated lines; lizard functions get a
+    header row above their first line and findings a caret note below.
+    """
+    width = max(4, len(str(len(source_lines))))
+    out = []
+    for number, content in enumerate(source_lines, start=1):
+        for func in annotations.functions_starting_at(number):
+            out.append(
+                f"   {'-' * width} | -- {func.name} (CCN {func.ccn}, NLOC {func.nloc}) --"
+            )
+        entries = annotations.findings.get(number, ())
+        finding_marker = _severity_marker(entries) if entries else " "
+        dup_marker = "D" if number in annotations.duplicate_lines else " "
+        out.append(f"{finding_marker}{dup_marker} {number:>{width}} | {content}")
+        for entry in entries:
+            note = f"{entry.tool}/{entry.rule or '-'}"
+            if entry.message:
+                note += f": {entry.message.splitlines()[0]}"
+            out.append(f"   {'':>{width}} |   ^ {note}")
+    return "\n".join(out)
+
+
+_HTML_STYLE = """\
+body { font-family: monospace; margin: 1em; }
+table { border-collapse: collapse; }
+td { padding: 0 0.5em; white-space: pre; }
+td.num { color: #888; text-align: right; user-select: none; }
+tr.dup td.src { background: #fff3cd; }
+tr.sev-critical td.src, tr.sev-high td.src { background: #f8d7da; }
+tr.sev-medium td.src { background: #ffe5d0; }
+tr.sev-low td.src { background: #e2e3e5; }
+tr.func td { color: #0a58ca; font-weight: bold; }
+tr.note td { color: #842029; font-size: 0.9em; }
+"""
+
+
+def render_html(
+    source_lines: list[str], annotations: FileAnnotations, relative_path: str
+) -> str:
+    """Standalone HTML page with the same annotations as the text view."""
+    rows = []
+    for number, content in enumerate(source_lines, start=1):
+        for func in annotations.functions_starting_at(number):
+            rows.append(
+                f'<tr class="func"><td class="num"></td>'
+                f"<td>{html_module.escape(func.name)} "
+                f"(CCN {func.ccn}, NLOC {func.nloc})</td></tr>"
+            )
+        entries = annotations.findings.get(number, ())
+        classes = []
+        if entries:
+            worst = max(
+                entries, key=lambda e: _SEVERITY_RANK.get((e.severity or "").upper(), 0)
+            )
+            classes.append(f"sev-{(worst.severity or 'low').lower()}")
+        if number in annotations.duplicate_lines:
+            classes.append("dup")
+        class_attr = f' class="{" ".join(classes)}"' if classes else ""
+        rows.append(
+            f'<tr{class_attr}><td class="num">{number}</td>'
+            f'<td class="src">{html_module.escape(content)}</td></tr>'
+        )
+        for entry in entries:
+            note = f"{entry.tool}/{entry.rule or '-'}"
+            if entry.message:
+                note += f": {entry.message.splitlines()[0]}"
+            rows.append(
+                f'<tr class="note"><td class="num"></td>'
+                f"<td>^ {html_module.escape(note)}</td></tr>"
+            )
+    title = html_module.escape(relative_path)
+    body = "\n".join(rows)
+    return (
+        "<!DOCTYPE html>\n"
+        f"<html><head><meta charset=\"utf-8\"><title>{title}</title>"
+        f"<style>{_HTML_STYLE}</style></head>\n"
+        f"<body><h1>{title}</h1>\n<table>\n{body}\n</table></body></html>\n"
+    )
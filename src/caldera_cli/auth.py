@@ -0,0 +1,155 @@
+"""Token authentication for the HTTP API server.
+
+Scoped bearer tokens for ``caldera serve``: a token grants ``read``
+(query runs/findings) or ``write`` (trigger scans; implies read), scoped
+to specific repo ids or ``*``. Only SHA-256 hashes are stored on disk —
+the plaintext token is shown once at issue time — and presented tokens
+are compared constant-time. ``caldera tokens`` manages the store;
+rotation revokes the old token and issues a replacement under the same
+name in one step.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import hmac
+import json
+import secrets
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+
+SCOPE_READ = "read"
+SCOPE_WRITE = "write"
+KNOWN_SCOPES = (SCOPE_READ, SCOPE_WRITE)
+
+TOKEN_BYTES = 32
+
+
+class AuthError(RuntimeError):
+    """Token store operation failed."""
+
+
+@dataclass(frozen=True)
+class TokenRecord:
+    """One issued token (hash only; plaintext is never stored)."""
+
+    name: str
+    token_hash: str
+    scope: str
+    repos: tuple[str, ...] = ("*",)
+    created_at: str = ""
+    revoked: bool = False
+
+    def to_dict(self) -> dict:
+        return {
+            "name": self.name,
+            "token_hash": self.token_hash,
+            "scope": self.scope,
+            "repos": list(self.repos),
+            "created_at": self.created_at,
+            "revoked": self.revoked,
+        }
+
+
+def hash_token(token: str) -> str:
+    return hashlib.sha256(token.encode()).hexdigest()
+
+
+@dataclass
+class TokenStore:
+    """File-backed token store (JSON list of token records)."""
+
+    path: Path
+    records: list[TokenRecord] = field(default_factory=list)
+
+    @classmethod
+    def load(cls, path: Path) -> TokenStore:
+        if not path.exists():
+            return cls(path=path)
+        records = [
+            TokenRecord(
+                name=entry["name"],
+                token_hash=entry["token_hash"],
+                scope=entry["scope"],
+                repos=tuple(entry.get("repos", ["*"])),
+                created_at=entry.get("created_at", ""),
+                revoked=bool(entry.get("revoked", False)),
+            )
+            for entry in json.loads(path.read_text())
+        ]
+        return cls(path=path, records=records)
+
+    def save(self) -> None:
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        self.path.write_text(
+            json.dumps([record.to_dict() for record in self.records], indent=2)
+        )
+        self.path.chmod(0o600)
+
+    def _active(self, name: str) -> TokenRecord | None:
+        for record in self.records:
+            if record.name == name and not record.revoked:
+                return record
+        return None
+
+    def issue(self, name: str, scope: str, repos: tuple[str, ...] = ("*",)) -> str:
+        """Issue a new token; returns the plaintext exactly once."""
+        if scope not in KNOWN_SCOPES:
+            raise AuthError(f"Unknown scope: {scope} (one of: {', '.join(KNOWN_SCOPES)})")
+        if self._active(name) is not None:
+            raise AuthError(f"Token {name!r} already exists (rotate or revoke it first)")
+        token = secrets.token_urlsafe(TOKEN_BYTES)
+        self.records.append(
+            TokenRecord(
+                name=name,
+                token_hash=hash_token(token),
+                scope=scope,
+                repos=repos,
+                created_at=datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+            )
+        )
+        self.save()
+        return token
+
+    def revoke(self, name: str) -> None:
+        record = self._active(name)
+        if record is None:
+            raise AuthError(f"No active token named {name!r}")
+        self.records[self.records.index(record)] = TokenRecord(
+            name=record.name,
+            token_hash=record.token_hash,
+            scope=record.scope,
+            repos=record.repos,
+            created_at=record.created_at,
+            revoked=True,
+        )
+        self.save()
+
+    def rotate(self, name: str) -> str:
+        """Revoke the current token and issue a replacement with its grants."""
+        record = self._active(name)
+        if record is None:
+            raise AuthError(f"No active token named {name!r}")
+        self.revoke(name)
+        return self.issue(name, record.scope, record.repos)
+
+    def authenticate(self, token: str) -> TokenRecord | None:
+        """Constant-time lookup of a presented token; None when invalid."""
+        presented = hash_token(token)
+        matched: TokenRecord | None = None
+        # Compare against every record so timing does not reveal which
+        # (or whether any) token matched.
+        for record in self.records:
+            if hmac.compare_digest(presented, record.token_hash) and not record.revoked:
+                matched = record
+        return matched
+
+
+def authorized(record: TokenRecord, scope: str, repo_id: str | None = None) -> bool:
+    """Check a token's grants: write implies read; repos may be ``*``."""
+    if scope == SCOPE_WRITE and record.scope != SCOPE_WRITE:
+        return False
+    if repo_id is not None and "*" not in record.repos and repo_id not in record.repos:
+        return False
+    return True
@@ -0,0 +1,174 @@
+"""Shields-style SVG badges for Caldera metrics.
+
+Renders flat README badges for a repo's current score straight from the
+landing zone: ``health`` (the insights composite health score),
+``duplication`` (duplicated-line percentage, pmd-cpd), and ``ccn`` (mean
+cyclomatic complexity, lizard). Values come from the latest completed
+collection run; missing data renders a grey "unknown" badge instead of
+failing. Available as ``caldera badge`` and as ``GET /badge/<metric>``
+in server mode.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import duckdb
+
+BADGE_METRICS = {
+    "health": "caldera health",
+    "duplication": "duplication",
+    "ccn": "avg ccn",
+}
+
+_COLOR_HEX = {
+    "brightgreen": "#4c1",
+    "green": "#97ca00",
+    "yellowgreen": "#a4a61d",
+    "yellow": "#dfb317",
+    "orange": "#fe7d37",
+    "red": "#e05d44",
+    "lightgrey": "#9f9f9f",
+}
+
+# (threshold, color) pairs checked in order; health is better-is-higher,
+# the other two are better-is-lower.
+_HEALTH_COLORS = [(90.0, "brightgreen"), (75.0, "green"), (60.0, "yellowgreen"), (40.0, "yellow")]
+_DUPLICATION_COLORS = [(3.0, "brightgreen"), (10.0, "yellowgreen"), (20.0, "yellow"), (30.0, "orange")]
+_CCN_COLORS = [(5.0, "brightgreen"), (10.0, "yellowgreen"), (15.0, "yellow"), (20.0, "orange")]
+
+_CHAR_WIDTH = 7
+_PADDING = 10
+
+
+def color_for(metric: str, value: float) -> str:
+    if metric == "health":
+        for threshold, color in _HEALTH_COLORS:
+            if value >= threshold:
+                return color
+        return "red"
+    thresholds = _DUPLICATION_COLORS if metric == "duplication" else _CCN_COLORS
+    for threshold, color in thresholds:
+        if value <= threshold:
+            return color
+    return "red"
+
+
+def render_svg(label: str, value: str, color: str) -> str:
+    """One flat shields-style badge: grey label, colored value."""
+    label_width = len(label) * _CHAR_WIDTH + _PADDING
+    value_width = len(value) * _CHAR_WIDTH + _PADDING
+    total = label_width + value_width
+    value_hex = _COLOR_HEX.get(color, _COLOR_HEX["lightgrey"])
+    label_center = label_width / 2
+    value_center = label_width + value_width / 2
+    return f"""<svg xmlns="http://www.w3.org/2000/svg" width="{total}" height="20" role="img" aria-label="{label}: {value}">
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r"><rect width="{total}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{value_width}" height="20" fill="{value_hex}"/>
+    <rect width="{total}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_center}" y="15" fill="#010101" fill-opacity=".3">{label}</text>
+    <text x="{label_center}" y="14">{label}</text>
+    <text x="{value_center}" y="15" fill="#010101" fill-opacity=".3">{value}</text>
+    <text x="{value_center}" y="14">{value}</text>
+  </g>
+</svg>
+"""
+
+
+def _run_pks(conn: duckdb.DuckDBPyConnection, tool_name: str, repo_id: str | None) -> list[int]:
+    filters = ["c.status = 'completed'"]
+    args: list = []
+    if repo_id:
+        filters.append("c.repo_id = ?")
+        args.append(repo_id)
+    row = conn.execute(
+        f"""SELECT c.collection_run_id FROM lz_collection_runs c
+            WHERE {' AND '.join(filters)}
+            ORDER BY c.started_at DESC LIMIT 1""",
+        args,
+    ).fetchone()
+    if row is None:
+        return []
+    rows = conn.execute(
+        "SELECT run_pk FROM lz_tool_runs WHERE collection_run_id = ? AND tool_name = ?",
+        [row[0], tool_name],
+    ).fetchall()
+    return [r[0] for r in rows]
+
+
+def _duplication(conn: duckdb.DuckDBPyConnection, repo_id: str | None) -> float | None:
+    run_pks = _run_pks(conn, "pmd-cpd", repo_id)
+    if not run_pks:
+        return None
+    row = conn.execute(
+        f"""SELECT SUM(duplicate_lines), SUM(total_lines) FROM lz_pmd_cpd_file_metrics
+            WHERE run_pk IN ({', '.join('?' for _ in run_pks)})""",
+        run_pks,
+    ).fetchone()
+    if not row or not row[1]:
+        return None
+    return round(100.0 * row[0] / row[1], 1)
+
+
+def _mean_ccn(conn: duckdb.DuckDBPyConnection, repo_id: str | None) -> float | None:
+    run_pks = _run_pks(conn, "lizard", repo_id)
+    if not run_pks:
+        return None
+    row = conn.execute(
+        f"""SELECT SUM(total_ccn), SUM(function_count) FROM lz_lizard_file_metrics
+            WHERE run_pk IN ({', '.join('?' for _ in run_pks)})""",
+        run_pks,
+    ).fetchone()
+    if not row or not row[1]:
+        return None
+    return round(row[0] / row[1], 1)
+
+
+def metric_reading(
+    db_path: Path, metric: str, repo_id: str | None = None
+) -> tuple[str, str]:
+    """(value text, color) for one metric; ('unknown', grey) without data."""
+    if metric not in BADGE_METRICS:
+        raise ValueError(f"unknown metric: {metric} (one of: {', '.join(BADGE_METRICS)})")
+    conn = duckdb.connect(str(db_path), read_only=True)
+    try:
+        if metric == "duplication":
+            value = _duplication(conn, repo_id)
+            if value is None:
+                return "unknown", "lightgrey"
+            return f"{value:.1f}%", color_for(metric, value)
+        if metric == "ccn":
+            value = _mean_ccn(conn, repo_id)
+            if value is None:
+                return "unknown", "lightgrey"
+            return f"{value:.1f}", color_for(metric, value)
+        # health: composite score from the insights formula over the
+        # dimensions the landing zone can answer directly.
+        from insights.health_score import compute_health_score, load_health_config
+
+        duplication = _duplication(conn, repo_id)
+        mean_ccn = _mean_ccn(conn, repo_id)
+        if duplication is None and mean_ccn is None:
+            return "unknown", "lightgrey"
+        config = load_health_config(Path(__file__).resolve().parents[2] / "caldera.toml")
+        score = compute_health_score(
+            repo_id or "repo",
+            {"complexity": mean_ccn or 0.0, "duplication": duplication or 0.0},
+            config,
+        ).score
+        return f"{score:.0f}", color_for(metric, score)
+    finally:
+        conn.close()
+
+
+def build_badge(db_path: Path, metric: str, repo_id: str | None = None) -> str:
+    value, color = metric_reading(db_path, metric, repo_id)
+    return render_svg(BADGE_METRICS[metric], value, color)
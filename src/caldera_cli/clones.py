@@ -0,0 +1,194 @@
+"""Side-by-side diff viewer for stored duplication findings.
+
+A clone report tells you two fragments are 90% identical; deciding
+whether to extract a helper requires seeing the 10% that differs.
+``caldera clones show <clone-id>`` loads a pmd-cpd or jscpd clone from
+the landing zone, reads both fragments from the repository checkout
+(falling back to the stored code fragment when the file has changed),
+and renders them side by side — or unified — with the identifiers and
+literals that differ between the two fragments wrapped in ``«»`` so
+renamed variables and changed constants stand out at a glance.
+"""
+
+from __future__ import annotations
+
+import difflib
+import re
+from dataclasses import dataclass
+from pathlib import Path
+
+import duckdb
+
+# Clone tables searched, in order: (tool, duplications table, occurrences table).
+_CLONE_SOURCES = (
+    ("pmd-cpd", "lz_pmd_cpd_duplications", "lz_pmd_cpd_occurrences"),
+    ("jscpd", "lz_jscpd_duplications", "lz_jscpd_occurrences"),
+)
+
+# Identifiers, numbers, and string literals — the token classes that vary
+# between otherwise-identical clone fragments.
+_TOKEN_RE = re.compile(r"[A-Za-z_][A-Za-z0-9_]*|\d+(?:\.\d+)?|\"[^\"]*\"|'[^']*'")
+
+_SIDE_WIDTH = 58
+
+
+@dataclass(frozen=True)
+class CloneOccurrence:
+    """One location of a clone fragment."""
+
+    relative_path: str
+    line_start: int
+    line_end: int
+
+
+@dataclass(frozen=True)
+class Clone:
+    """One stored duplication with all of its occurrences."""
+
+    tool: str
+    clone_id: str
+    lines: int
+    tokens: int
+    occurrences: tuple[CloneOccurrence, ...]
+    code_fragment: str | None
+
+
+def fetch_clone(
+    conn: duckdb.DuckDBPyConnection, clone_id: str, collection_run_id: str | None = None
+) -> Clone | None:
+    """Load a clone by id from either duplication tool, newest run first."""
+    run_filter = "AND t.collection_run_id = ?" if collection_run_id else ""
+    for tool, dup_table, occ_table in _CLONE_SOURCES:
+        params: list = [clone_id]
+        if collection_run_id:
+            params.append(collection_run_id)
+        row = conn.execute(
+            f"""SELECT d.run_pk, d.lines, d.tokens, d.code_fragment
+                FROM {dup_table} d
+                JOIN lz_tool_runs t ON t.run_pk = d.run_pk
+                WHERE d.clone_id = ? {run_filter}
+                ORDER BY t.timestamp DESC LIMIT 1""",
+            params,
+        ).fetchone()
+        if row is None:
+            continue
+        run_pk, lines, tokens, code_fragment = row
+        occurrence_rows = conn.execute(
+            f"""SELECT relative_path, line_start, line_end
+                FROM {occ_table}
+                WHERE run_pk = ? AND clone_id = ?
+                ORDER BY relative_path, line_start""",
+            [run_pk, clone_id],
+        ).fetchall()
+        occurrences = tuple(CloneOccurrence(*occ) for occ in occurrence_rows)
+        return Clone(tool, clone_id, lines, tokens, occurrences, code_fragment)
+    return None
+
+
+def fragment_lines(
+    repo_root: Path | None, occurrence: CloneOccurrence, code_fragment: str | None
+) -> list[str]:
+    """The fragment's lines from the checkout, or the stored fragment.
+
+    Files drift between the scan and the viewing; when the occurrence's
+    line range no longer exists we fall back to the code fragment the
+    tool captured at scan time.
+    """
+    if repo_root is not None:
+        try:
+            lines = (repo_root / occurrence.relative_path).read_text(
+                encoding="utf-8", errors="replace"
+            ).splitlines()
+            if occurrence.line_end <= len(lines):
+                return lines[occurrence.line_start - 1 : occurrence.line_end]
+        except OSError:
+            pass
+    return (code_fragment or "").splitlines()
+
+
+def highlight_token_diffs(left: str, right: str) -> tuple[str, str]:
+    """Wrap identifiers/literals that differ between two lines in ``«»``."""
+    left_tokens = [(m.group(), m.span()) for m in _TOKEN_RE.finditer(left)]
+    right_tokens = [(m.group(), m.span()) for m in _TOKEN_RE.finditer(right)]
+    matcher = difflib.SequenceMatcher(
+        None, [t[0] for t in left_tokens], [t[0] for t in right_tokens], autojunk=False
+    )
+    left_spans: list[tuple[int, int]] = []
+    right_spans: list[tuple[int, int]] = []
+    for op, i1, i2, j1, j2 in matcher.get_opcodes():
+        if op == "equal":
+            continue
+        left_spans.extend(span for _, span in left_tokens[i1:i2])
+        right_spans.extend(span for _, span in right_tokens[j1:j2])
+    return _wrap_spans(left, left_spans), _wrap_spans(right, right_spans)
+
+
+def _wrap_spans(text: str, spans: list[tuple[int, int]]) -> str:
+    for start, end in sorted(spans, reverse=True):
+        text = f"{text[:start]}«{text[start:end]}»{text[end:]}"
+    return text
+
+
+def _header(clone: Clone, left: CloneOccurrence, right: CloneOccurrence) -> list[str]:
+    return [
+        f"Clone {clone.clone_id} ({clone.tool}): {clone.lines} lines, "
+        f"{clone.tokens} tokens, {len(clone.occurrences)} occurrence(s)",
+        f"  A: {left.relative_path}:{left.line_start}-{left.line_end}",
+        f"  B: {right.relative_path}:{right.line_start}-{right.line_end}",
+        "",
+    ]
+
+
+def render_unified(
+    clone: Clone, left: CloneOccurrence, right: CloneOccurrence, repo_root: Path | None = None
+) -> str:
+    """git-style unified diff of two occurrences of a clone."""
+    left_lines = fragment_lines(repo_root, left, clone.code_fragment)
+    right_lines = fragment_lines(repo_root, right, clone.code_fragment)
+    diff = difflib.unified_diff(
+        left_lines,
+        right_lines,
+        fromfile=f"A: {left.relative_path}",
+        tofile=f"B: {right.relative_path}",
+        lineterm="",
+    )
+    body = list(diff) or ["(fragments are identical)"]
+    return "\n".join(_header(clone, left, right) + body)
+
+
+def render_side_by_side(
+    clone: Clone, left: CloneOccurrence, right: CloneOccurrence, repo_root: Path | None = None
+) -> str:
+    """Two-column diff with differing tokens highlighted.
+
+    Gutter markers: `` `` equal, ``|`` changed, ``<`` only in A, ``>``
+    only in B.
+    """
+    left_lines = fragment_lines(repo_root, left, clone.code_fragment)
+    right_lines = fragment_lines(repo_root, right, clone.code_fragment)
+    matcher = difflib.SequenceMatcher(None, left_lines, right_lines, autojunk=False)
+    rows: list[tuple[str, str, str]] = []
+    for op, i1, i2, j1, j2 in matcher.get_opcodes():
+        if op == "equal":
+            rows.extend((line, " ", line) for line in left_lines[i1:i2])
+        elif op == "delete":
+            rows.extend((line, "<", "") for line in left_lines[i1:i2])
+        elif op == "insert":
+            rows.extend(("", ">", line) for line in right_lines[j1:j2])
+        else:
+            old, new = left_lines[i1:i2], right_lines[j1:j2]
+            for index in range(max(len(old), len(new))):
+                left_line = old[index] if index < len(old) else ""
+                right_line = new[index] if index < len(new) else ""
+                if left_line and right_line:
+                    left_line, right_line = highlight_token_diffs(left_line, right_line)
+                    rows.append((left_line, "|", right_line))
+                elif left_line:
+                    rows.append((left_line, "<", ""))
+                else:
+                    rows.append(("", ">", right_line))
+    body = [
+        f"{left_col[:_SIDE_WIDTH]:<{_SIDE_WIDTH}} {marker} {right_col[:_SIDE_WIDTH]}".rstrip()
+        for left_col, marker, right_col in rows
+    ]
+    return "\n".join(_header(clone, left, right) + body)
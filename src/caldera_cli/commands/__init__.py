@@ -0,0 +1 @@
+# Makes commands a package
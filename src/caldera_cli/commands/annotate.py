@@ -0,0 +1,77 @@
+"""`caldera annotate` — render a source file with stored annotations."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "annotate",
+        help="Print a file with inline finding, CCN, and duplication markers",
+        description=(
+            "Prints a file with gutter markers for findings, per-function "
+            "CCN headers from lizard, and duplication shading from pmd-cpd, "
+            "for code-review walkthroughs. With --output html a standalone "
+            "HTML page is emitted for the report."
+        ),
+    )
+    parser.add_argument("file", help="Repo-relative path of the file to annotate")
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--run",
+        metavar="RUN_ID",
+        help="Collection run to annotate from (default: latest completed run)",
+    )
+    parser.add_argument(
+        "--repo-path",
+        type=Path,
+        default=Path.cwd(),
+        help="Repository checkout containing the file (default: cwd)",
+    )
+    parser.add_argument(
+        "--output",
+        choices=["text", "html"],
+        default="text",
+        help="Output format (default: text)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.annotate import load_annotations, render_html, render_text
+    from caldera_cli.commands.query import _latest_completed_run
+
+    source_path = args.repo_path / args.file
+    if not source_path.is_file():
+        print(f"Error: {source_path} does not exist")
+        return 1
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path), read_only=True)
+    try:
+        run_id = args.run or _latest_completed_run(conn)
+        if not run_id:
+            print("Error: no completed collection runs in the database")
+            return 1
+        annotations = load_annotations(conn, run_id, args.file)
+    finally:
+        conn.close()
+    source_lines = source_path.read_text(errors="replace").splitlines()
+    if args.output == "html":
+        print(render_html(source_lines, annotations, args.file))
+    else:
+        print(render_text(source_lines, annotations))
+    return 0
@@ -0,0 +1,57 @@
+"""`caldera badge` — render shields-style SVG badges for README embedding."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "badge",
+        help="Render an SVG badge for a Caldera metric",
+        description=(
+            "Emits a shields-style SVG badge for the latest completed run: "
+            "health (composite score), duplication (duplicated-line "
+            "percentage), or ccn (mean cyclomatic complexity). Server mode "
+            "serves the same badges at /badge/<metric>."
+        ),
+    )
+    parser.add_argument(
+        "--metric",
+        choices=["health", "duplication", "ccn"],
+        default="health",
+        help="Metric to render (default: health)",
+    )
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument("--repo-id", help="Pin to one repo (default: latest run of any repo)")
+    parser.add_argument(
+        "--output",
+        "-o",
+        type=Path,
+        help="Write the SVG here (default: stdout)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    from caldera_cli.badge import build_badge
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    svg = build_badge(args.db_path, args.metric, repo_id=args.repo_id)
+    if args.output is not None:
+        args.output.write_text(svg)
+        print(f"Wrote {args.metric} badge: {args.output}")
+    else:
+        print(svg, end="")
+    return 0
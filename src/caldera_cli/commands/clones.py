@@ -0,0 +1,78 @@
+"""`caldera clones` — inspect stored duplication findings."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    clones_group = subparsers.add_parser("clones", help="Inspect duplication findings")
+    clones_commands = clones_group.add_subparsers(dest="command", required=True)
+
+    show = clones_commands.add_parser(
+        "show",
+        help="Render a diff of two occurrences of a clone",
+        description=(
+            "Loads a pmd-cpd or jscpd clone by id and renders a "
+            "side-by-side (default) or unified diff of two of its "
+            "occurrences, with differing identifiers/literals wrapped "
+            "in «» so near-miss clones are quick to judge."
+        ),
+    )
+    show.add_argument("clone_id", help="Clone id from the duplication report")
+    show.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    show.add_argument("--run", metavar="RUN_ID", help="Collection run (default: newest with this clone)")
+    show.add_argument(
+        "--repo-path",
+        type=Path,
+        help="Repository checkout to read fragments from (default: stored fragment)",
+    )
+    show.add_argument(
+        "--occurrences",
+        nargs=2,
+        type=int,
+        default=[1, 2],
+        metavar=("A", "B"),
+        help="1-based indices of the two occurrences to diff (default: 1 2)",
+    )
+    show.add_argument("--unified", action="store_true", help="Unified diff instead of side-by-side")
+    show.set_defaults(handler=run_show_command)
+
+
+def run_show_command(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.clones import fetch_clone, render_side_by_side, render_unified
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path), read_only=True)
+    try:
+        clone = fetch_clone(conn, args.clone_id, collection_run_id=args.run)
+    finally:
+        conn.close()
+    if clone is None:
+        print(f"No stored clone matches id {args.clone_id}")
+        return 1
+    indices = args.occurrences
+    for index in indices:
+        if not 1 <= index <= len(clone.occurrences):
+            print(
+                f"Error: occurrence {index} out of range "
+                f"(clone has {len(clone.occurrences)} occurrence(s))"
+            )
+            return 2
+    left, right = clone.occurrences[indices[0] - 1], clone.occurrences[indices[1] - 1]
+    render = render_unified if args.unified else render_side_by_side
+    print(render(clone, left, right, repo_root=args.repo_path))
+    return 0
@@ -0,0 +1,63 @@
+"""`caldera compare` — delta report between two stored runs."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "compare",
+        help="Report new/resolved findings and metric deltas between two runs",
+        description=(
+            "Diffs two stored runs: new, resolved, and persisting findings "
+            "by fingerprint, plus code-line, mean-CCN, and duplication "
+            "deltas. Runs are named by collection run id or commit SHA "
+            "prefix; markdown output is suited to PR comments."
+        ),
+    )
+    parser.add_argument("run_a", help="Baseline run (run id or commit prefix)")
+    parser.add_argument("run_b", help="Run to compare against the baseline")
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--output",
+        choices=["text", "markdown", "json"],
+        default="text",
+        help="Output format (default: text)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+    import json
+
+    from caldera_cli.compare import compare_runs, render_markdown, render_text, to_dict
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path), read_only=True)
+    try:
+        delta = compare_runs(conn, args.run_a, args.run_b)
+    except ValueError as exc:
+        print(f"Error: {exc}")
+        return 1
+    finally:
+        conn.close()
+    if args.output == "json":
+        print(json.dumps(to_dict(delta), indent=2))
+    elif args.output == "markdown":
+        print(render_markdown(delta))
+    else:
+        print(render_text(delta))
+    return 0
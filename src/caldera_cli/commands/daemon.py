@@ -0,0 +1,111 @@
+"""`caldera daemon` — run scheduled scans behind the HTTP API server."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH, load_token_store
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "daemon",
+        help="Run scheduled scans from a cron config, with the API server",
+        description=(
+            "Loads cron-style scan schedules from a YAML config, runs due "
+            "scans in the background through the scan manager, and serves "
+            "the HTTP API so status is queryable at /scans and /schedule."
+        ),
+    )
+    parser.add_argument("config", type=Path, help="Schedule config YAML (see scheduler module)")
+    parser.add_argument("--host", default="127.0.0.1", help="Bind address (default: 127.0.0.1)")
+    parser.add_argument("--port", type=int, default=8765, help="Port (default: 8765)")
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database for scan results (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--auth-tokens",
+        type=Path,
+        metavar="TOKENS_FILE",
+        help="Require bearer-token auth using this token store (see `caldera tokens`)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def _store_gc(db_path: Path, gc_config: dict) -> None:
+    """Run retention GC from daemon mode (see `caldera store gc`)."""
+    import sys
+
+    sys.path.insert(0, str(Path(__file__).resolve().parents[2] / "sot-engine"))
+    from persistence.retention import RetentionPolicy, load_retention_policy, run_gc
+
+    policy = load_retention_policy(Path(__file__).resolve().parents[3] / "caldera.toml")
+    policy = RetentionPolicy(
+        keep_runs_per_repo=int(gc_config.get("keep_runs_per_repo", policy.keep_runs_per_repo)),
+        max_age_days=int(gc_config.get("max_age_days", policy.max_age_days)),
+    )
+    summary = run_gc(str(db_path), policy)
+    print(f"Store GC: pruned {len(summary['pruned_runs'])} collection run(s)")
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import yaml
+
+    from caldera_cli.scheduler import ScheduledScan, Scheduler, load_schedule
+    from caldera_cli.server import create_server
+
+    try:
+        entries = load_schedule(args.config)
+    except (OSError, ValueError) as exc:
+        print(f"Error loading schedule: {exc}")
+        return 1
+    if not entries:
+        print(f"Warning: {args.config} has an empty schedule; daemon will only serve the API")
+
+    server = create_server(
+        args.host, args.port, args.db_path, token_store=load_token_store(args.auth_tokens)
+    )
+    manager = server.scan_manager  # type: ignore[attr-defined]
+    scheduler = Scheduler(
+        entries,
+        starter=lambda entry: manager.start(
+            repo_path=entry.repo_path,
+            repo_id=entry.repo_id,
+            branch=entry.branch,
+            commit="0" * 40,
+            replace=entry.replace,
+        ),
+    )
+    server.scheduler = scheduler  # type: ignore[attr-defined]
+    scheduler.start()
+
+    # Optional automatic retention GC: a `gc: {cron: ...}` block in the
+    # schedule config runs `caldera store gc` on its own cron.
+    gc_scheduler = None
+    gc_config = (yaml.safe_load(args.config.read_text()) or {}).get("gc") or {}
+    if gc_config.get("cron"):
+        gc_entry = ScheduledScan(
+            repo_id="__store_gc__", repo_path=str(args.db_path), cron=str(gc_config["cron"])
+        )
+        gc_scheduler = Scheduler([gc_entry], starter=lambda entry: _store_gc(args.db_path, gc_config))
+        gc_scheduler.start()
+
+    print(
+        f"Caldera daemon: {len(entries)} scheduled repo(s), "
+        f"API on http://{args.host}:{args.port} (db: {args.db_path})"
+    )
+    try:
+        server.serve_forever()
+    except KeyboardInterrupt:
+        print("\nShutting down.")
+    finally:
+        scheduler.stop()
+        if gc_scheduler is not None:
+            gc_scheduler.stop()
+        server.server_close()
+    return 0
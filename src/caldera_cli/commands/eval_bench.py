@@ -0,0 +1,89 @@
+"""`caldera eval bench` — compare tool versions on the eval corpus."""
+
+from __future__ import annotations
+
+import argparse
+import json
+from pathlib import Path
+
+from shared.evaluation.bench import (
+    DockerBenchBackend,
+    format_comparison_table,
+    run_bench,
+)
+
+DEFAULT_TOOLS_ROOT = Path(__file__).resolve().parents[2] / "tools"
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "bench",
+        help="Benchmark multiple tool versions against the eval repos",
+        description=(
+            "Runs each requested tool version (via its official Docker image) "
+            "against the tool's synthetic eval repos and prints a comparison "
+            "table of score, runtime, and peak memory."
+        ),
+    )
+    parser.add_argument("--tool", required=True, help="Tool name (e.g. semgrep)")
+    parser.add_argument(
+        "--versions",
+        required=True,
+        help="Comma-separated image versions to compare (e.g. 1.60,1.70,1.80)",
+    )
+    parser.add_argument(
+        "--tools-root",
+        type=Path,
+        default=DEFAULT_TOOLS_ROOT,
+        help="Root directory containing tool directories (default: src/tools)",
+    )
+    parser.add_argument(
+        "--work-dir",
+        type=Path,
+        default=Path("/tmp/caldera-bench"),
+        help="Scratch directory for raw tool reports",
+    )
+    parser.add_argument(
+        "--json",
+        dest="json_output",
+        type=Path,
+        default=None,
+        help="Also write the full report as JSON to this path",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    versions = [v.strip() for v in args.versions.split(",") if v.strip()]
+    if not versions:
+        print("No versions given (use --versions 1.60,1.70)")
+        return 2
+
+    backend = DockerBenchBackend()
+    if not backend.available():
+        print("docker not found on PATH; the bench command requires the Docker backend")
+        return 2
+
+    try:
+        report = run_bench(
+            args.tool,
+            versions,
+            tools_root=args.tools_root,
+            work_dir=args.work_dir,
+            backend=backend,
+            logger=print,
+        )
+    except ValueError as exc:
+        print(f"Error: {exc}")
+        return 2
+
+    print()
+    print(format_comparison_table(report))
+
+    if args.json_output:
+        args.json_output.parent.mkdir(parents=True, exist_ok=True)
+        args.json_output.write_text(json.dumps(report.to_dict(), indent=2))
+        print(f"\nJSON report: {args.json_output}")
+
+    failed = sum(1 for r in report.results if r.status == "failed")
+    return 1 if failed == len(report.results) and report.results else 0
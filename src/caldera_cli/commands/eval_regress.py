@@ -0,0 +1,101 @@
+"""`caldera eval regress` — golden snapshot regression check for tool outputs."""
+
+from __future__ import annotations
+
+import argparse
+import json
+from pathlib import Path
+
+from shared.evaluation.snapshots import SnapshotStore
+
+from .eval_bench import DEFAULT_TOOLS_ROOT
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "regress",
+        help="Compare fresh tool outputs against golden snapshots",
+        description=(
+            "Canonicalizes tool output envelopes and compares them against the "
+            "stored snapshots under <tool>/evaluation/snapshots/. Fails when a "
+            "tool version or adapter change alters parsed results unexpectedly."
+        ),
+    )
+    parser.add_argument("--tool", required=True, help="Tool name (e.g. semgrep)")
+    parser.add_argument(
+        "--output",
+        action="append",
+        type=Path,
+        default=None,
+        help="Fresh output.json to check (repeatable; default: scan <tool>/outputs/)",
+    )
+    parser.add_argument(
+        "--name",
+        help="Snapshot name (only valid with a single --output; default: output's parent directory name)",
+    )
+    parser.add_argument(
+        "--update-snapshots",
+        action="store_true",
+        help="Re-baseline snapshots from the given outputs instead of comparing",
+    )
+    parser.add_argument(
+        "--tools-root",
+        type=Path,
+        default=DEFAULT_TOOLS_ROOT,
+        help="Root directory containing tool directories (default: src/tools)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def _discover_outputs(tool_dir: Path) -> list[Path]:
+    outputs_dir = tool_dir / "outputs"
+    if not outputs_dir.is_dir():
+        return []
+    return sorted(outputs_dir.glob("*/output.json"))
+
+
+def run(args: argparse.Namespace) -> int:
+    tool_dir = args.tools_root / args.tool
+    if not tool_dir.is_dir():
+        print(f"Error: no such tool directory: {tool_dir}")
+        return 2
+
+    outputs = args.output or _discover_outputs(tool_dir)
+    if not outputs:
+        print(f"No outputs found under {tool_dir / 'outputs'}; pass --output explicitly")
+        return 2
+    if args.name and len(outputs) != 1:
+        print("--name requires exactly one --output")
+        return 2
+
+    store = SnapshotStore(tool_dir)
+    regressions = 0
+    for output_path in outputs:
+        name = args.name or output_path.parent.name
+        try:
+            payload = json.loads(output_path.read_text())
+        except (OSError, json.JSONDecodeError) as exc:
+            print(f"FAIL {name}: cannot read {output_path}: {exc}")
+            regressions += 1
+            continue
+
+        if args.update_snapshots:
+            saved = store.save(name, payload)
+            print(f"UPDATED {name}: {saved}")
+            continue
+
+        diffs = store.check(name, payload)
+        if diffs:
+            regressions += 1
+            print(f"FAIL {name}: {len(diffs)} difference(s)")
+            for diff in diffs:
+                print(f"  {diff}")
+        else:
+            print(f"OK {name}")
+
+    if args.update_snapshots:
+        return 0
+    if regressions:
+        print(f"\n{regressions} snapshot(s) regressed. If intended, re-run with --update-snapshots.")
+        return 1
+    return 0
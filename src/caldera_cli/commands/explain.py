@@ -0,0 +1,56 @@
+"""`caldera explain` — full context for a single finding fingerprint."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "explain",
+        help="Explain a finding by its fingerprint",
+        description=(
+            "Prints the full context of one finding: rule, severity, "
+            "location, code excerpt, why it matched, how to suppress it, "
+            "and every stored run in which it appears. Fingerprints come "
+            "from Code Climate exports, Jira sync labels, or `caldera "
+            "query` output."
+        ),
+    )
+    parser.add_argument("fingerprint", help="Finding fingerprint (32-char hex)")
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--repo-path",
+        type=Path,
+        help="Repository checkout for the code excerpt (optional)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.explain import build_explanation, find_occurrences
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path), read_only=True)
+    try:
+        occurrences = find_occurrences(conn, args.fingerprint)
+    finally:
+        conn.close()
+    if not occurrences:
+        print(f"No stored finding matches fingerprint {args.fingerprint}")
+        return 1
+    print(build_explanation(args.fingerprint, occurrences, repo_root=args.repo_path))
+    return 0
@@ -0,0 +1,106 @@
+"""`caldera fix` — apply machine-applicable fixes from tool output."""
+
+from __future__ import annotations
+
+import argparse
+import json
+import subprocess
+from pathlib import Path
+
+DEFAULT_BACKUP_DIR = Path(".caldera-fix-backup")
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "fix",
+        help="Apply machine-applicable fixes from semgrep output",
+        description=(
+            "Collects fixes from a semgrep JSON output, drops overlapping "
+            "ones, and shows the resulting patch (--dry-run, default) or "
+            "applies it atomically with a backup and patch file (--apply). "
+            "--rescan-cmd re-runs the scanner afterwards and reports fixes "
+            "whose findings are still present."
+        ),
+    )
+    parser.add_argument(
+        "--from",
+        dest="fixes_from",
+        type=Path,
+        required=True,
+        metavar="JSON",
+        help="Tool output to collect fixes from (semgrep --json format)",
+    )
+    parser.add_argument("--repo-path", type=Path, default=Path.cwd(), help="Repository (default: cwd)")
+    mode = parser.add_mutually_exclusive_group()
+    mode.add_argument("--dry-run", action="store_true", help="Show the patch without writing (default)")
+    mode.add_argument("--apply", action="store_true", help="Apply the fixes to the working tree")
+    parser.add_argument(
+        "--backup-dir",
+        type=Path,
+        default=DEFAULT_BACKUP_DIR,
+        help=f"Where originals and the patch are kept (default: {DEFAULT_BACKUP_DIR})",
+    )
+    parser.add_argument(
+        "--rescan-cmd",
+        metavar="CMD",
+        help="Shell command producing fresh semgrep JSON on stdout, run after --apply",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    from caldera_cli.fixes import (
+        FixError,
+        apply_plan,
+        detect_conflicts,
+        load_fixes,
+        plan_patch,
+        unresolved_fixes,
+    )
+
+    try:
+        fixes = load_fixes(args.fixes_from)
+    except FixError as exc:
+        print(f"Error: {exc}")
+        return 1
+    if not fixes:
+        print(f"No machine-applicable fixes in {args.fixes_from}")
+        return 0
+    applicable, conflicts = detect_conflicts(fixes)
+    for first, second in conflicts:
+        print(
+            f"Conflict (both skipped): {first.rule_id} and {second.rule_id} "
+            f"overlap in {first.path}:{first.line_start}"
+        )
+    if not applicable:
+        print("All fixes conflict; nothing to apply")
+        return 1
+    try:
+        plan = plan_patch(args.repo_path, applicable)
+    except FixError as exc:
+        print(f"Error: {exc}")
+        return 1
+    if not args.apply:
+        print(plan.patch, end="")
+        print(f"{len(applicable)} fix(es) across {len(plan.new_contents)} file(s); re-run with --apply")
+        return 0
+    backup_dir = args.backup_dir if args.backup_dir.is_absolute() else args.repo_path / args.backup_dir
+    patch_path = apply_plan(args.repo_path, plan, backup_dir)
+    print(f"Applied {len(applicable)} fix(es) to {len(plan.new_contents)} file(s)")
+    print(f"Backups and patch: {patch_path.parent}")
+    if args.rescan_cmd:
+        result = subprocess.run(
+            args.rescan_cmd, shell=True, cwd=args.repo_path, capture_output=True, text=True
+        )
+        try:
+            fresh = json.loads(result.stdout or "{}")
+        except json.JSONDecodeError:
+            print("Error: re-scan did not produce valid JSON; verification skipped")
+            return 1
+        unresolved = unresolved_fixes(applicable, fresh)
+        if unresolved:
+            for fix in unresolved:
+                print(f"Still reported after re-scan: {fix.rule_id} in {fix.path}")
+            return 1
+        print("Re-scan confirms all fixed findings are resolved")
+    return 0
@@ -0,0 +1,122 @@
+"""`caldera hook` — staged-files pre-commit analysis and hook installer."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    hook_group = subparsers.add_parser(
+        "hook",
+        help="Fast pre-commit analysis of staged files",
+    )
+    hook_commands = hook_group.add_subparsers(dest="command", required=True)
+
+    run_cmd = hook_commands.add_parser(
+        "run",
+        help="Analyze the staged commit (intended to be called by git)",
+        description=(
+            "Runs fast native analyzers (complexity, secrets, LOC delta) "
+            "over staged blobs only, with per-blob result caching. Secrets "
+            "block the commit; other findings warn unless --strict."
+        ),
+    )
+    run_cmd.add_argument("--repo-path", type=Path, default=Path.cwd(), help="Repository (default: cwd)")
+    run_cmd.add_argument("--strict", action="store_true", help="Any finding blocks the commit")
+    run_cmd.add_argument(
+        "--max-added-lines",
+        type=int,
+        default=0,
+        metavar="N",
+        help="Warn when the commit adds more than N lines (0 disables)",
+    )
+    run_cmd.add_argument(
+        "--output",
+        choices=["text", "json", "grep"],
+        default="text",
+        help="Output format; grep emits path:line:col: lines for editors (default: text)",
+    )
+    run_cmd.add_argument(
+        "--filter",
+        metavar="EXPR",
+        help="Filter findings (shared --filter grammar, e.g. \"kind = secret\")",
+    )
+    run_cmd.add_argument(
+        "--select",
+        metavar="FIELDS",
+        help="Comma-separated finding fields to keep (e.g. path,line)",
+    )
+    run_cmd.set_defaults(handler=run_hook_command)
+
+    install = hook_commands.add_parser(
+        "install",
+        help="Install the git pre-commit hook or pre-commit-framework config",
+    )
+    install.add_argument("--repo-path", type=Path, default=Path.cwd(), help="Repository (default: cwd)")
+    install.add_argument(
+        "--framework",
+        action="store_true",
+        help="Write a .pre-commit-config.yaml entry instead of a git hook",
+    )
+    install.add_argument("--force", action="store_true", help="Overwrite an existing foreign hook")
+    install.set_defaults(handler=run_install_command)
+
+
+def run_hook_command(args: argparse.Namespace) -> int:
+    import json
+    import subprocess
+
+    from caldera_cli.hook import run_hook
+    from common.record_filter import FilterError, apply_filter, apply_select, parse_filter, parse_select
+
+    try:
+        row_filter = parse_filter(args.filter) if args.filter else None
+        select = parse_select(args.select) if args.select else None
+    except FilterError as exc:
+        print(f"Error: {exc}")
+        return 2
+    if row_filter is not None and args.output == "text":
+        print("Error: --filter requires --output json or grep")
+        return 2
+    if select is not None and args.output != "json":
+        print("Error: --select requires --output json")
+        return 2
+    try:
+        exit_code, findings = run_hook(
+            args.repo_path,
+            max_added_lines=args.max_added_lines,
+            strict=args.strict,
+            quiet=args.output != "text",
+        )
+    except subprocess.CalledProcessError as exc:
+        print(f"Error: git failed: {exc.stderr or exc}")
+        return 1
+    if args.output != "text":
+        rows = [finding.to_dict() for finding in findings]
+        if row_filter is not None:
+            rows = apply_filter(rows, row_filter)
+        if args.output == "grep":
+            for row in rows:
+                print(f"{row['path']}:{row['line'] or 1}:1: [caldera/{row['kind']}] {row['message']}")
+        else:
+            if select is not None:
+                rows = apply_select(rows, select)
+            print(json.dumps(rows, indent=2))
+    return exit_code
+
+
+def run_install_command(args: argparse.Namespace) -> int:
+    from caldera_cli.hook import install_git_hook, install_pre_commit_config
+
+    try:
+        if args.framework:
+            path = install_pre_commit_config(args.repo_path)
+            print(f"Wrote pre-commit framework config: {path}")
+        else:
+            path = install_git_hook(args.repo_path, force=args.force)
+            print(f"Installed git hook: {path}")
+    except RuntimeError as exc:
+        print(f"Error: {exc}")
+        return 1
+    return 0
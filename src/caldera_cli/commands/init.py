@@ -0,0 +1,129 @@
+"""`caldera init` — generate a starter caldera.toml for a repository."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "init",
+        help="Inspect a repo and generate a starter caldera.toml",
+        description=(
+            "Inspects the repository (languages, size, existing lint "
+            "configs) and writes a caldera.toml proposing enabled tools "
+            "and resource limits. With --baseline, all findings from the "
+            "latest stored run are additionally baselined so future scans "
+            "only report new ones."
+        ),
+    )
+    parser.add_argument(
+        "--repo-path",
+        type=Path,
+        default=Path.cwd(),
+        help="Repository to inspect (default: cwd)",
+    )
+    parser.add_argument(
+        "--output",
+        type=Path,
+        help="Where to write the config (default: <repo>/caldera.toml)",
+    )
+    parser.add_argument(
+        "--force",
+        action="store_true",
+        help="Overwrite an existing caldera.toml",
+    )
+    parser.add_argument(
+        "--dry-run",
+        action="store_true",
+        help="Print the proposed config instead of writing it",
+    )
+    parser.add_argument(
+        "--baseline",
+        action="store_true",
+        help="Also baseline all findings from the latest stored run",
+    )
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database for --baseline (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    from caldera_cli.init_config import _TOOL_ORDER, profile_repo, propose_tools, render_config
+
+    if not args.repo_path.is_dir():
+        print(f"Error: {args.repo_path} is not a directory")
+        return 1
+    profile = profile_repo(args.repo_path)
+    tools = propose_tools(profile)
+    config = render_config(profile, tools)
+    if args.dry_run:
+        print(config)
+        return 0
+    output = args.output or args.repo_path / "caldera.toml"
+    if output.exists() and not args.force:
+        print(f"Error: {output} already exists (use --force to overwrite)")
+        return 1
+    output.write_text(config)
+    languages = ", ".join(lang for lang, _ in profile.languages[:6]) or "none detected"
+    print(f"Wrote {output}")
+    print(f"  Languages: {languages}")
+    print(f"  Tools enabled: {len(tools)} of {len(_TOOL_ORDER)}")
+    if args.baseline:
+        return _create_baseline(args)
+    return 0
+
+
+def _create_baseline(args: argparse.Namespace) -> int:
+    """Baseline every finding in the latest completed run as fix-later."""
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.commands.query import _latest_completed_run
+    from caldera_cli.triage import (
+        TriageDecision,
+        ensure_triage_table,
+        load_decisions,
+        record_decision,
+        undecided_findings,
+        write_baseline,
+    )
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path))
+    try:
+        ensure_triage_table(conn)
+        run_id = _latest_completed_run(conn)
+        if not run_id:
+            print("Error: no completed collection runs in the database")
+            return 1
+        findings = undecided_findings(conn, run_id)
+        for finding in findings:
+            record_decision(
+                conn,
+                TriageDecision(
+                    finding["fingerprint"],
+                    "fix-later",
+                    None,
+                    finding["tool"],
+                    finding["rule"],
+                    finding["path"],
+                    finding["line"],
+                ),
+            )
+        all_decisions = load_decisions(conn)
+    finally:
+        conn.close()
+    baseline_path = args.repo_path / "caldera-baseline.json"
+    count = write_baseline(baseline_path, all_decisions)
+    print(f"  Baselined {len(findings)} finding(s) from {run_id} -> {baseline_path} ({count} total)")
+    return 0
@@ -0,0 +1,43 @@
+"""`caldera lsp` — Language Server Protocol server over stdio."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "lsp",
+        help="Serve cached findings as editor diagnostics via LSP (stdio)",
+        description=(
+            "Publishes stored findings, high-complexity functions, and "
+            "duplicated blocks as LSP diagnostics for any LSP-capable "
+            "editor, with quickfixes to suppress a finding or jump to a "
+            "duplicate's partner copy. Read-only."
+        ),
+    )
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--repo-id",
+        help="Pin queries to one repo (default: latest completed run of any repo)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    from caldera_cli.lsp_server import LSPServer
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    LSPServer(args.db_path, repo_id=args.repo_id).run()
+    return 0
@@ -0,0 +1,42 @@
+"""`caldera mcp` — Model Context Protocol server over stdio."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "mcp",
+        help="Serve stored analysis to AI assistants via MCP (stdio)",
+        description=(
+            "Speaks the Model Context Protocol on stdin/stdout so LLM coding "
+            "assistants can query findings, function complexity, and code "
+            "duplicates from the landing zone while refactoring. Read-only."
+        ),
+    )
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--repo-id",
+        help="Pin queries to one repo (default: latest completed run of any repo)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    from caldera_cli.mcp_server import MCPServer
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    MCPServer(args.db_path, repo_id=args.repo_id).run()
+    return 0
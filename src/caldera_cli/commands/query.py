@@ -0,0 +1,105 @@
+"""`caldera query` — run findings queries against the landing zone."""
+
+from __future__ import annotations
+
+import argparse
+import json
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "query",
+        help="Query stored findings with a filter expression",
+        description=(
+            "Runs a filter expression over a run's findings, e.g. "
+            "\"severity >= high AND tool = semgrep AND path GLOB "
+            "'src/payments/**' ORDER BY line\". Fields: tool, path, rule, "
+            "severity, line, message; combine with AND/OR/NOT, GLOB/LIKE, "
+            "ORDER BY, and LIMIT."
+        ),
+    )
+    parser.add_argument("expression", help="Query expression to evaluate")
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--run",
+        metavar="RUN_ID",
+        help="Collection run to query (default: latest completed run)",
+    )
+    parser.add_argument(
+        "--output",
+        choices=["table", "json", "grep"],
+        default="table",
+        help="Output format; grep emits path:line:col: lines for editors (default: table)",
+    )
+    parser.add_argument(
+        "--filter",
+        metavar="EXPR",
+        help="Extra filter applied to result rows (shared --filter grammar)",
+    )
+    parser.add_argument(
+        "--select",
+        metavar="FIELDS",
+        help="Comma-separated fields to keep in the output (e.g. path,line)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def _latest_completed_run(conn) -> str | None:
+    row = conn.execute(
+        """SELECT collection_run_id FROM lz_collection_runs
+           WHERE status = 'completed'
+           ORDER BY started_at DESC LIMIT 1"""
+    ).fetchone()
+    return row[0] if row else None
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.query import QueryError, format_grep, format_table, parse_query, run_query
+    from common.record_filter import FilterError, apply_filter, apply_select, parse_filter, parse_select
+
+    try:
+        query = parse_query(args.expression)
+        row_filter = parse_filter(args.filter) if args.filter else None
+        select = parse_select(args.select) if args.select else None
+    except (QueryError, FilterError) as exc:
+        print(f"Error: {exc}")
+        return 2
+    if select is not None and args.output == "grep":
+        print("Error: --select requires --output table or json")
+        return 2
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path), read_only=True)
+    try:
+        run_id = args.run or _latest_completed_run(conn)
+        if not run_id:
+            print("Error: no completed collection runs in the database")
+            return 1
+        rows = run_query(conn, query, run_id)
+    finally:
+        conn.close()
+    if row_filter is not None:
+        rows = apply_filter(rows, row_filter)
+    if select is not None:
+        rows = apply_select(rows, select)
+    if args.output == "json":
+        print(json.dumps({"run": run_id, "count": len(rows), "findings": rows}, indent=2))
+    elif args.output == "grep":
+        # No matches means no output, like grep itself.
+        if rows:
+            print(format_grep(rows))
+    else:
+        print(format_table(rows))
+    return 0
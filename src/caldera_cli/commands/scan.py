@@ -0,0 +1,85 @@
+"""`caldera scan` — run the full pipeline against a repository.
+
+Thin wrapper over the orchestrator that adds resume support: interrupted
+scans checkpoint completed tool work, and ``caldera scan --resume <run-id>``
+picks up where the previous attempt died instead of restarting from scratch.
+"""
+
+from __future__ import annotations
+
+import argparse
+import subprocess
+import sys
+from pathlib import Path
+
+_REPO_ROOT = Path(__file__).resolve().parents[3]
+ORCHESTRATOR_PATH = _REPO_ROOT / "src" / "sot-engine" / "orchestrator.py"
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "scan",
+        help="Run the full analysis pipeline against a repository",
+        description=(
+            "Runs tools, ingests outputs into the landing zone, and optionally "
+            "builds marts. Completed tool work is checkpointed so interrupted "
+            "scans can continue with --resume."
+        ),
+    )
+    parser.add_argument("--repo-path", required=True, help="Repository to analyze")
+    parser.add_argument("--repo-id", required=True, help="Stable identifier for the repository")
+    parser.add_argument("--commit", help="Full 40-char commit SHA")
+    parser.add_argument("--branch", help="Branch name (default: main)")
+    parser.add_argument("--db-path", help="DuckDB database path")
+    parser.add_argument("--replace", action="store_true", help="Replace an existing run for repo+commit")
+    parser.add_argument("--run-dbt", action="store_true", help="Build marts after ingestion")
+    parser.add_argument(
+        "--resume",
+        metavar="RUN_ID",
+        help="Resume the interrupted scan with this run id from its checkpoint",
+    )
+    parser.add_argument(
+        "--profile",
+        metavar="NAME",
+        help=(
+            "Scan preset: fast (native analyzers only), standard, deep "
+            "(all tools + history analysis), or a [profile.<name>] from caldera.toml"
+        ),
+    )
+    parser.add_argument(
+        "--timings",
+        action="store_true",
+        help="Print a per-phase/per-tool performance breakdown after the scan",
+    )
+    parser.set_defaults(handler=run)
+
+
+def build_orchestrator_argv(args: argparse.Namespace) -> list[str]:
+    """Translate scan arguments into an orchestrator invocation."""
+    argv = [
+        sys.executable, str(ORCHESTRATOR_PATH),
+        "--repo-path", args.repo_path,
+        "--repo-id", args.repo_id,
+        "--run-tools",
+    ]
+    if args.resume:
+        argv += ["--run-id", args.resume, "--resume"]
+    if args.commit:
+        argv += ["--commit", args.commit]
+    if args.branch:
+        argv += ["--branch", args.branch]
+    if args.db_path:
+        argv += ["--db-path", args.db_path]
+    if args.replace:
+        argv.append("--replace")
+    if args.run_dbt:
+        argv.append("--run-dbt")
+    if args.profile:
+        argv += ["--profile", args.profile]
+    if args.timings:
+        argv.append("--timings")
+    return argv
+
+
+def run(args: argparse.Namespace) -> int:
+    return subprocess.run(build_orchestrator_argv(args)).returncode
@@ -0,0 +1,57 @@
+"""`caldera serve` — run the HTTP API server."""
+
+from __future__ import annotations
+
+import argparse
+import os
+from pathlib import Path
+
+DEFAULT_DB_PATH = Path(os.environ.get("HOME", "/tmp")) / ".caldera" / "caldera_sot.duckdb"
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "serve",
+        help="Run the Caldera HTTP API server",
+        description=(
+            "Exposes scan triggering, progress streaming, and run/finding "
+            "queries as a JSON-over-HTTP API for dashboards and automation."
+        ),
+    )
+    parser.add_argument("--host", default="127.0.0.1", help="Bind address (default: 127.0.0.1)")
+    parser.add_argument("--port", type=int, default=8765, help="Port (default: 8765)")
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to serve (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--auth-tokens",
+        type=Path,
+        metavar="TOKENS_FILE",
+        help="Require bearer-token auth using this token store (see `caldera tokens`)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def load_token_store(path: Path | None):
+    """Load the token store for --auth-tokens, or None when auth is off."""
+    if path is None:
+        return None
+    from caldera_cli.auth import TokenStore
+
+    store = TokenStore.load(path)
+    if not any(not record.revoked for record in store.records):
+        print(f"Warning: {path} has no active tokens; all authenticated requests will fail")
+    return store
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    from caldera_cli.server import serve_forever
+
+    if not args.db_path.exists():
+        print(f"Warning: database {args.db_path} does not exist yet; queries will fail until a scan runs")
+    serve_forever(args.host, args.port, args.db_path, token_store=load_token_store(args.auth_tokens))
+    return 0
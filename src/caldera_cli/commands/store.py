@@ -0,0 +1,123 @@
+"""`caldera store` — result store maintenance commands."""
+
+from __future__ import annotations
+
+import argparse
+import sys
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+# Repo root on path for persistence imports (src/sot-engine is not a package).
+_SOT_ENGINE = Path(__file__).resolve().parents[2] / "sot-engine"
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    store_group = subparsers.add_parser("store", help="Result store maintenance")
+    store_commands = store_group.add_subparsers(dest="command", required=True)
+
+    gc = store_commands.add_parser(
+        "gc",
+        help="Prune old runs and compact the store",
+        description=(
+            "Applies the [retention] policy from caldera.toml: keeps the N "
+            "newest runs per repo, drops runs older than the age cutoff, and "
+            "compacts the database afterwards. Runs in 'running' status are "
+            "never pruned."
+        ),
+    )
+    gc.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB file or postgresql:// DSN (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    gc.add_argument("--config", type=Path, help="Path to caldera.toml (defaults to repo root)")
+    gc.add_argument("--keep-runs", type=int, help="Override keep_runs_per_repo")
+    gc.add_argument("--max-age-days", type=int, help="Override max_age_days")
+    gc.add_argument("--dry-run", action="store_true", help="Show what would be pruned")
+    gc.add_argument("--no-compact", action="store_true", help="Skip compaction after pruning")
+    gc.set_defaults(handler=run_gc_command)
+
+    raw = store_commands.add_parser(
+        "raw",
+        help="Inspect or prune the compressed raw-output archive",
+        description=(
+            "Lists runs archived by `orchestrator --archive-raw` with their "
+            "compressed sizes, or deletes one run's archives."
+        ),
+    )
+    raw.add_argument(
+        "--root",
+        type=Path,
+        help="Raw store directory (default: ~/.caldera/raw)",
+    )
+    raw.add_argument("--delete-run", metavar="RUN_ID", help="Delete one run's archives")
+    raw.set_defaults(handler=run_raw_command)
+
+
+def run_gc_command(args: argparse.Namespace) -> int:
+    sys.path.insert(0, str(_SOT_ENGINE))
+    from persistence.retention import RetentionPolicy, load_retention_policy, run_gc
+
+    config = args.config
+    if config is None:
+        config = Path(__file__).resolve().parents[3] / "caldera.toml"
+    policy = load_retention_policy(config)
+    if args.keep_runs is not None or args.max_age_days is not None:
+        policy = RetentionPolicy(
+            keep_runs_per_repo=(
+                args.keep_runs if args.keep_runs is not None else policy.keep_runs_per_repo
+            ),
+            max_age_days=(
+                args.max_age_days if args.max_age_days is not None else policy.max_age_days
+            ),
+        )
+
+    db_path = str(args.db_path)
+    if not db_path.startswith(("postgresql://", "postgres://")) and not Path(db_path).exists():
+        print(f"Error: database {db_path} does not exist")
+        return 1
+
+    summary = run_gc(db_path, policy, dry_run=args.dry_run, do_compact=not args.no_compact)
+
+    verb = "Would prune" if args.dry_run else "Pruned"
+    print(f"{verb} {len(summary['pruned_runs'])} collection run(s)")
+    for run in summary["pruned_runs"]:
+        print(f"  {run['collection_run_id']}: {run['reason']}")
+    total_rows = sum(summary["rows_deleted"].values())
+    if total_rows:
+        print(f"Deleted {total_rows} rows across {len(summary['rows_deleted'])} tables")
+    before, after = summary["size_before_bytes"], summary["size_after_bytes"]
+    if before is not None and after is not None and after < before:
+        print(f"Store size: {before:,} -> {after:,} bytes")
+    return 0
+
+
+def run_raw_command(args: argparse.Namespace) -> int:
+    sys.path.insert(0, str(_SOT_ENGINE))
+    from persistence.raw_store import DEFAULT_RAW_STORE_ROOT, RawResultStore
+
+    store = RawResultStore(args.root if args.root else DEFAULT_RAW_STORE_ROOT)
+
+    if args.delete_run:
+        freed = store.delete_run(args.delete_run)
+        if not freed:
+            print(f"No archives for run {args.delete_run} under {store.root}")
+            return 1
+        print(f"Deleted archives for {args.delete_run}: {freed:,} bytes freed")
+        return 0
+
+    runs = store.list_runs()
+    if not runs:
+        print(f"No archived runs under {store.root}")
+        return 0
+    for run_id in runs:
+        tools = store.list_tools(run_id)
+        total = sum(
+            handle.compressed_size
+            for tool in tools
+            if (handle := store.load(run_id, tool)) is not None
+        )
+        print(f"{run_id}: {len(tools)} tool(s), {total:,} bytes")
+    return 0
@@ -0,0 +1,111 @@
+"""`caldera suggest` — rank refactoring opportunities for a run."""
+
+from __future__ import annotations
+
+import argparse
+import json
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "suggest",
+        help="Rank refactoring candidates by payoff and blast radius",
+        description=(
+            "Combines clone classes, over-threshold functions, and recent "
+            "churn into a ranked list of refactoring candidates. Each entry "
+            "shows the estimated payoff (remediation minutes removed, "
+            "priced like the insights debt model) and the blast radius "
+            "(files the refactoring touches)."
+        ),
+    )
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--run",
+        metavar="RUN_ID",
+        help="Collection run to rank (default: latest completed run)",
+    )
+    parser.add_argument(
+        "--min-ccn",
+        type=int,
+        default=15,
+        help="CCN threshold for decompose-hotspot candidates (default: 15)",
+    )
+    parser.add_argument(
+        "--limit",
+        type=int,
+        default=15,
+        help="Maximum candidates to show (default: 15)",
+    )
+    parser.add_argument(
+        "--output",
+        choices=["table", "json"],
+        default="table",
+        help="Output format (default: table)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.commands.query import _latest_completed_run
+    from caldera_cli.suggest import suggest
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path), read_only=True)
+    try:
+        run_id = args.run or _latest_completed_run(conn)
+        if not run_id:
+            print("Error: no completed collection runs in the database")
+            return 1
+        candidates = suggest(conn, run_id, min_ccn=args.min_ccn)
+    finally:
+        conn.close()
+
+    candidates = candidates[: args.limit]
+    if args.output == "json":
+        print(
+            json.dumps(
+                {
+                    "run": run_id,
+                    "count": len(candidates),
+                    "candidates": [
+                        {
+                            "kind": c.kind,
+                            "target": c.target,
+                            "description": c.description,
+                            "files": list(c.files),
+                            "payoff_minutes": c.payoff_minutes,
+                            "blast_radius": c.blast_radius,
+                            "churn_90d": c.churn_90d,
+                            "score": c.score,
+                        }
+                        for c in candidates
+                    ],
+                },
+                indent=2,
+            )
+        )
+        return 0
+
+    if not candidates:
+        print("No refactoring candidates above the thresholds.")
+        return 0
+    print(f"{'Score':>7}  {'Payoff':>7}  {'Blast':>5}  {'Churn':>5}  Candidate")
+    for c in candidates:
+        print(
+            f"{c.score:>7.1f}  {c.payoff_minutes:>6.0f}m  {c.blast_radius:>5}  "
+            f"{c.churn_90d:>5}  [{c.kind}] {c.target}: {c.description}"
+        )
+    return 0
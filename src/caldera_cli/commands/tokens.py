@@ -0,0 +1,124 @@
+"""`caldera tokens` — manage API tokens for server mode."""
+
+from __future__ import annotations
+
+import argparse
+import os
+from pathlib import Path
+
+DEFAULT_TOKENS_PATH = Path(os.environ.get("HOME", "/tmp")) / ".caldera" / "tokens.json"
+
+
+def _add_store_arg(parser: argparse.ArgumentParser) -> None:
+    parser.add_argument(
+        "--store",
+        type=Path,
+        default=DEFAULT_TOKENS_PATH,
+        help="Token store file (default: ~/.caldera/tokens.json)",
+    )
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    tokens_group = subparsers.add_parser(
+        "tokens",
+        help="Manage API tokens for `caldera serve --auth-tokens`",
+    )
+    tokens_commands = tokens_group.add_subparsers(dest="command", required=True)
+
+    create = tokens_commands.add_parser(
+        "create",
+        help="Issue a new token (plaintext is printed exactly once)",
+    )
+    create.add_argument("name", help="Token name (one active token per name)")
+    create.add_argument(
+        "--scope",
+        choices=["read", "write"],
+        default="read",
+        help="Grant: read (query) or write (trigger scans; implies read)",
+    )
+    create.add_argument(
+        "--repo",
+        action="append",
+        dest="repos",
+        metavar="REPO_ID",
+        help="Restrict to a repo_id (repeatable; default: all repos)",
+    )
+    _add_store_arg(create)
+    create.set_defaults(handler=run_create)
+
+    list_cmd = tokens_commands.add_parser("list", help="List tokens (hashes, never plaintext)")
+    _add_store_arg(list_cmd)
+    list_cmd.set_defaults(handler=run_list)
+
+    rotate = tokens_commands.add_parser(
+        "rotate",
+        help="Revoke a token and issue a replacement with the same grants",
+    )
+    rotate.add_argument("name", help="Token name to rotate")
+    _add_store_arg(rotate)
+    rotate.set_defaults(handler=run_rotate)
+
+    revoke = tokens_commands.add_parser("revoke", help="Revoke a token")
+    revoke.add_argument("name", help="Token name to revoke")
+    _add_store_arg(revoke)
+    revoke.set_defaults(handler=run_revoke)
+
+
+def run_create(args: argparse.Namespace) -> int:
+    from caldera_cli.auth import AuthError, TokenStore
+
+    store = TokenStore.load(args.store)
+    repos = tuple(args.repos) if args.repos else ("*",)
+    try:
+        token = store.issue(args.name, args.scope, repos)
+    except AuthError as exc:
+        print(f"Error: {exc}")
+        return 1
+    print(f"Token {args.name!r} ({args.scope}, repos: {', '.join(repos)}):")
+    print(f"  {token}")
+    print("Store this now — it cannot be recovered later.")
+    return 0
+
+
+def run_list(args: argparse.Namespace) -> int:
+    from caldera_cli.auth import TokenStore
+
+    store = TokenStore.load(args.store)
+    if not store.records:
+        print(f"No tokens in {args.store}")
+        return 0
+    for record in store.records:
+        state = "revoked" if record.revoked else "active"
+        print(
+            f"{record.name}: {record.scope}, repos: {', '.join(record.repos)}, "
+            f"{state}, created {record.created_at or 'unknown'}"
+        )
+    return 0
+
+
+def run_rotate(args: argparse.Namespace) -> int:
+    from caldera_cli.auth import AuthError, TokenStore
+
+    store = TokenStore.load(args.store)
+    try:
+        token = store.rotate(args.name)
+    except AuthError as exc:
+        print(f"Error: {exc}")
+        return 1
+    print(f"Rotated token {args.name!r}; new value:")
+    print(f"  {token}")
+    print("Store this now — it cannot be recovered later.")
+    return 0
+
+
+def run_revoke(args: argparse.Namespace) -> int:
+    from caldera_cli.auth import AuthError, TokenStore
+
+    store = TokenStore.load(args.store)
+    try:
+        store.revoke(args.name)
+    except AuthError as exc:
+        print(f"Error: {exc}")
+        return 1
+    print(f"Revoked token {args.name!r}")
+    return 0
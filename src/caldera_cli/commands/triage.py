@@ -0,0 +1,91 @@
+"""`caldera triage` — interactively triage new findings."""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+
+from caldera_cli.commands.serve import DEFAULT_DB_PATH
+
+
+def register(subparsers: argparse._SubParsersAction) -> None:
+    parser = subparsers.add_parser(
+        "triage",
+        help="Step through new findings and record decisions",
+        description=(
+            "Steps through a run's undecided findings; each can be marked "
+            "fix-later (baselined), false-positive (suppressed, with a "
+            "reason), or wont-fix. Decisions are persisted in the store "
+            "and the baseline/suppression files are regenerated."
+        ),
+    )
+    parser.add_argument(
+        "--db-path",
+        type=Path,
+        default=DEFAULT_DB_PATH,
+        help="DuckDB database to query (default: ~/.caldera/caldera_sot.duckdb)",
+    )
+    parser.add_argument(
+        "--run",
+        metavar="RUN_ID",
+        help="Collection run to triage (default: latest completed run)",
+    )
+    parser.add_argument(
+        "--baseline",
+        type=Path,
+        default=Path("caldera-baseline.json"),
+        help="Baseline file to regenerate (default: caldera-baseline.json)",
+    )
+    parser.add_argument(
+        "--suppressions",
+        type=Path,
+        default=Path("caldera-suppressions.json"),
+        help="Suppression file to regenerate (default: caldera-suppressions.json)",
+    )
+    parser.set_defaults(handler=run)
+
+
+def run(args: argparse.Namespace) -> int:
+    # Imported lazily so `caldera --help` works without duckdb installed.
+    import duckdb
+
+    from caldera_cli.commands.query import _latest_completed_run
+    from caldera_cli.triage import (
+        ensure_triage_table,
+        load_decisions,
+        record_decision,
+        triage_findings,
+        undecided_findings,
+        write_baseline,
+        write_suppressions,
+    )
+
+    if not args.db_path.exists():
+        print(f"Error: database {args.db_path} does not exist; run a scan first")
+        return 1
+    conn = duckdb.connect(str(args.db_path))
+    try:
+        ensure_triage_table(conn)
+        run_id = args.run or _latest_completed_run(conn)
+        if not run_id:
+            print("Error: no completed collection runs in the database")
+            return 1
+        findings = undecided_findings(conn, run_id)
+        if not findings:
+            print(f"No undecided findings in {run_id}")
+            return 0
+        decisions = triage_findings(findings)
+        for decision in decisions:
+            record_decision(conn, decision)
+        all_decisions = load_decisions(conn)
+    finally:
+        conn.close()
+    baselined = write_baseline(args.baseline, all_decisions)
+    suppressed = write_suppressions(args.suppressions, all_decisions)
+    print(
+        f"Recorded {len(decisions)} decision(s); baseline now has {baselined} "
+        f"entr(ies), suppressions {suppressed}"
+    )
+    print(f"Baseline: {args.baseline}")
+    print(f"Suppressions: {args.suppressions}")
+    return 0
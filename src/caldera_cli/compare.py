@@ -0,0 +1,245 @@
+"""Delta report between two stored collection runs.
+
+``caldera compare <run-a> <run-b>`` diffs the unified findings of two
+runs by fingerprint — new, resolved, and persisting — and reports the
+headline metric deltas (code lines from scc, mean CCN from lizard,
+duplicated-line % from pmd-cpd) between them. Runs can be named by
+collection run id or by a commit SHA prefix, so a PR pipeline can
+compare the merge-base scan against the branch scan directly. Renders
+as terminal text, markdown for the PR comment, or JSON.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+import duckdb
+
+from caldera_cli.explain import fingerprint
+from caldera_cli.query import _SEVERITY_RANK, _unified_view_sql
+
+
+@dataclass(frozen=True)
+class RunMetrics:
+    """Headline metrics for one run; None when the tool did not run."""
+
+    code_lines: int | None
+    mean_ccn: float | None
+    duplication_pct: float | None
+
+
+@dataclass(frozen=True)
+class RunDelta:
+    """Everything that changed between two runs."""
+
+    run_a: str
+    run_b: str
+    new: tuple[dict, ...]
+    resolved: tuple[dict, ...]
+    persisting_count: int
+    metrics_a: RunMetrics
+    metrics_b: RunMetrics
+
+
+def resolve_run(conn: duckdb.DuckDBPyConnection, ref: str) -> str:
+    """Resolve a run reference — collection run id or commit SHA prefix."""
+    row = conn.execute(
+        "SELECT collection_run_id FROM lz_collection_runs WHERE collection_run_id = ?",
+        [ref],
+    ).fetchone()
+    if row:
+        return row[0]
+    rows = conn.execute(
+        """SELECT collection_run_id FROM lz_collection_runs
+           WHERE commit LIKE ? ORDER BY started_at DESC""",
+        [ref + "%"],
+    ).fetchall()
+    if not rows:
+        raise ValueError(f"no stored run matches {ref!r} (run id or commit prefix)")
+    return rows[0][0]
+
+
+def _run_findings(conn: duckdb.DuckDBPyConnection, run_id: str) -> dict[str, dict]:
+    rows = conn.execute(
+        f"""SELECT tool, path, rule, severity, line, message
+            FROM ({_unified_view_sql()}) findings
+            WHERE collection_run_id = ?""",
+        [run_id],
+    ).fetchall()
+    findings = {}
+    for tool, path, rule, severity, line, message in rows:
+        fp = fingerprint(tool, rule or "", path, line)
+        findings[fp] = {
+            "fingerprint": fp,
+            "tool": tool,
+            "path": path,
+            "rule": rule,
+            "severity": severity,
+            "line": line,
+            "message": message,
+        }
+    return findings
+
+
+def _run_metrics(conn: duckdb.DuckDBPyConnection, run_id: str) -> RunMetrics:
+    loc = conn.execute(
+        """SELECT SUM(f.code_lines) FROM lz_scc_file_metrics f
+           JOIN lz_tool_runs t ON t.run_pk = f.run_pk
+           WHERE t.collection_run_id = ?""",
+        [run_id],
+    ).fetchone()[0]
+    ccn_row = conn.execute(
+        """SELECT SUM(f.total_ccn), SUM(f.function_count) FROM lz_lizard_file_metrics f
+           JOIN lz_tool_runs t ON t.run_pk = f.run_pk
+           WHERE t.collection_run_id = ?""",
+        [run_id],
+    ).fetchone()
+    dup_row = conn.execute(
+        """SELECT SUM(f.duplicate_lines), SUM(f.total_lines) FROM lz_pmd_cpd_file_metrics f
+           JOIN lz_tool_runs t ON t.run_pk = f.run_pk
+           WHERE t.collection_run_id = ?""",
+        [run_id],
+    ).fetchone()
+    return RunMetrics(
+        code_lines=int(loc) if loc is not None else None,
+        mean_ccn=round(ccn_row[0] / ccn_row[1], 1) if ccn_row and ccn_row[1] else None,
+        duplication_pct=round(100.0 * dup_row[0] / dup_row[1], 1) if dup_row and dup_row[1] else None,
+    )
+
+
+def _severity_order(finding: dict) -> tuple:
+    rank = _SEVERITY_RANK.get((finding["severity"] or "").upper(), 0)
+    return (-rank, finding["path"], finding["line"] or 0)
+
+
+def compare_runs(conn: duckdb.DuckDBPyConnection, ref_a: str, ref_b: str) -> RunDelta:
+    """Diff two runs' findings by fingerprint and collect metric deltas."""
+    run_a = resolve_run(conn, ref_a)
+    run_b = resolve_run(conn, ref_b)
+    findings_a = _run_findings(conn, run_a)
+    findings_b = _run_findings(conn, run_b)
+    new = sorted(
+        (findings_b[fp] for fp in findings_b.keys() - findings_a.keys()),
+        key=_severity_order,
+    )
+    resolved = sorted(
+        (findings_a[fp] for fp in findings_a.keys() - findings_b.keys()),
+        key=_severity_order,
+    )
+    return RunDelta(
+        run_a=run_a,
+        run_b=run_b,
+        new=tuple(new),
+        resolved=tuple(resolved),
+        persisting_count=len(findings_a.keys() & findings_b.keys()),
+        metrics_a=_run_metrics(conn, run_a),
+        metrics_b=_run_metrics(conn, run_b),
+    )
+
+
+_METRIC_LABELS = (
+    ("code_lines", "code lines"),
+    ("mean_ccn", "mean CCN"),
+    ("duplication_pct", "duplication %"),
+)
+
+
+def _format_delta(before: float | None, after: float | None) -> str:
+    if before is None or after is None:
+        return f"{before if before is not None else '?'} -> {after if after is not None else '?'}"
+    delta = after - before
+    sign = "+" if delta >= 0 else ""
+    if isinstance(before, int) and isinstance(after, int):
+        return f"{before} -> {after} ({sign}{delta})"
+    return f"{before} -> {after} ({sign}{round(delta, 1)})"
+
+
+def _finding_line(finding: dict) -> str:
+    location = f"{finding['path']}:{finding['line']}" if finding["line"] else finding["path"]
+    line = f"{finding['severity'] or '?'} {finding['tool']}/{finding['rule'] or '-'} {location}"
+    if finding["message"]:
+        line += f" — {finding['message'].splitlines()[0]}"
+    return line
+
+
+def to_dict(delta: RunDelta) -> dict:
+    """JSON-ready representation of the delta."""
+    return {
+        "run_a": delta.run_a,
+        "run_b": delta.run_b,
+        "new": list(delta.new),
+        "resolved": list(delta.resolved),
+        "persisting_count": delta.persisting_count,
+        "metrics": {
+            key: {
+                "before": getattr(delta.metrics_a, key),
+                "after": getattr(delta.metrics_b, key),
+            }
+            for key, _ in _METRIC_LABELS
+        },
+    }
+
+
+def render_text(delta: RunDelta) -> str:
+    lines = [
+        f"Comparing {delta.run_a} -> {delta.run_b}",
+        f"Findings: {len(delta.new)} new, {len(delta.resolved)} resolved, "
+        f"{delta.persisting_count} persisting",
+    ]
+    if delta.new:
+        lines.append("")
+        lines.append("New:")
+        lines += [f"  {_finding_line(f)}" for f in delta.new]
+    if delta.resolved:
+        lines.append("")
+        lines.append("Resolved:")
+        lines += [f"  {_finding_line(f)}" for f in delta.resolved]
+    lines.append("")
+    lines.append("Metrics:")
+    for key, label in _METRIC_LABELS:
+        lines.append(
+            f"  {label:<14} "
+            + _format_delta(getattr(delta.metrics_a, key), getattr(delta.metrics_b, key))
+        )
+    return "\n".join(lines)
+
+
+def render_markdown(delta: RunDelta) -> str:
+    lines = [
+        f"## Caldera delta: `{delta.run_a}` → `{delta.run_b}`",
+        "",
+        f"**{len(delta.new)} new**, {len(delta.resolved)} resolved, "
+        f"{delta.persisting_count} persisting findings",
+    ]
+    for title, findings in (("New findings", delta.new), ("Resolved findings", delta.resolved)):
+        if not findings:
+            continue
+        lines += [
+            "",
+            f"### {title}",
+            "",
+            "| Severity | Tool/Rule | Location | Message |",
+            "| --- | --- | --- | --- |",
+        ]
+        for finding in findings:
+            location = (
+                f"{finding['path']}:{finding['line']}" if finding["line"] else finding["path"]
+            )
+            message = (finding["message"] or "").splitlines()[0] if finding["message"] else ""
+            lines.append(
+                f"| {finding['severity'] or '?'} "
+                f"| {finding['tool']}/{finding['rule'] or '-'} "
+                f"| `{location}` | {message} |"
+            )
+    lines += [
+        "",
+        "### Metrics",
+        "",
+        "| Metric | Before | After |",
+        "| --- | --- | --- |",
+    ]
+    for key, label in _METRIC_LABELS:
+        before = getattr(delta.metrics_a, key)
+        after = getattr(delta.metrics_b, key)
+        lines.append(f"| {label} | {before if before is not None else '?'} | {after if after is not None else '?'} |")
+    return "\n".join(lines)
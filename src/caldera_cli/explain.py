@@ -0,0 +1,137 @@
+"""Full-context explanation of a single finding.
+
+``caldera explain <fingerprint>`` turns the opaque fingerprint a reviewer
+gets from a Code Climate export, a Jira ticket label, or ``caldera query``
+output back into something actionable: which rule fired where, the code
+around the finding, how to suppress it for that tool, and every stored
+run in which the same finding appears.
+
+Fingerprints use the same formula as ``insights.codeclimate.fingerprint``
+(md5 of ``tool:rule:path:line``), so identifiers from those exports
+resolve here directly. Occurrences are found by scanning the unified
+findings view across all stored runs and fingerprinting each row.
+"""
+
+from __future__ import annotations
+
+import hashlib
+from dataclasses import dataclass
+from pathlib import Path
+
+import duckdb
+
+from caldera_cli.query import _unified_view_sql
+
+# Context lines shown on each side of the finding in the code excerpt.
+EXCERPT_CONTEXT = 3
+
+# What each tool looks for — the one-line "why it matched" framing.
+_TOOL_HINTS = {
+    "semgrep": "semgrep matched the rule's code pattern against this file",
+    "bandit": "bandit flagged a known-risky Python construct",
+    "devskim": "devskim matched a security lint pattern",
+    "gitleaks": "gitleaks matched a secret pattern in git history",
+    "roslyn-analyzers": "a Roslyn analyzer reported this diagnostic",
+    "sonarqube": "SonarQube reported this issue for the file",
+    "trivy": "trivy matched a vulnerable package version",
+}
+
+# Per-tool inline suppression syntax; {rule} is substituted.
+_SUPPRESSION_HINTS = {
+    "semgrep": "add `# nosemgrep: {rule}` on the flagged line",
+    "bandit": "add `# nosec {rule}` on the flagged line",
+    "devskim": "add `// DevSkim: ignore {rule}` on the flagged line",
+    "gitleaks": "add the finding's fingerprint to .gitleaksignore",
+    "roslyn-analyzers": "add `#pragma warning disable {rule}` around the code",
+    "sonarqube": "add `// NOSONAR` on the flagged line or mark it in SonarQube",
+    "trivy": "add the vulnerability ID to .trivyignore",
+}
+
+
+def fingerprint(tool: str, rule: str, path: str, line: int | None) -> str:
+    """Stable finding identity; mirrors insights.codeclimate.fingerprint."""
+    raw = ":".join([tool, rule, path, str(line or 0)])
+    return hashlib.md5(raw.encode("utf-8")).hexdigest()
+
+
+@dataclass(frozen=True)
+class Occurrence:
+    """One stored instance of the explained finding."""
+
+    collection_run_id: str
+    started_at: str
+    tool: str
+    path: str
+    rule: str
+    severity: str | None
+    line: int | None
+    message: str | None
+
+
+def find_occurrences(conn: duckdb.DuckDBPyConnection, target: str) -> list[Occurrence]:
+    """All stored findings whose fingerprint matches, newest run first."""
+    rows = conn.execute(
+        f"""SELECT f.tool, f.path, f.rule, f.severity, f.line, f.message,
+                   f.collection_run_id, c.started_at
+            FROM ({_unified_view_sql()}) f
+            JOIN lz_collection_runs c ON c.collection_run_id = f.collection_run_id
+            ORDER BY c.started_at DESC, f.path"""
+    ).fetchall()
+    occurrences = []
+    for tool, path, rule, severity, line, message, run_id, started_at in rows:
+        if fingerprint(tool, rule or "", path, line) == target:
+            occurrences.append(
+                Occurrence(run_id, str(started_at), tool, path, rule, severity, line, message)
+            )
+    return occurrences
+
+
+def code_excerpt(repo_root: Path, path: str, line: int) -> str | None:
+    """±EXCERPT_CONTEXT lines around the finding, or None if unreadable."""
+    file_path = repo_root / path
+    try:
+        lines = file_path.read_text(encoding="utf-8", errors="replace").splitlines()
+    except OSError:
+        return None
+    if not 1 <= line <= len(lines):
+        return None
+    start = max(1, line - EXCERPT_CONTEXT)
+    end = min(len(lines), line + EXCERPT_CONTEXT)
+    excerpt = []
+    for number in range(start, end + 1):
+        marker = ">" if number == line else " "
+        excerpt.append(f"  {marker} {number:>5} | {lines[number - 1]}")
+    return "\n".join(excerpt)
+
+
+def build_explanation(
+    target: str, occurrences: list[Occurrence], repo_root: Path | None = None
+) -> str:
+    """Render the full explanation for one fingerprint."""
+    latest = occurrences[0]
+    location = f"{latest.path}:{latest.line}" if latest.line else latest.path
+    lines = [
+        f"Finding {target}",
+        f"  tool:     {latest.tool}",
+        f"  rule:     {latest.rule}",
+        f"  severity: {latest.severity or 'unknown'}",
+        f"  location: {location}",
+    ]
+    if latest.message:
+        lines += ["", f"Rule says: {latest.message}"]
+    hint = _TOOL_HINTS.get(latest.tool)
+    if hint:
+        lines += ["", f"Why it matched: {hint}."]
+    if repo_root is not None and latest.line:
+        excerpt = code_excerpt(repo_root, latest.path, latest.line)
+        if excerpt:
+            lines += ["", "Code:", excerpt]
+    suppression = _SUPPRESSION_HINTS.get(latest.tool)
+    lines += ["", "To suppress:"]
+    if suppression:
+        lines.append(f"  - {suppression.format(rule=latest.rule)}")
+    lines.append(f"  - add `{latest.path}` to .calderaignore to exclude the file entirely")
+    lines += ["", f"Seen in {len(occurrences)} stored run(s):"]
+    for occurrence in occurrences:
+        lines.append(f"  {occurrence.started_at}  {occurrence.collection_run_id}")
+    return "\n".join(lines)
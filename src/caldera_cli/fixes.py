@@ -0,0 +1,181 @@
+"""Machine-applicable fix collection and application.
+
+Semgrep emits a ``fix`` alongside findings for rules that know their
+remediation. ``caldera fix`` collects those fixes from a semgrep JSON
+output, drops the ones whose spans overlap (applying both would corrupt
+the file), and applies the rest atomically: originals are copied to a
+backup directory and a unified patch file is written first, then each
+file is replaced via ``os.replace`` so a crash never leaves a
+half-written source file. A fresh scan can then be compared against the
+applied fixes to confirm the findings are actually gone.
+
+Spans follow semgrep's convention: 1-based line and column, end
+exclusive. Fixes are applied bottom-up per file so earlier spans stay
+valid while later ones are replaced.
+"""
+
+from __future__ import annotations
+
+import difflib
+import json
+import os
+from dataclasses import dataclass
+from pathlib import Path
+
+
+class FixError(RuntimeError):
+    """A fix could not be collected or applied."""
+
+
+@dataclass(frozen=True)
+class Fix:
+    """One machine-applicable fix: replace [start, end) with ``replacement``."""
+
+    tool: str
+    rule_id: str
+    path: str
+    line_start: int
+    column_start: int
+    line_end: int
+    column_end: int
+    replacement: str
+
+
+@dataclass(frozen=True)
+class PatchPlan:
+    """Resolved new contents per file plus the combined unified diff."""
+
+    new_contents: dict[str, str]
+    patch: str
+    fixes: tuple[Fix, ...]
+
+
+def collect_semgrep_fixes(output: dict) -> list[Fix]:
+    """Fixes from a semgrep ``--json`` output; findings without one are skipped."""
+    fixes = []
+    for result in output.get("results", []):
+        fix = (result.get("extra") or {}).get("fix")
+        if fix is None:
+            continue
+        start, end = result.get("start") or {}, result.get("end") or {}
+        fixes.append(
+            Fix(
+                tool="semgrep",
+                rule_id=result.get("check_id", ""),
+                path=result.get("path", ""),
+                line_start=start.get("line", 1),
+                column_start=start.get("col", 1),
+                line_end=end.get("line", 1),
+                column_end=end.get("col", 1),
+                replacement=fix,
+            )
+        )
+    return fixes
+
+
+def load_fixes(fixes_path: Path) -> list[Fix]:
+    """Collect fixes from a JSON file (currently: semgrep output format)."""
+    try:
+        output = json.loads(fixes_path.read_text())
+    except (OSError, json.JSONDecodeError) as exc:
+        raise FixError(f"cannot read fixes from {fixes_path}: {exc}") from exc
+    if not isinstance(output, dict):
+        raise FixError(f"{fixes_path} is not a JSON object")
+    return collect_semgrep_fixes(output)
+
+
+def _offset(text: str, line: int, column: int) -> int:
+    """Absolute offset of a 1-based (line, column) position."""
+    lines = text.splitlines(keepends=True)
+    if line < 1 or line > len(lines) + 1:
+        raise FixError(f"line {line} out of range")
+    return sum(len(l) for l in lines[: line - 1]) + (column - 1)
+
+
+def detect_conflicts(fixes: list[Fix]) -> tuple[list[Fix], list[tuple[Fix, Fix]]]:
+    """Split fixes into an applicable set and overlapping pairs.
+
+    Two fixes conflict when their spans in the same file overlap; both
+    are withheld, since either order of application would clobber the
+    other's context.
+    """
+    conflicts: list[tuple[Fix, Fix]] = []
+    conflicted: set[Fix] = set()
+    by_path: dict[str, list[Fix]] = {}
+    for fix in fixes:
+        by_path.setdefault(fix.path, []).append(fix)
+    for path_fixes in by_path.values():
+        ordered = sorted(path_fixes, key=lambda f: (f.line_start, f.column_start))
+        for first, second in zip(ordered, ordered[1:]):
+            if (first.line_end, first.column_end) > (second.line_start, second.column_start):
+                conflicts.append((first, second))
+                conflicted.update((first, second))
+    applicable = [fix for fix in fixes if fix not in conflicted]
+    return applicable, conflicts
+
+
+def plan_patch(repo_root: Path, fixes: list[Fix]) -> PatchPlan:
+    """Compute post-fix file contents and the unified patch; changes nothing."""
+    new_contents: dict[str, str] = {}
+    patch_chunks: list[str] = []
+    applied: list[Fix] = []
+    by_path: dict[str, list[Fix]] = {}
+    for fix in fixes:
+        by_path.setdefault(fix.path, []).append(fix)
+    for path in sorted(by_path):
+        file_path = repo_root / path
+        try:
+            original = file_path.read_text(encoding="utf-8")
+        except OSError as exc:
+            raise FixError(f"cannot read {path}: {exc}") from exc
+        text = original
+        ordered = sorted(
+            by_path[path], key=lambda f: (f.line_start, f.column_start), reverse=True
+        )
+        for fix in ordered:
+            start = _offset(text, fix.line_start, fix.column_start)
+            end = _offset(text, fix.line_end, fix.column_end)
+            text = text[:start] + fix.replacement + text[end:]
+            applied.append(fix)
+        new_contents[path] = text
+        patch_chunks.extend(
+            difflib.unified_diff(
+                original.splitlines(keepends=True),
+                text.splitlines(keepends=True),
+                fromfile=f"a/{path}",
+                tofile=f"b/{path}",
+            )
+        )
+    return PatchPlan(new_contents, "".join(patch_chunks), tuple(applied))
+
+
+def apply_plan(repo_root: Path, plan: PatchPlan, backup_dir: Path) -> Path:
+    """Apply a plan atomically; returns the written patch file path.
+
+    Every touched file is first copied into ``backup_dir`` (preserving
+    its relative path) and the combined patch is written there, so the
+    change can be reverted wholesale even without git.
+    """
+    backup_dir.mkdir(parents=True, exist_ok=True)
+    for path in plan.new_contents:
+        original = (repo_root / path).read_text(encoding="utf-8")
+        backup_path = backup_dir / path
+        backup_path.parent.mkdir(parents=True, exist_ok=True)
+        backup_path.write_text(original, encoding="utf-8")
+    patch_path = backup_dir / "caldera-fix.patch"
+    patch_path.write_text(plan.patch, encoding="utf-8")
+    for path, text in plan.new_contents.items():
+        target = repo_root / path
+        tmp_path = target.with_name(target.name + ".caldera-fix-tmp")
+        tmp_path.write_text(text, encoding="utf-8")
+        os.replace(tmp_path, target)
+    return patch_path
+
+
+def unresolved_fixes(fixes: list[Fix], fresh_output: dict) -> list[Fix]:
+    """Applied fixes whose (path, rule) still report findings after a re-scan."""
+    remaining = {
+        (result.get("path", ""), result.get("check_id", ""))
+        for result in fresh_output.get("results", [])
+    }
+    return [fix for fix in fixes if (fix.path, fix.rule_id) in remaining]
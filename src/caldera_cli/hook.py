@@ -0,0 +1,298 @@
+"""Pre-commit hook mode: staged-files-only analysis on a sub-second budget.
+
+Analyzes only the staged blobs of the current commit with fast native
+analyzers — no tool subprocesses, no landing zone:
+
+    complexity — decision-point count per function (regex-based, language-light)
+    secrets    — credential patterns plus an entropy check for generic keys
+    loc        — added/removed line delta for the commit
+
+Results are cached per blob SHA in ``~/.caldera/hook-cache.json`` so
+re-commits after review comments only analyze blobs that changed.
+Secrets always block the commit; complexity and LOC budget findings warn
+unless ``--strict``. ``caldera hook install`` writes the git hook or a
+pre-commit-framework config entry.
+"""
+
+from __future__ import annotations
+
+import json
+import math
+import re
+import subprocess
+import time
+from collections import Counter
+from dataclasses import dataclass
+from pathlib import Path
+
+CACHE_VERSION = 1
+DEFAULT_CACHE_PATH = Path.home() / ".caldera" / "hook-cache.json"
+MAX_CACHE_ENTRIES = 10_000
+
+CCN_THRESHOLD = 15
+MAX_SECRET_ENTROPY = 3.5
+DEFAULT_MAX_ADDED_LINES = 0  # 0 disables the LOC budget
+
+_FUNCTION_RE = re.compile(
+    r"^\s*(?:async\s+)?(?:def|fn|func|function)\s+(\w+)|^\s*(?:public|private|protected|internal)\s[\w\s<>\[\],]*?(\w+)\s*\(",
+)
+_DECISION_RE = re.compile(
+    r"\b(?:if|elif|else if|for|while|case|when|catch|except)\b|&&|\|\|"
+)
+
+# (pattern, label). Generic assignments additionally need high entropy.
+_SECRET_PATTERNS: list[tuple[re.Pattern[str], str]] = [
+    (re.compile(r"\bAKIA[0-9A-Z]{16}\b"), "AWS access key"),
+    (re.compile(r"-----BEGIN [A-Z ]*PRIVATE KEY-----"), "private key"),
+    (re.compile(r"\bghp_[A-Za-z0-9]{36}\b"), "GitHub token"),
+    (re.compile(r"\bgithub_pat_[A-Za-z0-9_]{22,}\b"), "GitHub fine-grained token"),
+    (re.compile(r"\bxox[baprs]-[A-Za-z0-9-]{10,}\b"), "Slack token"),
+]
+_GENERIC_SECRET_RE = re.compile(
+    r"(?i)\b(api[_-]?key|secret|token|password)\b\s*[:=]\s*[\"']([^\"']{16,})[\"']"
+)
+_PLACEHOLDER_MARKERS = ("example", "changeme", "placeholder", "dummy", "xxx", "<", "${", "{{")
+
+_BINARY_SUFFIXES = {".png", ".jpg", ".jpeg", ".gif", ".ico", ".pdf", ".zip", ".gz", ".jar", ".so", ".dll", ".exe", ".woff", ".woff2", ".duckdb"}
+
+
+@dataclass(frozen=True)
+class HookFinding:
+    """One pre-commit finding."""
+
+    kind: str  # complexity | secret | loc
+    path: str
+    line: int
+    message: str
+    blocking: bool
+
+    def to_dict(self) -> dict:
+        return {
+            "kind": self.kind,
+            "path": self.path,
+            "line": self.line,
+            "message": self.message,
+            "blocking": self.blocking,
+        }
+
+
+def _entropy(value: str) -> float:
+    counts = Counter(value)
+    total = len(value)
+    return -sum((n / total) * math.log2(n / total) for n in counts.values())
+
+
+def analyze_complexity(path: str, text: str) -> list[HookFinding]:
+    """Warn on functions whose decision-point count exceeds the threshold."""
+    findings = []
+    lines = text.splitlines()
+    boundaries: list[tuple[int, str]] = [
+        (number, match.group(1) or match.group(2))
+        for number, line in enumerate(lines, start=1)
+        if (match := _FUNCTION_RE.match(line))
+    ]
+    for index, (start, name) in enumerate(boundaries):
+        end = boundaries[index + 1][0] - 1 if index + 1 < len(boundaries) else len(lines)
+        decisions = sum(
+            len(_DECISION_RE.findall(line)) for line in lines[start:end]
+        )
+        if decisions + 1 > CCN_THRESHOLD:
+            findings.append(HookFinding(
+                kind="complexity",
+                path=path,
+                line=start,
+                message=f"{name} has ~{decisions + 1} decision points (threshold {CCN_THRESHOLD})",
+                blocking=False,
+            ))
+    return findings
+
+
+def analyze_secrets(path: str, text: str) -> list[HookFinding]:
+    """Block on credential patterns staged into the commit."""
+    findings = []
+    for number, line in enumerate(text.splitlines(), start=1):
+        for pattern, label in _SECRET_PATTERNS:
+            if pattern.search(line):
+                findings.append(HookFinding(
+                    kind="secret", path=path, line=number,
+                    message=f"{label} staged in commit", blocking=True,
+                ))
+        for match in _GENERIC_SECRET_RE.finditer(line):
+            value = match.group(2)
+            if any(marker in value.lower() for marker in _PLACEHOLDER_MARKERS):
+                continue
+            if _entropy(value) >= MAX_SECRET_ENTROPY:
+                findings.append(HookFinding(
+                    kind="secret", path=path, line=number,
+                    message=f"high-entropy {match.group(1)} value staged in commit",
+                    blocking=True,
+                ))
+    return findings
+
+
+def analyze_blob(path: str, text: str) -> list[HookFinding]:
+    return analyze_secrets(path, text) + analyze_complexity(path, text)
+
+
+class HookCache:
+    """Findings per blob SHA, so unchanged blobs are never re-analyzed."""
+
+    def __init__(self, path: Path = DEFAULT_CACHE_PATH) -> None:
+        self.path = path
+        self._entries: dict[str, list[dict]] = {}
+        if path.exists():
+            try:
+                data = json.loads(path.read_text())
+            except json.JSONDecodeError:
+                data = {}
+            if data.get("version") == CACHE_VERSION:
+                self._entries = data.get("blobs", {})
+
+    def get(self, blob_sha: str) -> list[HookFinding] | None:
+        entries = self._entries.get(blob_sha)
+        if entries is None:
+            return None
+        return [HookFinding(**entry) for entry in entries]
+
+    def put(self, blob_sha: str, findings: list[HookFinding]) -> None:
+        self._entries[blob_sha] = [finding.to_dict() for finding in findings]
+
+    def save(self) -> None:
+        while len(self._entries) > MAX_CACHE_ENTRIES:
+            self._entries.pop(next(iter(self._entries)))
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        self.path.write_text(json.dumps({"version": CACHE_VERSION, "blobs": self._entries}))
+
+
+def _git(repo_path: Path, *argv: str) -> str:
+    result = subprocess.run(
+        ["git", "-C", str(repo_path), *argv],
+        capture_output=True, text=True, check=True,
+    )
+    return result.stdout
+
+
+def staged_blobs(repo_path: Path) -> dict[str, str]:
+    """Staged path -> blob SHA for added/changed files (deletes excluded)."""
+    changed = set(
+        _git(repo_path, "diff", "--cached", "--name-only", "--diff-filter=ACMR").split("\n")
+    )
+    blobs = {}
+    for line in _git(repo_path, "ls-files", "--cached", "-s").splitlines():
+        meta, path = line.split("\t", 1)
+        if path in changed:
+            blobs[path] = meta.split()[1]
+    return blobs
+
+
+def loc_delta(repo_path: Path) -> tuple[int, int]:
+    """(added, removed) lines across the staged diff."""
+    added = removed = 0
+    for line in _git(repo_path, "diff", "--cached", "--numstat").splitlines():
+        parts = line.split("\t")
+        if parts[0] != "-":  # '-' marks binary files
+            added += int(parts[0])
+            removed += int(parts[1])
+    return added, removed
+
+
+def run_hook(
+    repo_path: Path,
+    cache: HookCache | None = None,
+    max_added_lines: int = DEFAULT_MAX_ADDED_LINES,
+    strict: bool = False,
+    analyzer=analyze_blob,
+    quiet: bool = False,
+) -> tuple[int, list[HookFinding]]:
+    """Analyze the staged commit; returns (exit_code, findings)."""
+    started = time.monotonic()
+    cache = cache if cache is not None else HookCache()
+    findings: list[HookFinding] = []
+    for path, blob_sha in sorted(staged_blobs(repo_path).items()):
+        if Path(path).suffix.lower() in _BINARY_SUFFIXES:
+            continue
+        cached = cache.get(blob_sha)
+        if cached is not None:
+            findings.extend(cached)
+            continue
+        try:
+            text = _git(repo_path, "show", f":{path}")
+        except subprocess.CalledProcessError:
+            continue
+        blob_findings = analyzer(path, text)
+        cache.put(blob_sha, blob_findings)
+        findings.extend(blob_findings)
+    cache.save()
+
+    added, removed = loc_delta(repo_path)
+    if max_added_lines and added > max_added_lines:
+        findings.append(HookFinding(
+            kind="loc", path="", line=0,
+            message=f"commit adds {added} lines (budget {max_added_lines}); consider splitting",
+            blocking=False,
+        ))
+
+    blocking = any(f.blocking for f in findings) or (strict and bool(findings))
+    elapsed_ms = (time.monotonic() - started) * 1000
+    if not quiet:
+        for finding in findings:
+            marker = "BLOCK" if finding.blocking or strict else "warn"
+            location = f"{finding.path}:{finding.line}: " if finding.path else ""
+            print(f"  [{marker}] {location}{finding.message}")
+        print(
+            f"caldera hook: {len(findings)} finding(s), +{added}/-{removed} lines, "
+            f"{elapsed_ms:.0f}ms"
+        )
+    return (1 if blocking else 0), findings
+
+
+# -- installer ---------------------------------------------------------------
+
+HOOK_MARKER = "# installed by `caldera hook install`"
+
+HOOK_SCRIPT = f"""#!/bin/sh
+{HOOK_MARKER}
+exec python3 -m caldera_cli hook run "$@"
+"""
+
+PRE_COMMIT_CONFIG_ENTRY = """\
+  - repo: local
+    hooks:
+      - id: caldera-hook
+        name: caldera pre-commit analysis
+        entry: python3 -m caldera_cli hook run
+        language: system
+        pass_filenames: false
+"""
+
+
+def install_git_hook(repo_path: Path, force: bool = False) -> Path:
+    """Write .git/hooks/pre-commit; refuses to overwrite a foreign hook."""
+    git_dir = Path(_git(repo_path, "rev-parse", "--git-dir").strip())
+    if not git_dir.is_absolute():
+        git_dir = repo_path / git_dir
+    hook_path = git_dir / "hooks" / "pre-commit"
+    if hook_path.exists() and HOOK_MARKER not in hook_path.read_text() and not force:
+        raise RuntimeError(
+            f"{hook_path} already exists and was not installed by caldera; "
+            "use --force to overwrite"
+        )
+    hook_path.parent.mkdir(parents=True, exist_ok=True)
+    hook_path.write_text(HOOK_SCRIPT)
+    hook_path.chmod(0o755)
+    return hook_path
+
+
+def install_pre_commit_config(repo_path: Path) -> Path:
+    """Append the caldera hook to .pre-commit-config.yaml (create if absent)."""
+    config_path = repo_path / ".pre-commit-config.yaml"
+    if config_path.exists():
+        content = config_path.read_text()
+        if "caldera-hook" in content:
+            return config_path
+        if not content.endswith("\n"):
+            content += "\n"
+        config_path.write_text(content + PRE_COMMIT_CONFIG_ENTRY)
+    else:
+        config_path.write_text("repos:\n" + PRE_COMMIT_CONFIG_ENTRY)
+    return config_path
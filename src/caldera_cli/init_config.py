@@ -0,0 +1,227 @@
+"""Starter-configuration wizard backing ``caldera init``.
+
+Inspects a repository — languages present, size, existing lint configs —
+and proposes a ``caldera.toml``: which tools are worth enabling for this
+codebase, plus ``[limits]`` caps scaled to the repo's size. The emitted
+``[tools] enabled`` list is honoured by the orchestrator (tools not on
+the list are skipped), and the ``[limits]``/``[gates]`` sections are the
+ones the rest of the pipeline already reads. Pass the file to the
+orchestrator with ``--config``.
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+from pathlib import Path
+
+_SKIP_DIRS = {
+    ".git",
+    ".hg",
+    ".svn",
+    ".venv",
+    "venv",
+    "node_modules",
+    "__pycache__",
+    "bin",
+    "obj",
+    "target",
+    "dist",
+    "build",
+}
+
+# Extension → language, for the languages that steer tool selection.
+_LANGUAGE_EXTENSIONS = {
+    ".py": "Python",
+    ".cs": "C#",
+    ".js": "JavaScript",
+    ".jsx": "JavaScript",
+    ".ts": "TypeScript",
+    ".tsx": "TypeScript",
+    ".go": "Go",
+    ".rs": "Rust",
+    ".sh": "Shell",
+    ".bash": "Shell",
+    ".sql": "SQL",
+    ".tf": "Terraform",
+    ".java": "Java",
+    ".rb": "Ruby",
+    ".php": "PHP",
+}
+
+# Root-level lint/scanner configs worth calling out in the proposal.
+_LINT_CONFIG_FILES = (
+    ".editorconfig",
+    ".eslintrc",
+    ".eslintrc.json",
+    ".eslintrc.js",
+    ".flake8",
+    ".gitleaks.toml",
+    ".golangci.yml",
+    ".golangci.yaml",
+    ".pre-commit-config.yaml",
+    ".pylintrc",
+    ".ruff.toml",
+    ".sqlfluff",
+    "ruff.toml",
+    "sonar-project.properties",
+)
+
+# Language-independent tools proposed for every repo.
+_ALWAYS_TOOLS = (
+    "layout-scanner",
+    "scc",
+    "lizard",
+    "semgrep",
+    "gitleaks",
+    "pmd-cpd",
+    "todo-scanner",
+)
+
+_LANGUAGE_TOOLS = {
+    "Python": ("bandit",),
+    "C#": ("roslyn-analyzers", "dotcover"),
+    "JavaScript": ("jscpd",),
+    "TypeScript": ("jscpd",),
+    "Go": ("golangci",),
+    "Rust": ("rust-deadcode", "rust-api"),
+    "Shell": ("shellcheck",),
+    "SQL": ("sqlfluff",),
+    "Terraform": ("trivy", "checkov"),
+}
+
+_GIT_TOOLS = ("git-fame", "git-sizer", "git-blame-scanner")
+
+# Canonical tool ordering for the emitted config (orchestrator order).
+_TOOL_ORDER = (
+    "layout-scanner",
+    "scc",
+    "lizard",
+    "roslyn-analyzers",
+    "semgrep",
+    "sonarqube",
+    "trivy",
+    "gitleaks",
+    "symbol-scanner",
+    "scancode",
+    "pmd-cpd",
+    "jscpd",
+    "devskim",
+    "bandit",
+    "shellcheck",
+    "checkov",
+    "golangci",
+    "sqlfluff",
+    "todo-scanner",
+    "rust-deadcode",
+    "rust-api",
+    "dotcover",
+    "git-fame",
+    "git-sizer",
+    "git-blame-scanner",
+    "dependensee",
+)
+
+# Repos past this size get the larger resource caps.
+_LARGE_REPO_MB = 200
+
+
+@dataclass(frozen=True)
+class RepoProfile:
+    """What the wizard learned about the repository."""
+
+    file_count: int
+    total_bytes: int
+    languages: tuple[tuple[str, int], ...]  # (language, file count), most files first
+    lint_configs: tuple[str, ...]
+    is_git_repo: bool
+
+
+def profile_repo(repo_path: Path) -> RepoProfile:
+    """Walk the repo and count files per language, skipping vendored dirs."""
+    file_count = 0
+    total_bytes = 0
+    language_counts: dict[str, int] = {}
+    for root, dirs, files in os.walk(repo_path):
+        dirs[:] = sorted(d for d in dirs if d not in _SKIP_DIRS)
+        for name in files:
+            path = Path(root) / name
+            try:
+                total_bytes += path.stat().st_size
+            except OSError:
+                continue
+            file_count += 1
+            if name == "Dockerfile":
+                language_counts["Dockerfile"] = language_counts.get("Dockerfile", 0) + 1
+                continue
+            language = _LANGUAGE_EXTENSIONS.get(path.suffix.lower())
+            if language:
+                language_counts[language] = language_counts.get(language, 0) + 1
+    languages = tuple(
+        sorted(language_counts.items(), key=lambda item: (-item[1], item[0]))
+    )
+    lint_configs = tuple(
+        name for name in _LINT_CONFIG_FILES if (repo_path / name).exists()
+    )
+    return RepoProfile(
+        file_count=file_count,
+        total_bytes=total_bytes,
+        languages=languages,
+        lint_configs=lint_configs,
+        is_git_repo=(repo_path / ".git").exists(),
+    )
+
+
+def propose_tools(profile: RepoProfile) -> tuple[str, ...]:
+    """Tool enablement proposal, in canonical orchestrator order."""
+    enabled = set(_ALWAYS_TOOLS)
+    present = {language for language, _ in profile.languages}
+    for language in present:
+        enabled.update(_LANGUAGE_TOOLS.get(language, ()))
+    if "Dockerfile" in present:
+        enabled.add("trivy")
+    if profile.is_git_repo:
+        enabled.update(_GIT_TOOLS)
+    return tuple(name for name in _TOOL_ORDER if name in enabled)
+
+
+def render_config(profile: RepoProfile, tools: tuple[str, ...]) -> str:
+    """The proposed caldera.toml, annotated with what was detected."""
+    size_mb = profile.total_bytes / (1024 * 1024)
+    large = size_mb > _LARGE_REPO_MB
+    lines = [
+        "# caldera.toml — generated by `caldera init`",
+        f"# Detected: {profile.file_count} files, {size_mb:.1f} MB"
+        + ("" if profile.is_git_repo else " (not a git repo)"),
+    ]
+    if profile.languages:
+        summary = ", ".join(f"{lang} ({count})" for lang, count in profile.languages[:6])
+        lines.append(f"# Languages: {summary}")
+    if profile.lint_configs:
+        lines.append(f"# Existing lint configs: {', '.join(profile.lint_configs)}")
+    lines += [
+        "",
+        "[tools]",
+        "# Tools not on this list are skipped by the orchestrator.",
+        "enabled = [",
+        *[f'    "{name}",' for name in tools],
+        "]",
+        "",
+        "[limits]",
+        "# Per-tool resource caps; 0 disables a cap. Sized for a "
+        + ("large" if large else "small-to-medium")
+        + " repo.",
+        f"max_memory_mb = {8192 if large else 4096}",
+        f"max_cpu_seconds = {3600 if large else 1200}",
+        f"max_output_mb = {512 if large else 256}",
+        "",
+        "[gates.no_fixable_criticals]",
+        "enabled = true",
+        "max_count = 0",
+        "",
+        "[gates.no_denied_licenses]",
+        "enabled = true",
+        "max_count = 0",
+        "",
+    ]
+    return "\n".join(lines)
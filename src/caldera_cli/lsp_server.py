@@ -0,0 +1,292 @@
+"""LSP server surfacing cached findings as editor diagnostics.
+
+Speaks the Language Server Protocol (JSON-RPC 2.0 with Content-Length
+framing over stdio) so any LSP-capable editor shows Caldera's stored
+findings, high-complexity functions, and duplicated blocks inline —
+no per-IDE plugin required. Data access reuses the MCP server's query
+layer; the landing zone is never written. Started via ``caldera lsp``.
+
+Code actions:
+    Suppress finding       — inserts a ``caldera:suppress <rule>`` comment;
+                             diagnostics on marked lines are filtered out
+    Show duplicate partner — navigates to the other copy of a clone via
+                             window/showDocument
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+from typing import BinaryIO
+from urllib.parse import unquote, urlparse
+
+from caldera_cli.mcp_server import MCPServer
+
+SUPPRESS_MARKER = "caldera:suppress"
+SHOW_PARTNER_COMMAND = "caldera.showDuplicatePartner"
+
+CCN_WARNING_THRESHOLD = 10
+
+# LSP DiagnosticSeverity: 1=Error, 2=Warning, 3=Information, 4=Hint.
+_SEVERITY_MAP = {"CRITICAL": 1, "HIGH": 1, "MEDIUM": 2, "LOW": 3, "INFO": 3}
+
+# Line-comment leader per extension for the suppress edit ('#' default).
+_COMMENT_LEADERS = {
+    ".c": "//", ".cpp": "//", ".cs": "//", ".go": "//", ".java": "//",
+    ".js": "//", ".jsx": "//", ".kt": "//", ".rs": "//", ".swift": "//",
+    ".ts": "//", ".tsx": "//", ".sql": "--", ".lua": "--",
+}
+
+
+def read_message(stream: BinaryIO) -> dict | None:
+    """Read one Content-Length framed message; None at end of stream."""
+    length = None
+    while True:
+        line = stream.readline()
+        if not line:
+            return None
+        line = line.strip()
+        if not line:
+            break
+        if line.lower().startswith(b"content-length:"):
+            length = int(line.split(b":", 1)[1])
+    if length is None:
+        return None
+    return json.loads(stream.read(length))
+
+
+def write_message(stream: BinaryIO, message: dict) -> None:
+    body = json.dumps(message).encode()
+    stream.write(f"Content-Length: {len(body)}\r\n\r\n".encode() + body)
+    stream.flush()
+
+
+def _uri_to_path(uri: str) -> Path:
+    return Path(unquote(urlparse(uri).path))
+
+
+class LSPServer:
+    """Publishes landing-zone diagnostics for documents the editor opens."""
+
+    def __init__(self, db_path: Path, repo_id: str | None = None, send=None) -> None:
+        self._data = MCPServer(db_path, repo_id=repo_id)
+        self._send = send or (lambda message: write_message(sys.stdout.buffer, message))
+        self._root: Path | None = None
+        self._documents: dict[str, str] = {}
+        self._diagnostics: dict[str, list[dict]] = {}
+        self._request_id = 0
+        self.exited = False
+
+    # -- document helpers --------------------------------------------------
+
+    def _relative_path(self, uri: str) -> str | None:
+        if self._root is None:
+            return None
+        try:
+            return _uri_to_path(uri).relative_to(self._root).as_posix()
+        except ValueError:
+            return None
+
+    def _suppressed(self, uri: str, line: int, code: str) -> bool:
+        lines = self._documents.get(uri, "").splitlines()
+        if line >= len(lines) or SUPPRESS_MARKER not in lines[line]:
+            return False
+        marker_args = lines[line].split(SUPPRESS_MARKER, 1)[1].split()
+        return not marker_args or code in marker_args
+
+    # -- diagnostics -------------------------------------------------------
+
+    def _build_diagnostics(self, uri: str, relative_path: str) -> list[dict]:
+        diagnostics: list[dict] = []
+        for finding in self._data.get_findings(relative_path)["findings"]:
+            if finding.get("relative_path") != relative_path:
+                continue  # prefix query; keep exact matches only
+            line = max((finding.get("line_start") or finding.get("line_number") or 1) - 1, 0)
+            end_line = max((finding.get("line_end") or line + 1) - 1, line)
+            code = finding.get("rule_id") or finding.get("vulnerability_id") or finding["tool"]
+            diagnostics.append({
+                "range": {"start": {"line": line, "character": 0},
+                          "end": {"line": end_line, "character": 0}},
+                "severity": _SEVERITY_MAP.get(str(finding.get("severity")).upper(), 2),
+                "code": code,
+                "source": f"caldera ({finding['tool']})",
+                "message": finding.get("message") or finding.get("secret_type") or code,
+            })
+        for fn in self._data.get_function_metrics(path=relative_path)["functions"]:
+            if fn["relative_path"] != relative_path or fn["ccn"] < CCN_WARNING_THRESHOLD:
+                continue
+            line = max((fn["line_start"] or 1) - 1, 0)
+            diagnostics.append({
+                "range": {"start": {"line": line, "character": 0},
+                          "end": {"line": line, "character": 0}},
+                "severity": 2,
+                "code": "complexity",
+                "source": "caldera (lizard)",
+                "message": f"{fn['function_name']} has cyclomatic complexity {fn['ccn']} "
+                           f"(threshold {CCN_WARNING_THRESHOLD})",
+            })
+        for clone in self._data.get_duplicates_of(relative_path)["clones"]:
+            partners = [occ for occ in clone["occurrences"]
+                        if occ["relative_path"] != relative_path]
+            for occ in clone["occurrences"]:
+                if occ["relative_path"] != relative_path:
+                    continue
+                line = max(occ["line_start"] - 1, 0)
+                diagnostics.append({
+                    "range": {"start": {"line": line, "character": 0},
+                              "end": {"line": max(occ["line_end"] - 1, line), "character": 0}},
+                    "severity": 3,
+                    "code": "duplication",
+                    "source": "caldera (pmd-cpd)",
+                    "message": f"duplicated block ({clone['occurrence_count']} copies, "
+                               f"{clone['lines']} lines)",
+                    "data": {"partners": partners},
+                })
+        return [
+            diagnostic for diagnostic in diagnostics
+            if not self._suppressed(uri, diagnostic["range"]["start"]["line"],
+                                    str(diagnostic["code"]))
+        ]
+
+    def _publish(self, uri: str) -> None:
+        relative_path = self._relative_path(uri)
+        diagnostics = self._build_diagnostics(uri, relative_path) if relative_path else []
+        self._diagnostics[uri] = diagnostics
+        self._send({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": {"uri": uri, "diagnostics": diagnostics},
+        })
+
+    # -- code actions ------------------------------------------------------
+
+    def _code_actions(self, params: dict) -> list[dict]:
+        uri = params["textDocument"]["uri"]
+        actions: list[dict] = []
+        for diagnostic in params.get("context", {}).get("diagnostics", []):
+            if not str(diagnostic.get("source", "")).startswith("caldera"):
+                continue
+            actions.append(self._suppress_action(uri, diagnostic))
+            for partner in (diagnostic.get("data") or {}).get("partners", []):
+                actions.append({
+                    "title": f"Show duplicate partner: {partner['relative_path']}:"
+                             f"{partner['line_start']}",
+                    "kind": "quickfix",
+                    "diagnostics": [diagnostic],
+                    "command": {
+                        "title": "Show duplicate partner",
+                        "command": SHOW_PARTNER_COMMAND,
+                        "arguments": [partner],
+                    },
+                })
+        return actions
+
+    def _suppress_action(self, uri: str, diagnostic: dict) -> dict:
+        line = diagnostic["range"]["start"]["line"]
+        lines = self._documents.get(uri, "").splitlines()
+        line_text = lines[line] if line < len(lines) else ""
+        leader = _COMMENT_LEADERS.get(_uri_to_path(uri).suffix, "#")
+        position = {"line": line, "character": len(line_text)}
+        return {
+            "title": f"Suppress finding ({diagnostic.get('code')})",
+            "kind": "quickfix",
+            "diagnostics": [diagnostic],
+            "edit": {
+                "changes": {
+                    uri: [{
+                        "range": {"start": position, "end": position},
+                        "newText": f"  {leader} {SUPPRESS_MARKER} {diagnostic.get('code')}",
+                    }],
+                },
+            },
+        }
+
+    def _show_partner(self, partner: dict) -> None:
+        """Ask the client to open the other copy of the clone."""
+        if self._root is None:
+            return
+        self._request_id += 1
+        line = max(partner["line_start"] - 1, 0)
+        self._send({
+            "jsonrpc": "2.0",
+            "id": f"caldera-{self._request_id}",
+            "method": "window/showDocument",
+            "params": {
+                "uri": (self._root / partner["relative_path"]).as_uri(),
+                "takeFocus": True,
+                "selection": {"start": {"line": line, "character": 0},
+                              "end": {"line": line, "character": 0}},
+            },
+        })
+
+    # -- protocol ----------------------------------------------------------
+
+    def handle(self, message: dict) -> dict | None:
+        """Handle one message; returns the response for requests, else None."""
+        method = message.get("method", "")
+        message_id = message.get("id")
+        params = message.get("params", {}) or {}
+
+        if method == "initialize":
+            root_uri = params.get("rootUri")
+            self._root = _uri_to_path(root_uri) if root_uri else Path.cwd()
+            return {
+                "jsonrpc": "2.0",
+                "id": message_id,
+                "result": {
+                    "capabilities": {
+                        "textDocumentSync": {"openClose": True, "change": 1, "save": True},
+                        "codeActionProvider": True,
+                        "executeCommandProvider": {"commands": [SHOW_PARTNER_COMMAND]},
+                    },
+                    "serverInfo": {"name": "caldera-lsp", "version": "1.0.0"},
+                },
+            }
+        if method == "textDocument/didOpen":
+            document = params["textDocument"]
+            self._documents[document["uri"]] = document.get("text", "")
+            self._publish(document["uri"])
+        elif method == "textDocument/didChange":
+            uri = params["textDocument"]["uri"]
+            changes = params.get("contentChanges", [])
+            if changes:
+                self._documents[uri] = changes[-1].get("text", "")
+            self._publish(uri)
+        elif method == "textDocument/didClose":
+            uri = params["textDocument"]["uri"]
+            self._documents.pop(uri, None)
+            self._diagnostics.pop(uri, None)
+            self._send({
+                "jsonrpc": "2.0",
+                "method": "textDocument/publishDiagnostics",
+                "params": {"uri": uri, "diagnostics": []},
+            })
+        elif method == "textDocument/codeAction":
+            return {"jsonrpc": "2.0", "id": message_id, "result": self._code_actions(params)}
+        elif method == "workspace/executeCommand":
+            if params.get("command") == SHOW_PARTNER_COMMAND:
+                self._show_partner(params.get("arguments", [{}])[0])
+            return {"jsonrpc": "2.0", "id": message_id, "result": None}
+        elif method == "shutdown":
+            return {"jsonrpc": "2.0", "id": message_id, "result": None}
+        elif method == "exit":
+            self.exited = True
+        elif message_id is not None:
+            return {
+                "jsonrpc": "2.0",
+                "id": message_id,
+                "error": {"code": -32601, "message": f"method not found: {method}"},
+            }
+        return None
+
+    def run(self, stdin: BinaryIO | None = None) -> None:
+        """Serve framed JSON-RPC until the client sends exit or disconnects."""
+        stdin = stdin or sys.stdin.buffer
+        while not self.exited:
+            message = read_message(stdin)
+            if message is None:
+                break
+            response = self.handle(message)
+            if response is not None:
+                self._send(response)
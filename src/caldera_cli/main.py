@@ -0,0 +1,57 @@
+"""Top-level argument parsing and command dispatch for the caldera CLI."""
+
+from __future__ import annotations
+
+import argparse
+import sys
+from pathlib import Path
+
+# Allow running as `python -m caldera_cli` from a checkout without installing.
+sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
+
+from caldera_cli.commands import annotate, badge, clones, compare, daemon, eval_bench, eval_regress, explain, fix, hook, init, lsp, mcp, query, scan, serve, store, suggest, tokens, triage
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Build the top-level parser with all registered command groups."""
+    parser = argparse.ArgumentParser(
+        prog="caldera",
+        description="Project Caldera command-line interface.",
+    )
+    groups = parser.add_subparsers(dest="group", required=True)
+
+    eval_group = groups.add_parser("eval", help="Tool evaluation commands")
+    eval_commands = eval_group.add_subparsers(dest="command", required=True)
+    eval_bench.register(eval_commands)
+    eval_regress.register(eval_commands)
+
+    init.register(groups)
+    scan.register(groups)
+    serve.register(groups)
+    query.register(groups)
+    compare.register(groups)
+    explain.register(groups)
+    annotate.register(groups)
+    clones.register(groups)
+    fix.register(groups)
+    triage.register(groups)
+    suggest.register(groups)
+    daemon.register(groups)
+    store.register(groups)
+    tokens.register(groups)
+    mcp.register(groups)
+    lsp.register(groups)
+    hook.register(groups)
+    badge.register(groups)
+
+    return parser
+
+
+def main(argv: list[str] | None = None) -> int:
+    parser = build_parser()
+    args = parser.parse_args(argv)
+    handler = getattr(args, "handler", None)
+    if handler is None:
+        parser.print_help()
+        return 2
+    return handler(args)
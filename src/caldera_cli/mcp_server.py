@@ -0,0 +1,296 @@
+"""MCP server exposing stored Caldera analysis to AI coding assistants.
+
+Implements the Model Context Protocol (JSON-RPC 2.0 over stdio, one
+message per line) so LLM assistants can query the landing zone while
+refactoring: findings for a file, function complexity by symbol, and the
+other copies of a duplicated span. Read-only — the assistant never
+triggers scans through this surface. Started via ``caldera mcp``.
+
+Tools:
+    get_findings(path, tool?, severity?)      — findings touching a path prefix
+    get_function_metrics(symbol?, path?)      — lizard CCN/NLOC per function
+    get_duplicates_of(path, line_start?, line_end?) — clones overlapping a span
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+from typing import Any, TextIO
+
+import duckdb
+
+from caldera_cli.server import FINDINGS_TABLES
+
+PROTOCOL_VERSION = "2024-11-05"
+SERVER_INFO = {"name": "caldera", "version": "1.0.0"}
+
+MAX_ROWS = 100
+
+TOOL_DEFINITIONS = [
+    {
+        "name": "get_findings",
+        "description": (
+            "Static-analysis findings (security, smells, secrets) for files "
+            "under a repo-relative path prefix, from the latest completed scan."
+        ),
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "path": {"type": "string", "description": "Repo-relative path prefix, e.g. src/app"},
+                "tool": {"type": "string", "description": f"Restrict to one tool (one of: {', '.join(sorted(FINDINGS_TABLES))})"},
+                "severity": {"type": "string", "description": "Minimum display filter, e.g. HIGH"},
+            },
+            "required": ["path"],
+        },
+    },
+    {
+        "name": "get_function_metrics",
+        "description": (
+            "Cyclomatic complexity (CCN), NLOC, and parameter counts per "
+            "function, filtered by symbol name substring and/or path prefix."
+        ),
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "symbol": {"type": "string", "description": "Function name substring"},
+                "path": {"type": "string", "description": "Repo-relative path prefix"},
+            },
+        },
+    },
+    {
+        "name": "get_duplicates_of",
+        "description": (
+            "Duplicate-code clones overlapping a file span: where else the "
+            "same code appears, so all copies can be refactored together."
+        ),
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "path": {"type": "string", "description": "Repo-relative file path"},
+                "line_start": {"type": "integer", "description": "Span start (default: whole file)"},
+                "line_end": {"type": "integer", "description": "Span end (default: whole file)"},
+            },
+            "required": ["path"],
+        },
+    },
+]
+
+
+class MCPServer:
+    """Dispatches MCP requests against a read-only landing-zone database."""
+
+    def __init__(self, db_path: Path, repo_id: str | None = None) -> None:
+        self._db_path = db_path
+        self._repo_id = repo_id
+
+    # -- database helpers --------------------------------------------------
+
+    def _connect(self) -> duckdb.DuckDBPyConnection:
+        return duckdb.connect(str(self._db_path), read_only=True)
+
+    def _run_pks(self, conn: duckdb.DuckDBPyConnection, tool_name: str) -> list[int]:
+        """Tool run_pks for the latest completed collection run."""
+        filters = ["c.status = 'completed'"]
+        args: list = []
+        if self._repo_id:
+            filters.append("c.repo_id = ?")
+            args.append(self._repo_id)
+        row = conn.execute(
+            f"""SELECT c.collection_run_id FROM lz_collection_runs c
+                WHERE {' AND '.join(filters)}
+                ORDER BY c.started_at DESC LIMIT 1""",
+            args,
+        ).fetchone()
+        if row is None:
+            return []
+        rows = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE collection_run_id = ? AND tool_name = ?",
+            [row[0], tool_name],
+        ).fetchall()
+        return [r[0] for r in rows]
+
+    # -- tools -------------------------------------------------------------
+
+    def get_findings(self, path: str, tool: str | None = None, severity: str | None = None) -> dict:
+        tools = [tool] if tool else sorted(FINDINGS_TABLES)
+        if tool and tool not in FINDINGS_TABLES:
+            raise ValueError(f"unknown tool '{tool}' (one of: {', '.join(sorted(FINDINGS_TABLES))})")
+        findings: list[dict] = []
+        conn = self._connect()
+        try:
+            for tool_name in tools:
+                table, columns = FINDINGS_TABLES[tool_name]
+                run_pks = self._run_pks(conn, tool_name)
+                if not run_pks:
+                    continue
+                filters = [
+                    f"run_pk IN ({', '.join('?' for _ in run_pks)})",
+                    "relative_path LIKE ?",
+                ]
+                args: list = [*run_pks, path + "%"]
+                if severity and "severity" in columns:
+                    filters.append("severity = ?")
+                    args.append(severity.upper())
+                rows = conn.execute(
+                    f"""SELECT {', '.join(columns)} FROM {table}
+                        WHERE {' AND '.join(filters)}
+                        ORDER BY relative_path LIMIT {MAX_ROWS}""",
+                    args,
+                ).fetchall()
+                findings.extend({"tool": tool_name, **dict(zip(columns, row))} for row in rows)
+        finally:
+            conn.close()
+        return {"path": path, "count": len(findings), "findings": findings[:MAX_ROWS]}
+
+    def get_function_metrics(self, symbol: str | None = None, path: str | None = None) -> dict:
+        if not symbol and not path:
+            raise ValueError("provide at least one of: symbol, path")
+        conn = self._connect()
+        try:
+            run_pks = self._run_pks(conn, "lizard")
+            if not run_pks:
+                return {"count": 0, "functions": []}
+            filters = [f"fn.run_pk IN ({', '.join('?' for _ in run_pks)})"]
+            args: list = list(run_pks)
+            if symbol:
+                filters.append("fn.function_name LIKE ?")
+                args.append(f"%{symbol}%")
+            if path:
+                filters.append("f.relative_path LIKE ?")
+                args.append(path + "%")
+            rows = conn.execute(
+                f"""SELECT f.relative_path, fn.function_name, fn.long_name, fn.ccn,
+                           fn.nloc, fn.params, fn.line_start, fn.line_end
+                    FROM lz_lizard_function_metrics fn
+                    JOIN lz_lizard_file_metrics f
+                      ON f.run_pk = fn.run_pk AND f.file_id = fn.file_id
+                    WHERE {' AND '.join(filters)}
+                    ORDER BY fn.ccn DESC LIMIT {MAX_ROWS}""",
+                args,
+            ).fetchall()
+        finally:
+            conn.close()
+        columns = ["relative_path", "function_name", "long_name", "ccn", "nloc", "params", "line_start", "line_end"]
+        return {"count": len(rows), "functions": [dict(zip(columns, row)) for row in rows]}
+
+    def get_duplicates_of(self, path: str, line_start: int | None = None, line_end: int | None = None) -> dict:
+        conn = self._connect()
+        try:
+            run_pks = self._run_pks(conn, "pmd-cpd")
+            if not run_pks:
+                return {"path": path, "clones": []}
+            placeholders = ", ".join("?" for _ in run_pks)
+            filters = [f"run_pk IN ({placeholders})", "relative_path = ?"]
+            args: list = [*run_pks, path]
+            if line_start is not None:
+                filters.append("line_end >= ?")
+                args.append(line_start)
+            if line_end is not None:
+                filters.append("line_start <= ?")
+                args.append(line_end)
+            clone_rows = conn.execute(
+                f"""SELECT DISTINCT clone_id FROM lz_pmd_cpd_occurrences
+                    WHERE {' AND '.join(filters)}""",
+                args,
+            ).fetchall()
+            clones = []
+            for (clone_id,) in clone_rows:
+                info = conn.execute(
+                    f"""SELECT lines, tokens, occurrence_count, is_cross_file
+                        FROM lz_pmd_cpd_duplications
+                        WHERE run_pk IN ({placeholders}) AND clone_id = ?""",
+                    [*run_pks, clone_id],
+                ).fetchone()
+                occurrences = conn.execute(
+                    f"""SELECT relative_path, line_start, line_end
+                        FROM lz_pmd_cpd_occurrences
+                        WHERE run_pk IN ({placeholders}) AND clone_id = ?
+                        ORDER BY relative_path, line_start""",
+                    [*run_pks, clone_id],
+                ).fetchall()
+                clones.append({
+                    "clone_id": clone_id,
+                    "lines": info[0] if info else None,
+                    "tokens": info[1] if info else None,
+                    "occurrence_count": info[2] if info else len(occurrences),
+                    "is_cross_file": bool(info[3]) if info else None,
+                    "occurrences": [
+                        {"relative_path": r[0], "line_start": r[1], "line_end": r[2]}
+                        for r in occurrences
+                    ],
+                })
+        finally:
+            conn.close()
+        return {"path": path, "clones": clones}
+
+    # -- protocol ----------------------------------------------------------
+
+    def _call_tool(self, name: str, arguments: dict) -> dict:
+        handlers = {
+            "get_findings": self.get_findings,
+            "get_function_metrics": self.get_function_metrics,
+            "get_duplicates_of": self.get_duplicates_of,
+        }
+        if name not in handlers:
+            raise ValueError(f"unknown tool: {name}")
+        result = handlers[name](**arguments)
+        return {
+            "content": [{"type": "text", "text": json.dumps(result, indent=2, default=str)}],
+            "isError": False,
+        }
+
+    def handle(self, message: dict) -> dict | None:
+        """Handle one JSON-RPC message; None for notifications (no reply)."""
+        method = message.get("method", "")
+        message_id = message.get("id")
+        if message_id is None:
+            return None  # notifications (e.g. notifications/initialized)
+        try:
+            if method == "initialize":
+                result: Any = {
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": {"tools": {}},
+                    "serverInfo": SERVER_INFO,
+                }
+            elif method == "ping":
+                result = {}
+            elif method == "tools/list":
+                result = {"tools": TOOL_DEFINITIONS}
+            elif method == "tools/call":
+                params = message.get("params", {})
+                result = self._call_tool(params.get("name", ""), params.get("arguments", {}))
+            else:
+                return {
+                    "jsonrpc": "2.0",
+                    "id": message_id,
+                    "error": {"code": -32601, "message": f"method not found: {method}"},
+                }
+        except (TypeError, ValueError, duckdb.Error) as exc:
+            return {
+                "jsonrpc": "2.0",
+                "id": message_id,
+                "error": {"code": -32602, "message": str(exc)},
+            }
+        return {"jsonrpc": "2.0", "id": message_id, "result": result}
+
+    def run(self, stdin: TextIO = sys.stdin, stdout: TextIO = sys.stdout) -> None:
+        """Serve newline-delimited JSON-RPC until stdin closes."""
+        for line in stdin:
+            line = line.strip()
+            if not line:
+                continue
+            try:
+                message = json.loads(line)
+            except json.JSONDecodeError:
+                response: dict | None = {
+                    "jsonrpc": "2.0",
+                    "id": None,
+                    "error": {"code": -32700, "message": "parse error"},
+                }
+            else:
+                response = self.handle(message)
+            if response is not None:
+                stdout.write(json.dumps(response) + "\n")
+                stdout.flush()
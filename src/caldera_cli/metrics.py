@@ -0,0 +1,156 @@
+"""Prometheus exposition of scan and eval run metrics.
+
+Renders the Prometheus text format (version 0.0.4) from the landing zone
+and the API server's in-process scan jobs. Served at ``GET /metrics`` by
+``caldera serve``.
+"""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import TYPE_CHECKING, Iterable
+
+import duckdb
+
+if TYPE_CHECKING:
+    from caldera_cli.server import ScanJob
+
+# Landing zone findings tables surfaced as caldera_findings_total{tool=...}.
+# Kept in sync with caldera_cli.server.FINDINGS_TABLES.
+_FINDINGS_TABLES: dict[str, str] = {
+    "semgrep": "lz_semgrep_smells",
+    "bandit": "lz_bandit_findings",
+    "devskim": "lz_devskim_findings",
+    "gitleaks": "lz_gitleaks_secrets",
+    "roslyn-analyzers": "lz_roslyn_violations",
+    "sonarqube": "lz_sonarqube_issues",
+    "trivy": "lz_trivy_vulnerabilities",
+}
+
+
+def _escape_label(value: str) -> str:
+    return value.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n")
+
+
+def _metric(
+    lines: list[str],
+    name: str,
+    metric_type: str,
+    help_text: str,
+    samples: Iterable[tuple[dict[str, str], float]],
+) -> None:
+    lines.append(f"# HELP {name} {help_text}")
+    lines.append(f"# TYPE {name} {metric_type}")
+    for labels, value in samples:
+        if labels:
+            rendered = ",".join(f'{k}="{_escape_label(v)}"' for k, v in sorted(labels.items()))
+            lines.append(f"{name}{{{rendered}}} {value:g}")
+        else:
+            lines.append(f"{name} {value:g}")
+
+
+def _table_exists(conn: duckdb.DuckDBPyConnection, table: str) -> bool:
+    row = conn.execute(
+        "SELECT 1 FROM information_schema.tables WHERE table_name = ?", [table]
+    ).fetchone()
+    return row is not None
+
+
+def collect_db_metrics(conn: duckdb.DuckDBPyConnection) -> list[str]:
+    """Collect landing-zone metrics from an open DuckDB connection."""
+    lines: list[str] = []
+
+    if _table_exists(conn, "lz_collection_runs"):
+        rows = conn.execute(
+            "SELECT status, COUNT(*) FROM lz_collection_runs GROUP BY status"
+        ).fetchall()
+        _metric(
+            lines,
+            "caldera_collection_runs_total",
+            "gauge",
+            "Collection runs in the landing zone by status.",
+            [({"status": status}, float(count)) for status, count in rows],
+        )
+        durations = conn.execute(
+            """SELECT COUNT(*), COALESCE(SUM(epoch(completed_at) - epoch(started_at)), 0)
+               FROM lz_collection_runs WHERE completed_at IS NOT NULL"""
+        ).fetchone()
+        _metric(
+            lines,
+            "caldera_collection_run_duration_seconds",
+            "summary",
+            "Wall-clock duration of completed collection runs.",
+            [],
+        )
+        lines.append(f"caldera_collection_run_duration_seconds_count {float(durations[0]):g}")
+        lines.append(f"caldera_collection_run_duration_seconds_sum {float(durations[1]):g}")
+
+    if _table_exists(conn, "lz_tool_runs"):
+        rows = conn.execute(
+            "SELECT tool_name, COUNT(*) FROM lz_tool_runs GROUP BY tool_name"
+        ).fetchall()
+        _metric(
+            lines,
+            "caldera_tool_runs_total",
+            "gauge",
+            "Tool runs in the landing zone by tool.",
+            [({"tool": tool}, float(count)) for tool, count in rows],
+        )
+
+    findings_samples: list[tuple[dict[str, str], float]] = []
+    for tool, table in sorted(_FINDINGS_TABLES.items()):
+        if not _table_exists(conn, table):
+            continue
+        count = conn.execute(f"SELECT COUNT(*) FROM {table}").fetchone()[0]
+        findings_samples.append(({"tool": tool}, float(count)))
+    if findings_samples:
+        _metric(
+            lines,
+            "caldera_findings_total",
+            "gauge",
+            "Persisted findings in the landing zone by tool.",
+            findings_samples,
+        )
+
+    return lines
+
+
+def collect_scan_metrics(jobs: list["ScanJob"]) -> list[str]:
+    """Collect metrics for scans triggered through the API server."""
+    lines: list[str] = []
+    by_status: dict[str, int] = {}
+    duration_sum = 0.0
+    duration_count = 0
+    for job in jobs:
+        by_status[job.status] = by_status.get(job.status, 0) + 1
+        if job.finished_at and job.started_at:
+            started = datetime.fromisoformat(job.started_at)
+            finished = datetime.fromisoformat(job.finished_at)
+            duration_sum += (finished - started).total_seconds()
+            duration_count += 1
+    _metric(
+        lines,
+        "caldera_api_scans_total",
+        "gauge",
+        "Scans triggered through the API server by status.",
+        [({"status": status}, float(count)) for status, count in sorted(by_status.items())],
+    )
+    _metric(
+        lines,
+        "caldera_api_scan_duration_seconds",
+        "summary",
+        "Wall-clock duration of finished API-triggered scans.",
+        [],
+    )
+    lines.append(f"caldera_api_scan_duration_seconds_count {float(duration_count):g}")
+    lines.append(f"caldera_api_scan_duration_seconds_sum {duration_sum:g}")
+    return lines
+
+
+def render_metrics(conn: duckdb.DuckDBPyConnection | None, jobs: list["ScanJob"]) -> str:
+    """Render the full /metrics payload."""
+    lines: list[str] = []
+    if conn is not None:
+        lines.extend(collect_db_metrics(conn))
+    lines.extend(collect_scan_metrics(jobs))
+    return "\n".join(lines) + "\n"
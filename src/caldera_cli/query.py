@@ -0,0 +1,369 @@
+"""Small query language over stored findings.
+
+``caldera query "severity >= high AND tool = semgrep AND path GLOB
+'src/payments/**' ORDER BY line"`` answers ad-hoc questions about a run's
+findings without exporting to SQLite and writing SQL by hand. Expressions
+are parsed here into parameterized SQL over a UNION of the landing-zone
+findings tables (the same set the server exposes), so values never reach
+the query string and only whitelisted fields are queryable.
+
+Fields: ``tool``, ``path``, ``rule``, ``severity``, ``line``, ``message``.
+Operators: ``=`` ``!=`` ``<`` ``<=`` ``>`` ``>=`` ``GLOB`` ``LIKE``,
+combined with ``AND``/``OR``/``NOT`` and parentheses, plus optional
+``ORDER BY field [ASC|DESC], ...`` and ``LIMIT n``. Severity comparisons
+are ordered (info < low < medium < high < critical), not lexicographic.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+
+import duckdb
+
+# Per-tool sources for the unified findings view. Each entry maps the
+# tool's table onto (rule, severity, line, message) expressions; every
+# table carries run_pk and relative_path, so tool/path/run are uniform.
+_UNIFIED_SOURCES: dict[str, tuple[str, str, str, str, str]] = {
+    "semgrep": ("lz_semgrep_smells", "rule_id", "severity", "line_start", "message"),
+    "bandit": ("lz_bandit_findings", "rule_id", "severity", "line_start", "message"),
+    "devskim": ("lz_devskim_findings", "rule_id", "severity", "line_start", "message"),
+    "gitleaks": ("lz_gitleaks_secrets", "rule_id", "severity", "line_number", "secret_type"),
+    "roslyn-analyzers": ("lz_roslyn_violations", "rule_id", "severity", "line_start", "message"),
+    "sonarqube": ("lz_sonarqube_issues", "rule_id", "severity", "line_start", "message"),
+    "trivy": ("lz_trivy_vulnerabilities", "vulnerability_id", "severity", "NULL", "pkg_name"),
+}
+
+RESULT_COLUMNS = ("tool", "path", "rule", "severity", "line", "message")
+
+_FIELD_ALIASES = {
+    "tool": "tool",
+    "path": "path",
+    "file": "path",
+    "rule": "rule",
+    "rule_id": "rule",
+    "severity": "severity",
+    "line": "line",
+    "message": "message",
+}
+
+_SEVERITY_RANK = {"INFO": 0, "LOW": 1, "MEDIUM": 2, "HIGH": 3, "CRITICAL": 4}
+_SEVERITY_RANK_SQL = (
+    "CASE severity WHEN 'CRITICAL' THEN 4 WHEN 'HIGH' THEN 3 "
+    "WHEN 'MEDIUM' THEN 2 WHEN 'LOW' THEN 1 ELSE 0 END"
+)
+
+_COMPARISON_OPS = ("=", "!=", "<", "<=", ">", ">=")
+
+
+class QueryError(ValueError):
+    """Invalid query expression (syntax or unknown field/operator/value)."""
+
+
+# --- Parsing -----------------------------------------------------------------
+
+_TOKEN_RE = re.compile(
+    r"""\s*(?:
+        (?P<string>'(?:[^'\\]|\\.)*'|"(?:[^"\\]|\\.)*")
+      | (?P<number>\d+(?:\.\d+)?)
+      | (?P<ident>[A-Za-z_][A-Za-z0-9_.-]*)
+      | (?P<op>>=|<=|!=|=|<|>)
+      | (?P<paren>[(),])
+    )""",
+    re.VERBOSE,
+)
+
+_KEYWORDS = {"AND", "OR", "NOT", "GLOB", "LIKE", "ORDER", "BY", "ASC", "DESC", "LIMIT"}
+
+
+@dataclass(frozen=True)
+class _Token:
+    kind: str  # string | number | ident | keyword | op | paren | end
+    value: str
+
+
+def _tokenize(text: str) -> list[_Token]:
+    tokens: list[_Token] = []
+    pos = 0
+    while pos < len(text):
+        match = _TOKEN_RE.match(text, pos)
+        if not match:
+            remainder = text[pos:].strip()
+            if not remainder:
+                break
+            raise QueryError(f"unexpected character at: {remainder[:20]!r}")
+        pos = match.end()
+        if match.lastgroup == "string":
+            raw = match.group("string")
+            tokens.append(_Token("string", re.sub(r"\\(.)", r"\1", raw[1:-1])))
+        elif match.lastgroup == "number":
+            tokens.append(_Token("number", match.group("number")))
+        elif match.lastgroup == "ident":
+            word = match.group("ident")
+            if word.upper() in _KEYWORDS:
+                tokens.append(_Token("keyword", word.upper()))
+            else:
+                tokens.append(_Token("ident", word))
+        elif match.lastgroup == "op":
+            tokens.append(_Token("op", match.group("op")))
+        else:
+            tokens.append(_Token("paren", match.group("paren")))
+    tokens.append(_Token("end", ""))
+    return tokens
+
+
+@dataclass(frozen=True)
+class Comparison:
+    field: str
+    op: str  # one of _COMPARISON_OPS, or GLOB / LIKE
+    value: str
+
+
+@dataclass(frozen=True)
+class Not:
+    operand: "Node"
+
+
+@dataclass(frozen=True)
+class BoolOp:
+    op: str  # AND | OR
+    left: "Node"
+    right: "Node"
+
+
+Node = Comparison | Not | BoolOp
+
+
+@dataclass(frozen=True)
+class Query:
+    where: Node
+    order_by: tuple[tuple[str, bool], ...]  # (field, descending)
+    limit: int | None
+
+
+class _Parser:
+    def __init__(self, tokens: list[_Token]) -> None:
+        self._tokens = tokens
+        self._pos = 0
+
+    def _peek(self) -> _Token:
+        return self._tokens[self._pos]
+
+    def _next(self) -> _Token:
+        token = self._tokens[self._pos]
+        self._pos += 1
+        return token
+
+    def _expect_keyword(self, word: str) -> None:
+        token = self._next()
+        if token.kind != "keyword" or token.value != word:
+            raise QueryError(f"expected {word}, got {token.value or 'end of query'!r}")
+
+    def parse(self) -> Query:
+        where = self._or_expr()
+        order_by: list[tuple[str, bool]] = []
+        limit: int | None = None
+        if self._peek() == _Token("keyword", "ORDER"):
+            self._next()
+            self._expect_keyword("BY")
+            order_by.append(self._order_item())
+            while self._peek() == _Token("paren", ","):
+                self._next()
+                order_by.append(self._order_item())
+        if self._peek() == _Token("keyword", "LIMIT"):
+            self._next()
+            token = self._next()
+            if token.kind != "number" or "." in token.value:
+                raise QueryError(f"LIMIT expects an integer, got {token.value!r}")
+            limit = int(token.value)
+        tail = self._peek()
+        if tail.kind != "end":
+            raise QueryError(f"unexpected trailing input at {tail.value!r}")
+        return Query(where, tuple(order_by), limit)
+
+    def _order_item(self) -> tuple[str, bool]:
+        field = self._field(self._next())
+        descending = False
+        if self._peek() in (_Token("keyword", "ASC"), _Token("keyword", "DESC")):
+            descending = self._next().value == "DESC"
+        return field, descending
+
+    def _or_expr(self) -> Node:
+        node = self._and_expr()
+        while self._peek() == _Token("keyword", "OR"):
+            self._next()
+            node = BoolOp("OR", node, self._and_expr())
+        return node
+
+    def _and_expr(self) -> Node:
+        node = self._not_expr()
+        while self._peek() == _Token("keyword", "AND"):
+            self._next()
+            node = BoolOp("AND", node, self._not_expr())
+        return node
+
+    def _not_expr(self) -> Node:
+        if self._peek() == _Token("keyword", "NOT"):
+            self._next()
+            return Not(self._not_expr())
+        return self._primary()
+
+    def _primary(self) -> Node:
+        if self._peek() == _Token("paren", "("):
+            self._next()
+            node = self._or_expr()
+            if self._next() != _Token("paren", ")"):
+                raise QueryError("missing closing parenthesis")
+            return node
+        return self._comparison()
+
+    def _comparison(self) -> Comparison:
+        field = self._field(self._next())
+        op_token = self._next()
+        if op_token.kind == "op":
+            op = op_token.value
+        elif op_token.kind == "keyword" and op_token.value in ("GLOB", "LIKE"):
+            op = op_token.value
+        else:
+            raise QueryError(f"expected an operator after {field!r}, got {op_token.value!r}")
+        value_token = self._next()
+        if value_token.kind not in ("string", "number", "ident"):
+            raise QueryError(f"expected a value after {field} {op}")
+        return Comparison(field, op, value_token.value)
+
+    def _field(self, token: _Token) -> str:
+        if token.kind != "ident" or token.value.lower() not in _FIELD_ALIASES:
+            known = ", ".join(sorted(set(_FIELD_ALIASES)))
+            raise QueryError(f"unknown field {token.value!r} (one of: {known})")
+        return _FIELD_ALIASES[token.value.lower()]
+
+
+def parse_query(text: str) -> Query:
+    """Parse a query expression; raises QueryError on invalid input."""
+    if not text.strip():
+        raise QueryError("empty query")
+    return _Parser(_tokenize(text)).parse()
+
+
+# --- SQL generation ----------------------------------------------------------
+
+
+def _comparison_sql(node: Comparison) -> tuple[str, list]:
+    field, op, value = node.field, node.op, node.value
+    if field == "severity" and op in ("<", "<=", ">", ">="):
+        rank = _SEVERITY_RANK.get(value.upper())
+        if rank is None:
+            known = ", ".join(s.lower() for s in _SEVERITY_RANK)
+            raise QueryError(f"unknown severity {value!r} (one of: {known})")
+        return f"{_SEVERITY_RANK_SQL} {op} ?", [rank]
+    if field == "line":
+        if op in ("GLOB", "LIKE"):
+            raise QueryError(f"{op} is not valid for the numeric field 'line'")
+        try:
+            number = float(value)
+        except ValueError:
+            raise QueryError(f"line expects a number, got {value!r}") from None
+        return f"line {op} ?", [int(number) if number.is_integer() else number]
+    if field == "severity":
+        if op not in ("=", "!="):
+            raise QueryError(f"{op} is not valid for severity")
+        return f"severity {op} ?", [value.upper()]
+    if op in ("<", "<=", ">", ">="):
+        raise QueryError(f"{op} is not valid for the text field {field!r}")
+    return f"{field} {op} ?", [value]
+
+
+def _where_sql(node: Node) -> tuple[str, list]:
+    if isinstance(node, Comparison):
+        return _comparison_sql(node)
+    if isinstance(node, Not):
+        sql, params = _where_sql(node.operand)
+        return f"NOT ({sql})", params
+    left_sql, left_params = _where_sql(node.left)
+    right_sql, right_params = _where_sql(node.right)
+    return f"({left_sql} {node.op} {right_sql})", [*left_params, *right_params]
+
+
+def _unified_view_sql() -> str:
+    selects = []
+    for tool, (table, rule, severity, line, message) in _UNIFIED_SOURCES.items():
+        selects.append(
+            f"SELECT '{tool}' AS tool, f.relative_path AS path, f.{rule} AS rule, "
+            f"UPPER(f.{severity}) AS severity, "
+            f"{'NULL' if line == 'NULL' else 'f.' + line} AS line, "
+            f"f.{message} AS message, t.collection_run_id AS collection_run_id "
+            f"FROM {table} f JOIN lz_tool_runs t ON t.run_pk = f.run_pk"
+        )
+    return " UNION ALL ".join(selects)
+
+
+def build_sql(query: Query, collection_run_id: str) -> tuple[str, list]:
+    """The full parameterized SELECT for a parsed query against one run."""
+    where_sql, params = _where_sql(query.where)
+    order_terms = []
+    for field, descending in query.order_by:
+        expr = _SEVERITY_RANK_SQL if field == "severity" else field
+        order_terms.append(f"{expr} {'DESC' if descending else 'ASC'}")
+    order_terms.append("path ASC")
+    sql = (
+        f"SELECT {', '.join(RESULT_COLUMNS)} FROM ({_unified_view_sql()}) findings "
+        f"WHERE collection_run_id = ? AND ({where_sql}) "
+        f"ORDER BY {', '.join(order_terms)}"
+    )
+    params = [collection_run_id, *params]
+    if query.limit is not None:
+        sql += " LIMIT ?"
+        params.append(query.limit)
+    return sql, params
+
+
+def run_query(
+    conn: duckdb.DuckDBPyConnection, query: Query, collection_run_id: str
+) -> list[dict]:
+    """Execute a parsed query; returns one dict per finding."""
+    sql, params = build_sql(query, collection_run_id)
+    rows = conn.execute(sql, params).fetchall()
+    return [dict(zip(RESULT_COLUMNS, row)) for row in rows]
+
+
+def format_table(rows: list[dict]) -> str:
+    """Plain aligned-column rendering for terminal output.
+
+    Columns come from the rows themselves, so projected output
+    (``--select``) renders just the selected fields.
+    """
+    if not rows:
+        return "No findings matched."
+    columns = list(rows[0])
+    widths = {
+        column: max(len(column), *(len(str(row.get(column) or "")) for row in rows))
+        for column in columns
+    }
+    lines = [
+        "  ".join(column.ljust(widths[column]) for column in columns),
+        "  ".join("-" * widths[column] for column in columns),
+    ]
+    for row in rows:
+        lines.append(
+            "  ".join(str(row.get(column) or "").ljust(widths[column]) for column in columns)
+        )
+    return "\n".join(lines)
+
+
+def format_grep(rows: list[dict]) -> str:
+    """``path:line:col: [tool/rule] message`` lines for editor integration.
+
+    The shape vim's quickfix, Emacs compilation mode, and fzf parse without
+    custom configuration. The landing zone stores no column, so col is
+    always 1; findings without a line (e.g. trivy) also report line 1.
+    Multi-line messages are collapsed to their first line.
+    """
+    lines = []
+    for row in rows:
+        message_lines = str(row.get("message") or "").splitlines()
+        message = message_lines[0] if message_lines else ""
+        lines.append(
+            f"{row.get('path')}:{row.get('line') or 1}:1: "
+            f"[{row.get('tool')}/{row.get('rule') or '-'}] {message}"
+        )
+    return "\n".join(lines)
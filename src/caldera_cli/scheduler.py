@@ -0,0 +1,229 @@
+"""Scheduled scans for daemon mode.
+
+Stdlib-only cron scheduling behind ``caldera daemon``: a YAML config maps
+repos to five-field cron expressions, the scheduler fires due entries
+through the server's ScanManager (so results persist to the landing zone
+and scans show up via the REST API), and ``GET /schedule`` reports each
+entry's next run.
+
+Config format::
+
+    schedule:
+      - repo_id: my-app
+        repo_path: /repos/my-app
+        cron: "0 2 * * *"          # nightly at 02:00
+        branch: main               # optional, default main
+        replace: true              # optional, default true (re-scan same commit)
+
+Supported cron syntax per field: ``*``, numbers, ranges (``1-5``),
+lists (``1,15``), and steps (``*/15``, ``1-30/5``). Weekday 0 and 7 are
+both Sunday, matching standard cron.
+"""
+
+from __future__ import annotations
+
+import threading
+from dataclasses import dataclass
+from datetime import datetime, timedelta
+from pathlib import Path
+
+import yaml
+
+# (min, max) bounds per cron field, in expression order. Weekday allows
+# 7 on input (Sunday, like cron); values are folded to 0-6 after parsing.
+_FIELD_BOUNDS = (
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day", 1, 31),
+    ("month", 1, 12),
+    ("weekday", 0, 7),
+)
+
+# Searching beyond a year means the expression can never match
+# (e.g. "0 0 31 2 *").
+_NEXT_RUN_HORIZON_DAYS = 366
+
+
+def _parse_field(expression: str, name: str, low: int, high: int) -> frozenset[int]:
+    """Expand one cron field into the set of matching values."""
+    values: set[int] = set()
+    for part in expression.split(","):
+        step = 1
+        if "/" in part:
+            part, step_text = part.split("/", 1)
+            step = int(step_text)
+            if step < 1:
+                raise ValueError(f"Invalid step in cron {name} field: {step_text}")
+        if part == "*":
+            start, end = low, high
+        elif "-" in part:
+            start_text, end_text = part.split("-", 1)
+            start, end = int(start_text), int(end_text)
+        else:
+            start = end = int(part)
+        if start < low or end > high or start > end:
+            raise ValueError(f"Cron {name} field out of range {low}-{high}: {part}")
+        values.update(range(start, end + 1, step))
+    if name == "weekday":
+        values = {value % 7 for value in values}
+    return frozenset(values)
+
+
+@dataclass(frozen=True)
+class CronSchedule:
+    """A parsed five-field cron expression."""
+
+    minutes: frozenset[int]
+    hours: frozenset[int]
+    days: frozenset[int]
+    months: frozenset[int]
+    weekdays: frozenset[int]
+
+    @classmethod
+    def parse(cls, expression: str) -> CronSchedule:
+        fields = expression.split()
+        if len(fields) != 5:
+            raise ValueError(f"Cron expression must have 5 fields: {expression!r}")
+        parsed = [
+            _parse_field(field, name, low, high)
+            for field, (name, low, high) in zip(fields, _FIELD_BOUNDS)
+        ]
+        return cls(*parsed)
+
+    def matches(self, moment: datetime) -> bool:
+        # Python's Monday=0 weekday vs cron's Sunday=0.
+        cron_weekday = (moment.weekday() + 1) % 7
+        return (
+            moment.minute in self.minutes
+            and moment.hour in self.hours
+            and moment.day in self.days
+            and moment.month in self.months
+            and cron_weekday in self.weekdays
+        )
+
+    def next_after(self, moment: datetime) -> datetime | None:
+        """First matching minute strictly after ``moment``."""
+        candidate = moment.replace(second=0, microsecond=0) + timedelta(minutes=1)
+        horizon = moment + timedelta(days=_NEXT_RUN_HORIZON_DAYS)
+        while candidate <= horizon:
+            if self.matches(candidate):
+                return candidate
+            candidate += timedelta(minutes=1)
+        return None
+
+
+@dataclass(frozen=True)
+class ScheduledScan:
+    """One schedule config entry."""
+
+    repo_id: str
+    repo_path: str
+    cron: str
+    branch: str = "main"
+    replace: bool = True
+
+    def __post_init__(self) -> None:
+        if not self.repo_id:
+            raise ValueError("Schedule entry missing repo_id")
+        if not self.repo_path:
+            raise ValueError(f"Schedule entry {self.repo_id!r} missing repo_path")
+        CronSchedule.parse(self.cron)  # fail fast on bad expressions
+
+    @property
+    def schedule(self) -> CronSchedule:
+        return CronSchedule.parse(self.cron)
+
+
+def load_schedule(config_path: Path) -> list[ScheduledScan]:
+    """Parse the daemon schedule config."""
+    config = yaml.safe_load(config_path.read_text())
+    if not isinstance(config, dict) or not isinstance(config.get("schedule"), list):
+        raise ValueError(f"{config_path}: config must have a 'schedule' list")
+    entries = [
+        ScheduledScan(
+            repo_id=str(item.get("repo_id", "")),
+            repo_path=str(item.get("repo_path", "")),
+            cron=str(item.get("cron", "")),
+            branch=str(item.get("branch", "main")),
+            replace=bool(item.get("replace", True)),
+        )
+        for item in config["schedule"]
+    ]
+    seen: set[str] = set()
+    for entry in entries:
+        if entry.repo_id in seen:
+            raise ValueError(f"{config_path}: duplicate repo_id {entry.repo_id!r}")
+        seen.add(entry.repo_id)
+    return entries
+
+
+class Scheduler:
+    """Fires due schedule entries; runs in a daemon thread.
+
+    ``starter`` receives the due ScheduledScan and launches the scan
+    (in the daemon this is the server's ScanManager); injectable so the
+    loop is testable without real scans. ``clock`` likewise.
+    """
+
+    def __init__(self, entries: list[ScheduledScan], starter, clock=datetime.now) -> None:
+        self._entries = entries
+        self._starter = starter
+        self._clock = clock
+        self._last_fired: dict[str, datetime] = {}
+        self._lock = threading.Lock()
+        self._stop = threading.Event()
+
+    def tick(self, now: datetime | None = None) -> list[ScheduledScan]:
+        """Fire every entry due at the current minute; returns what fired."""
+        moment = (now or self._clock()).replace(second=0, microsecond=0)
+        fired: list[ScheduledScan] = []
+        with self._lock:
+            for entry in self._entries:
+                if self._last_fired.get(entry.repo_id) == moment:
+                    continue  # already fired this minute
+                if entry.schedule.matches(moment):
+                    self._last_fired[entry.repo_id] = moment
+                    fired.append(entry)
+        for entry in fired:
+            self._starter(entry)
+        return fired
+
+    def status(self) -> list[dict]:
+        """Schedule status for the REST API."""
+        now = self._clock()
+        with self._lock:
+            return [
+                {
+                    "repo_id": entry.repo_id,
+                    "repo_path": entry.repo_path,
+                    "cron": entry.cron,
+                    "branch": entry.branch,
+                    "last_fired": (
+                        self._last_fired[entry.repo_id].isoformat()
+                        if entry.repo_id in self._last_fired
+                        else None
+                    ),
+                    "next_run": (
+                        next_run.isoformat()
+                        if (next_run := entry.schedule.next_after(now))
+                        else None
+                    ),
+                }
+                for entry in self._entries
+            ]
+
+    def run(self) -> None:
+        """Tick once per minute until stopped."""
+        while not self._stop.is_set():
+            self.tick()
+            now = self._clock()
+            until_next_minute = 60 - now.second + 1
+            self._stop.wait(until_next_minute)
+
+    def start(self) -> threading.Thread:
+        thread = threading.Thread(target=self.run, daemon=True, name="caldera-scheduler")
+        thread.start()
+        return thread
+
+    def stop(self) -> None:
+        self._stop.set()
@@ -0,0 +1,485 @@
+"""HTTP API server for Project Caldera.
+
+Stdlib-only (http.server) JSON API that lets the internal dashboard trigger
+scans, stream their progress, and query stored runs and findings from the
+landing zone. Started via ``caldera serve``.
+
+Endpoints:
+    GET  /health                          — liveness probe
+    POST /scans                           — trigger a scan (orchestrator subprocess)
+    GET  /scans/<scan_id>                 — scan status
+    GET  /scans/<scan_id>/events          — NDJSON progress stream
+    GET  /runs                            — list collection runs (pagination, filters)
+    GET  /runs/<run_pk>/findings          — query findings (tool, severity, path filters)
+    GET  /schedule                        — scheduled scans (daemon mode only)
+    GET  /badge/<metric>                  — SVG badge (health, duplication, ccn)
+
+When the server is started with a token store (``caldera serve
+--auth-tokens``), every endpoint except ``GET /health`` requires an
+``Authorization: Bearer <token>`` header: GET endpoints need the ``read``
+scope, ``POST /scans`` needs ``write`` plus authorization for the target
+repo_id. Tokens are managed with ``caldera tokens``.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import sys
+import threading
+import time
+import uuid
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from pathlib import Path
+from urllib.parse import parse_qs, urlparse
+
+import duckdb
+
+from caldera_cli.auth import SCOPE_READ, SCOPE_WRITE, TokenStore, authorized
+
+# Findings tables queryable via /runs/<run_pk>/findings?tool=<name>.
+# Each entry: (table, columns to expose). All tables carry run_pk and
+# relative_path, so pagination and path filtering are uniform.
+FINDINGS_TABLES: dict[str, tuple[str, list[str]]] = {
+    "semgrep": ("lz_semgrep_smells", ["relative_path", "rule_id", "dd_category", "severity", "line_start", "line_end", "message"]),
+    "bandit": ("lz_bandit_findings", ["relative_path", "rule_id", "dd_category", "severity", "confidence", "cwe_id", "line_start", "message"]),
+    "devskim": ("lz_devskim_findings", ["relative_path", "rule_id", "dd_category", "severity", "line_start", "line_end", "message"]),
+    "gitleaks": ("lz_gitleaks_secrets", ["relative_path", "rule_id", "secret_type", "severity", "line_number", "in_current_head"]),
+    "roslyn-analyzers": ("lz_roslyn_violations", ["relative_path", "rule_id", "dd_category", "severity", "line_start", "line_end", "message"]),
+    "sonarqube": ("lz_sonarqube_issues", ["relative_path", "rule_id", "severity", "line_start", "message"]),
+    "trivy": ("lz_trivy_vulnerabilities", ["relative_path", "vulnerability_id", "severity", "pkg_name", "installed_version", "fixed_version"]),
+}
+
+MAX_PAGE_SIZE = 500
+DEFAULT_PAGE_SIZE = 50
+
+
+@dataclass
+class ScanJob:
+    """One orchestrator scan triggered through the API."""
+    scan_id: str
+    repo_path: str
+    repo_id: str
+    branch: str
+    commit: str
+    status: str = "running"  # running | completed | failed
+    started_at: str = ""
+    finished_at: str | None = None
+    returncode: int | None = None
+    events: list[dict] = field(default_factory=list)
+    _lock: threading.Lock = field(default_factory=threading.Lock, repr=False)
+
+    def add_event(self, kind: str, message: str) -> None:
+        with self._lock:
+            self.events.append({
+                "ts": datetime.now(timezone.utc).isoformat(),
+                "kind": kind,
+                "message": message,
+            })
+
+    def snapshot(self) -> dict:
+        with self._lock:
+            return {
+                "scan_id": self.scan_id,
+                "repo_path": self.repo_path,
+                "repo_id": self.repo_id,
+                "branch": self.branch,
+                "commit": self.commit,
+                "status": self.status,
+                "started_at": self.started_at,
+                "finished_at": self.finished_at,
+                "returncode": self.returncode,
+                "event_count": len(self.events),
+            }
+
+
+class ScanManager:
+    """Launches orchestrator scans in worker threads and tracks their state."""
+
+    def __init__(self, db_path: Path, repo_root: Path | None = None) -> None:
+        self._db_path = db_path
+        self._repo_root = repo_root or Path(__file__).resolve().parents[2]
+        self._jobs: dict[str, ScanJob] = {}
+        self._lock = threading.Lock()
+
+    def jobs(self) -> list[ScanJob]:
+        with self._lock:
+            return list(self._jobs.values())
+
+    def get(self, scan_id: str) -> ScanJob | None:
+        with self._lock:
+            return self._jobs.get(scan_id)
+
+    def start(self, repo_path: str, repo_id: str, branch: str, commit: str, replace: bool = False) -> ScanJob:
+        job = ScanJob(
+            scan_id=str(uuid.uuid4()),
+            repo_path=repo_path,
+            repo_id=repo_id,
+            branch=branch,
+            commit=commit,
+            started_at=datetime.now(timezone.utc).isoformat(),
+        )
+        with self._lock:
+            self._jobs[job.scan_id] = job
+        thread = threading.Thread(target=self._run, args=(job, replace), daemon=True)
+        thread.start()
+        return job
+
+    def _run(self, job: ScanJob, replace: bool) -> None:
+        orchestrator = self._repo_root / "src" / "sot-engine" / "orchestrator.py"
+        cmd = [
+            sys.executable, str(orchestrator),
+            "--repo-path", job.repo_path,
+            "--repo-id", job.repo_id,
+            "--branch", job.branch,
+            "--commit", job.commit,
+            "--db-path", str(self._db_path),
+            "--run-tools",
+            "--no-progress",
+        ]
+        if replace:
+            cmd.append("--replace")
+        job.add_event("start", f"scan started for {job.repo_id}")
+        try:
+            process = subprocess.Popen(
+                cmd,
+                stdout=subprocess.PIPE,
+                stderr=subprocess.STDOUT,
+                text=True,
+                cwd=str(self._repo_root),
+            )
+            assert process.stdout is not None
+            for line in process.stdout:
+                line = line.rstrip()
+                if line:
+                    job.add_event("progress", line)
+            job.returncode = process.wait()
+        except OSError as exc:
+            job.returncode = -1
+            job.add_event("error", str(exc))
+        job.status = "completed" if job.returncode == 0 else "failed"
+        job.finished_at = datetime.now(timezone.utc).isoformat()
+        job.add_event("done", f"scan {job.status} (exit {job.returncode})")
+
+
+class CalderaAPIHandler(BaseHTTPRequestHandler):
+    """Request handler; scan_manager and db_path are set on the server."""
+
+    server_version = "CalderaAPI/1.0"
+
+    # -- helpers ----------------------------------------------------------
+
+    def _send_json(self, payload: dict | list, status: int = 200) -> None:
+        body = json.dumps(payload).encode()
+        self.send_response(status)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def _error(self, status: int, message: str) -> None:
+        self._send_json({"error": message}, status=status)
+
+    def _query(self) -> dict[str, str]:
+        parsed = parse_qs(urlparse(self.path).query)
+        return {key: values[0] for key, values in parsed.items()}
+
+    def _pagination(self, params: dict[str, str]) -> tuple[int, int]:
+        limit = min(int(params.get("limit", DEFAULT_PAGE_SIZE)), MAX_PAGE_SIZE)
+        offset = max(int(params.get("offset", 0)), 0)
+        return limit, offset
+
+    def _connect(self) -> duckdb.DuckDBPyConnection:
+        return duckdb.connect(str(self.server.db_path), read_only=True)  # type: ignore[attr-defined]
+
+    def log_message(self, format: str, *args) -> None:  # noqa: A002
+        pass  # quiet by default; progress is in the scan event streams
+
+    def _authorize(self, scope: str, repo_id: str | None = None) -> bool:
+        """Enforce bearer-token auth; sends the error response on failure.
+
+        A server without a token store runs open (local development); with
+        one, a missing/invalid token is 401 and an insufficient scope or
+        unauthorized repo is 403.
+        """
+        store: TokenStore | None = getattr(self.server, "token_store", None)
+        if store is None:
+            return True
+        header = self.headers.get("Authorization", "")
+        if not header.startswith("Bearer "):
+            self._error(401, "missing bearer token")
+            return False
+        record = store.authenticate(header[len("Bearer "):].strip())
+        if record is None:
+            self._error(401, "invalid or revoked token")
+            return False
+        if not authorized(record, scope, repo_id):
+            detail = f" for repo {repo_id!r}" if repo_id else ""
+            self._error(403, f"token {record.name!r} lacks {scope} access{detail}")
+            return False
+        return True
+
+    # -- routing ----------------------------------------------------------
+
+    def do_GET(self) -> None:  # noqa: N802
+        path = urlparse(self.path).path.rstrip("/")
+        parts = [p for p in path.split("/") if p]
+        try:
+            if path in ("", "/health"):
+                self._send_json({"status": "ok"})
+                return
+            if not self._authorize(SCOPE_READ):
+                return
+            if path == "/metrics":
+                self._metrics()
+            elif parts == ["runs"]:
+                self._list_runs()
+            elif len(parts) == 3 and parts[0] == "runs" and parts[2] == "findings":
+                self._list_findings(parts[1])
+            elif len(parts) == 2 and parts[0] == "scans":
+                self._scan_status(parts[1])
+            elif len(parts) == 3 and parts[0] == "scans" and parts[2] == "events":
+                self._scan_events(parts[1])
+            elif parts == ["scans"]:
+                manager: ScanManager = self.server.scan_manager  # type: ignore[attr-defined]
+                self._send_json([job.snapshot() for job in manager.jobs()])
+            elif parts == ["schedule"]:
+                self._schedule()
+            elif len(parts) == 2 and parts[0] == "badge":
+                self._badge(parts[1])
+            else:
+                self._error(404, f"no such resource: {path}")
+        except (ValueError, duckdb.Error) as exc:
+            self._error(400, str(exc))
+
+    def do_POST(self) -> None:  # noqa: N802
+        path = urlparse(self.path).path.rstrip("/")
+        if path != "/scans":
+            self._error(404, f"no such resource: {path}")
+            return
+        if not self._authorize(SCOPE_WRITE):
+            return
+        length = int(self.headers.get("Content-Length", 0))
+        try:
+            body = json.loads(self.rfile.read(length) or b"{}")
+        except json.JSONDecodeError:
+            self._error(400, "request body must be JSON")
+            return
+        repo_path = body.get("repo_path")
+        repo_id = body.get("repo_id")
+        if not repo_path or not repo_id:
+            self._error(422, "repo_path and repo_id are required")
+            return
+        if not self._authorize(SCOPE_WRITE, repo_id=repo_id):  # per-repo check
+            return
+        manager: ScanManager = self.server.scan_manager  # type: ignore[attr-defined]
+        job = manager.start(
+            repo_path=repo_path,
+            repo_id=repo_id,
+            branch=body.get("branch", "main"),
+            commit=body.get("commit", "0" * 40),
+            replace=bool(body.get("replace", False)),
+        )
+        self._send_json(job.snapshot(), status=202)
+
+    # -- handlers ---------------------------------------------------------
+
+    def _list_runs(self) -> None:
+        params = self._query()
+        limit, offset = self._pagination(params)
+        filters = ["1=1"]
+        args: list = []
+        if params.get("repo_id"):
+            filters.append("repo_id = ?")
+            args.append(params["repo_id"])
+        if params.get("status"):
+            filters.append("status = ?")
+            args.append(params["status"])
+        conn = self._connect()
+        try:
+            rows = conn.execute(
+                f"""SELECT collection_run_id, repo_id, run_id, branch, commit,
+                           started_at, completed_at, status
+                    FROM lz_collection_runs
+                    WHERE {' AND '.join(filters)}
+                    ORDER BY started_at DESC
+                    LIMIT ? OFFSET ?""",
+                [*args, limit, offset],
+            ).fetchall()
+            total = conn.execute(
+                f"SELECT COUNT(*) FROM lz_collection_runs WHERE {' AND '.join(filters)}",
+                args,
+            ).fetchone()[0]
+        finally:
+            conn.close()
+        self._send_json({
+            "total": total,
+            "limit": limit,
+            "offset": offset,
+            "runs": [
+                {
+                    "collection_run_id": r[0], "repo_id": r[1], "run_id": r[2],
+                    "branch": r[3], "commit": r[4],
+                    "started_at": str(r[5]), "completed_at": str(r[6]) if r[6] else None,
+                    "status": r[7],
+                }
+                for r in rows
+            ],
+        })
+
+    def _list_findings(self, collection_run_id: str) -> None:
+        params = self._query()
+        tool = params.get("tool")
+        if not tool:
+            self._error(422, f"tool parameter is required (one of: {', '.join(sorted(FINDINGS_TABLES))})")
+            return
+        if tool not in FINDINGS_TABLES:
+            self._error(422, f"unknown tool '{tool}' (one of: {', '.join(sorted(FINDINGS_TABLES))})")
+            return
+        table, columns = FINDINGS_TABLES[tool]
+        limit, offset = self._pagination(params)
+        filters = ["t.collection_run_id = ?", "t.tool_name = ?"]
+        args: list = [collection_run_id, tool]
+        if params.get("severity") and "severity" in columns:
+            filters.append("f.severity = ?")
+            args.append(params["severity"].upper())
+        if params.get("path_prefix"):
+            filters.append("f.relative_path LIKE ?")
+            args.append(params["path_prefix"] + "%")
+        conn = self._connect()
+        try:
+            rows = conn.execute(
+                f"""SELECT {', '.join('f.' + c for c in columns)}
+                    FROM {table} f
+                    JOIN lz_tool_runs t ON t.run_pk = f.run_pk
+                    WHERE {' AND '.join(filters)}
+                    ORDER BY f.relative_path
+                    LIMIT ? OFFSET ?""",
+                [*args, limit, offset],
+            ).fetchall()
+            total = conn.execute(
+                f"""SELECT COUNT(*)
+                    FROM {table} f
+                    JOIN lz_tool_runs t ON t.run_pk = f.run_pk
+                    WHERE {' AND '.join(filters)}""",
+                args,
+            ).fetchone()[0]
+        finally:
+            conn.close()
+        self._send_json({
+            "total": total,
+            "limit": limit,
+            "offset": offset,
+            "tool": tool,
+            "findings": [dict(zip(columns, row)) for row in rows],
+        })
+
+    def _metrics(self) -> None:
+        """Prometheus text exposition of scan and landing-zone metrics."""
+        from caldera_cli.metrics import render_metrics
+
+        manager: ScanManager = self.server.scan_manager  # type: ignore[attr-defined]
+        conn = None
+        try:
+            if Path(self.server.db_path).exists():  # type: ignore[attr-defined]
+                conn = self._connect()
+            body = render_metrics(conn, manager.jobs()).encode()
+        finally:
+            if conn is not None:
+                conn.close()
+        self.send_response(200)
+        self.send_header("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def _badge(self, metric: str) -> None:
+        """SVG badge for README embedding (?repo_id=<id> to pin a repo)."""
+        from caldera_cli.badge import BADGE_METRICS, build_badge
+
+        if metric not in BADGE_METRICS:
+            self._error(404, f"unknown badge metric: {metric} (one of: {', '.join(BADGE_METRICS)})")
+            return
+        params = self._query()
+        body = build_badge(
+            Path(self.server.db_path), metric, repo_id=params.get("repo_id")  # type: ignore[attr-defined]
+        ).encode()
+        self.send_response(200)
+        self.send_header("Content-Type", "image/svg+xml")
+        self.send_header("Cache-Control", "max-age=300")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def _schedule(self) -> None:
+        scheduler = getattr(self.server, "scheduler", None)
+        if scheduler is None:
+            self._error(404, "no schedule: server is not running in daemon mode")
+            return
+        self._send_json({"schedule": scheduler.status()})
+
+    def _scan_status(self, scan_id: str) -> None:
+        manager: ScanManager = self.server.scan_manager  # type: ignore[attr-defined]
+        job = manager.get(scan_id)
+        if job is None:
+            self._error(404, f"no such scan: {scan_id}")
+            return
+        self._send_json(job.snapshot())
+
+    def _scan_events(self, scan_id: str) -> None:
+        """Stream scan events as NDJSON until the scan finishes."""
+        manager: ScanManager = self.server.scan_manager  # type: ignore[attr-defined]
+        job = manager.get(scan_id)
+        if job is None:
+            self._error(404, f"no such scan: {scan_id}")
+            return
+        self.send_response(200)
+        self.send_header("Content-Type", "application/x-ndjson")
+        self.end_headers()
+        cursor = 0
+        while True:
+            with job._lock:
+                pending = job.events[cursor:]
+                cursor = len(job.events)
+                done = job.status != "running"
+            for event in pending:
+                try:
+                    self.wfile.write((json.dumps(event) + "\n").encode())
+                    self.wfile.flush()
+                except (BrokenPipeError, ConnectionResetError):
+                    return
+            if done and not pending:
+                return
+            if not pending:
+                time.sleep(0.2)
+
+
+def create_server(
+    host: str,
+    port: int,
+    db_path: Path,
+    repo_root: Path | None = None,
+    scheduler=None,
+    token_store: TokenStore | None = None,
+) -> ThreadingHTTPServer:
+    """Build the HTTP server with scan manager and database attached."""
+    server = ThreadingHTTPServer((host, port), CalderaAPIHandler)
+    server.db_path = db_path  # type: ignore[attr-defined]
+    server.scan_manager = ScanManager(db_path, repo_root)  # type: ignore[attr-defined]
+    server.scheduler = scheduler  # type: ignore[attr-defined]
+    server.token_store = token_store  # type: ignore[attr-defined]
+    return server
+
+
+def serve_forever(host: str, port: int, db_path: Path, token_store: TokenStore | None = None) -> None:
+    server = create_server(host, port, db_path, token_store=token_store)
+    auth = "token auth" if token_store is not None else "no auth"
+    print(f"Caldera API listening on http://{host}:{port} (db: {db_path}, {auth})")
+    try:
+        server.serve_forever()
+    except KeyboardInterrupt:
+        print("\nShutting down.")
+    finally:
+        server.server_close()
@@ -0,0 +1,194 @@
+"""Ranked refactoring candidates from clones, complexity, and churn.
+
+``caldera suggest`` turns a run's stored metrics into an ordered to-do
+list. Two kinds of candidate are surfaced:
+
+- ``extract-helper``     a pmd-cpd/jscpd clone class; collapsing the
+  copies into one helper removes every duplicated line but the first —
+  semantic near-duplicates (the renamed-variable discount-calculator
+  kind) score exactly like literal copies since the clone tools already
+  matched them
+- ``decompose-hotspot``  a file whose functions exceed the CCN
+  threshold; each one is a decomposition target
+
+Payoff is the remediation time the change removes, priced with the same
+per-unit costs as the insights debt model (0.5 min per duplicated line,
+20 min per over-threshold function). Blast radius is the number of
+files the refactoring touches. Ranking multiplies payoff by a churn
+factor — debt in a file changed daily costs more than debt in a frozen
+one — and divides by the square root of the blast radius, so a
+two-file clone outranks an equally sized ten-file one without burying
+large clone classes entirely.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+import duckdb
+
+from caldera_cli.clones import _CLONE_SOURCES
+
+# Per-unit remediation costs, matching the insights debt model defaults.
+DUPLICATED_LINE_MINUTES = 0.5
+COMPLEX_FUNCTION_MINUTES = 20.0
+
+DEFAULT_MIN_CCN = 15
+
+# Churn factor caps at 6x so one churn-heavy file cannot drown the list.
+_CHURN_CAP = 500
+
+
+@dataclass(frozen=True)
+class RefactoringCandidate:
+    """One ranked refactoring opportunity."""
+
+    kind: str  # "extract-helper" or "decompose-hotspot"
+    target: str  # clone id, or the hotspot file path
+    description: str
+    files: tuple[str, ...]
+    payoff_minutes: float
+    blast_radius: int  # files touched by the refactoring
+    churn_90d: int  # summed over the touched files
+    score: float
+
+    def __post_init__(self) -> None:
+        if self.kind not in ("extract-helper", "decompose-hotspot"):
+            raise ValueError(f"unknown candidate kind {self.kind!r}")
+        if self.payoff_minutes < 0:
+            raise ValueError("payoff_minutes must be >= 0")
+        if self.blast_radius < 1:
+            raise ValueError("blast_radius must be >= 1")
+
+
+def _churn_factor(churn_90d: int) -> float:
+    return 1.0 + min(churn_90d, _CHURN_CAP) / 100.0
+
+
+def _score(payoff_minutes: float, blast_radius: int, churn_90d: int) -> float:
+    return round(payoff_minutes * _churn_factor(churn_90d) / blast_radius**0.5, 1)
+
+
+def _tool_run_pk(
+    conn: duckdb.DuckDBPyConnection, collection_run_id: str, tool_name: str
+) -> int | None:
+    row = conn.execute(
+        """SELECT run_pk FROM lz_tool_runs
+           WHERE collection_run_id = ? AND tool_name = ?
+           ORDER BY timestamp DESC LIMIT 1""",
+        [collection_run_id, tool_name],
+    ).fetchone()
+    return row[0] if row else None
+
+
+def fetch_churn(
+    conn: duckdb.DuckDBPyConnection, collection_run_id: str
+) -> dict[str, int]:
+    """90-day churn per file, empty when git-blame-scanner did not run."""
+    run_pk = _tool_run_pk(conn, collection_run_id, "git-blame-scanner")
+    if run_pk is None:
+        return {}
+    rows = conn.execute(
+        "SELECT relative_path, churn_90d FROM lz_git_blame_summary WHERE run_pk = ?",
+        [run_pk],
+    ).fetchall()
+    return {path: churn for path, churn in rows}
+
+
+def fetch_clone_candidates(
+    conn: duckdb.DuckDBPyConnection, collection_run_id: str, churn: dict[str, int]
+) -> list[RefactoringCandidate]:
+    """Clone classes from both duplication tools as extract-helper candidates."""
+    candidates = []
+    for tool, dup_table, occ_table in _CLONE_SOURCES:
+        run_pk = _tool_run_pk(conn, collection_run_id, tool)
+        if run_pk is None:
+            continue
+        rows = conn.execute(
+            f"""SELECT d.clone_id, d.lines, d.occurrence_count,
+                       LIST(DISTINCT o.relative_path ORDER BY o.relative_path)
+                FROM {dup_table} d
+                JOIN {occ_table} o ON o.run_pk = d.run_pk AND o.clone_id = d.clone_id
+                WHERE d.run_pk = ?
+                GROUP BY d.clone_id, d.lines, d.occurrence_count""",
+            [run_pk],
+        ).fetchall()
+        for clone_id, lines, occurrence_count, files in rows:
+            if occurrence_count < 2:
+                continue
+            payoff = lines * (occurrence_count - 1) * DUPLICATED_LINE_MINUTES
+            files = tuple(files)
+            churn_90d = sum(churn.get(path, 0) for path in files)
+            candidates.append(
+                RefactoringCandidate(
+                    kind="extract-helper",
+                    target=clone_id,
+                    description=(
+                        f"{tool}: {occurrence_count} copies of "
+                        f"{lines} duplicated lines"
+                    ),
+                    files=files,
+                    payoff_minutes=payoff,
+                    blast_radius=len(files),
+                    churn_90d=churn_90d,
+                    score=_score(payoff, len(files), churn_90d),
+                )
+            )
+    return candidates
+
+
+def fetch_hotspot_candidates(
+    conn: duckdb.DuckDBPyConnection,
+    collection_run_id: str,
+    churn: dict[str, int],
+    min_ccn: int = DEFAULT_MIN_CCN,
+) -> list[RefactoringCandidate]:
+    """Files with over-threshold functions as decompose-hotspot candidates."""
+    run_pk = _tool_run_pk(conn, collection_run_id, "lizard")
+    if run_pk is None:
+        return []
+    rows = conn.execute(
+        """SELECT fm.relative_path, COUNT(*), MAX(fn.ccn),
+                  LIST(fn.function_name ORDER BY fn.ccn DESC)
+           FROM lz_lizard_function_metrics fn
+           JOIN lz_lizard_file_metrics fm
+               ON fm.run_pk = fn.run_pk AND fm.file_id = fn.file_id
+           WHERE fn.run_pk = ? AND fn.ccn >= ?
+           GROUP BY fm.relative_path""",
+        [run_pk, min_ccn],
+    ).fetchall()
+    candidates = []
+    for path, function_count, max_ccn, worst in rows:
+        payoff = function_count * COMPLEX_FUNCTION_MINUTES
+        churn_90d = churn.get(path, 0)
+        candidates.append(
+            RefactoringCandidate(
+                kind="decompose-hotspot",
+                target=path,
+                description=(
+                    f"{function_count} function(s) with CCN >= {min_ccn} "
+                    f"(worst {max_ccn}: {', '.join(worst[:3])})"
+                ),
+                files=(path,),
+                payoff_minutes=payoff,
+                blast_radius=1,
+                churn_90d=churn_90d,
+                score=_score(payoff, 1, churn_90d),
+            )
+        )
+    return candidates
+
+
+def suggest(
+    conn: duckdb.DuckDBPyConnection,
+    collection_run_id: str,
+    min_ccn: int = DEFAULT_MIN_CCN,
+) -> list[RefactoringCandidate]:
+    """All refactoring candidates for a run, best payoff-per-risk first."""
+    churn = fetch_churn(conn, collection_run_id)
+    candidates = fetch_clone_candidates(conn, collection_run_id, churn)
+    candidates += fetch_hotspot_candidates(conn, collection_run_id, churn, min_ccn)
+    return sorted(
+        candidates,
+        key=lambda candidate: (-candidate.score, candidate.kind, candidate.target),
+    )
@@ -0,0 +1,149 @@
+"""Tests for annotated source rendering."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.annotate import (
+    FileAnnotations,
+    FunctionSpan,
+    LineFinding,
+    load_annotations,
+    render_html,
+    render_text,
+)
+
+PATH = "src/a.py"
+SOURCE = ["def foo():", "    eval(data)", "    return 1", "", "x = 1", "y = 2"]
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    for tool in ("semgrep", "lizard", "pmd-cpd"):
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool}", tool, "a" * 40, datetime(2026, 8, 1)],
+        )
+    pks = dict(
+        conn.execute("SELECT tool_name, run_pk FROM lz_tool_runs").fetchall()
+    )
+    conn.execute(
+        """INSERT INTO lz_semgrep_smells (run_pk, file_id, relative_path, rule_id,
+               severity, line_start, message)
+           VALUES (?, ?, ?, 'no-eval', 'HIGH', 2, 'eval is dangerous')""",
+        [pks["semgrep"], PATH, PATH],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_file_metrics (run_pk, file_id, relative_path,
+               language, nloc, function_count, total_ccn, avg_ccn, max_ccn)
+           VALUES (?, ?, ?, 'Python', 3, 1, 4, 4.0, 4)""",
+        [pks["lizard"], PATH, PATH],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_function_metrics (run_pk, file_id, function_name,
+               long_name, ccn, nloc, params, token_count, line_start, line_end,
+               max_nesting_depth)
+           VALUES (?, ?, 'foo', 'foo()', 4, 3, 0, 12, 1, 3, 1)""",
+        [pks["lizard"], PATH],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_duplications (run_pk, clone_id, lines, tokens,
+               occurrence_count, is_cross_file, code_fragment)
+           VALUES (?, 'clone-1', 2, 10, 2, false, 'x = 1')""",
+        [pks["pmd-cpd"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_occurrences (run_pk, clone_id, file_id,
+               directory_id, relative_path, line_start, line_end)
+           VALUES (?, 'clone-1', ?, 'src', ?, 5, 6)""",
+        [pks["pmd-cpd"], PATH, PATH],
+    )
+    yield conn
+    conn.close()
+
+
+class TestLoadAnnotations:
+    def test_findings_keyed_by_line(self, db: duckdb.DuckDBPyConnection) -> None:
+        annotations = load_annotations(db, "run-1", PATH)
+        assert annotations.findings[2][0].rule == "no-eval"
+        assert annotations.findings[2][0].severity == "HIGH"
+
+    def test_functions_and_duplicate_lines(self, db: duckdb.DuckDBPyConnection) -> None:
+        annotations = load_annotations(db, "run-1", PATH)
+        assert annotations.functions == (FunctionSpan("foo", 4, 3, 1, 3),)
+        assert annotations.duplicate_lines == frozenset({5, 6})
+
+    def test_other_files_do_not_bleed_in(self, db: duckdb.DuckDBPyConnection) -> None:
+        annotations = load_annotations(db, "run-1", "src/other.py")
+        assert annotations.findings == {}
+        assert annotations.functions == ()
+        assert annotations.duplicate_lines == frozenset()
+
+
+class TestRenderText:
+    ANNOTATIONS = FileAnnotations(
+        findings={2: (LineFinding("semgrep", "no-eval", "HIGH", "eval is dangerous"),)},
+        functions=(FunctionSpan("foo", 4, 3, 1, 3),),
+        duplicate_lines=frozenset({5, 6}),
+    )
+
+    def test_severity_marker_and_note(self) -> None:
+        text = render_text(SOURCE, self.ANNOTATIONS)
+        lines = text.splitlines()
+        assert any(line.startswith("H ") and "eval(data)" in line for line in lines)
+        assert any("^ semgrep/no-eval: eval is dangerous" in line for line in lines)
+
+    def test_function_header_precedes_first_line(self) -> None:
+        lines = render_text(SOURCE, self.ANNOTATIONS).splitlines()
+        header = next(i for i, line in enumerate(lines) if "foo (CCN 4, NLOC 3)" in line)
+        assert "def foo():" in lines[header + 1]
+
+    def test_duplicate_lines_marked(self) -> None:
+        lines = render_text(SOURCE, self.ANNOTATIONS).splitlines()
+        marked = [line for line in lines if line[1] == "D"]
+        assert len(marked) == 2
+        assert "x = 1" in marked[0]
+
+    def test_clean_file_has_empty_gutter(self) -> None:
+        empty = FileAnnotations(findings={}, functions=(), duplicate_lines=frozenset())
+        lines = render_text(["x = 1"], empty).splitlines()
+        assert lines == ["      1 | x = 1"]
+
+
+class TestRenderHtml:
+    def test_classes_and_escaping(self) -> None:
+        annotations = FileAnnotations(
+            findings={1: (LineFinding("semgrep", "r1", "HIGH", "bad <tag>"),)},
+            functions=(),
+            duplicate_lines=frozenset({2}),
+        )
+        html = render_html(["if a < b:", "    pass"], annotations, PATH)
+        assert 'class="sev-high"' in html
+        assert 'class="dup"' in html
+        assert "if a &lt; b:" in html
+        assert "bad &lt;tag&gt;" in html
+
+    def test_page_titled_with_path(self) -> None:
+        empty = FileAnnotations(findings={}, functions=(), duplicate_lines=frozenset())
+        html = render_html(["x = 1"], empty, PATH)
+        assert f"<title>{PATH}</title>" in html
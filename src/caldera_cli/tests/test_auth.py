@@ -0,0 +1,174 @@
+"""Tests for API token auth: the token store and server enforcement."""
+
+from __future__ import annotations
+
+import json
+import sys
+import threading
+import urllib.error
+import urllib.request
+from pathlib import Path
+
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.auth import AuthError, TokenStore, authorized, hash_token
+from caldera_cli.server import create_server
+
+
+@pytest.fixture
+def store(tmp_path: Path) -> TokenStore:
+    return TokenStore.load(tmp_path / "tokens.json")
+
+
+# -- token store ------------------------------------------------------------
+
+
+def test_issue_stores_hash_not_plaintext(store: TokenStore) -> None:
+    token = store.issue("ci", "read")
+    saved = json.loads(store.path.read_text())
+    assert token not in store.path.read_text()
+    assert saved[0]["token_hash"] == hash_token(token)
+    assert saved[0]["scope"] == "read"
+
+
+def test_store_file_is_owner_only(store: TokenStore) -> None:
+    store.issue("ci", "read")
+    assert store.path.stat().st_mode & 0o777 == 0o600
+
+
+def test_authenticate_round_trip(store: TokenStore) -> None:
+    token = store.issue("ci", "write", repos=("repo-a",))
+    record = TokenStore.load(store.path).authenticate(token)
+    assert record is not None
+    assert record.name == "ci"
+    assert record.repos == ("repo-a",)
+
+
+def test_authenticate_rejects_unknown_and_revoked(store: TokenStore) -> None:
+    token = store.issue("ci", "read")
+    assert store.authenticate("not-a-token") is None
+    store.revoke("ci")
+    assert store.authenticate(token) is None
+
+
+def test_duplicate_name_rejected(store: TokenStore) -> None:
+    store.issue("ci", "read")
+    with pytest.raises(AuthError, match="already exists"):
+        store.issue("ci", "write")
+
+
+def test_unknown_scope_rejected(store: TokenStore) -> None:
+    with pytest.raises(AuthError, match="Unknown scope"):
+        store.issue("ci", "admin")
+
+
+def test_rotate_revokes_old_and_keeps_grants(store: TokenStore) -> None:
+    old = store.issue("ci", "write", repos=("repo-a",))
+    new = store.rotate("ci")
+    assert new != old
+    assert store.authenticate(old) is None
+    record = store.authenticate(new)
+    assert record is not None
+    assert record.scope == "write"
+    assert record.repos == ("repo-a",)
+
+
+def test_authorized_scopes_and_repos(store: TokenStore) -> None:
+    reader = store.authenticate(store.issue("reader", "read"))
+    writer = store.authenticate(store.issue("writer", "write", repos=("repo-a",)))
+    assert authorized(reader, "read")
+    assert not authorized(reader, "write")
+    assert authorized(writer, "read")  # write implies read
+    assert authorized(writer, "write", repo_id="repo-a")
+    assert not authorized(writer, "write", repo_id="repo-b")
+
+
+# -- server enforcement -----------------------------------------------------
+
+
+@pytest.fixture
+def secured_api(tmp_path: Path):
+    store = TokenStore.load(tmp_path / "tokens.json")
+    read_token = store.issue("reader", "read")
+    write_token = store.issue("writer", "write", repos=("repo-a",))
+    server = create_server("127.0.0.1", 0, tmp_path / "missing.duckdb", token_store=store)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    base = f"http://127.0.0.1:{server.server_address[1]}"
+    yield base, read_token, write_token
+    server.shutdown()
+    server.server_close()
+
+
+def _request(url: str, token: str | None = None, body: dict | None = None) -> tuple[int, dict]:
+    data = json.dumps(body).encode() if body is not None else None
+    request = urllib.request.Request(url, data=data)
+    if token is not None:
+        request.add_header("Authorization", f"Bearer {token}")
+    try:
+        with urllib.request.urlopen(request) as response:
+            return response.status, json.loads(response.read())
+    except urllib.error.HTTPError as exc:
+        return exc.code, json.loads(exc.read())
+
+
+def test_health_is_open(secured_api) -> None:
+    base, _, _ = secured_api
+    status, payload = _request(f"{base}/health")
+    assert status == 200
+    assert payload == {"status": "ok"}
+
+
+def test_get_without_token_is_401(secured_api) -> None:
+    base, _, _ = secured_api
+    status, payload = _request(f"{base}/scans")
+    assert status == 401
+    assert "bearer token" in payload["error"]
+
+
+def test_get_with_bad_token_is_401(secured_api) -> None:
+    base, _, _ = secured_api
+    status, payload = _request(f"{base}/scans", token="wrong")
+    assert status == 401
+    assert "invalid or revoked" in payload["error"]
+
+
+def test_read_token_can_list_scans(secured_api) -> None:
+    base, read_token, _ = secured_api
+    status, payload = _request(f"{base}/scans", token=read_token)
+    assert status == 200
+    assert payload == []
+
+
+def test_read_token_cannot_trigger_scan(secured_api) -> None:
+    base, read_token, _ = secured_api
+    status, payload = _request(
+        f"{base}/scans", token=read_token, body={"repo_path": "/tmp/x", "repo_id": "repo-a"}
+    )
+    assert status == 403
+    assert "lacks write access" in payload["error"]
+
+
+def test_write_token_restricted_to_its_repos(secured_api) -> None:
+    base, _, write_token = secured_api
+    status, payload = _request(
+        f"{base}/scans", token=write_token, body={"repo_path": "/tmp/x", "repo_id": "repo-b"}
+    )
+    assert status == 403
+    assert "repo 'repo-b'" in payload["error"]
+
+
+def test_open_server_requires_no_token(tmp_path: Path) -> None:
+    server = create_server("127.0.0.1", 0, tmp_path / "missing.duckdb")
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    try:
+        status, payload = _request(f"http://127.0.0.1:{server.server_address[1]}/scans")
+        assert status == 200
+        assert payload == []
+    finally:
+        server.shutdown()
+        server.server_close()
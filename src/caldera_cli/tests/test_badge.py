@@ -0,0 +1,113 @@
+"""Tests for SVG badge generation."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.badge import build_badge, color_for, metric_reading, render_svg
+
+
+@pytest.fixture
+def db_path(tmp_path: Path) -> Path:
+    path = tmp_path / "test.duckdb"
+    conn = duckdb.connect(str(path))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    pks = {}
+    for tool_name in ("lizard", "pmd-cpd"):
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool_name}", tool_name, "a" * 40, datetime(2026, 8, 1)],
+        )
+        pks[tool_name] = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = ?", [tool_name]
+        ).fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_lizard_file_metrics
+           (run_pk, file_id, relative_path, language, nloc, function_count, total_ccn, avg_ccn, max_ccn)
+           VALUES (?, 'f-1', 'src/a.py', 'Python', 100, 10, 40, 4.0, 9)""",
+        [pks["lizard"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_file_metrics
+           (run_pk, file_id, directory_id, relative_path, language,
+            total_lines, duplicate_lines, duplicate_blocks, duplication_percentage)
+           VALUES (?, 'f-1', 'd-1', 'src/a.py', 'Python', 1000, 20, 2, 2.0)""",
+        [pks["pmd-cpd"]],
+    )
+    conn.close()
+    return path
+
+
+class TestColors:
+    def test_health_higher_is_better(self) -> None:
+        assert color_for("health", 95.0) == "brightgreen"
+        assert color_for("health", 70.0) == "yellowgreen"
+        assert color_for("health", 10.0) == "red"
+
+    def test_duplication_lower_is_better(self) -> None:
+        assert color_for("duplication", 1.0) == "brightgreen"
+        assert color_for("duplication", 15.0) == "yellow"
+        assert color_for("duplication", 45.0) == "red"
+
+    def test_ccn_thresholds(self) -> None:
+        assert color_for("ccn", 4.0) == "brightgreen"
+        assert color_for("ccn", 12.0) == "yellow"
+        assert color_for("ccn", 25.0) == "red"
+
+
+class TestRenderSvg:
+    def test_contains_label_and_value(self) -> None:
+        svg = render_svg("caldera health", "92", "brightgreen")
+        assert svg.startswith("<svg ")
+        assert 'aria-label="caldera health: 92"' in svg
+        assert svg.count(">caldera health</text>") == 1
+        assert 'fill="#4c1"' in svg
+
+    def test_width_scales_with_text(self) -> None:
+        short = render_svg("x", "1", "red")
+        long = render_svg("a much longer label", "100.0%", "red")
+        assert len(long) > len(short)
+
+
+class TestMetricReading:
+    def test_duplication(self, db_path: Path) -> None:
+        assert metric_reading(db_path, "duplication") == ("2.0%", "brightgreen")
+
+    def test_ccn(self, db_path: Path) -> None:
+        assert metric_reading(db_path, "ccn") == ("4.0", "brightgreen")
+
+    def test_health_uses_insights_formula(self, db_path: Path) -> None:
+        value, color = metric_reading(db_path, "health")
+        assert float(value) > 80  # low ccn and duplication -> healthy
+        assert color in ("brightgreen", "green")
+
+    def test_unknown_repo_renders_unknown(self, db_path: Path) -> None:
+        assert metric_reading(db_path, "ccn", repo_id="repo-zzz") == ("unknown", "lightgrey")
+
+    def test_unknown_metric_rejected(self, db_path: Path) -> None:
+        with pytest.raises(ValueError, match="unknown metric"):
+            metric_reading(db_path, "velocity")
+
+
+def test_build_badge_end_to_end(db_path: Path) -> None:
+    svg = build_badge(db_path, "duplication")
+    assert "2.0%" in svg
+    assert "duplication" in svg
@@ -0,0 +1,146 @@
+"""Tests for the clone diff viewer."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.clones import (
+    Clone,
+    CloneOccurrence,
+    fetch_clone,
+    fragment_lines,
+    highlight_token_diffs,
+    render_side_by_side,
+    render_unified,
+)
+
+FRAGMENT = "def charge(amount):\n    total = amount * tax\n    return total\n"
+
+
+def _clone(occurrences: tuple[CloneOccurrence, ...]) -> Clone:
+    return Clone("pmd-cpd", "dup-1", 3, 20, occurrences, FRAGMENT)
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    conn.execute(
+        """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+               tool_version, schema_version, branch, commit, timestamp)
+           VALUES ('run-1', 'repo-a', 'run-1-pmd-cpd', 'pmd-cpd', '1.0', '1.0.0', 'main', ?, ?)""",
+        ["a" * 40, datetime(2026, 8, 1)],
+    )
+    pk = conn.execute("SELECT run_pk FROM lz_tool_runs").fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_duplications (run_pk, clone_id, lines, tokens,
+               occurrence_count, is_cross_file, code_fragment)
+           VALUES (?, 'dup-1', 3, 20, 2, TRUE, ?)""",
+        [pk, FRAGMENT],
+    )
+    for path, start in (("src/billing/charge.py", 10), ("src/billing/refund.py", 40)):
+        conn.execute(
+            """INSERT INTO lz_pmd_cpd_occurrences (run_pk, clone_id, file_id, directory_id,
+                   relative_path, line_start, line_end)
+               VALUES (?, 'dup-1', ?, 'src/billing', ?, ?, ?)""",
+            [pk, path, path, start, start + 2],
+        )
+    yield conn
+    conn.close()
+
+
+class TestFetchClone:
+    def test_loads_clone_with_occurrences(self, db: duckdb.DuckDBPyConnection) -> None:
+        clone = fetch_clone(db, "dup-1")
+        assert clone is not None
+        assert clone.tool == "pmd-cpd"
+        assert clone.lines == 3
+        assert [o.relative_path for o in clone.occurrences] == [
+            "src/billing/charge.py",
+            "src/billing/refund.py",
+        ]
+
+    def test_unknown_clone_returns_none(self, db: duckdb.DuckDBPyConnection) -> None:
+        assert fetch_clone(db, "dup-404") is None
+
+    def test_run_filter(self, db: duckdb.DuckDBPyConnection) -> None:
+        assert fetch_clone(db, "dup-1", collection_run_id="run-1") is not None
+        assert fetch_clone(db, "dup-1", collection_run_id="run-2") is None
+
+
+class TestFragmentLines:
+    def test_reads_from_checkout(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("\n".join(f"line {n}" for n in range(1, 11)))
+        occurrence = CloneOccurrence("a.py", 3, 5)
+        assert fragment_lines(tmp_path, occurrence, FRAGMENT) == ["line 3", "line 4", "line 5"]
+
+    def test_falls_back_to_stored_fragment(self, tmp_path: Path) -> None:
+        occurrence = CloneOccurrence("gone.py", 3, 5)
+        assert fragment_lines(tmp_path, occurrence, FRAGMENT) == FRAGMENT.splitlines()
+
+    def test_falls_back_when_file_shrank(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("only one line\n")
+        occurrence = CloneOccurrence("a.py", 3, 5)
+        assert fragment_lines(tmp_path, occurrence, FRAGMENT) == FRAGMENT.splitlines()
+
+
+class TestHighlightTokenDiffs:
+    def test_differing_identifiers_wrapped(self) -> None:
+        left, right = highlight_token_diffs("total = amount * tax", "total = amount * vat")
+        assert left == "total = amount * «tax»"
+        assert right == "total = amount * «vat»"
+
+    def test_identical_lines_untouched(self) -> None:
+        left, right = highlight_token_diffs("return total", "return total")
+        assert left == "return total"
+        assert right == "return total"
+
+    def test_changed_literal_wrapped(self) -> None:
+        left, right = highlight_token_diffs("retry(3)", "retry(5)")
+        assert "«3»" in left and "«5»" in right
+
+
+class TestRendering:
+    def test_side_by_side_markers(self, tmp_path: Path) -> None:
+        (tmp_path / "left.py").write_text("a = 1\nshared()\n")
+        (tmp_path / "right.py").write_text("b = 1\nshared()\nextra()\n")
+        clone = _clone((CloneOccurrence("left.py", 1, 2), CloneOccurrence("right.py", 1, 3)))
+        text = render_side_by_side(clone, clone.occurrences[0], clone.occurrences[1], tmp_path)
+        assert "«a» = 1" in text and "«b» = 1" in text
+        lines = text.splitlines()
+        assert any(" | " in line for line in lines)
+        assert any(line.lstrip().startswith("> extra()") for line in lines)
+
+    def test_unified_diff(self, tmp_path: Path) -> None:
+        (tmp_path / "left.py").write_text("a = 1\n")
+        (tmp_path / "right.py").write_text("b = 1\n")
+        clone = _clone((CloneOccurrence("left.py", 1, 1), CloneOccurrence("right.py", 1, 1)))
+        text = render_unified(clone, clone.occurrences[0], clone.occurrences[1], tmp_path)
+        assert "-a = 1" in text and "+b = 1" in text
+
+    def test_identical_fragments_note(self) -> None:
+        clone = _clone((CloneOccurrence("a.py", 1, 3), CloneOccurrence("b.py", 10, 12)))
+        text = render_unified(clone, clone.occurrences[0], clone.occurrences[1])
+        assert "(fragments are identical)" in text
+
+    def test_header_lists_both_locations(self) -> None:
+        clone = _clone((CloneOccurrence("a.py", 1, 3), CloneOccurrence("b.py", 10, 12)))
+        text = render_side_by_side(clone, clone.occurrences[0], clone.occurrences[1])
+        assert "A: a.py:1-3" in text
+        assert "B: b.py:10-12" in text
@@ -0,0 +1,134 @@
+"""Tests for the run-to-run delta report."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.compare import (
+    RunMetrics,
+    compare_runs,
+    render_markdown,
+    render_text,
+    resolve_run,
+    to_dict,
+)
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    for run, commit, day in (("run-a", "a" * 40, 1), ("run-b", "b" * 40, 2)):
+        conn.execute(
+            """INSERT INTO lz_collection_runs VALUES
+               (?, 'repo-a', ?, 'main', ?, ?, ?, 'completed')""",
+            [run, run, commit, datetime(2026, 8, day), datetime(2026, 8, day)],
+        )
+        for tool in ("semgrep", "scc", "lizard", "pmd-cpd"):
+            conn.execute(
+                """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id,
+                       tool_name, tool_version, schema_version, branch, commit, timestamp)
+                   VALUES (?, 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+                [run, f"{run}-{tool}", tool, commit, datetime(2026, 8, day)],
+            )
+    pks = {
+        (run, tool): pk
+        for run, tool, pk in conn.execute(
+            "SELECT collection_run_id, tool_name, run_pk FROM lz_tool_runs"
+        ).fetchall()
+    }
+    # run-a: two findings; run-b keeps one, resolves one, adds one.
+    for run, rule, line in (
+        ("run-a", "kept", 3),
+        ("run-a", "resolved", 9),
+        ("run-b", "kept", 3),
+        ("run-b", "introduced", 20),
+    ):
+        conn.execute(
+            """INSERT INTO lz_semgrep_smells (run_pk, file_id, relative_path, rule_id,
+                   severity, line_start, message)
+               VALUES (?, 'src/a.py', 'src/a.py', ?, 'HIGH', ?, 'smelly')""",
+            [pks[(run, "semgrep")], rule, line],
+        )
+    for run, loc, ccn, functions, dup, total in (
+        ("run-a", 1000, 30, 10, 50, 1000),
+        ("run-b", 1100, 44, 11, 44, 1100),
+    ):
+        conn.execute(
+            """INSERT INTO lz_scc_file_metrics (run_pk, file_id, directory_id,
+                   relative_path, code_lines) VALUES (?, 'src/a.py', 'src', 'src/a.py', ?)""",
+            [pks[(run, "scc")], loc],
+        )
+        conn.execute(
+            """INSERT INTO lz_lizard_file_metrics (run_pk, file_id, relative_path,
+                   total_ccn, function_count) VALUES (?, 'src/a.py', 'src/a.py', ?, ?)""",
+            [pks[(run, "lizard")], ccn, functions],
+        )
+        conn.execute(
+            """INSERT INTO lz_pmd_cpd_file_metrics (run_pk, file_id, directory_id,
+                   relative_path, total_lines, duplicate_lines, duplicate_blocks,
+                   duplication_percentage)
+               VALUES (?, 'src/a.py', 'src', 'src/a.py', ?, ?, 1, 0.0)""",
+            [pks[(run, "pmd-cpd")], total, dup],
+        )
+    yield conn
+    conn.close()
+
+
+class TestResolveRun:
+    def test_exact_run_id(self, db: duckdb.DuckDBPyConnection) -> None:
+        assert resolve_run(db, "run-a") == "run-a"
+
+    def test_commit_prefix(self, db: duckdb.DuckDBPyConnection) -> None:
+        assert resolve_run(db, "bbbbbbb") == "run-b"
+
+    def test_unknown_ref_raises(self, db: duckdb.DuckDBPyConnection) -> None:
+        with pytest.raises(ValueError, match="no stored run"):
+            resolve_run(db, "nope")
+
+
+class TestCompareRuns:
+    def test_new_resolved_persisting(self, db: duckdb.DuckDBPyConnection) -> None:
+        delta = compare_runs(db, "run-a", "run-b")
+        assert [f["rule"] for f in delta.new] == ["introduced"]
+        assert [f["rule"] for f in delta.resolved] == ["resolved"]
+        assert delta.persisting_count == 1
+
+    def test_metric_deltas(self, db: duckdb.DuckDBPyConnection) -> None:
+        delta = compare_runs(db, "run-a", "run-b")
+        assert delta.metrics_a == RunMetrics(1000, 3.0, 5.0)
+        assert delta.metrics_b == RunMetrics(1100, 4.0, 4.0)
+
+    def test_refs_resolved_via_commit(self, db: duckdb.DuckDBPyConnection) -> None:
+        delta = compare_runs(db, "aaaa", "bbbb")
+        assert (delta.run_a, delta.run_b) == ("run-a", "run-b")
+
+
+class TestRendering:
+    def test_text_report(self, db: duckdb.DuckDBPyConnection) -> None:
+        text = render_text(compare_runs(db, "run-a", "run-b"))
+        assert "Findings: 1 new, 1 resolved, 1 persisting" in text
+        assert "HIGH semgrep/introduced src/a.py:20" in text
+        assert "code lines     1000 -> 1100 (+100)" in text
+
+    def test_markdown_report(self, db: duckdb.DuckDBPyConnection) -> None:
+        markdown = render_markdown(compare_runs(db, "run-a", "run-b"))
+        assert "### New findings" in markdown
+        assert "| HIGH | semgrep/introduced | `src/a.py:20` | smelly |" in markdown
+        assert "| mean CCN | 3.0 | 4.0 |" in markdown
+
+    def test_json_shape(self, db: duckdb.DuckDBPyConnection) -> None:
+        payload = to_dict(compare_runs(db, "run-a", "run-b"))
+        assert payload["persisting_count"] == 1
+        assert payload["metrics"]["duplication_pct"] == {"before": 5.0, "after": 4.0}
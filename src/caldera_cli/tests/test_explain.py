@@ -0,0 +1,117 @@
+"""Tests for the finding explanation command."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.explain import build_explanation, code_excerpt, find_occurrences, fingerprint
+from insights.codeclimate import fingerprint as codeclimate_fingerprint
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    # The same finding persisted in two collection runs.
+    for index, run_id in enumerate(("run-1", "run-2")):
+        conn.execute(
+            """INSERT INTO lz_collection_runs VALUES (?, 'repo-a', ?, 'main', ?, ?, ?, 'completed')""",
+            [run_id, run_id, chr(ord("a") + index) * 40,
+             datetime(2026, 8, 1 + index), datetime(2026, 8, 1 + index)],
+        )
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES (?, 'repo-a', ?, 'semgrep', '1.0', '1.0.0', 'main', ?, ?)""",
+            [run_id, f"{run_id}-semgrep", chr(ord("a") + index) * 40, datetime(2026, 8, 1 + index)],
+        )
+        pk = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE collection_run_id = ?", [run_id]
+        ).fetchone()[0]
+        conn.execute(
+            """INSERT INTO lz_semgrep_smells (run_pk, file_id, relative_path, rule_id,
+                   severity, line_start, message)
+               VALUES (?, 'src/app.py', 'src/app.py', 'python.lang.eval', 'HIGH', 4, 'eval() detected')""",
+            [pk],
+        )
+    yield conn
+    conn.close()
+
+
+FP = fingerprint("semgrep", "python.lang.eval", "src/app.py", 4)
+
+
+class TestFingerprint:
+    def test_matches_codeclimate_formula(self) -> None:
+        finding = {
+            "tool": "semgrep",
+            "rule_id": "python.lang.eval",
+            "relative_path": "src/app.py",
+            "line_start": 4,
+        }
+        assert fingerprint("semgrep", "python.lang.eval", "src/app.py", 4) == \
+            codeclimate_fingerprint(finding)
+
+    def test_missing_line_hashes_as_zero(self) -> None:
+        assert fingerprint("trivy", "CVE-1", "requirements.txt", None) == \
+            fingerprint("trivy", "CVE-1", "requirements.txt", 0)
+
+
+class TestFindOccurrences:
+    def test_finds_finding_in_every_run(self, db: duckdb.DuckDBPyConnection) -> None:
+        occurrences = find_occurrences(db, FP)
+        assert [o.collection_run_id for o in occurrences] == ["run-2", "run-1"]
+        assert occurrences[0].rule == "python.lang.eval"
+        assert occurrences[0].severity == "HIGH"
+
+    def test_unknown_fingerprint_matches_nothing(self, db: duckdb.DuckDBPyConnection) -> None:
+        assert find_occurrences(db, "0" * 32) == []
+
+
+class TestCodeExcerpt:
+    def test_marks_the_finding_line(self, tmp_path: Path) -> None:
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "app.py").write_text("\n".join(f"line {n}" for n in range(1, 11)))
+        excerpt = code_excerpt(tmp_path, "src/app.py", 4)
+        assert excerpt is not None
+        assert "  >     4 | line 4" in excerpt
+        assert excerpt.count("\n") == 6  # lines 1-7
+
+    def test_missing_file_returns_none(self, tmp_path: Path) -> None:
+        assert code_excerpt(tmp_path, "src/gone.py", 4) is None
+
+    def test_line_out_of_range_returns_none(self, tmp_path: Path) -> None:
+        (tmp_path / "tiny.py").write_text("x = 1\n")
+        assert code_excerpt(tmp_path, "tiny.py", 99) is None
+
+
+class TestBuildExplanation:
+    def test_contains_rule_suppression_and_history(self, db: duckdb.DuckDBPyConnection) -> None:
+        occurrences = find_occurrences(db, FP)
+        text = build_explanation(FP, occurrences)
+        assert "python.lang.eval" in text
+        assert "eval() detected" in text
+        assert "nosemgrep" in text
+        assert ".calderaignore" in text
+        assert "2 stored run(s)" in text
+        assert "run-1" in text and "run-2" in text
+
+    def test_excerpt_included_with_repo_path(
+        self, db: duckdb.DuckDBPyConnection, tmp_path: Path
+    ) -> None:
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "app.py").write_text("\n".join(f"line {n}" for n in range(1, 11)))
+        text = build_explanation(FP, find_occurrences(db, FP), repo_root=tmp_path)
+        assert "Code:" in text
+        assert "line 4" in text
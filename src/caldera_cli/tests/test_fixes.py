@@ -0,0 +1,134 @@
+"""Tests for the autofix application pipeline."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.fixes import (
+    Fix,
+    FixError,
+    apply_plan,
+    collect_semgrep_fixes,
+    detect_conflicts,
+    plan_patch,
+    unresolved_fixes,
+)
+
+
+def _semgrep_result(path: str, rule: str, start: tuple[int, int], end: tuple[int, int], fix: str | None) -> dict:
+    return {
+        "check_id": rule,
+        "path": path,
+        "start": {"line": start[0], "col": start[1]},
+        "end": {"line": end[0], "col": end[1]},
+        "extra": {"fix": fix} if fix is not None else {},
+    }
+
+
+def _fix(path: str, start: tuple[int, int], end: tuple[int, int], replacement: str, rule: str = "r1") -> Fix:
+    return Fix("semgrep", rule, path, start[0], start[1], end[0], end[1], replacement)
+
+
+class TestCollect:
+    def test_collects_only_results_with_fix(self) -> None:
+        output = {
+            "results": [
+                _semgrep_result("a.py", "r1", (1, 1), (1, 5), "safe()"),
+                _semgrep_result("a.py", "r2", (2, 1), (2, 5), None),
+            ]
+        }
+        fixes = collect_semgrep_fixes(output)
+        assert len(fixes) == 1
+        assert fixes[0].rule_id == "r1"
+        assert fixes[0].replacement == "safe()"
+
+
+class TestDetectConflicts:
+    def test_overlapping_spans_both_withheld(self) -> None:
+        first = _fix("a.py", (1, 1), (2, 5), "x")
+        second = _fix("a.py", (2, 1), (3, 1), "y", rule="r2")
+        applicable, conflicts = detect_conflicts([first, second])
+        assert applicable == []
+        assert conflicts == [(first, second)]
+
+    def test_adjacent_spans_do_not_conflict(self) -> None:
+        first = _fix("a.py", (1, 1), (1, 5), "x")
+        second = _fix("a.py", (1, 5), (1, 9), "y")
+        applicable, conflicts = detect_conflicts([first, second])
+        assert len(applicable) == 2
+        assert conflicts == []
+
+    def test_same_span_in_different_files_ok(self) -> None:
+        applicable, conflicts = detect_conflicts(
+            [_fix("a.py", (1, 1), (1, 5), "x"), _fix("b.py", (1, 1), (1, 5), "y")]
+        )
+        assert len(applicable) == 2
+        assert conflicts == []
+
+
+class TestPlanPatch:
+    def test_span_replacement(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("eval(data)\nprint(1)\n")
+        plan = plan_patch(tmp_path, [_fix("a.py", (1, 1), (1, 11), "ast.literal_eval(data)")])
+        assert plan.new_contents["a.py"] == "ast.literal_eval(data)\nprint(1)\n"
+        assert "-eval(data)" in plan.patch
+        assert "+ast.literal_eval(data)" in plan.patch
+
+    def test_multiple_fixes_applied_bottom_up(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("one\ntwo\nthree\n")
+        plan = plan_patch(
+            tmp_path,
+            [
+                _fix("a.py", (1, 1), (1, 4), "ONE"),
+                _fix("a.py", (3, 1), (3, 6), "THREE"),
+            ],
+        )
+        assert plan.new_contents["a.py"] == "ONE\ntwo\nTHREE\n"
+
+    def test_missing_file_raises(self, tmp_path: Path) -> None:
+        with pytest.raises(FixError, match="cannot read"):
+            plan_patch(tmp_path, [_fix("gone.py", (1, 1), (1, 2), "x")])
+
+    def test_planning_writes_nothing(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("eval(data)\n")
+        plan_patch(tmp_path, [_fix("a.py", (1, 1), (1, 11), "safe()")])
+        assert (tmp_path / "a.py").read_text() == "eval(data)\n"
+
+
+class TestApplyPlan:
+    def test_applies_with_backup_and_patch(self, tmp_path: Path) -> None:
+        repo = tmp_path / "repo"
+        (repo / "src").mkdir(parents=True)
+        (repo / "src" / "a.py").write_text("eval(data)\n")
+        plan = plan_patch(repo, [_fix("src/a.py", (1, 1), (1, 11), "safe()")])
+        backup_dir = tmp_path / "backup"
+        patch_path = apply_plan(repo, plan, backup_dir)
+        assert (repo / "src" / "a.py").read_text() == "safe()\n"
+        assert (backup_dir / "src" / "a.py").read_text() == "eval(data)\n"
+        assert "-eval(data)" in patch_path.read_text()
+
+    def test_no_temp_files_left_behind(self, tmp_path: Path) -> None:
+        repo = tmp_path / "repo"
+        repo.mkdir()
+        (repo / "a.py").write_text("eval(x)\n")
+        plan = plan_patch(repo, [_fix("a.py", (1, 1), (1, 8), "safe()")])
+        apply_plan(repo, plan, tmp_path / "backup")
+        assert not list(repo.glob("*.caldera-fix-tmp"))
+
+
+class TestUnresolvedFixes:
+    def test_fix_resolved_when_finding_gone(self) -> None:
+        fix = _fix("a.py", (1, 1), (1, 5), "x", rule="r1")
+        fresh = {"results": [_semgrep_result("b.py", "r1", (1, 1), (1, 5), None)]}
+        assert unresolved_fixes([fix], fresh) == []
+
+    def test_fix_unresolved_when_still_reported(self) -> None:
+        fix = _fix("a.py", (1, 1), (1, 5), "x", rule="r1")
+        fresh = {"results": [_semgrep_result("a.py", "r1", (4, 1), (4, 5), None)]}
+        assert unresolved_fixes([fix], fresh) == [fix]
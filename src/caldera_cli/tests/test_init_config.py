@@ -0,0 +1,89 @@
+"""Tests for the `caldera init` starter-configuration wizard."""
+
+from __future__ import annotations
+
+import sys
+import tomllib
+from pathlib import Path
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.init_config import (
+    RepoProfile,
+    profile_repo,
+    propose_tools,
+    render_config,
+)
+
+
+def _make_repo(tmp_path: Path) -> Path:
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "app.py").write_text("x = 1\n")
+    (tmp_path / "src" / "util.py").write_text("y = 2\n")
+    (tmp_path / "deploy.sh").write_text("echo hi\n")
+    (tmp_path / ".pre-commit-config.yaml").write_text("repos: []\n")
+    (tmp_path / ".git").mkdir()
+    return tmp_path
+
+
+class TestProfileRepo:
+    def test_counts_languages_most_files_first(self, tmp_path: Path) -> None:
+        profile = profile_repo(_make_repo(tmp_path))
+        assert profile.languages == (("Python", 2), ("Shell", 1))
+        assert profile.is_git_repo
+
+    def test_detects_lint_configs(self, tmp_path: Path) -> None:
+        profile = profile_repo(_make_repo(tmp_path))
+        assert profile.lint_configs == (".pre-commit-config.yaml",)
+
+    def test_skips_vendored_directories(self, tmp_path: Path) -> None:
+        repo = _make_repo(tmp_path)
+        (repo / "node_modules").mkdir()
+        (repo / "node_modules" / "dep.js").write_text("x\n")
+        profile = profile_repo(repo)
+        assert not any(lang == "JavaScript" for lang, _ in profile.languages)
+
+    def test_dockerfile_counted_as_language(self, tmp_path: Path) -> None:
+        (tmp_path / "Dockerfile").write_text("FROM python\n")
+        profile = profile_repo(tmp_path)
+        assert ("Dockerfile", 1) in profile.languages
+
+
+class TestProposeTools:
+    def test_language_tools_enabled(self, tmp_path: Path) -> None:
+        tools = propose_tools(profile_repo(_make_repo(tmp_path)))
+        assert "bandit" in tools
+        assert "shellcheck" in tools
+        assert "golangci" not in tools
+
+    def test_git_tools_only_for_git_repos(self) -> None:
+        profile = RepoProfile(1, 10, (("Python", 1),), (), is_git_repo=False)
+        assert "git-fame" not in propose_tools(profile)
+        profile = RepoProfile(1, 10, (("Python", 1),), (), is_git_repo=True)
+        assert "git-fame" in propose_tools(profile)
+
+    def test_dockerfile_enables_trivy(self) -> None:
+        profile = RepoProfile(1, 10, (("Dockerfile", 1),), (), is_git_repo=False)
+        assert "trivy" in propose_tools(profile)
+
+
+class TestRenderConfig:
+    def test_emitted_config_is_valid_toml(self, tmp_path: Path) -> None:
+        profile = profile_repo(_make_repo(tmp_path))
+        tools = propose_tools(profile)
+        config = tomllib.loads(render_config(profile, tools))
+        assert config["tools"]["enabled"] == list(tools)
+        assert config["limits"]["max_memory_mb"] == 4096
+        assert config["gates"]["no_fixable_criticals"]["enabled"] is True
+
+    def test_large_repo_gets_larger_caps(self) -> None:
+        profile = RepoProfile(50_000, 500 * 1024 * 1024, (("C#", 40_000),), (), True)
+        config = tomllib.loads(render_config(profile, propose_tools(profile)))
+        assert config["limits"]["max_memory_mb"] == 8192
+
+    def test_detected_facts_in_comments(self, tmp_path: Path) -> None:
+        profile = profile_repo(_make_repo(tmp_path))
+        text = render_config(profile, propose_tools(profile))
+        assert "# Languages: Python (2), Shell (1)" in text
+        assert ".pre-commit-config.yaml" in text
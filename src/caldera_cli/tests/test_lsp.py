@@ -0,0 +1,211 @@
+"""Tests for the LSP server.
+
+Drives LSPServer.handle() with protocol messages against a seeded DuckDB
+database, collecting server-initiated notifications via an injected send.
+"""
+
+from __future__ import annotations
+
+import io
+import json
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.lsp_server import LSPServer, read_message, write_message
+
+
+@pytest.fixture
+def db_path(tmp_path: Path) -> Path:
+    path = tmp_path / "test.duckdb"
+    conn = duckdb.connect(str(path))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    pks = {}
+    for tool_name in ("bandit", "lizard", "pmd-cpd"):
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool_name}", tool_name, "a" * 40, datetime(2026, 8, 1)],
+        )
+        pks[tool_name] = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = ?", [tool_name]
+        ).fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_bandit_findings
+           (run_pk, file_id, directory_id, relative_path, rule_id, dd_category,
+            severity, confidence, cwe_id, line_start, line_end, column_start, message, code_snippet)
+           VALUES (?, 'f-1', 'd-1', 'src/db.py', 'B608', 'sql_injection',
+                   'HIGH', 'HIGH', 'CWE-89', 2, 2, 0, 'SQL injection', NULL)""",
+        [pks["bandit"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_file_metrics
+           (run_pk, file_id, relative_path, language, nloc, function_count, total_ccn, avg_ccn, max_ccn)
+           VALUES (?, 'f-1', 'src/db.py', 'Python', 50, 1, 14, 14.0, 14)""",
+        [pks["lizard"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_function_metrics
+           (run_pk, file_id, function_name, long_name, ccn, nloc, params,
+            token_count, line_start, line_end, max_nesting_depth)
+           VALUES (?, 'f-1', 'run_query', 'run_query(sql)', 14, 40, 1, 200, 4, 44, 3)""",
+        [pks["lizard"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_duplications
+           (run_pk, clone_id, lines, tokens, occurrence_count, is_cross_file, code_fragment)
+           VALUES (?, 'clone-1', 10, 80, 2, TRUE, NULL)""",
+        [pks["pmd-cpd"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_occurrences
+           (run_pk, clone_id, file_id, directory_id, relative_path, line_start, line_end)
+           VALUES (?, 'clone-1', 'f-1', 'd-1', 'src/db.py', 6, 16),
+                  (?, 'clone-1', 'f-2', 'd-1', 'src/legacy_db.py', 1, 11)""",
+        [pks["pmd-cpd"], pks["pmd-cpd"]],
+    )
+    conn.close()
+    return path
+
+
+@pytest.fixture
+def lsp(db_path: Path, tmp_path: Path):
+    sent: list[dict] = []
+    server = LSPServer(db_path, send=sent.append)
+    server.handle({
+        "jsonrpc": "2.0", "id": 1, "method": "initialize",
+        "params": {"rootUri": tmp_path.as_uri()},
+    })
+    return server, sent, tmp_path
+
+
+def _open(server: LSPServer, root: Path, relative_path: str, text: str) -> str:
+    uri = (root / relative_path).as_uri()
+    server.handle({
+        "jsonrpc": "2.0", "method": "textDocument/didOpen",
+        "params": {"textDocument": {"uri": uri, "text": text}},
+    })
+    return uri
+
+
+def test_initialize_advertises_capabilities(db_path: Path, tmp_path: Path) -> None:
+    server = LSPServer(db_path, send=lambda m: None)
+    response = server.handle({
+        "jsonrpc": "2.0", "id": 1, "method": "initialize",
+        "params": {"rootUri": tmp_path.as_uri()},
+    })
+    capabilities = response["result"]["capabilities"]
+    assert capabilities["codeActionProvider"] is True
+    assert "caldera.showDuplicatePartner" in capabilities["executeCommandProvider"]["commands"]
+
+
+def test_did_open_publishes_diagnostics(lsp) -> None:
+    server, sent, root = lsp
+    _open(server, root, "src/db.py", "import x\nrun('...')\n")
+    params = sent[-1]["params"]
+    codes = {d["code"] for d in params["diagnostics"]}
+    assert codes == {"B608", "complexity", "duplication"}
+    finding = next(d for d in params["diagnostics"] if d["code"] == "B608")
+    assert finding["severity"] == 1  # HIGH maps to Error
+    assert finding["range"]["start"]["line"] == 1  # 1-based -> 0-based
+
+
+def test_file_outside_workspace_gets_no_diagnostics(lsp) -> None:
+    server, sent, _ = lsp
+    server.handle({
+        "jsonrpc": "2.0", "method": "textDocument/didOpen",
+        "params": {"textDocument": {"uri": "file:///elsewhere/db.py", "text": ""}},
+    })
+    assert sent[-1]["params"]["diagnostics"] == []
+
+
+def test_suppress_marker_filters_diagnostic(lsp) -> None:
+    server, sent, root = lsp
+    uri = _open(server, root, "src/db.py", "import x\nrun('...')  # caldera:suppress B608\n")
+    assert "B608" not in {d["code"] for d in sent[-1]["params"]["diagnostics"]}
+    # Bare marker suppresses everything on the line.
+    server.handle({
+        "jsonrpc": "2.0", "method": "textDocument/didChange",
+        "params": {
+            "textDocument": {"uri": uri},
+            "contentChanges": [{"text": "import x\nrun('...')  # caldera:suppress\n"}],
+        },
+    })
+    assert "B608" not in {d["code"] for d in sent[-1]["params"]["diagnostics"]}
+
+
+def test_did_close_clears_diagnostics(lsp) -> None:
+    server, sent, root = lsp
+    uri = _open(server, root, "src/db.py", "import x\n")
+    server.handle({
+        "jsonrpc": "2.0", "method": "textDocument/didClose",
+        "params": {"textDocument": {"uri": uri}},
+    })
+    assert sent[-1]["params"] == {"uri": uri, "diagnostics": []}
+
+
+def test_suppress_code_action_appends_marker(lsp) -> None:
+    server, sent, root = lsp
+    uri = _open(server, root, "src/db.py", "import x\nrun('...')\n")
+    diagnostic = next(d for d in sent[-1]["params"]["diagnostics"] if d["code"] == "B608")
+    response = server.handle({
+        "jsonrpc": "2.0", "id": 2, "method": "textDocument/codeAction",
+        "params": {"textDocument": {"uri": uri}, "range": diagnostic["range"],
+                   "context": {"diagnostics": [diagnostic]}},
+    })
+    suppress = next(a for a in response["result"] if a["title"].startswith("Suppress"))
+    edit = suppress["edit"]["changes"][uri][0]
+    assert edit["newText"] == "  # caldera:suppress B608"
+    assert edit["range"]["start"] == {"line": 1, "character": len("run('...')")}
+
+
+def test_show_partner_action_and_command(lsp) -> None:
+    server, sent, root = lsp
+    uri = _open(server, root, "src/db.py", "import x\n")
+    diagnostic = next(d for d in sent[-1]["params"]["diagnostics"] if d["code"] == "duplication")
+    response = server.handle({
+        "jsonrpc": "2.0", "id": 3, "method": "textDocument/codeAction",
+        "params": {"textDocument": {"uri": uri}, "range": diagnostic["range"],
+                   "context": {"diagnostics": [diagnostic]}},
+    })
+    partner_action = next(a for a in response["result"] if "partner" in a["title"])
+    assert "src/legacy_db.py" in partner_action["title"]
+    server.handle({
+        "jsonrpc": "2.0", "id": 4, "method": "workspace/executeCommand",
+        "params": {"command": "caldera.showDuplicatePartner",
+                   "arguments": partner_action["command"]["arguments"]},
+    })
+    show = sent[-1]
+    assert show["method"] == "window/showDocument"
+    assert show["params"]["uri"].endswith("src/legacy_db.py")
+    assert show["params"]["selection"]["start"]["line"] == 0
+
+
+def test_shutdown_and_exit(lsp) -> None:
+    server, _, _ = lsp
+    assert server.handle({"jsonrpc": "2.0", "id": 9, "method": "shutdown"})["result"] is None
+    server.handle({"jsonrpc": "2.0", "method": "exit"})
+    assert server.exited
+
+
+def test_message_framing_round_trip() -> None:
+    stream = io.BytesIO()
+    write_message(stream, {"jsonrpc": "2.0", "id": 1, "method": "ping"})
+    stream.seek(0)
+    assert read_message(stream) == {"jsonrpc": "2.0", "id": 1, "method": "ping"}
+    assert read_message(stream) is None
@@ -0,0 +1,62 @@
+"""Tests for CLI argument parsing and dispatch."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.main import build_parser
+
+
+def test_eval_bench_parses_required_args() -> None:
+    parser = build_parser()
+    args = parser.parse_args(["eval", "bench", "--tool", "semgrep", "--versions", "1.60,1.70"])
+
+    assert args.group == "eval"
+    assert args.command == "bench"
+    assert args.tool == "semgrep"
+    assert args.versions == "1.60,1.70"
+    assert callable(args.handler)
+
+
+def test_eval_bench_requires_tool() -> None:
+    parser = build_parser()
+    with pytest.raises(SystemExit):
+        parser.parse_args(["eval", "bench", "--versions", "1.60"])
+
+
+def test_unknown_group_exits() -> None:
+    parser = build_parser()
+    with pytest.raises(SystemExit):
+        parser.parse_args(["nope"])
+
+
+def test_scan_parses_and_builds_orchestrator_argv() -> None:
+    from caldera_cli.commands.scan import build_orchestrator_argv
+
+    parser = build_parser()
+    args = parser.parse_args(
+        ["scan", "--repo-path", "/tmp/repo", "--repo-id", "demo", "--resume", "run-7"]
+    )
+    assert args.group == "scan"
+    assert callable(args.handler)
+
+    argv = build_orchestrator_argv(args)
+    assert "--run-tools" in argv
+    assert "--resume" in argv
+    assert argv[argv.index("--run-id") + 1] == "run-7"
+
+
+def test_scan_without_resume_omits_resume_flags() -> None:
+    from caldera_cli.commands.scan import build_orchestrator_argv
+
+    parser = build_parser()
+    args = parser.parse_args(["scan", "--repo-path", "/tmp/repo", "--repo-id", "demo"])
+    argv = build_orchestrator_argv(args)
+    assert "--resume" not in argv
+    assert "--run-id" not in argv
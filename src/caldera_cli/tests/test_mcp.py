@@ -0,0 +1,179 @@
+"""Tests for the MCP server.
+
+Drives MCPServer.handle() directly with JSON-RPC messages against a
+seeded DuckDB database — no stdio plumbing needed.
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.mcp_server import MCPServer
+
+
+@pytest.fixture
+def db_path(tmp_path: Path) -> Path:
+    path = tmp_path / "test.duckdb"
+    conn = duckdb.connect(str(path))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    pks = {}
+    for tool_name, version in [("bandit", "1.7.9"), ("lizard", "1.17.10"), ("pmd-cpd", "7.0.0")]:
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, ?, '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool_name}", tool_name, version, "a" * 40, datetime(2026, 8, 1)],
+        )
+        pks[tool_name] = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = ?", [tool_name]
+        ).fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_bandit_findings
+           (run_pk, file_id, directory_id, relative_path, rule_id, dd_category,
+            severity, confidence, cwe_id, line_start, line_end, column_start, message, code_snippet)
+           VALUES (?, 'f-1', 'd-1', 'src/db.py', 'B608', 'sql_injection',
+                   'MEDIUM', 'HIGH', 'CWE-89', 12, 12, 4, 'SQLi', NULL)""",
+        [pks["bandit"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_file_metrics
+           (run_pk, file_id, relative_path, language, nloc, function_count, total_ccn, avg_ccn, max_ccn)
+           VALUES (?, 'f-1', 'src/db.py', 'Python', 80, 2, 18, 9.0, 14)""",
+        [pks["lizard"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_function_metrics
+           (run_pk, file_id, function_name, long_name, ccn, nloc, params,
+            token_count, line_start, line_end, max_nesting_depth)
+           VALUES (?, 'f-1', 'run_query', 'run_query(sql, params)', 14, 60, 2, 300, 10, 70, 4),
+                  (?, 'f-1', 'connect', 'connect(path)', 4, 20, 1, 80, 72, 92, 2)""",
+        [pks["lizard"], pks["lizard"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_duplications
+           (run_pk, clone_id, lines, tokens, occurrence_count, is_cross_file, code_fragment)
+           VALUES (?, 'clone-1', 20, 150, 2, TRUE, NULL)""",
+        [pks["pmd-cpd"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_occurrences
+           (run_pk, clone_id, file_id, directory_id, relative_path, line_start, line_end)
+           VALUES (?, 'clone-1', 'f-1', 'd-1', 'src/db.py', 10, 30),
+                  (?, 'clone-1', 'f-2', 'd-1', 'src/legacy_db.py', 5, 25)""",
+        [pks["pmd-cpd"], pks["pmd-cpd"]],
+    )
+    conn.close()
+    return path
+
+
+@pytest.fixture
+def server(db_path: Path) -> MCPServer:
+    return MCPServer(db_path)
+
+
+def _call(server: MCPServer, tool: str, arguments: dict) -> dict:
+    response = server.handle({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": tool, "arguments": arguments},
+    })
+    assert "error" not in response, response
+    return json.loads(response["result"]["content"][0]["text"])
+
+
+def test_initialize_handshake(server: MCPServer) -> None:
+    response = server.handle({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}})
+    assert response["result"]["serverInfo"]["name"] == "caldera"
+    assert "tools" in response["result"]["capabilities"]
+
+
+def test_notifications_get_no_reply(server: MCPServer) -> None:
+    assert server.handle({"jsonrpc": "2.0", "method": "notifications/initialized"}) is None
+
+
+def test_tools_list(server: MCPServer) -> None:
+    response = server.handle({"jsonrpc": "2.0", "id": 2, "method": "tools/list"})
+    names = [tool["name"] for tool in response["result"]["tools"]]
+    assert names == ["get_findings", "get_function_metrics", "get_duplicates_of"]
+
+
+def test_unknown_method_is_jsonrpc_error(server: MCPServer) -> None:
+    response = server.handle({"jsonrpc": "2.0", "id": 3, "method": "resources/list"})
+    assert response["error"]["code"] == -32601
+
+
+def test_get_findings_by_path(server: MCPServer) -> None:
+    result = _call(server, "get_findings", {"path": "src/db.py"})
+    assert result["count"] == 1
+    assert result["findings"][0]["tool"] == "bandit"
+    assert result["findings"][0]["rule_id"] == "B608"
+
+
+def test_get_findings_severity_filter(server: MCPServer) -> None:
+    result = _call(server, "get_findings", {"path": "src/", "severity": "HIGH"})
+    assert result["count"] == 0
+
+
+def test_get_findings_unknown_tool_is_error(server: MCPServer) -> None:
+    response = server.handle({
+        "jsonrpc": "2.0", "id": 4, "method": "tools/call",
+        "params": {"name": "get_findings", "arguments": {"path": "src/", "tool": "nope"}},
+    })
+    assert response["error"]["code"] == -32602
+    assert "unknown tool" in response["error"]["message"]
+
+
+def test_get_function_metrics_by_symbol(server: MCPServer) -> None:
+    result = _call(server, "get_function_metrics", {"symbol": "run_query"})
+    assert result["count"] == 1
+    assert result["functions"][0]["ccn"] == 14
+    assert result["functions"][0]["relative_path"] == "src/db.py"
+
+
+def test_get_function_metrics_sorted_by_ccn(server: MCPServer) -> None:
+    result = _call(server, "get_function_metrics", {"path": "src/"})
+    assert [fn["function_name"] for fn in result["functions"]] == ["run_query", "connect"]
+
+
+def test_get_function_metrics_requires_a_filter(server: MCPServer) -> None:
+    response = server.handle({
+        "jsonrpc": "2.0", "id": 5, "method": "tools/call",
+        "params": {"name": "get_function_metrics", "arguments": {}},
+    })
+    assert response["error"]["code"] == -32602
+
+
+def test_get_duplicates_of_span(server: MCPServer) -> None:
+    result = _call(server, "get_duplicates_of", {"path": "src/db.py", "line_start": 15, "line_end": 20})
+    assert len(result["clones"]) == 1
+    clone = result["clones"][0]
+    assert clone["occurrence_count"] == 2
+    assert {occ["relative_path"] for occ in clone["occurrences"]} == {"src/db.py", "src/legacy_db.py"}
+
+
+def test_get_duplicates_of_non_overlapping_span(server: MCPServer) -> None:
+    result = _call(server, "get_duplicates_of", {"path": "src/db.py", "line_start": 100, "line_end": 120})
+    assert result["clones"] == []
+
+
+def test_repo_pinning_hides_other_repos(db_path: Path) -> None:
+    result = MCPServer(db_path, repo_id="repo-zzz").get_findings("src/")
+    assert result["count"] == 0
@@ -0,0 +1,82 @@
+"""Tests for the Prometheus metrics renderer and /metrics endpoint wiring."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import duckdb
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.metrics import collect_scan_metrics, render_metrics
+from caldera_cli.server import ScanJob
+
+
+def _seeded_conn() -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(":memory:")
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', repeat('a', 40),
+            TIMESTAMP '2026-08-01 10:00:00', TIMESTAMP '2026-08-01 10:05:00', 'completed'),
+           ('run-2', 'repo-b', 'run-2', 'main', repeat('b', 40),
+            TIMESTAMP '2026-08-02 10:00:00', NULL, 'failed')"""
+    )
+    conn.execute(
+        """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+               tool_version, schema_version, branch, commit, timestamp)
+           VALUES ('run-1', 'repo-a', 'run-1', 'scc', '3.1.0', '1.0.0', 'main',
+                   repeat('a', 40), TIMESTAMP '2026-08-01 10:00:00')"""
+    )
+    return conn
+
+
+def test_render_metrics_includes_run_counts_by_status() -> None:
+    conn = _seeded_conn()
+    body = render_metrics(conn, [])
+    conn.close()
+
+    assert '# TYPE caldera_collection_runs_total gauge' in body
+    assert 'caldera_collection_runs_total{status="completed"} 1' in body
+    assert 'caldera_collection_runs_total{status="failed"} 1' in body
+    assert 'caldera_tool_runs_total{tool="scc"} 1' in body
+
+
+def test_render_metrics_includes_findings_totals() -> None:
+    conn = _seeded_conn()
+    body = render_metrics(conn, [])
+    conn.close()
+
+    assert 'caldera_findings_total{tool="bandit"} 0' in body
+    assert 'caldera_findings_total{tool="semgrep"} 0' in body
+
+
+def test_scan_metrics_count_jobs_and_durations() -> None:
+    done = ScanJob(
+        scan_id="s1", repo_path="/r", repo_id="a", branch="main", commit="0" * 40,
+        status="completed",
+        started_at="2026-08-26T10:00:00+00:00",
+        finished_at="2026-08-26T10:01:30+00:00",
+    )
+    running = ScanJob(
+        scan_id="s2", repo_path="/r", repo_id="b", branch="main", commit="0" * 40,
+        started_at="2026-08-26T10:02:00+00:00",
+    )
+    lines = collect_scan_metrics([done, running])
+    body = "\n".join(lines)
+
+    assert 'caldera_api_scans_total{status="completed"} 1' in body
+    assert 'caldera_api_scans_total{status="running"} 1' in body
+    assert "caldera_api_scan_duration_seconds_count 1" in body
+    assert "caldera_api_scan_duration_seconds_sum 90" in body
+
+
+def test_render_metrics_without_database_only_reports_scans() -> None:
+    body = render_metrics(None, [])
+    assert "caldera_collection_runs_total" not in body
+    assert "caldera_api_scan_duration_seconds_count 0" in body
@@ -0,0 +1,218 @@
+"""Tests for the findings query language."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.query import (
+    BoolOp,
+    Comparison,
+    Not,
+    QueryError,
+    build_sql,
+    format_grep,
+    format_table,
+    parse_query,
+    run_query,
+)
+
+
+class TestParse:
+    def test_single_comparison(self) -> None:
+        query = parse_query("tool = semgrep")
+        assert query.where == Comparison("tool", "=", "semgrep")
+        assert query.order_by == ()
+        assert query.limit is None
+
+    def test_and_or_precedence(self) -> None:
+        query = parse_query("tool = semgrep OR tool = bandit AND severity = high")
+        assert isinstance(query.where, BoolOp)
+        assert query.where.op == "OR"
+        assert isinstance(query.where.right, BoolOp)
+        assert query.where.right.op == "AND"
+
+    def test_parentheses_override_precedence(self) -> None:
+        query = parse_query("(tool = semgrep OR tool = bandit) AND severity = high")
+        assert isinstance(query.where, BoolOp)
+        assert query.where.op == "AND"
+
+    def test_not_and_quoted_values(self) -> None:
+        query = parse_query("NOT path GLOB 'src/payments/**'")
+        assert query.where == Not(Comparison("path", "GLOB", "src/payments/**"))
+
+    def test_order_by_and_limit(self) -> None:
+        query = parse_query("severity >= high ORDER BY line DESC, path LIMIT 10")
+        assert query.order_by == (("line", True), ("path", False))
+        assert query.limit == 10
+
+    def test_field_aliases(self) -> None:
+        query = parse_query("file = 'a.py' AND rule_id = B101")
+        assert isinstance(query.where, BoolOp)
+        assert query.where.left.field == "path"
+        assert query.where.right.field == "rule"
+
+    def test_keywords_are_case_insensitive(self) -> None:
+        query = parse_query("severity >= high and tool = semgrep order by line limit 5")
+        assert query.limit == 5
+
+    def test_unknown_field_rejected(self) -> None:
+        with pytest.raises(QueryError, match="unknown field"):
+            parse_query("commit = abc")
+
+    def test_empty_query_rejected(self) -> None:
+        with pytest.raises(QueryError, match="empty"):
+            parse_query("   ")
+
+    def test_trailing_garbage_rejected(self) -> None:
+        with pytest.raises(QueryError, match="trailing"):
+            parse_query("tool = semgrep extra")
+
+    def test_missing_close_paren_rejected(self) -> None:
+        with pytest.raises(QueryError, match="parenthesis"):
+            parse_query("(tool = semgrep")
+
+
+class TestSql:
+    def test_values_are_parameterized(self) -> None:
+        sql, params = build_sql(parse_query("message = \"'; DROP TABLE x--\""), "run-1")
+        assert "DROP TABLE" not in sql
+        assert params == ["run-1", "'; DROP TABLE x--"]
+
+    def test_severity_comparison_uses_rank(self) -> None:
+        sql, params = build_sql(parse_query("severity >= high"), "run-1")
+        assert "CASE severity" in sql
+        assert params == ["run-1", 3]
+
+    def test_unknown_severity_rejected(self) -> None:
+        with pytest.raises(QueryError, match="unknown severity"):
+            build_sql(parse_query("severity >= enormous"), "run-1")
+
+    def test_line_requires_number(self) -> None:
+        with pytest.raises(QueryError, match="expects a number"):
+            build_sql(parse_query("line > ten"), "run-1")
+
+    def test_ordering_text_fields_rejected(self) -> None:
+        with pytest.raises(QueryError, match="not valid"):
+            build_sql(parse_query("message > abc"), "run-1")
+
+    def test_limit_appended_as_parameter(self) -> None:
+        sql, params = build_sql(parse_query("tool = semgrep LIMIT 3"), "run-1")
+        assert sql.endswith("LIMIT ?")
+        assert params[-1] == 3
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    pks = {}
+    for tool_name in ("semgrep", "devskim"):
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool_name}", tool_name, "a" * 40, datetime(2026, 8, 1)],
+        )
+        pks[tool_name] = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = ?", [tool_name]
+        ).fetchone()[0]
+    smells = [
+        ("src/payments/charge.py", "python.lang.eval", "HIGH", 10, "eval() detected"),
+        ("src/payments/refund.py", "python.lang.exec", "CRITICAL", 42, "exec() detected"),
+        ("src/util/log.py", "python.lang.print", "LOW", 3, "print statement"),
+    ]
+    for path, rule, severity, line, message in smells:
+        conn.execute(
+            """INSERT INTO lz_semgrep_smells (run_pk, file_id, relative_path, rule_id,
+                   severity, line_start, message)
+               VALUES (?, ?, ?, ?, ?, ?, ?)""",
+            [pks["semgrep"], path, path, rule, severity, line, message],
+        )
+    conn.execute(
+        """INSERT INTO lz_devskim_findings (run_pk, file_id, directory_id, relative_path,
+               rule_id, severity, line_start, message)
+           VALUES (?, 'src/util/net.py', 'src/util', 'src/util/net.py',
+                   'DS137138', 'MEDIUM', 7, 'http url')""",
+        [pks["devskim"]],
+    )
+    yield conn
+    conn.close()
+
+
+class TestRunQuery:
+    def test_severity_threshold_and_tool(self, db: duckdb.DuckDBPyConnection) -> None:
+        rows = run_query(db, parse_query("severity >= high AND tool = semgrep"), "run-1")
+        assert [r["rule"] for r in rows] == ["python.lang.eval", "python.lang.exec"]
+
+    def test_glob_filters_paths(self, db: duckdb.DuckDBPyConnection) -> None:
+        rows = run_query(db, parse_query("path GLOB 'src/payments/**' ORDER BY line"), "run-1")
+        assert [r["line"] for r in rows] == [10, 42]
+
+    def test_union_spans_tools(self, db: duckdb.DuckDBPyConnection) -> None:
+        rows = run_query(db, parse_query("severity = medium OR severity = low"), "run-1")
+        assert {r["tool"] for r in rows} == {"semgrep", "devskim"}
+
+    def test_order_by_severity_is_ranked(self, db: duckdb.DuckDBPyConnection) -> None:
+        rows = run_query(db, parse_query("tool = semgrep ORDER BY severity DESC"), "run-1")
+        assert [r["severity"] for r in rows] == ["CRITICAL", "HIGH", "LOW"]
+
+    def test_limit(self, db: duckdb.DuckDBPyConnection) -> None:
+        rows = run_query(db, parse_query("line > 0 ORDER BY line LIMIT 2"), "run-1")
+        assert len(rows) == 2
+
+    def test_other_run_matches_nothing(self, db: duckdb.DuckDBPyConnection) -> None:
+        assert run_query(db, parse_query("line > 0"), "run-2") == []
+
+
+class TestFormatTable:
+    def test_empty(self) -> None:
+        assert format_table([]) == "No findings matched."
+
+    def test_columns_aligned(self) -> None:
+        rows = [
+            {"tool": "semgrep", "path": "a.py", "rule": "r1", "severity": "HIGH",
+             "line": 1, "message": "m"},
+        ]
+        text = format_table(rows)
+        header, separator, row = text.splitlines()
+        assert header.startswith("tool")
+        assert len(header) == len(separator) == len(row)
+
+
+class TestFormatGrep:
+    def test_one_line_per_finding(self) -> None:
+        rows = [
+            {"tool": "semgrep", "path": "src/a.py", "rule": "r1", "severity": "HIGH",
+             "line": 12, "message": "eval is dangerous"},
+        ]
+        assert format_grep(rows) == "src/a.py:12:1: [semgrep/r1] eval is dangerous"
+
+    def test_missing_line_and_rule_default(self) -> None:
+        rows = [
+            {"tool": "trivy", "path": "Dockerfile", "rule": None, "severity": "HIGH",
+             "line": None, "message": "libssl"},
+        ]
+        assert format_grep(rows) == "Dockerfile:1:1: [trivy/-] libssl"
+
+    def test_multiline_message_collapsed(self) -> None:
+        rows = [
+            {"tool": "semgrep", "path": "a.py", "rule": "r1", "severity": "LOW",
+             "line": 3, "message": "first\nsecond"},
+        ]
+        assert format_grep(rows).splitlines() == ["a.py:3:1: [semgrep/r1] first"]
@@ -0,0 +1,131 @@
+"""Tests for cron parsing and the daemon scan scheduler."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.scheduler import (
+    CronSchedule,
+    ScheduledScan,
+    Scheduler,
+    load_schedule,
+)
+
+SCHEDULE_YAML = """\
+schedule:
+  - repo_id: app
+    repo_path: /repos/app
+    cron: "0 2 * * *"
+  - repo_id: lib
+    repo_path: /repos/lib
+    cron: "*/15 * * * 1-5"
+    branch: develop
+    replace: false
+"""
+
+
+def test_cron_parse_expands_steps_ranges_and_lists() -> None:
+    schedule = CronSchedule.parse("*/15 0,12 1-3 * *")
+    assert schedule.minutes == frozenset({0, 15, 30, 45})
+    assert schedule.hours == frozenset({0, 12})
+    assert schedule.days == frozenset({1, 2, 3})
+    assert schedule.months == frozenset(range(1, 13))
+
+
+def test_cron_weekday_seven_is_sunday() -> None:
+    assert CronSchedule.parse("0 0 * * 7").weekdays == frozenset({0})
+    assert CronSchedule.parse("0 0 * * 5-7").weekdays == frozenset({5, 6, 0})
+
+
+def test_cron_matches_moment() -> None:
+    nightly = CronSchedule.parse("0 2 * * *")
+    assert nightly.matches(datetime(2026, 8, 26, 2, 0))
+    assert not nightly.matches(datetime(2026, 8, 26, 2, 1))
+
+    # 2026-08-26 is a Wednesday (cron weekday 3).
+    weekdays_only = CronSchedule.parse("0 2 * * 1-5")
+    assert weekdays_only.matches(datetime(2026, 8, 26, 2, 0))
+    assert not weekdays_only.matches(datetime(2026, 8, 30, 2, 0))  # Sunday
+
+
+def test_cron_next_after() -> None:
+    nightly = CronSchedule.parse("0 2 * * *")
+    assert nightly.next_after(datetime(2026, 8, 26, 1, 30)) == datetime(2026, 8, 26, 2, 0)
+    assert nightly.next_after(datetime(2026, 8, 26, 2, 0)) == datetime(2026, 8, 27, 2, 0)
+
+
+def test_cron_next_after_impossible_expression_is_none() -> None:
+    # February 31st never happens.
+    assert CronSchedule.parse("0 0 31 2 *").next_after(datetime(2026, 1, 1)) is None
+
+
+def test_cron_rejects_bad_expressions() -> None:
+    with pytest.raises(ValueError, match="5 fields"):
+        CronSchedule.parse("0 2 * *")
+    with pytest.raises(ValueError, match="out of range"):
+        CronSchedule.parse("99 * * * *")
+    with pytest.raises(ValueError, match="out of range"):
+        CronSchedule.parse("0 0 * 13 *")
+
+
+def test_load_schedule_parses_entries(tmp_path: Path) -> None:
+    config = tmp_path / "schedule.yaml"
+    config.write_text(SCHEDULE_YAML)
+
+    entries = load_schedule(config)
+
+    assert entries[0] == ScheduledScan(repo_id="app", repo_path="/repos/app", cron="0 2 * * *")
+    assert entries[1].branch == "develop"
+    assert entries[1].replace is False
+
+
+def test_load_schedule_rejects_duplicates_and_bad_cron(tmp_path: Path) -> None:
+    config = tmp_path / "schedule.yaml"
+    config.write_text(
+        "schedule:\n"
+        "  - {repo_id: app, repo_path: /a, cron: '0 2 * * *'}\n"
+        "  - {repo_id: app, repo_path: /b, cron: '0 3 * * *'}\n"
+    )
+    with pytest.raises(ValueError, match="duplicate repo_id"):
+        load_schedule(config)
+
+    config.write_text("schedule:\n  - {repo_id: app, repo_path: /a, cron: 'nope'}\n")
+    with pytest.raises(ValueError, match="5 fields"):
+        load_schedule(config)
+
+
+def test_scheduler_fires_due_entries_once_per_minute() -> None:
+    entry = ScheduledScan(repo_id="app", repo_path="/repos/app", cron="0 2 * * *")
+    started: list[str] = []
+    scheduler = Scheduler([entry], starter=lambda e: started.append(e.repo_id))
+
+    due = datetime(2026, 8, 26, 2, 0, 15)
+    assert scheduler.tick(due) == [entry]
+    assert scheduler.tick(due.replace(second=45)) == []  # same minute: no refire
+    assert started == ["app"]
+
+    assert scheduler.tick(datetime(2026, 8, 26, 3, 0)) == []  # not due
+
+
+def test_scheduler_status_reports_next_run_and_last_fired() -> None:
+    entry = ScheduledScan(repo_id="app", repo_path="/repos/app", cron="0 2 * * *")
+    scheduler = Scheduler(
+        [entry],
+        starter=lambda e: None,
+        clock=lambda: datetime(2026, 8, 26, 1, 0),
+    )
+
+    status = scheduler.status()
+    assert status[0]["repo_id"] == "app"
+    assert status[0]["last_fired"] is None
+    assert status[0]["next_run"] == "2026-08-26T02:00:00"
+
+    scheduler.tick(datetime(2026, 8, 26, 2, 0))
+    assert scheduler.status()[0]["last_fired"] == "2026-08-26T02:00:00"
@@ -0,0 +1,154 @@
+"""Tests for the HTTP API server.
+
+Uses a live ThreadingHTTPServer on an ephemeral port with a seeded DuckDB
+database and stubbed scan subprocesses.
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+import threading
+import time
+import urllib.error
+import urllib.request
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.server import ScanManager, create_server
+
+
+@pytest.fixture
+def db_path(tmp_path: Path) -> Path:
+    path = tmp_path / "test.duckdb"
+    conn = duckdb.connect(str(path))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    conn.execute(
+        """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+               tool_version, schema_version, branch, commit, timestamp)
+           VALUES ('run-1', 'repo-a', 'run-1', 'bandit', '1.7.9', '1.0.0', 'main', ?, ?)""",
+        ["a" * 40, datetime(2026, 8, 1)],
+    )
+    run_pk = conn.execute(
+        "SELECT run_pk FROM lz_tool_runs WHERE tool_name = 'bandit'"
+    ).fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_bandit_findings
+           (run_pk, file_id, directory_id, relative_path, rule_id, dd_category,
+            severity, confidence, cwe_id, line_start, line_end, column_start, message, code_snippet)
+           VALUES (?, 'f-1', 'd-1', 'src/db.py', 'B608', 'sql_injection',
+                   'MEDIUM', 'HIGH', 'CWE-89', 12, 12, 4, 'SQLi', NULL)""",
+        [run_pk],
+    )
+    conn.close()
+    return path
+
+
+@pytest.fixture
+def api(db_path: Path):
+    server = create_server("127.0.0.1", 0, db_path)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    base = f"http://127.0.0.1:{server.server_address[1]}"
+    yield base
+    server.shutdown()
+    server.server_close()
+
+
+def _get(url: str) -> tuple[int, dict]:
+    try:
+        with urllib.request.urlopen(url) as response:
+            return response.status, json.loads(response.read())
+    except urllib.error.HTTPError as exc:
+        return exc.code, json.loads(exc.read())
+
+
+def test_health(api: str) -> None:
+    status, body = _get(f"{api}/health")
+    assert status == 200
+    assert body == {"status": "ok"}
+
+
+def test_list_runs_with_pagination(api: str) -> None:
+    status, body = _get(f"{api}/runs?limit=10&offset=0")
+    assert status == 200
+    assert body["total"] == 1
+    assert body["runs"][0]["repo_id"] == "repo-a"
+    assert body["runs"][0]["status"] == "completed"
+
+
+def test_list_runs_filters_by_status(api: str) -> None:
+    status, body = _get(f"{api}/runs?status=failed")
+    assert status == 200
+    assert body["total"] == 0
+
+
+def test_findings_query_with_filters(api: str) -> None:
+    status, body = _get(f"{api}/runs/run-1/findings?tool=bandit&severity=medium")
+    assert status == 200
+    assert body["total"] == 1
+    finding = body["findings"][0]
+    assert finding["rule_id"] == "B608"
+    assert finding["relative_path"] == "src/db.py"
+
+    status, body = _get(f"{api}/runs/run-1/findings?tool=bandit&severity=high")
+    assert body["total"] == 0
+
+
+def test_findings_requires_known_tool(api: str) -> None:
+    status, body = _get(f"{api}/runs/run-1/findings?tool=nonexistent")
+    assert status == 422
+    assert "unknown tool" in body["error"]
+
+
+def test_unknown_resource_404(api: str) -> None:
+    status, body = _get(f"{api}/nope")
+    assert status == 404
+
+
+def test_post_scan_validates_body(api: str) -> None:
+    request = urllib.request.Request(
+        f"{api}/scans",
+        data=json.dumps({"repo_path": "/tmp/x"}).encode(),
+        headers={"Content-Type": "application/json"},
+        method="POST",
+    )
+    try:
+        urllib.request.urlopen(request)
+        raise AssertionError("expected 422")
+    except urllib.error.HTTPError as exc:
+        assert exc.code == 422
+
+
+def test_scan_manager_tracks_job_lifecycle(db_path: Path, monkeypatch) -> None:
+    manager = ScanManager(db_path)
+
+    def fake_run(self, job, replace):
+        job.add_event("progress", "working")
+        job.returncode = 0
+        job.status = "completed"
+
+    monkeypatch.setattr(ScanManager, "_run", fake_run)
+    job = manager.start("/tmp/repo", "repo-x", "main", "0" * 40)
+    for _ in range(100):
+        if job.status != "running":
+            break
+        time.sleep(0.01)
+    assert manager.get(job.scan_id) is job
+    snapshot = job.snapshot()
+    assert snapshot["repo_id"] == "repo-x"
+    assert snapshot["status"] == "completed"
@@ -0,0 +1,150 @@
+"""Tests for the refactoring opportunity ranker."""
+
+from __future__ import annotations
+
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.suggest import (
+    RefactoringCandidate,
+    _churn_factor,
+    _score,
+    suggest,
+)
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    pks = {}
+    for tool in ("lizard", "pmd-cpd", "git-blame-scanner"):
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool}", tool, "a" * 40, datetime(2026, 8, 1)],
+        )
+        pks[tool] = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = ?", [tool]
+        ).fetchone()[0]
+
+    # One file with two CCN-20 functions, one file below the threshold.
+    for path, functions in (
+        ("src/engine.py", (("dispatch", 20), ("resolve", 20))),
+        ("src/util.py", (("helper", 3),)),
+    ):
+        conn.execute(
+            """INSERT INTO lz_lizard_file_metrics
+               (run_pk, file_id, relative_path) VALUES (?, ?, ?)""",
+            [pks["lizard"], path, path],
+        )
+        for name, ccn in functions:
+            conn.execute(
+                """INSERT INTO lz_lizard_function_metrics
+                   (run_pk, file_id, function_name, ccn, line_start) VALUES (?, ?, ?, ?, 1)""",
+                [pks["lizard"], path, name, ccn],
+            )
+
+    # A 40-line clone duplicated across two files.
+    conn.execute(
+        """INSERT INTO lz_pmd_cpd_duplications
+           (run_pk, clone_id, lines, tokens, occurrence_count, is_cross_file)
+           VALUES (?, 'clone-1', 40, 200, 2, TRUE)""",
+        [pks["pmd-cpd"]],
+    )
+    for path in ("src/billing.py", "src/invoices.py"):
+        conn.execute(
+            """INSERT INTO lz_pmd_cpd_occurrences
+               (run_pk, clone_id, file_id, directory_id, relative_path, line_start, line_end)
+               VALUES (?, 'clone-1', ?, 'src', ?, 10, 50)""",
+            [pks["pmd-cpd"], path, path],
+        )
+
+    # engine.py churns heavily; the clone files are quiet.
+    for path, churn in (("src/engine.py", 200), ("src/billing.py", 0)):
+        conn.execute(
+            """INSERT INTO lz_git_blame_summary
+               (run_pk, file_id, directory_id, relative_path, total_lines,
+                unique_authors, top_author, top_author_lines, top_author_pct,
+                churn_30d, churn_90d)
+               VALUES (?, ?, 'src', ?, 100, 1, 't', 100, 100.0, 0, ?)""",
+            [pks["git-blame-scanner"], path, path, churn],
+        )
+    yield conn
+    conn.close()
+
+
+class TestScoring:
+    def test_churn_factor_caps(self):
+        assert _churn_factor(0) == 1.0
+        assert _churn_factor(100) == 2.0
+        assert _churn_factor(10_000) == _churn_factor(500)
+
+    def test_blast_radius_dampens_score(self):
+        assert _score(40.0, 4, 0) == 20.0
+        assert _score(40.0, 1, 0) == 40.0
+
+
+class TestSuggest:
+    def test_clone_candidate_payoff_and_blast_radius(self, db) -> None:
+        clone = next(c for c in suggest(db, "run-1") if c.kind == "extract-helper")
+
+        # 40 lines x (2 copies - 1) x 0.5 min.
+        assert clone.payoff_minutes == 20.0
+        assert clone.blast_radius == 2
+        assert clone.files == ("src/billing.py", "src/invoices.py")
+
+    def test_hotspot_candidate_prices_each_function(self, db) -> None:
+        hotspot = next(c for c in suggest(db, "run-1") if c.kind == "decompose-hotspot")
+
+        assert hotspot.target == "src/engine.py"
+        assert hotspot.payoff_minutes == 40.0
+        assert "dispatch" in hotspot.description
+
+    def test_below_threshold_file_not_suggested(self, db) -> None:
+        assert all(c.target != "src/util.py" for c in suggest(db, "run-1"))
+
+    def test_churn_ranks_hot_file_first(self, db) -> None:
+        candidates = suggest(db, "run-1")
+
+        # Hotspot: 40 min x 3.0 churn factor; clone: 20 min / sqrt(2).
+        assert candidates[0].kind == "decompose-hotspot"
+        assert candidates[0].score > candidates[1].score
+
+    def test_min_ccn_threshold_respected(self, db) -> None:
+        kinds = {c.kind for c in suggest(db, "run-1", min_ccn=25)}
+        assert "decompose-hotspot" not in kinds
+
+    def test_unknown_run_yields_nothing(self, db) -> None:
+        assert suggest(db, "run-404") == []
+
+
+class TestRefactoringCandidate:
+    def test_rejects_unknown_kind(self):
+        with pytest.raises(ValueError, match="kind"):
+            RefactoringCandidate(
+                kind="rewrite-everything",
+                target="x",
+                description="",
+                files=("a.py",),
+                payoff_minutes=1.0,
+                blast_radius=1,
+                churn_90d=0,
+                score=1.0,
+            )
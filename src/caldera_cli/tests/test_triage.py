@@ -0,0 +1,147 @@
+"""Tests for interactive triage and persisted decisions."""
+
+from __future__ import annotations
+
+import json
+import sys
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from caldera_cli.triage import (
+    TriageDecision,
+    ensure_triage_table,
+    load_decisions,
+    record_decision,
+    triage_findings,
+    undecided_findings,
+    write_baseline,
+    write_suppressions,
+)
+
+
+@pytest.fixture
+def db(tmp_path: Path) -> duckdb.DuckDBPyConnection:
+    conn = duckdb.connect(str(tmp_path / "test.duckdb"))
+    schema_sql = (
+        Path(__file__).parent.parent.parent / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    conn.execute(
+        """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+               tool_version, schema_version, branch, commit, timestamp)
+           VALUES ('run-1', 'repo-a', 'run-1-semgrep', 'semgrep', '1.0', '1.0.0', 'main', ?, ?)""",
+        ["a" * 40, datetime(2026, 8, 1)],
+    )
+    pk = conn.execute("SELECT run_pk FROM lz_tool_runs").fetchone()[0]
+    for path, rule, line in (("src/a.py", "rule-1", 3), ("src/b.py", "rule-2", 7)):
+        conn.execute(
+            """INSERT INTO lz_semgrep_smells (run_pk, file_id, relative_path, rule_id,
+                   severity, line_start, message)
+               VALUES (?, ?, ?, ?, 'HIGH', ?, 'smelly')""",
+            [pk, path, path, rule, line],
+        )
+    yield conn
+    conn.close()
+
+
+def _decision(fp: str, decision: str, reason: str | None = None) -> TriageDecision:
+    return TriageDecision(fp, decision, reason, "semgrep", "rule-1", "src/a.py", 3)
+
+
+class TestPersistence:
+    def test_record_and_load_round_trip(self, db: duckdb.DuckDBPyConnection) -> None:
+        ensure_triage_table(db)
+        record_decision(db, _decision("fp-1", "false-positive", "test fixture"))
+        decisions = load_decisions(db)
+        assert decisions["fp-1"].decision == "false-positive"
+        assert decisions["fp-1"].reason == "test fixture"
+
+    def test_retriage_replaces_decision(self, db: duckdb.DuckDBPyConnection) -> None:
+        ensure_triage_table(db)
+        record_decision(db, _decision("fp-1", "fix-later"))
+        record_decision(db, _decision("fp-1", "wont-fix"))
+        assert load_decisions(db)["fp-1"].decision == "wont-fix"
+
+    def test_unknown_decision_rejected(self, db: duckdb.DuckDBPyConnection) -> None:
+        ensure_triage_table(db)
+        with pytest.raises(ValueError, match="unknown decision"):
+            record_decision(db, _decision("fp-1", "maybe"))
+
+
+class TestUndecidedFindings:
+    def test_all_findings_pending_initially(self, db: duckdb.DuckDBPyConnection) -> None:
+        ensure_triage_table(db)
+        findings = undecided_findings(db, "run-1")
+        assert [f["rule"] for f in findings] == ["rule-1", "rule-2"]
+        assert all(f["fingerprint"] for f in findings)
+
+    def test_decided_findings_excluded(self, db: duckdb.DuckDBPyConnection) -> None:
+        ensure_triage_table(db)
+        first = undecided_findings(db, "run-1")[0]
+        record_decision(db, _decision(first["fingerprint"], "fix-later"))
+        remaining = undecided_findings(db, "run-1")
+        assert [f["rule"] for f in remaining] == ["rule-2"]
+
+
+class TestTriageLoop:
+    def _findings(self) -> list[dict]:
+        return [
+            {"fingerprint": f"fp-{n}", "tool": "semgrep", "path": f"src/{n}.py",
+             "rule": f"rule-{n}", "severity": "HIGH", "line": n, "message": "smelly"}
+            for n in (1, 2, 3)
+        ]
+
+    def test_decisions_recorded_per_key(self) -> None:
+        answers = iter(["f", "p", "not a real bug", "w"])
+        decisions = triage_findings(self._findings(), input_fn=lambda _: next(answers), print_fn=lambda _: None)
+        assert [d.decision for d in decisions] == ["fix-later", "false-positive", "wont-fix"]
+        assert decisions[1].reason == "not a real bug"
+
+    def test_skip_leaves_finding_undecided(self) -> None:
+        answers = iter(["s", "f"])
+        decisions = triage_findings(self._findings()[:2], input_fn=lambda _: next(answers), print_fn=lambda _: None)
+        assert [d.fingerprint for d in decisions] == ["fp-2"]
+
+    def test_quit_keeps_earlier_decisions(self) -> None:
+        answers = iter(["f", "q"])
+        decisions = triage_findings(self._findings(), input_fn=lambda _: next(answers), print_fn=lambda _: None)
+        assert [d.fingerprint for d in decisions] == ["fp-1"]
+
+    def test_unrecognized_key_reprompts(self) -> None:
+        answers = iter(["x", "f"])
+        decisions = triage_findings(self._findings()[:1], input_fn=lambda _: next(answers), print_fn=lambda _: None)
+        assert len(decisions) == 1
+
+
+class TestEmittedFiles:
+    DECISIONS = {
+        "fp-1": TriageDecision("fp-1", "fix-later", None, "semgrep", "r1", "a.py", 1),
+        "fp-2": TriageDecision("fp-2", "false-positive", "fixture", "semgrep", "r2", "b.py", 2),
+        "fp-3": TriageDecision("fp-3", "wont-fix", None, "semgrep", "r3", "c.py", 3),
+    }
+
+    def test_baseline_contains_fix_later_and_wont_fix(self, tmp_path: Path) -> None:
+        path = tmp_path / "baseline.json"
+        count = write_baseline(path, self.DECISIONS)
+        assert count == 2
+        data = json.loads(path.read_text())
+        assert [e["fingerprint"] for e in data["findings"]] == ["fp-1", "fp-3"]
+
+    def test_suppressions_contain_false_positives_with_reason(self, tmp_path: Path) -> None:
+        path = tmp_path / "suppressions.json"
+        count = write_suppressions(path, self.DECISIONS)
+        assert count == 1
+        data = json.loads(path.read_text())
+        assert data["suppressions"][0]["fingerprint"] == "fp-2"
+        assert data["suppressions"][0]["reason"] == "fixture"
@@ -0,0 +1,206 @@
+"""Interactive triage of findings with persisted decisions.
+
+``caldera triage`` steps through a run's undecided findings one at a
+time and records a decision for each: ``fix-later`` (acknowledged, goes
+into the baseline so it stops counting as new), ``false-positive``
+(suppressed, with the reason recorded), or ``wont-fix`` (accepted risk,
+baselined). Decisions are keyed by the finding's fingerprint (the
+``caldera explain`` formula) and persisted in the landing zone, so a
+finding is only ever triaged once no matter how many scans follow; the
+baseline and suppression files are regenerated from the store after
+every session for CI and ``insights notify-findings`` to consume.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Callable
+
+import duckdb
+
+from caldera_cli.explain import fingerprint
+from caldera_cli.query import _unified_view_sql
+
+DECISIONS = ("fix-later", "false-positive", "wont-fix")
+
+# Created on demand so triage works against databases predating the table.
+_TRIAGE_TABLE_SQL = """
+CREATE TABLE IF NOT EXISTS lz_triage_decisions (
+    fingerprint VARCHAR NOT NULL,
+    decision VARCHAR NOT NULL,
+    reason VARCHAR,
+    tool VARCHAR,
+    rule_id VARCHAR,
+    relative_path VARCHAR,
+    line INTEGER,
+    decided_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (fingerprint)
+)
+"""
+
+_PROMPT = "[f]ix-later / false-[p]ositive / [w]ont-fix / [s]kip / [q]uit: "
+
+_KEY_TO_DECISION = {"f": "fix-later", "p": "false-positive", "w": "wont-fix"}
+
+
+@dataclass(frozen=True)
+class TriageDecision:
+    """One persisted triage decision."""
+
+    fingerprint: str
+    decision: str
+    reason: str | None
+    tool: str
+    rule_id: str
+    relative_path: str
+    line: int | None
+
+
+def ensure_triage_table(conn: duckdb.DuckDBPyConnection) -> None:
+    conn.execute(_TRIAGE_TABLE_SQL)
+
+
+def load_decisions(conn: duckdb.DuckDBPyConnection) -> dict[str, TriageDecision]:
+    """All persisted decisions, keyed by fingerprint."""
+    rows = conn.execute(
+        """SELECT fingerprint, decision, reason, tool, rule_id, relative_path, line
+           FROM lz_triage_decisions"""
+    ).fetchall()
+    return {row[0]: TriageDecision(*row) for row in rows}
+
+
+def record_decision(conn: duckdb.DuckDBPyConnection, decision: TriageDecision) -> None:
+    """Persist one decision; re-triaging a fingerprint replaces the old one."""
+    if decision.decision not in DECISIONS:
+        raise ValueError(f"unknown decision {decision.decision!r} (one of: {', '.join(DECISIONS)})")
+    conn.execute("DELETE FROM lz_triage_decisions WHERE fingerprint = ?", [decision.fingerprint])
+    conn.execute(
+        "INSERT INTO lz_triage_decisions VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        [
+            decision.fingerprint,
+            decision.decision,
+            decision.reason,
+            decision.tool,
+            decision.rule_id,
+            decision.relative_path,
+            decision.line,
+            datetime.now(timezone.utc),
+        ],
+    )
+
+
+def undecided_findings(
+    conn: duckdb.DuckDBPyConnection, collection_run_id: str
+) -> list[dict]:
+    """A run's findings without a persisted decision, fingerprinted."""
+    rows = conn.execute(
+        f"""SELECT tool, path, rule, severity, line, message
+            FROM ({_unified_view_sql()}) findings
+            WHERE collection_run_id = ?
+            ORDER BY path, line""",
+        [collection_run_id],
+    ).fetchall()
+    decided = set(load_decisions(conn))
+    findings = []
+    for tool, path, rule, severity, line, message in rows:
+        fp = fingerprint(tool, rule or "", path, line)
+        if fp in decided:
+            continue
+        findings.append(
+            {
+                "fingerprint": fp,
+                "tool": tool,
+                "path": path,
+                "rule": rule,
+                "severity": severity,
+                "line": line,
+                "message": message,
+            }
+        )
+    return findings
+
+
+def triage_findings(
+    findings: list[dict],
+    input_fn: Callable[[str], str] = input,
+    print_fn: Callable[[str], None] = print,
+) -> list[TriageDecision]:
+    """Step through findings interactively; returns the decisions taken.
+
+    ``s`` skips a finding (it comes back next session), ``q`` ends the
+    session keeping decisions made so far.
+    """
+    decisions: list[TriageDecision] = []
+    total = len(findings)
+    for index, finding in enumerate(findings, start=1):
+        location = f"{finding['path']}:{finding['line']}" if finding["line"] else finding["path"]
+        print_fn(
+            f"[{index}/{total}] {finding['severity'] or '?'} {finding['tool']} "
+            f"{finding['rule']} at {location}"
+        )
+        if finding["message"]:
+            print_fn(f"    {finding['message']}")
+        while True:
+            key = input_fn(_PROMPT).strip().lower()
+            if key == "q":
+                return decisions
+            if key == "s":
+                break
+            if key in _KEY_TO_DECISION:
+                decision = _KEY_TO_DECISION[key]
+                reason = None
+                if decision == "false-positive":
+                    reason = input_fn("reason: ").strip() or None
+                decisions.append(
+                    TriageDecision(
+                        finding["fingerprint"],
+                        decision,
+                        reason,
+                        finding["tool"],
+                        finding["rule"],
+                        finding["path"],
+                        finding["line"],
+                    )
+                )
+                break
+            print_fn(f"unrecognized choice {key!r}")
+    return decisions
+
+
+def write_baseline(path: Path, decisions: dict[str, TriageDecision]) -> int:
+    """Emit fix-later and wont-fix decisions as the findings baseline."""
+    entries = [
+        {
+            "fingerprint": decision.fingerprint,
+            "decision": decision.decision,
+            "tool": decision.tool,
+            "rule_id": decision.rule_id,
+            "relative_path": decision.relative_path,
+            "line": decision.line,
+        }
+        for decision in sorted(decisions.values(), key=lambda d: d.fingerprint)
+        if decision.decision in ("fix-later", "wont-fix")
+    ]
+    path.write_text(json.dumps({"version": 1, "findings": entries}, indent=2))
+    return len(entries)
+
+
+def write_suppressions(path: Path, decisions: dict[str, TriageDecision]) -> int:
+    """Emit false-positive decisions, with reasons, as the suppression file."""
+    entries = [
+        {
+            "fingerprint": decision.fingerprint,
+            "tool": decision.tool,
+            "rule_id": decision.rule_id,
+            "relative_path": decision.relative_path,
+            "line": decision.line,
+            "reason": decision.reason,
+        }
+        for decision in sorted(decisions.values(), key=lambda d: d.fingerprint)
+        if decision.decision == "false-positive"
+    ]
+    path.write_text(json.dumps({"version": 1, "suppressions": entries}, indent=2))
+    return len(entries)
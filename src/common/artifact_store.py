@@ -0,0 +1,204 @@
+"""
+Artifact Store - Shared module for uploading run artifacts to object storage.
+
+Scan results, HTML reports, and SARIF exports currently live only as CI
+artifacts and expire with the CI retention window. This module uploads
+them to durable object storage instead. Keys are content-addressed
+(``<prefix>/<repo_id>/<sha256[:2]>/<sha256>/<filename>``) so identical
+artifacts dedupe and uploads are idempotent; a retention tag on every
+object lets bucket lifecycle rules do the expiring.
+
+Backends are selected from the store URL:
+
+- ``s3://bucket/prefix``  — via the ``aws`` CLI
+- ``gs://bucket/prefix``  — via the ``gsutil`` CLI
+- ``file:///path`` or a plain directory — local filesystem (dev/tests)
+
+The CLI runner is injectable so backends are testable without cloud
+credentials.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import shutil
+import subprocess
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+
+RETENTION_TAG_KEY = "caldera-retention"
+DEFAULT_RETENTION = "90d"
+UPLOAD_TIMEOUT_SECONDS = 300
+MANIFEST_NAME = "artifact-manifest.json"
+
+
+class ArtifactStoreError(RuntimeError):
+    """An upload failed or the store URL is unusable."""
+
+
+@dataclass(frozen=True)
+class UploadedArtifact:
+    """One uploaded artifact, as recorded in the manifest."""
+
+    file_name: str
+    key: str
+    uri: str
+    sha256: str
+    size_bytes: int
+
+    def to_dict(self) -> dict:
+        return {
+            "file_name": self.file_name,
+            "key": self.key,
+            "uri": self.uri,
+            "sha256": self.sha256,
+            "size_bytes": self.size_bytes,
+        }
+
+
+def file_sha256(path: Path) -> str:
+    digest = hashlib.sha256()
+    with path.open("rb") as handle:
+        for chunk in iter(lambda: handle.read(1 << 20), b""):
+            digest.update(chunk)
+    return digest.hexdigest()
+
+
+def content_key(prefix: str, repo_id: str, sha256: str, file_name: str) -> str:
+    """Content-addressed object key for one artifact."""
+    parts = [part for part in (prefix.strip("/"), repo_id, sha256[:2], sha256, file_name) if part]
+    return "/".join(parts)
+
+
+def _run_cli(command: list[str]) -> None:
+    try:
+        result = subprocess.run(
+            command, capture_output=True, text=True, timeout=UPLOAD_TIMEOUT_SECONDS
+        )
+    except subprocess.TimeoutExpired:
+        raise ArtifactStoreError(f"Upload timed out: {' '.join(command[:3])}")
+    except FileNotFoundError:
+        raise ArtifactStoreError(f"{command[0]} is not installed or not on PATH")
+    if result.returncode != 0:
+        tail = (result.stderr or result.stdout).strip().splitlines()[-3:]
+        raise ArtifactStoreError(f"{command[0]} failed: {' / '.join(tail)}")
+
+
+class LocalStore:
+    """Filesystem store for development and tests."""
+
+    def __init__(self, root: Path) -> None:
+        self._root = root
+
+    def put(self, local_path: Path, key: str, retention: str) -> str:
+        target = self._root / key
+        target.parent.mkdir(parents=True, exist_ok=True)
+        shutil.copy2(local_path, target)
+        # Retention is recorded next to the object; local stores have no
+        # lifecycle rules, this keeps the manifest round-trippable.
+        (target.parent / f"{target.name}.tags").write_text(
+            json.dumps({RETENTION_TAG_KEY: retention})
+        )
+        return target.as_uri()
+
+
+class S3Store:
+    """S3 store driven by the ``aws`` CLI."""
+
+    def __init__(self, bucket: str, runner=_run_cli) -> None:
+        self._bucket = bucket
+        self._runner = runner
+
+    def put(self, local_path: Path, key: str, retention: str) -> str:
+        uri = f"s3://{self._bucket}/{key}"
+        self._runner(["aws", "s3", "cp", "--only-show-errors", str(local_path), uri])
+        # Lifecycle rules match on object tags, so tag after the copy.
+        self._runner([
+            "aws", "s3api", "put-object-tagging",
+            "--bucket", self._bucket,
+            "--key", key,
+            "--tagging", f"TagSet=[{{Key={RETENTION_TAG_KEY},Value={retention}}}]",
+        ])
+        return uri
+
+
+class GCSStore:
+    """GCS store driven by the ``gsutil`` CLI."""
+
+    def __init__(self, bucket: str, runner=_run_cli) -> None:
+        self._bucket = bucket
+        self._runner = runner
+
+    def put(self, local_path: Path, key: str, retention: str) -> str:
+        uri = f"gs://{self._bucket}/{key}"
+        self._runner([
+            "gsutil", "-q",
+            "-h", f"x-goog-meta-{RETENTION_TAG_KEY}:{retention}",
+            "cp", str(local_path), uri,
+        ])
+        return uri
+
+
+def parse_store_url(store_url: str) -> tuple[str, str, str]:
+    """Split a store URL into (scheme, bucket/root, prefix)."""
+    for scheme in ("s3", "gs"):
+        marker = f"{scheme}://"
+        if store_url.startswith(marker):
+            rest = store_url[len(marker):]
+            bucket, _, prefix = rest.partition("/")
+            if not bucket:
+                raise ArtifactStoreError(f"Store URL has no bucket: {store_url}")
+            return scheme, bucket, prefix.strip("/")
+    if store_url.startswith("file://"):
+        return "file", store_url[len("file://"):], ""
+    return "file", store_url, ""
+
+
+def create_store(store_url: str, runner=_run_cli):
+    """Build the backend for a store URL; returns (store, prefix)."""
+    scheme, bucket, prefix = parse_store_url(store_url)
+    if scheme == "s3":
+        return S3Store(bucket, runner=runner), prefix
+    if scheme == "gs":
+        return GCSStore(bucket, runner=runner), prefix
+    return LocalStore(Path(bucket)), prefix
+
+
+def upload_artifacts(
+    store_url: str,
+    paths: list[Path],
+    repo_id: str,
+    retention: str = DEFAULT_RETENTION,
+    runner=_run_cli,
+) -> dict:
+    """Upload artifacts with content-addressed keys; returns the manifest.
+
+    The manifest maps each file to its key, URI, and digest so later
+    stages (and humans) can locate artifacts without listing the bucket.
+    """
+    store, prefix = create_store(store_url, runner=runner)
+    uploaded: list[UploadedArtifact] = []
+    for path in paths:
+        if not path.is_file():
+            raise ArtifactStoreError(f"Not a file: {path}")
+        sha256 = file_sha256(path)
+        key = content_key(prefix, repo_id, sha256, path.name)
+        uri = store.put(path, key, retention)
+        uploaded.append(
+            UploadedArtifact(
+                file_name=path.name,
+                key=key,
+                uri=uri,
+                sha256=sha256,
+                size_bytes=path.stat().st_size,
+            )
+        )
+    return {
+        "store": store_url,
+        "repo_id": repo_id,
+        "retention": retention,
+        "uploaded_at": datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        "artifacts": [artifact.to_dict() for artifact in uploaded],
+    }
@@ -0,0 +1,240 @@
+"""
+Incremental Clone-Detection Index - Shared module for duplication scans.
+
+Re-tokenizing a 2M-LOC repo to find clones after a one-file commit is
+almost all wasted work: every unchanged file produces exactly the same
+token windows it produced last run. This index persists the per-file
+token-hash windows between runs, keyed by a content hash, and on the
+next scan re-tokenizes only files whose content changed (plus drops
+entries for deleted files) — duplication analysis after a small commit
+then takes seconds instead of a full pass.
+
+Detection is deliberately simple: tokens are lexemes (whitespace- and
+layout-insensitive, exact otherwise — CPD's type-1 clones), hashed over
+non-overlapping windows of ``window_tokens`` tokens. A window hash that
+occurs in two or more places is a clone group. The index file is JSON,
+written atomically (tmp file + rename) like the run checkpoints.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import itertools
+import json
+import os
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+
+INDEX_VERSION = 1
+DEFAULT_WINDOW_TOKENS = 50
+
+# Lexemes: identifiers/keywords, numbers, strings, then any single
+# non-space character (operators, punctuation). Comments are not
+# stripped — they tokenize like code, which only makes matches stricter.
+_TOKEN_RE = re.compile(
+    r"[A-Za-z_][A-Za-z_0-9]*"
+    r"|\d[\w.]*"
+    r'|"(?:[^"\\\n]|\\.)*"'
+    r"|'(?:[^'\\\n]|\\.)*'"
+    r"|\S"
+)
+
+
+@dataclass(frozen=True)
+class Window:
+    """One hashed token window inside a file."""
+
+    digest: str
+    start_line: int
+    end_line: int
+
+
+@dataclass(frozen=True)
+class CloneOccurrence:
+    path: str
+    start_line: int
+    end_line: int
+
+
+@dataclass(frozen=True)
+class CloneGroup:
+    """One duplicated window and everywhere it occurs."""
+
+    digest: str
+    token_count: int
+    occurrences: tuple[CloneOccurrence, ...]
+
+
+@dataclass(frozen=True)
+class UpdateStats:
+    """What an incremental update actually had to do."""
+
+    reused: int = 0
+    reindexed: int = 0
+    removed: int = 0
+
+
+@dataclass
+class _FileEntry:
+    content_hash: str
+    windows: list[Window] = field(default_factory=list)
+
+
+def tokenize(text: str) -> list[tuple[str, int]]:
+    """Lexemes with their 1-based line numbers, layout-insensitive."""
+    tokens = []
+    for line_no, line in enumerate(text.splitlines(), start=1):
+        for match in _TOKEN_RE.finditer(line):
+            tokens.append((match.group(), line_no))
+    return tokens
+
+
+def _window_hashes(text: str, window_tokens: int) -> list[Window]:
+    tokens = tokenize(text)
+    windows = []
+    for start in range(0, len(tokens) - window_tokens + 1, window_tokens):
+        chunk = tokens[start : start + window_tokens]
+        digest = hashlib.sha1("\x00".join(t for t, _ in chunk).encode()).hexdigest()
+        windows.append(Window(digest=digest, start_line=chunk[0][1], end_line=chunk[-1][1]))
+    return windows
+
+
+def _passes_prefilter(
+    occurrences: list[CloneOccurrence], candidate_pairs: set[tuple[str, str]]
+) -> bool:
+    paths = sorted({occ.path for occ in occurrences})
+    if len(paths) == 1:
+        return True
+    return any(pair in candidate_pairs for pair in itertools.combinations(paths, 2))
+
+
+class CloneIndex:
+    """Persistent token-hash index, updated only for changed files."""
+
+    def __init__(self, window_tokens: int = DEFAULT_WINDOW_TOKENS) -> None:
+        if window_tokens < 2:
+            raise ValueError("window_tokens must be >= 2")
+        self.window_tokens = window_tokens
+        self._files: dict[str, _FileEntry] = {}
+
+    def update(self, root: Path, paths: list[str]) -> UpdateStats:
+        """Bring the index in line with ``paths`` (repo-relative) under root.
+
+        Unchanged files (same content hash) keep their cached windows;
+        changed or new files are re-tokenized; indexed files no longer in
+        ``paths`` are dropped. Unreadable/undecodable files are skipped.
+        """
+        reused = reindexed = 0
+        wanted = set(paths)
+        removed = len([p for p in self._files if p not in wanted])
+        self._files = {p: e for p, e in self._files.items() if p in wanted}
+        for rel_path in paths:
+            try:
+                data = (root / rel_path).read_bytes()
+            except OSError:
+                continue
+            content_hash = hashlib.sha256(data).hexdigest()
+            entry = self._files.get(rel_path)
+            if entry is not None and entry.content_hash == content_hash:
+                reused += 1
+                continue
+            try:
+                text = data.decode("utf-8")
+            except UnicodeDecodeError:
+                self._files.pop(rel_path, None)
+                continue
+            self._files[rel_path] = _FileEntry(
+                content_hash=content_hash,
+                windows=_window_hashes(text, self.window_tokens),
+            )
+            reindexed += 1
+        return UpdateStats(reused=reused, reindexed=reindexed, removed=removed)
+
+    def duplicate_groups(
+        self,
+        min_occurrences: int = 2,
+        candidate_pairs: set[tuple[str, str]] | None = None,
+    ) -> list[CloneGroup]:
+        """Window hashes seen in ``min_occurrences``+ places, largest first.
+
+        With ``candidate_pairs`` (from clone_prefilter's MinHash/LSH
+        stage), cross-file groups are kept only when some pair of their
+        files is a candidate; same-file duplication always survives.
+        """
+        by_digest: dict[str, list[CloneOccurrence]] = {}
+        for rel_path, entry in self._files.items():
+            for window in entry.windows:
+                by_digest.setdefault(window.digest, []).append(
+                    CloneOccurrence(rel_path, window.start_line, window.end_line)
+                )
+        if candidate_pairs is not None:
+            by_digest = {
+                digest: occ
+                for digest, occ in by_digest.items()
+                if _passes_prefilter(occ, candidate_pairs)
+            }
+        groups = [
+            CloneGroup(
+                digest=digest,
+                token_count=self.window_tokens,
+                occurrences=tuple(sorted(occ, key=lambda o: (o.path, o.start_line))),
+            )
+            for digest, occ in by_digest.items()
+            if len(occ) >= min_occurrences
+        ]
+        return sorted(groups, key=lambda g: (-len(g.occurrences), g.digest))
+
+    @property
+    def file_count(self) -> int:
+        return len(self._files)
+
+    def tokens_of(self, rel_path: str, root: Path) -> list[str]:
+        """The file's lexemes, for feeding the MinHash prefilter."""
+        text = (root / rel_path).read_text(encoding="utf-8")
+        return [token for token, _ in tokenize(text)]
+
+    def save(self, path: Path) -> None:
+        """Atomic write, matching the run-checkpoint idiom."""
+        payload = {
+            "version": INDEX_VERSION,
+            "window_tokens": self.window_tokens,
+            "files": {
+                rel_path: {
+                    "content_hash": entry.content_hash,
+                    "windows": [
+                        [w.digest, w.start_line, w.end_line] for w in entry.windows
+                    ],
+                }
+                for rel_path, entry in sorted(self._files.items())
+            },
+        }
+        path.parent.mkdir(parents=True, exist_ok=True)
+        tmp_path = path.with_suffix(path.suffix + ".tmp")
+        tmp_path.write_text(json.dumps(payload, indent=2))
+        os.replace(tmp_path, path)
+
+    @classmethod
+    def load(cls, path: Path, window_tokens: int = DEFAULT_WINDOW_TOKENS) -> CloneIndex:
+        """Load a saved index; any mismatch falls back to an empty one.
+
+        A stale version or a different window size makes the cached
+        hashes incomparable, so starting fresh (full re-tokenize) is the
+        correct degradation, not an error.
+        """
+        index = cls(window_tokens=window_tokens)
+        try:
+            payload = json.loads(path.read_text())
+        except (OSError, json.JSONDecodeError):
+            return index
+        if (
+            payload.get("version") != INDEX_VERSION
+            or payload.get("window_tokens") != window_tokens
+        ):
+            return index
+        for rel_path, entry in payload.get("files", {}).items():
+            index._files[rel_path] = _FileEntry(
+                content_hash=entry["content_hash"],
+                windows=[Window(d, s, e) for d, s, e in entry["windows"]],
+            )
+        return index
@@ -0,0 +1,194 @@
+"""
+MinHash/LSH Clone Prefilter - Shared module for candidate pair pruning.
+
+Exact token comparison between every pair of files is O(n²) in the file
+count; on a large corpus almost all of those pairs share nothing and the
+comparisons are wasted. This prefilter computes a MinHash signature per
+file over its token shingles and buckets signatures with
+locality-sensitive hashing (banding): only files that collide in at
+least one band become candidate pairs for the exact comparison in
+clone_index. Similar files collide with high probability, dissimilar
+ones almost never — the classic recall-for-speed trade.
+
+Tuning: with ``num_hashes`` rows split into ``bands`` bands of ``r``
+rows, a pair with Jaccard similarity ``s`` becomes a candidate with
+probability ``1 - (1 - s^r)^bands``. The defaults (128 hashes, 32 bands
+of 4) put the cutoff near s ≈ 0.4. Run the built-in benchmark to see
+recall loss against speedup on a synthetic corpus::
+
+    python -m common.clone_prefilter --files 500
+"""
+
+from __future__ import annotations
+
+import hashlib
+import itertools
+
+DEFAULT_NUM_HASHES = 128
+DEFAULT_BANDS = 32
+DEFAULT_SHINGLE_TOKENS = 8
+
+# Fixed seed mixed into every shingle hash so signatures are stable
+# across processes and runs.
+_PARAM_SEED = 0x7CA1DE2A
+
+
+def _shingles(tokens: list[str], shingle_tokens: int) -> set[int]:
+    if len(tokens) < shingle_tokens:
+        tokens = tokens + [""] * (shingle_tokens - len(tokens))
+    return {
+        int.from_bytes(
+            hashlib.sha1("\x00".join(tokens[i : i + shingle_tokens]).encode()).digest()[:8],
+            "big",
+        )
+        for i in range(len(tokens) - shingle_tokens + 1)
+    }
+
+
+class MinHashIndex:
+    """Per-file MinHash signatures with LSH banding for candidate pairs."""
+
+    def __init__(
+        self,
+        num_hashes: int = DEFAULT_NUM_HASHES,
+        bands: int = DEFAULT_BANDS,
+        shingle_tokens: int = DEFAULT_SHINGLE_TOKENS,
+    ) -> None:
+        if num_hashes % bands != 0:
+            raise ValueError("num_hashes must be divisible by bands")
+        self.num_hashes = num_hashes
+        self.bands = bands
+        self.rows = num_hashes // bands
+        self.shingle_tokens = shingle_tokens
+        self._signatures: dict[str, tuple[int, ...]] = {}
+        # band index -> band key -> paths in that bucket
+        self._buckets: list[dict[tuple[int, ...], list[str]]] = [
+            {} for _ in range(bands)
+        ]
+
+    def add(self, path: str, tokens: list[str]) -> None:
+        """Index one file's tokens; short files are padded to one shingle."""
+        signature = self._signature(_shingles(tokens, self.shingle_tokens))
+        self._signatures[path] = signature
+        for band, bucket in enumerate(self._buckets):
+            key = signature[band * self.rows : (band + 1) * self.rows]
+            bucket.setdefault(key, []).append(path)
+
+    def _signature(self, shingles: set[int]) -> tuple[int, ...]:
+        """One-permutation MinHash: a single hash per shingle, min per bin.
+
+        Classic MinHash costs ``num_hashes`` modular hashes per shingle;
+        one-permutation hashing gets the same sketch from one pass by
+        splitting the hash space into ``num_hashes`` bins and taking the
+        minimum per bin. Empty bins borrow circularly from the next
+        filled bin (densification) so sparse files still compare.
+        """
+        mins: list[int | None] = [None] * self.num_hashes
+        for shingle in shingles:
+            mixed = (shingle * 0x9E3779B97F4A7C15 + _PARAM_SEED) & ((1 << 64) - 1)
+            slot = mixed % self.num_hashes
+            value = mixed // self.num_hashes
+            current = mins[slot]
+            if current is None or value < current:
+                mins[slot] = value
+        filled = [(i, v) for i, v in enumerate(mins) if v is not None]
+        if not filled:
+            return tuple(0 for _ in range(self.num_hashes))
+        densified = list(mins)
+        for i in range(self.num_hashes):
+            if densified[i] is None:
+                offset = 1
+                while mins[(i + offset) % self.num_hashes] is None:
+                    offset += 1
+                # Mix in the distance so two files sharing one shingle do
+                # not agree on every densified slot.
+                densified[i] = mins[(i + offset) % self.num_hashes] + offset
+        return tuple(densified)  # type: ignore[arg-type]
+
+    def similarity(self, path_a: str, path_b: str) -> float:
+        """Estimated Jaccard similarity from signature agreement."""
+        sig_a = self._signatures[path_a]
+        sig_b = self._signatures[path_b]
+        return sum(a == b for a, b in zip(sig_a, sig_b)) / self.num_hashes
+
+    def candidate_pairs(self) -> set[tuple[str, str]]:
+        """Unordered file pairs colliding in at least one LSH band."""
+        pairs: set[tuple[str, str]] = set()
+        for bucket in self._buckets:
+            for paths in bucket.values():
+                if len(paths) < 2:
+                    continue
+                for a, b in itertools.combinations(sorted(paths), 2):
+                    pairs.add((a, b))
+        return pairs
+
+
+def _benchmark(file_count: int, duplicate_pairs: int) -> None:
+    """Recall loss vs speedup on a synthetic skewed corpus."""
+    import random
+    import time
+
+    from common.clone_index import tokenize
+
+    rng = random.Random(2871)
+    corpus: dict[str, list[str]] = {}
+    for i in range(file_count):
+        lines = [
+            f"value_{rng.randrange(10_000)} = compute_{rng.randrange(10_000)}(a, b)"
+            for _ in range(60)
+        ]
+        corpus[f"file_{i}.py"] = [t for t, _ in tokenize("\n".join(lines))]
+    true_pairs = set()
+    for i in range(duplicate_pairs):
+        original = f"file_{i}.py"
+        copy = f"copy_{i}.py"
+        corpus[copy] = list(corpus[original])
+        true_pairs.add((copy, original))
+
+    def exact_match(tokens_a: list[str], tokens_b: list[str]) -> bool:
+        # Full positional comparison, no short-circuit: mirrors the cost of
+        # real clone matching, which scans both token streams for every pair.
+        if len(tokens_a) != len(tokens_b):
+            return False
+        return sum(a == b for a, b in zip(tokens_a, tokens_b)) == len(tokens_a)
+
+    names = sorted(corpus)
+    start = time.perf_counter()
+    full_found = {
+        (a, b)
+        for a, b in itertools.combinations(names, 2)
+        if exact_match(corpus[a], corpus[b])
+    }
+    full_s = time.perf_counter() - start
+    full_pairs = len(names) * (len(names) - 1) // 2
+
+    start = time.perf_counter()
+    index = MinHashIndex()
+    for name in names:
+        index.add(name, corpus[name])
+    candidates = index.candidate_pairs()
+    lsh_found = {
+        (a, b) for a, b in candidates if exact_match(corpus[a], corpus[b])
+    }
+    lsh_s = time.perf_counter() - start
+
+    recall = len(lsh_found & full_found) / len(full_found) if full_found else 1.0
+    print(f"{len(names)} files, {len(true_pairs)} duplicated pairs")
+    print(f"exhaustive: {full_pairs} pairs compared in {full_s:.2f}s")
+    print(f"minhash/LSH: {len(candidates)} candidate pairs in {lsh_s:.2f}s "
+          f"({full_s / lsh_s:.1f}x, {full_pairs / max(len(candidates), 1):.0f}x fewer pairs)")
+    print(f"recall: {recall:.3f}")
+
+
+def main() -> None:
+    import argparse
+
+    parser = argparse.ArgumentParser(description="Benchmark the clone prefilter")
+    parser.add_argument("--files", type=int, default=500)
+    parser.add_argument("--duplicates", type=int, default=25)
+    args = parser.parse_args()
+    _benchmark(args.files, args.duplicates)
+
+
+if __name__ == "__main__":
+    main()
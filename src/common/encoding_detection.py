@@ -0,0 +1,160 @@
+"""
+Encoding Detector - Shared module for non-UTF-8 file handling.
+
+scc and lizard silently disagree on files that are not UTF-8: one
+counts the raw bytes, the other skips or mangles them, and the marts
+diverge without any trace of why. This module gives tools and adapters
+one policy: detect the encoding, transcode what can be transcoded, skip
+what cannot, and record a diagnostic either way so the divergence is
+visible in the output instead of silent.
+
+Detection signals, in order:
+
+1. BOM (UTF-8, UTF-16 LE/BE, UTF-32 LE/BE)
+2. Strict UTF-8 decode
+3. BOM-less UTF-16 heuristic (NUL bytes concentrated on one side)
+4. Binary sniff (NUL bytes without UTF-16 shape -> skip)
+5. Latin-1 fallback (always decodes; flagged as transcoded)
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from pathlib import Path
+
+# Actions a caller should take for a file, in the envelope's vocabulary.
+ACTION_OK = "ok"
+ACTION_TRANSCODED = "transcoded"
+ACTION_SKIPPED = "skipped"
+
+# BOMs checked longest-first so UTF-32 LE is not misread as UTF-16 LE.
+_BOMS: tuple[tuple[bytes, str], ...] = (
+    (b"\xff\xfe\x00\x00", "utf-32-le"),
+    (b"\x00\x00\xfe\xff", "utf-32-be"),
+    (b"\xef\xbb\xbf", "utf-8-sig"),
+    (b"\xff\xfe", "utf-16-le"),
+    (b"\xfe\xff", "utf-16-be"),
+)
+
+# Sample size for heuristics; encodings are stable within a file head.
+_SNIFF_BYTES = 8192
+
+# Fraction of NUL bytes on one parity side that marks BOM-less UTF-16.
+_UTF16_NUL_RATIO = 0.4
+
+
+@dataclass(frozen=True)
+class EncodingDiagnostic:
+    """Outcome of decoding one file."""
+
+    file_path: str
+    encoding: str | None
+    action: str  # ok | transcoded | skipped
+    reason: str
+
+    def to_dict(self) -> dict:
+        return {
+            "file_path": self.file_path,
+            "encoding": self.encoding,
+            "action": self.action,
+            "reason": self.reason,
+        }
+
+
+def detect_encoding(data: bytes) -> tuple[str | None, str]:
+    """Detect the encoding of raw file bytes.
+
+    Returns ``(encoding, reason)``; encoding is None for files that
+    should be skipped (binary or undecodable).
+    """
+    if not data:
+        return "utf-8", "empty file"
+    for bom, encoding in _BOMS:
+        if data.startswith(bom):
+            return encoding, f"{encoding} BOM"
+
+    head = data[:_SNIFF_BYTES]
+    # NUL bytes first: UTF-16 of ASCII text is also "valid" UTF-8.
+    if b"\x00" not in head:
+        try:
+            head.decode("utf-8")
+            return "utf-8", "valid UTF-8"
+        except UnicodeDecodeError:
+            pass
+
+    if b"\x00" in head:
+        even_nuls = head[::2].count(0) / max(len(head[::2]), 1)
+        odd_nuls = head[1::2].count(0) / max(len(head[1::2]), 1)
+        if odd_nuls >= _UTF16_NUL_RATIO and even_nuls < _UTF16_NUL_RATIO:
+            return "utf-16-le", "BOM-less UTF-16 (NUL high bytes)"
+        if even_nuls >= _UTF16_NUL_RATIO and odd_nuls < _UTF16_NUL_RATIO:
+            return "utf-16-be", "BOM-less UTF-16 (NUL high bytes)"
+        return None, "binary content (NUL bytes)"
+
+    return "latin-1", "not UTF-8; Latin-1 fallback"
+
+
+def read_text_with_diagnostic(
+    path: Path,
+    file_path: str | None = None,
+) -> tuple[str | None, EncodingDiagnostic]:
+    """Read a file, transcoding or skipping per the detected encoding.
+
+    Returns ``(text, diagnostic)``; text is None when the file was
+    skipped. ``file_path`` is the repo-relative path recorded in the
+    diagnostic (defaults to the filesystem path).
+    """
+    recorded_path = file_path if file_path is not None else str(path)
+    try:
+        data = path.read_bytes()
+    except OSError as exc:
+        return None, EncodingDiagnostic(
+            file_path=recorded_path,
+            encoding=None,
+            action=ACTION_SKIPPED,
+            reason=f"unreadable: {exc.strerror or exc}",
+        )
+
+    encoding, reason = detect_encoding(data)
+    if encoding is None:
+        return None, EncodingDiagnostic(
+            file_path=recorded_path,
+            encoding=None,
+            action=ACTION_SKIPPED,
+            reason=reason,
+        )
+
+    try:
+        text = data.decode(encoding)
+    except (UnicodeDecodeError, LookupError):
+        return None, EncodingDiagnostic(
+            file_path=recorded_path,
+            encoding=encoding,
+            action=ACTION_SKIPPED,
+            reason=f"detected {encoding} but decode failed",
+        )
+
+    action = ACTION_OK if encoding == "utf-8" else ACTION_TRANSCODED
+    return text, EncodingDiagnostic(
+        file_path=recorded_path,
+        encoding=encoding,
+        action=action,
+        reason=reason,
+    )
+
+
+def scan_tree_encodings(root: Path) -> list[EncodingDiagnostic]:
+    """Collect diagnostics for every non-plain-UTF-8 file under root.
+
+    Clean UTF-8 files are omitted; the result is the exceptions list a
+    tool can attach to its envelope so divergent counts are explainable.
+    """
+    diagnostics = []
+    for path in sorted(root.rglob("*")):
+        if not path.is_file() or ".git" in path.parts:
+            continue
+        relative = path.relative_to(root).as_posix()
+        _, diagnostic = read_text_with_diagnostic(path, relative)
+        if diagnostic.action != ACTION_OK:
+            diagnostics.append(diagnostic)
+    return diagnostics
@@ -0,0 +1,178 @@
+"""
+Binary/Minified File Prefilter - Shared module for noise exclusion.
+
+A single minified bundle feeds CPD thousands of bogus duplication
+tokens and gives lizard one unreadable 40k-token "function". This
+prefilter classifies files as binary, minified, or machine-generated
+single-line output so CPD and lizard can exclude them by default —
+with every exclusion reported in a skip summary, not silently dropped.
+
+Detection signals, in order:
+
+1. Minified naming (``*.min.js``, ``*.bundle.js``, ...)
+2. Binary content (NUL bytes / undecodable, via encoding_detection)
+3. Single-line generated output (large files with one or two lines:
+   bundles, source maps, inlined data blobs)
+4. Minification heuristics (long average lines, kilometer first lines)
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from fnmatch import fnmatch
+from pathlib import Path
+
+from common.encoding_detection import detect_encoding
+
+CATEGORY_SOURCE = "source"
+CATEGORY_BINARY = "binary"
+CATEGORY_MINIFIED = "minified"
+CATEGORY_SINGLE_LINE = "single-line-generated"
+
+SKIP_CATEGORIES = (CATEGORY_BINARY, CATEGORY_MINIFIED, CATEGORY_SINGLE_LINE)
+
+# Filename globs that mark minified/bundled output without reading it.
+MINIFIED_NAME_PATTERNS: tuple[str, ...] = (
+    "*.min.js",
+    "*.min.css",
+    "*.min.ts",
+    "*.bundle.js",
+    "*.chunk.js",
+    "*.map",
+)
+
+# Bytes sampled for content heuristics.
+_SNIFF_BYTES = 8192
+
+# Average line length above which content counts as minified.
+_MINIFIED_AVG_LINE_LENGTH = 500
+
+# A single line this long in the file head also counts as minified.
+_MINIFIED_MAX_LINE_LENGTH = 1000
+
+# Files with <= this many lines but >= _SINGLE_LINE_MIN_BYTES are
+# machine-generated single-line output, not hand-written code.
+_SINGLE_LINE_MAX_LINES = 2
+_SINGLE_LINE_MIN_BYTES = 4096
+
+# Skip summary keeps at most this many example paths per category.
+_SUMMARY_EXAMPLE_LIMIT = 20
+
+
+@dataclass(frozen=True)
+class PrefilterDecision:
+    """Classification of one file."""
+
+    file_path: str
+    category: str
+    reason: str
+
+    @property
+    def keep(self) -> bool:
+        return self.category == CATEGORY_SOURCE
+
+    def to_dict(self) -> dict:
+        return {
+            "file_path": self.file_path,
+            "category": self.category,
+            "reason": self.reason,
+        }
+
+
+def classify_bytes(filename: str, data: bytes, total_size: int | None = None) -> tuple[str, str]:
+    """Classify file content; ``data`` may be a head sample.
+
+    Returns ``(category, reason)``. ``total_size`` is the full file
+    size when ``data`` is truncated.
+    """
+    for pattern in MINIFIED_NAME_PATTERNS:
+        if fnmatch(filename, pattern):
+            return CATEGORY_MINIFIED, f"filename matches {pattern}"
+
+    if not data:
+        return CATEGORY_SOURCE, "empty file"
+
+    head = data[:_SNIFF_BYTES]
+    encoding, _ = detect_encoding(head)
+    if encoding is None:
+        return CATEGORY_BINARY, "binary content (NUL bytes)"
+
+    text = head.decode(encoding, errors="replace")
+    size = total_size if total_size is not None else len(data)
+    lines = text.split("\n")
+
+    if size >= _SINGLE_LINE_MIN_BYTES and len(data) == size:
+        line_count = data.count(b"\n") + 1
+        if line_count <= _SINGLE_LINE_MAX_LINES:
+            return CATEGORY_SINGLE_LINE, f"{size} bytes in {line_count} line(s)"
+
+    if len(text) / max(len(lines), 1) > _MINIFIED_AVG_LINE_LENGTH:
+        return CATEGORY_MINIFIED, "average line length > 500 chars"
+    if any(len(line) > _MINIFIED_MAX_LINE_LENGTH for line in lines[:10]):
+        return CATEGORY_MINIFIED, "line > 1000 chars in file head"
+
+    return CATEGORY_SOURCE, "plain source"
+
+
+def classify_file(path: Path, file_path: str | None = None) -> PrefilterDecision:
+    """Classify a file on disk; unreadable files count as binary."""
+    recorded_path = file_path if file_path is not None else str(path)
+    try:
+        size = path.stat().st_size
+        with path.open("rb") as handle:
+            # Read the whole file only when small enough for the
+            # single-line check; otherwise the head sample decides.
+            data = handle.read() if size <= _SNIFF_BYTES * 16 else handle.read(_SNIFF_BYTES)
+    except OSError as exc:
+        return PrefilterDecision(
+            file_path=recorded_path,
+            category=CATEGORY_BINARY,
+            reason=f"unreadable: {exc.strerror or exc}",
+        )
+    category, reason = classify_bytes(path.name, data, total_size=size)
+    return PrefilterDecision(file_path=recorded_path, category=category, reason=reason)
+
+
+def partition_files(
+    paths: list[Path],
+    repo_root: Path | None = None,
+) -> tuple[list[Path], list[PrefilterDecision]]:
+    """Split paths into kept source files and skip decisions.
+
+    Paths are recorded repo-relative when ``repo_root`` is given.
+    """
+    kept = []
+    skipped = []
+    for path in paths:
+        relative = (
+            path.relative_to(repo_root).as_posix()
+            if repo_root is not None
+            else str(path)
+        )
+        decision = classify_file(path, relative)
+        if decision.keep:
+            kept.append(path)
+        else:
+            skipped.append(decision)
+    return kept, skipped
+
+
+def skip_summary(skipped: list[PrefilterDecision]) -> dict:
+    """Aggregate skip decisions for the envelope.
+
+    Counts per category plus a bounded list of example paths, so the
+    output explains what was dropped without ballooning on repos with
+    thousands of skipped files.
+    """
+    by_category: dict[str, int] = {}
+    examples: dict[str, list[str]] = {}
+    for decision in skipped:
+        by_category[decision.category] = by_category.get(decision.category, 0) + 1
+        bucket = examples.setdefault(decision.category, [])
+        if len(bucket) < _SUMMARY_EXAMPLE_LIMIT:
+            bucket.append(decision.file_path)
+    return {
+        "total_skipped": len(skipped),
+        "by_category": {category: by_category[category] for category in sorted(by_category)},
+        "examples": {category: examples[category] for category in sorted(examples)},
+    }
@@ -0,0 +1,156 @@
+"""
+Work-Stealing File Scheduler - Shared module for per-file analyzers.
+
+Static partitioning (split the file list into one contiguous chunk per
+worker) serializes on skew: one massive generated file lands in some
+worker's chunk and the other workers finish early and idle while it
+grinds alone. This scheduler replaces that with a shared work queue —
+workers pull the next file the moment they go idle, which is the
+work-stealing behaviour rayon gives compiled analyzers — plus a
+longest-file-first dispatch order so the expensive outliers start
+early instead of last.
+
+Used by in-process per-file analyzers (complexity, secret, clone
+scanning); ``static_partitions`` keeps the old static split available as
+the benchmark baseline. Run the built-in benchmark against a repo to
+see the skew effect::
+
+    python -m common.file_scheduler /path/to/repo --workers 4
+"""
+
+from __future__ import annotations
+
+import os
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Callable, Iterable, TypeVar
+
+T = TypeVar("T")
+
+# Matches the lizard tool's default: half the logical CPUs approximates
+# physical cores and avoids thread thrashing, capped to stay polite.
+DEFAULT_MAX_WORKERS = min((os.cpu_count() or 2) // 2 or 1, 4)
+
+
+@dataclass(frozen=True)
+class FileResult:
+    """Outcome for one scheduled file (result and error are exclusive)."""
+
+    path: Path
+    result: object | None = None
+    error: str | None = None
+
+    @property
+    def ok(self) -> bool:
+        return self.error is None
+
+
+def schedule_order(files: Iterable[Path]) -> list[Path]:
+    """Files ordered largest-first (longest-processing-time-first).
+
+    File size is the only pre-analysis cost signal available, and for
+    per-file analyzers it correlates well enough: dispatching the big
+    outliers first means they overlap with everything else instead of
+    starting when the queue is nearly drained.
+    """
+
+    def size_of(path: Path) -> int:
+        try:
+            return path.stat().st_size
+        except OSError:
+            return 0
+
+    return sorted(files, key=size_of, reverse=True)
+
+
+def static_partitions(files: list[Path], partitions: int) -> list[list[Path]]:
+    """Static contiguous split — the baseline the scheduler replaces.
+
+    Kept for process-based workers that need up-front partitions and for
+    benchmarking against ``map_files``.
+    """
+    if partitions < 1:
+        raise ValueError("partitions must be >= 1")
+    chunk_size = -(-len(files) // partitions) if files else 0
+    return [files[i : i + chunk_size] for i in range(0, len(files), chunk_size or 1)]
+
+
+def map_files(
+    worker: Callable[[Path], T],
+    files: Iterable[Path],
+    max_workers: int | None = None,
+) -> list[FileResult]:
+    """Run ``worker`` over ``files`` with dynamic load balancing.
+
+    One task per file on a shared executor queue: an idle worker always
+    takes the next undispatched file, so a skewed file only occupies the
+    one thread actually analyzing it. Worker exceptions are captured per
+    file, not raised — one unparseable file must not sink the batch.
+    Results come back in the original input order.
+    """
+    ordered = schedule_order(files)
+    if not ordered:
+        return []
+    workers = max_workers or DEFAULT_MAX_WORKERS
+
+    def run_one(path: Path) -> FileResult:
+        try:
+            return FileResult(path=path, result=worker(path))
+        except Exception as exc:  # noqa: BLE001 - captured per file by design
+            return FileResult(path=path, error=f"{type(exc).__name__}: {exc}")
+
+    with ThreadPoolExecutor(max_workers=workers) as executor:
+        by_path = {r.path: r for r in executor.map(run_one, ordered)}
+    return [by_path[path] for path in files]
+
+
+def _benchmark(repo: Path, workers: int, pattern: str) -> None:
+    """Compare static partitioning against the shared-queue scheduler."""
+    import hashlib
+    import time
+
+    def analyze(path: Path) -> str:
+        # Stand-in for a per-file analyzer: cost proportional to size.
+        digest = hashlib.sha256()
+        for _ in range(20):
+            digest.update(path.read_bytes())
+        return digest.hexdigest()
+
+    # Keep discovery order for the static baseline — sorting it first
+    # would pile the big files into one chunk and rig the comparison.
+    files = [p for p in repo.rglob(pattern) if p.is_file()][:2000]
+    if not files:
+        print(f"No files matching {pattern} under {repo}")
+        return
+    sizes = [p.stat().st_size for p in files]
+    print(f"{len(files)} files, {sum(sizes) / 1024:.0f} KiB total, "
+          f"largest {max(sizes) / 1024:.0f} KiB, {workers} workers")
+
+    start = time.perf_counter()
+    with ThreadPoolExecutor(max_workers=workers) as executor:
+        chunks = static_partitions(files, workers)
+        list(executor.map(lambda chunk: [analyze(p) for p in chunk], chunks))
+    static_s = time.perf_counter() - start
+
+    start = time.perf_counter()
+    map_files(analyze, files, max_workers=workers)
+    dynamic_s = time.perf_counter() - start
+
+    print(f"static partitioning: {static_s:.2f}s")
+    print(f"work-stealing queue: {dynamic_s:.2f}s ({static_s / dynamic_s:.2f}x)")
+
+
+def main() -> None:
+    import argparse
+
+    parser = argparse.ArgumentParser(description="Benchmark the file scheduler")
+    parser.add_argument("repo", type=Path, help="Repository to scan")
+    parser.add_argument("--workers", type=int, default=DEFAULT_MAX_WORKERS)
+    parser.add_argument("--pattern", default="*.py", help="Glob for files to analyze")
+    args = parser.parse_args()
+    _benchmark(args.repo.resolve(), args.workers, args.pattern)
+
+
+if __name__ == "__main__":
+    main()
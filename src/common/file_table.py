@@ -0,0 +1,131 @@
+"""
+Memory-Mapped File Table - Shared module for multi-analyzer scans.
+
+When several in-process analyzers (complexity, secrets, clones) run over
+the same checkout, each one re-reads every source file from disk — on a
+large repo that is the same gigabytes of IO three or four times over.
+The ``FileTable`` maps each file once with ``mmap`` and hands out
+zero-copy ``memoryview`` slices; the page cache then serves every
+analyzer from the single mapping, and decoded text is cached per file so
+the encoding detection (see encoding_detection) also runs once.
+
+Usage::
+
+    with FileTable() as table:
+        data = table.get_bytes(path)        # memoryview over the mmap
+        text = table.get_text(path)         # decoded once, cached
+    # all mappings released on exit
+
+Mappings are read-only; files that change size mid-scan surface as the
+content that was mapped first, which is the snapshot semantics a scan
+wants anyway.
+"""
+
+from __future__ import annotations
+
+import mmap
+from dataclasses import dataclass
+from pathlib import Path
+
+from common.encoding_detection import detect_encoding
+
+# Empty bytes shared for zero-length files, which mmap cannot map.
+_EMPTY = memoryview(b"")
+
+
+@dataclass(frozen=True)
+class FileTableStats:
+    """IO accounting for one table's lifetime."""
+
+    files_mapped: int
+    bytes_mapped: int
+    hits: int
+    misses: int
+
+
+class FileTable:
+    """Maps each file at most once and serves shared read-only slices."""
+
+    def __init__(self) -> None:
+        self._maps: dict[Path, mmap.mmap] = {}
+        self._views: dict[Path, memoryview] = {}
+        self._texts: dict[Path, str | None] = {}
+        self._bytes_mapped = 0
+        self._hits = 0
+        self._misses = 0
+        self._closed = False
+
+    def get_bytes(self, path: Path) -> memoryview:
+        """A zero-copy view of the file's bytes, mapping it on first use.
+
+        Raises ``OSError`` for unreadable files and ``ValueError`` once
+        the table is closed (outstanding views die with the mappings).
+        """
+        if self._closed:
+            raise ValueError("FileTable is closed")
+        path = Path(path)
+        view = self._views.get(path)
+        if view is not None:
+            self._hits += 1
+            return view
+        self._misses += 1
+        with path.open("rb") as handle:
+            size = handle.seek(0, 2)
+            if size == 0:
+                view = _EMPTY
+            else:
+                mapped = mmap.mmap(handle.fileno(), 0, access=mmap.ACCESS_READ)
+                self._maps[path] = mapped
+                self._bytes_mapped += size
+                view = memoryview(mapped)
+        self._views[path] = view
+        return view
+
+    def get_text(self, path: Path) -> str | None:
+        """The file decoded per detect_encoding, cached after the first call.
+
+        Returns ``None`` for binary/undecodable files — the same skip
+        policy every analyzer already applies individually.
+        """
+        path = Path(path)
+        if path in self._texts:
+            self._hits += 1
+            return self._texts[path]
+        data = bytes(self.get_bytes(path))
+        encoding, _ = detect_encoding(data)
+        text: str | None = None
+        if encoding is not None:
+            try:
+                text = data.decode(encoding)
+            except (UnicodeDecodeError, LookupError):
+                text = None
+        self._texts[path] = text
+        return text
+
+    @property
+    def stats(self) -> FileTableStats:
+        return FileTableStats(
+            files_mapped=len(self._maps),
+            bytes_mapped=self._bytes_mapped,
+            hits=self._hits,
+            misses=self._misses,
+        )
+
+    def close(self) -> None:
+        """Release every mapping; views handed out earlier become invalid."""
+        if self._closed:
+            return
+        self._closed = True
+        for view in self._views.values():
+            view.release()
+        for mapped in self._maps.values():
+            mapped.close()
+        self._maps.clear()
+        self._views.clear()
+        self._texts.clear()
+
+    def __enter__(self) -> FileTable:
+        return self
+
+    def __exit__(self, *exc_info) -> None:
+        self.close()
@@ -0,0 +1,174 @@
+"""
+Generated/Vendored Code Detector - Shared module for code provenance.
+
+Duplication and size numbers are dominated by code nobody wrote by hand:
+protobuf/bindgen output, minified bundles, and vendored trees. This module
+classifies each file as ``source``, ``generated``, or ``vendored`` so tools
+and adapters can apply one of three policies:
+
+- ``exclude``            drop the file from metrics entirely
+- ``include``            keep it (legacy behavior)
+- ``report-separately``  keep it, but tagged so marts can split it out
+
+Detection signals, in order:
+
+1. ``.gitattributes`` ``linguist-generated`` / ``linguist-vendored``
+2. Vendored directory names anywhere in the path (vendor/, node_modules/, ...)
+3. Generated filename patterns (``*.pb.go``, ``*_pb2.py``, ``*.min.js``, ...)
+4. Content markers in the file head (``@generated``, ``DO NOT EDIT``, ...)
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from fnmatch import fnmatch
+from pathlib import Path
+
+POLICIES = ("exclude", "include", "report-separately")
+
+# Directory names (any path segment) treated as vendored.
+VENDORED_DIR_NAMES: frozenset[str] = frozenset(
+    {
+        "vendor",
+        "vendors",
+        "third_party",
+        "third-party",
+        "node_modules",
+        "bower_components",
+        "Pods",
+        ".yarn",
+    }
+)
+
+# Filename globs for common code generators and build artifacts.
+GENERATED_FILENAME_PATTERNS: tuple[str, ...] = (
+    "*.pb.go",
+    "*.pb.cc",
+    "*.pb.h",
+    "*_pb2.py",
+    "*_pb2_grpc.py",
+    "*.g.cs",
+    "*.g.i.cs",
+    "*.Designer.cs",
+    "*.generated.*",
+    "*.gen.go",
+    "*.min.js",
+    "*.min.css",
+    "*_string.go",  # stringer
+    "bindings.rs",  # bindgen default output
+)
+
+# Markers that generators leave near the top of emitted files.
+GENERATED_CONTENT_MARKERS: tuple[str, ...] = (
+    "@generated",
+    "do not edit",
+    "code generated by",
+    "autogenerated",
+    "auto-generated",
+    "<auto-generated",
+    "automatically generated",
+)
+
+# How many leading lines to scan for content markers.
+CONTENT_MARKER_LINES = 5
+
+
+@dataclass(frozen=True)
+class CodeClassification:
+    """Provenance classification for a single file."""
+
+    file_path: str  # repo-relative
+    kind: str  # source | generated | vendored
+    reason: str  # which signal matched, for diagnostics
+
+
+def parse_provenance_gitattributes(text: str) -> dict[str, str]:
+    """Extract linguist-generated/linguist-vendored globs from .gitattributes.
+
+    Returns pattern -> "generated" | "vendored". ``=false`` annotations are
+    ignored (the file falls through to the heuristics).
+    """
+    rules: dict[str, str] = {}
+    for raw_line in text.splitlines():
+        line = raw_line.strip()
+        if not line or line.startswith("#"):
+            continue
+        parts = line.split()
+        pattern = parts[0]
+        for attribute in parts[1:]:
+            if attribute in ("linguist-generated", "linguist-generated=true"):
+                rules[pattern] = "generated"
+            elif attribute in ("linguist-vendored", "linguist-vendored=true"):
+                rules[pattern] = "vendored"
+    return rules
+
+
+def _head_has_generated_marker(head: str) -> bool:
+    lines = head.splitlines()[:CONTENT_MARKER_LINES]
+    lowered = "\n".join(lines).lower()
+    return any(marker in lowered for marker in GENERATED_CONTENT_MARKERS)
+
+
+class GeneratedCodeDetector:
+    """Classifies files as source, generated, or vendored.
+
+    The policy only affects ``should_exclude``; ``classify`` always reports
+    the full classification so ``report-separately`` consumers can tag rows.
+    """
+
+    def __init__(self, repo_root: Path | None = None, policy: str = "exclude") -> None:
+        if policy not in POLICIES:
+            raise ValueError(f"policy must be one of {POLICIES}, got {policy!r}")
+        self._repo_root = repo_root
+        self.policy = policy
+        self._gitattributes: dict[str, str] = {}
+        if repo_root is not None:
+            gitattributes_path = repo_root / ".gitattributes"
+            if gitattributes_path.exists():
+                self._gitattributes = parse_provenance_gitattributes(
+                    gitattributes_path.read_text(encoding="utf-8", errors="replace")
+                )
+
+    def classify(self, file_path: str, head: str | None = None) -> CodeClassification:
+        """Classify a repo-relative path.
+
+        ``head`` is the first few lines of the file; when omitted and a repo
+        root is configured, it is read from disk.
+        """
+        basename = Path(file_path).name
+
+        for pattern, kind in self._gitattributes.items():
+            candidate = basename if "/" not in pattern else file_path
+            if fnmatch(candidate, pattern):
+                return CodeClassification(file_path, kind, f"gitattributes:{pattern}")
+
+        for segment in Path(file_path).parts[:-1]:
+            if segment in VENDORED_DIR_NAMES:
+                return CodeClassification(file_path, "vendored", f"directory:{segment}")
+
+        for pattern in GENERATED_FILENAME_PATTERNS:
+            if fnmatch(basename, pattern):
+                return CodeClassification(file_path, "generated", f"filename:{pattern}")
+
+        if head is None:
+            head = self._read_head(file_path)
+        if head and _head_has_generated_marker(head):
+            return CodeClassification(file_path, "generated", "content-marker")
+
+        return CodeClassification(file_path, "source", "default")
+
+    def should_exclude(self, classification: CodeClassification) -> bool:
+        """True when the configured policy drops this file from metrics."""
+        return self.policy == "exclude" and classification.kind != "source"
+
+    def _read_head(self, file_path: str) -> str | None:
+        if self._repo_root is None:
+            return None
+        full_path = self._repo_root / file_path
+        if not full_path.is_file():
+            return None
+        try:
+            with full_path.open(encoding="utf-8", errors="replace") as handle:
+                return "".join(handle.readline() for _ in range(CONTENT_MARKER_LINES))
+        except OSError:
+            return None
@@ -0,0 +1,146 @@
+"""Shared .calderaignore handling with gitignore-style semantics.
+
+Patterns come from a ``.calderaignore`` file at the repository root (and
+optionally ``.gitignore``), one per line:
+
+- ``#`` starts a comment, blank lines are skipped
+- ``!pattern`` re-includes a previously ignored path
+- a trailing ``/`` restricts the pattern to directories
+- a pattern containing ``/`` is anchored to the repo root, otherwise it
+  matches at any depth
+- ``**`` spans directory separators, ``*`` and ``?`` stay within one segment
+
+The last matching pattern decides, and a path inside an ignored directory
+stays ignored. The layout adapter applies these rules before persisting,
+so every downstream adapter consistently skips ignored files.
+"""
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+
+CALDERAIGNORE = ".calderaignore"
+GITIGNORE = ".gitignore"
+
+
+@dataclass(frozen=True)
+class IgnorePattern:
+    """One parsed ignore pattern with its origin for diagnostics."""
+
+    pattern: str  # pattern body without '!' or trailing '/'
+    negated: bool
+    dir_only: bool
+    source: str  # file the pattern came from, e.g. ".calderaignore"
+    line: int  # 1-based line number in the source file
+
+
+def _translate(pattern: str) -> re.Pattern[str]:
+    """Compile a gitignore-style pattern into a path regex."""
+    anchored = "/" in pattern.rstrip("/")
+    body = pattern.lstrip("/")
+    tokens = re.findall(r"\*\*/|\*\*|\*|\?|[^*?]+", body)
+    parts = []
+    for token in tokens:
+        if token == "**/":
+            parts.append("(?:.*/)?")
+        elif token == "**":
+            parts.append(".*")
+        elif token == "*":
+            parts.append("[^/]*")
+        elif token == "?":
+            parts.append("[^/]")
+        else:
+            parts.append(re.escape(token))
+    prefix = "^" if anchored else "^(?:.*/)?"
+    return re.compile(prefix + "".join(parts) + "$")
+
+
+def parse_patterns(text: str, source: str) -> list[IgnorePattern]:
+    """Parse ignore file content into patterns, keeping file order."""
+    patterns = []
+    for line_number, raw_line in enumerate(text.splitlines(), start=1):
+        line = raw_line.strip()
+        if not line or line.startswith("#"):
+            continue
+        negated = line.startswith("!")
+        if negated:
+            line = line[1:]
+        dir_only = line.endswith("/")
+        if dir_only:
+            line = line.rstrip("/")
+        if not line:
+            continue
+        patterns.append(IgnorePattern(
+            pattern=line,
+            negated=negated,
+            dir_only=dir_only,
+            source=source,
+            line=line_number,
+        ))
+    return patterns
+
+
+class IgnoreRules:
+    """Evaluates gitignore-style patterns against repo-relative paths."""
+
+    def __init__(self, patterns: list[IgnorePattern]) -> None:
+        self._patterns = patterns
+        self._compiled = [(pattern, _translate(pattern.pattern)) for pattern in patterns]
+
+    @classmethod
+    def load(cls, repo_root: Path, include_gitignore: bool = False) -> IgnoreRules:
+        """Load rules from the repo root.
+
+        .gitignore (when requested) is read first so .calderaignore entries
+        take precedence; missing files simply contribute no patterns.
+        """
+        patterns: list[IgnorePattern] = []
+        sources = ([GITIGNORE] if include_gitignore else []) + [CALDERAIGNORE]
+        for source in sources:
+            ignore_file = repo_root / source
+            if ignore_file.exists():
+                patterns.extend(parse_patterns(ignore_file.read_text(), source))
+        return cls(patterns)
+
+    def __bool__(self) -> bool:
+        return bool(self._patterns)
+
+    def _last_match(self, candidate: str, is_dir: bool) -> IgnorePattern | None:
+        result = None
+        for pattern, regex in self._compiled:
+            if pattern.dir_only and not is_dir:
+                continue
+            if regex.match(candidate):
+                result = pattern
+        return result
+
+    def decide(self, path: str, is_dir: bool = False) -> IgnorePattern | None:
+        """Return the pattern that decides this path's fate, if any.
+
+        Ancestor directories are checked first: once a directory is
+        ignored, nothing below it can be re-included.
+        """
+        parts = path.split("/")
+        for depth in range(1, len(parts)):
+            ancestor = "/".join(parts[:depth])
+            match = self._last_match(ancestor, is_dir=True)
+            if match is not None and not match.negated:
+                return match
+        return self._last_match(path, is_dir=is_dir)
+
+    def is_ignored(self, path: str, is_dir: bool = False) -> bool:
+        """Whether a repo-relative path should be skipped."""
+        decision = self.decide(path, is_dir=is_dir)
+        return decision is not None and not decision.negated
+
+    def explain(self, path: str, is_dir: bool = False) -> str:
+        """Human-readable explanation for ``explain-ignore``."""
+        decision = self.decide(path, is_dir=is_dir)
+        if decision is None:
+            return f"{path}: not ignored (no matching pattern)"
+        location = f"{decision.source}:{decision.line}"
+        if decision.negated:
+            return f"{path}: not ignored (re-included by {location} `!{decision.pattern}`)"
+        suffix = "/" if decision.dir_only else ""
+        return f"{path}: ignored (matched {location} `{decision.pattern}{suffix}`)"
@@ -0,0 +1,184 @@
+"""
+Input Resolver - Shared module for remote and archive scan inputs.
+
+Batch analysis jobs currently wrap the orchestrator in shell scripts
+to clone URLs or unpack snapshots before scanning. This module folds
+that in: a scan input may be a local directory, a git URL (with an
+optional ``#branch`` fragment, shallow-cloned), or an archive
+(``.tar.gz``/``.tgz``/``.tar``/``.zip``, extracted safely). Remote and
+archive inputs land in a temp workspace with a disk-space limit and
+are cleaned up when the scan finishes; local directories are used in
+place and never deleted.
+"""
+
+from __future__ import annotations
+
+import shutil
+import subprocess
+import tarfile
+import tempfile
+import zipfile
+from dataclasses import dataclass
+from pathlib import Path
+
+SOURCE_LOCAL = "local"
+SOURCE_GIT_URL = "git-url"
+SOURCE_ARCHIVE = "archive"
+
+ARCHIVE_SUFFIXES = (".tar.gz", ".tgz", ".tar.bz2", ".tar.xz", ".tar", ".zip")
+
+DEFAULT_MAX_WORKSPACE_MB = 2048
+CLONE_TIMEOUT_SECONDS = 600
+
+
+class InputResolutionError(RuntimeError):
+    """A scan input could not be materialized into a workspace."""
+
+
+@dataclass(frozen=True)
+class ResolvedInput:
+    """A scan input materialized on local disk."""
+
+    path: Path
+    source: str
+    source_kind: str
+    temporary: bool
+    workspace: Path | None = None  # temp root to delete (may be above path)
+
+    def cleanup(self) -> None:
+        """Delete the temp workspace; local inputs are never touched."""
+        if self.temporary and self.workspace and self.workspace.exists():
+            shutil.rmtree(self.workspace, ignore_errors=True)
+
+
+def classify_source(source: str) -> str:
+    """Classify a scan input as local directory, git URL, or archive."""
+    base = source.split("#", 1)[0]
+    if base.startswith(("http://", "https://", "git@", "ssh://", "file://")):
+        return SOURCE_GIT_URL
+    if base.endswith(ARCHIVE_SUFFIXES):
+        return SOURCE_ARCHIVE
+    return SOURCE_LOCAL
+
+
+def parse_git_source(source: str) -> tuple[str, str | None]:
+    """Split ``url#branch`` into URL and optional branch."""
+    if "#" in source:
+        url, branch = source.split("#", 1)
+        return url, branch or None
+    return source, None
+
+
+def clone_repository(url: str, branch: str | None, dest: Path) -> None:
+    """Shallow-clone a repository into dest."""
+    command = ["git", "clone", "--depth", "1"]
+    if branch:
+        command += ["--branch", branch]
+    command += [url, str(dest)]
+    try:
+        result = subprocess.run(
+            command,
+            capture_output=True,
+            text=True,
+            timeout=CLONE_TIMEOUT_SECONDS,
+        )
+    except subprocess.TimeoutExpired:
+        raise InputResolutionError(f"Clone timed out after {CLONE_TIMEOUT_SECONDS}s: {url}")
+    except FileNotFoundError:
+        raise InputResolutionError("git is not installed or not on PATH")
+    if result.returncode != 0:
+        tail = (result.stderr or result.stdout).strip().splitlines()[-3:]
+        raise InputResolutionError(f"Clone failed for {url}: {' / '.join(tail)}")
+
+
+def _check_member_path(dest: Path, name: str) -> None:
+    target = (dest / name).resolve()
+    if not target.is_relative_to(dest.resolve()):
+        raise InputResolutionError(f"Archive member escapes workspace: {name}")
+
+
+def extract_archive(archive_path: Path, dest: Path, max_workspace_mb: int) -> None:
+    """Extract an archive into dest with traversal and size guards.
+
+    Members with absolute or ``..`` paths and symlink/hardlink members
+    are rejected; extraction aborts once the cumulative uncompressed
+    size exceeds the workspace limit (zip-bomb guard).
+    """
+    budget = max_workspace_mb * 1024 * 1024
+    extracted = 0
+    if archive_path.name.endswith(".zip"):
+        with zipfile.ZipFile(archive_path) as archive:
+            for info in archive.infolist():
+                _check_member_path(dest, info.filename)
+                extracted += info.file_size
+                if extracted > budget:
+                    raise InputResolutionError(
+                        f"Archive exceeds workspace limit of {max_workspace_mb}MB"
+                    )
+                archive.extract(info, dest)
+        return
+    try:
+        with tarfile.open(archive_path) as archive:
+            for member in archive:
+                if member.islnk() or member.issym():
+                    raise InputResolutionError(
+                        f"Archive member is a link (not allowed): {member.name}"
+                    )
+                _check_member_path(dest, member.name)
+                extracted += member.size
+                if extracted > budget:
+                    raise InputResolutionError(
+                        f"Archive exceeds workspace limit of {max_workspace_mb}MB"
+                    )
+                archive.extract(member, dest)
+    except tarfile.TarError as exc:
+        raise InputResolutionError(f"Cannot extract {archive_path.name}: {exc}")
+
+
+def _strip_single_root(workspace: Path) -> Path:
+    """Descend into a lone top-level directory (tar convention)."""
+    entries = list(workspace.iterdir())
+    if len(entries) == 1 and entries[0].is_dir():
+        return entries[0]
+    return workspace
+
+
+def resolve_input(
+    source: str,
+    max_workspace_mb: int = DEFAULT_MAX_WORKSPACE_MB,
+    workspace_root: Path | None = None,
+) -> ResolvedInput:
+    """Materialize a scan input, cloning or extracting when needed.
+
+    The caller owns the result and must call ``cleanup()`` when done
+    (a no-op for local directories).
+    """
+    kind = classify_source(source)
+
+    if kind == SOURCE_LOCAL:
+        path = Path(source)
+        if not path.is_dir():
+            raise InputResolutionError(f"Not a directory: {source}")
+        return ResolvedInput(path=path, source=source, source_kind=kind, temporary=False)
+
+    workspace = Path(tempfile.mkdtemp(
+        prefix="caldera-scan-",
+        dir=str(workspace_root) if workspace_root else None,
+    ))
+    try:
+        if kind == SOURCE_GIT_URL:
+            url, branch = parse_git_source(source)
+            clone_repository(url, branch, workspace / "repo")
+            path = workspace / "repo"
+        else:
+            archive_path = Path(source)
+            if not archive_path.is_file():
+                raise InputResolutionError(f"Archive not found: {source}")
+            extract_archive(archive_path, workspace, max_workspace_mb)
+            path = _strip_single_root(workspace)
+    except Exception:
+        shutil.rmtree(workspace, ignore_errors=True)
+        raise
+    return ResolvedInput(
+        path=path, source=source, source_kind=kind, temporary=True, workspace=workspace
+    )
@@ -0,0 +1,259 @@
+"""
+Language Detector - Shared module for consistent language assignment.
+
+Tools disagree on what language a file is (lizard, scc, and CPD each ship
+their own extension tables), which skews per-language metrics in the marts.
+This module gives every adapter one source of truth, resolved in precedence
+order:
+
+1. Config overrides (glob rules like ``*.tpl = html``)
+2. ``.gitattributes`` ``linguist-language`` annotations
+3. Well-known filenames (Makefile, Dockerfile, ...)
+4. File extension
+5. Shebang line (``#!/usr/bin/env python3``, ...)
+
+Detected names are canonicalized (``golang`` -> ``Go``, ``html`` -> ``HTML``)
+so downstream grouping keys are stable across sources.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from fnmatch import fnmatch
+from pathlib import Path
+
+# Canonical language names keyed by file extension (without the dot).
+EXTENSION_MAP: dict[str, str] = {
+    "py": "Python",
+    "pyi": "Python",
+    "js": "JavaScript",
+    "mjs": "JavaScript",
+    "cjs": "JavaScript",
+    "jsx": "JSX",
+    "ts": "TypeScript",
+    "tsx": "TSX",
+    "go": "Go",
+    "rs": "Rust",
+    "java": "Java",
+    "kt": "Kotlin",
+    "kts": "Kotlin",
+    "cs": "C#",
+    "c": "C",
+    "h": "C Header",
+    "cpp": "C++",
+    "cc": "C++",
+    "cxx": "C++",
+    "hpp": "C++ Header",
+    "rb": "Ruby",
+    "php": "PHP",
+    "swift": "Swift",
+    "scala": "Scala",
+    "sh": "Shell",
+    "bash": "Shell",
+    "zsh": "Shell",
+    "ps1": "PowerShell",
+    "sql": "SQL",
+    "html": "HTML",
+    "htm": "HTML",
+    "css": "CSS",
+    "scss": "SCSS",
+    "less": "LESS",
+    "json": "JSON",
+    "yaml": "YAML",
+    "yml": "YAML",
+    "toml": "TOML",
+    "xml": "XML",
+    "md": "Markdown",
+    "tf": "Terraform",
+    "proto": "Protocol Buffers",
+    "r": "R",
+    "pl": "Perl",
+    "lua": "Lua",
+    "dart": "Dart",
+    "vue": "Vue",
+}
+
+# Canonical language names keyed by exact filename.
+FILENAME_MAP: dict[str, str] = {
+    "Makefile": "Makefile",
+    "makefile": "Makefile",
+    "GNUmakefile": "Makefile",
+    "Dockerfile": "Dockerfile",
+    "Jenkinsfile": "Groovy",
+    "Rakefile": "Ruby",
+    "Gemfile": "Ruby",
+    "CMakeLists.txt": "CMake",
+}
+
+# Canonical language names keyed by shebang interpreter basename.
+SHEBANG_MAP: dict[str, str] = {
+    "python": "Python",
+    "python3": "Python",
+    "node": "JavaScript",
+    "sh": "Shell",
+    "bash": "Shell",
+    "zsh": "Shell",
+    "ruby": "Ruby",
+    "perl": "Perl",
+    "php": "PHP",
+}
+
+# Lowercase aliases accepted from overrides/.gitattributes -> canonical name.
+LANGUAGE_ALIASES: dict[str, str] = {
+    **{language.lower(): language for language in EXTENSION_MAP.values()},
+    **{language.lower(): language for language in FILENAME_MAP.values()},
+    "golang": "Go",
+    "csharp": "C#",
+    "c#": "C#",
+    "c++": "C++",
+    "js": "JavaScript",
+    "ts": "TypeScript",
+    "py": "Python",
+    "shell script": "Shell",
+}
+
+
+@dataclass(frozen=True)
+class LanguageDetection:
+    """Resolved language for a single file."""
+
+    file_path: str  # repo-relative
+    language: str  # canonical name, or "Unknown"
+    source: str  # override | gitattributes | filename | extension | shebang | unknown
+
+
+def canonicalize_language(name: str) -> str:
+    """Map a language name from any source to its canonical form."""
+    stripped = name.strip()
+    return LANGUAGE_ALIASES.get(stripped.lower(), stripped)
+
+
+def parse_override_rules(text: str) -> dict[str, str]:
+    """Parse override rules of the form ``<glob> = <language>``, one per line.
+
+    Blank lines and ``#`` comments are ignored. Later rules win over earlier
+    ones for the same pattern.
+    """
+    rules: dict[str, str] = {}
+    for raw_line in text.splitlines():
+        line = raw_line.strip()
+        if not line or line.startswith("#"):
+            continue
+        if "=" not in line:
+            raise ValueError(f"invalid language override rule: {raw_line!r}")
+        pattern, _, language = line.partition("=")
+        if not pattern.strip() or not language.strip():
+            raise ValueError(f"invalid language override rule: {raw_line!r}")
+        rules[pattern.strip()] = canonicalize_language(language)
+    return rules
+
+
+def parse_gitattributes(text: str) -> dict[str, str]:
+    """Extract ``linguist-language`` assignments from .gitattributes content."""
+    rules: dict[str, str] = {}
+    for raw_line in text.splitlines():
+        line = raw_line.strip()
+        if not line or line.startswith("#"):
+            continue
+        parts = line.split()
+        pattern = parts[0]
+        for attribute in parts[1:]:
+            if attribute.startswith("linguist-language="):
+                rules[pattern] = canonicalize_language(
+                    attribute.split("=", 1)[1]
+                )
+    return rules
+
+
+def _shebang_language(first_line: str) -> str | None:
+    if not first_line.startswith("#!"):
+        return None
+    parts = first_line[2:].strip().split()
+    if not parts:
+        return None
+    interpreter = Path(parts[0]).name
+    if interpreter == "env" and len(parts) > 1:
+        interpreter = Path(parts[1]).name
+    # Tolerate versioned interpreters like python3.12
+    for known, language in SHEBANG_MAP.items():
+        if interpreter == known or interpreter.startswith(known + "."):
+            return language
+    return None
+
+
+class LanguageDetector:
+    """Resolves one consistent language per file for all adapters.
+
+    Overrides and .gitattributes rules are glob patterns matched against the
+    repo-relative path (and, for bare patterns like ``*.tpl``, against the
+    basename).
+    """
+
+    def __init__(
+        self,
+        repo_root: Path | None = None,
+        overrides: dict[str, str] | None = None,
+    ) -> None:
+        self._repo_root = repo_root
+        self._overrides = {
+            pattern: canonicalize_language(language)
+            for pattern, language in (overrides or {}).items()
+        }
+        self._gitattributes: dict[str, str] = {}
+        if repo_root is not None:
+            gitattributes_path = repo_root / ".gitattributes"
+            if gitattributes_path.exists():
+                self._gitattributes = parse_gitattributes(
+                    gitattributes_path.read_text(encoding="utf-8", errors="replace")
+                )
+
+    def detect(self, file_path: str, first_line: str | None = None) -> LanguageDetection:
+        """Detect the language of a repo-relative file path.
+
+        ``first_line`` feeds shebang detection; when omitted and a repo root
+        is configured, it is read from disk for extensionless files.
+        """
+        matched = self._match_rules(self._overrides, file_path)
+        if matched:
+            return LanguageDetection(file_path, matched, "override")
+
+        matched = self._match_rules(self._gitattributes, file_path)
+        if matched:
+            return LanguageDetection(file_path, matched, "gitattributes")
+
+        name = Path(file_path).name
+        if name in FILENAME_MAP:
+            return LanguageDetection(file_path, FILENAME_MAP[name], "filename")
+
+        suffix = Path(file_path).suffix.lstrip(".").lower()
+        if suffix in EXTENSION_MAP:
+            return LanguageDetection(file_path, EXTENSION_MAP[suffix], "extension")
+
+        if first_line is None:
+            first_line = self._read_first_line(file_path)
+        if first_line:
+            shebang = _shebang_language(first_line)
+            if shebang:
+                return LanguageDetection(file_path, shebang, "shebang")
+
+        return LanguageDetection(file_path, "Unknown", "unknown")
+
+    def _match_rules(self, rules: dict[str, str], file_path: str) -> str | None:
+        basename = Path(file_path).name
+        for pattern, language in rules.items():
+            candidate = basename if "/" not in pattern else file_path
+            if fnmatch(candidate, pattern):
+                return language
+        return None
+
+    def _read_first_line(self, file_path: str) -> str | None:
+        if self._repo_root is None:
+            return None
+        full_path = self._repo_root / file_path
+        if not full_path.is_file():
+            return None
+        try:
+            with full_path.open(encoding="utf-8", errors="replace") as handle:
+                return handle.readline()
+        except OSError:
+            return None
@@ -0,0 +1,257 @@
+"""jq-style filter and projection over reporter records.
+
+Every reporter that emits rows of JSON-shaped records (``caldera query``,
+``caldera hook run``, ``insights export``) accepts the same ``--filter``
+and ``--select`` flags so scripts can extract exactly the fields they
+need without piping through jq. This module is the shared implementation:
+``--filter`` is a boolean expression evaluated in Python against each
+record, which makes it work uniformly whether the rows came from DuckDB,
+a git scan, or a JSON file; ``--select`` is a comma-separated list of
+(dotted) field paths to keep.
+
+The expression grammar mirrors the ``caldera query`` language: ``=``
+``!=`` ``<`` ``<=`` ``>`` ``>=`` ``GLOB`` ``LIKE`` comparisons combined
+with ``AND``/``OR``/``NOT`` and parentheses — but fields are arbitrary
+dotted paths into the record rather than a fixed whitelist. Ordered
+comparisons are numeric when both sides parse as numbers, lexicographic
+otherwise; a missing field fails every comparison except ``!=``.
+"""
+
+from __future__ import annotations
+
+import fnmatch
+import re
+from dataclasses import dataclass
+from typing import Any, Iterable
+
+
+class FilterError(ValueError):
+    """Invalid --filter or --select expression."""
+
+
+# --- Parsing -----------------------------------------------------------------
+
+_TOKEN_RE = re.compile(
+    r"""\s*(?:
+        (?P<string>'(?:[^'\\]|\\.)*'|"(?:[^"\\]|\\.)*")
+      | (?P<number>-?\d+(?:\.\d+)?)
+      | (?P<ident>[A-Za-z_][A-Za-z0-9_.-]*)
+      | (?P<op>>=|<=|!=|=|<|>)
+      | (?P<paren>[()])
+    )""",
+    re.VERBOSE,
+)
+
+_KEYWORDS = {"AND", "OR", "NOT", "GLOB", "LIKE"}
+
+
+@dataclass(frozen=True)
+class _Token:
+    kind: str  # string | number | ident | keyword | op | paren | end
+    value: str
+
+
+def _tokenize(text: str) -> list[_Token]:
+    tokens: list[_Token] = []
+    pos = 0
+    while pos < len(text):
+        match = _TOKEN_RE.match(text, pos)
+        if not match:
+            remainder = text[pos:].strip()
+            if not remainder:
+                break
+            raise FilterError(f"unexpected character at: {remainder[:20]!r}")
+        pos = match.end()
+        if match.lastgroup == "string":
+            raw = match.group("string")
+            tokens.append(_Token("string", re.sub(r"\\(.)", r"\1", raw[1:-1])))
+        elif match.lastgroup == "number":
+            tokens.append(_Token("number", match.group("number")))
+        elif match.lastgroup == "ident":
+            word = match.group("ident")
+            if word.upper() in _KEYWORDS:
+                tokens.append(_Token("keyword", word.upper()))
+            else:
+                tokens.append(_Token("ident", word))
+        elif match.lastgroup == "op":
+            tokens.append(_Token("op", match.group("op")))
+        else:
+            tokens.append(_Token("paren", match.group("paren")))
+    tokens.append(_Token("end", ""))
+    return tokens
+
+
+@dataclass(frozen=True)
+class Comparison:
+    field: str
+    op: str  # = != < <= > >= GLOB LIKE
+    value: str
+
+
+@dataclass(frozen=True)
+class Not:
+    operand: "Node"
+
+
+@dataclass(frozen=True)
+class BoolOp:
+    op: str  # AND | OR
+    left: "Node"
+    right: "Node"
+
+
+Node = Comparison | Not | BoolOp
+
+
+class _Parser:
+    def __init__(self, tokens: list[_Token]) -> None:
+        self._tokens = tokens
+        self._pos = 0
+
+    def _peek(self) -> _Token:
+        return self._tokens[self._pos]
+
+    def _next(self) -> _Token:
+        token = self._tokens[self._pos]
+        self._pos += 1
+        return token
+
+    def parse(self) -> Node:
+        node = self._or_expr()
+        tail = self._peek()
+        if tail.kind != "end":
+            raise FilterError(f"unexpected trailing input at {tail.value!r}")
+        return node
+
+    def _or_expr(self) -> Node:
+        node = self._and_expr()
+        while self._peek() == _Token("keyword", "OR"):
+            self._next()
+            node = BoolOp("OR", node, self._and_expr())
+        return node
+
+    def _and_expr(self) -> Node:
+        node = self._not_expr()
+        while self._peek() == _Token("keyword", "AND"):
+            self._next()
+            node = BoolOp("AND", node, self._not_expr())
+        return node
+
+    def _not_expr(self) -> Node:
+        if self._peek() == _Token("keyword", "NOT"):
+            self._next()
+            return Not(self._not_expr())
+        return self._primary()
+
+    def _primary(self) -> Node:
+        if self._peek() == _Token("paren", "("):
+            self._next()
+            node = self._or_expr()
+            if self._next() != _Token("paren", ")"):
+                raise FilterError("missing closing parenthesis")
+            return node
+        return self._comparison()
+
+    def _comparison(self) -> Comparison:
+        field_token = self._next()
+        if field_token.kind != "ident":
+            raise FilterError(f"expected a field name, got {field_token.value or 'end of expression'!r}")
+        op_token = self._next()
+        if op_token.kind == "op":
+            op = op_token.value
+        elif op_token.kind == "keyword" and op_token.value in ("GLOB", "LIKE"):
+            op = op_token.value
+        else:
+            raise FilterError(
+                f"expected an operator after {field_token.value!r}, got {op_token.value!r}"
+            )
+        value_token = self._next()
+        if value_token.kind not in ("string", "number", "ident"):
+            raise FilterError(f"expected a value after {field_token.value} {op}")
+        return Comparison(field_token.value, op, value_token.value)
+
+
+def parse_filter(text: str) -> Node:
+    """Parse a --filter expression; raises FilterError on invalid input."""
+    if not text.strip():
+        raise FilterError("empty filter expression")
+    return _Parser(_tokenize(text)).parse()
+
+
+def parse_select(spec: str) -> tuple[str, ...]:
+    """Parse a --select field list ("path,line" or "meta.fingerprint")."""
+    fields = tuple(field.strip() for field in spec.split(",") if field.strip())
+    if not fields:
+        raise FilterError("empty select list")
+    return fields
+
+
+# --- Evaluation --------------------------------------------------------------
+
+
+def lookup(record: dict, field: str) -> Any:
+    """Resolve a dotted field path in a record; None when any step is missing."""
+    value: Any = record
+    for part in field.split("."):
+        if not isinstance(value, dict) or part not in value:
+            return None
+        value = value[part]
+    return value
+
+
+def _as_number(value: Any) -> float | None:
+    if isinstance(value, bool):
+        return None
+    try:
+        return float(value)
+    except (TypeError, ValueError):
+        return None
+
+
+def _compare(actual: Any, op: str, expected: str) -> bool:
+    if actual is None:
+        return op == "!="
+    if isinstance(actual, bool):
+        actual = "true" if actual else "false"
+    if op == "GLOB":
+        return fnmatch.fnmatchcase(str(actual), expected)
+    if op == "LIKE":
+        pattern = re.escape(expected).replace("%", ".*").replace("_", ".")
+        return re.fullmatch(pattern, str(actual), re.IGNORECASE) is not None
+    actual_num, expected_num = _as_number(actual), _as_number(expected)
+    if actual_num is not None and expected_num is not None:
+        left, right = actual_num, expected_num
+    else:
+        left, right = str(actual), str(expected)
+    if op == "=":
+        return left == right
+    if op == "!=":
+        return left != right
+    if op == "<":
+        return left < right
+    if op == "<=":
+        return left <= right
+    if op == ">":
+        return left > right
+    return left >= right
+
+
+def matches(node: Node, record: dict) -> bool:
+    """Whether one record satisfies a parsed filter expression."""
+    if isinstance(node, Comparison):
+        return _compare(lookup(record, node.field), node.op, node.value)
+    if isinstance(node, Not):
+        return not matches(node.operand, record)
+    if node.op == "AND":
+        return matches(node.left, record) and matches(node.right, record)
+    return matches(node.left, record) or matches(node.right, record)
+
+
+def apply_filter(records: Iterable[dict], node: Node) -> list[dict]:
+    """Records that satisfy the filter, in input order."""
+    return [record for record in records if matches(node, record)]
+
+
+def apply_select(records: Iterable[dict], fields: tuple[str, ...]) -> list[dict]:
+    """Project records down to the selected fields, keyed by their paths."""
+    return [{field: lookup(record, field) for field in fields} for record in records]
@@ -0,0 +1,302 @@
+"""Lightweight custom rule engine configured via rules/*.yaml.
+
+Teams can express simple organization-specific checks without writing
+semgrep rules or a full plugin. A rules file looks like:
+
+    rules:
+      - id: no-print
+        kind: banned-api
+        severity: HIGH
+        description: Use the structured logger instead of print
+        apis: [print, pprint]
+        paths: ["src/**"]
+        exclude: ["**/tests/**"]
+      - id: license-header
+        kind: required-header
+        pattern: "Copyright"
+        max_lines: 5
+        paths: ["**/*.py"]
+
+Rule kinds:
+
+- ``regex``            pattern matched line by line
+- ``banned-api``       identifiers matched on word boundaries
+- ``required-header``  pattern that must appear within the first
+                       ``max_lines`` lines; one finding when it is missing
+- ``structural``       tree-sitter node matcher (``language`` +
+                       ``node_type`` + optional ``pattern`` over the node
+                       text); active only when tree-sitter is installed
+
+Scanning honors ``.calderaignore`` so custom rules see the same file
+universe as the rest of the pipeline.
+"""
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+
+import yaml
+
+from common.ignore_rules import IgnoreRules
+
+# Try to import tree-sitter for structural rules (optional)
+try:
+    from tree_sitter_language_pack import get_parser
+    TREESITTER_AVAILABLE = True
+except ImportError:
+    TREESITTER_AVAILABLE = False
+
+RULE_KINDS = ("regex", "banned-api", "required-header", "structural")
+SEVERITIES = ("HIGH", "MEDIUM", "LOW")
+
+# Extensions covered by structural rules, mapped to tree-sitter grammars
+EXTENSION_LANGUAGES = {
+    ".py": "python",
+    ".js": "javascript",
+    ".ts": "typescript",
+    ".go": "go",
+    ".rs": "rust",
+    ".java": "java",
+    ".cs": "c_sharp",
+}
+
+
+@dataclass(frozen=True)
+class Rule:
+    """One validated rule from a rules/*.yaml file."""
+
+    rule_id: str
+    kind: str
+    severity: str = "MEDIUM"
+    description: str = ""
+    pattern: str | None = None
+    apis: tuple[str, ...] = ()
+    max_lines: int = 10
+    language: str | None = None
+    node_type: str | None = None
+    paths: tuple[str, ...] = ("**",)
+    exclude: tuple[str, ...] = ()
+
+    def __post_init__(self) -> None:
+        if not self.rule_id:
+            raise ValueError("rule id must be non-empty")
+        if self.kind not in RULE_KINDS:
+            raise ValueError(f"rule {self.rule_id}: unknown kind {self.kind!r}")
+        if self.severity not in SEVERITIES:
+            raise ValueError(f"rule {self.rule_id}: unknown severity {self.severity!r}")
+        if self.kind in ("regex", "required-header") and not self.pattern:
+            raise ValueError(f"rule {self.rule_id}: kind {self.kind} requires a pattern")
+        if self.kind == "banned-api" and not self.apis:
+            raise ValueError(f"rule {self.rule_id}: kind banned-api requires apis")
+        if self.kind == "structural" and (not self.language or not self.node_type):
+            raise ValueError(
+                f"rule {self.rule_id}: kind structural requires language and node_type"
+            )
+        if self.pattern is not None:
+            try:
+                re.compile(self.pattern)
+            except re.error as exc:
+                raise ValueError(f"rule {self.rule_id}: invalid pattern: {exc}") from exc
+        if self.max_lines < 1:
+            raise ValueError(f"rule {self.rule_id}: max_lines must be >= 1")
+
+
+@dataclass(frozen=True)
+class RuleFinding:
+    """A single rule violation in one file."""
+
+    rule_id: str
+    severity: str
+    path: str
+    line: int
+    message: str
+    excerpt: str
+
+
+def _compile_glob(pattern: str) -> re.Pattern[str]:
+    """Compile a path glob; ``**`` spans separators, ``dir/**`` includes files below."""
+    suffix = "$"
+    if pattern.endswith("/**"):
+        pattern = pattern[:-3]
+        suffix = "(?:/.*)?$"
+    tokens = re.findall(r"\*\*/|\*\*|\*|\?|[^*?]+", pattern)
+    parts = []
+    for token in tokens:
+        if token == "**/":
+            parts.append("(?:.*/)?")
+        elif token == "**":
+            parts.append(".*")
+        elif token == "*":
+            parts.append("[^/]*")
+        elif token == "?":
+            parts.append("[^/]")
+        else:
+            parts.append(re.escape(token))
+    return re.compile("^" + "".join(parts) + suffix)
+
+
+def load_rules(rules_dir: Path) -> list[Rule]:
+    """Load all rules from rules/*.yaml in filename order.
+
+    Raises:
+        ValueError: On malformed files, unknown kinds, or duplicate ids
+    """
+    rules: list[Rule] = []
+    seen: set[str] = set()
+    if not rules_dir.is_dir():
+        return rules
+    for rules_file in sorted(rules_dir.glob("*.yaml")):
+        document = yaml.safe_load(rules_file.read_text()) or {}
+        if not isinstance(document, dict) or not isinstance(document.get("rules"), list):
+            raise ValueError(f"{rules_file.name}: expected a top-level 'rules' list")
+        for entry in document["rules"]:
+            if not isinstance(entry, dict):
+                raise ValueError(f"{rules_file.name}: each rule must be a mapping")
+            rule = Rule(
+                rule_id=str(entry.get("id", "")),
+                kind=str(entry.get("kind", "")),
+                severity=str(entry.get("severity", "MEDIUM")),
+                description=str(entry.get("description", "")),
+                pattern=entry.get("pattern"),
+                apis=tuple(entry.get("apis", [])),
+                max_lines=int(entry.get("max_lines", 10)),
+                language=entry.get("language"),
+                node_type=entry.get("node_type"),
+                paths=tuple(entry.get("paths", ["**"])),
+                exclude=tuple(entry.get("exclude", [])),
+            )
+            if rule.rule_id in seen:
+                raise ValueError(f"{rules_file.name}: duplicate rule id {rule.rule_id!r}")
+            seen.add(rule.rule_id)
+            rules.append(rule)
+    return rules
+
+
+def rule_applies(rule: Rule, path: str) -> bool:
+    """Whether a rule's path globs select this repo-relative path."""
+    if any(_compile_glob(pattern).match(path) for pattern in rule.exclude):
+        return False
+    return any(_compile_glob(pattern).match(path) for pattern in rule.paths)
+
+
+def _message(rule: Rule, fallback: str) -> str:
+    return rule.description or fallback
+
+
+def _regex_findings(rule: Rule, path: str, lines: list[str]) -> list[RuleFinding]:
+    regex = re.compile(rule.pattern or "")
+    return [
+        RuleFinding(
+            rule_id=rule.rule_id,
+            severity=rule.severity,
+            path=path,
+            line=line_number,
+            message=_message(rule, f"pattern {rule.pattern!r} matched"),
+            excerpt=line.strip(),
+        )
+        for line_number, line in enumerate(lines, start=1)
+        if regex.search(line)
+    ]
+
+
+def _banned_api_findings(rule: Rule, path: str, lines: list[str]) -> list[RuleFinding]:
+    regex = re.compile(
+        r"\b(?:" + "|".join(re.escape(api) for api in rule.apis) + r")\b"
+    )
+    findings = []
+    for line_number, line in enumerate(lines, start=1):
+        match = regex.search(line)
+        if match:
+            findings.append(RuleFinding(
+                rule_id=rule.rule_id,
+                severity=rule.severity,
+                path=path,
+                line=line_number,
+                message=_message(rule, f"banned API {match.group(0)!r} used"),
+                excerpt=line.strip(),
+            ))
+    return findings
+
+
+def _required_header_findings(rule: Rule, path: str, lines: list[str]) -> list[RuleFinding]:
+    regex = re.compile(rule.pattern or "")
+    if any(regex.search(line) for line in lines[: rule.max_lines]):
+        return []
+    return [RuleFinding(
+        rule_id=rule.rule_id,
+        severity=rule.severity,
+        path=path,
+        line=1,
+        message=_message(
+            rule, f"required header {rule.pattern!r} missing from first {rule.max_lines} lines"
+        ),
+        excerpt=lines[0].strip() if lines else "",
+    )]
+
+
+def _structural_findings(rule: Rule, path: str, text: str) -> list[RuleFinding]:
+    if not TREESITTER_AVAILABLE:
+        return []
+    if EXTENSION_LANGUAGES.get(Path(path).suffix) != rule.language:
+        return []
+    parser = get_parser(rule.language)
+    tree = parser.parse(text.encode("utf-8"))
+    text_regex = re.compile(rule.pattern) if rule.pattern else None
+    findings = []
+    stack = [tree.root_node]
+    while stack:
+        node = stack.pop()
+        stack.extend(node.children)
+        if node.type != rule.node_type:
+            continue
+        node_text = node.text.decode("utf-8", errors="replace") if node.text else ""
+        if text_regex is not None and not text_regex.search(node_text):
+            continue
+        findings.append(RuleFinding(
+            rule_id=rule.rule_id,
+            severity=rule.severity,
+            path=path,
+            line=node.start_point[0] + 1,
+            message=_message(rule, f"{rule.node_type} node matched"),
+            excerpt=node_text.splitlines()[0].strip() if node_text else "",
+        ))
+    return findings
+
+
+def scan_file(rules: list[Rule], path: str, text: str) -> list[RuleFinding]:
+    """Apply all applicable rules to one file's content."""
+    lines = text.splitlines()
+    findings: list[RuleFinding] = []
+    for rule in rules:
+        if not rule_applies(rule, path):
+            continue
+        if rule.kind == "regex":
+            findings.extend(_regex_findings(rule, path, lines))
+        elif rule.kind == "banned-api":
+            findings.extend(_banned_api_findings(rule, path, lines))
+        elif rule.kind == "required-header":
+            findings.extend(_required_header_findings(rule, path, lines))
+        elif rule.kind == "structural":
+            findings.extend(_structural_findings(rule, path, text))
+    return findings
+
+
+def scan_tree(repo_root: Path, rules: list[Rule]) -> list[RuleFinding]:
+    """Scan a repository tree, honoring .calderaignore and skipping binaries."""
+    ignore_rules = IgnoreRules.load(repo_root)
+    findings: list[RuleFinding] = []
+    for file_path in sorted(repo_root.rglob("*")):
+        if not file_path.is_file():
+            continue
+        relative_path = file_path.relative_to(repo_root).as_posix()
+        if relative_path.startswith(".git/"):
+            continue
+        if ignore_rules.is_ignored(relative_path):
+            continue
+        try:
+            text = file_path.read_text(encoding="utf-8")
+        except (UnicodeDecodeError, OSError):
+            continue
+        findings.extend(scan_file(rules, relative_path, text))
+    return findings
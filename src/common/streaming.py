@@ -0,0 +1,457 @@
+"""
+Memory-Bounded Streaming Parsers - Shared module for large tool outputs.
+
+A CPD run over a large monorepo emits hundreds of MB of XML, and a
+semgrep run with the full ruleset can produce multi-GB JSON. Reading
+those outputs into one string before parsing is what drives peak RSS on
+big scans. This module provides pull parsers that keep only the record
+currently being decoded in memory:
+
+* ``iter_xml_elements`` — wraps ``ElementTree.iterparse`` and frees each
+  yielded element (and the processed siblings accumulated on the root),
+  so the document tree never materializes.
+* ``iter_json_array`` — incrementally decodes the items of one top-level
+  array (e.g. semgrep's ``results``) from a JSON file using chunked
+  reads and ``JSONDecoder.raw_decode``; the buffer is bounded by the
+  largest single item plus one read chunk, not the file size.
+
+For outputs that are not well-formed at all — stderr interleaved into
+stdout, truncation on timeout — the ``recover_*`` variants salvage every
+complete record they can find, never raise on garbage, and report what
+was dropped through a ``ParseDiagnostics`` that tools attach to the run.
+
+All parsers accept a path or an open file object. Consumers still decide
+what to keep; the guarantee here is that the raw output text is never
+held whole.
+"""
+
+from __future__ import annotations
+
+import json
+from collections.abc import Iterator
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import IO, Any
+from xml.etree import ElementTree as ET
+
+# Bytes read per refill when streaming JSON.
+STREAM_CHUNK_SIZE = 65536
+
+# Parse diagnostics keep at most this many example error messages.
+_DIAG_ERROR_LIMIT = 10
+
+
+@dataclass
+class ParseDiagnostics:
+    """What recovery parsing salvaged and what it had to drop.
+
+    Attached to the run output so a truncated or stderr-polluted tool
+    report is visible in the data instead of silently shrinking it.
+    """
+
+    records_recovered: int = 0
+    records_discarded: int = 0
+    truncated: bool = False
+    errors: list[str] = field(default_factory=list)
+
+    def note(self, message: str) -> None:
+        if len(self.errors) < _DIAG_ERROR_LIMIT:
+            self.errors.append(message)
+
+    @property
+    def clean(self) -> bool:
+        return not (self.records_discarded or self.truncated or self.errors)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "records_recovered": self.records_recovered,
+            "records_discarded": self.records_discarded,
+            "truncated": self.truncated,
+            "errors": list(self.errors),
+        }
+
+
+def iter_xml_elements(source: str | Path | IO, tag: str) -> Iterator[ET.Element]:
+    """Yield each ``tag`` element from an XML document, freeing as it goes.
+
+    Elements are yielded on their end event with their subtree intact;
+    after the consumer returns, the element and everything already
+    processed is cleared off the root. Raises ``ET.ParseError`` for
+    malformed input, like ``fromstring``.
+    """
+    if isinstance(source, (str, Path)):
+        context = ET.iterparse(str(source), events=("start", "end"))
+    else:
+        context = ET.iterparse(source, events=("start", "end"))
+    root: ET.Element | None = None
+    for event, element in context:
+        if event == "start":
+            if root is None:
+                root = element
+            continue
+        if element.tag == tag:
+            yield element
+            element.clear()
+            if root is not None and element is not root:
+                # Drop processed children so the root doesn't accumulate
+                # the whole document.
+                root.clear()
+
+
+class _JSONStream:
+    """Chunked reader with ``raw_decode`` over a bounded buffer."""
+
+    def __init__(self, handle: IO[str], chunk_size: int) -> None:
+        self._handle = handle
+        self._chunk_size = chunk_size
+        self._buffer = ""
+        self._pos = 0
+        self._eof = False
+        self._decoder = json.JSONDecoder()
+
+    def _fill(self) -> bool:
+        """Read one more chunk; returns False at end of input."""
+        if self._eof:
+            return False
+        chunk = self._handle.read(self._chunk_size)
+        if not chunk:
+            self._eof = True
+            return False
+        # Compact consumed input so the buffer stays bounded by one
+        # item plus one chunk.
+        self._buffer = self._buffer[self._pos:] + chunk
+        self._pos = 0
+        return True
+
+    def skip_whitespace(self) -> None:
+        while True:
+            while self._pos < len(self._buffer) and self._buffer[self._pos] in " \t\r\n":
+                self._pos += 1
+            if self._pos < len(self._buffer) or not self._fill():
+                return
+
+    def peek(self) -> str:
+        if self._pos >= len(self._buffer) and not self._fill():
+            return ""
+        return self._buffer[self._pos]
+
+    def expect(self, char: str) -> None:
+        if self.peek() != char:
+            raise json.JSONDecodeError(f"expected {char!r}", self._buffer, self._pos)
+        self._pos += 1
+
+    def accept(self, char: str) -> bool:
+        if self.peek() == char:
+            self._pos += 1
+            return True
+        return False
+
+    def decode_value(self) -> Any:
+        """Decode one JSON value, refilling until it is complete."""
+        while True:
+            try:
+                value, end = self._decoder.raw_decode(self._buffer, self._pos)
+            except json.JSONDecodeError:
+                if self._fill():
+                    continue
+                raise
+            # A bare literal ending exactly at the buffer edge may be a
+            # prefix of a longer token (e.g. "12" of "123"); refill once
+            # more before trusting it.
+            if end == len(self._buffer) and self._fill():
+                continue
+            self._pos = end
+            return value
+
+
+def iter_json_array(
+    source: str | Path | IO[str], key: str, chunk_size: int = STREAM_CHUNK_SIZE
+) -> Iterator[Any]:
+    """Yield the items of the top-level array ``key`` from a JSON object.
+
+    Other top-level members are skipped; sibling arrays are skipped
+    item by item, so a giant ``results`` array never loads into memory
+    while streaming ``errors``. Yields nothing when the key is absent.
+    Raises ``json.JSONDecodeError`` for malformed input.
+    """
+    if isinstance(source, (str, Path)):
+        with open(source, "r", encoding="utf-8") as handle:
+            yield from _iter_json_array(handle, key, chunk_size)
+    else:
+        yield from _iter_json_array(source, key, chunk_size)
+
+
+def _iter_json_array(handle: IO[str], key: str, chunk_size: int) -> Iterator[Any]:
+    stream = _JSONStream(handle, chunk_size)
+    stream.skip_whitespace()
+    stream.expect("{")
+    while True:
+        stream.skip_whitespace()
+        if stream.accept("}"):
+            return
+        member = stream.decode_value()
+        stream.skip_whitespace()
+        stream.expect(":")
+        stream.skip_whitespace()
+        if member == key:
+            stream.expect("[")
+            stream.skip_whitespace()
+            if not stream.accept("]"):
+                while True:
+                    yield stream.decode_value()
+                    stream.skip_whitespace()
+                    if stream.accept("]"):
+                        break
+                    stream.expect(",")
+                    stream.skip_whitespace()
+            return
+        _skip_value(stream)
+        stream.skip_whitespace()
+        if not stream.accept(","):
+            stream.expect("}")
+            return
+
+
+def _skip_value(stream: _JSONStream) -> None:
+    """Consume one value; arrays item by item to keep the buffer bounded."""
+    if stream.accept("["):
+        stream.skip_whitespace()
+        if stream.accept("]"):
+            return
+        while True:
+            _skip_value(stream)
+            stream.skip_whitespace()
+            if stream.accept("]"):
+                return
+            stream.expect(",")
+            stream.skip_whitespace()
+    else:
+        stream.decode_value()
+
+
+class _TextScanner:
+    """Chunked text scanner with a bounded, explicitly trimmed buffer."""
+
+    def __init__(self, handle: IO[str], chunk_size: int) -> None:
+        self.handle = handle
+        self.chunk_size = chunk_size
+        self.buffer = ""
+        self.eof = False
+
+    def refill(self) -> bool:
+        if self.eof:
+            return False
+        data = self.handle.read(self.chunk_size)
+        if not data:
+            self.eof = True
+            return False
+        self.buffer += data
+        return True
+
+    def trim(self, keep_tail: int) -> None:
+        """Drop consumed input, keeping a tail for boundary-straddling markers."""
+        if len(self.buffer) > keep_tail:
+            self.buffer = self.buffer[len(self.buffer) - keep_tail:]
+
+    def consume(self, upto: int) -> None:
+        self.buffer = self.buffer[upto:]
+
+
+def recover_xml_elements(
+    source: str | Path | IO[str],
+    tag: str,
+    diagnostics: ParseDiagnostics,
+    chunk_size: int = STREAM_CHUNK_SIZE,
+) -> Iterator[ET.Element]:
+    """Salvage well-formed ``tag`` elements from a damaged XML stream.
+
+    Scans for complete ``<tag>…</tag>`` (or self-closing) blocks and
+    parses each on its own, so stderr lines interleaved into the report
+    or a truncated document cost only the records they touch. Never
+    raises on garbage; drops and truncation land in ``diagnostics``.
+    ``tag`` must not nest within itself.
+    """
+    if isinstance(source, (str, Path)):
+        with open(source, "r", encoding="utf-8", errors="replace") as handle:
+            yield from _recover_xml(handle, tag, diagnostics, chunk_size)
+    else:
+        yield from _recover_xml(source, tag, diagnostics, chunk_size)
+
+
+def _recover_xml(
+    handle: IO[str], tag: str, diagnostics: ParseDiagnostics, chunk_size: int
+) -> Iterator[ET.Element]:
+    scanner = _TextScanner(handle, chunk_size)
+    start_marker = f"<{tag}"
+    end_marker = f"</{tag}>"
+    while _scan_to_block_start(scanner, start_marker):
+        # The block starts at buffer position 0; find where it ends.
+        while True:
+            close = scanner.buffer.find(">")
+            end = scanner.buffer.find(end_marker)
+            if close != -1 and scanner.buffer[close - 1] == "/" and (end == -1 or close < end):
+                block_end = close + 1  # self-closing start tag
+                break
+            if end != -1:
+                block_end = end + len(end_marker)
+                break
+            if not scanner.refill():
+                diagnostics.truncated = True
+                diagnostics.note(f"input ends inside <{tag}> block")
+                return
+        block = scanner.buffer[:block_end]
+        scanner.consume(block_end)
+        try:
+            element = ET.fromstring(block)
+        except ET.ParseError as exc:
+            diagnostics.records_discarded += 1
+            diagnostics.note(f"discarded malformed <{tag}> block: {exc}")
+        else:
+            diagnostics.records_recovered += 1
+            yield element
+
+
+def _scan_to_block_start(scanner: _TextScanner, marker: str) -> bool:
+    """Advance the scanner to the next ``marker`` opening a real tag."""
+    search_from = 0
+    while True:
+        found = scanner.buffer.find(marker, search_from)
+        if found == -1:
+            scanner.trim(len(marker))
+            search_from = 0
+            if not scanner.refill():
+                return False
+            continue
+        while found + len(marker) >= len(scanner.buffer):
+            if not scanner.refill():
+                return False
+        after = scanner.buffer[found + len(marker)]
+        if after in " \t\r\n/>":
+            scanner.consume(found)
+            return True
+        # Prefix of a longer tag name (e.g. <duplications); keep looking.
+        search_from = found + 1
+
+
+def recover_json_array(
+    source: str | Path | IO[str],
+    key: str,
+    diagnostics: ParseDiagnostics,
+    chunk_size: int = STREAM_CHUNK_SIZE,
+) -> Iterator[Any]:
+    """Salvage object records from the top-level array ``key``.
+
+    Best-effort counterpart to ``iter_json_array``: locates the array
+    textually, extracts each balanced ``{…}`` record on its own, and
+    skips garbage between records. A record that fails to decode or an
+    input truncated mid-record is counted in ``diagnostics`` instead of
+    raising. Only object records are recovered.
+    """
+    if isinstance(source, (str, Path)):
+        with open(source, "r", encoding="utf-8", errors="replace") as handle:
+            yield from _recover_json(handle, key, diagnostics, chunk_size)
+    else:
+        yield from _recover_json(source, key, diagnostics, chunk_size)
+
+
+def _recover_json(
+    handle: IO[str], key: str, diagnostics: ParseDiagnostics, chunk_size: int
+) -> Iterator[Any]:
+    scanner = _TextScanner(handle, chunk_size)
+    if not _scan_to_array_start(scanner, key):
+        diagnostics.note(f'no "{key}" array found in output')
+        return
+    while True:
+        # Skip to the next record or the end of the array; anything
+        # else between records is garbage we step over.
+        index = 0
+        while True:
+            while index < len(scanner.buffer) and scanner.buffer[index] not in "{]":
+                index += 1
+            if index < len(scanner.buffer):
+                break
+            scanner.consume(index)
+            index = 0
+            if not scanner.refill():
+                diagnostics.truncated = True
+                diagnostics.note(f'"{key}" array is not closed')
+                return
+        if scanner.buffer[index] == "]":
+            return
+        scanner.consume(index)
+        block = _extract_balanced_object(scanner, diagnostics)
+        if block is None:
+            return
+        try:
+            record = json.loads(block)
+        except json.JSONDecodeError as exc:
+            diagnostics.records_discarded += 1
+            diagnostics.note(f"discarded malformed record: {exc}")
+        else:
+            diagnostics.records_recovered += 1
+            yield record
+
+
+def _scan_to_array_start(scanner: _TextScanner, key: str) -> bool:
+    """Advance past ``"key"`` followed by ``:`` and ``[``."""
+    needle = f'"{key}"'
+    while True:
+        found = scanner.buffer.find(needle)
+        if found == -1:
+            scanner.trim(len(needle))
+            if not scanner.refill():
+                return False
+            continue
+        index = found + len(needle)
+        matched = True
+        for expected in (":", "["):
+            while True:
+                while index < len(scanner.buffer) and scanner.buffer[index] in " \t\r\n":
+                    index += 1
+                if index < len(scanner.buffer):
+                    break
+                if not scanner.refill():
+                    return False
+            if scanner.buffer[index] != expected:
+                matched = False
+                break
+            index += 1
+        if matched:
+            scanner.consume(index)
+            return True
+        # A string that merely contains the key; keep looking.
+        scanner.consume(found + 1)
+
+
+def _extract_balanced_object(
+    scanner: _TextScanner, diagnostics: ParseDiagnostics
+) -> str | None:
+    """Return the balanced ``{…}`` starting the buffer, or None if cut off."""
+    depth = 0
+    in_string = False
+    escaped = False
+    index = 0
+    while True:
+        if index >= len(scanner.buffer) and not scanner.refill():
+            diagnostics.truncated = True
+            diagnostics.note("input ends inside a record")
+            return None
+        char = scanner.buffer[index]
+        if in_string:
+            if escaped:
+                escaped = False
+            elif char == "\\":
+                escaped = True
+            elif char == '"':
+                in_string = False
+        elif char == '"':
+            in_string = True
+        elif char == "{":
+            depth += 1
+        elif char == "}":
+            depth -= 1
+            if depth == 0:
+                block = scanner.buffer[: index + 1]
+                scanner.consume(index + 1)
+                return block
+        index += 1
@@ -0,0 +1,118 @@
+"""Tests for content-addressed artifact upload to object storage."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from common.artifact_store import (
+    ArtifactStoreError,
+    GCSStore,
+    LocalStore,
+    S3Store,
+    content_key,
+    file_sha256,
+    parse_store_url,
+    upload_artifacts,
+)
+
+
+class TestKeysAndUrls:
+    def test_content_key_layout(self) -> None:
+        key = content_key("runs", "my-app", "ab" + "0" * 62, "report.html")
+        assert key == f"runs/my-app/ab/{'ab' + '0' * 62}/report.html"
+
+    def test_content_key_without_prefix(self) -> None:
+        key = content_key("", "my-app", "cd" + "0" * 62, "out.json")
+        assert key.startswith("my-app/cd/")
+
+    def test_parse_store_urls(self) -> None:
+        assert parse_store_url("s3://bucket/some/prefix") == ("s3", "bucket", "some/prefix")
+        assert parse_store_url("gs://bucket") == ("gs", "bucket", "")
+        assert parse_store_url("/var/artifacts") == ("file", "/var/artifacts", "")
+
+    def test_bucketless_url_rejected(self) -> None:
+        with pytest.raises(ArtifactStoreError, match="no bucket"):
+            parse_store_url("s3://")
+
+
+class TestLocalStore:
+    def test_upload_is_content_addressed_and_tagged(self, tmp_path: Path) -> None:
+        artifact = tmp_path / "report.html"
+        artifact.write_text("<html>hi</html>")
+        sha = file_sha256(artifact)
+
+        manifest = upload_artifacts(
+            str(tmp_path / "store"), [artifact], repo_id="my-app", retention="30d"
+        )
+
+        entry = manifest["artifacts"][0]
+        assert entry["sha256"] == sha
+        assert entry["key"] == f"my-app/{sha[:2]}/{sha}/report.html"
+        stored = tmp_path / "store" / entry["key"]
+        assert stored.read_text() == "<html>hi</html>"
+        tags = json.loads((stored.parent / "report.html.tags").read_text())
+        assert tags == {"caldera-retention": "30d"}
+
+    def test_identical_content_dedupes_to_same_key(self, tmp_path: Path) -> None:
+        first = tmp_path / "a" / "report.html"
+        second = tmp_path / "b" / "report.html"
+        for path in (first, second):
+            path.parent.mkdir()
+            path.write_text("same content")
+
+        manifest = upload_artifacts(str(tmp_path / "store"), [first, second], repo_id="r")
+
+        keys = {entry["key"] for entry in manifest["artifacts"]}
+        assert len(keys) == 1
+
+    def test_missing_file_rejected(self, tmp_path: Path) -> None:
+        with pytest.raises(ArtifactStoreError, match="Not a file"):
+            upload_artifacts(str(tmp_path / "store"), [tmp_path / "nope.json"], repo_id="r")
+
+
+class TestCloudBackends:
+    def test_s3_copy_then_retention_tagging(self, tmp_path: Path) -> None:
+        artifact = tmp_path / "findings.sarif"
+        artifact.write_text("{}")
+        commands: list[list[str]] = []
+        store = S3Store("bucket", runner=commands.append)
+
+        uri = store.put(artifact, "runs/r/ab/abc/findings.sarif", "90d")
+
+        assert uri == "s3://bucket/runs/r/ab/abc/findings.sarif"
+        assert commands[0][:3] == ["aws", "s3", "cp"]
+        assert commands[1][:2] == ["aws", "s3api"]
+        assert "TagSet=[{Key=caldera-retention,Value=90d}]" in commands[1]
+
+    def test_gcs_copy_carries_retention_metadata(self, tmp_path: Path) -> None:
+        artifact = tmp_path / "report.html"
+        artifact.write_text("x")
+        commands: list[list[str]] = []
+        store = GCSStore("bucket", runner=commands.append)
+
+        uri = store.put(artifact, "runs/r/cd/cde/report.html", "30d")
+
+        assert uri == "gs://bucket/runs/r/cd/cde/report.html"
+        assert "x-goog-meta-caldera-retention:30d" in commands[0]
+
+    def test_cloud_upload_via_upload_artifacts(self, tmp_path: Path) -> None:
+        artifact = tmp_path / "out.json"
+        artifact.write_text("{}")
+        commands: list[list[str]] = []
+
+        manifest = upload_artifacts(
+            "s3://bucket/runs", [artifact], repo_id="my-app", runner=commands.append
+        )
+
+        assert manifest["artifacts"][0]["uri"].startswith("s3://bucket/runs/my-app/")
+        assert len(commands) == 2  # cp + tagging
+
+    def test_local_store_ignores_runner(self, tmp_path: Path) -> None:
+        artifact = tmp_path / "out.json"
+        artifact.write_text("{}")
+        store = LocalStore(tmp_path / "store")
+        uri = store.put(artifact, "k/out.json", "7d")
+        assert uri.startswith("file://")
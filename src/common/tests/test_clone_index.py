@@ -0,0 +1,131 @@
+"""Tests for the incremental clone-detection index."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from common.clone_index import CloneIndex, tokenize
+
+# Twenty distinct statements; duplicated across files it exceeds any
+# small test window.
+BLOCK = "\n".join(f"value_{i} = compute_{i}(a, b) + {i}" for i in range(20)) + "\n"
+
+
+def _write(tmp_path: Path, name: str, text: str) -> str:
+    (tmp_path / name).write_text(text)
+    return name
+
+
+class TestTokenize:
+    def test_layout_insensitive(self) -> None:
+        compact = tokenize("x=f(1,'s')")
+        spaced = tokenize("x = f( 1 , 's' )")
+        assert [t for t, _ in compact] == [t for t, _ in spaced]
+
+    def test_line_numbers_recorded(self) -> None:
+        tokens = tokenize("a = 1\nb = 2\n")
+        assert tokens[0] == ("a", 1)
+        assert tokens[-1] == ("2", 2)
+
+
+class TestUpdate:
+    def test_initial_scan_indexes_everything(self, tmp_path: Path) -> None:
+        paths = [_write(tmp_path, "a.py", BLOCK), _write(tmp_path, "b.py", BLOCK)]
+        index = CloneIndex(window_tokens=10)
+        stats = index.update(tmp_path, paths)
+        assert stats.reindexed == 2
+        assert stats.reused == 0
+        assert index.file_count == 2
+
+    def test_unchanged_files_reused(self, tmp_path: Path) -> None:
+        paths = [_write(tmp_path, "a.py", BLOCK), _write(tmp_path, "b.py", BLOCK)]
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, paths)
+        stats = index.update(tmp_path, paths)
+        assert stats.reused == 2
+        assert stats.reindexed == 0
+
+    def test_only_changed_file_reindexed(self, tmp_path: Path) -> None:
+        paths = [_write(tmp_path, "a.py", BLOCK), _write(tmp_path, "b.py", BLOCK)]
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, paths)
+        (tmp_path / "b.py").write_text(BLOCK + "extra = 1\n")
+        stats = index.update(tmp_path, paths)
+        assert stats.reused == 1
+        assert stats.reindexed == 1
+
+    def test_deleted_file_dropped(self, tmp_path: Path) -> None:
+        paths = [_write(tmp_path, "a.py", BLOCK), _write(tmp_path, "b.py", BLOCK)]
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, paths)
+        stats = index.update(tmp_path, paths[:1])
+        assert stats.removed == 1
+        assert index.file_count == 1
+
+    def test_unreadable_file_skipped(self, tmp_path: Path) -> None:
+        index = CloneIndex(window_tokens=10)
+        stats = index.update(tmp_path, ["missing.py"])
+        assert stats.reindexed == 0
+        assert index.file_count == 0
+
+
+class TestDuplicateGroups:
+    def test_duplicated_block_found_across_files(self, tmp_path: Path) -> None:
+        paths = [
+            _write(tmp_path, "a.py", BLOCK),
+            _write(tmp_path, "b.py", BLOCK),
+            _write(tmp_path, "unique.py", "only = here(1)\n"),
+        ]
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, paths)
+        groups = index.duplicate_groups()
+        assert groups, "identical files must produce clone groups"
+        files = {occ.path for group in groups for occ in group.occurrences}
+        assert files == {"a.py", "b.py"}
+
+    def test_no_groups_without_duplication(self, tmp_path: Path) -> None:
+        paths = [
+            _write(tmp_path, "a.py", BLOCK),
+            _write(tmp_path, "b.py", BLOCK.replace("compute", "evaluate")),
+        ]
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, paths)
+        assert index.duplicate_groups() == []
+
+
+class TestPersistence:
+    def test_round_trip_reuses_cached_windows(self, tmp_path: Path) -> None:
+        repo = tmp_path / "repo"
+        repo.mkdir()
+        paths = [_write(repo, "a.py", BLOCK), _write(repo, "b.py", BLOCK)]
+        index = CloneIndex(window_tokens=10)
+        index.update(repo, paths)
+        index_file = tmp_path / "clone-index.json"
+        index.save(index_file)
+
+        loaded = CloneIndex.load(index_file, window_tokens=10)
+        stats = loaded.update(repo, paths)
+        assert stats.reused == 2
+        assert stats.reindexed == 0
+        assert loaded.duplicate_groups()
+
+    def test_window_size_mismatch_starts_fresh(self, tmp_path: Path) -> None:
+        repo = tmp_path / "repo"
+        repo.mkdir()
+        paths = [_write(repo, "a.py", BLOCK)]
+        index = CloneIndex(window_tokens=10)
+        index.update(repo, paths)
+        index_file = tmp_path / "clone-index.json"
+        index.save(index_file)
+        assert CloneIndex.load(index_file, window_tokens=25).file_count == 0
+
+    def test_corrupt_index_file_starts_fresh(self, tmp_path: Path) -> None:
+        index_file = tmp_path / "clone-index.json"
+        index_file.write_text("{not json")
+        assert CloneIndex.load(index_file).file_count == 0
+
+    def test_rejects_tiny_window(self) -> None:
+        with pytest.raises(ValueError, match="window_tokens"):
+            CloneIndex(window_tokens=1)
@@ -0,0 +1,81 @@
+"""Tests for the MinHash/LSH clone candidate prefilter."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from common.clone_index import CloneIndex, tokenize
+from common.clone_prefilter import MinHashIndex
+
+BLOCK = "\n".join(f"value_{i} = compute_{i}(a, b) + {i}" for i in range(20)) + "\n"
+OTHER = "\n".join(f"total_{i} = aggregate_{i}(x, y) * {i}" for i in range(20)) + "\n"
+
+
+def _tokens(text: str) -> list[str]:
+    return [token for token, _ in tokenize(text)]
+
+
+class TestMinHashIndex:
+    def test_identical_files_are_candidates(self) -> None:
+        index = MinHashIndex()
+        index.add("a.py", _tokens(BLOCK))
+        index.add("b.py", _tokens(BLOCK))
+        assert ("a.py", "b.py") in index.candidate_pairs()
+
+    def test_dissimilar_files_are_pruned(self) -> None:
+        index = MinHashIndex()
+        index.add("a.py", _tokens(BLOCK))
+        index.add("b.py", _tokens(OTHER))
+        assert index.candidate_pairs() == set()
+
+    def test_similarity_estimate_tracks_overlap(self) -> None:
+        index = MinHashIndex()
+        index.add("a.py", _tokens(BLOCK))
+        index.add("same.py", _tokens(BLOCK))
+        index.add("other.py", _tokens(OTHER))
+        assert index.similarity("a.py", "same.py") == 1.0
+        assert index.similarity("a.py", "other.py") < 0.2
+
+    def test_signatures_are_deterministic(self) -> None:
+        first = MinHashIndex()
+        first.add("a.py", _tokens(BLOCK))
+        second = MinHashIndex()
+        second.add("a.py", _tokens(BLOCK))
+        assert first._signatures["a.py"] == second._signatures["a.py"]
+
+    def test_short_file_indexable(self) -> None:
+        index = MinHashIndex()
+        index.add("tiny.py", ["x", "=", "1"])
+        assert index.candidate_pairs() == set()
+
+    def test_bands_must_divide_hashes(self) -> None:
+        with pytest.raises(ValueError, match="divisible"):
+            MinHashIndex(num_hashes=100, bands=33)
+
+
+class TestPrefilteredDuplicateGroups:
+    def test_candidate_pairs_gate_cross_file_groups(self, tmp_path: Path) -> None:
+        for name, text in [("a.py", BLOCK), ("b.py", BLOCK), ("c.py", OTHER)]:
+            (tmp_path / name).write_text(text)
+        paths = ["a.py", "b.py", "c.py"]
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, paths)
+
+        prefilter = MinHashIndex()
+        for rel_path in paths:
+            prefilter.add(rel_path, index.tokens_of(rel_path, tmp_path))
+        candidates = prefilter.candidate_pairs()
+
+        groups = index.duplicate_groups(candidate_pairs=candidates)
+        assert groups == index.duplicate_groups()
+        assert index.duplicate_groups(candidate_pairs=set()) == []
+
+    def test_same_file_duplication_survives_empty_candidates(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text(BLOCK + BLOCK)
+        index = CloneIndex(window_tokens=10)
+        index.update(tmp_path, ["a.py"])
+        groups = index.duplicate_groups(candidate_pairs=set())
+        assert groups
+        assert all(occ.path == "a.py" for g in groups for occ in g.occurrences)
@@ -0,0 +1,114 @@
+"""Tests for encoding detection and transcoding diagnostics."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from common.encoding_detection import (
+    ACTION_OK,
+    ACTION_SKIPPED,
+    ACTION_TRANSCODED,
+    detect_encoding,
+    read_text_with_diagnostic,
+    scan_tree_encodings,
+)
+
+CORPUS = Path(__file__).parent.parent.parent / "shared" / "eval-corpus" / "encoding"
+
+SOURCE_MARKER = "fn café_total"
+
+
+class TestDetectEncoding:
+    def test_boms_win(self) -> None:
+        assert detect_encoding(b"\xef\xbb\xbfhello")[0] == "utf-8-sig"
+        assert detect_encoding(b"\xff\xfeh\x00i\x00")[0] == "utf-16-le"
+        assert detect_encoding(b"\xfe\xff\x00h\x00i")[0] == "utf-16-be"
+        assert detect_encoding(b"\xff\xfe\x00\x00h\x00\x00\x00")[0] == "utf-32-le"
+
+    def test_valid_utf8(self) -> None:
+        encoding, reason = detect_encoding("café".encode("utf-8"))
+        assert encoding == "utf-8"
+        assert reason == "valid UTF-8"
+
+    def test_bomless_utf16_by_nul_pattern(self) -> None:
+        assert detect_encoding("hello world".encode("utf-16-le"))[0] == "utf-16-le"
+        assert detect_encoding("hello world".encode("utf-16-be"))[0] == "utf-16-be"
+
+    def test_latin1_fallback(self) -> None:
+        encoding, _ = detect_encoding("café".encode("latin-1"))
+        assert encoding == "latin-1"
+
+    def test_binary_is_skipped(self) -> None:
+        encoding, reason = detect_encoding(b"\x89PNG\x00\x1a\xff\x00data")
+        assert encoding is None
+        assert "binary" in reason
+
+    def test_empty_file_is_utf8(self) -> None:
+        assert detect_encoding(b"")[0] == "utf-8"
+
+
+class TestReadTextWithDiagnostic:
+    def test_plain_utf8_is_ok(self, tmp_path: Path) -> None:
+        path = tmp_path / "plain.rs"
+        path.write_bytes("fn main() {}\n".encode("utf-8"))
+
+        text, diagnostic = read_text_with_diagnostic(path, "plain.rs")
+
+        assert text == "fn main() {}\n"
+        assert diagnostic.action == ACTION_OK
+        assert diagnostic.file_path == "plain.rs"
+
+    def test_utf16_is_transcoded(self, tmp_path: Path) -> None:
+        path = tmp_path / "wide.rs"
+        path.write_bytes(b"\xff\xfe" + "fn main() {}\n".encode("utf-16-le"))
+
+        text, diagnostic = read_text_with_diagnostic(path)
+
+        assert text == "fn main() {}\n"
+        assert diagnostic.action == ACTION_TRANSCODED
+        assert diagnostic.encoding == "utf-16-le"
+
+    def test_binary_is_skipped_with_reason(self, tmp_path: Path) -> None:
+        path = tmp_path / "blob.bin"
+        path.write_bytes(b"\xffdata\x00\x00more\x00")
+
+        text, diagnostic = read_text_with_diagnostic(path)
+
+        assert text is None
+        assert diagnostic.action == ACTION_SKIPPED
+        assert "binary" in diagnostic.reason
+
+    def test_missing_file_is_skipped(self, tmp_path: Path) -> None:
+        text, diagnostic = read_text_with_diagnostic(tmp_path / "nope.rs")
+        assert text is None
+        assert diagnostic.action == ACTION_SKIPPED
+
+
+class TestEncodingCorpus:
+    """The shipped corpus must round-trip to the same source text."""
+
+    def test_every_decodable_fixture_yields_the_same_function(self) -> None:
+        decodable = [
+            "utf8_plain.rs",
+            "utf8_bom.rs",
+            "utf16_le_bom.rs",
+            "utf16_be_bom.rs",
+            "utf16_le_nobom.rs",
+            "latin1.rs",
+        ]
+        for name in decodable:
+            text, diagnostic = read_text_with_diagnostic(CORPUS / name, name)
+            assert text is not None, diagnostic
+            assert SOURCE_MARKER in text, name
+
+    def test_invalid_utf8_fixture_is_skipped(self) -> None:
+        text, diagnostic = read_text_with_diagnostic(CORPUS / "invalid_utf8.rs")
+        assert text is None
+        assert diagnostic.action == ACTION_SKIPPED
+
+    def test_scan_tree_reports_only_exceptions(self) -> None:
+        diagnostics = {d.file_path: d for d in scan_tree_encodings(CORPUS)}
+
+        assert "utf8_plain.rs" not in diagnostics
+        assert diagnostics["latin1.rs"].action == ACTION_TRANSCODED
+        assert diagnostics["invalid_utf8.rs"].action == ACTION_SKIPPED
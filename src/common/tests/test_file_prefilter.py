@@ -0,0 +1,87 @@
+"""Tests for the binary/minified file prefilter."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from common.file_prefilter import (
+    CATEGORY_BINARY,
+    CATEGORY_MINIFIED,
+    CATEGORY_SINGLE_LINE,
+    CATEGORY_SOURCE,
+    classify_bytes,
+    classify_file,
+    partition_files,
+    skip_summary,
+)
+
+PLAIN_SOURCE = b"def handler(payload):\n    return dict(payload)\n"
+
+
+class TestClassifyBytes:
+    def test_plain_source_is_kept(self) -> None:
+        category, _ = classify_bytes("handler.py", PLAIN_SOURCE)
+        assert category == CATEGORY_SOURCE
+
+    def test_minified_filename_wins_without_reading(self) -> None:
+        category, reason = classify_bytes("app.min.js", PLAIN_SOURCE)
+        assert category == CATEGORY_MINIFIED
+        assert "*.min.js" in reason
+
+    def test_binary_content(self) -> None:
+        category, _ = classify_bytes("blob.dat", b"\x89PNG\x00\x1a\xff\x00data")
+        assert category == CATEGORY_BINARY
+
+    def test_single_line_generated(self) -> None:
+        data = b"export default " + b"x" * 5000
+        category, reason = classify_bytes("bundle.js", data)
+        assert category == CATEGORY_SINGLE_LINE
+        assert "1 line" in reason
+
+    def test_long_average_lines_are_minified(self) -> None:
+        data = b"\n".join(b"a" * 700 for _ in range(5))
+        category, _ = classify_bytes("compact.js", data)
+        assert category == CATEGORY_MINIFIED
+
+    def test_empty_file_is_source(self) -> None:
+        assert classify_bytes("empty.py", b"")[0] == CATEGORY_SOURCE
+
+
+class TestClassifyFile:
+    def test_records_repo_relative_path(self, tmp_path: Path) -> None:
+        path = tmp_path / "main.py"
+        path.write_bytes(PLAIN_SOURCE)
+
+        decision = classify_file(path, "src/main.py")
+
+        assert decision.keep
+        assert decision.file_path == "src/main.py"
+
+    def test_unreadable_file_counts_as_binary(self, tmp_path: Path) -> None:
+        decision = classify_file(tmp_path / "missing.py")
+        assert decision.category == CATEGORY_BINARY
+        assert not decision.keep
+
+
+class TestPartitionAndSummary:
+    def test_partition_splits_kept_and_skipped(self, tmp_path: Path) -> None:
+        (tmp_path / "main.py").write_bytes(PLAIN_SOURCE)
+        (tmp_path / "app.min.js").write_bytes(PLAIN_SOURCE)
+        (tmp_path / "blob.dat").write_bytes(b"\x00\x01\xff\x00")
+
+        kept, skipped = partition_files(sorted(tmp_path.iterdir()), tmp_path)
+
+        assert [p.name for p in kept] == ["main.py"]
+        assert {d.file_path for d in skipped} == {"app.min.js", "blob.dat"}
+
+    def test_summary_counts_per_category(self, tmp_path: Path) -> None:
+        (tmp_path / "a.min.js").write_bytes(PLAIN_SOURCE)
+        (tmp_path / "b.min.js").write_bytes(PLAIN_SOURCE)
+        (tmp_path / "blob.dat").write_bytes(b"\x00\xff\x00\x00")
+
+        _, skipped = partition_files(sorted(tmp_path.iterdir()), tmp_path)
+        summary = skip_summary(skipped)
+
+        assert summary["total_skipped"] == 3
+        assert summary["by_category"] == {CATEGORY_BINARY: 1, CATEGORY_MINIFIED: 2}
+        assert summary["examples"][CATEGORY_MINIFIED] == ["a.min.js", "b.min.js"]
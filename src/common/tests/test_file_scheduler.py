@@ -0,0 +1,111 @@
+"""Tests for the work-stealing file scheduler."""
+
+from __future__ import annotations
+
+import threading
+import time
+from pathlib import Path
+
+import pytest
+
+from common.file_scheduler import (
+    FileResult,
+    map_files,
+    schedule_order,
+    static_partitions,
+)
+
+
+def _make_files(tmp_path: Path, sizes: dict[str, int]) -> list[Path]:
+    paths = []
+    for name, size in sizes.items():
+        path = tmp_path / name
+        path.write_bytes(b"x" * size)
+        paths.append(path)
+    return paths
+
+
+class TestScheduleOrder:
+    def test_largest_first(self, tmp_path: Path) -> None:
+        files = _make_files(tmp_path, {"small.py": 10, "massive.py": 10_000, "mid.py": 500})
+        ordered = schedule_order(files)
+        assert [p.name for p in ordered] == ["massive.py", "mid.py", "small.py"]
+
+    def test_missing_file_sorts_last(self, tmp_path: Path) -> None:
+        files = _make_files(tmp_path, {"real.py": 100})
+        files.append(tmp_path / "gone.py")
+        assert schedule_order(files)[-1].name == "gone.py"
+
+
+class TestStaticPartitions:
+    def test_contiguous_split(self) -> None:
+        files = [Path(f"f{i}.py") for i in range(5)]
+        chunks = static_partitions(files, 2)
+        assert chunks == [files[:3], files[3:]]
+
+    def test_empty_input(self) -> None:
+        assert static_partitions([], 4) == []
+
+    def test_rejects_zero_partitions(self) -> None:
+        with pytest.raises(ValueError, match="partitions"):
+            static_partitions([Path("f.py")], 0)
+
+
+class TestMapFiles:
+    def test_results_in_input_order(self, tmp_path: Path) -> None:
+        files = _make_files(tmp_path, {"a.py": 10, "b.py": 10_000, "c.py": 500})
+        results = map_files(lambda p: p.name.upper(), files, max_workers=2)
+        assert [r.path for r in results] == files
+        assert [r.result for r in results] == ["A.PY", "B.PY", "C.PY"]
+        assert all(r.ok for r in results)
+
+    def test_empty_input(self) -> None:
+        assert map_files(lambda p: p, []) == []
+
+    def test_worker_exception_captured_per_file(self, tmp_path: Path) -> None:
+        files = _make_files(tmp_path, {"good.py": 10, "bad.py": 20})
+
+        def worker(path: Path) -> str:
+            if path.name == "bad.py":
+                raise ValueError("unparseable")
+            return "ok"
+
+        results = {r.path.name: r for r in map_files(worker, files, max_workers=2)}
+        assert results["good.py"].ok
+        assert not results["bad.py"].ok
+        assert results["bad.py"].error == "ValueError: unparseable"
+
+    def test_skewed_file_does_not_serialize_other_work(self, tmp_path: Path) -> None:
+        # One "massive" file blocks its worker; with a shared queue the
+        # second worker must still drain every other file. Static
+        # partitioning would strand half of them behind the blocker.
+        files = _make_files(
+            tmp_path, {"massive.py": 10_000, **{f"f{i}.py": 10 for i in range(6)}}
+        )
+        release_massive = threading.Event()
+        done = threading.Event()
+        completed: list[str] = []
+
+        def worker(path: Path) -> str:
+            if path.name == "massive.py":
+                assert release_massive.wait(timeout=10)
+            completed.append(path.name)
+            return path.name
+
+        results: list[FileResult] = []
+
+        def run() -> None:
+            results.extend(map_files(worker, files, max_workers=2))
+            done.set()
+
+        thread = threading.Thread(target=run)
+        thread.start()
+        for _ in range(200):
+            if len(completed) >= 6:
+                break
+            time.sleep(0.05)
+        assert len(completed) >= 6, "small files stuck behind the skewed file"
+        release_massive.set()
+        assert done.wait(timeout=10)
+        thread.join()
+        assert all(r.ok for r in results)
@@ -0,0 +1,92 @@
+"""Tests for the memory-mapped shared file table."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from common.file_table import FileTable
+
+
+class TestGetBytes:
+    def test_returns_file_contents(self, tmp_path: Path) -> None:
+        source = tmp_path / "main.py"
+        source.write_bytes(b"print('hi')\n")
+        with FileTable() as table:
+            assert bytes(table.get_bytes(source)) == b"print('hi')\n"
+
+    def test_second_read_hits_existing_mapping(self, tmp_path: Path) -> None:
+        source = tmp_path / "main.py"
+        source.write_bytes(b"x = 1\n")
+        with FileTable() as table:
+            first = table.get_bytes(source)
+            second = table.get_bytes(source)
+            assert first is second
+            assert table.stats.files_mapped == 1
+            assert table.stats.hits == 1
+            assert table.stats.misses == 1
+
+    def test_empty_file_served_without_mapping(self, tmp_path: Path) -> None:
+        empty = tmp_path / "empty.py"
+        empty.touch()
+        with FileTable() as table:
+            assert bytes(table.get_bytes(empty)) == b""
+            # mmap cannot map zero bytes; the table must not try.
+            assert table.stats.files_mapped == 0
+
+    def test_missing_file_raises_oserror(self, tmp_path: Path) -> None:
+        with FileTable() as table:
+            with pytest.raises(OSError):
+                table.get_bytes(tmp_path / "gone.py")
+
+    def test_bytes_mapped_accumulates(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_bytes(b"x" * 100)
+        (tmp_path / "b.py").write_bytes(b"y" * 50)
+        with FileTable() as table:
+            table.get_bytes(tmp_path / "a.py")
+            table.get_bytes(tmp_path / "b.py")
+            assert table.stats.bytes_mapped == 150
+
+
+class TestGetText:
+    def test_decodes_utf8(self, tmp_path: Path) -> None:
+        source = tmp_path / "main.py"
+        source.write_text("grüße = 1\n", encoding="utf-8")
+        with FileTable() as table:
+            assert table.get_text(source) == "grüße = 1\n"
+
+    def test_decoded_text_cached(self, tmp_path: Path) -> None:
+        source = tmp_path / "main.py"
+        source.write_bytes(b"x = 1\n")
+        with FileTable() as table:
+            assert table.get_text(source) == table.get_text(source)
+            assert table.stats.hits >= 1
+
+    def test_binary_file_returns_none(self, tmp_path: Path) -> None:
+        blob = tmp_path / "data.bin"
+        blob.write_bytes(b"\x00\x01\x02\x03binary")
+        with FileTable() as table:
+            assert table.get_text(blob) is None
+
+    def test_latin1_fallback(self, tmp_path: Path) -> None:
+        source = tmp_path / "legacy.py"
+        source.write_bytes("caf\xe9".encode("latin-1"))
+        with FileTable() as table:
+            assert table.get_text(source) == "café"
+
+
+class TestClose:
+    def test_use_after_close_rejected(self, tmp_path: Path) -> None:
+        source = tmp_path / "main.py"
+        source.write_bytes(b"x = 1\n")
+        table = FileTable()
+        table.get_bytes(source)
+        table.close()
+        with pytest.raises(ValueError, match="closed"):
+            table.get_bytes(source)
+
+    def test_close_is_idempotent(self) -> None:
+        table = FileTable()
+        table.close()
+        table.close()
@@ -0,0 +1,87 @@
+"""Tests for generated_code module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from ..generated_code import (
+    GeneratedCodeDetector,
+    parse_provenance_gitattributes,
+)
+
+
+class TestGitattributesParsing:
+    """Tests for linguist-generated/vendored extraction."""
+
+    def test_extracts_generated_and_vendored(self):
+        text = (
+            "*.pb.go linguist-generated=true\n"
+            "deps/** linguist-vendored\n"
+            "*.sql linguist-language=SQL\n"
+        )
+        rules = parse_provenance_gitattributes(text)
+        assert rules == {"*.pb.go": "generated", "deps/**": "vendored"}
+
+    def test_false_annotations_are_ignored(self):
+        assert parse_provenance_gitattributes("*.js linguist-generated=false\n") == {}
+
+
+class TestClassification:
+    """Tests for classification signals and precedence."""
+
+    def test_vendored_directory_segment(self):
+        classification = GeneratedCodeDetector().classify("node_modules/lib/index.js")
+        assert classification.kind == "vendored"
+        assert classification.reason == "directory:node_modules"
+
+    def test_vendored_matches_nested_segments_only(self):
+        # A *file* named vendor is not a vendored tree.
+        assert GeneratedCodeDetector().classify("vendor").kind == "source"
+        assert GeneratedCodeDetector().classify("src/vendor/x.go").kind == "vendored"
+
+    def test_generated_filename_patterns(self):
+        detector = GeneratedCodeDetector()
+        assert detector.classify("api/service_pb2.py").kind == "generated"
+        assert detector.classify("web/app.min.js").kind == "generated"
+        assert detector.classify("src/app.js").kind == "source"
+
+    def test_content_marker_in_head(self):
+        head = "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage api\n"
+        classification = GeneratedCodeDetector().classify("api/service.go", head=head)
+        assert classification.kind == "generated"
+        assert classification.reason == "content-marker"
+
+    def test_marker_beyond_head_window_is_ignored(self):
+        head = "\n".join(["line"] * 10) + "\n# @generated\n"
+        assert GeneratedCodeDetector().classify("a.py", head=head).kind == "source"
+
+    def test_gitattributes_beats_heuristics(self, tmp_path: Path):
+        (tmp_path / ".gitattributes").write_text("docs/** linguist-generated=true\n")
+        detector = GeneratedCodeDetector(repo_root=tmp_path)
+        assert detector.classify("docs/api.md").kind == "generated"
+
+    def test_reads_head_from_disk(self, tmp_path: Path):
+        target = tmp_path / "service.go"
+        target.write_text("// Code generated by protoc. DO NOT EDIT.\n")
+        detector = GeneratedCodeDetector(repo_root=tmp_path)
+        assert detector.classify("service.go").kind == "generated"
+
+
+class TestPolicy:
+    """Tests for exclusion policy handling."""
+
+    def test_exclude_policy_drops_non_source(self):
+        detector = GeneratedCodeDetector(policy="exclude")
+        assert detector.should_exclude(detector.classify("vendor/x.go"))
+        assert not detector.should_exclude(detector.classify("src/x.go"))
+
+    def test_include_and_report_separately_keep_everything(self):
+        for policy in ("include", "report-separately"):
+            detector = GeneratedCodeDetector(policy=policy)
+            assert not detector.should_exclude(detector.classify("vendor/x.go"))
+
+    def test_unknown_policy_rejected(self):
+        with pytest.raises(ValueError):
+            GeneratedCodeDetector(policy="drop")
@@ -0,0 +1,84 @@
+"""Tests for .calderaignore pattern handling."""
+from __future__ import annotations
+
+from pathlib import Path
+
+from common.ignore_rules import IgnoreRules, parse_patterns
+
+
+class TestParsePatterns:
+    def test_skips_comments_and_blanks(self) -> None:
+        patterns = parse_patterns("# header\n\n*.min.js\n", ".calderaignore")
+        assert [p.pattern for p in patterns] == ["*.min.js"]
+        assert patterns[0].line == 3
+
+    def test_negation_and_dir_markers(self) -> None:
+        patterns = parse_patterns("!keep.js\nbuild/\n", ".calderaignore")
+        assert patterns[0].negated is True
+        assert patterns[0].pattern == "keep.js"
+        assert patterns[1].dir_only is True
+        assert patterns[1].pattern == "build"
+
+
+class TestMatching:
+    def _rules(self, text: str) -> IgnoreRules:
+        return IgnoreRules(parse_patterns(text, ".calderaignore"))
+
+    def test_basename_pattern_matches_any_depth(self) -> None:
+        rules = self._rules("*.min.js\n")
+        assert rules.is_ignored("app.min.js") is True
+        assert rules.is_ignored("static/js/app.min.js") is True
+        assert rules.is_ignored("static/js/app.js") is False
+
+    def test_slash_pattern_is_anchored(self) -> None:
+        rules = self._rules("docs/*.html\n")
+        assert rules.is_ignored("docs/index.html") is True
+        assert rules.is_ignored("site/docs/index.html") is False
+
+    def test_double_star_spans_directories(self) -> None:
+        rules = self._rules("src/**/generated.py\n")
+        assert rules.is_ignored("src/a/b/generated.py") is True
+        assert rules.is_ignored("src/generated.py") is True
+
+    def test_last_matching_pattern_wins(self) -> None:
+        rules = self._rules("*.js\n!vendor.js\n")
+        assert rules.is_ignored("app.js") is True
+        assert rules.is_ignored("vendor.js") is False
+
+    def test_ignored_directory_swallows_contents(self) -> None:
+        rules = self._rules("build/\n!build/keep.txt\n")
+        assert rules.is_ignored("build/out.bin") is True
+        # Re-inclusion below an ignored directory has no effect
+        assert rules.is_ignored("build/keep.txt") is True
+
+    def test_dir_only_pattern_leaves_files_alone(self) -> None:
+        rules = self._rules("cache/\n")
+        assert rules.is_ignored("cache", is_dir=True) is True
+        assert rules.is_ignored("cache") is False
+
+
+class TestLoadAndExplain:
+    def test_load_prefers_calderaignore_over_gitignore(self, tmp_path: Path) -> None:
+        (tmp_path / ".gitignore").write_text("*.log\n")
+        (tmp_path / ".calderaignore").write_text("!important.log\n")
+
+        rules = IgnoreRules.load(tmp_path, include_gitignore=True)
+        assert rules.is_ignored("debug.log") is True
+        assert rules.is_ignored("important.log") is False
+
+    def test_load_without_gitignore(self, tmp_path: Path) -> None:
+        (tmp_path / ".gitignore").write_text("*.log\n")
+        rules = IgnoreRules.load(tmp_path)
+        assert rules.is_ignored("debug.log") is False
+
+    def test_missing_files_yield_empty_rules(self, tmp_path: Path) -> None:
+        rules = IgnoreRules.load(tmp_path)
+        assert bool(rules) is False
+        assert rules.is_ignored("anything.py") is False
+
+    def test_explain_names_the_deciding_pattern(self) -> None:
+        rules = IgnoreRules(parse_patterns("*.min.js\n!keep.min.js\n", ".calderaignore"))
+        assert "`*.min.js`" in rules.explain("app.min.js")
+        assert ".calderaignore:1" in rules.explain("app.min.js")
+        assert "re-included by .calderaignore:2" in rules.explain("keep.min.js")
+        assert "no matching pattern" in rules.explain("main.py")
@@ -0,0 +1,124 @@
+"""Tests for remote and archive scan input resolution."""
+
+from __future__ import annotations
+
+import subprocess
+import tarfile
+import zipfile
+from pathlib import Path
+
+import pytest
+
+from common.input_resolver import (
+    InputResolutionError,
+    classify_source,
+    extract_archive,
+    parse_git_source,
+    resolve_input,
+)
+
+
+def _make_targz(path: Path, files: dict[str, str]) -> None:
+    with tarfile.open(path, "w:gz") as archive:
+        for name, content in files.items():
+            member_path = path.parent / "stage" / name
+            member_path.parent.mkdir(parents=True, exist_ok=True)
+            member_path.write_text(content)
+            archive.add(member_path, arcname=name)
+
+
+class TestClassifyAndParse:
+    def test_git_urls(self) -> None:
+        assert classify_source("https://github.com/org/repo.git") == "git-url"
+        assert classify_source("https://github.com/org/repo.git#develop") == "git-url"
+        assert classify_source("git@github.com:org/repo.git") == "git-url"
+
+    def test_archives(self) -> None:
+        assert classify_source("./snapshot.tar.gz") == "archive"
+        assert classify_source("/data/dump.zip") == "archive"
+
+    def test_local_paths(self) -> None:
+        assert classify_source("/path/to/repo") == "local"
+        assert classify_source(".") == "local"
+
+    def test_branch_fragment(self) -> None:
+        assert parse_git_source("https://x/repo.git#develop") == ("https://x/repo.git", "develop")
+        assert parse_git_source("https://x/repo.git") == ("https://x/repo.git", None)
+
+
+class TestArchiveExtraction:
+    def test_targz_round_trip(self, tmp_path: Path) -> None:
+        archive = tmp_path / "snapshot.tar.gz"
+        _make_targz(archive, {"repo/src/main.py": "x = 1\n"})
+
+        resolved = resolve_input(str(archive), workspace_root=tmp_path)
+        try:
+            assert resolved.temporary
+            assert (resolved.path / "src" / "main.py").read_text() == "x = 1\n"
+        finally:
+            resolved.cleanup()
+        assert resolved.workspace is not None
+        assert not resolved.workspace.exists()
+
+    def test_zip_round_trip(self, tmp_path: Path) -> None:
+        archive = tmp_path / "snapshot.zip"
+        with zipfile.ZipFile(archive, "w") as handle:
+            handle.writestr("repo/main.py", "y = 2\n")
+
+        resolved = resolve_input(str(archive), workspace_root=tmp_path)
+        try:
+            assert (resolved.path / "main.py").read_text() == "y = 2\n"
+        finally:
+            resolved.cleanup()
+
+    def test_traversal_member_rejected(self, tmp_path: Path) -> None:
+        archive = tmp_path / "evil.zip"
+        with zipfile.ZipFile(archive, "w") as handle:
+            handle.writestr("../escape.py", "boom\n")
+        dest = tmp_path / "dest"
+        dest.mkdir()
+
+        with pytest.raises(InputResolutionError, match="escapes workspace"):
+            extract_archive(archive, dest, max_workspace_mb=10)
+
+    def test_size_limit_enforced(self, tmp_path: Path) -> None:
+        archive = tmp_path / "big.tar.gz"
+        _make_targz(archive, {"blob.txt": "x" * (2 * 1024 * 1024)})
+        dest = tmp_path / "dest"
+        dest.mkdir()
+
+        with pytest.raises(InputResolutionError, match="workspace limit"):
+            extract_archive(archive, dest, max_workspace_mb=1)
+
+    def test_missing_archive_fails(self, tmp_path: Path) -> None:
+        with pytest.raises(InputResolutionError, match="not found"):
+            resolve_input(str(tmp_path / "nope.tar.gz"))
+
+
+class TestLocalAndClone:
+    def test_local_directory_used_in_place(self, tmp_path: Path) -> None:
+        resolved = resolve_input(str(tmp_path))
+        assert resolved.path == tmp_path
+        assert not resolved.temporary
+        resolved.cleanup()
+        assert tmp_path.exists()
+
+    def test_local_non_directory_fails(self, tmp_path: Path) -> None:
+        with pytest.raises(InputResolutionError, match="Not a directory"):
+            resolve_input(str(tmp_path / "missing"))
+
+    def test_shallow_clone_of_local_origin(self, tmp_path: Path) -> None:
+        origin = tmp_path / "origin"
+        origin.mkdir()
+        (origin / "main.py").write_text("x = 1\n")
+        git = ["git", "-C", str(origin), "-c", "user.email=t@t", "-c", "user.name=t"]
+        subprocess.run([*git[:3], "init", "-q", "-b", "main"], check=True)
+        subprocess.run([*git[:3], "add", "."], check=True)
+        subprocess.run([*git, "commit", "-q", "-m", "init"], check=True)
+
+        resolved = resolve_input(f"file://{origin}", workspace_root=tmp_path)
+        try:
+            assert resolved.source_kind == "git-url"
+            assert (resolved.path / "main.py").exists()
+        finally:
+            resolved.cleanup()
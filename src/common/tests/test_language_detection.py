@@ -0,0 +1,104 @@
+"""Tests for language_detection module."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from ..language_detection import (
+    LanguageDetector,
+    canonicalize_language,
+    parse_gitattributes,
+    parse_override_rules,
+)
+
+
+class TestCanonicalization:
+    """Tests for language name canonicalization."""
+
+    def test_aliases_map_to_canonical_names(self):
+        assert canonicalize_language("golang") == "Go"
+        assert canonicalize_language("html") == "HTML"
+        assert canonicalize_language("csharp") == "C#"
+
+    def test_unknown_names_pass_through(self):
+        assert canonicalize_language("Brainfuck") == "Brainfuck"
+
+
+class TestOverrideRules:
+    """Tests for the override rule parser."""
+
+    def test_parses_glob_to_language_lines(self):
+        rules = parse_override_rules("# templates\n*.tpl = html\nsrc/gen/*.py = python\n")
+        assert rules == {"*.tpl": "HTML", "src/gen/*.py": "Python"}
+
+    def test_rejects_lines_without_language(self):
+        with pytest.raises(ValueError):
+            parse_override_rules("*.tpl =")
+        with pytest.raises(ValueError):
+            parse_override_rules("not-a-rule")
+
+
+class TestGitattributes:
+    """Tests for .gitattributes linguist-language parsing."""
+
+    def test_extracts_linguist_language(self):
+        text = "*.sql linguist-language=SQL\n*.min.js linguist-generated=true\n"
+        assert parse_gitattributes(text) == {"*.sql": "SQL"}
+
+    def test_ignores_comments_and_blank_lines(self):
+        assert parse_gitattributes("# nothing here\n\n") == {}
+
+
+class TestLanguageDetector:
+    """Tests for detection precedence and sources."""
+
+    def test_extension_detection(self):
+        detection = LanguageDetector().detect("src/main.py")
+        assert detection.language == "Python"
+        assert detection.source == "extension"
+
+    def test_filename_detection_beats_extension(self):
+        detection = LanguageDetector().detect("build/Makefile")
+        assert detection.language == "Makefile"
+        assert detection.source == "filename"
+
+    def test_shebang_detection_for_extensionless_files(self):
+        detection = LanguageDetector().detect("bin/deploy", first_line="#!/usr/bin/env python3\n")
+        assert detection.language == "Python"
+        assert detection.source == "shebang"
+
+    def test_override_beats_extension(self):
+        detector = LanguageDetector(overrides={"*.tpl": "html"})
+        detection = detector.detect("templates/index.tpl")
+        assert detection.language == "HTML"
+        assert detection.source == "override"
+
+    def test_gitattributes_beats_extension(self, tmp_path: Path):
+        (tmp_path / ".gitattributes").write_text("*.h linguist-language=C++\n")
+        detection = LanguageDetector(repo_root=tmp_path).detect("include/api.h")
+        assert detection.language == "C++"
+        assert detection.source == "gitattributes"
+
+    def test_override_beats_gitattributes(self, tmp_path: Path):
+        (tmp_path / ".gitattributes").write_text("*.tpl linguist-language=Smarty\n")
+        detector = LanguageDetector(repo_root=tmp_path, overrides={"*.tpl": "html"})
+        assert detector.detect("index.tpl").language == "HTML"
+
+    def test_reads_shebang_from_disk(self, tmp_path: Path):
+        script = tmp_path / "run"
+        script.write_text("#!/bin/bash\necho hi\n")
+        detection = LanguageDetector(repo_root=tmp_path).detect("run")
+        assert detection.language == "Shell"
+        assert detection.source == "shebang"
+
+    def test_unknown_file(self):
+        detection = LanguageDetector().detect("data/blob.xyz")
+        assert detection.language == "Unknown"
+        assert detection.source == "unknown"
+
+    def test_path_scoped_patterns_match_full_path(self):
+        detector = LanguageDetector(overrides={"vendor/*.js": "JavaScript"})
+        assert detector.detect("vendor/lib.js").source == "override"
+        assert detector.detect("src/app.js").source == "extension"
@@ -0,0 +1,108 @@
+"""Tests for the shared --filter/--select record expressions."""
+
+from __future__ import annotations
+
+import pytest
+
+from common.record_filter import (
+    BoolOp,
+    Comparison,
+    FilterError,
+    Not,
+    apply_filter,
+    apply_select,
+    lookup,
+    matches,
+    parse_filter,
+    parse_select,
+)
+
+ROWS = [
+    {"path": "src/payments/charge.py", "severity": "HIGH", "line": 10, "blocking": True},
+    {"path": "src/payments/refund.py", "severity": "LOW", "line": 42, "blocking": False},
+    {"path": "docs/readme.md", "severity": "LOW", "line": 3, "blocking": False},
+]
+
+
+class TestParseFilter:
+    def test_single_comparison(self) -> None:
+        assert parse_filter("severity = HIGH") == Comparison("severity", "=", "HIGH")
+
+    def test_and_or_precedence(self) -> None:
+        node = parse_filter("a = 1 OR b = 2 AND c = 3")
+        assert isinstance(node, BoolOp)
+        assert node.op == "OR"
+        assert isinstance(node.right, BoolOp)
+
+    def test_not_and_parentheses(self) -> None:
+        node = parse_filter("NOT (a = 1 OR b = 2)")
+        assert isinstance(node, Not)
+        assert isinstance(node.operand, BoolOp)
+
+    def test_dotted_fields_allowed(self) -> None:
+        assert parse_filter("meta.fingerprint = abc").field == "meta.fingerprint"
+
+    def test_empty_rejected(self) -> None:
+        with pytest.raises(FilterError, match="empty"):
+            parse_filter("  ")
+
+    def test_trailing_garbage_rejected(self) -> None:
+        with pytest.raises(FilterError, match="trailing"):
+            parse_filter("a = 1 b")
+
+    def test_missing_operator_rejected(self) -> None:
+        with pytest.raises(FilterError, match="operator"):
+            parse_filter("a (")
+
+
+class TestParseSelect:
+    def test_fields_split_and_stripped(self) -> None:
+        assert parse_select(" path , line ") == ("path", "line")
+
+    def test_empty_rejected(self) -> None:
+        with pytest.raises(FilterError, match="empty select"):
+            parse_select(" , ")
+
+
+class TestMatches:
+    def test_numeric_comparison(self) -> None:
+        assert matches(parse_filter("line > 9"), ROWS[0])
+        assert not matches(parse_filter("line > 9"), ROWS[2])
+
+    def test_string_equality(self) -> None:
+        assert matches(parse_filter("severity = HIGH"), ROWS[0])
+        assert matches(parse_filter("severity != HIGH"), ROWS[1])
+
+    def test_glob_matches_across_separators(self) -> None:
+        node = parse_filter("path GLOB 'src/payments/**'")
+        assert matches(node, ROWS[0])
+        assert not matches(node, ROWS[2])
+
+    def test_like_is_case_insensitive(self) -> None:
+        assert matches(parse_filter("path LIKE '%README%'"), ROWS[2])
+
+    def test_booleans_match_true_false(self) -> None:
+        assert matches(parse_filter("blocking = true"), ROWS[0])
+        assert matches(parse_filter("blocking = false"), ROWS[1])
+
+    def test_missing_field_only_matches_not_equal(self) -> None:
+        assert not matches(parse_filter("fingerprint = abc"), ROWS[0])
+        assert matches(parse_filter("fingerprint != abc"), ROWS[0])
+
+    def test_nested_lookup(self) -> None:
+        record = {"meta": {"fingerprint": "abc123"}}
+        assert lookup(record, "meta.fingerprint") == "abc123"
+        assert matches(parse_filter("meta.fingerprint = abc123"), record)
+
+
+class TestApply:
+    def test_filter_keeps_order(self) -> None:
+        rows = apply_filter(ROWS, parse_filter("severity = LOW"))
+        assert [row["line"] for row in rows] == [42, 3]
+
+    def test_select_projects_fields(self) -> None:
+        rows = apply_select(ROWS[:1], ("path", "line"))
+        assert rows == [{"path": "src/payments/charge.py", "line": 10}]
+
+    def test_select_missing_field_is_none(self) -> None:
+        assert apply_select(ROWS[:1], ("fingerprint",)) == [{"fingerprint": None}]
@@ -0,0 +1,112 @@
+"""Tests for the custom rule engine."""
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from common.rules_engine import Rule, load_rules, rule_applies, scan_file, scan_tree
+
+
+class TestRuleValidation:
+    def test_unknown_kind_rejected(self) -> None:
+        with pytest.raises(ValueError, match="unknown kind"):
+            Rule(rule_id="r1", kind="magic")
+
+    def test_regex_rule_requires_pattern(self) -> None:
+        with pytest.raises(ValueError, match="requires a pattern"):
+            Rule(rule_id="r1", kind="regex")
+
+    def test_banned_api_requires_apis(self) -> None:
+        with pytest.raises(ValueError, match="requires apis"):
+            Rule(rule_id="r1", kind="banned-api")
+
+    def test_invalid_pattern_rejected(self) -> None:
+        with pytest.raises(ValueError, match="invalid pattern"):
+            Rule(rule_id="r1", kind="regex", pattern="[unclosed")
+
+
+class TestLoadRules:
+    def test_loads_rules_in_filename_order(self, tmp_path: Path) -> None:
+        (tmp_path / "b.yaml").write_text(
+            "rules:\n  - id: second\n    kind: regex\n    pattern: x\n"
+        )
+        (tmp_path / "a.yaml").write_text(
+            "rules:\n  - id: first\n    kind: regex\n    pattern: x\n"
+        )
+        rules = load_rules(tmp_path)
+        assert [rule.rule_id for rule in rules] == ["first", "second"]
+
+    def test_duplicate_ids_rejected(self, tmp_path: Path) -> None:
+        (tmp_path / "a.yaml").write_text(
+            "rules:\n"
+            "  - id: dup\n    kind: regex\n    pattern: x\n"
+            "  - id: dup\n    kind: regex\n    pattern: y\n"
+        )
+        with pytest.raises(ValueError, match="duplicate rule id"):
+            load_rules(tmp_path)
+
+    def test_missing_rules_key_rejected(self, tmp_path: Path) -> None:
+        (tmp_path / "a.yaml").write_text("checks: []\n")
+        with pytest.raises(ValueError, match="top-level 'rules' list"):
+            load_rules(tmp_path)
+
+    def test_missing_directory_yields_no_rules(self, tmp_path: Path) -> None:
+        assert load_rules(tmp_path / "nope") == []
+
+
+class TestPathGlobs:
+    def test_paths_select_and_exclude_wins(self) -> None:
+        rule = Rule(
+            rule_id="r1",
+            kind="regex",
+            pattern="x",
+            paths=("src/**",),
+            exclude=("**/tests/**",),
+        )
+        assert rule_applies(rule, "src/app.py") is True
+        assert rule_applies(rule, "src/pkg/tests/test_app.py") is False
+        assert rule_applies(rule, "docs/guide.md") is False
+
+
+class TestScanFile:
+    def test_regex_reports_line_and_excerpt(self) -> None:
+        rule = Rule(rule_id="no-todo", kind="regex", pattern="TODO")
+        findings = scan_file([rule], "src/app.py", "ok\n# TODO: later\n")
+        assert len(findings) == 1
+        assert findings[0].line == 2
+        assert findings[0].excerpt == "# TODO: later"
+
+    def test_banned_api_matches_word_boundaries(self) -> None:
+        rule = Rule(rule_id="no-print", kind="banned-api", apis=("print",))
+        findings = scan_file([rule], "src/app.py", "sprint(1)\nprint(2)\n")
+        assert [finding.line for finding in findings] == [2]
+        assert "print" in findings[0].message
+
+    def test_required_header_flags_missing_only(self) -> None:
+        rule = Rule(
+            rule_id="header", kind="required-header", pattern="Copyright", max_lines=2
+        )
+        missing = scan_file([rule], "src/app.py", "line1\nline2\n# Copyright late\n")
+        present = scan_file([rule], "src/app.py", "# Copyright Acme\ncode\n")
+        assert len(missing) == 1
+        assert missing[0].line == 1
+        assert present == []
+
+
+class TestScanTree:
+    def test_scans_files_and_honors_calderaignore(self, tmp_path: Path) -> None:
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "app.py").write_text("eval(data)\n")
+        (tmp_path / "src" / "gen.py").write_text("eval(data)\n")
+        (tmp_path / ".calderaignore").write_text("src/gen.py\n")
+
+        rule = Rule(rule_id="no-eval", kind="banned-api", apis=("eval",))
+        findings = scan_tree(tmp_path, [rule])
+
+        assert [finding.path for finding in findings] == ["src/app.py"]
+
+    def test_binary_files_are_skipped(self, tmp_path: Path) -> None:
+        (tmp_path / "blob.bin").write_bytes(b"\xff\xfe\x00eval\x00")
+        rule = Rule(rule_id="no-eval", kind="banned-api", apis=("eval",))
+        assert scan_tree(tmp_path, [rule]) == []
@@ -0,0 +1,276 @@
+"""Tests for the memory-bounded streaming parsers."""
+
+from __future__ import annotations
+
+import io
+import json
+import random
+import tracemalloc
+from pathlib import Path
+from xml.etree import ElementTree as ET
+
+import pytest
+
+from common.streaming import (
+    ParseDiagnostics,
+    iter_json_array,
+    iter_xml_elements,
+    recover_json_array,
+    recover_xml_elements,
+)
+
+CPD_XML = """<?xml version="1.0" encoding="UTF-8"?>
+<pmd-cpd>
+  <duplication lines="10" tokens="55">
+    <file path="/repo/src/a.py" line="5" endline="14"/>
+    <file path="/repo/src/b.py" line="20" endline="29"/>
+    <codefragment>def process(data): pass</codefragment>
+  </duplication>
+  <duplication lines="8" tokens="42">
+    <file path="/repo/src/c.ts" line="1" endline="8"/>
+  </duplication>
+</pmd-cpd>"""
+
+SEMGREP_JSON = {
+    "version": "1.50.0",
+    "results": [
+        {"check_id": "DD-D1-EMPTY-CATCH-python", "path": "src/a.py", "start": {"line": 3}},
+        {"check_id": "sql-injection", "path": "src/b.py", "start": {"line": 12}},
+    ],
+    "errors": [{"type": "Timeout", "path": "src/slow.py"}],
+    "paths": {"scanned": ["src/a.py", "src/b.py"]},
+}
+
+
+def _write_large_xml(path: Path, duplications: int) -> None:
+    with path.open("w") as handle:
+        handle.write('<?xml version="1.0" encoding="UTF-8"?>\n<pmd-cpd>\n')
+        fragment = "x" * 400
+        for index in range(duplications):
+            handle.write(
+                f'<duplication lines="10" tokens="55">'
+                f'<file path="/repo/f{index}.py" line="1" endline="10"/>'
+                f"<codefragment>{fragment}</codefragment></duplication>\n"
+            )
+        handle.write("</pmd-cpd>\n")
+
+
+def _write_large_json(path: Path, results: int) -> None:
+    with path.open("w") as handle:
+        handle.write('{"version": "1.50.0", "results": [')
+        padding = "y" * 400
+        for index in range(results):
+            if index:
+                handle.write(", ")
+            handle.write(
+                json.dumps({"check_id": f"rule-{index}", "path": f"src/f{index}.py", "extra": padding})
+            )
+        handle.write('], "errors": [{"type": "Timeout"}]}')
+
+
+class TestIterXmlElements:
+    def test_subtree_available_during_iteration(self) -> None:
+        seen = []
+        for element in iter_xml_elements(io.StringIO(CPD_XML), "duplication"):
+            files = [child.get("path") for child in element.findall("file")]
+            seen.append((element.get("lines"), files))
+        assert seen == [
+            ("10", ["/repo/src/a.py", "/repo/src/b.py"]),
+            ("8", ["/repo/src/c.ts"]),
+        ]
+
+    def test_reads_from_path(self, tmp_path: Path) -> None:
+        report = tmp_path / "report.xml"
+        report.write_text(CPD_XML)
+        count = sum(1 for _ in iter_xml_elements(report, "duplication"))
+        assert count == 2
+
+    def test_malformed_xml_raises_parse_error(self) -> None:
+        with pytest.raises(ET.ParseError):
+            list(iter_xml_elements(io.StringIO("<bad xml>"), "duplication"))
+
+    def test_large_report_stays_memory_bounded(self, tmp_path: Path) -> None:
+        report = tmp_path / "large.xml"
+        _write_large_xml(report, duplications=20_000)  # ~10 MB on disk
+        file_size = report.stat().st_size
+
+        tracemalloc.start()
+        count = 0
+        for element in iter_xml_elements(report, "duplication"):
+            count += len(element.findall("file"))
+        _, peak = tracemalloc.get_traced_memory()
+        tracemalloc.stop()
+
+        assert count == 20_000
+        # Peak allocation is one element plus parser state, not the
+        # document; the bound holds regardless of report size.
+        assert peak < file_size / 4
+
+
+class TestIterJsonArray:
+    def test_streams_named_array(self, tmp_path: Path) -> None:
+        report = tmp_path / "semgrep.json"
+        report.write_text(json.dumps(SEMGREP_JSON))
+        results = list(iter_json_array(report, "results"))
+        assert [item["check_id"] for item in results] == [
+            "DD-D1-EMPTY-CATCH-python",
+            "sql-injection",
+        ]
+
+    def test_skips_giant_sibling_arrays(self, tmp_path: Path) -> None:
+        report = tmp_path / "semgrep.json"
+        report.write_text(json.dumps(SEMGREP_JSON))
+        # "errors" comes after "results"; the results array is skipped
+        # item by item, never held whole.
+        errors = list(iter_json_array(report, "errors"))
+        assert errors == [{"type": "Timeout", "path": "src/slow.py"}]
+
+    def test_missing_key_yields_nothing(self) -> None:
+        assert list(iter_json_array(io.StringIO('{"version": "1.0"}'), "results")) == []
+
+    def test_empty_array(self) -> None:
+        assert list(iter_json_array(io.StringIO('{"results": []}'), "results")) == []
+
+    def test_key_inside_string_values_is_not_matched(self) -> None:
+        payload = '{"note": "the results key", "results": [1, 2]}'
+        assert list(iter_json_array(io.StringIO(payload), "results")) == [1, 2]
+
+    def test_items_split_across_chunks(self) -> None:
+        payload = json.dumps({"results": [{"id": index, "pad": "z" * 50} for index in range(200)]})
+        items = list(iter_json_array(io.StringIO(payload), "results", chunk_size=7))
+        assert [item["id"] for item in items] == list(range(200))
+
+    def test_malformed_json_raises(self) -> None:
+        with pytest.raises(json.JSONDecodeError):
+            list(iter_json_array(io.StringIO('{"results": [{"a": 1}'), "results"))
+
+    def test_large_report_stays_memory_bounded(self, tmp_path: Path) -> None:
+        report = tmp_path / "large.json"
+        _write_large_json(report, results=20_000)  # ~10 MB on disk
+        file_size = report.stat().st_size
+
+        tracemalloc.start()
+        count = sum(1 for _ in iter_json_array(report, "results"))
+        errors = list(iter_json_array(report, "errors"))
+        _, peak = tracemalloc.get_traced_memory()
+        tracemalloc.stop()
+
+        assert count == 20_000
+        assert errors == [{"type": "Timeout"}]
+        assert peak < file_size / 4
+
+
+class TestRecoverXmlElements:
+    def test_clean_input_recovers_everything(self) -> None:
+        diagnostics = ParseDiagnostics()
+        elements = []
+        for element in recover_xml_elements(io.StringIO(CPD_XML), "duplication", diagnostics):
+            elements.append(element.get("lines"))
+        assert elements == ["10", "8"]
+        assert diagnostics.clean
+        assert diagnostics.records_recovered == 2
+
+    def test_interleaved_stderr_costs_nothing(self) -> None:
+        polluted = CPD_XML.replace(
+            "</duplication>\n  <duplication",
+            "</duplication>\nWARNING: something went sideways\n  <duplication",
+            1,
+        )
+        diagnostics = ParseDiagnostics()
+        count = sum(1 for _ in recover_xml_elements(io.StringIO(polluted), "duplication", diagnostics))
+        assert count == 2
+        assert diagnostics.records_discarded == 0
+
+    def test_stderr_inside_a_block_drops_only_that_block(self) -> None:
+        polluted = CPD_XML.replace(
+            "<codefragment>def process(data): pass</codefragment>",
+            "ERROR: <unclosed garbage\n",
+            1,
+        )
+        diagnostics = ParseDiagnostics()
+        recovered = list(recover_xml_elements(io.StringIO(polluted), "duplication", diagnostics))
+        assert [element.get("lines") for element in recovered] == ["8"]
+        assert diagnostics.records_discarded == 1
+        assert diagnostics.errors
+
+    def test_truncation_is_reported(self) -> None:
+        truncated = CPD_XML[: CPD_XML.index("<codefragment>") + 10]
+        diagnostics = ParseDiagnostics()
+        recovered = list(recover_xml_elements(io.StringIO(truncated), "duplication", diagnostics))
+        assert recovered == []
+        assert diagnostics.truncated
+
+    def test_pure_garbage_never_raises(self) -> None:
+        diagnostics = ParseDiagnostics()
+        assert list(recover_xml_elements(io.StringIO("<bad xml> &&& not xml"), "duplication", diagnostics)) == []
+
+    def test_fuzz_mutated_reports_never_raise(self) -> None:
+        rng = random.Random(2864)
+        for _ in range(200):
+            text = _mutate(rng, CPD_XML)
+            diagnostics = ParseDiagnostics()
+            recovered = list(recover_xml_elements(io.StringIO(text), "duplication", diagnostics))
+            assert len(recovered) == diagnostics.records_recovered
+            assert diagnostics.records_recovered + diagnostics.records_discarded <= 2 + text.count("<duplication")
+
+
+class TestRecoverJsonArray:
+    def test_clean_input_recovers_everything(self, tmp_path: Path) -> None:
+        report = tmp_path / "semgrep.json"
+        report.write_text(json.dumps(SEMGREP_JSON))
+        diagnostics = ParseDiagnostics()
+        results = list(recover_json_array(report, "results", diagnostics))
+        assert [item["check_id"] for item in results] == [
+            "DD-D1-EMPTY-CATCH-python",
+            "sql-injection",
+        ]
+        assert diagnostics.clean
+
+    def test_garbage_between_records_is_skipped(self) -> None:
+        payload = '{"results": [{"check_id": "a"}, WARN stderr leaked here, {"check_id": "b"}]}'
+        diagnostics = ParseDiagnostics()
+        results = list(recover_json_array(io.StringIO(payload), "results", diagnostics))
+        assert [item["check_id"] for item in results] == ["a", "b"]
+
+    def test_truncated_report_salvages_complete_records(self) -> None:
+        full = json.dumps({"results": [{"check_id": "a", "path": "x.py"}, {"check_id": "b", "path": "y.py"}]})
+        cut = full[: full.index('"b"') + 2]
+        diagnostics = ParseDiagnostics()
+        results = list(recover_json_array(io.StringIO(cut), "results", diagnostics))
+        assert [item["check_id"] for item in results] == ["a"]
+        assert diagnostics.truncated
+
+    def test_missing_array_is_noted_not_raised(self) -> None:
+        diagnostics = ParseDiagnostics()
+        assert list(recover_json_array(io.StringIO("not json at all"), "results", diagnostics)) == []
+        assert not diagnostics.clean
+        assert 'no "results" array' in diagnostics.errors[0]
+
+    def test_fuzz_mutated_reports_never_raise(self) -> None:
+        baseline = json.dumps(SEMGREP_JSON)
+        rng = random.Random(2864)
+        for _ in range(200):
+            text = _mutate(rng, baseline)
+            diagnostics = ParseDiagnostics()
+            results = list(recover_json_array(io.StringIO(text), "results", diagnostics))
+            assert len(results) == diagnostics.records_recovered
+
+
+def _mutate(rng: random.Random, text: str) -> str:
+    """Apply 1-3 random corruptions of the kinds tools actually produce."""
+    for _ in range(rng.randint(1, 3)):
+        kind = rng.randrange(4)
+        if kind == 0:  # truncate (timeout)
+            text = text[: rng.randrange(len(text) + 1)]
+        elif kind == 1:  # interleave a stderr line
+            pos = rng.randrange(len(text) + 1)
+            text = text[:pos] + "\nWARN: thread pool exhausted\n" + text[pos:]
+        elif kind == 2:  # delete a span
+            if len(text) > 10:
+                start = rng.randrange(len(text) - 5)
+                text = text[:start] + text[start + rng.randint(1, 5):]
+        else:  # flip a character
+            if text:
+                pos = rng.randrange(len(text))
+                text = text[:pos] + rng.choice('<>{}[]",x') + text[pos + 1:]
+    return text
@@ -0,0 +1,70 @@
+"""Tests for the locale-independent tool environment and number parsing."""
+
+from __future__ import annotations
+
+import pytest
+
+from common.tool_env import parse_decimal, parse_int, tool_environment
+
+
+class TestToolEnvironment:
+    def test_pins_locale_and_timezone(self) -> None:
+        env = tool_environment(base={"LANG": "de_DE.UTF-8", "PATH": "/usr/bin"})
+        assert env["LANG"] == "C.UTF-8"
+        assert env["LC_ALL"] == "C.UTF-8"
+        assert env["TZ"] == "UTC"
+        assert env["PATH"] == "/usr/bin"
+
+    def test_overrides_stray_lc_variables(self) -> None:
+        env = tool_environment(base={"LC_ALL": "de_DE.UTF-8", "LC_NUMERIC": "de_DE.UTF-8"})
+        # LC_ALL trumps every LC_* variable, so setting it is enough.
+        assert env["LC_ALL"] == "C.UTF-8"
+
+    def test_defaults_to_process_environment(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        monkeypatch.setenv("CALDERA_MARKER", "present")
+        env = tool_environment()
+        assert env["CALDERA_MARKER"] == "present"
+        assert env["TZ"] == "UTC"
+
+
+class TestParseDecimal:
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            ("3.14", 3.14),
+            ("3,14", 3.14),  # German decimal comma
+            ("1,234.56", 1234.56),  # English grouping
+            ("1.234,56", 1234.56),  # German grouping
+            ("1 234,56", 1234.56),  # French grouping
+            ("-2,5", -2.5),
+            ("42", 42.0),
+            ("1,234,567", 1234567.0),  # repeated commas can only be grouping
+        ],
+    )
+    def test_locale_variants(self, text: str, expected: float) -> None:
+        assert parse_decimal(text) == pytest.approx(expected)
+
+    def test_numbers_pass_through(self) -> None:
+        assert parse_decimal(2.5) == 2.5
+        assert parse_decimal(7) == 7.0
+
+    def test_garbage_returns_default(self) -> None:
+        assert parse_decimal("n/a") == 0.0
+        assert parse_decimal("", default=-1.0) == -1.0
+
+
+class TestParseInt:
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            ("42", 42),
+            ("1.234", 1234),  # German thousands grouping, counts are whole
+            ("12,345,678", 12345678),
+            ("7,0", 7),  # decimal comma on a whole count
+        ],
+    )
+    def test_locale_variants(self, text: str, expected: int) -> None:
+        assert parse_int(text) == expected
+
+    def test_garbage_returns_default(self) -> None:
+        assert parse_int("lots", default=-1) == -1
@@ -0,0 +1,100 @@
+"""Tests for explicit symlink/submodule/worktree traversal policy."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from common.traversal_policy import TraversalPolicy, find_submodule_paths, walk_tree
+
+
+def _relative(root: Path, files: list[Path]) -> set[str]:
+    return {f.relative_to(root).as_posix() for f in files}
+
+
+def _repo_with_submodule(tmp_path: Path) -> Path:
+    root = tmp_path / "repo"
+    (root / "src").mkdir(parents=True)
+    (root / "src" / "main.py").write_text("x = 1\n")
+    (root / ".gitmodules").write_text(
+        '[submodule "libs/dep"]\n\tpath = libs/dep\n\turl = ../dep.git\n'
+    )
+    dep = root / "libs" / "dep"
+    dep.mkdir(parents=True)
+    (dep / ".git").write_text("gitdir: ../../.git/modules/libs/dep\n")
+    (dep / "dep.py").write_text("y = 2\n")
+    return root
+
+
+class TestPolicy:
+    def test_defaults_are_conservative(self) -> None:
+        policy = TraversalPolicy()
+        assert not policy.follow_symlinks
+        assert not policy.descend_submodules
+        assert not policy.include_worktrees
+
+    def test_from_dict_rejects_unknown_keys(self) -> None:
+        with pytest.raises(ValueError, match="Unknown traversal policy keys"):
+            TraversalPolicy.from_dict({"follow_links": True})
+
+    def test_metadata_records_all_flags(self) -> None:
+        metadata = TraversalPolicy(follow_symlinks=True).to_metadata()
+        assert metadata["traversal_policy"] == {
+            "follow_symlinks": True,
+            "descend_submodules": False,
+            "include_worktrees": False,
+        }
+
+
+class TestSubmodules:
+    def test_gitmodules_paths_parsed(self, tmp_path: Path) -> None:
+        root = _repo_with_submodule(tmp_path)
+        assert find_submodule_paths(root) == {"libs/dep"}
+
+    def test_submodules_skipped_by_default(self, tmp_path: Path) -> None:
+        root = _repo_with_submodule(tmp_path)
+        files = walk_tree(root, TraversalPolicy())
+        assert _relative(root, files) == {".gitmodules", "src/main.py"}
+
+    def test_submodules_descended_when_enabled(self, tmp_path: Path) -> None:
+        root = _repo_with_submodule(tmp_path)
+        files = walk_tree(root, TraversalPolicy(descend_submodules=True))
+        assert "libs/dep/dep.py" in _relative(root, files)
+
+
+class TestWorktrees:
+    def test_nested_checkout_skipped_by_default(self, tmp_path: Path) -> None:
+        root = tmp_path / "repo"
+        (root / "src").mkdir(parents=True)
+        (root / "src" / "main.py").write_text("x = 1\n")
+        worktree = root / "wt"
+        worktree.mkdir()
+        (worktree / ".git").write_text("gitdir: /elsewhere\n")
+        (worktree / "feature.py").write_text("z = 3\n")
+
+        assert _relative(root, walk_tree(root, TraversalPolicy())) == {"src/main.py"}
+        included = walk_tree(root, TraversalPolicy(include_worktrees=True))
+        assert "wt/feature.py" in _relative(root, included)
+
+
+class TestSymlinks:
+    def test_symlinks_skipped_by_default(self, tmp_path: Path) -> None:
+        root = tmp_path / "repo"
+        (root / "real").mkdir(parents=True)
+        (root / "real" / "a.py").write_text("a = 1\n")
+        (root / "link").symlink_to(root / "real")
+        (root / "file_link.py").symlink_to(root / "real" / "a.py")
+
+        assert _relative(root, walk_tree(root, TraversalPolicy())) == {"real/a.py"}
+
+    def test_followed_symlinks_with_cycle_terminate(self, tmp_path: Path) -> None:
+        root = tmp_path / "repo"
+        (root / "real").mkdir(parents=True)
+        (root / "real" / "a.py").write_text("a = 1\n")
+        # Cycle: real/loop -> repo root
+        (root / "real" / "loop").symlink_to(root)
+
+        files = walk_tree(root, TraversalPolicy(follow_symlinks=True))
+
+        assert _relative(root, files) == {"real/a.py"}
@@ -0,0 +1,93 @@
+"""
+Locale-Independent Tool Invocation - Shared module for subprocess environments.
+
+Spawned tools inherit the agent's locale, and a German-locale agent made
+CPD format ``3,14`` where the parser expected ``3.14``, silently breaking
+duplication percentages. The fix has two halves:
+
+1. ``tool_environment`` pins every spawned tool to a controlled locale
+   (``C.UTF-8``) and timezone (``UTC``), so output formatting does not
+   depend on which machine ran the scan.
+2. ``parse_decimal`` / ``parse_int`` accept decimal commas and grouping
+   separators anyway, so output from a tool that ignores the environment
+   (or from archived runs on mis-configured agents) still parses.
+"""
+
+from __future__ import annotations
+
+import os
+import re
+
+# Thousands-grouped integer, e.g. "1.234" or "12,345,678".
+_GROUPED_INT_RE = re.compile(r"[+-]?\d{1,3}(?:[.,]\d{3})+")
+
+# The locale every spawned tool runs under: C semantics (dot decimal
+# separator, C date formatting) with UTF-8 so paths survive.
+TOOL_LOCALE = "C.UTF-8"
+TOOL_TIMEZONE = "UTC"
+
+
+def tool_environment(base: dict[str, str] | None = None) -> dict[str, str]:
+    """A copy of the environment pinned to a controlled locale and timezone.
+
+    ``LC_ALL`` is set (not defaulted) because it overrides every other
+    ``LC_*`` variable — a stray ``LC_NUMERIC`` on the agent must not
+    leak through.
+    """
+    env = dict(os.environ if base is None else base)
+    env["LANG"] = TOOL_LOCALE
+    env["LC_ALL"] = TOOL_LOCALE
+    env["TZ"] = TOOL_TIMEZONE
+    return env
+
+
+def parse_decimal(text: str | float | int, default: float = 0.0) -> float:
+    """Parse a number regardless of the locale that formatted it.
+
+    Handles ``3.14``, ``3,14``, ``1,234.56``, and ``1.234,56``: when both
+    separators appear, the rightmost one is the decimal point; a lone
+    comma is a decimal comma unless it groups exactly three trailing
+    digits alongside nothing else — tools do not emit bare thousands
+    grouping for metrics, so a lone separator is treated as decimal.
+    Returns ``default`` for unparseable input.
+    """
+    if isinstance(text, (int, float)):
+        return float(text)
+    # French-style grouping separates thousands with (non-breaking) spaces.
+    cleaned = text.strip().replace(" ", "").replace(" ", "").replace(" ", "")
+    if not cleaned:
+        return default
+    dot = cleaned.rfind(".")
+    comma = cleaned.rfind(",")
+    if dot != -1 and comma != -1:
+        if comma > dot:
+            cleaned = cleaned.replace(".", "").replace(",", ".")
+        else:
+            cleaned = cleaned.replace(",", "")
+    elif comma != -1:
+        cleaned = cleaned.replace(",", ".", 1) if cleaned.count(",") == 1 else cleaned.replace(",", "")
+    try:
+        return float(cleaned)
+    except ValueError:
+        return default
+
+
+def parse_int(text: str | float | int, default: int = 0) -> int:
+    """Integer counterpart of ``parse_decimal`` for counts.
+
+    Counts are whole numbers, so a separator grouping exactly three
+    digits is thousands grouping (``1.234`` from a German locale is
+    1234, not one-and-a-bit lines).
+    """
+    if isinstance(text, (int, float)):
+        return int(text)
+    cleaned = text.strip()
+    if not cleaned:
+        return default
+    try:
+        return int(cleaned)
+    except ValueError:
+        pass
+    if _GROUPED_INT_RE.fullmatch(cleaned):
+        return int(cleaned.replace(".", "").replace(",", ""))
+    return int(parse_decimal(cleaned, default=float(default)))
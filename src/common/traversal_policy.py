@@ -0,0 +1,112 @@
+"""
+Traversal Policy - Shared module for symlink/submodule/worktree handling.
+
+Tools currently differ silently on whether they follow symlinks,
+descend into git submodules, or pick up linked worktrees — so the same
+repo yields different file universes per tool. This module makes the
+policy explicit: one dataclass with three flags, one walker that
+enforces it with cycle detection, and a metadata dict tools attach to
+their envelope (via ``create_envelope(extra_metadata=...)``) so every
+run records which policy produced its numbers.
+
+Defaults are conservative: symlinks are not followed, submodules and
+nested checkouts are not descended into.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+
+GITMODULES_NAME = ".gitmodules"
+
+_GITMODULES_PATH_RE = re.compile(r"^\s*path\s*=\s*(.+?)\s*$", re.MULTILINE)
+
+
+@dataclass(frozen=True)
+class TraversalPolicy:
+    """Explicit choices for repo tree traversal."""
+
+    follow_symlinks: bool = False
+    descend_submodules: bool = False
+    include_worktrees: bool = False
+
+    @classmethod
+    def from_dict(cls, config: dict) -> TraversalPolicy:
+        unknown = set(config) - {"follow_symlinks", "descend_submodules", "include_worktrees"}
+        if unknown:
+            raise ValueError(f"Unknown traversal policy keys: {', '.join(sorted(unknown))}")
+        return cls(**{key: bool(value) for key, value in config.items()})
+
+    def to_metadata(self) -> dict:
+        """Render for envelope ``extra_metadata`` under ``traversal_policy``."""
+        return {
+            "traversal_policy": {
+                "follow_symlinks": self.follow_symlinks,
+                "descend_submodules": self.descend_submodules,
+                "include_worktrees": self.include_worktrees,
+            },
+        }
+
+
+def find_submodule_paths(repo_root: Path) -> set[str]:
+    """Read submodule paths (repo-relative, POSIX) from .gitmodules."""
+    gitmodules = repo_root / GITMODULES_NAME
+    if not gitmodules.is_file():
+        return set()
+    try:
+        text = gitmodules.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        return set()
+    return {match.replace("\\", "/") for match in _GITMODULES_PATH_RE.findall(text)}
+
+
+def _is_nested_checkout(directory: Path) -> bool:
+    """A directory with its own .git (file or dir) below the repo root.
+
+    A ``.git`` file marks a linked worktree or submodule checkout; a
+    ``.git`` directory marks an embedded repository.
+    """
+    return (directory / ".git").exists()
+
+
+def walk_tree(root: Path, policy: TraversalPolicy) -> list[Path]:
+    """Collect files under root according to the traversal policy.
+
+    The repo's own ``.git`` is always skipped. When following symlinks,
+    directories already visited (by resolved path) are skipped so
+    symlink cycles terminate. Results are sorted for determinism.
+    """
+    submodule_paths = find_submodule_paths(root)
+    visited_real_dirs = {root.resolve()}
+    files: list[Path] = []
+
+    def visit(directory: Path) -> None:
+        try:
+            entries = sorted(directory.iterdir())
+        except OSError:
+            return
+        for entry in entries:
+            if entry.name == ".git":
+                continue
+            is_symlink = entry.is_symlink()
+            if is_symlink and not policy.follow_symlinks:
+                continue
+            if entry.is_dir():
+                relative = entry.relative_to(root).as_posix()
+                if relative in submodule_paths:
+                    if not policy.descend_submodules:
+                        continue
+                elif _is_nested_checkout(entry) and not policy.include_worktrees:
+                    continue
+                real = entry.resolve()
+                if real in visited_real_dirs:
+                    continue  # symlink cycle or re-entered directory
+                visited_real_dirs.add(real)
+                visit(entry)
+            elif entry.is_file():
+                files.append(entry)
+
+    visit(root)
+    return files
@@ -0,0 +1,225 @@
+"""CI reporters for Azure DevOps and Bitbucket.
+
+Rounds out the CI integrations for orgs not on GitHub/GitLab:
+
+* Azure DevOps — ``##vso`` logging commands on stdout. Azure Pipelines
+  picks these up from any script step, so ``insights azure-annotations``
+  needs no API credentials: findings become build annotations and the
+  task result is set from the gate engine.
+* Bitbucket — Code Insights reports via the REST API: one report per
+  commit with annotations (batched to the API's 100-per-request limit,
+  capped per report), shown inline on the pull request diff.
+
+The Bitbucket token comes from the ``BITBUCKET_TOKEN`` environment
+variable (a repository access token with report write), never config.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import urllib.error
+import urllib.request
+from dataclasses import dataclass
+
+from insights.gates import GateResult
+from insights.notifications import severity_rank
+
+# Azure logging-command issue types only distinguish error and warning.
+_AZURE_TYPE_BY_SEVERITY = {
+    "CRITICAL": "error",
+    "HIGH": "error",
+    "MEDIUM": "warning",
+    "LOW": "warning",
+    "INFO": "warning",
+}
+MAX_AZURE_ISSUES = 100
+
+BITBUCKET_API_ROOT = "https://api.bitbucket.org/2.0"
+BITBUCKET_REPORT_ID = "caldera"
+# Bitbucket accepts at most 100 annotations per request, 1000 per report.
+BITBUCKET_BATCH_SIZE = 100
+MAX_BITBUCKET_ANNOTATIONS = 1000
+
+_BITBUCKET_SEVERITIES = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+_SECURITY_TOOLS = {"trivy", "gitleaks", "bandit", "devskim", "semgrep"}
+
+
+class ReporterError(RuntimeError):
+    """CI reporter API call failed."""
+
+
+def _prioritized(findings: list[dict], cap: int) -> tuple[list[dict], int]:
+    ordered = sorted(
+        findings,
+        key=lambda f: (-severity_rank(str(f.get("severity", "INFO"))), str(f.get("relative_path", ""))),
+    )
+    return ordered[:cap], max(len(ordered) - cap, 0)
+
+
+# -- Azure DevOps ------------------------------------------------------------
+
+
+def render_azure_commands(
+    findings: list[dict],
+    gate_results: list[GateResult],
+    cap: int = MAX_AZURE_ISSUES,
+) -> list[str]:
+    """Findings and gate verdict as Azure Pipelines logging commands.
+
+    Worst severity first, capped; the final ``task.complete`` command sets
+    the step result (Failed on a failing gate, SucceededWithIssues when
+    findings exist, Succeeded otherwise).
+    """
+    commands = []
+    selected, truncated = _prioritized(findings, cap)
+    for finding in selected:
+        severity = str(finding.get("severity", "INFO")).upper()
+        issue_type = _AZURE_TYPE_BY_SEVERITY.get(severity, "warning")
+        properties = {
+            "type": issue_type,
+            "sourcepath": finding.get("relative_path", ""),
+            "linenumber": finding.get("line_start") or finding.get("line_number") or 1,
+            "code": finding.get("rule_id", ""),
+        }
+        rendered = ";".join(f"{key}={value}" for key, value in properties.items())
+        message = str(finding.get("message") or finding.get("rule_id") or "finding")
+        commands.append(f"##vso[task.logissue {rendered}]{message}")
+    if truncated:
+        commands.append(
+            f"##vso[task.logissue type=warning]{truncated} further finding(s) not annotated "
+            f"(cap {cap})"
+        )
+    for result in gate_results:
+        if not result.passed:
+            commands.append(f"##vso[task.logissue type=error]gate {result.name} failed: {result.message}")
+    if gate_results and not all(result.passed for result in gate_results):
+        commands.append("##vso[task.complete result=Failed]Caldera gates failed")
+    elif findings:
+        commands.append("##vso[task.complete result=SucceededWithIssues]Caldera found issues")
+    else:
+        commands.append("##vso[task.complete result=Succeeded]Caldera clean")
+    return commands
+
+
+# -- Bitbucket Code Insights -------------------------------------------------
+
+
+@dataclass(frozen=True)
+class BitbucketReport:
+    """What was published to Bitbucket for one commit."""
+
+    report_id: str
+    result: str
+    annotation_count: int
+    truncated: int
+
+    def to_dict(self) -> dict:
+        return {
+            "report_id": self.report_id,
+            "result": self.result,
+            "annotation_count": self.annotation_count,
+            "truncated": self.truncated,
+        }
+
+
+def build_bitbucket_report(findings: list[dict], gate_results: list[GateResult]) -> dict:
+    failed_gates = [result for result in gate_results if not result.passed]
+    details = f"{len(findings)} finding(s) from the Caldera analysis pipeline."
+    if failed_gates:
+        details += " Failed gates: " + ", ".join(result.name for result in failed_gates) + "."
+    return {
+        "title": "Caldera analysis",
+        "report_type": "SECURITY",
+        "reporter": "caldera",
+        "result": "FAILED" if failed_gates else "PASSED",
+        "details": details,
+    }
+
+
+def build_bitbucket_annotations(
+    findings: list[dict], cap: int = MAX_BITBUCKET_ANNOTATIONS
+) -> tuple[list[dict], int]:
+    """Findings as Code Insights annotations, worst severity first, capped."""
+    selected, truncated = _prioritized(findings, cap)
+    annotations = []
+    for index, finding in enumerate(selected):
+        severity = str(finding.get("severity", "INFO")).upper()
+        tool = str(finding.get("tool", ""))
+        annotations.append({
+            "external_id": f"caldera-{index}",
+            "annotation_type": "VULNERABILITY" if tool in _SECURITY_TOOLS else "CODE_SMELL",
+            "severity": severity if severity in _BITBUCKET_SEVERITIES else "LOW",
+            "path": finding.get("relative_path", ""),
+            "line": int(finding.get("line_start") or finding.get("line_number") or 1),
+            "summary": str(finding.get("message") or finding.get("rule_id") or "finding")[:450],
+        })
+    return annotations, truncated
+
+
+def _default_transport(method: str, url: str, payload) -> dict:
+    token = os.environ.get("BITBUCKET_TOKEN")
+    if not token:
+        raise ReporterError("BITBUCKET_TOKEN is not set; a repository access token is required")
+    request = urllib.request.Request(
+        url,
+        data=json.dumps(payload).encode() if payload is not None else None,
+        method=method,
+        headers={"Authorization": f"Bearer {token}", "Content-Type": "application/json"},
+    )
+    try:
+        with urllib.request.urlopen(request, timeout=30) as response:
+            return json.loads(response.read() or b"{}")
+    except urllib.error.HTTPError as exc:
+        raise ReporterError(f"{method} {url} failed: HTTP {exc.code} {exc.read()[:200]!r}") from exc
+    except urllib.error.URLError as exc:
+        raise ReporterError(f"{method} {url} failed: {exc.reason}") from exc
+
+
+class BitbucketClient:
+    """Code Insights client; ``transport(method, url, payload)`` is injectable."""
+
+    def __init__(self, workspace: str, repo_slug: str, transport=None) -> None:
+        self._base = f"{BITBUCKET_API_ROOT}/repositories/{workspace}/{repo_slug}"
+        self._transport = transport or _default_transport
+
+    def put_report(self, commit: str, report: dict, report_id: str = BITBUCKET_REPORT_ID) -> None:
+        self._transport(
+            "PUT", f"{self._base}/commit/{commit}/reports/{report_id}", report
+        )
+
+    def post_annotations(
+        self, commit: str, annotations: list[dict], report_id: str = BITBUCKET_REPORT_ID
+    ) -> int:
+        """POST annotations in API-sized batches; returns batch count."""
+        batches = [
+            annotations[start:start + BITBUCKET_BATCH_SIZE]
+            for start in range(0, len(annotations), BITBUCKET_BATCH_SIZE)
+        ]
+        for batch in batches:
+            self._transport(
+                "POST",
+                f"{self._base}/commit/{commit}/reports/{report_id}/annotations",
+                batch,
+            )
+        return len(batches)
+
+
+def publish_bitbucket_report(
+    client: BitbucketClient,
+    commit: str,
+    findings: list[dict],
+    gate_results: list[GateResult],
+) -> BitbucketReport:
+    """Publish one Code Insights report with annotations for a commit."""
+    report = build_bitbucket_report(findings, gate_results)
+    annotations, truncated = build_bitbucket_annotations(findings)
+    client.put_report(commit, report)
+    if annotations:
+        client.post_annotations(commit, annotations)
+    return BitbucketReport(
+        report_id=BITBUCKET_REPORT_ID,
+        result=report["result"],
+        annotation_count=len(annotations),
+        truncated=truncated,
+    )
@@ -349,6 +349,2575 @@ def tool_readiness_report(
         raise typer.Exit(1)
 
 
+@app.command("gates")
+def run_quality_gates(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead for collection-level gates)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    repo_path: Path | None = typer.Option(None, "--repo-path", help="Repository path; enables the license policy gate"),
+    base_collection_run_id: str | None = typer.Option(
+        None,
+        "--base-collection-run-id",
+        help="Baseline collection run; enables the complexity delta and new-clone gates",
+    ),
+    changed_files: Path | None = typer.Option(
+        None,
+        "--changed-files",
+        help="File listing changed repo-relative paths (one per line, e.g. from git diff --name-only); scopes the new-clone gate to the PR",
+    ),
+    notify: bool = typer.Option(False, "--notify", help="POST gate failures to configured webhooks"),
+) -> None:
+    """Evaluate quality gates for a collection run.
+
+    Fetches trivy vulnerabilities for the collection and evaluates the
+    configured gates (see [gates] in caldera.toml). With --repo-path the
+    license policy gate also runs (see [license_policy]). Exits non-zero
+    when any gate fails, so this command can guard CI pipelines. With
+    --notify, failures are POSTed to the webhooks configured under
+    [[notifications.webhooks]].
+
+    Example:
+        insights gates 19 --db /tmp/caldera.duckdb
+        insights gates --collection-run-id abc123... --db /tmp/caldera.duckdb
+    """
+    from .data_fetcher import DataFetcher
+    from .gates import (
+        gates_passed,
+        group_clone_rows,
+        load_clone_gate_config,
+        load_complexity_gate_config,
+        load_gates_config,
+        load_policy_gates,
+        run_gates,
+    )
+    from .license_scan import find_policy_violations, load_license_policy, scan_repository
+    from .notifications import build_gate_failure_event, load_notification_config
+    from .notifications import notify as deliver_notifications
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        configs = load_gates_config(config)
+        vulnerabilities = fetcher.fetch("fixable_vulnerabilities", run_pk=run_pk)
+
+        policy_configs = load_policy_gates(config)
+        findings = None
+        if policy_configs:
+            findings = fetcher.fetch("policy_findings", run_pk=run_pk)
+
+        license_violations = None
+        if repo_path is not None:
+            policy = load_license_policy(config)
+            license_violations = find_policy_violations(scan_repository(repo_path), policy)
+
+        complexity_functions = None
+        clone_sets = None
+        if base_collection_run_id is not None:
+            base_run_pk = fetcher.get_scc_run_pk_for_collection(base_collection_run_id)
+            complexity_functions = (
+                fetcher.fetch("lizard_functions", run_pk=base_run_pk),
+                fetcher.fetch("lizard_functions", run_pk=run_pk),
+            )
+            clone_sets = (
+                group_clone_rows(fetcher.fetch("pmd_cpd_clones", run_pk=base_run_pk)),
+                group_clone_rows(fetcher.fetch("pmd_cpd_clones", run_pk=run_pk)),
+            )
+
+        changed_paths = None
+        if changed_files is not None:
+            changed_paths = {
+                line.strip()
+                for line in changed_files.read_text().splitlines()
+                if line.strip()
+            }
+
+        results = run_gates(
+            vulnerabilities,
+            configs,
+            license_violations=license_violations,
+            complexity_functions=complexity_functions,
+            complexity_config=load_complexity_gate_config(config),
+            clone_sets=clone_sets,
+            clone_config=load_clone_gate_config(config),
+            changed_files=changed_paths,
+            findings=findings,
+            policy_configs=policy_configs,
+        )
+
+        table = Table(title="Quality Gates")
+        table.add_column("Gate", style="cyan")
+        table.add_column("Status")
+        table.add_column("Actual", justify="right")
+        table.add_column("Limit", justify="right")
+        table.add_column("Detail")
+
+        for result in results:
+            status = "[green]PASS[/green]" if result.passed else "[red]FAIL[/red]"
+            table.add_row(result.name, status, str(result.actual), str(result.limit), result.message)
+
+        console.print(table)
+
+        for result in results:
+            if not result.passed:
+                for offender in result.offenders:
+                    console.print(f"  [red]•[/red] {offender}")
+
+        if not gates_passed(results):
+            if notify:
+                webhooks = load_notification_config(config)
+                if not webhooks:
+                    console.print("[yellow]--notify given but no [[notifications.webhooks]] configured[/yellow]")
+                else:
+                    run_info = fetcher.get_run_info(run_pk)
+                    event = build_gate_failure_event(
+                        repo_id=run_info.get("repo_id", "unknown"),
+                        run_ref=collection_run_id or f"run_pk={run_pk}",
+                        gate_results=results,
+                    )
+                    for delivery in deliver_notifications([event], webhooks):
+                        status = "[green]sent[/green]" if delivery.ok else "[red]failed[/red]"
+                        console.print(f"  webhook {delivery.url}: {status} ({delivery.detail})")
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error evaluating gates:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("ownership")
+def ownership_report(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    limit: int = typer.Option(30, "--limit", "-n", help="Maximum directories to show"),
+    risk: str | None = typer.Option(None, "--risk", help="Only show directories at this risk level (high/medium/low)"),
+) -> None:
+    """Report per-directory ownership concentration and bus factor.
+
+    Aggregates git-blame-scanner authorship into directory-level bus factor
+    and joins lizard complexity and semgrep finding density, flagging
+    high-risk areas owned by a single developer. Thresholds come from
+    [ownership] in caldera.toml.
+
+    Example:
+        insights ownership 19 --db /tmp/caldera.duckdb
+        insights ownership -c abc123... --db /tmp/caldera.duckdb --risk high
+    """
+    from .data_fetcher import DataFetcher
+    from .ownership import compute_directory_ownership, load_ownership_config
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if risk is not None and risk not in ("high", "medium", "low"):
+        console.print(f"[red]Error:[/red] Invalid risk level: {risk}")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        blame_rows = fetcher.fetch("blame_files", run_pk=run_pk)
+        if not blame_rows:
+            console.print("[yellow]No git-blame-scanner data for this run; nothing to report.[/yellow]")
+            return
+
+        entries = compute_directory_ownership(
+            blame_rows,
+            complexity_rows=fetcher.fetch("lizard_files", run_pk=run_pk),
+            finding_rows=fetcher.fetch("semgrep_file_counts", run_pk=run_pk),
+            config=load_ownership_config(config),
+        )
+        if risk is not None:
+            entries = [entry for entry in entries if entry.risk == risk]
+
+        table = Table(title="Directory Ownership")
+        table.add_column("Directory", style="cyan")
+        table.add_column("Lines", justify="right")
+        table.add_column("Bus factor", justify="right")
+        table.add_column("Top author")
+        table.add_column("Churn 90d", justify="right")
+        table.add_column("Mean CCN", justify="right")
+        table.add_column("Findings/KLOC", justify="right")
+        table.add_column("Risk")
+
+        risk_styles = {"high": "[red]high[/red]", "medium": "[yellow]medium[/yellow]", "low": "[green]low[/green]"}
+        for entry in entries[:limit]:
+            top = f"{entry.top_author} ({entry.top_author_pct}%)" if entry.top_author else "-"
+            table.add_row(
+                entry.directory,
+                str(entry.total_lines),
+                str(entry.bus_factor),
+                top,
+                str(entry.churn_90d),
+                "-" if entry.mean_ccn is None else str(entry.mean_ccn),
+                "-" if entry.findings_per_kloc is None else str(entry.findings_per_kloc),
+                risk_styles[entry.risk],
+            )
+
+        console.print(table)
+
+        high = sum(1 for entry in entries if entry.risk == "high")
+        if high:
+            console.print(f"[red]{high} high-risk director{'y' if high == 1 else 'ies'} owned by a single developer.[/red]")
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error generating ownership report:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("cochange")
+def cochange_report(
+    repo_path: Path = typer.Argument(..., help="Path to the git repository to mine"),
+    max_commits: int = typer.Option(2000, "--max-commits", help="Recent non-merge commits to mine"),
+    min_support: int = typer.Option(5, "--min-support", help="Minimum commits touching both files"),
+    min_confidence: float = typer.Option(0.5, "--min-confidence", help="Minimum confidence (0-1)"),
+    all_pairs: bool = typer.Option(False, "--all", help="Include same-module pairs (default: cross-module only)"),
+    limit: int = typer.Option(30, "--limit", "-n", help="Maximum pairs to show"),
+) -> None:
+    """Report temporal coupling mined from commit history.
+
+    Finds file pairs frequently modified in the same commit. Pairs that
+    span top-level modules reveal implicit coupling the dependency graph
+    does not show — useful input for architecture reviews.
+
+    Example:
+        insights cochange /path/to/repo --min-support 8
+    """
+    from .cochange import compute_cochange, read_commit_file_sets
+
+    if not repo_path.exists():
+        console.print(f"[red]Error:[/red] Repository not found: {repo_path}")
+        raise typer.Exit(1)
+
+    try:
+        file_sets = read_commit_file_sets(repo_path, max_commits=max_commits)
+        pairs = compute_cochange(
+            file_sets, min_support=min_support, min_confidence=min_confidence
+        )
+        if not all_pairs:
+            pairs = [pair for pair in pairs if pair.cross_module]
+
+        if not pairs:
+            console.print("[green]No coupled file pairs above the thresholds.[/green]")
+            return
+
+        table = Table(title=f"Temporal Coupling ({len(file_sets)} commits mined)")
+        table.add_column("File A", style="cyan")
+        table.add_column("File B", style="cyan")
+        table.add_column("Support", justify="right")
+        table.add_column("Confidence", justify="right")
+        table.add_column("Cross-module")
+
+        for pair in pairs[:limit]:
+            table.add_row(
+                pair.file_a,
+                pair.file_b,
+                str(pair.support),
+                f"{pair.confidence:.2f}",
+                "[red]yes[/red]" if pair.cross_module else "no",
+            )
+
+        console.print(table)
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error mining commit history:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("dependencies")
+def dependencies_report(
+    repo_path: Path = typer.Argument(..., help="Path to the repository to analyze"),
+    cycles_only: bool = typer.Option(False, "--cycles-only", help="Skip the fan-in/fan-out table"),
+    limit: int = typer.Option(20, "--limit", "-n", help="Maximum rows per table"),
+) -> None:
+    """Report the module dependency graph and import cycles.
+
+    Parses import statements (Python, JS/TS, Rust, Go) into a file-level
+    graph, lists the highest fan-in/fan-out files, and flags dependency
+    cycles — files that can only be understood and released together.
+
+    Example:
+        insights dependencies /path/to/repo --cycles-only
+    """
+    from .dependency_graph import build_dependency_graph, find_cycles
+
+    if not repo_path.exists():
+        console.print(f"[red]Error:[/red] Repository not found: {repo_path}")
+        raise typer.Exit(1)
+
+    try:
+        graph = build_dependency_graph(repo_path)
+        cycles = find_cycles(graph)
+
+        if not cycles_only:
+            hubs = sorted(
+                graph.nodes,
+                key=lambda node: (-(node.fan_in + node.fan_out), node.path),
+            )
+            hubs = [node for node in hubs if node.fan_in + node.fan_out > 0]
+
+            table = Table(
+                title=f"Dependency Hubs ({len(graph.nodes)} files, {len(graph.edges)} edges)"
+            )
+            table.add_column("File", style="cyan")
+            table.add_column("Language")
+            table.add_column("Fan-in", justify="right")
+            table.add_column("Fan-out", justify="right")
+
+            for node in hubs[:limit]:
+                table.add_row(
+                    node.path, node.language, str(node.fan_in), str(node.fan_out)
+                )
+            console.print(table)
+
+        if not cycles:
+            console.print("[green]No dependency cycles found.[/green]")
+            return
+
+        table = Table(title=f"Dependency Cycles ({len(cycles)})")
+        table.add_column("Size", justify="right")
+        table.add_column("Edges", justify="right")
+        table.add_column("Members", style="cyan")
+
+        for cycle in cycles[:limit]:
+            table.add_row(
+                str(cycle.size),
+                str(cycle.internal_edges),
+                "\n".join(cycle.members),
+            )
+        console.print(table)
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error building dependency graph:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("debt")
+def debt_report(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    by: str = typer.Option("file", "--by", help="Aggregation level: file or directory"),
+    limit: int = typer.Option(25, "--limit", "-n", help="Maximum rows to show"),
+) -> None:
+    """Estimate technical debt in remediation minutes (SQALE-style).
+
+    Prices findings, over-threshold functions, and duplicated lines with
+    the cost model from [debt] in caldera.toml and aggregates per file or
+    per directory.
+
+    Example:
+        insights debt 19 --db /tmp/caldera.duckdb --by directory
+    """
+    from .data_fetcher import DataFetcher
+    from .debt import (
+        aggregate_directory_debt,
+        compute_file_debt,
+        format_minutes,
+        load_debt_config,
+    )
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if by not in ("file", "directory"):
+        console.print(f"[red]Error:[/red] Invalid aggregation level: {by}")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        file_debts = compute_file_debt(
+            fetcher.fetch("debt_findings", run_pk=run_pk),
+            function_rows=fetcher.fetch("lizard_functions", run_pk=run_pk),
+            duplication_rows=fetcher.fetch("pmd_cpd_files", run_pk=run_pk),
+            config=load_debt_config(config),
+        )
+        if not file_debts:
+            console.print("[green]No priced debt signals for this run.[/green]")
+            return
+
+        total = sum(debt.total_minutes for debt in file_debts)
+
+        table = Table(title=f"Technical Debt (total {format_minutes(total)})")
+        table.add_column("Directory" if by == "directory" else "File", style="cyan")
+        if by == "directory":
+            table.add_column("Files", justify="right")
+        table.add_column("Findings", justify="right")
+        table.add_column("Complexity", justify="right")
+        table.add_column("Duplication", justify="right")
+        table.add_column("Total", justify="right", style="bold")
+
+        if by == "directory":
+            for entry in aggregate_directory_debt(file_debts)[:limit]:
+                table.add_row(
+                    entry.directory,
+                    str(entry.file_count),
+                    format_minutes(entry.finding_minutes),
+                    format_minutes(entry.complexity_minutes),
+                    format_minutes(entry.duplication_minutes),
+                    format_minutes(entry.total_minutes),
+                )
+        else:
+            for entry in file_debts[:limit]:
+                table.add_row(
+                    entry.relative_path,
+                    format_minutes(entry.finding_minutes),
+                    format_minutes(entry.complexity_minutes),
+                    format_minutes(entry.duplication_minutes),
+                    format_minutes(entry.total_minutes),
+                )
+
+        console.print(table)
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error estimating debt:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("buckets")
+def buckets_report(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+) -> None:
+    """Report metrics split into production, test, and fixture buckets.
+
+    Classifies every file by path convention and reports size and mean
+    complexity per bucket plus the test-to-code ratio, so averages are no
+    longer skewed by test fixtures.
+
+    Example:
+        insights buckets 19 --db /tmp/caldera.duckdb
+    """
+    from .code_classification import compute_bucket_metrics, compute_test_ratio
+    from .data_fetcher import DataFetcher
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        scc_rows = fetcher.fetch("scc_files", run_pk=run_pk)
+        if not scc_rows:
+            console.print("[yellow]No scc data for this run; nothing to report.[/yellow]")
+            return
+
+        buckets = compute_bucket_metrics(
+            scc_rows, complexity_rows=fetcher.fetch("lizard_files", run_pk=run_pk)
+        )
+        total_lines = sum(bucket.code_lines for bucket in buckets)
+
+        table = Table(title="Code Buckets")
+        table.add_column("Bucket", style="cyan")
+        table.add_column("Files", justify="right")
+        table.add_column("Code lines", justify="right")
+        table.add_column("Share", justify="right")
+        table.add_column("Mean CCN", justify="right")
+
+        for bucket in buckets:
+            share = f"{100 * bucket.code_lines / total_lines:.1f}%" if total_lines else "-"
+            table.add_row(
+                bucket.bucket,
+                str(bucket.file_count),
+                str(bucket.code_lines),
+                share,
+                "-" if bucket.mean_ccn is None else str(bucket.mean_ccn),
+            )
+
+        console.print(table)
+
+        ratio = compute_test_ratio(buckets)
+        if ratio is not None:
+            console.print(f"Test-to-code ratio: [bold]{ratio}[/bold] test lines per production line")
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error generating bucket report:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("function-coverage")
+def function_coverage_report(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    coverage: Path = typer.Option(..., "--coverage", help="Coverage report (lcov .info, Cobertura .xml, or llvm-cov .json)"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    repo_path: Path | None = typer.Option(None, "--repo-path", help="Repository root, used to relativize report paths"),
+    min_ccn: int = typer.Option(10, "--min-ccn", help="CCN threshold for the risk quadrant"),
+    max_coverage: float = typer.Option(50.0, "--max-coverage", help="Coverage threshold (%) for the risk quadrant"),
+    limit: int = typer.Option(25, "--limit", "-n", help="Maximum functions to show"),
+) -> None:
+    """Report high-CCN, low-coverage functions (the risk quadrant).
+
+    Parses the coverage report into line hits and joins them with lizard's
+    function ranges, surfacing complex functions the test suite barely
+    touches.
+
+    Example:
+        insights function-coverage 19 --db /tmp/caldera.duckdb --coverage coverage.info
+    """
+    from .data_fetcher import DataFetcher
+    from .function_coverage import join_function_coverage, parse_coverage, risk_quadrant
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if not coverage.exists():
+        console.print(f"[red]Error:[/red] Coverage report not found: {coverage}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        function_rows = fetcher.fetch("lizard_functions", run_pk=run_pk)
+        if not function_rows:
+            console.print("[yellow]No lizard data for this run; nothing to join.[/yellow]")
+            return
+
+        joined = join_function_coverage(function_rows, parse_coverage(coverage, repo_path))
+        risky = risk_quadrant(joined, min_ccn=min_ccn, max_coverage_pct=max_coverage)
+
+        if not risky:
+            console.print(
+                f"[green]No functions with CCN >= {min_ccn} below {max_coverage}% coverage "
+                f"({len(joined)} functions joined).[/green]"
+            )
+            return
+
+        table = Table(title=f"Risk Quadrant: CCN >= {min_ccn}, coverage < {max_coverage}%")
+        table.add_column("Function", style="cyan")
+        table.add_column("Location")
+        table.add_column("CCN", justify="right")
+        table.add_column("Coverage", justify="right")
+
+        for function in risky[:limit]:
+            table.add_row(
+                function.function_name,
+                f"{function.file}:{function.line_start}",
+                str(function.ccn),
+                f"{function.coverage_pct}%",
+            )
+
+        console.print(table)
+        console.print(f"[red]{len(risky)} functions in the risk quadrant[/red] of {len(joined)} joined.")
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error joining coverage:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("code-age")
+def code_age_report(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    repo_path: Path = typer.Option(..., "--repo-path", help="Path to the git repository to blame"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    min_ccn: int = typer.Option(10, "--min-ccn", help="Only blame files containing functions at this CCN"),
+    stale_days: int = typer.Option(365, "--stale-days", help="Untouched days before a function counts as stale"),
+    churn_days: int = typer.Option(30, "--churn-days", help="Window for the recently-churned section"),
+    limit: int = typer.Option(15, "--limit", "-n", help="Maximum functions per section"),
+) -> None:
+    """Report stale complex code and recently churned hotspots.
+
+    Blames the files holding complex functions and folds per-line ages
+    into lizard's function ranges, splitting the result into two
+    sections: old complex code nobody touches, and complex code churned
+    in the last weeks.
+
+    Example:
+        insights code-age 19 --db /tmp/caldera.duckdb --repo-path /path/to/repo
+    """
+    from .code_age import blame_line_ages, compute_function_ages, recently_churned, stale_complex
+    from .data_fetcher import DataFetcher
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if not repo_path.exists():
+        console.print(f"[red]Error:[/red] Repository not found: {repo_path}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        function_rows = fetcher.fetch("lizard_functions", run_pk=run_pk)
+        complex_files = sorted(
+            {row["file"] for row in function_rows if int(row.get("ccn") or 0) >= min_ccn}
+        )
+        if not complex_files:
+            console.print(f"[green]No functions with CCN >= {min_ccn}; nothing to blame.[/green]")
+            return
+
+        line_ages = {}
+        for file in complex_files:
+            try:
+                line_ages[file] = blame_line_ages(repo_path, file)
+            except ValueError:
+                continue  # deleted or untracked since the run was collected
+
+        ages = compute_function_ages(function_rows, line_ages)
+
+        def _section(title: str, entries, age_label: str) -> None:
+            table = Table(title=title)
+            table.add_column("Function", style="cyan")
+            table.add_column("Location")
+            table.add_column("CCN", justify="right")
+            table.add_column(age_label, justify="right")
+            for entry in entries[:limit]:
+                table.add_row(
+                    entry.function_name,
+                    f"{entry.file}:{entry.line_start}",
+                    str(entry.ccn),
+                    f"{entry.last_touched_days}d",
+                )
+            console.print(table)
+
+        _section(
+            f"Stale Complex Code (untouched >= {stale_days}d)",
+            stale_complex(ages, min_age_days=stale_days, min_ccn=min_ccn),
+            "Untouched",
+        )
+        _section(
+            f"Recently Churned Hotspots (touched <= {churn_days}d)",
+            recently_churned(ages, max_age_days=churn_days, min_ccn=min_ccn),
+            "Last touch",
+        )
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error computing code age:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("secret-history")
+def secret_history_report(
+    repo_path: Path = typer.Argument(..., help="Path to the git repository to scan"),
+    since: str = typer.Option(None, "--since", help="Limit the walk (any git log --since expression)"),
+    limit: int = typer.Option(30, "--limit", "-n", help="Maximum exposures to show"),
+) -> None:
+    """Report secret exposure history for incident response.
+
+    Walks reachable git history for secret introductions and reports when
+    each secret first landed, whether it is still present at HEAD, and how
+    many commits a history rewrite would touch. Secrets are shown masked.
+
+    Example:
+        insights secret-history /path/to/repo --since "90 days ago"
+    """
+    from .secret_history import scan_secret_history
+
+    if not repo_path.exists():
+        console.print(f"[red]Error:[/red] Repository not found: {repo_path}")
+        raise typer.Exit(1)
+
+    try:
+        exposures = scan_secret_history(repo_path, since=since)
+
+        if not exposures:
+            console.print("[green]No secrets found in reachable history.[/green]")
+            return
+
+        table = Table(title=f"Secret Exposure History ({len(exposures)} secrets)")
+        table.add_column("Severity")
+        table.add_column("Rule", style="cyan")
+        table.add_column("File", style="cyan")
+        table.add_column("Preview")
+        table.add_column("First seen")
+        table.add_column("At HEAD")
+        table.add_column("Rewrite", justify="right")
+
+        severity_styles = {"CRITICAL": "red bold", "HIGH": "red", "MEDIUM": "yellow"}
+        for exposure in exposures[:limit]:
+            table.add_row(
+                f"[{severity_styles.get(exposure.severity, 'white')}]{exposure.severity}[/]",
+                exposure.rule_id,
+                exposure.file_path,
+                exposure.secret_preview,
+                f"{exposure.first_commit[:8]} {exposure.first_commit_date[:10]}",
+                "[red]yes[/red]" if exposure.in_head else "no",
+                f"{exposure.rewrite_commit_count} commits",
+            )
+
+        console.print(table)
+        if any(exposure.in_head for exposure in exposures):
+            console.print(
+                "[red]Secrets still present at HEAD — rotate them first;"
+                " a history rewrite alone does not revoke access.[/red]"
+            )
+
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error scanning secret history:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("provenance")
+def provenance_attestation(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead for collection-level provenance)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    output: Path = typer.Option(..., "--output", "-o", help="Output file for the attestation JSON"),
+    sign_key: Path | None = typer.Option(None, "--sign-key", help="Key file; wraps the statement in a signed DSSE envelope"),
+    config: Path | None = typer.Option(None, "--config", help="caldera.toml to hash into the predicate (defaults to repo root)"),
+    tools_root: Path | None = typer.Option(None, "--tools-root", help="Tool checkout to digest (defaults to src/tools)"),
+) -> None:
+    """Write a provenance attestation for a collection run.
+
+    Records an in-toto statement with the repo commit, every tool run with
+    its version and source digest, the config hash, and the environment —
+    so downstream consumers can verify what produced a report. With
+    --sign-key the statement is signed (HMAC-SHA256, DSSE envelope).
+
+    Example:
+        insights provenance -c abc123... --db /tmp/caldera.duckdb -o provenance.json
+    """
+    import json
+
+    from .data_fetcher import DataFetcher
+    from .provenance import build_statement, sign_statement
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+    if tools_root is None:
+        tools_root = Path(__file__).resolve().parents[1] / "tools"
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        rows = fetcher.fetch("collection_tool_runs", run_pk=run_pk)
+        statement = build_statement(rows, config_path=config, tools_root=tools_root)
+
+        document = statement
+        if sign_key is not None:
+            if not sign_key.exists():
+                console.print(f"[red]Error:[/red] Key file not found: {sign_key}")
+                raise typer.Exit(1)
+            document = sign_statement(statement, sign_key.read_bytes())
+
+        output.parent.mkdir(parents=True, exist_ok=True)
+        output.write_text(json.dumps(document, indent=2, default=str))
+
+        tools = statement["predicate"]["tools"]
+        signed = " (signed)" if sign_key is not None else ""
+        console.print(
+            f"[green]Wrote provenance for {len(tools)} tool run(s) "
+            f"to {output}{signed}[/green]"
+        )
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error building provenance:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("sonarqube-export")
+def sonarqube_export(
+    run_pk: int = typer.Argument(..., help="Tool run primary key"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    output: Path = typer.Option(..., "--output", "-o", help="Output file path for the generic issue import JSON"),
+) -> None:
+    """Export Caldera findings in SonarQube's generic issue import format.
+
+    The resulting file can be fed to a SonarQube scan via
+    sonar.externalIssuesReportPaths, so Caldera results show up next to
+    SonarQube's own during a migration.
+
+    Example:
+        insights sonarqube-export 19 --db /tmp/caldera.duckdb -o caldera-issues.json
+    """
+    from .data_fetcher import DataFetcher
+    from .sonarqube_bridge import write_export
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        findings = fetcher.fetch("findings_export", run_pk=run_pk)
+        count = write_export(findings, output)
+        console.print(f"[green]Exported {count} issues to:[/green] {output}")
+    except Exception as e:
+        console.print(f"[red]Error exporting findings:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("sonarqube-compare")
+def sonarqube_compare(
+    run_pk: int = typer.Argument(..., help="Tool run primary key"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    sonarqube_export_path: Path = typer.Option(
+        ...,
+        "--sonarqube-export",
+        "-s",
+        help="Path to a SonarQube issue export (api/issues/search response)",
+    ),
+) -> None:
+    """Compare Caldera findings against a SonarQube issue export.
+
+    Findings are matched by file and start line; the summary shows overlap
+    and what each system found alone, so teams running both can reconcile.
+
+    Example:
+        insights sonarqube-compare 19 --db /tmp/caldera.duckdb -s sonar-issues.json
+    """
+    from .data_fetcher import DataFetcher
+    from .sonarqube_bridge import compare_findings, import_sonarqube_issues
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    if not sonarqube_export_path.exists():
+        console.print(f"[red]Error:[/red] SonarQube export not found: {sonarqube_export_path}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        caldera_findings = fetcher.fetch("findings_export", run_pk=run_pk)
+        sonarqube_findings = import_sonarqube_issues(sonarqube_export_path)
+        result = compare_findings(caldera_findings, sonarqube_findings)
+
+        table = Table(title="Caldera vs SonarQube")
+        table.add_column("Metric", style="cyan")
+        table.add_column("Count", justify="right")
+        table.add_row("Matched (same file and line)", str(result.matched))
+        table.add_row("Only in Caldera", str(result.only_caldera))
+        table.add_row("Only in SonarQube", str(result.only_sonarqube))
+        table.add_row("Total Caldera", str(result.total_caldera))
+        table.add_row("Total SonarQube", str(result.total_sonarqube))
+        console.print(table)
+
+    except Exception as e:
+        console.print(f"[red]Error comparing findings:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("functions")
+def query_functions(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead for collection-level queries)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    where: str | None = typer.Option(None, "--where", "-w", help='Filter expression, e.g. "ccn > 20 and file ~ src/"'),
+    sort: str = typer.Option("ccn", "--sort", "-s", help="Column to sort by (descending by default)"),
+    ascending: bool = typer.Option(False, "--asc", help="Sort ascending instead of descending"),
+    limit: int = typer.Option(30, "--limit", "-n", help="Maximum number of functions to show"),
+) -> None:
+    """Query per-function metrics with ad-hoc filters.
+
+    Filters and sorts the stored function records (name, file, CCN, NLOC,
+    params, nesting) beyond the fixed report views. The --where expression
+    joins clauses with 'and'; operators are <=, >=, !=, =, <, >, and ~
+    (substring match).
+
+    Example:
+        insights functions 19 --db /tmp/caldera.duckdb --where "ccn > 20" --sort nloc
+    """
+    from .data_fetcher import DataFetcher
+    from .function_query import build_functions_sql, parse_where
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        conditions = parse_where(where)
+        sql = build_functions_sql(
+            run_pk, conditions, sort=sort, descending=not ascending, limit=limit
+        )
+        results = fetcher.fetch_raw(sql)
+
+        table = Table(title="Functions")
+        table.add_column("File")
+        table.add_column("Function", style="cyan")
+        table.add_column("CCN", justify="right")
+        table.add_column("NLOC", justify="right")
+        table.add_column("Params", justify="right")
+        table.add_column("Nesting", justify="right")
+        table.add_column("Lines", justify="right")
+
+        for row in results:
+            line_start = row.get("line_start")
+            line_end = row.get("line_end")
+            lines = f"{line_start}-{line_end}" if line_start and line_end else ""
+            table.add_row(
+                row.get("file") or "",
+                row.get("name", ""),
+                str(row.get("ccn") if row.get("ccn") is not None else ""),
+                str(row.get("nloc") if row.get("nloc") is not None else ""),
+                str(row.get("params") if row.get("params") is not None else ""),
+                str(row.get("nesting") if row.get("nesting") is not None else ""),
+                lines,
+            )
+
+        console.print(table)
+        console.print(f"\n{len(results)} functions")
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error querying functions:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("top")
+def top_offenders_report(
+    run_pk: int | None = typer.Argument(None, help="Tool run primary key (use --collection-run-id instead for collection-level queries)"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    collection_run_id: str | None = typer.Option(
+        None,
+        "--collection-run-id",
+        "-c",
+        help="Collection run ID (auto-resolves to SCC tool's run_pk)",
+    ),
+    metric: str = typer.Option("ccn", "--metric", "-m", help="Metric to rank by: ccn, duplication, findings, churn"),
+    n: int = typer.Option(25, "--n", "-n", help="Number of offenders to show"),
+    repo_path: Path | None = typer.Option(None, "--repo-path", help="Repository path; enables code excerpts"),
+    report: Path | None = typer.Option(None, "--report", help="Path to a generated HTML report; prints an anchor link into it"),
+) -> None:
+    """Show the top-N offenders for a metric as a prioritized worklist.
+
+    Ranks the worst files/functions by the chosen metric. With --repo-path
+    the offending lines are shown inline; with --report a deep link into
+    the matching HTML report section is printed.
+
+    Example:
+        insights top 19 --db /tmp/caldera.duckdb --metric ccn --n 25
+    """
+    from .data_fetcher import DataFetcher
+    from .top_offenders import extract_excerpt, report_link, top_offenders
+
+    if run_pk is None and collection_run_id is None:
+        console.print("[red]Error:[/red] Must specify either run_pk argument or --collection-run-id option")
+        raise typer.Exit(1)
+
+    if run_pk is not None and collection_run_id is not None:
+        console.print("[red]Error:[/red] Cannot specify both run_pk and --collection-run-id")
+        raise typer.Exit(1)
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        if collection_run_id:
+            run_pk = fetcher.get_scc_run_pk_for_collection(collection_run_id)
+
+        offenders = top_offenders(fetcher, metric, run_pk, n=n)
+
+        table = Table(title=f"Top {len(offenders)} by {metric}")
+        table.add_column("#", justify="right")
+        table.add_column("Subject", style="cyan")
+        table.add_column("Value", justify="right")
+        table.add_column("Detail")
+
+        for rank, offender in enumerate(offenders, start=1):
+            value = f"{offender.value:g}"
+            table.add_row(str(rank), offender.subject, value, offender.detail)
+
+        console.print(table)
+
+        if repo_path is not None:
+            for rank, offender in enumerate(offenders, start=1):
+                if offender.path is None or offender.line_start is None:
+                    continue
+                excerpt = extract_excerpt(
+                    repo_path, offender.path, offender.line_start, offender.line_end
+                )
+                if not excerpt:
+                    continue
+                console.print(f"\n[cyan]{rank}. {offender.subject}[/cyan]")
+                for number, text in excerpt:
+                    console.print(f"  [dim]{number:>5} |[/dim] {text}")
+
+        if report is not None:
+            console.print(f"\n[dim]Report section: {report_link(report, metric)}[/dim]")
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error building top offenders:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("codeclimate-export")
+def codeclimate_export(
+    run_pk: int = typer.Argument(..., help="Tool run primary key"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    output: Path = typer.Option(..., "--output", "-o", help="Output file path for the Code Climate issues JSON"),
+) -> None:
+    """Export Caldera findings in the Code Climate issues format.
+
+    The resulting file is consumable by GitLab code quality widgets and
+    other dashboards in the Code Climate engine ecosystem.
+
+    Example:
+        insights codeclimate-export 19 --db /tmp/caldera.duckdb -o gl-code-quality-report.json
+    """
+    from .codeclimate import write_report
+    from .data_fetcher import DataFetcher
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        findings = fetcher.fetch("findings_export", run_pk=run_pk)
+        count = write_report(findings, output)
+        console.print(f"[green]Exported {count} issues to:[/green] {output}")
+    except Exception as e:
+        console.print(f"[red]Error exporting findings:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command()
+def sbom(
+    repo_path: Path = typer.Argument(..., help="Path to the repository to inventory"),
+    output: Path = typer.Option(Path("bom.json"), "--output", "-o", help="Output file path for the CycloneDX document"),
+) -> None:
+    """Generate a CycloneDX SBOM from the repository's lockfiles.
+
+    Parses Cargo.lock, package-lock.json, go.sum, and requirements.txt
+    into a component inventory that vulnerability adapters can
+    cross-reference.
+
+    Example:
+        insights sbom /path/to/repo -o bom.json
+    """
+    from .sbom import write_sbom
+
+    if not repo_path.is_dir():
+        console.print(f"[red]Error:[/red] Repository path not found: {repo_path}")
+        raise typer.Exit(1)
+
+    try:
+        count = write_sbom(repo_path, output)
+        console.print(f"[green]Wrote SBOM with {count} components to:[/green] {output}")
+    except Exception as e:
+        console.print(f"[red]Error generating SBOM:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("license-scan")
+def license_scan(
+    repo_path: Path = typer.Argument(..., help="Path to the repository to scan"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+) -> None:
+    """Detect licenses and check them against the license policy.
+
+    Reports SPDX identifiers found in file headers and dependency
+    licenses resolved from lockfiles. Exits non-zero when any license
+    matches the [license_policy] deny list.
+
+    Example:
+        insights license-scan /path/to/repo
+    """
+    from .license_scan import find_policy_violations, load_license_policy, scan_repository
+
+    if not repo_path.is_dir():
+        console.print(f"[red]Error:[/red] Repository path not found: {repo_path}")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        findings = scan_repository(repo_path)
+        policy = load_license_policy(config)
+        violations = find_policy_violations(findings, policy)
+        violating = set(violations)
+
+        table = Table(title="License Findings")
+        table.add_column("License", style="cyan")
+        table.add_column("Source")
+        table.add_column("Subject")
+        table.add_column("Policy")
+
+        for finding in findings:
+            verdict = "[red]DENIED[/red]" if finding in violating else "[green]OK[/green]"
+            table.add_row(finding.license, finding.source, finding.subject, verdict)
+
+        console.print(table)
+        console.print(f"\n{len(findings)} licenses detected, {len(violations)} policy violations")
+
+        if violations:
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except Exception as e:
+        console.print(f"[red]Error scanning licenses:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("explain-ignore")
+def explain_ignore(
+    path: str = typer.Argument(..., help="Repo-relative path to explain"),
+    repo_path: Path = typer.Option(Path("."), "--repo-path", help="Repository root containing .calderaignore"),
+    gitignore: bool = typer.Option(False, "--gitignore", help="Also honor the repository's .gitignore"),
+) -> None:
+    """Explain why a path is (or is not) ignored.
+
+    Evaluates .calderaignore (and optionally .gitignore) rules the same
+    way the layout adapter does before persisting, and reports which
+    pattern decided the path's fate.
+
+    Example:
+        insights explain-ignore src/generated/model.py --repo-path .
+    """
+    from common.ignore_rules import IgnoreRules
+
+    if not repo_path.is_dir():
+        console.print(f"[red]Error:[/red] Repository path not found: {repo_path}")
+        raise typer.Exit(1)
+
+    rules = IgnoreRules.load(repo_path, include_gitignore=gitignore)
+    if not rules:
+        console.print(f"[yellow]No ignore patterns found in {repo_path}[/yellow]")
+
+    explanation = rules.explain(path.strip("/"))
+    if ": ignored" in explanation:
+        console.print(f"[red]{explanation}[/red]")
+    else:
+        console.print(f"[green]{explanation}[/green]")
+
+
+@app.command("rules")
+def custom_rules(
+    rules_dir: Path = typer.Option(Path("rules"), "--rules-dir", help="Directory containing rules/*.yaml"),
+    repo_path: Path = typer.Option(Path("."), "--repo-path", help="Repository root to scan"),
+    fail_on_findings: bool = typer.Option(False, "--fail-on-findings", help="Exit non-zero when any rule matches"),
+) -> None:
+    """Run custom rules (rules/*.yaml) against a repository tree.
+
+    Supports regex, banned-api, required-header and (when tree-sitter is
+    installed) structural rules. Honors .calderaignore like the rest of
+    the pipeline.
+
+    Example:
+        insights rules --rules-dir rules --repo-path . --fail-on-findings
+    """
+    from common.rules_engine import TREESITTER_AVAILABLE, load_rules, scan_tree
+
+    try:
+        if not repo_path.is_dir():
+            console.print(f"[red]Error:[/red] Repository path not found: {repo_path}")
+            raise typer.Exit(1)
+
+        rules = load_rules(rules_dir)
+        if not rules:
+            console.print(f"[yellow]No rules found in {rules_dir}[/yellow]")
+            return
+        if any(rule.kind == "structural" for rule in rules) and not TREESITTER_AVAILABLE:
+            console.print("[yellow]tree-sitter not installed; structural rules skipped[/yellow]")
+
+        findings = scan_tree(repo_path, rules)
+
+        table = Table(title=f"Custom Rule Findings ({len(rules)} rules)")
+        table.add_column("Rule", style="cyan")
+        table.add_column("Severity")
+        table.add_column("Location", style="magenta")
+        table.add_column("Message")
+        for finding in findings:
+            table.add_row(
+                finding.rule_id,
+                finding.severity,
+                f"{finding.path}:{finding.line}",
+                finding.message,
+            )
+        console.print(table)
+        console.print(f"\n{len(findings)} findings")
+
+        if findings and fail_on_findings:
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error running custom rules:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-score")
+def eval_score(
+    outputs: Path = typer.Option(..., "--outputs", help="Directory of <tool>.json envelopes from clean-corpus runs"),
+    corpus: Path | None = typer.Option(None, "--corpus", help="Clean corpus directory (defaults to src/shared/eval-corpus/clean)"),
+    output_json: Path | None = typer.Option(None, "--output", "-o", help="Write the full noise report as JSON"),
+) -> None:
+    """Score tool noise (false positives) against the clean eval corpus.
+
+    Every finding on the clean corpus is a false positive; the noise
+    score is findings per KLOC, reported per adapter and per rule so
+    noisy rules can be pruned with data.
+
+    Example:
+        insights eval-score --outputs /tmp/clean-runs -o noise-report.json
+    """
+    import json
+
+    from shared.evaluation.noise import CLEAN_CORPUS_DIR, noise_report, score_outputs
+
+    try:
+        if not outputs.is_dir():
+            console.print(f"[red]Error:[/red] Outputs directory not found: {outputs}")
+            raise typer.Exit(1)
+
+        corpus_dir = corpus or CLEAN_CORPUS_DIR
+        scores = score_outputs(outputs, corpus_dir)
+        if not scores:
+            console.print(f"[yellow]No tool outputs found in {outputs}[/yellow]")
+            return
+
+        table = Table(title="Noise Score per Adapter (clean corpus)")
+        table.add_column("Tool", style="cyan")
+        table.add_column("False positives", justify="right")
+        table.add_column("Noise / KLOC", justify="right", style="magenta")
+        for score in scores:
+            table.add_row(score.tool, str(score.findings), f"{score.noise_per_kloc:.2f}")
+        console.print(table)
+
+        report = noise_report(scores)
+        if report["noisiest_rules"]:
+            rule_table = Table(title="Noisiest Rules")
+            rule_table.add_column("Tool", style="cyan")
+            rule_table.add_column("Rule")
+            rule_table.add_column("False positives", justify="right")
+            for entry in report["noisiest_rules"][:10]:
+                rule_table.add_row(entry["tool"], entry["rule"], str(entry["findings"]))
+            console.print(rule_table)
+
+        if output_json:
+            output_json.parent.mkdir(parents=True, exist_ok=True)
+            output_json.write_text(json.dumps(report, indent=2))
+            console.print(f"[green]Noise report written to:[/green] {output_json}")
+
+    except typer.Exit:
+        raise
+    except Exception as e:
+        console.print(f"[red]Error scoring noise:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-mutate")
+def eval_mutate(
+    repo_path: Path = typer.Argument(..., help="Target repository to seed clones into"),
+    inject_clones: int = typer.Option(50, "--inject-clones", help="Number of clones to seed"),
+    types: str = typer.Option("1,2,3", "--type", help="Comma-separated clone types (1=exact, 2=renamed, 3=near-miss)"),
+    seed: int = typer.Option(0, "--seed", help="RNG seed for deterministic injection"),
+    ground_truth: Path | None = typer.Option(None, "--ground-truth", help="Ground truth output path (defaults to <repo>/clone_ground_truth.json)"),
+) -> None:
+    """Seed mutated function clones into a repo for clone-detector evals.
+
+    Copies functions from the target repo, mutates them per clone type,
+    writes them back as sibling files, and records ground truth so
+    detector output can be scored against known clones.
+
+    Example:
+        insights eval-mutate /tmp/target-repo --inject-clones 50 --type 1,2,3
+    """
+    from shared.evaluation.mutate import inject_clones as run_inject
+    from shared.evaluation.mutate import write_ground_truth
+
+    try:
+        if not repo_path.is_dir():
+            console.print(f"[red]Error:[/red] Repository path not found: {repo_path}")
+            raise typer.Exit(1)
+
+        clone_types = tuple(int(part) for part in types.split(",") if part.strip())
+        records = run_inject(repo_path, count=inject_clones, clone_types=clone_types, seed=seed)
+        output_path = write_ground_truth(repo_path, records, seed=seed, output_path=ground_truth)
+
+        by_type: dict[int, int] = {}
+        for record in records:
+            by_type[record.clone_type] = by_type.get(record.clone_type, 0) + 1
+        summary = ", ".join(f"type {t}: {n}" for t, n in sorted(by_type.items()))
+        console.print(f"[green]Seeded {len(records)} clones[/green] ({summary})")
+        console.print(f"Ground truth written to: {output_path}")
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error seeding clones:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-inject")
+def eval_inject(
+    repo_path: Path = typer.Argument(..., help="Target repository to inject vulnerabilities into"),
+    vulns: int = typer.Option(20, "--vulns", help="Number of vulnerabilities to inject"),
+    types: str = typer.Option("sqli,cmdi,traversal", "--type", help="Comma-separated vulnerability types"),
+    seed: int = typer.Option(0, "--seed", help="RNG seed for deterministic injection"),
+    manifest: Path | None = typer.Option(None, "--manifest", help="Manifest output path (defaults to <repo>/vuln_ground_truth.json)"),
+) -> None:
+    """Inject synthetic vulnerabilities into donor code for SAST evals.
+
+    Appends parameterized SQLi / command injection / path traversal
+    patterns to donor files and writes a ground-truth manifest with the
+    exact sink lines, so semgrep rule coverage can be measured at scale.
+
+    Example:
+        insights eval-inject /tmp/target-repo --vulns 20 --type sqli,cmdi
+    """
+    from shared.evaluation.vuln_inject import inject_vulns, write_manifest
+
+    try:
+        if not repo_path.is_dir():
+            console.print(f"[red]Error:[/red] Repository path not found: {repo_path}")
+            raise typer.Exit(1)
+
+        vuln_types = tuple(part.strip() for part in types.split(",") if part.strip())
+        records = inject_vulns(repo_path, count=vulns, vuln_types=vuln_types, seed=seed)
+        output_path = write_manifest(repo_path, records, seed=seed, output_path=manifest)
+
+        by_type: dict[str, int] = {}
+        for record in records:
+            by_type[record.vuln_type] = by_type.get(record.vuln_type, 0) + 1
+        summary = ", ".join(f"{t}: {n}" for t, n in sorted(by_type.items()))
+        console.print(f"[green]Injected {len(records)} vulnerabilities[/green] ({summary})")
+        console.print(f"Manifest written to: {output_path}")
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error injecting vulnerabilities:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-determinism")
+def eval_determinism(
+    runs: int = typer.Option(5, "--runs", help="Number of repeated runs per tool"),
+    tools: str | None = typer.Option(None, "--tools", help="Comma-separated tool names (defaults to all tools with a Makefile)"),
+    tools_dir: Path = typer.Option(Path("src/tools"), "--tools-dir", help="Directory containing the tools"),
+    output_json: Path | None = typer.Option(None, "--output", "-o", help="Write the full determinism report as JSON"),
+) -> None:
+    """Check tools for nondeterministic output across repeated runs.
+
+    Executes each tool's `make analyze` repeatedly and diffs canonicalized
+    outputs, distinguishing order instability from real nondeterminism
+    and naming the flaky rules.
+
+    Example:
+        insights eval-determinism --runs 5 --tools pmd-cpd,semgrep
+    """
+    import json
+
+    from shared.evaluation.determinism import make_tool_runner, run_determinism
+
+    try:
+        if tools:
+            tool_names = [part.strip() for part in tools.split(",") if part.strip()]
+        else:
+            tool_names = sorted(
+                entry.name for entry in tools_dir.iterdir()
+                if entry.is_dir() and (entry / "Makefile").exists()
+            )
+        if not tool_names:
+            console.print(f"[yellow]No tools found in {tools_dir}[/yellow]")
+            return
+
+        runner = make_tool_runner(tools_dir, logger=lambda msg: console.print(f"[dim]{msg}[/dim]"))
+        results = run_determinism(tool_names, runs, runner)
+
+        table = Table(title=f"Determinism Check ({runs} runs)")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Verdict")
+        table.add_column("Unique outputs", justify="right")
+        table.add_column("Flaky rules")
+        for result in results:
+            if result.error:
+                verdict = "[red]error[/red]"
+            elif result.deterministic and not result.order_unstable:
+                verdict = "[green]deterministic[/green]"
+            elif result.order_unstable:
+                verdict = "[yellow]order-unstable[/yellow]"
+            else:
+                verdict = "[red]nondeterministic[/red]"
+            table.add_row(
+                result.tool,
+                verdict,
+                str(result.unique_outputs),
+                ", ".join(result.flaky_rules) or "-",
+            )
+        console.print(table)
+
+        if output_json:
+            output_json.parent.mkdir(parents=True, exist_ok=True)
+            output_json.write_text(json.dumps([r.to_dict() for r in results], indent=2))
+            console.print(f"[green]Report written to:[/green] {output_json}")
+
+        if any(not result.deterministic for result in results):
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error checking determinism:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-profile")
+def eval_profile(
+    tool: str = typer.Option(..., "--tool", help="Tool name for the report"),
+    command: str = typer.Option(..., "--command", help="Command template; {repo} is replaced per eval repo"),
+    eval_repos: list[Path] = typer.Option(..., "--eval-repo", help="Eval repo path (repeatable)"),
+    timeout: float = typer.Option(1800.0, "--timeout", help="Per-run timeout in seconds"),
+    output_json: Path | None = typer.Option(None, "--output", "-o", help="Write profiles as JSON"),
+) -> None:
+    """Profile a tool's resource usage across eval repos.
+
+    Measures wall time, CPU time, and peak RSS per run via rusage so
+    tool selection can weigh accuracy against cost.
+
+    Example:
+        insights eval-profile --tool scc \\
+            --command "make -C src/tools/scc analyze EVAL_REPO={repo}" \\
+            --eval-repo src/tools/scc/eval-repos/synthetic
+    """
+    import json
+
+    from shared.evaluation.profiling import profile_eval_repos
+
+    try:
+        profiles = profile_eval_repos(tool, command, eval_repos, timeout=timeout)
+
+        table = Table(title=f"Resource Profile: {tool}")
+        table.add_column("Eval repo", style="cyan")
+        table.add_column("Wall (s)", justify="right")
+        table.add_column("CPU (s)", justify="right")
+        table.add_column("Peak RSS (MB)", justify="right", style="magenta")
+        table.add_column("Exit", justify="right")
+        for profile in profiles:
+            table.add_row(
+                profile.eval_repo,
+                f"{profile.wall_seconds:.2f}",
+                f"{profile.cpu_seconds:.2f}",
+                f"{profile.peak_rss_mb:.1f}",
+                str(profile.exit_code),
+            )
+        console.print(table)
+
+        if output_json:
+            output_json.parent.mkdir(parents=True, exist_ok=True)
+            output_json.write_text(json.dumps([p.to_dict() for p in profiles], indent=2))
+            console.print(f"[green]Profiles written to:[/green] {output_json}")
+
+        if any(profile.exit_code != 0 for profile in profiles):
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except Exception as e:
+        console.print(f"[red]Error profiling tool:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-record")
+def eval_record(
+    scorecard: Path = typer.Argument(..., help="Path to a tool's scorecard.json"),
+    db: Path = typer.Option(Path("eval-history.sqlite"), "--db", "-d", help="Eval history database"),
+    tool_version: str = typer.Option("unknown", "--tool-version", help="Tool version that produced the scorecard"),
+    corpus_revision: str = typer.Option("unknown", "--corpus-revision", help="Eval corpus git revision"),
+    wall_seconds: float | None = typer.Option(None, "--wall-seconds", help="Eval wall time in seconds"),
+) -> None:
+    """Record an eval scorecard into the history database.
+
+    Example:
+        insights eval-record src/tools/semgrep/evaluation/scorecard.json \\
+            --tool-version 1.70 --corpus-revision $(git rev-parse --short HEAD)
+    """
+    from shared.evaluation.history import EvalHistory
+
+    try:
+        if not scorecard.exists():
+            console.print(f"[red]Error:[/red] Scorecard not found: {scorecard}")
+            raise typer.Exit(1)
+
+        history = EvalHistory(db)
+        run = history.record_scorecard(
+            scorecard,
+            tool_version=tool_version,
+            corpus_revision=corpus_revision,
+            wall_seconds=wall_seconds,
+        )
+        console.print(
+            f"[green]Recorded[/green] {run.tool} score {run.score:.4f} "
+            f"({run.decision}) at {run.recorded_at}"
+        )
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error recording eval run:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-trend")
+def eval_trend(
+    tool: str | None = typer.Argument(None, help="Tool to chart (defaults to all recorded tools)"),
+    db: Path = typer.Option(Path("eval-history.sqlite"), "--db", "-d", help="Eval history database"),
+    limit: int = typer.Option(20, "--limit", "-n", help="Number of runs to chart per tool"),
+) -> None:
+    """Chart eval score trends over time per tool.
+
+    Shows a sparkline of recent scores and flags tools whose latest
+    score regressed against the previous run.
+
+    Example:
+        insights eval-trend semgrep --db eval-history.sqlite
+    """
+    from shared.evaluation.history import EvalHistory, sparkline
+
+    try:
+        if not db.exists():
+            console.print(f"[red]Error:[/red] History database not found: {db}")
+            raise typer.Exit(1)
+
+        history = EvalHistory(db)
+        tool_names = [tool] if tool else history.tools()
+        if not tool_names:
+            console.print("[yellow]No eval runs recorded yet[/yellow]")
+            return
+
+        table = Table(title="Eval Score Trends")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Runs", justify="right")
+        table.add_column("Trend")
+        table.add_column("Latest", justify="right")
+        table.add_column("Version")
+        for name in tool_names:
+            runs = history.trend(name, limit=limit)
+            if not runs:
+                console.print(f"[yellow]No runs recorded for {name}[/yellow]")
+                continue
+            latest = runs[-1]
+            table.add_row(
+                name,
+                str(len(runs)),
+                sparkline([run.score for run in runs]),
+                f"{latest.score:.4f}",
+                latest.tool_version,
+            )
+        console.print(table)
+
+        regressions = history.regressions()
+        for regression in regressions:
+            console.print(
+                f"[red]Regression:[/red] {regression['tool']} dropped "
+                f"{regression['drop']:.4f} "
+                f"({regression['previous_score']:.4f} -> {regression['latest_score']:.4f}, "
+                f"{regression['previous_version']} -> {regression['latest_version']})"
+            )
+        if regressions:
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except Exception as e:
+        console.print(f"[red]Error charting trends:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-tune")
+def eval_tune(
+    scored_runs: Path = typer.Argument(..., help="Scored-run JSON file or directory"),
+    fp_budget: int = typer.Option(10, "--fp-budget", "-b", help="Maximum false positives allowed"),
+    output: Path | None = typer.Option(None, "--output", "-o", help="Write full tuning results JSON"),
+) -> None:
+    """Recommend tool thresholds that maximize F1 under an FP budget.
+
+    Sweeps the threshold space of tunable parameters (lizard CCN cutoff,
+    pmd-cpd minimum tokens, semgrep severity filter) against labeled eval
+    findings and prints a ready-to-use config snippet.
+
+    Example:
+        insights eval-tune scored-runs/ --fp-budget 5
+    """
+    import json
+
+    from shared.evaluation.tuning import config_snippet, load_scored_runs, tune
+
+    try:
+        if not scored_runs.exists():
+            console.print(f"[red]Error:[/red] Scored runs not found: {scored_runs}")
+            raise typer.Exit(1)
+
+        results = tune(load_scored_runs(scored_runs), fp_budget=fp_budget)
+
+        table = Table(title=f"Threshold Tuning (fp_budget={fp_budget})")
+        table.add_column("Parameter", style="cyan")
+        table.add_column("Recommended", justify="right")
+        table.add_column("F1", justify="right")
+        table.add_column("Precision", justify="right")
+        table.add_column("Recall", justify="right")
+        table.add_column("FP", justify="right")
+        for result in results:
+            recommended = result.recommended
+            if recommended is None:
+                table.add_row(result.spec.parameter, "-", "-", "-", "-", "-")
+                continue
+            fp_cell = str(recommended.false_positives)
+            if not result.within_budget:
+                fp_cell = f"[red]{fp_cell}[/red]"
+            table.add_row(
+                result.spec.parameter,
+                str(recommended.value),
+                f"{recommended.f1:.2f}",
+                f"{recommended.precision:.2f}",
+                f"{recommended.recall:.2f}",
+                fp_cell,
+            )
+        console.print(table)
+        console.print(config_snippet(results, fp_budget))
+
+        if output:
+            output.write_text(json.dumps([result.to_dict() for result in results], indent=2))
+            console.print(f"[green]Tuning results written to {output}[/green]")
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error tuning thresholds:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-clone-bench")
+def eval_clone_bench(
+    dataset: Path = typer.Argument(..., help="Benchmark dataset directory (pairs CSV + sources)"),
+    output: Path = typer.Argument(..., help="Directory to materialize the scorable eval repo"),
+    pairs_csv: Path | None = typer.Option(None, "--pairs-csv", help="Pairs CSV (defaults to clone_pairs.csv in dataset)"),
+    limit: int | None = typer.Option(None, "--limit", "-n", help="Cap imported pairs"),
+    detected: Path | None = typer.Option(None, "--detected", help="Detector pairs JSON to score recall against"),
+) -> None:
+    """Import a public clone benchmark and optionally score recall.
+
+    Materializes BigCloneBench-style or generic pair datasets as an eval
+    repo with clone_ground_truth.json, in the same format eval-mutate
+    writes. With --detected, scores the detector's recall per clone type.
+
+    Example:
+        insights eval-clone-bench ~/bcb-reduced /tmp/bcb-repo --detected pairs.json
+    """
+    import json
+
+    from shared.evaluation.clone_bench import import_benchmark, recall_by_type
+
+    try:
+        if not dataset.exists():
+            console.print(f"[red]Error:[/red] Dataset not found: {dataset}")
+            raise typer.Exit(1)
+
+        records, stats = import_benchmark(dataset, output, pairs_csv=pairs_csv, limit=limit)
+        console.print(
+            f"[green]Imported {stats.pairs_imported} pairs[/green] "
+            f"({stats.pairs_skipped} skipped, {stats.functions_copied} files) to {output}"
+        )
+
+        if detected is not None:
+            report = recall_by_type(records, json.loads(detected.read_text()))
+            table = Table(title="Benchmark Recall")
+            table.add_column("Clone Type", style="cyan")
+            table.add_column("Expected", justify="right")
+            table.add_column("Found", justify="right")
+            table.add_column("Recall", justify="right")
+            for clone_type, row in report["by_type"].items():
+                table.add_row(
+                    clone_type,
+                    str(row["expected"]),
+                    str(row["found"]),
+                    f"{row['recall']:.2%}",
+                )
+            overall = report["overall"]
+            table.add_row(
+                "overall",
+                str(overall["expected"]),
+                str(overall["found"]),
+                f"{overall['recall']:.2%}",
+            )
+            console.print(table)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error importing benchmark:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("eval-stress")
+def eval_stress(
+    output: Path = typer.Argument(..., help="Directory to generate the stress repo in"),
+    preset: str = typer.Option("smoke", "--preset", "-p", help="Generation preset: smoke or full"),
+    file_count: int | None = typer.Option(None, "--files", help="Override small-file count"),
+    max_depth: int | None = typer.Option(None, "--depth", help="Override directory nesting depth"),
+    command: str | None = typer.Option(None, "--command", "-c", help="Tool command to profile; {repo} expands to the stress repo"),
+    tool: str = typer.Option("tool", "--tool", help="Tool name for the report"),
+    max_wall: float = typer.Option(600.0, "--max-wall", help="Wall-clock budget in seconds"),
+    max_rss: float = typer.Option(4096.0, "--max-rss", help="Peak RSS budget in MB"),
+) -> None:
+    """Generate a large-repo stress corpus and optionally gate a tool on it.
+
+    Pass/fail is judged on runtime and peak memory, not accuracy — the
+    corpus has 100k+ files (full preset), deep directory chains, a
+    multi-MB single file, and pathological nesting.
+
+    Example:
+        insights eval-stress /tmp/stress --preset full \\
+            --command "make -C src/tools/scc analyze REPO={repo}" --max-wall 300
+    """
+    import json
+
+    from shared.evaluation.stress import (
+        StressCriteria,
+        StressSpec,
+        generate_stress_repo,
+        run_stress,
+    )
+
+    try:
+        spec = StressSpec.from_preset(preset)
+        overrides = {}
+        if file_count is not None:
+            overrides["file_count"] = file_count
+        if max_depth is not None:
+            overrides["max_depth"] = max_depth
+        if overrides:
+            spec = StressSpec(**{**spec.__dict__, **overrides})
+
+        console.print(f"Generating stress repo ({spec.file_count} files) in {output}...")
+        manifest = generate_stress_repo(output, spec)
+        (output / "stress_manifest.json").write_text(json.dumps(manifest, indent=2))
+        console.print(f"[green]Generated {manifest['total_files']} files[/green]")
+
+        if command is None:
+            return
+
+        result = run_stress(tool, command, output, StressCriteria(max_wall, max_rss))
+        profile = result["profile"]
+        table = Table(title=f"Stress Run: {tool}")
+        table.add_column("Check", style="cyan")
+        table.add_column("Measured", justify="right")
+        table.add_column("Budget", justify="right")
+        table.add_column("Result")
+        checks = result["checks"]
+        table.add_row(
+            "wall seconds",
+            f"{profile['wall_seconds']:.1f}",
+            f"{max_wall:.1f}",
+            "[green]PASS[/green]" if checks["wall_within_budget"] else "[red]FAIL[/red]",
+        )
+        table.add_row(
+            "peak RSS MB",
+            f"{profile['peak_rss_mb']:.0f}",
+            f"{max_rss:.0f}",
+            "[green]PASS[/green]" if checks["rss_within_budget"] else "[red]FAIL[/red]",
+        )
+        table.add_row(
+            "exit code",
+            str(profile["exit_code"]),
+            "0",
+            "[green]PASS[/green]" if checks["exit_ok"] else "[red]FAIL[/red]",
+        )
+        console.print(table)
+        if not result["passed"]:
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error running stress eval:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("notify-findings")
+def notify_findings(
+    current: Path = typer.Argument(..., help="Current findings JSON (list of finding objects)"),
+    baseline: Path = typer.Option(..., "--baseline", "-b", help="Baseline findings JSON to diff against"),
+    repo_id: str = typer.Option(..., "--repo-id", help="Repository identifier for the notification"),
+    run_ref: str = typer.Option("", "--run-ref", help="Run reference shown in the notification"),
+    min_severity: str = typer.Option("HIGH", "--min-severity", help="Lowest severity that counts as new"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    dry_run: bool = typer.Option(False, "--dry-run", help="Print the payload instead of POSTing"),
+) -> None:
+    """Notify webhooks about findings introduced relative to a baseline.
+
+    Diffs two findings JSON files (objects with tool, rule_id,
+    relative_path, severity, message) by rule and location, and POSTs the
+    new findings at or above --min-severity to the webhooks configured
+    under [[notifications.webhooks]]. Exits non-zero when new findings
+    exist, so this can gate CI alongside `insights gates`.
+
+    Example:
+        insights notify-findings current.json -b baseline.json --repo-id my-app
+    """
+    import json as json_module
+
+    from .notifications import (
+        build_new_findings_event,
+        diff_new_findings,
+        load_notification_config,
+    )
+    from .notifications import notify as deliver_notifications
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        current_findings = json_module.loads(current.read_text())
+        baseline_findings = json_module.loads(baseline.read_text())
+        new_findings = diff_new_findings(
+            current_findings, baseline_findings, min_severity=min_severity.upper()
+        )
+        if not new_findings:
+            console.print("[green]No new findings at or above[/green] " + min_severity.upper())
+            return
+
+        event = build_new_findings_event(repo_id, run_ref or current.stem, new_findings)
+
+        table = Table(title=f"New findings vs baseline ({len(new_findings)})")
+        table.add_column("Severity", style="red")
+        table.add_column("Tool", style="cyan")
+        table.add_column("Rule")
+        table.add_column("Path")
+        for finding in new_findings:
+            table.add_row(
+                str(finding.get("severity")),
+                str(finding.get("tool")),
+                str(finding.get("rule_id")),
+                str(finding.get("relative_path")),
+            )
+        console.print(table)
+
+        if dry_run:
+            console.print_json(json_module.dumps(event))
+        else:
+            webhooks = load_notification_config(config)
+            if not webhooks:
+                console.print("[yellow]No [[notifications.webhooks]] configured; nothing sent[/yellow]")
+            for delivery in deliver_notifications([event], webhooks):
+                status = "[green]sent[/green]" if delivery.ok else "[red]failed[/red]"
+                console.print(f"  webhook {delivery.url}: {status} ({delivery.detail})")
+        raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error notifying findings:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("jira-sync")
+def jira_sync(
+    findings_file: Path = typer.Argument(..., help="Findings JSON (list of finding objects)"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    dry_run: bool = typer.Option(False, "--dry-run", help="Show the sync plan without touching Jira"),
+) -> None:
+    """Sync findings to Jira, one ticket per fingerprint.
+
+    New findings at or above the [jira] min_severity open tickets,
+    recurring ones get a recurrence comment, and tickets whose finding
+    disappeared are transitioned to done. Requires [jira] in caldera.toml
+    and JIRA_USER/JIRA_TOKEN in the environment (except with --dry-run,
+    which still needs Jira to list open tickets only when they exist —
+    a dry run plans against an empty ticket set if credentials are absent).
+
+    Example:
+        insights jira-sync findings.json --dry-run
+    """
+    import json as json_module
+
+    from .jira_sync import (
+        JiraClient,
+        JiraSyncError,
+        apply_plan,
+        load_jira_config,
+        plan_sync,
+        select_findings,
+    )
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        jira_config = load_jira_config(config)
+        if jira_config is None:
+            console.print(f"[red]Error:[/red] No [jira] section in {config}")
+            raise typer.Exit(1)
+
+        findings = select_findings(
+            json_module.loads(findings_file.read_text()), jira_config
+        )
+
+        client = None
+        open_issues: dict[str, str] = {}
+        try:
+            client = JiraClient(jira_config)
+            open_issues = client.open_caldera_issues()
+        except JiraSyncError:
+            if not dry_run:
+                raise
+
+        plan = plan_sync(findings, open_issues)
+
+        table = Table(title="Jira sync plan")
+        table.add_column("Action", style="cyan")
+        table.add_column("Count", justify="right")
+        table.add_column("Detail")
+        table.add_row("create", str(len(plan.create)), ", ".join(
+            f"{f.get('tool')}/{f.get('rule_id')}" for f in plan.create[:5]))
+        table.add_row("update", str(len(plan.update)), ", ".join(
+            key for key, _ in plan.update[:5]))
+        table.add_row("close", str(len(plan.close)), ", ".join(plan.close[:5]))
+        console.print(table)
+
+        if dry_run:
+            console.print("[yellow]Dry run: nothing sent to Jira[/yellow]")
+            return
+
+        summary = apply_plan(plan, client)
+        console.print(
+            f"[green]Synced:[/green] {len(summary['created'])} created, "
+            f"{len(summary['updated'])} updated, {len(summary['closed'])} closed"
+        )
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error syncing to Jira:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("upload")
+def upload_artifacts_command(
+    files: list[Path] = typer.Argument(..., help="Artifact files to upload (reports, SARIF, run JSON)"),
+    store: str = typer.Option(..., "--store", "-s", help="Store URL: s3://bucket/prefix, gs://bucket/prefix, or a directory"),
+    repo_id: str = typer.Option(..., "--repo-id", help="Repository identifier used in object keys"),
+    retention: str = typer.Option("90d", "--retention", help="Retention tag for bucket lifecycle rules"),
+    manifest: Path | None = typer.Option(None, "--manifest", help="Write the upload manifest JSON here"),
+) -> None:
+    """Upload run artifacts to object storage with content-addressed keys.
+
+    Keys embed the file's sha256 so identical artifacts dedupe and
+    re-uploads are idempotent; every object carries a caldera-retention
+    tag that bucket lifecycle rules can expire on.
+
+    Example:
+        insights upload report.html findings.sarif -s s3://caldera-artifacts/runs --repo-id my-app
+    """
+    import json as json_module
+
+    from common.artifact_store import ArtifactStoreError, upload_artifacts
+
+    try:
+        result = upload_artifacts(store, files, repo_id=repo_id, retention=retention)
+
+        table = Table(title=f"Uploaded {len(result['artifacts'])} artifacts to {store}")
+        table.add_column("File", style="cyan")
+        table.add_column("URI")
+        table.add_column("Size", justify="right")
+        for artifact in result["artifacts"]:
+            table.add_row(
+                artifact["file_name"], artifact["uri"], f"{artifact['size_bytes']:,}"
+            )
+        console.print(table)
+
+        if manifest is not None:
+            manifest.write_text(json_module.dumps(result, indent=2))
+            console.print(f"Manifest: {manifest}")
+
+    except typer.Exit:
+        raise
+    except ArtifactStoreError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error uploading artifacts:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("github-check")
+def github_check(
+    findings_file: Path = typer.Argument(..., help="Findings JSON (list of finding objects)"),
+    repo: str = typer.Option(..., "--repo", help="GitHub repository as owner/name"),
+    sha: str = typer.Option(..., "--sha", help="Head commit SHA the check run attaches to"),
+    run_pk: int | None = typer.Option(None, "--run-pk", help="Tool run primary key for gate evaluation"),
+    db: Path | None = typer.Option(None, "--db", "-d", help="Path to DuckDB database (required with --run-pk)"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    name: str = typer.Option("caldera", "--name", help="Check run name shown on the PR"),
+    dry_run: bool = typer.Option(False, "--dry-run", help="Show what would be published without calling GitHub"),
+) -> None:
+    """Publish findings as a GitHub check run with inline annotations.
+
+    Annotations appear in the Files Changed view, worst severity first and
+    capped so a noisy run cannot flood the UI. With --run-pk the gate
+    engine sets the conclusion (failure on any failing gate); without it
+    the conclusion is neutral. Requires GITHUB_TOKEN with checks:write.
+
+    Example:
+        insights github-check findings.json --repo acme/app --sha $GITHUB_SHA --run-pk 19 --db /tmp/caldera.duckdb
+    """
+    import json as json_module
+
+    from .github_checks import (
+        ChecksClient,
+        CheckRunError,
+        build_annotations,
+        build_summary,
+        conclusion_from_gates,
+        publish_check_run,
+    )
+
+    if run_pk is not None and db is None:
+        console.print("[red]Error:[/red] --run-pk requires --db")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        findings = json_module.loads(findings_file.read_text())
+
+        gate_results = []
+        if run_pk is not None:
+            from .data_fetcher import DataFetcher
+            from .gates import load_gates_config, run_gates
+
+            fetcher = DataFetcher(db_path=db)
+            vulnerabilities = fetcher.fetch("fixable_vulnerabilities", run_pk=run_pk)
+            gate_results = run_gates(vulnerabilities, load_gates_config(config))
+
+        if dry_run:
+            annotations, truncated = build_annotations(findings)
+            conclusion = conclusion_from_gates(gate_results)
+            console.print(build_summary(gate_results, findings, truncated))
+            console.print(
+                f"[yellow]Dry run:[/yellow] would publish {len(annotations)} annotation(s) "
+                f"({truncated} truncated) with conclusion [bold]{conclusion}[/bold]"
+            )
+            return
+
+        report = publish_check_run(
+            ChecksClient(repo), sha, findings, gate_results, name=name
+        )
+        console.print(
+            f"[green]Check run {report.check_run_id}:[/green] {report.annotation_count} "
+            f"annotation(s), conclusion {report.conclusion}"
+            + (f" ({report.truncated} truncated)" if report.truncated else "")
+        )
+        if report.conclusion == "failure":
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except CheckRunError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error publishing check run:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("azure-annotations")
+def azure_annotations(
+    findings_file: Path = typer.Argument(..., help="Findings JSON (list of finding objects)"),
+    run_pk: int | None = typer.Option(None, "--run-pk", help="Tool run primary key for gate evaluation"),
+    db: Path | None = typer.Option(None, "--db", "-d", help="Path to DuckDB database (required with --run-pk)"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+) -> None:
+    """Emit findings as Azure DevOps logging commands.
+
+    Prints ##vso[task.logissue] commands that Azure Pipelines turns into
+    build annotations, plus a task.complete command set from the gate
+    engine. Run this as a script step in the pipeline — no API
+    credentials needed.
+
+    Example:
+        insights azure-annotations findings.json --run-pk 19 --db /tmp/caldera.duckdb
+    """
+    import json as json_module
+
+    from .ci_reporters import render_azure_commands
+
+    if run_pk is not None and db is None:
+        console.print("[red]Error:[/red] --run-pk requires --db")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        findings = json_module.loads(findings_file.read_text())
+
+        gate_results = []
+        if run_pk is not None:
+            from .data_fetcher import DataFetcher
+            from .gates import load_gates_config, run_gates
+
+            fetcher = DataFetcher(db_path=db)
+            vulnerabilities = fetcher.fetch("fixable_vulnerabilities", run_pk=run_pk)
+            gate_results = run_gates(vulnerabilities, load_gates_config(config))
+
+        # Plain print: Azure parses logging commands from raw stdout.
+        for command in render_azure_commands(findings, gate_results):
+            print(command)
+
+    except typer.Exit:
+        raise
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error rendering Azure annotations:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("bitbucket-report")
+def bitbucket_report(
+    findings_file: Path = typer.Argument(..., help="Findings JSON (list of finding objects)"),
+    workspace: str = typer.Option(..., "--workspace", help="Bitbucket workspace"),
+    repo_slug: str = typer.Option(..., "--repo-slug", help="Bitbucket repository slug"),
+    commit: str = typer.Option(..., "--commit", help="Commit SHA the report attaches to"),
+    run_pk: int | None = typer.Option(None, "--run-pk", help="Tool run primary key for gate evaluation"),
+    db: Path | None = typer.Option(None, "--db", "-d", help="Path to DuckDB database (required with --run-pk)"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+    dry_run: bool = typer.Option(False, "--dry-run", help="Show what would be published without calling Bitbucket"),
+) -> None:
+    """Publish findings as a Bitbucket Code Insights report.
+
+    Creates one report per commit with inline annotations on the pull
+    request diff; the report result comes from the gate engine. Requires
+    BITBUCKET_TOKEN with report write access.
+
+    Example:
+        insights bitbucket-report findings.json --workspace acme --repo-slug app --commit $BITBUCKET_COMMIT
+    """
+    import json as json_module
+
+    from .ci_reporters import (
+        BitbucketClient,
+        ReporterError,
+        build_bitbucket_annotations,
+        build_bitbucket_report,
+        publish_bitbucket_report,
+    )
+
+    if run_pk is not None and db is None:
+        console.print("[red]Error:[/red] --run-pk requires --db")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        findings = json_module.loads(findings_file.read_text())
+
+        gate_results = []
+        if run_pk is not None:
+            from .data_fetcher import DataFetcher
+            from .gates import load_gates_config, run_gates
+
+            fetcher = DataFetcher(db_path=db)
+            vulnerabilities = fetcher.fetch("fixable_vulnerabilities", run_pk=run_pk)
+            gate_results = run_gates(vulnerabilities, load_gates_config(config))
+
+        if dry_run:
+            report = build_bitbucket_report(findings, gate_results)
+            annotations, truncated = build_bitbucket_annotations(findings)
+            console.print(
+                f"[yellow]Dry run:[/yellow] would publish report "
+                f"[bold]{report['result']}[/bold] with {len(annotations)} annotation(s) "
+                f"({truncated} truncated)"
+            )
+            return
+
+        result = publish_bitbucket_report(
+            BitbucketClient(workspace, repo_slug), commit, findings, gate_results
+        )
+        console.print(
+            f"[green]Report {result.report_id}:[/green] {result.result}, "
+            f"{result.annotation_count} annotation(s)"
+            + (f" ({result.truncated} truncated)" if result.truncated else "")
+        )
+        if result.result == "FAILED":
+            raise typer.Exit(1)
+
+    except typer.Exit:
+        raise
+    except ReporterError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error publishing Bitbucket report:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("defectdojo-export")
+def defectdojo_export(
+    run_pk: int = typer.Argument(..., help="Tool run primary key"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    output: Path = typer.Option(..., "--output", "-o", help="Output file path for the generic findings JSON"),
+    date: str | None = typer.Option(None, "--date", help="Finding date (YYYY-MM-DD) recorded on import"),
+    all_tools: bool = typer.Option(False, "--all-tools", help="Include non-security tools as well"),
+) -> None:
+    """Export findings in DefectDojo's generic findings JSON format.
+
+    Findings carry a stable dedup key and a CWE where available, so
+    re-imports into DefectDojo update existing findings instead of
+    duplicating them. Only security tools are exported unless --all-tools.
+
+    Example:
+        insights defectdojo-export 19 --db /tmp/caldera.duckdb -o defectdojo.json
+    """
+    from .data_fetcher import DataFetcher
+    from .defectdojo import write_export
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    fetcher = DataFetcher(db_path=db)
+
+    try:
+        findings = fetcher.fetch("findings_export", run_pk=run_pk)
+        tools = {str(f.get("tool")) for f in findings} if all_tools else None
+        count = write_export(findings, output, tools=tools, date=date)
+        console.print(f"[green]Exported {count} findings to:[/green] {output}")
+    except Exception as e:
+        console.print(f"[red]Error exporting findings:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("export")
+def export_tabular(
+    dataset: str = typer.Argument(..., help="Dataset to export: findings or file-metrics"),
+    run_pk: int = typer.Option(..., "--run-pk", help="Tool run primary key"),
+    db: Path = typer.Option(..., "--db", "-d", help="Path to DuckDB database"),
+    output: Path = typer.Option(..., "--output", "-o", help="Output file path"),
+    output_format: str = typer.Option("csv", "--format", "-f", help="Output format: csv, parquet, or jsonl"),
+    select: str | None = typer.Option(None, "--select", help="Comma-separated columns to keep (csv/jsonl only)"),
+    row_filter: str | None = typer.Option(None, "--filter", help='Row filter expression, e.g. "severity = HIGH" (csv/jsonl only)'),
+) -> None:
+    """Export findings or per-file metrics as CSV, Parquet, or JSONL.
+
+    CSV and Parquet use DuckDB's native COPY, so Parquet files load
+    directly into warehouses and notebooks with types intact. JSONL
+    streams one record per line without buffering the whole result,
+    which keeps memory flat on very large scans. --select and --filter
+    (the shared grammar from `caldera query`) project and filter rows on
+    the way out for csv and jsonl exports.
+
+    Example:
+        insights export findings --run-pk 19 --db /tmp/caldera.duckdb -f parquet -o findings.parquet
+    """
+    from common.record_filter import parse_filter, parse_select
+
+    from .tabular_export import export_dataset
+
+    if not db.exists():
+        console.print(f"[red]Error:[/red] Database not found: {db}")
+        raise typer.Exit(1)
+
+    try:
+        count = export_dataset(
+            db,
+            dataset,
+            run_pk,
+            output,
+            output_format,
+            select=parse_select(select) if select else None,
+            record_filter=parse_filter(row_filter) if row_filter else None,
+        )
+        console.print(f"[green]Exported {count} rows to:[/green] {output}")
+    except ValueError as e:
+        console.print(f"[red]Error:[/red] {e}")
+        raise typer.Exit(1)
+    except Exception as e:
+        console.print(f"[red]Error exporting {dataset}:[/red] {e}")
+        raise typer.Exit(1)
+
+
+@app.command("junit-report")
+def junit_report(
+    findings_file: Path = typer.Argument(..., help="Findings JSON (list of finding objects)"),
+    output: Path = typer.Option(Path("caldera-junit.xml"), "--output", "-o", help="Output XML path"),
+    run_pk: int | None = typer.Option(None, "--run-pk", help="Tool run primary key for gate evaluation"),
+    db: Path | None = typer.Option(None, "--db", "-d", help="Path to DuckDB database (required with --run-pk)"),
+    config: Path | None = typer.Option(None, "--config", help="Path to caldera.toml (defaults to repo root)"),
+) -> None:
+    """Emit findings and gate results as JUnit XML for CI test panes.
+
+    Each gate and each (tool, rule) with findings becomes a test case, so
+    Jenkins and friends render Caldera results in their native test UI.
+    With --run-pk the gate engine contributes pass/fail cases too.
+
+    Example:
+        insights junit-report findings.json --run-pk 19 --db /tmp/caldera.duckdb -o reports/caldera.xml
+    """
+    import json as json_module
+
+    from .junit_report import write_junit_report
+
+    if run_pk is not None and db is None:
+        console.print("[red]Error:[/red] --run-pk requires --db")
+        raise typer.Exit(1)
+
+    if config is None:
+        config = Path(__file__).resolve().parents[2] / "caldera.toml"
+
+    try:
+        findings = json_module.loads(findings_file.read_text())
+
+        gate_results = []
+        if run_pk is not None:
+            from .data_fetcher import DataFetcher
+            from .gates import load_gates_config, run_gates
+
+            fetcher = DataFetcher(db_path=db)
+            vulnerabilities = fetcher.fetch("fixable_vulnerabilities", run_pk=run_pk)
+            gate_results = run_gates(vulnerabilities, load_gates_config(config))
+
+        count = write_junit_report(findings, gate_results, output)
+        console.print(f"[green]Wrote {count} test case(s) to:[/green] {output}")
+    except typer.Exit:
+        raise
+    except Exception as e:
+        console.print(f"[red]Error writing JUnit report:[/red] {e}")
+        raise typer.Exit(1)
+
+
 def main() -> None:
     """Main entry point."""
     app()
@@ -0,0 +1,145 @@
+"""
+Temporal coupling analysis from commit history.
+
+Mines ``git log`` for files that are frequently modified in the same
+commit. Two files that keep changing together despite living in different
+top-level modules are implicitly coupled — a shared assumption, a missing
+abstraction, or a contract the dependency graph does not show — which
+makes the cross-module pairs prime input for architecture reviews.
+
+For each file pair:
+
+- ``support``     number of commits touching both files
+- ``confidence``  support divided by the change count of the *less*
+  frequently changed file, so a stable file dragged along by a hot one
+  still scores high
+
+Merge commits are skipped, and commits touching more than
+``max_files_per_commit`` files are ignored as bulk operations (formatting
+sweeps, renames) that would couple everything to everything.
+"""
+
+from __future__ import annotations
+
+import subprocess
+from collections import Counter
+from dataclasses import dataclass
+from itertools import combinations
+from pathlib import Path
+
+DEFAULT_MAX_COMMITS = 2000
+DEFAULT_MAX_FILES_PER_COMMIT = 30
+DEFAULT_MIN_SUPPORT = 5
+DEFAULT_MIN_CONFIDENCE = 0.5
+
+# Sentinel prefixing each commit in the parsed git log output.
+_COMMIT_MARKER = "\x01"
+
+
+@dataclass(frozen=True)
+class CoChangePair:
+    """One implicitly coupled file pair, ``file_a < file_b`` lexically."""
+
+    file_a: str
+    file_b: str
+    support: int  # commits touching both files
+    confidence: float  # support / changes of the less-changed file
+    cross_module: bool  # top-level directories differ
+
+    def __post_init__(self) -> None:
+        if self.support <= 0:
+            raise ValueError("support must be > 0")
+        if not 0 < self.confidence <= 1:
+            raise ValueError("confidence must be in (0, 1]")
+
+
+def _module_of(relative_path: str) -> str:
+    return relative_path.split("/", 1)[0] if "/" in relative_path else "."
+
+
+def read_commit_file_sets(
+    repo_path: Path,
+    max_commits: int = DEFAULT_MAX_COMMITS,
+    max_files_per_commit: int = DEFAULT_MAX_FILES_PER_COMMIT,
+) -> list[frozenset[str]]:
+    """Return the set of files touched by each recent non-merge commit.
+
+    Commits touching more than ``max_files_per_commit`` files are dropped
+    (see module docstring). Raises ``ValueError`` when ``repo_path`` is not
+    a git repository.
+    """
+    result = subprocess.run(
+        [
+            "git",
+            "-C",
+            str(repo_path),
+            "log",
+            "--no-merges",
+            "--name-only",
+            f"--pretty=format:{_COMMIT_MARKER}",
+            "-n",
+            str(max_commits),
+        ],
+        capture_output=True,
+        text=True,
+    )
+    if result.returncode != 0:
+        raise ValueError(f"not a git repository: {repo_path}")
+
+    file_sets: list[frozenset[str]] = []
+    current: set[str] = set()
+    for line in result.stdout.splitlines():
+        if line.startswith(_COMMIT_MARKER):
+            if current and len(current) <= max_files_per_commit:
+                file_sets.append(frozenset(current))
+            current = set()
+        elif line.strip():
+            current.add(line.strip())
+    if current and len(current) <= max_files_per_commit:
+        file_sets.append(frozenset(current))
+    return file_sets
+
+
+def compute_cochange(
+    file_sets: list[frozenset[str]],
+    min_support: int = DEFAULT_MIN_SUPPORT,
+    min_confidence: float = DEFAULT_MIN_CONFIDENCE,
+) -> list[CoChangePair]:
+    """Score file pairs that keep changing together.
+
+    Returns pairs meeting both thresholds, cross-module pairs first, then
+    by support and confidence descending. Same-module pairs are kept (with
+    ``cross_module=False``) so callers can widen the report if wanted.
+    """
+    change_counts: Counter[str] = Counter()
+    pair_counts: Counter[tuple[str, str]] = Counter()
+    for files in file_sets:
+        change_counts.update(files)
+        pair_counts.update(combinations(sorted(files), 2))
+
+    pairs = []
+    for (file_a, file_b), support in pair_counts.items():
+        if support < min_support:
+            continue
+        confidence = support / min(change_counts[file_a], change_counts[file_b])
+        if confidence < min_confidence:
+            continue
+        pairs.append(
+            CoChangePair(
+                file_a=file_a,
+                file_b=file_b,
+                support=support,
+                confidence=round(confidence, 2),
+                cross_module=_module_of(file_a) != _module_of(file_b),
+            )
+        )
+    return sorted(
+        pairs,
+        key=lambda pair: (
+            not pair.cross_module,
+            -pair.support,
+            -pair.confidence,
+            pair.file_a,
+            pair.file_b,
+        ),
+    )
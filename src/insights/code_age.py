@@ -0,0 +1,158 @@
+"""
+Code-age analysis from git blame.
+
+Reads per-line last-modified dates via ``git blame --line-porcelain`` and
+folds them into lizard's function ranges, giving every function a median
+age and a last-touched date. Two report sections fall out:
+
+- ``stale complex code``        old, complex functions nobody has touched
+  in a long time (optionally also untested, when a coverage join is
+  supplied) — risky to change precisely because nobody remembers them
+- ``recently churned hotspots`` complex functions modified in the last
+  few weeks, where the next regression is most likely brewing
+
+Blame is invoked per file, so callers should restrict it to the files
+that actually hold complex functions rather than blaming the whole tree.
+"""
+
+from __future__ import annotations
+
+import re
+import statistics
+import subprocess
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+
+STALE_MIN_AGE_DAYS = 365
+CHURN_MAX_AGE_DAYS = 30
+DEFAULT_MIN_CCN = 10
+
+_HEADER_PATTERN = re.compile(r"^[0-9a-f]{40} \d+ (\d+)")
+
+
+@dataclass(frozen=True)
+class FunctionAge:
+    """Line-age profile for one function."""
+
+    file: str
+    function_name: str
+    ccn: int
+    line_start: int
+    line_end: int
+    median_age_days: int
+    last_touched_days: int  # age of the youngest line in the range
+
+
+def blame_line_ages(
+    repo_path: Path, relative_path: str, as_of: datetime | None = None
+) -> dict[int, int]:
+    """Per-line age in days from ``git blame --line-porcelain``.
+
+    Raises ``ValueError`` when the file is not tracked by git.
+    """
+    as_of = as_of or datetime.now(timezone.utc)
+    result = subprocess.run(
+        ["git", "-C", str(repo_path), "blame", "--line-porcelain", "--", relative_path],
+        capture_output=True,
+        text=True,
+    )
+    if result.returncode != 0:
+        raise ValueError(f"git blame failed for {relative_path}: {result.stderr.strip()}")
+
+    ages: dict[int, int] = {}
+    current_line: int | None = None
+    for line in result.stdout.splitlines():
+        header = _HEADER_PATTERN.match(line)
+        if header:
+            current_line = int(header.group(1))
+        elif line.startswith("committer-time ") and current_line is not None:
+            touched = datetime.fromtimestamp(int(line.split()[1]), tz=timezone.utc)
+            ages[current_line] = max((as_of - touched).days, 0)
+    return ages
+
+
+def compute_function_ages(
+    function_rows: list[dict],
+    line_ages_by_file: dict[str, dict[int, int]],
+) -> list[FunctionAge]:
+    """Fold per-line ages into lizard function ranges.
+
+    ``function_rows`` are lizard_functions query rows (file, function_name,
+    ccn, line_start, line_end). Functions in files without blame data, or
+    whose range holds no blamed lines, are skipped.
+    """
+    ages = []
+    for row in function_rows:
+        file_ages = line_ages_by_file.get(row["file"])
+        if not file_ages:
+            continue
+        line_start = int(row.get("line_start") or 0)
+        line_end = int(row.get("line_end") or 0)
+        in_range = [
+            age for line, age in file_ages.items() if line_start <= line <= line_end
+        ]
+        if not in_range:
+            continue
+        ages.append(
+            FunctionAge(
+                file=row["file"],
+                function_name=row["function_name"],
+                ccn=int(row.get("ccn") or 0),
+                line_start=line_start,
+                line_end=line_end,
+                median_age_days=round(statistics.median(in_range)),
+                last_touched_days=min(in_range),
+            )
+        )
+    return ages
+
+
+def stale_complex(
+    function_ages: list[FunctionAge],
+    min_age_days: int = STALE_MIN_AGE_DAYS,
+    min_ccn: int = DEFAULT_MIN_CCN,
+    coverage_pct_by_function: dict[tuple[str, str, int], float] | None = None,
+    max_coverage_pct: float = 50.0,
+) -> list[FunctionAge]:
+    """Old, complex functions nobody touches — oldest first.
+
+    Staleness keys off ``last_touched_days`` so one recent edit anywhere
+    in the function resets it. With ``coverage_pct_by_function`` (keyed by
+    ``(file, function_name, line_start)``) the filter also demands low
+    coverage, completing the old/complex/untested triple.
+    """
+    stale = [
+        function
+        for function in function_ages
+        if function.ccn >= min_ccn and function.last_touched_days >= min_age_days
+    ]
+    if coverage_pct_by_function is not None:
+        stale = [
+            function
+            for function in stale
+            if coverage_pct_by_function.get(
+                (function.file, function.function_name, function.line_start),
+                0.0,
+            )
+            < max_coverage_pct
+        ]
+    return sorted(
+        stale, key=lambda function: (-function.last_touched_days, -function.ccn)
+    )
+
+
+def recently_churned(
+    function_ages: list[FunctionAge],
+    max_age_days: int = CHURN_MAX_AGE_DAYS,
+    min_ccn: int = DEFAULT_MIN_CCN,
+) -> list[FunctionAge]:
+    """Complex functions touched within the churn window — most complex first."""
+    churned = [
+        function
+        for function in function_ages
+        if function.ccn >= min_ccn and function.last_touched_days <= max_age_days
+    ]
+    return sorted(
+        churned, key=lambda function: (-function.ccn, function.last_touched_days)
+    )
@@ -0,0 +1,117 @@
+"""
+Production / test / fixture code classification.
+
+Classifies every file from its repo-relative path so metrics can be
+reported per bucket instead of blended — complexity and size averages are
+otherwise skewed by generated test fixtures and test scaffolding. Three
+buckets:
+
+- ``production``  everything not matched below
+- ``test``        test sources, by directory convention (``tests/``,
+  ``spec/``, ``__tests__/``) or filename convention (``test_*.py``,
+  ``*_test.go``, ``*.spec.ts``, ``*Tests.cs``, ...)
+- ``fixture``     checked-in test data (``fixtures/``, ``testdata/``,
+  ``__snapshots__/``, ``golden/``), classified before the test bucket so
+  data under ``tests/fixtures/`` counts as fixture, not test
+
+Heuristics are path-based only — contents are not inspected — which keeps
+the classification usable straight from landing-zone rows.
+"""
+
+from __future__ import annotations
+
+import posixpath
+import re
+from dataclasses import dataclass
+
+BUCKETS = ("production", "test", "fixture")
+
+_FIXTURE_DIRS = frozenset(
+    {"fixtures", "fixture", "testdata", "test_data", "test-data", "__snapshots__", "golden"}
+)
+
+_TEST_DIRS = frozenset({"tests", "test", "spec", "specs", "__tests__"})
+
+# Filename conventions across the ecosystems the tools cover.
+_TEST_FILE_PATTERN = re.compile(
+    r"""(?x)
+    ^ (
+        test_[^/]*             # pytest: test_foo.py
+      | conftest\.py           # pytest scaffolding
+      | [^/]* (
+            _test\.[a-z]+      # go/python: foo_test.go
+          | \.test\.[a-z]+     # jest: foo.test.ts
+          | \.spec\.[a-z]+     # jasmine/jest: foo.spec.ts
+          | Tests?\.(cs|java|kt|swift)  # xunit/junit: FooTests.cs
+        )
+    ) $
+    """
+)
+
+
+@dataclass(frozen=True)
+class BucketMetrics:
+    """Aggregated metrics for one classification bucket."""
+
+    bucket: str
+    file_count: int
+    code_lines: int
+    mean_ccn: float | None  # None when lizard covered no file in the bucket
+
+
+def classify_path(relative_path: str) -> str:
+    """Classify a repo-relative path as production, test, or fixture."""
+    segments = relative_path.split("/")
+    directories = {segment.lower() for segment in segments[:-1]}
+    if directories & _FIXTURE_DIRS:
+        return "fixture"
+    if directories & _TEST_DIRS or _TEST_FILE_PATTERN.match(posixpath.basename(relative_path)):
+        return "test"
+    return "production"
+
+
+def compute_bucket_metrics(
+    scc_rows: list[dict],
+    complexity_rows: list[dict] | None = None,
+) -> list[BucketMetrics]:
+    """Aggregate per-file size and complexity rows into the three buckets.
+
+    ``scc_rows`` carry (relative_path, code_lines) and ``complexity_rows``
+    are lz_lizard_file_metrics rows (relative_path, total_ccn,
+    function_count). Buckets with no files are still reported with zero
+    counts so the output shape is stable.
+    """
+    files: dict[str, list[int]] = {bucket: [] for bucket in BUCKETS}
+    for row in scc_rows:
+        files[classify_path(row["relative_path"])].append(int(row.get("code_lines") or 0))
+
+    ccn: dict[str, tuple[float, int]] = {bucket: (0.0, 0) for bucket in BUCKETS}
+    for row in complexity_rows or []:
+        bucket = classify_path(row["relative_path"])
+        total_ccn, functions = ccn[bucket]
+        ccn[bucket] = (
+            total_ccn + float(row.get("total_ccn") or 0),
+            functions + int(row.get("function_count") or 0),
+        )
+
+    results = []
+    for bucket in BUCKETS:
+        total_ccn, functions = ccn[bucket]
+        results.append(
+            BucketMetrics(
+                bucket=bucket,
+                file_count=len(files[bucket]),
+                code_lines=sum(files[bucket]),
+                mean_ccn=round(total_ccn / functions, 1) if functions else None,
+            )
+        )
+    return results
+
+
+def compute_test_ratio(buckets: list[BucketMetrics]) -> float | None:
+    """Test code lines per production code line, or None without production code."""
+    by_bucket = {bucket.bucket: bucket for bucket in buckets}
+    production = by_bucket["production"].code_lines
+    if production <= 0:
+        return None
+    return round(by_bucket["test"].code_lines / production, 2)
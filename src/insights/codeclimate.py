@@ -0,0 +1,96 @@
+"""
+Code Climate issue format reporter.
+
+Converts Caldera linter findings into the Code Climate engine issue
+format (an array of issue objects), so dashboards and tooling built
+around that ecosystem — GitLab code quality widgets, reek-style engines —
+can consume Caldera output without adapters on their side.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+from pathlib import Path
+
+# Caldera severity -> Code Climate severity.
+SEVERITY_TO_CODECLIMATE = {
+    "CRITICAL": "blocker",
+    "HIGH": "critical",
+    "MEDIUM": "major",
+    "LOW": "minor",
+}
+
+# Tool -> Code Climate category (one of the spec's fixed vocabulary).
+TOOL_TO_CATEGORY = {
+    "bandit": "Security",
+    "devskim": "Security",
+    "checkov": "Security",
+    "gitleaks": "Security",
+    "trivy": "Security",
+    "semgrep": "Bug Risk",
+    "shellcheck": "Bug Risk",
+    "golangci": "Bug Risk",
+    "sqlfluff": "Style",
+    "pmd-cpd": "Duplication",
+    "jscpd": "Duplication",
+    "lizard": "Complexity",
+}
+
+DEFAULT_CATEGORY = "Bug Risk"
+
+
+def fingerprint(finding: dict) -> str:
+    """Stable identity for a finding, for Code Climate deduplication.
+
+    Derived from tool, rule, file, and line so re-exports of the same run
+    (and unchanged findings across runs) keep the same fingerprint.
+    """
+    raw = ":".join([
+        finding.get("tool", ""),
+        finding.get("rule_id", ""),
+        finding.get("relative_path", ""),
+        str(finding.get("line_start") or 0),
+    ])
+    return hashlib.md5(raw.encode("utf-8")).hexdigest()
+
+
+def export_codeclimate_issues(findings: list[dict]) -> list[dict]:
+    """Convert normalized finding rows into Code Climate issue objects.
+
+    Each row needs ``tool``, ``relative_path``, ``rule_id``, ``severity``,
+    ``line_start``/``line_end``, and ``message`` (the shape produced by the
+    ``findings_export`` query).
+    """
+    issues = []
+    for finding in findings:
+        tool = finding.get("tool", "")
+        rule_id = finding.get("rule_id", "")
+        line_start = finding.get("line_start") or 1
+        line_end = finding.get("line_end") or line_start
+        issues.append({
+            "type": "issue",
+            "check_name": f"{tool}/{rule_id}" if tool else rule_id,
+            "description": finding.get("message") or rule_id,
+            "categories": [TOOL_TO_CATEGORY.get(tool, DEFAULT_CATEGORY)],
+            "location": {
+                "path": finding.get("relative_path", ""),
+                "lines": {
+                    "begin": line_start,
+                    "end": max(line_end, line_start),
+                },
+            },
+            "severity": SEVERITY_TO_CODECLIMATE.get(
+                (finding.get("severity") or "").upper(), "major"
+            ),
+            "fingerprint": fingerprint(finding),
+        })
+    return issues
+
+
+def write_report(findings: list[dict], output_path: Path) -> int:
+    """Write the Code Climate issue array; returns the issue count."""
+    issues = export_codeclimate_issues(findings)
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    output_path.write_text(json.dumps(issues, indent=2, ensure_ascii=False))
+    return len(issues)
@@ -0,0 +1,111 @@
+"""
+Directory-level configuration overrides.
+
+caldera.toml can carry an ``[overrides]`` table whose keys are glob
+patterns matched against repo-relative paths:
+
+    [overrides."tests/**".health_score.caps]
+    complexity = 40.0
+
+    [overrides."src/generated/**".health_score.weights]
+    duplication = 0.0
+
+Precedence is simple and explicit: the base config applies everywhere;
+every override whose pattern matches the path is deep-merged on top, in
+the order the entries appear in the file, so later entries win on
+conflict. ``**`` spans directory separators, ``*`` and ``?`` stay within
+one path segment, and ``dir/**`` also matches ``dir`` itself.
+"""
+
+from __future__ import annotations
+
+import re
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+
+@dataclass(frozen=True)
+class OverrideRule:
+    """One override entry: a glob pattern and the config tables it merges."""
+
+    pattern: str
+    settings: dict
+
+    def __post_init__(self) -> None:
+        if not self.pattern:
+            raise ValueError("override pattern must be non-empty")
+        if not isinstance(self.settings, dict):
+            raise ValueError(
+                f"override for {self.pattern!r} must be a table of config sections"
+            )
+
+
+def _translate_glob(pattern: str) -> re.Pattern[str]:
+    """Compile a glob pattern into a path-aware regex."""
+    suffix = "$"
+    if pattern.endswith("/**"):
+        pattern = pattern[:-3]
+        suffix = "(?:/.*)?$"  # trailing /** also matches the dir itself
+    tokens = re.findall(r"\*\*/|\*\*|\*|\?|[^*?]+", pattern)
+    parts = []
+    for token in tokens:
+        if token == "**/":
+            parts.append("(?:.*/)?")
+        elif token == "**":
+            parts.append(".*")
+        elif token == "*":
+            parts.append("[^/]*")
+        elif token == "?":
+            parts.append("[^/]")
+        else:
+            parts.append(re.escape(token))
+    return re.compile("^" + "".join(parts) + suffix)
+
+
+def glob_match(pattern: str, path: str) -> bool:
+    """Match a repo-relative path against an override glob pattern."""
+    return _translate_glob(pattern).match(path) is not None
+
+
+def load_overrides(caldera_toml: Path | None = None) -> list[OverrideRule]:
+    """Load override rules from caldera.toml in declaration order.
+
+    A missing file or missing [overrides] table yields no rules.
+    """
+    if caldera_toml is None or not caldera_toml.exists():
+        return []
+    config = tomllib.loads(caldera_toml.read_text())
+    return parse_overrides(config)
+
+
+def parse_overrides(config: dict) -> list[OverrideRule]:
+    """Extract override rules from a parsed caldera.toml dict."""
+    return [
+        OverrideRule(pattern=pattern, settings=settings)
+        for pattern, settings in config.get("overrides", {}).items()
+    ]
+
+
+def deep_merge(base: dict, override: dict) -> dict:
+    """Merge nested tables, the override winning on scalar conflicts."""
+    merged = dict(base)
+    for key, value in override.items():
+        if isinstance(value, dict) and isinstance(merged.get(key), dict):
+            merged[key] = deep_merge(merged[key], value)
+        else:
+            merged[key] = value
+    return merged
+
+
+def resolve_for_path(config: dict, path: str) -> dict:
+    """Resolve the effective config for one repo-relative path.
+
+    Starts from the base config (without the [overrides] table) and
+    deep-merges every matching override in declaration order.
+    """
+    effective = {key: value for key, value in config.items() if key != "overrides"}
+    for rule in parse_overrides(config):
+        if glob_match(rule.pattern, path):
+            effective = deep_merge(effective, rule.settings)
+    return effective
@@ -0,0 +1,213 @@
+"""
+SQALE-style technical debt estimation in remediation minutes.
+
+Maps every debt signal to an estimated time-to-fix and aggregates the
+result per file and per directory, so "how bad is it" becomes "how long
+would it take". Three signal classes feed the model:
+
+- ``findings``     each static-analysis finding costs the minutes
+  configured for its severity
+- ``complexity``   each function with CCN at or above ``ccn_threshold``
+  costs ``complex_function_minutes``
+- ``duplication``  each duplicated line costs ``duplicated_line_minutes``
+
+The cost model lives under ``[debt]`` in ``caldera.toml`` so organizations
+can calibrate it against their own remediation history; missing keys fall
+back to the defaults below. The defaults are deliberately round numbers —
+the value of the model is in ranking and trending, not in the absolute
+minute counts.
+"""
+
+from __future__ import annotations
+
+import posixpath
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+DEFAULT_SEVERITY_MINUTES: dict[str, float] = {
+    "CRITICAL": 60.0,
+    "HIGH": 30.0,
+    "MEDIUM": 15.0,
+    "LOW": 5.0,
+    "INFO": 2.0,
+}
+
+DEFAULT_CCN_THRESHOLD = 10
+DEFAULT_COMPLEX_FUNCTION_MINUTES = 20.0
+DEFAULT_DUPLICATED_LINE_MINUTES = 0.5
+
+# SQALE working day used when formatting minutes as days.
+_MINUTES_PER_HOUR = 60
+_HOURS_PER_DAY = 8
+
+
+@dataclass(frozen=True)
+class DebtConfig:
+    """Remediation cost model from ``[debt]`` in caldera.toml."""
+
+    severity_minutes: dict[str, float]
+    ccn_threshold: int = DEFAULT_CCN_THRESHOLD
+    complex_function_minutes: float = DEFAULT_COMPLEX_FUNCTION_MINUTES
+    duplicated_line_minutes: float = DEFAULT_DUPLICATED_LINE_MINUTES
+
+    def __post_init__(self) -> None:
+        for severity, minutes in self.severity_minutes.items():
+            if minutes < 0:
+                raise ValueError(f"severity_minutes for {severity} must be >= 0")
+        if self.ccn_threshold <= 0:
+            raise ValueError("ccn_threshold must be > 0")
+        if self.complex_function_minutes < 0:
+            raise ValueError("complex_function_minutes must be >= 0")
+        if self.duplicated_line_minutes < 0:
+            raise ValueError("duplicated_line_minutes must be >= 0")
+
+
+@dataclass(frozen=True)
+class FileDebt:
+    """Estimated remediation minutes for one file, split by signal class."""
+
+    relative_path: str
+    finding_minutes: float
+    complexity_minutes: float
+    duplication_minutes: float
+
+    @property
+    def total_minutes(self) -> float:
+        return round(
+            self.finding_minutes + self.complexity_minutes + self.duplication_minutes, 1
+        )
+
+
+@dataclass(frozen=True)
+class DirectoryDebt:
+    """Summed remediation minutes for the files directly in one directory."""
+
+    directory: str
+    file_count: int
+    finding_minutes: float
+    complexity_minutes: float
+    duplication_minutes: float
+
+    @property
+    def total_minutes(self) -> float:
+        return round(
+            self.finding_minutes + self.complexity_minutes + self.duplication_minutes, 1
+        )
+
+
+def load_debt_config(caldera_toml: Path | None = None) -> DebtConfig:
+    """Load the cost model from ``[debt]``, falling back to defaults.
+
+    Severity costs merge over the defaults, so a config only has to list
+    the severities it wants to recalibrate.
+    """
+    severity_minutes = dict(DEFAULT_SEVERITY_MINUTES)
+    if caldera_toml is None or not caldera_toml.exists():
+        return DebtConfig(severity_minutes=severity_minutes)
+    section = tomllib.loads(caldera_toml.read_text()).get("debt", {})
+    severity_minutes.update(
+        {
+            severity.upper(): float(minutes)
+            for severity, minutes in section.get("severity_minutes", {}).items()
+        }
+    )
+    return DebtConfig(
+        severity_minutes=severity_minutes,
+        ccn_threshold=int(section.get("ccn_threshold", DEFAULT_CCN_THRESHOLD)),
+        complex_function_minutes=float(
+            section.get("complex_function_minutes", DEFAULT_COMPLEX_FUNCTION_MINUTES)
+        ),
+        duplicated_line_minutes=float(
+            section.get("duplicated_line_minutes", DEFAULT_DUPLICATED_LINE_MINUTES)
+        ),
+    )
+
+
+def compute_file_debt(
+    finding_rows: list[dict],
+    function_rows: list[dict] | None = None,
+    duplication_rows: list[dict] | None = None,
+    config: DebtConfig | None = None,
+) -> list[FileDebt]:
+    """Price every file's debt signals, most expensive file first.
+
+    ``finding_rows`` carry (relative_path, severity, finding_count),
+    ``function_rows`` are lizard function rows (file, ccn) and
+    ``duplication_rows`` are lz_pmd_cpd_file_metrics rows (relative_path,
+    duplicate_lines). Unknown severities are priced as MEDIUM so an
+    uncalibrated tool still contributes.
+    """
+    config = config or DebtConfig(severity_minutes=dict(DEFAULT_SEVERITY_MINUTES))
+    medium = config.severity_minutes.get("MEDIUM", 0.0)
+
+    finding_minutes: dict[str, float] = {}
+    for row in finding_rows:
+        severity = (row.get("severity") or "MEDIUM").upper()
+        minutes = config.severity_minutes.get(severity, medium) * int(
+            row.get("finding_count") or 0
+        )
+        path = row["relative_path"]
+        finding_minutes[path] = finding_minutes.get(path, 0.0) + minutes
+
+    complexity_minutes: dict[str, float] = {}
+    for row in function_rows or []:
+        if int(row.get("ccn") or 0) >= config.ccn_threshold:
+            path = row["file"]
+            complexity_minutes[path] = (
+                complexity_minutes.get(path, 0.0) + config.complex_function_minutes
+            )
+
+    duplication_minutes: dict[str, float] = {}
+    for row in duplication_rows or []:
+        path = row["relative_path"]
+        duplication_minutes[path] = duplication_minutes.get(path, 0.0) + (
+            int(row.get("duplicate_lines") or 0) * config.duplicated_line_minutes
+        )
+
+    debts = [
+        FileDebt(
+            relative_path=path,
+            finding_minutes=round(finding_minutes.get(path, 0.0), 1),
+            complexity_minutes=round(complexity_minutes.get(path, 0.0), 1),
+            duplication_minutes=round(duplication_minutes.get(path, 0.0), 1),
+        )
+        for path in finding_minutes.keys()
+        | complexity_minutes.keys()
+        | duplication_minutes.keys()
+    ]
+    return sorted(
+        (debt for debt in debts if debt.total_minutes > 0),
+        key=lambda debt: (-debt.total_minutes, debt.relative_path),
+    )
+
+
+def aggregate_directory_debt(file_debts: list[FileDebt]) -> list[DirectoryDebt]:
+    """Sum file debt into direct parent directories, most expensive first."""
+    grouped: dict[str, list[FileDebt]] = {}
+    for debt in file_debts:
+        directory = posixpath.dirname(debt.relative_path) or "."
+        grouped.setdefault(directory, []).append(debt)
+    directories = [
+        DirectoryDebt(
+            directory=directory,
+            file_count=len(debts),
+            finding_minutes=round(sum(debt.finding_minutes for debt in debts), 1),
+            complexity_minutes=round(sum(debt.complexity_minutes for debt in debts), 1),
+            duplication_minutes=round(sum(debt.duplication_minutes for debt in debts), 1),
+        )
+        for directory, debts in grouped.items()
+    ]
+    return sorted(directories, key=lambda debt: (-debt.total_minutes, debt.directory))
+
+
+def format_minutes(minutes: float) -> str:
+    """Render minutes as ``45m``, ``3h 15m``, or ``2d 1h`` (8-hour days)."""
+    total = round(minutes)
+    if total < _MINUTES_PER_HOUR:
+        return f"{total}m"
+    hours, remainder = divmod(total, _MINUTES_PER_HOUR)
+    if hours < _HOURS_PER_DAY:
+        return f"{hours}h {remainder}m" if remainder else f"{hours}h"
+    days, hours = divmod(hours, _HOURS_PER_DAY)
+    return f"{days}d {hours}h" if hours else f"{days}d"
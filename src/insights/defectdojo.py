@@ -0,0 +1,124 @@
+"""DefectDojo exporter for security findings.
+
+Converts Caldera findings into DefectDojo's generic findings JSON format
+so the security team can ingest semgrep/gitleaks/trivy results into their
+vulnerability management flow. Each finding carries a stable
+``unique_id_from_tool`` (the codeclimate fingerprint) so DefectDojo's
+deduplication recognizes re-imports of the same finding, and a CWE is
+attached where the source tool provides one or the rule maps to a known
+weakness class.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from pathlib import Path
+
+from insights.codeclimate import fingerprint
+
+# Tools whose findings are security-relevant; others are skipped by
+# default because DefectDojo is a vulnerability management system, not a
+# general code-quality dashboard.
+SECURITY_TOOLS = {"bandit", "devskim", "gitleaks", "semgrep", "trivy"}
+
+# Caldera severity -> DefectDojo severity (title case, Info..Critical).
+SEVERITY_TO_DEFECTDOJO = {
+    "CRITICAL": "Critical",
+    "HIGH": "High",
+    "MEDIUM": "Medium",
+    "LOW": "Low",
+    "INFO": "Info",
+}
+
+# Fallback CWE by weakness class for tools that do not emit one directly.
+# Secrets in source are CWE-798 (hard-coded credentials); rule-based
+# mappings cover the common semgrep/devskim categories.
+CWE_BY_TOOL = {"gitleaks": 798}
+CWE_BY_CATEGORY = {
+    "sql_injection": 89,
+    "command_injection": 78,
+    "path_traversal": 22,
+    "xss": 79,
+    "weak_crypto": 327,
+    "hardcoded_secret": 798,
+    "deserialization": 502,
+    "ssrf": 918,
+}
+
+_CWE_RE = re.compile(r"CWE-?(\d+)", re.IGNORECASE)
+
+
+def map_cwe(finding: dict) -> int | None:
+    """Best-effort CWE for a finding: explicit field, category, then tool."""
+    explicit = finding.get("cwe_id")
+    if explicit:
+        match = _CWE_RE.search(str(explicit))
+        if match:
+            return int(match.group(1))
+    category = str(finding.get("dd_category") or "").lower()
+    if category in CWE_BY_CATEGORY:
+        return CWE_BY_CATEGORY[category]
+    return CWE_BY_TOOL.get(str(finding.get("tool") or ""))
+
+
+def export_findings(
+    findings: list[dict],
+    tools: set[str] | None = None,
+    date: str | None = None,
+) -> dict:
+    """Convert finding rows into DefectDojo's generic findings document.
+
+    Each row needs ``tool``, ``relative_path``, ``rule_id``, ``severity``,
+    ``line_start``, and ``message``; non-security tools are filtered out
+    unless ``tools`` overrides the default set.
+    """
+    tools = tools if tools is not None else SECURITY_TOOLS
+    exported = []
+    for finding in findings:
+        tool = str(finding.get("tool") or "")
+        if tool not in tools:
+            continue
+        severity = SEVERITY_TO_DEFECTDOJO.get(
+            str(finding.get("severity") or "").upper(), "Medium"
+        )
+        rule_id = finding.get("rule_id") or finding.get("vulnerability_id") or tool
+        message = str(finding.get("message") or finding.get("secret_type") or rule_id)
+        entry = {
+            "title": f"{tool}: {rule_id}",
+            "description": message,
+            "severity": severity,
+            "file_path": finding.get("relative_path", ""),
+            "line": int(finding.get("line_start") or finding.get("line_number") or 1),
+            "unique_id_from_tool": fingerprint({**finding, "rule_id": str(rule_id)}),
+            "vuln_id_from_tool": str(rule_id),
+            "static_finding": True,
+            "dynamic_finding": False,
+            "active": True,
+        }
+        cwe = map_cwe(finding)
+        if cwe is not None:
+            entry["cwe"] = cwe
+        if date is not None:
+            entry["date"] = date
+        component = finding.get("pkg_name")
+        if component:
+            entry["component_name"] = component
+            entry["component_version"] = finding.get("installed_version")
+            if finding.get("fixed_version"):
+                entry["mitigation"] = f"Upgrade to {finding['fixed_version']}"
+        exported.append(entry)
+    return {"findings": exported}
+
+
+def write_export(
+    findings: list[dict],
+    output_path: Path,
+    tools: set[str] | None = None,
+    date: str | None = None,
+) -> int:
+    """Write the generic findings document; returns the finding count."""
+    document = export_findings(findings, tools=tools, date=date)
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    output_path.write_text(json.dumps(document, indent=2, ensure_ascii=False))
+    return len(document["findings"])
@@ -0,0 +1,358 @@
+"""
+Module-level dependency graph with cycle detection.
+
+Builds an import graph over the repository's source files by parsing
+import statements directly — no compiler or language server needed — and
+reports fan-in, fan-out, and dependency cycles. Cycles (strongly
+connected components with more than one member, or a self-import) are
+the actionable findings: every member of a cycle has to be understood,
+tested, and released together, so each cycle is reported with its full
+membership.
+
+Only imports that resolve to a file inside the repository become edges;
+third-party and standard-library imports are ignored. Supported
+languages and the statements parsed:
+
+- Python      ``import a.b``, ``from a.b import c`` (including relative)
+- JS/TS       ``import ... from './x'``, ``require('./x')`` — relative
+  specifiers only, since bare specifiers are external packages
+- Rust        ``mod foo;``, ``use crate::a::b``
+- Go          ``import "module/pkg"`` resolved against the go.mod module
+
+Resolution is per-file: each source file is a node and an import edge
+points at the file it resolves to, so "module" here means source file.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+
+_SKIP_DIRS = {
+    ".git",
+    "node_modules",
+    "target",
+    "vendor",
+    "dist",
+    "build",
+    ".venv",
+    "venv",
+    "__pycache__",
+}
+
+_LANGUAGE_BY_SUFFIX = {
+    ".py": "python",
+    ".js": "javascript",
+    ".jsx": "javascript",
+    ".ts": "typescript",
+    ".tsx": "typescript",
+    ".rs": "rust",
+    ".go": "go",
+}
+
+_PYTHON_IMPORT = re.compile(r"^\s*import\s+([\w. ,]+)")
+_PYTHON_FROM = re.compile(r"^\s*from\s+([\w.]+)\s+import\s+([\w, *]+)")
+_JS_IMPORT = re.compile(r"""(?:\bfrom|\bimport|\brequire\s*\()\s*['"]([^'"]+)['"]""")
+_RUST_MOD = re.compile(r"^\s*(?:pub\s+)?mod\s+(\w+)\s*;")
+_RUST_USE = re.compile(r"^\s*(?:pub\s+)?use\s+crate::([\w:]+)")
+_GO_IMPORT = re.compile(r'"([^"]+)"')
+_GO_MODULE = re.compile(r"^module\s+(\S+)", re.MULTILINE)
+
+_JS_EXTENSIONS = (".ts", ".tsx", ".js", ".jsx")
+
+
+@dataclass(frozen=True)
+class ModuleNode:
+    """One source file in the dependency graph."""
+
+    path: str
+    language: str
+    fan_in: int  # files importing this one
+    fan_out: int  # repo files this one imports
+
+    def __post_init__(self) -> None:
+        if self.fan_in < 0 or self.fan_out < 0:
+            raise ValueError("fan_in and fan_out must be >= 0")
+
+
+@dataclass(frozen=True)
+class DependencyCycle:
+    """One strongly connected component of size > 1 (or a self-import)."""
+
+    members: tuple[str, ...]
+    internal_edges: int  # edges between members — density hint
+
+    def __post_init__(self) -> None:
+        if not self.members:
+            raise ValueError("members must not be empty")
+
+    @property
+    def size(self) -> int:
+        return len(self.members)
+
+
+@dataclass(frozen=True)
+class DependencyGraph:
+    """Import graph over repository files, edges as (importer, imported)."""
+
+    nodes: tuple[ModuleNode, ...]
+    edges: frozenset[tuple[str, str]]
+
+
+def discover_source_files(repo_path: Path) -> list[str]:
+    """Return repo-relative paths of supported source files, sorted."""
+    files = []
+    for path in sorted(repo_path.rglob("*")):
+        if not path.is_file() or path.suffix not in _LANGUAGE_BY_SUFFIX:
+            continue
+        relative = path.relative_to(repo_path).as_posix()
+        if any(part in _SKIP_DIRS for part in relative.split("/")):
+            continue
+        files.append(relative)
+    return files
+
+
+def _resolve_python(spec: str, importer: str, known: set[str]) -> str | None:
+    if spec.startswith("."):
+        stripped = spec.lstrip(".")
+        level = len(spec) - len(stripped)
+        base_parts = importer.split("/")[:-1]
+        if level > 1:
+            base_parts = base_parts[: len(base_parts) - (level - 1)]
+        parts = base_parts + ([p for p in stripped.split(".") if p])
+    else:
+        parts = spec.split(".")
+    # Walk from the most specific candidate outward so "from a.b import c"
+    # resolves to a/b/c.py when c is a module, else a/b.py when an attribute.
+    while parts:
+        for candidate in ("/".join(parts) + ".py", "/".join(parts) + "/__init__.py"):
+            if candidate in known and candidate != importer:
+                return candidate
+        parts = parts[:-1]
+    return None
+
+
+def _resolve_js(spec: str, importer: str, known: set[str]) -> str | None:
+    if not spec.startswith("."):
+        return None  # bare specifier — external package
+    base = Path(importer).parent
+    target = (base / spec).as_posix()
+    # Normalize "a/./b" and "a/../b" without touching the filesystem.
+    parts: list[str] = []
+    for part in target.split("/"):
+        if part in ("", "."):
+            continue
+        if part == "..":
+            if parts:
+                parts.pop()
+            continue
+        parts.append(part)
+    target = "/".join(parts)
+    candidates = [target] if Path(target).suffix else []
+    candidates += [target + ext for ext in _JS_EXTENSIONS]
+    candidates += [f"{target}/index{ext}" for ext in _JS_EXTENSIONS]
+    for candidate in candidates:
+        if candidate in known and candidate != importer:
+            return candidate
+    return None
+
+
+def _resolve_rust_mod(name: str, importer: str, known: set[str]) -> str | None:
+    base = "/".join(importer.split("/")[:-1])
+    stem = Path(importer).stem
+    # mod declarations in foo.rs (not mod.rs/lib.rs/main.rs) look in foo/.
+    if stem not in ("mod", "lib", "main"):
+        base = f"{base}/{stem}" if base else stem
+    for candidate in (f"{base}/{name}.rs", f"{base}/{name}/mod.rs"):
+        candidate = candidate.lstrip("/")
+        if candidate in known and candidate != importer:
+            return candidate
+    return None
+
+
+def _resolve_rust_use(spec: str, importer: str, known: set[str]) -> str | None:
+    # use crate::a::b::Item — try the deepest path segment chain first.
+    src_root = importer.split("/src/", 1)[0] + "/src" if "/src/" in importer else "src"
+    parts = [p for p in spec.split("::") if p]
+    while parts:
+        prefix = "/".join(parts)
+        for candidate in (f"{src_root}/{prefix}.rs", f"{src_root}/{prefix}/mod.rs"):
+            if candidate in known and candidate != importer:
+                return candidate
+        parts = parts[:-1]
+    return None
+
+
+def _resolve_go(spec: str, importer: str, known: set[str], module: str) -> str | None:
+    if not module or not spec.startswith(module):
+        return None
+    package_dir = spec[len(module):].strip("/")
+    for candidate in sorted(known):
+        if (
+            candidate.endswith(".go")
+            and candidate != importer
+            and "/".join(candidate.split("/")[:-1]) == package_dir
+        ):
+            return candidate
+    return None
+
+
+def _file_imports(text: str, language: str) -> list[tuple[str, str]]:
+    """Return (kind, spec) import statements found in ``text``."""
+    imports: list[tuple[str, str]] = []
+    for line in text.splitlines():
+        if language == "python":
+            match = _PYTHON_FROM.match(line)
+            if match:
+                module = match.group(1)
+                # Append each imported name so "from a.b import c" can
+                # resolve to a/b/c.py when c is itself a module; the
+                # resolver falls back to a/b.py otherwise.
+                for name in match.group(2).split(","):
+                    name = name.strip().split(" as ")[0]
+                    if name and name != "*":
+                        joiner = "" if module.endswith(".") else "."
+                        imports.append(("python", f"{module}{joiner}{name}"))
+                    else:
+                        imports.append(("python", module))
+                continue
+            match = _PYTHON_IMPORT.match(line)
+            if match:
+                for name in match.group(1).split(","):
+                    imports.append(("python", name.strip().split(" as ")[0]))
+        elif language in ("javascript", "typescript"):
+            imports.extend(("js", spec) for spec in _JS_IMPORT.findall(line))
+        elif language == "rust":
+            match = _RUST_MOD.match(line)
+            if match:
+                imports.append(("rust_mod", match.group(1)))
+                continue
+            match = _RUST_USE.match(line)
+            if match:
+                imports.append(("rust_use", match.group(1)))
+        elif language == "go":
+            if line.strip().startswith(("import", '"')) or "import" in line:
+                imports.extend(("go", spec) for spec in _GO_IMPORT.findall(line))
+    return imports
+
+
+def build_dependency_graph(repo_path: Path) -> DependencyGraph:
+    """Parse imports across the repo and return the resolved file graph."""
+    files = discover_source_files(repo_path)
+    known = set(files)
+
+    go_mod = repo_path / "go.mod"
+    go_module = ""
+    if go_mod.is_file():
+        match = _GO_MODULE.search(go_mod.read_text(encoding="utf-8", errors="replace"))
+        go_module = match.group(1) if match else ""
+
+    edges: set[tuple[str, str]] = set()
+    for relative in files:
+        language = _LANGUAGE_BY_SUFFIX[Path(relative).suffix]
+        text = (repo_path / relative).read_text(encoding="utf-8", errors="replace")
+        for kind, spec in _file_imports(text, language):
+            if kind == "python":
+                target = _resolve_python(spec, relative, known)
+            elif kind == "js":
+                target = _resolve_js(spec, relative, known)
+            elif kind == "rust_mod":
+                target = _resolve_rust_mod(spec, relative, known)
+            elif kind == "rust_use":
+                target = _resolve_rust_use(spec, relative, known)
+            else:
+                target = _resolve_go(spec, relative, known, go_module)
+            if target is not None:
+                edges.add((relative, target))
+
+    fan_out: dict[str, int] = {f: 0 for f in files}
+    fan_in: dict[str, int] = {f: 0 for f in files}
+    for importer, imported in edges:
+        fan_out[importer] += 1
+        fan_in[imported] += 1
+
+    nodes = tuple(
+        ModuleNode(
+            path=relative,
+            language=_LANGUAGE_BY_SUFFIX[Path(relative).suffix],
+            fan_in=fan_in[relative],
+            fan_out=fan_out[relative],
+        )
+        for relative in files
+    )
+    return DependencyGraph(nodes=nodes, edges=frozenset(edges))
+
+
+def _tarjan_sccs(vertices: list[str], adjacency: dict[str, list[str]]) -> list[list[str]]:
+    """Iterative Tarjan — recursion would overflow on deep import chains."""
+    index: dict[str, int] = {}
+    lowlink: dict[str, int] = {}
+    on_stack: set[str] = set()
+    stack: list[str] = []
+    sccs: list[list[str]] = []
+    counter = 0
+
+    for root in vertices:
+        if root in index:
+            continue
+        work = [(root, iter(adjacency.get(root, [])))]
+        index[root] = lowlink[root] = counter
+        counter += 1
+        stack.append(root)
+        on_stack.add(root)
+        while work:
+            vertex, successors = work[-1]
+            advanced = False
+            for successor in successors:
+                if successor not in index:
+                    index[successor] = lowlink[successor] = counter
+                    counter += 1
+                    stack.append(successor)
+                    on_stack.add(successor)
+                    work.append((successor, iter(adjacency.get(successor, []))))
+                    advanced = True
+                    break
+                if successor in on_stack:
+                    lowlink[vertex] = min(lowlink[vertex], index[successor])
+            if advanced:
+                continue
+            work.pop()
+            if work:
+                parent = work[-1][0]
+                lowlink[parent] = min(lowlink[parent], lowlink[vertex])
+            if lowlink[vertex] == index[vertex]:
+                component = []
+                while True:
+                    member = stack.pop()
+                    on_stack.discard(member)
+                    component.append(member)
+                    if member == vertex:
+                        break
+                sccs.append(component)
+    return sccs
+
+
+def find_cycles(graph: DependencyGraph) -> list[DependencyCycle]:
+    """Return dependency cycles, largest first.
+
+    A cycle is a strongly connected component with more than one member,
+    or a single file that imports itself.
+    """
+    adjacency: dict[str, list[str]] = {}
+    for importer, imported in sorted(graph.edges):
+        adjacency.setdefault(importer, []).append(imported)
+    vertices = [node.path for node in graph.nodes]
+
+    cycles = []
+    for component in _tarjan_sccs(vertices, adjacency):
+        members = tuple(sorted(component))
+        if len(members) == 1 and (members[0], members[0]) not in graph.edges:
+            continue
+        member_set = set(members)
+        internal = sum(
+            1 for importer, imported in graph.edges
+            if importer in member_set and imported in member_set
+        )
+        cycles.append(DependencyCycle(members=members, internal_edges=internal))
+    return sorted(cycles, key=lambda cycle: (-cycle.size, cycle.members))
@@ -0,0 +1,180 @@
+"""
+Per-function test coverage joined with complexity.
+
+Parses a coverage report (lcov, Cobertura XML, or llvm-cov export JSON)
+into per-file line-hit maps and intersects them with lizard's function
+line ranges, yielding coverage per function. The payoff is the classic
+risk quadrant: high-CCN functions with low coverage, where a bug is both
+likely and untested for.
+
+The coverage-ingest tool persists file-level coverage to the landing
+zone; this module re-reads the raw report instead because the function
+join needs line-level hits, which are not stored.
+
+Format detection is by extension (``.info``/``.lcov``, ``.xml``,
+``.json``). Report paths are normalized repo-relative before joining, so
+absolute paths emitted by instrumentation match lizard's paths.
+"""
+
+from __future__ import annotations
+
+import json
+import xml.etree.ElementTree as ET
+from dataclasses import dataclass
+from pathlib import Path, PurePosixPath
+
+RISK_MIN_CCN = 10
+RISK_MAX_COVERAGE_PCT = 50.0
+
+
+@dataclass(frozen=True)
+class FunctionCoverage:
+    """Coverage for one function, from the line hits inside its range."""
+
+    file: str
+    function_name: str
+    ccn: int
+    line_start: int
+    line_end: int
+    lines_instrumented: int
+    lines_covered: int
+
+    @property
+    def coverage_pct(self) -> float | None:
+        """Covered share of instrumented lines; None when uninstrumented."""
+        if self.lines_instrumented == 0:
+            return None
+        return round(100.0 * self.lines_covered / self.lines_instrumented, 1)
+
+
+def _normalize(path: str, repo_path: Path | None) -> str:
+    """Make a coverage report path repo-relative with POSIX separators."""
+    candidate = path.replace("\\", "/")
+    if repo_path is not None:
+        repo = repo_path.resolve().as_posix().rstrip("/") + "/"
+        if candidate.startswith(repo):
+            candidate = candidate[len(repo):]
+    if candidate.startswith("./"):
+        candidate = candidate[2:]
+    return str(PurePosixPath(candidate))
+
+
+def _parse_lcov(text: str, repo_path: Path | None) -> dict[str, dict[int, int]]:
+    hits: dict[str, dict[int, int]] = {}
+    current: dict[int, int] | None = None
+    for line in text.splitlines():
+        line = line.strip()
+        if line.startswith("SF:"):
+            current = hits.setdefault(_normalize(line[3:], repo_path), {})
+        elif line.startswith("DA:") and current is not None:
+            line_no, _, count = line[3:].partition(",")
+            current[int(line_no)] = max(current.get(int(line_no), 0), int(count.split(",")[0]))
+        elif line == "end_of_record":
+            current = None
+    return hits
+
+
+def _parse_cobertura(text: str, repo_path: Path | None) -> dict[str, dict[int, int]]:
+    hits: dict[str, dict[int, int]] = {}
+    root = ET.fromstring(text)
+    for cls in root.iter("class"):
+        filename = cls.get("filename")
+        if not filename:
+            continue
+        file_hits = hits.setdefault(_normalize(filename, repo_path), {})
+        for line in cls.iter("line"):
+            number = int(line.get("number", 0))
+            file_hits[number] = max(file_hits.get(number, 0), int(line.get("hits", 0)))
+    return hits
+
+
+def _parse_llvm_cov(text: str, repo_path: Path | None) -> dict[str, dict[int, int]]:
+    # llvm-cov export JSON: data[].files[].segments are
+    # [line, col, count, has_count, is_region_entry, ...]; region-entry
+    # segments with a count give per-line hits.
+    hits: dict[str, dict[int, int]] = {}
+    for export in json.loads(text).get("data", []):
+        for entry in export.get("files", []):
+            file_hits = hits.setdefault(_normalize(entry["filename"], repo_path), {})
+            for segment in entry.get("segments", []):
+                line_no, _, count, has_count, is_region_entry = segment[:5]
+                if has_count and is_region_entry:
+                    file_hits[int(line_no)] = max(file_hits.get(int(line_no), 0), int(count))
+    return hits
+
+
+def parse_coverage(
+    report_path: Path, repo_path: Path | None = None
+) -> dict[str, dict[int, int]]:
+    """Parse a coverage report into ``{relative_path: {line: hits}}``.
+
+    Raises ``ValueError`` for an unrecognized report extension.
+    """
+    suffix = report_path.suffix.lower()
+    text = report_path.read_text()
+    if suffix in (".info", ".lcov"):
+        return _parse_lcov(text, repo_path)
+    if suffix == ".xml":
+        return _parse_cobertura(text, repo_path)
+    if suffix == ".json":
+        return _parse_llvm_cov(text, repo_path)
+    raise ValueError(f"unrecognized coverage format: {report_path.name}")
+
+
+def join_function_coverage(
+    function_rows: list[dict],
+    coverage: dict[str, dict[int, int]],
+) -> list[FunctionCoverage]:
+    """Intersect lizard function ranges with per-line hits.
+
+    ``function_rows`` are lizard_functions query rows (file, function_name,
+    ccn, line_start, line_end). Functions in files absent from the report
+    are skipped entirely — no instrumentation is not the same as 0%.
+    """
+    joined = []
+    for row in function_rows:
+        file_hits = coverage.get(row["file"])
+        if file_hits is None:
+            continue
+        line_start = int(row.get("line_start") or 0)
+        line_end = int(row.get("line_end") or 0)
+        in_range = [
+            hit
+            for line, hit in file_hits.items()
+            if line_start <= line <= line_end
+        ]
+        joined.append(
+            FunctionCoverage(
+                file=row["file"],
+                function_name=row["function_name"],
+                ccn=int(row.get("ccn") or 0),
+                line_start=line_start,
+                line_end=line_end,
+                lines_instrumented=len(in_range),
+                lines_covered=sum(1 for hit in in_range if hit > 0),
+            )
+        )
+    return joined
+
+
+def risk_quadrant(
+    functions: list[FunctionCoverage],
+    min_ccn: int = RISK_MIN_CCN,
+    max_coverage_pct: float = RISK_MAX_COVERAGE_PCT,
+) -> list[FunctionCoverage]:
+    """High-CCN, low-coverage functions, riskiest first.
+
+    Sorted by coverage ascending then CCN descending, so the worst corner
+    of the quadrant leads the report.
+    """
+    risky = [
+        function
+        for function in functions
+        if function.ccn >= min_ccn
+        and function.coverage_pct is not None
+        and function.coverage_pct < max_coverage_pct
+    ]
+    return sorted(
+        risky,
+        key=lambda function: (function.coverage_pct, -function.ccn, function.file),
+    )
@@ -0,0 +1,169 @@
+"""
+Ad-hoc queries over per-function complexity records.
+
+Backs the ``insights functions`` command: a small ``--where`` expression
+language ("ccn > 20 and file ~ src/") is parsed into conditions validated
+against a column whitelist, then rendered into SQL over the
+``lz_lizard_function_metrics`` landing zone table. This lets users filter
+and sort function-level metrics beyond the fixed report views without
+writing SQL themselves.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+
+# Query-facing column names mapped to SQL expressions in the functions query.
+COLUMNS: dict[str, str] = {
+    "name": "fm.function_name",
+    "long_name": "fm.long_name",
+    "file": "lf.relative_path",
+    "ccn": "fm.ccn",
+    "nloc": "fm.nloc",
+    "params": "fm.params",
+    "nesting": "fm.max_nesting_depth",
+    "token_count": "fm.token_count",
+    "line_start": "fm.line_start",
+    "line_end": "fm.line_end",
+}
+
+# Longest operators first so <= is not consumed as <.
+OPERATORS = ("<=", ">=", "!=", "=", "<", ">", "~")
+
+_CLAUSE_PATTERN = re.compile(
+    r"^\s*(\w+)\s*(" + "|".join(re.escape(op) for op in OPERATORS) + r")\s*(.+?)\s*$"
+)
+
+
+@dataclass(frozen=True)
+class Condition:
+    """One parsed filter clause: ``column operator value``."""
+
+    column: str  # query-facing name, validated against COLUMNS
+    operator: str
+    value: str | int | float
+
+    def __post_init__(self) -> None:
+        if self.column not in COLUMNS:
+            raise ValueError(
+                f"unknown column: {self.column} "
+                f"(expected one of: {', '.join(sorted(COLUMNS))})"
+            )
+        if self.operator not in OPERATORS:
+            raise ValueError(f"unknown operator: {self.operator}")
+
+
+def _parse_value(raw: str) -> str | int | float:
+    """Interpret a clause value as a number or a (possibly quoted) string."""
+    if len(raw) >= 2 and raw[0] == raw[-1] and raw[0] in ("'", '"'):
+        return raw[1:-1]
+    try:
+        return int(raw)
+    except ValueError:
+        pass
+    try:
+        return float(raw)
+    except ValueError:
+        return raw
+
+
+def parse_where(expression: str | None) -> list[Condition]:
+    """Parse a ``--where`` expression into conditions.
+
+    Clauses are joined with ``and``; each clause is ``column op value``
+    where op is one of <=, >=, !=, =, <, >, or ~ (substring match).
+    Raises ValueError for malformed clauses or unknown columns.
+    """
+    if expression is None or not expression.strip():
+        return []
+
+    conditions = []
+    for clause in re.split(r"\s+and\s+", expression, flags=re.IGNORECASE):
+        match = _CLAUSE_PATTERN.match(clause)
+        if not match:
+            raise ValueError(
+                f"cannot parse clause: {clause.strip()!r} "
+                f"(expected: column operator value)"
+            )
+        conditions.append(
+            Condition(
+                column=match.group(1),
+                operator=match.group(2),
+                value=_parse_value(match.group(3)),
+            )
+        )
+    return conditions
+
+
+def _render_value(value: str | int | float) -> str:
+    if isinstance(value, (int, float)):
+        return str(value)
+    return "'" + value.replace("'", "''") + "'"
+
+
+def _render_condition(condition: Condition) -> str:
+    column_sql = COLUMNS[condition.column]
+    if condition.operator == "~":
+        pattern = str(condition.value).replace("'", "''")
+        return f"{column_sql} LIKE '%{pattern}%'"
+    return f"{column_sql} {condition.operator} {_render_value(condition.value)}"
+
+
+def build_functions_sql(
+    run_pk: int,
+    conditions: list[Condition],
+    sort: str = "ccn",
+    descending: bool = True,
+    limit: int = 30,
+) -> str:
+    """Render the functions query for a collection run.
+
+    Resolves the lizard and layout run_pks from the anchor run_pk (the
+    same run_map pattern the fixed queries use), joins layout for
+    repo-relative paths, and applies the parsed conditions.
+    """
+    if sort not in COLUMNS:
+        raise ValueError(
+            f"unknown sort column: {sort} "
+            f"(expected one of: {', '.join(sorted(COLUMNS))})"
+        )
+
+    where_clauses = [_render_condition(condition) for condition in conditions]
+    where_sql = " AND ".join(where_clauses) if where_clauses else "TRUE"
+    direction = "ASC" if not descending else "DESC"
+
+    return f"""
+WITH run_map AS (
+    SELECT
+        tr_lizard.run_pk AS lizard_run_pk,
+        tr_layout.run_pk AS layout_run_pk
+    FROM lz_tool_runs tr_anchor
+    LEFT JOIN lz_tool_runs tr_lizard
+        ON tr_lizard.collection_run_id = tr_anchor.collection_run_id
+        AND tr_lizard.tool_name = 'lizard'
+    LEFT JOIN lz_tool_runs tr_layout
+        ON tr_layout.collection_run_id = tr_anchor.collection_run_id
+        AND tr_layout.tool_name IN ('layout', 'layout-scanner')
+    WHERE tr_anchor.run_pk = {int(run_pk)}
+)
+SELECT
+    lf.relative_path AS file,
+    fm.function_name AS name,
+    fm.long_name,
+    fm.ccn,
+    fm.nloc,
+    fm.params,
+    fm.max_nesting_depth AS nesting,
+    fm.token_count,
+    fm.line_start,
+    fm.line_end
+FROM lz_lizard_function_metrics fm
+JOIN run_map rm ON fm.run_pk = rm.lizard_run_pk
+LEFT JOIN lz_layout_files lf
+    ON lf.run_pk = rm.layout_run_pk
+    AND lf.file_id = fm.file_id
+WHERE {where_sql}
+ORDER BY {COLUMNS[sort]} {direction} NULLS LAST
+LIMIT {int(limit)}
+"""
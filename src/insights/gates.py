@@ -0,0 +1,556 @@
+"""
+Quality gates evaluated against a collection run.
+
+A gate is a named pass/fail check with a configurable limit; a failing
+blocking gate should fail the surrounding pipeline (the `insights gates`
+CLI command exits non-zero). The first gate is ``no_fixable_criticals``:
+the run must not contain trivy vulnerabilities that are both critical and
+fixable (a fix version is published), because those are pure upgrade work
+with no excuse to ship.
+
+Gate configuration lives in ``[gates]`` in ``caldera.toml``; missing keys
+fall back to the defaults below so gates are always evaluable.
+
+Beyond the fixed gates, ``[[gates.policies]]`` entries define policy-as-code
+gates: each carries a ``where`` expression in the shared ``--filter`` grammar
+(see :mod:`common.record_filter`), evaluated against the run's unified
+finding records, and a ``max_count`` the matches may not exceed — e.g.
+``where = "severity = HIGH AND path GLOB 'src/payments/**'"`` with
+``max_count = 0``. Expressions are parsed at load time so a policy typo
+fails the pipeline loudly instead of silently never matching.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import tomllib
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from common.record_filter import FilterError, apply_filter, parse_filter
+
+COMPLEXITY_GATE_NAME = "complexity_delta"
+NEW_CLONE_GATE_NAME = "no_new_clones"
+POLICY_GATES_KEY = "policies"
+
+DEFAULT_GATES: dict[str, dict[str, Any]] = {
+    "no_fixable_criticals": {
+        "enabled": True,
+        "severities": ["CRITICAL"],
+        "max_count": 0,
+    },
+    # The deny list itself lives in [license_policy]; severities here is the
+    # severity attributed to a denied-license finding in gate output.
+    "no_denied_licenses": {
+        "enabled": True,
+        "severities": ["CRITICAL"],
+        "max_count": 0,
+    },
+    # Per-function gate: fails a PR when a changed function's CCN grows by
+    # more than max_ccn_increase, or crosses the max_ccn ceiling.
+    COMPLEXITY_GATE_NAME: {
+        "enabled": True,
+        "max_ccn_increase": 5,
+        "max_ccn": 15,
+    },
+    # Fails only on clones introduced by the PR (absent from the base run)
+    # of at least min_lines, so legacy duplication never blocks unrelated
+    # changes.
+    NEW_CLONE_GATE_NAME: {
+        "enabled": True,
+        "min_lines": 10,
+    },
+}
+
+
+@dataclass(frozen=True)
+class GateConfig:
+    """Configuration for one gate."""
+
+    name: str
+    enabled: bool
+    severities: tuple[str, ...]
+    max_count: int
+
+    def __post_init__(self) -> None:
+        if self.max_count < 0:
+            raise ValueError("max_count must be >= 0")
+        if not self.severities:
+            raise ValueError("severities must not be empty")
+
+
+@dataclass(frozen=True)
+class ComplexityGateConfig:
+    """Configuration for the per-function complexity delta gate."""
+
+    enabled: bool
+    max_ccn_increase: int
+    max_ccn: int
+
+    def __post_init__(self) -> None:
+        if self.max_ccn_increase <= 0:
+            raise ValueError("max_ccn_increase must be > 0")
+        if self.max_ccn <= 0:
+            raise ValueError("max_ccn must be > 0")
+
+
+@dataclass(frozen=True)
+class CloneGateConfig:
+    """Configuration for the new-clone gate."""
+
+    enabled: bool
+    min_lines: int
+
+    def __post_init__(self) -> None:
+        if self.min_lines <= 0:
+            raise ValueError("min_lines must be > 0")
+
+
+@dataclass(frozen=True)
+class PolicyGateConfig:
+    """Configuration for one user-defined policy gate."""
+
+    name: str
+    enabled: bool
+    where: str  # shared --filter expression over finding records
+    max_count: int
+
+    def __post_init__(self) -> None:
+        if not self.name:
+            raise ValueError("policy gate needs a name")
+        if self.max_count < 0:
+            raise ValueError("max_count must be >= 0")
+        try:
+            parse_filter(self.where)
+        except FilterError as exc:
+            raise ValueError(f"policy gate {self.name!r}: {exc}") from exc
+
+
+@dataclass(frozen=True)
+class GateResult:
+    """Outcome of evaluating one gate."""
+
+    name: str
+    passed: bool
+    actual: int
+    limit: int
+    message: str
+    offenders: tuple[str, ...] = field(default_factory=tuple)
+
+
+def load_gates_config(caldera_toml: Path | None = None) -> list[GateConfig]:
+    """Load gate configurations from caldera.toml, falling back to defaults.
+
+    Reads the ``[gates.<name>]`` tables. Unknown gate names are rejected so
+    a typo fails loudly instead of silently never enforcing anything.
+    """
+    merged: dict[str, dict[str, Any]] = {name: dict(cfg) for name, cfg in DEFAULT_GATES.items()}
+    if caldera_toml is not None and caldera_toml.exists():
+        config = tomllib.loads(caldera_toml.read_text())
+        for name, overrides in config.get("gates", {}).items():
+            if name == POLICY_GATES_KEY:
+                continue  # user-defined policy gates; see load_policy_gates
+            if name not in merged:
+                raise ValueError(f"unknown gate: {name}")
+            merged[name].update(overrides)
+    return [
+        GateConfig(
+            name=name,
+            enabled=cfg["enabled"],
+            severities=tuple(s.upper() for s in cfg["severities"]),
+            max_count=cfg["max_count"],
+        )
+        # The complexity and clone delta gates have their own config
+        # shapes; see load_complexity_gate_config / load_clone_gate_config.
+        for name, cfg in merged.items()
+        if name not in (COMPLEXITY_GATE_NAME, NEW_CLONE_GATE_NAME)
+    ]
+
+
+def load_clone_gate_config(caldera_toml: Path | None = None) -> CloneGateConfig:
+    """Load ``[gates.no_new_clones]``, falling back to the defaults."""
+    merged = dict(DEFAULT_GATES[NEW_CLONE_GATE_NAME])
+    if caldera_toml is not None and caldera_toml.exists():
+        config = tomllib.loads(caldera_toml.read_text())
+        merged.update(config.get("gates", {}).get(NEW_CLONE_GATE_NAME, {}))
+    return CloneGateConfig(
+        enabled=merged["enabled"],
+        min_lines=int(merged["min_lines"]),
+    )
+
+
+def load_complexity_gate_config(caldera_toml: Path | None = None) -> ComplexityGateConfig:
+    """Load ``[gates.complexity_delta]``, falling back to the defaults."""
+    merged = dict(DEFAULT_GATES[COMPLEXITY_GATE_NAME])
+    if caldera_toml is not None and caldera_toml.exists():
+        config = tomllib.loads(caldera_toml.read_text())
+        merged.update(config.get("gates", {}).get(COMPLEXITY_GATE_NAME, {}))
+    return ComplexityGateConfig(
+        enabled=merged["enabled"],
+        max_ccn_increase=int(merged["max_ccn_increase"]),
+        max_ccn=int(merged["max_ccn"]),
+    )
+
+
+def load_policy_gates(caldera_toml: Path | None = None) -> list[PolicyGateConfig]:
+    """Load the ``[[gates.policies]]`` entries; empty when none configured.
+
+    Each policy's ``where`` expression is parsed here, so an invalid policy
+    raises ``ValueError`` at load time rather than passing every run.
+    """
+    if caldera_toml is None or not caldera_toml.exists():
+        return []
+    config = tomllib.loads(caldera_toml.read_text())
+    policies = []
+    for entry in config.get("gates", {}).get(POLICY_GATES_KEY, []):
+        if "name" not in entry or "where" not in entry:
+            raise ValueError("each [[gates.policies]] entry needs 'name' and 'where'")
+        policies.append(
+            PolicyGateConfig(
+                name=entry["name"],
+                enabled=entry.get("enabled", True),
+                where=entry["where"],
+                max_count=int(entry.get("max_count", 0)),
+            )
+        )
+    return policies
+
+
+def evaluate_policy_gate(
+    findings: list[dict], config: PolicyGateConfig
+) -> GateResult:
+    """Count the findings matching the policy's ``where`` expression."""
+    matched = apply_filter(findings, parse_filter(config.where))
+    passed = len(matched) <= config.max_count
+    offenders = tuple(
+        f"{finding.get('path', '?')}:{finding.get('line', '?')} "
+        f"[{finding.get('tool', '?')}] {finding.get('rule', '?')}"
+        for finding in matched[:10]
+    )
+    return GateResult(
+        name=config.name,
+        passed=passed,
+        actual=len(matched),
+        limit=config.max_count,
+        message=(
+            f"{len(matched)} finding(s) match policy "
+            f"{config.where!r} (limit {config.max_count})"
+        ),
+        offenders=offenders if not passed else tuple(),
+    )
+
+
+def is_fixable(vulnerability: dict) -> bool:
+    """A vulnerability is fixable when a fix version is published."""
+    if vulnerability.get("fix_available"):
+        return True
+    fixed_version = vulnerability.get("fixed_version")
+    return bool(fixed_version and str(fixed_version).strip())
+
+
+def evaluate_no_fixable_criticals(
+    vulnerabilities: list[dict], config: GateConfig
+) -> GateResult:
+    """Evaluate the fixable-criticals gate over trivy vulnerability rows.
+
+    Each row needs ``severity``, ``fix_available``/``fixed_version``, and
+    ``vulnerability_id``/``package_name`` for the offender listing.
+    """
+    offenders = [
+        vulnerability
+        for vulnerability in vulnerabilities
+        if (vulnerability.get("severity") or "").upper() in config.severities
+        and is_fixable(vulnerability)
+    ]
+    actual = len(offenders)
+    passed = actual <= config.max_count
+    severities = "/".join(config.severities)
+    if passed:
+        message = f"{actual} fixable {severities} vulnerabilities (limit {config.max_count})"
+    else:
+        message = (
+            f"{actual} fixable {severities} vulnerabilities exceed limit {config.max_count}"
+        )
+    return GateResult(
+        name=config.name,
+        passed=passed,
+        actual=actual,
+        limit=config.max_count,
+        message=message,
+        offenders=tuple(
+            f"{v.get('vulnerability_id', '?')} in {v.get('package_name', '?')} "
+            f"({v.get('installed_version', '?')} -> {v.get('fixed_version', '?')})"
+            for v in offenders
+        ),
+    )
+
+
+def evaluate_no_denied_licenses(
+    license_violations: list, config: GateConfig
+) -> GateResult:
+    """Evaluate the denied-licenses gate over license policy violations.
+
+    The violations are ``LicenseFinding`` objects already matched against
+    the ``[license_policy]`` deny list (see ``license_scan``); this gate
+    only thresholds the count.
+    """
+    actual = len(license_violations)
+    passed = actual <= config.max_count
+    if passed:
+        message = f"{actual} denied-license findings (limit {config.max_count})"
+    else:
+        message = f"{actual} denied-license findings exceed limit {config.max_count}"
+    return GateResult(
+        name=config.name,
+        passed=passed,
+        actual=actual,
+        limit=config.max_count,
+        message=message,
+        offenders=tuple(
+            f"{violation.license} in {violation.subject} ({violation.source})"
+            for violation in license_violations
+        ),
+    )
+
+
+def match_functions(
+    base_functions: list[dict], head_functions: list[dict]
+) -> tuple[list[tuple[dict, dict]], list[dict]]:
+    """Pair head functions with their base-run counterparts.
+
+    Rows need ``file``, ``function_name``, ``long_name``, ``ccn``,
+    ``nloc``, and ``token_count`` (the ``lizard_functions`` query shape).
+    Matching runs in passes so renames and moves pair up instead of
+    counting as new high-CCN functions: exact (file, signature) first,
+    then signature alone (moved file), then (file, nloc, token_count)
+    for a rename in place. Returns the matched pairs and the head
+    functions with no counterpart.
+    """
+    pairs: list[tuple[dict, dict]] = []
+    remaining_base = list(base_functions)
+    remaining_head = list(head_functions)
+
+    def _signature(function: dict) -> str:
+        return function.get("long_name") or function["function_name"]
+
+    for key_fn in (
+        lambda f: (f["file"], _signature(f)),
+        _signature,
+        lambda f: (f["file"], f.get("nloc"), f.get("token_count")),
+    ):
+        base_index: dict[Any, list[dict]] = {}
+        for function in remaining_base:
+            base_index.setdefault(key_fn(function), []).append(function)
+        unmatched_head = []
+        for function in remaining_head:
+            candidates = base_index.get(key_fn(function))
+            if candidates:
+                pairs.append((candidates.pop(0), function))
+            else:
+                unmatched_head.append(function)
+        matched = {id(base) for base, _ in pairs}
+        remaining_base = [f for f in remaining_base if id(f) not in matched]
+        remaining_head = unmatched_head
+    return pairs, remaining_head
+
+
+def evaluate_complexity_delta(
+    base_functions: list[dict],
+    head_functions: list[dict],
+    config: ComplexityGateConfig,
+) -> GateResult:
+    """Evaluate the per-function complexity delta gate.
+
+    A matched function fails when its CCN grew by more than
+    ``max_ccn_increase`` or crossed the ``max_ccn`` ceiling; a new
+    function fails only when it is born above the ceiling. Functions
+    already above the ceiling that did not get worse are left to the
+    absolute reports — this gate polices regressions.
+    """
+    pairs, added = match_functions(base_functions, head_functions)
+    offenders = []
+    for base, head in pairs:
+        base_ccn = base.get("ccn") or 0
+        head_ccn = head.get("ccn") or 0
+        increase = head_ccn - base_ccn
+        location = f"{head['function_name']} in {head['file']}"
+        if increase > config.max_ccn_increase:
+            offenders.append(
+                f"{location}: CCN {base_ccn} -> {head_ccn} "
+                f"(+{increase}, limit +{config.max_ccn_increase})"
+            )
+        elif base_ccn <= config.max_ccn < head_ccn:
+            offenders.append(
+                f"{location}: CCN {base_ccn} -> {head_ccn} crosses ceiling {config.max_ccn}"
+            )
+    for function in added:
+        ccn = function.get("ccn") or 0
+        if ccn > config.max_ccn:
+            offenders.append(
+                f"{function['function_name']} in {function['file']}: "
+                f"new function with CCN {ccn} exceeds ceiling {config.max_ccn}"
+            )
+    actual = len(offenders)
+    passed = actual == 0
+    if passed:
+        message = (
+            f"no function grew past +{config.max_ccn_increase} CCN "
+            f"or ceiling {config.max_ccn}"
+        )
+    else:
+        message = f"{actual} function(s) regressed past the complexity limits"
+    return GateResult(
+        name=COMPLEXITY_GATE_NAME,
+        passed=passed,
+        actual=actual,
+        limit=0,
+        message=message,
+        offenders=tuple(offenders),
+    )
+
+
+def group_clone_rows(rows: list[dict]) -> list[dict]:
+    """Group occurrence-level clone rows (the ``pmd_cpd_clones`` query
+    shape) into one dict per clone with its occurrence list."""
+    clones: dict[str, dict] = {}
+    for row in rows:
+        clone = clones.setdefault(
+            row["clone_id"],
+            {
+                "clone_id": row["clone_id"],
+                "lines": row["lines"],
+                "tokens": row["tokens"],
+                "code_fragment": row.get("code_fragment"),
+                "occurrences": [],
+            },
+        )
+        clone["occurrences"].append(
+            {
+                "path": row["relative_path"],
+                "line_start": row.get("line_start"),
+                "line_end": row.get("line_end"),
+            }
+        )
+    return list(clones.values())
+
+
+def _clone_identity(clone: dict) -> tuple:
+    """Content-based clone identity, stable across runs and line shifts.
+
+    Keyed on size plus the whitespace-normalized fragment, so legacy
+    clones whose code merely moved still match their base-run selves;
+    without a stored fragment the sorted occurrence paths stand in.
+    """
+    fragment = clone.get("code_fragment")
+    if fragment:
+        normalized = "\n".join(
+            line.strip() for line in fragment.splitlines() if line.strip()
+        )
+        digest = hashlib.md5(normalized.encode("utf-8")).hexdigest()
+        return (clone["lines"], clone["tokens"], digest)
+    paths = tuple(sorted(o["path"] for o in clone["occurrences"]))
+    return (clone["lines"], clone["tokens"], paths)
+
+
+def evaluate_no_new_clones(
+    base_clones: list[dict],
+    head_clones: list[dict],
+    config: CloneGateConfig,
+    changed_files: set[str] | None = None,
+) -> GateResult:
+    """Evaluate the new-clone gate between a base and head run.
+
+    Only clones absent from the base run count, and only at or above
+    ``min_lines``; with ``changed_files`` given, a new clone must also
+    touch a changed file — so pre-existing duplication elsewhere in the
+    repo never blocks an unrelated PR.
+    """
+    base_keys = {_clone_identity(clone) for clone in base_clones}
+    offenders = []
+    for clone in head_clones:
+        if _clone_identity(clone) in base_keys:
+            continue
+        if (clone["lines"] or 0) < config.min_lines:
+            continue
+        paths = sorted({o["path"] for o in clone["occurrences"]})
+        if changed_files is not None and not any(p in changed_files for p in paths):
+            continue
+        offenders.append(
+            f"clone {clone['clone_id']}: {clone['lines']} lines x "
+            f"{len(clone['occurrences'])} occurrences in {', '.join(paths)}"
+        )
+    actual = len(offenders)
+    passed = actual == 0
+    if passed:
+        message = f"no new clones of {config.min_lines}+ lines introduced"
+    else:
+        message = f"{actual} new clone(s) of {config.min_lines}+ lines introduced"
+    return GateResult(
+        name=NEW_CLONE_GATE_NAME,
+        passed=passed,
+        actual=actual,
+        limit=0,
+        message=message,
+        offenders=tuple(offenders),
+    )
+
+
+def run_gates(
+    vulnerabilities: list[dict],
+    configs: list[GateConfig] | None = None,
+    license_violations: list | None = None,
+    complexity_functions: tuple[list[dict], list[dict]] | None = None,
+    complexity_config: ComplexityGateConfig | None = None,
+    clone_sets: tuple[list[dict], list[dict]] | None = None,
+    clone_config: CloneGateConfig | None = None,
+    changed_files: set[str] | None = None,
+    findings: list[dict] | None = None,
+    policy_configs: list[PolicyGateConfig] | None = None,
+) -> list[GateResult]:
+    """Evaluate all enabled gates and return their results.
+
+    Gates whose inputs were not collected are skipped rather than trivially
+    passed: the license gate only runs when ``license_violations`` is given
+    (i.e. a license scan actually happened), the complexity delta gate
+    only when ``complexity_functions`` carries the (base, head) function
+    rows, and policy gates only when ``findings`` carries the run's
+    unified finding records.
+    """
+    configs = configs if configs is not None else load_gates_config()
+    results = []
+    for config in configs:
+        if not config.enabled:
+            continue
+        if config.name == "no_fixable_criticals":
+            results.append(evaluate_no_fixable_criticals(vulnerabilities, config))
+        elif config.name == "no_denied_licenses" and license_violations is not None:
+            results.append(evaluate_no_denied_licenses(license_violations, config))
+    if complexity_functions is not None:
+        delta_config = (
+            complexity_config
+            if complexity_config is not None
+            else load_complexity_gate_config()
+        )
+        if delta_config.enabled:
+            base, head = complexity_functions
+            results.append(evaluate_complexity_delta(base, head, delta_config))
+    if clone_sets is not None:
+        gate_config = (
+            clone_config if clone_config is not None else load_clone_gate_config()
+        )
+        if gate_config.enabled:
+            base, head = clone_sets
+            results.append(
+                evaluate_no_new_clones(base, head, gate_config, changed_files)
+            )
+    if findings is not None and policy_configs:
+        for policy in policy_configs:
+            if policy.enabled:
+                results.append(evaluate_policy_gate(findings, policy))
+    return results
+
+
+def gates_passed(results: list[GateResult]) -> bool:
+    """True when every evaluated gate passed."""
+    return all(result.passed for result in results)
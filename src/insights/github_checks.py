@@ -0,0 +1,200 @@
+"""GitHub Checks API integration: findings as inline annotations.
+
+Beyond PR comments, a native check run puts findings directly in the
+Files Changed view: ``insights github-check`` creates a check run for the
+analyzed commit, streams annotations in API-sized batches (prioritized by
+severity and capped so a noisy run cannot flood the UI), and sets the
+conclusion from the gate engine's verdict.
+
+The token comes from the ``GITHUB_TOKEN`` environment variable (the
+default token of a GitHub Actions job is sufficient), never from config.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import urllib.error
+import urllib.request
+from dataclasses import dataclass
+
+from insights.gates import GateResult
+from insights.notifications import SEVERITY_ORDER, severity_rank
+
+CHECK_NAME = "caldera"
+API_ROOT = "https://api.github.com"
+
+# GitHub accepts at most 50 annotations per update request; we also cap
+# the total so a pathological run does not drown the Files Changed view.
+ANNOTATION_BATCH_SIZE = 50
+MAX_ANNOTATIONS = 200
+
+_LEVEL_BY_SEVERITY = {
+    "CRITICAL": "failure",
+    "HIGH": "failure",
+    "MEDIUM": "warning",
+    "LOW": "notice",
+    "INFO": "notice",
+}
+
+
+class CheckRunError(RuntimeError):
+    """GitHub Checks API call failed."""
+
+
+@dataclass(frozen=True)
+class CheckRunReport:
+    """What was published for one check run."""
+
+    check_run_id: int
+    conclusion: str
+    annotation_count: int
+    truncated: int
+
+    def to_dict(self) -> dict:
+        return {
+            "check_run_id": self.check_run_id,
+            "conclusion": self.conclusion,
+            "annotation_count": self.annotation_count,
+            "truncated": self.truncated,
+        }
+
+
+def build_annotations(findings: list[dict], cap: int = MAX_ANNOTATIONS) -> tuple[list[dict], int]:
+    """Findings as GitHub annotations, worst severity first, capped.
+
+    Returns (annotations, truncated_count).
+    """
+    prioritized = sorted(
+        findings,
+        key=lambda f: (-severity_rank(str(f.get("severity", "INFO"))), str(f.get("relative_path", ""))),
+    )
+    annotations = []
+    for finding in prioritized[:cap]:
+        severity = str(finding.get("severity", "INFO")).upper()
+        line = int(finding.get("line_start") or finding.get("line_number") or 1)
+        annotations.append({
+            "path": finding.get("relative_path", ""),
+            "start_line": line,
+            "end_line": int(finding.get("line_end") or line),
+            "annotation_level": _LEVEL_BY_SEVERITY.get(severity, "warning"),
+            "title": f"{finding.get('tool', '?')}: {finding.get('rule_id', '?')}",
+            "message": str(finding.get("message") or finding.get("rule_id") or "finding"),
+        })
+    return annotations, max(len(prioritized) - cap, 0)
+
+
+def conclusion_from_gates(gate_results: list[GateResult]) -> str:
+    """Check conclusion: failure when any gate fails, neutral without gates."""
+    if not gate_results:
+        return "neutral"
+    return "success" if all(result.passed for result in gate_results) else "failure"
+
+
+def build_summary(gate_results: list[GateResult], findings: list[dict], truncated: int) -> str:
+    """Markdown summary shown on the check run page."""
+    lines = []
+    by_severity = {severity: 0 for severity in SEVERITY_ORDER}
+    for finding in findings:
+        severity = str(finding.get("severity", "INFO")).upper()
+        if severity in by_severity:
+            by_severity[severity] += 1
+    counts = ", ".join(
+        f"{count} {severity.lower()}" for severity, count in by_severity.items() if count
+    )
+    lines.append(f"**{len(findings)} finding(s)**" + (f" ({counts})" if counts else ""))
+    if truncated:
+        lines.append(f"_{truncated} lower-severity finding(s) not annotated (cap {MAX_ANNOTATIONS})._")
+    if gate_results:
+        lines.append("")
+        lines.append("| Gate | Result | Detail |")
+        lines.append("|------|--------|--------|")
+        for result in gate_results:
+            verdict = "PASS" if result.passed else "FAIL"
+            lines.append(f"| {result.name} | {verdict} | {result.message} |")
+    return "\n".join(lines)
+
+
+def _default_transport(method: str, url: str, payload: dict | None) -> dict:
+    token = os.environ.get("GITHUB_TOKEN")
+    if not token:
+        raise CheckRunError("GITHUB_TOKEN is not set; a token with checks:write is required")
+    request = urllib.request.Request(
+        url,
+        data=json.dumps(payload).encode() if payload is not None else None,
+        method=method,
+        headers={
+            "Authorization": f"Bearer {token}",
+            "Accept": "application/vnd.github+json",
+            "Content-Type": "application/json",
+        },
+    )
+    try:
+        with urllib.request.urlopen(request, timeout=30) as response:
+            return json.loads(response.read() or b"{}")
+    except urllib.error.HTTPError as exc:
+        raise CheckRunError(f"{method} {url} failed: HTTP {exc.code} {exc.read()[:200]!r}") from exc
+    except urllib.error.URLError as exc:
+        raise CheckRunError(f"{method} {url} failed: {exc.reason}") from exc
+
+
+class ChecksClient:
+    """Minimal Checks API client; ``transport(method, url, payload)`` is injectable."""
+
+    def __init__(self, repo: str, transport=None) -> None:
+        if repo.count("/") != 1:
+            raise CheckRunError(f"repo must be owner/name, got: {repo!r}")
+        self._base = f"{API_ROOT}/repos/{repo}"
+        self._transport = transport or _default_transport
+
+    def create_check_run(self, head_sha: str, name: str = CHECK_NAME) -> int:
+        response = self._transport(
+            "POST",
+            f"{self._base}/check-runs",
+            {"name": name, "head_sha": head_sha, "status": "in_progress"},
+        )
+        return int(response["id"])
+
+    def stream_annotations(self, check_run_id: int, title: str, summary: str,
+                           annotations: list[dict]) -> int:
+        """PATCH annotations in API-sized batches; returns batch count."""
+        batches = [
+            annotations[start:start + ANNOTATION_BATCH_SIZE]
+            for start in range(0, len(annotations), ANNOTATION_BATCH_SIZE)
+        ] or [[]]
+        for batch in batches:
+            self._transport(
+                "PATCH",
+                f"{self._base}/check-runs/{check_run_id}",
+                {"output": {"title": title, "summary": summary, "annotations": batch}},
+            )
+        return len(batches)
+
+    def complete(self, check_run_id: int, conclusion: str) -> None:
+        self._transport(
+            "PATCH",
+            f"{self._base}/check-runs/{check_run_id}",
+            {"status": "completed", "conclusion": conclusion},
+        )
+
+
+def publish_check_run(
+    client: ChecksClient,
+    head_sha: str,
+    findings: list[dict],
+    gate_results: list[GateResult],
+    name: str = CHECK_NAME,
+) -> CheckRunReport:
+    """Create, annotate, and complete one check run for a commit."""
+    annotations, truncated = build_annotations(findings)
+    conclusion = conclusion_from_gates(gate_results)
+    summary = build_summary(gate_results, findings, truncated)
+    check_run_id = client.create_check_run(head_sha, name=name)
+    client.stream_annotations(check_run_id, title=name, summary=summary, annotations=annotations)
+    client.complete(check_run_id, conclusion)
+    return CheckRunReport(
+        check_run_id=check_run_id,
+        conclusion=conclusion,
+        annotation_count=len(annotations),
+        truncated=truncated,
+    )
@@ -0,0 +1,195 @@
+"""
+Composite repository health score.
+
+Combines normalized complexity, duplication, security, size, and dead-code metrics into
+a single 0-100 score per directory and repo-wide. The formula is deliberately
+simple and reproducible:
+
+1. Each dimension is turned into a penalty in [0, 1] by dividing the raw
+   metric by a cap and clamping (e.g. mean CCN 20 with cap 20 -> penalty 1.0).
+2. Penalties are combined as a weighted average using the configured weights.
+3. ``health = round(100 * (1 - weighted_penalty), 1)``.
+
+Dimensions and their raw inputs:
+
+- ``complexity``   mean cyclomatic complexity (lizard CCN)
+- ``duplication``  duplicated-line percentage (pmd-cpd)
+- ``security``     severity-weighted findings per 1000 LOC
+- ``size``         p90 file length in LOC (scc)
+- ``dead_code``    likely-dead symbols per 1000 LOC (rust-deadcode)
+
+Weights and caps come from ``[health_score]`` in ``caldera.toml``; missing
+keys fall back to the defaults below so the score is always computable.
+"""
+
+from __future__ import annotations
+
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+from .config_overrides import resolve_for_path
+
+DEFAULT_WEIGHTS: dict[str, float] = {
+    "complexity": 0.35,
+    "duplication": 0.25,
+    "security": 0.25,
+    "size": 0.15,
+    "dead_code": 0.10,
+}
+
+# Raw metric value at which a dimension's penalty saturates at 1.0.
+DEFAULT_CAPS: dict[str, float] = {
+    "complexity": 20.0,  # mean CCN
+    "duplication": 30.0,  # duplicated-line %
+    "security": 10.0,  # severity-weighted findings per KLOC
+    "size": 1000.0,  # p90 file LOC
+    "dead_code": 20.0,  # dead symbols per KLOC
+}
+
+# Multipliers applied per finding when computing the security input.
+SEVERITY_WEIGHTS: dict[str, float] = {
+    "CRITICAL": 4.0,
+    "HIGH": 2.0,
+    "MEDIUM": 1.0,
+    "LOW": 0.5,
+}
+
+DIMENSIONS = tuple(DEFAULT_WEIGHTS)
+
+
+@dataclass(frozen=True)
+class HealthConfig:
+    """Weights and caps for the health score formula."""
+
+    weights: dict[str, float]
+    caps: dict[str, float]
+
+    def __post_init__(self) -> None:
+        for dimension in DIMENSIONS:
+            if dimension not in self.weights:
+                raise ValueError(f"missing weight for dimension: {dimension}")
+            if self.weights[dimension] < 0:
+                raise ValueError(f"weight for {dimension} must be >= 0")
+            if self.caps.get(dimension, 0) <= 0:
+                raise ValueError(f"cap for {dimension} must be > 0")
+        if sum(self.weights.values()) <= 0:
+            raise ValueError("weights must sum to a positive value")
+
+
+@dataclass(frozen=True)
+class HealthScore:
+    """Computed health score for one scope (directory or repo)."""
+
+    scope: str  # repo-relative directory path, or "." for repo-wide
+    score: float  # 0-100, higher is healthier
+    penalties: dict[str, float]  # per-dimension penalty in [0, 1]
+
+
+def load_health_config(
+    caldera_toml: Path | None = None, scope: str | None = None
+) -> HealthConfig:
+    """Load weights/caps from caldera.toml, falling back to defaults.
+
+    Reads the ``[health_score.weights]`` and ``[health_score.caps]`` tables.
+    With a ``scope`` (repo-relative directory path), matching ``[overrides]``
+    entries are merged first, so e.g. ``tests/**`` can carry relaxed caps.
+    A missing file or missing keys yield the documented defaults.
+    """
+    weights = dict(DEFAULT_WEIGHTS)
+    caps = dict(DEFAULT_CAPS)
+    if caldera_toml is not None and caldera_toml.exists():
+        config = tomllib.loads(caldera_toml.read_text())
+        if scope is not None:
+            config = resolve_for_path(config, scope)
+        section = config.get("health_score", {})
+        weights.update(section.get("weights", {}))
+        caps.update(section.get("caps", {}))
+    return HealthConfig(weights=weights, caps=caps)
+
+
+def security_input(findings_by_severity: dict[str, int], total_loc: int) -> float:
+    """Severity-weighted findings per 1000 LOC."""
+    if total_loc <= 0:
+        return 0.0
+    weighted = sum(
+        SEVERITY_WEIGHTS.get(severity.upper(), 1.0) * count
+        for severity, count in findings_by_severity.items()
+    )
+    return weighted * 1000.0 / total_loc
+
+
+def _penalty(raw_value: float, cap: float) -> float:
+    if raw_value <= 0:
+        return 0.0
+    return min(raw_value / cap, 1.0)
+
+
+def compute_health_score(
+    scope: str,
+    metrics: dict[str, float],
+    config: HealthConfig | None = None,
+) -> HealthScore:
+    """Score one scope from its raw dimension inputs.
+
+    ``metrics`` maps dimension name to raw input (see module docstring);
+    missing dimensions contribute zero penalty, so sparse tool coverage
+    degrades gracefully rather than tanking the score.
+    """
+    config = config or HealthConfig(dict(DEFAULT_WEIGHTS), dict(DEFAULT_CAPS))
+    penalties = {
+        dimension: _penalty(metrics.get(dimension, 0.0), config.caps[dimension])
+        for dimension in DIMENSIONS
+    }
+    total_weight = sum(config.weights[dimension] for dimension in DIMENSIONS)
+    weighted_penalty = (
+        sum(config.weights[dimension] * penalties[dimension] for dimension in DIMENSIONS)
+        / total_weight
+    )
+    return HealthScore(
+        scope=scope,
+        score=round(100.0 * (1.0 - weighted_penalty), 1),
+        penalties=penalties,
+    )
+
+
+def compute_maintainability_score(
+    scope: str,
+    metrics: dict[str, float],
+    config: HealthConfig | None = None,
+) -> HealthScore:
+    """Score one scope on the non-security dimensions only.
+
+    Drops the ``security`` dimension and re-normalizes the remaining
+    weights, so together with :mod:`insights.security_score` the pair
+    partitions the health score: security and quality gates can then be
+    tuned independently without double-counting findings.
+    """
+    config = config or HealthConfig(dict(DEFAULT_WEIGHTS), dict(DEFAULT_CAPS))
+    dimensions = tuple(d for d in DIMENSIONS if d != "security")
+    penalties = {
+        dimension: _penalty(metrics.get(dimension, 0.0), config.caps[dimension])
+        for dimension in dimensions
+    }
+    total_weight = sum(config.weights[dimension] for dimension in dimensions)
+    weighted_penalty = (
+        sum(config.weights[dimension] * penalties[dimension] for dimension in dimensions)
+        / total_weight
+    )
+    return HealthScore(
+        scope=scope,
+        score=round(100.0 * (1.0 - weighted_penalty), 1),
+        penalties=penalties,
+    )
+
+
+def compute_directory_scores(
+    directory_metrics: dict[str, dict[str, float]],
+    config: HealthConfig | None = None,
+) -> list[HealthScore]:
+    """Score every directory, worst first."""
+    scores = [
+        compute_health_score(directory, metrics, config)
+        for directory, metrics in directory_metrics.items()
+    ]
+    return sorted(scores, key=lambda health: (health.score, health.scope))
@@ -0,0 +1,275 @@
+"""
+Jira issue sync for accepted findings.
+
+Keeps one Jira ticket per finding fingerprint (see ``codeclimate.fingerprint``):
+a new finding opens a ticket, a recurring one gets a recurrence comment, and
+a ticket whose finding disappeared is transitioned to done. Tickets are
+matched by a ``caldera-fp-<fingerprint>`` label, so the sync is stateless —
+Jira itself is the state store.
+
+Configuration lives in ``[jira]`` in ``caldera.toml`` (URL, project key,
+issue type, severity threshold, and optional custom field mapping);
+credentials come from the ``JIRA_USER``/``JIRA_TOKEN`` environment
+variables so they never land in the repo.
+
+The planning step (:func:`plan_sync`) is pure and the client takes an
+injectable transport, so everything is testable without a Jira instance.
+"""
+
+from __future__ import annotations
+
+import base64
+import json
+import os
+import tomllib
+import urllib.error
+import urllib.request
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from .codeclimate import fingerprint
+
+SYNC_LABEL = "caldera"
+FINGERPRINT_LABEL_PREFIX = "caldera-fp-"
+
+DEFAULT_ISSUE_TYPE = "Bug"
+DEFAULT_MIN_SEVERITY = "HIGH"
+DEFAULT_DONE_TRANSITION = "Done"
+REQUEST_TIMEOUT_SECONDS = 30
+
+_SEVERITY_ORDER = ("INFO", "LOW", "MEDIUM", "HIGH", "CRITICAL")
+
+
+class JiraSyncError(RuntimeError):
+    """A Jira request failed or the configuration is unusable."""
+
+
+@dataclass(frozen=True)
+class JiraConfig:
+    """Jira connection and mapping settings from ``[jira]``."""
+
+    url: str
+    project_key: str
+    issue_type: str = DEFAULT_ISSUE_TYPE
+    min_severity: str = DEFAULT_MIN_SEVERITY
+    done_transition: str = DEFAULT_DONE_TRANSITION
+    labels: tuple[str, ...] = ()
+    # Finding keys -> Jira field ids (e.g. severity = "customfield_10042").
+    fields: dict = field(default_factory=dict)
+
+    def __post_init__(self) -> None:
+        if not self.url.startswith(("http://", "https://")):
+            raise ValueError(f"Jira url must be http(s): {self.url!r}")
+        if not self.project_key:
+            raise ValueError("Jira project_key is required")
+        if self.min_severity not in _SEVERITY_ORDER:
+            raise ValueError(f"Unknown min_severity: {self.min_severity}")
+
+
+@dataclass(frozen=True)
+class SyncPlan:
+    """What the sync would do, before touching Jira."""
+
+    create: tuple[dict, ...]          # findings needing a new ticket
+    update: tuple[tuple[str, dict], ...]  # (issue_key, finding) recurrences
+    close: tuple[str, ...]            # issue keys whose finding disappeared
+
+
+def load_jira_config(caldera_toml: Path | None = None) -> JiraConfig | None:
+    """Load ``[jira]`` from caldera.toml; None when not configured."""
+    if caldera_toml is None or not caldera_toml.exists():
+        return None
+    config = tomllib.loads(caldera_toml.read_text())
+    jira = config.get("jira")
+    if not jira:
+        return None
+    return JiraConfig(
+        url=str(jira.get("url", "")).rstrip("/"),
+        project_key=str(jira.get("project_key", "")),
+        issue_type=str(jira.get("issue_type", DEFAULT_ISSUE_TYPE)),
+        min_severity=str(jira.get("min_severity", DEFAULT_MIN_SEVERITY)).upper(),
+        done_transition=str(jira.get("done_transition", DEFAULT_DONE_TRANSITION)),
+        labels=tuple(jira.get("labels", [])),
+        fields=dict(jira.get("fields", {})),
+    )
+
+
+def _severity_rank(severity: str | None) -> int:
+    try:
+        return _SEVERITY_ORDER.index((severity or "").upper())
+    except ValueError:
+        return -1
+
+
+def select_findings(findings: list[dict], config: JiraConfig) -> list[dict]:
+    """Findings at or above the configured severity threshold."""
+    threshold = _severity_rank(config.min_severity)
+    return [f for f in findings if _severity_rank(f.get("severity")) >= threshold]
+
+
+def plan_sync(findings: list[dict], open_issues: dict[str, str]) -> SyncPlan:
+    """Decide creates/updates/closes from current findings and open tickets.
+
+    ``open_issues`` maps fingerprint -> issue key for every open Caldera
+    ticket in the project. Duplicate fingerprints in the input collapse to
+    one ticket.
+    """
+    by_fingerprint: dict[str, dict] = {}
+    for finding in findings:
+        by_fingerprint.setdefault(fingerprint(finding), finding)
+
+    create = tuple(
+        finding for fp, finding in by_fingerprint.items() if fp not in open_issues
+    )
+    update = tuple(
+        (open_issues[fp], finding)
+        for fp, finding in by_fingerprint.items()
+        if fp in open_issues
+    )
+    close = tuple(
+        issue_key for fp, issue_key in open_issues.items() if fp not in by_fingerprint
+    )
+    return SyncPlan(create=create, update=update, close=close)
+
+
+def issue_fields(finding: dict, config: JiraConfig) -> dict:
+    """Build the Jira issue fields payload for one finding."""
+    tool = finding.get("tool", "")
+    rule_id = finding.get("rule_id", "")
+    path = finding.get("relative_path", "")
+    line = finding.get("line_start")
+    location = f"{path}:{line}" if line else path
+    fields_payload = {
+        "project": {"key": config.project_key},
+        "issuetype": {"name": config.issue_type},
+        "summary": f"[{tool}] {rule_id} in {path}",
+        "description": (
+            f"{finding.get('message') or rule_id}\n\n"
+            f"Severity: {finding.get('severity')}\n"
+            f"Location: {location}\n"
+            f"Reported by Caldera ({tool})."
+        ),
+        "labels": [
+            SYNC_LABEL,
+            FINGERPRINT_LABEL_PREFIX + fingerprint(finding),
+            *config.labels,
+        ],
+    }
+    for finding_key, jira_field in config.fields.items():
+        if finding.get(finding_key) is not None:
+            fields_payload[jira_field] = str(finding[finding_key])
+    return fields_payload
+
+
+def _http_transport(url: str, method: str, payload: dict | None, auth_header: str) -> dict:
+    request = urllib.request.Request(
+        url,
+        data=json.dumps(payload).encode() if payload is not None else None,
+        headers={
+            "Content-Type": "application/json",
+            "Authorization": auth_header,
+        },
+        method=method,
+    )
+    try:
+        with urllib.request.urlopen(request, timeout=REQUEST_TIMEOUT_SECONDS) as response:
+            body = response.read()
+            return json.loads(body) if body else {}
+    except urllib.error.HTTPError as exc:
+        raise JiraSyncError(f"Jira {method} {url} failed: HTTP {exc.code}")
+    except (urllib.error.URLError, TimeoutError, OSError) as exc:
+        raise JiraSyncError(f"Jira {method} {url} failed: {exc}")
+
+
+class JiraClient:
+    """Thin Jira REST v2 client.
+
+    ``transport(path, method, payload)`` is injectable for tests; the
+    default POSTs JSON with basic auth from JIRA_USER/JIRA_TOKEN.
+    """
+
+    def __init__(self, config: JiraConfig, transport=None) -> None:
+        self._config = config
+        if transport is not None:
+            self._transport = transport
+        else:
+            user = os.environ.get("JIRA_USER")
+            token = os.environ.get("JIRA_TOKEN")
+            if not user or not token:
+                raise JiraSyncError("JIRA_USER and JIRA_TOKEN must be set")
+            credentials = base64.b64encode(f"{user}:{token}".encode()).decode()
+            auth_header = f"Basic {credentials}"
+            self._transport = lambda path, method, payload=None: _http_transport(
+                config.url + path, method, payload, auth_header
+            )
+
+    def open_caldera_issues(self) -> dict[str, str]:
+        """Open Caldera tickets in the project, as fingerprint -> key."""
+        jql = (
+            f'project = "{self._config.project_key}" '
+            f'AND labels = "{SYNC_LABEL}" AND statusCategory != Done'
+        )
+        issues: dict[str, str] = {}
+        start_at = 0
+        while True:
+            response = self._transport(
+                "/rest/api/2/search",
+                "POST",
+                {"jql": jql, "fields": ["labels"], "startAt": start_at, "maxResults": 100},
+            )
+            for issue in response.get("issues", []):
+                for label in issue.get("fields", {}).get("labels", []):
+                    if label.startswith(FINGERPRINT_LABEL_PREFIX):
+                        issues[label[len(FINGERPRINT_LABEL_PREFIX):]] = issue["key"]
+            start_at += len(response.get("issues", []))
+            if start_at >= response.get("total", 0) or not response.get("issues"):
+                break
+        return issues
+
+    def create_issue(self, finding: dict) -> str:
+        response = self._transport(
+            "/rest/api/2/issue",
+            "POST",
+            {"fields": issue_fields(finding, self._config)},
+        )
+        return response["key"]
+
+    def add_recurrence_comment(self, issue_key: str, finding: dict) -> None:
+        line = finding.get("line_start")
+        location = (
+            f"{finding.get('relative_path', '')}:{line}"
+            if line
+            else finding.get("relative_path", "")
+        )
+        self._transport(
+            f"/rest/api/2/issue/{issue_key}/comment",
+            "POST",
+            {"body": f"Finding still present in the latest Caldera run ({location})."},
+        )
+
+    def close_issue(self, issue_key: str) -> None:
+        """Transition a ticket to done; no-op if no matching transition."""
+        response = self._transport(f"/rest/api/2/issue/{issue_key}/transitions", "GET", None)
+        wanted = self._config.done_transition.lower()
+        for transition in response.get("transitions", []):
+            if transition.get("name", "").lower() == wanted:
+                self._transport(
+                    f"/rest/api/2/issue/{issue_key}/transitions",
+                    "POST",
+                    {"transition": {"id": transition["id"]}},
+                )
+                return
+
+
+def apply_plan(plan: SyncPlan, client: JiraClient) -> dict:
+    """Execute a sync plan; returns a summary of what happened."""
+    created = [client.create_issue(finding) for finding in plan.create]
+    for issue_key, finding in plan.update:
+        client.add_recurrence_comment(issue_key, finding)
+    for issue_key in plan.close:
+        client.close_issue(issue_key)
+    return {
+        "created": created,
+        "updated": [issue_key for issue_key, _ in plan.update],
+        "closed": list(plan.close),
+    }
@@ -0,0 +1,92 @@
+"""JUnit XML reporter for CI test-result panes.
+
+Renders findings and gate results as a JUnit report so Jenkins, GitLab,
+and other CI systems show Caldera output in their native test UI without
+a plugin. The mapping keeps the tree shallow and readable:
+
+* one test case per gate — a failing gate becomes a test failure whose
+  text carries the gate message and offenders;
+* one test case per (tool, rule) with findings — the failure text lists
+  each finding as ``path:line message``.
+
+Gates that pass render as passing tests, so a green run still shows the
+checks that ran instead of an empty suite.
+"""
+
+from __future__ import annotations
+
+import xml.etree.ElementTree as ET
+from collections import defaultdict
+from pathlib import Path
+
+from insights.gates import GateResult
+
+SUITE_NAME = "caldera"
+# Keep failure bodies bounded so a noisy rule cannot bloat the report.
+MAX_FINDINGS_PER_CASE = 50
+
+
+def _finding_line(finding: dict) -> str:
+    path = finding.get("relative_path", "")
+    line = finding.get("line_start") or finding.get("line_number") or 0
+    message = str(finding.get("message") or finding.get("rule_id") or "finding")
+    return f"{path}:{line} {message}"
+
+
+def build_junit_xml(findings: list[dict], gate_results: list[GateResult]) -> str:
+    """Render one ``<testsuite>`` covering gates and per-rule findings."""
+    by_rule: dict[tuple[str, str], list[dict]] = defaultdict(list)
+    for finding in findings:
+        tool = str(finding.get("tool", "unknown"))
+        rule_id = str(finding.get("rule_id") or "unknown")
+        by_rule[(tool, rule_id)].append(finding)
+
+    failures = sum(1 for result in gate_results if not result.passed) + len(by_rule)
+    tests = len(gate_results) + len(by_rule)
+
+    suite = ET.Element(
+        "testsuite",
+        name=SUITE_NAME,
+        tests=str(tests),
+        failures=str(failures),
+        errors="0",
+        skipped="0",
+    )
+
+    for result in gate_results:
+        case = ET.SubElement(
+            suite, "testcase", classname=f"{SUITE_NAME}.gates", name=result.name
+        )
+        if not result.passed:
+            failure = ET.SubElement(case, "failure", message=result.message)
+            lines = [result.message]
+            lines.extend(f"  {offender}" for offender in result.offenders)
+            failure.text = "\n".join(lines)
+
+    for (tool, rule_id), rule_findings in sorted(by_rule.items()):
+        case = ET.SubElement(
+            suite, "testcase", classname=f"{SUITE_NAME}.{tool}", name=rule_id
+        )
+        shown = rule_findings[:MAX_FINDINGS_PER_CASE]
+        truncated = len(rule_findings) - len(shown)
+        failure = ET.SubElement(
+            case, "failure", message=f"{len(rule_findings)} finding(s) for {rule_id}"
+        )
+        lines = [_finding_line(finding) for finding in shown]
+        if truncated:
+            lines.append(f"... and {truncated} more")
+        failure.text = "\n".join(lines)
+
+    ET.indent(suite)
+    return ET.tostring(suite, encoding="unicode", xml_declaration=True)
+
+
+def write_junit_report(
+    findings: list[dict], gate_results: list[GateResult], output_path: Path
+) -> int:
+    """Write the JUnit report; returns the number of test cases emitted."""
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    xml = build_junit_xml(findings, gate_results)
+    output_path.write_text(xml + "\n")
+    root = ET.fromstring(xml)
+    return int(root.get("tests", "0"))
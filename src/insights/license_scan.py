@@ -0,0 +1,131 @@
+"""
+License detection and policy checking.
+
+Two detection sources feed one finding stream: SPDX license identifiers
+declared in file headers (``SPDX-License-Identifier: ...``), and
+dependency licenses resolved from the repository's lockfiles via the
+SBOM parsers. A ``[license_policy]`` section in ``caldera.toml`` lists
+denied SPDX identifiers; violations point at the offending dependency or
+file header and are enforced by the gate engine.
+"""
+
+from __future__ import annotations
+
+import re
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+from .sbom import collect_components
+
+# How many leading lines of a file are searched for an SPDX header.
+HEADER_LINE_LIMIT = 20
+
+SPDX_PATTERN = re.compile(r"SPDX-License-Identifier:\s*([A-Za-z0-9.+-]+(?:\s+(?:OR|AND|WITH)\s+[A-Za-z0-9.+-]+)*)")
+
+EXCLUDED_DIRS = {".git", "vendor", "node_modules"}
+
+DEFAULT_LICENSE_POLICY: dict[str, list[str]] = {
+    "deny": [],
+}
+
+
+@dataclass(frozen=True)
+class LicenseFinding:
+    """One detected license, from a file header or a dependency."""
+
+    license: str
+    source: str  # "file" or "dependency"
+    subject: str  # repo-relative path, or "name@version" for dependencies
+
+    def __post_init__(self) -> None:
+        if self.source not in ("file", "dependency"):
+            raise ValueError(f"source must be 'file' or 'dependency', got {self.source!r}")
+
+
+@dataclass(frozen=True)
+class LicensePolicy:
+    """Denied SPDX identifiers from ``[license_policy]`` in caldera.toml."""
+
+    deny: tuple[str, ...]
+
+
+def load_license_policy(caldera_toml: Path | None = None) -> LicensePolicy:
+    """Load the license policy, falling back to an empty deny list."""
+    deny = list(DEFAULT_LICENSE_POLICY["deny"])
+    if caldera_toml is not None and caldera_toml.exists():
+        config = tomllib.loads(caldera_toml.read_text())
+        deny = config.get("license_policy", {}).get("deny", deny)
+    return LicensePolicy(deny=tuple(deny))
+
+
+def scan_file_headers(repo_path: Path) -> list[LicenseFinding]:
+    """Find SPDX license identifiers declared in file headers.
+
+    Only the first ``HEADER_LINE_LIMIT`` lines of each file are searched;
+    unreadable (binary) files are skipped.
+    """
+    findings = []
+    for file_path in sorted(repo_path.rglob("*")):
+        if not file_path.is_file():
+            continue
+        relative_parts = file_path.relative_to(repo_path).parts
+        if any(part in EXCLUDED_DIRS for part in relative_parts):
+            continue
+        try:
+            header = "".join(
+                line for _, line in zip(range(HEADER_LINE_LIMIT), file_path.open(encoding="utf-8"))
+            )
+        except (UnicodeDecodeError, OSError):
+            continue
+        match = SPDX_PATTERN.search(header)
+        if match:
+            findings.append(LicenseFinding(
+                license=match.group(1).strip(),
+                source="file",
+                subject="/".join(relative_parts),
+            ))
+    return findings
+
+
+def resolve_dependency_licenses(repo_path: Path) -> list[LicenseFinding]:
+    """Resolve dependency licenses from the repository's lockfiles.
+
+    Only components whose manifest records a license produce a finding;
+    ecosystems without license metadata in their lockfiles are silent here
+    rather than guessed at.
+    """
+    return [
+        LicenseFinding(
+            license=component.license,
+            source="dependency",
+            subject=f"{component.name}@{component.version}",
+        )
+        for component in collect_components(repo_path)
+        if component.license
+    ]
+
+
+def scan_repository(repo_path: Path) -> list[LicenseFinding]:
+    """Run both detection sources over a repository."""
+    return scan_file_headers(repo_path) + resolve_dependency_licenses(repo_path)
+
+
+def _license_matches(license_id: str, denied: str) -> bool:
+    """Match an SPDX id against a denied id, including -only/-or-later variants
+    and each branch of a compound (OR/AND) expression."""
+    for part in re.split(r"\s+(?:OR|AND|WITH)\s+", license_id):
+        if part == denied or part.startswith(denied + "-"):
+            return True
+    return False
+
+
+def find_policy_violations(
+    findings: list[LicenseFinding], policy: LicensePolicy
+) -> list[LicenseFinding]:
+    """Findings whose license matches a denied identifier."""
+    return [
+        finding
+        for finding in findings
+        if any(_license_matches(finding.license, denied) for denied in policy.deny)
+    ]
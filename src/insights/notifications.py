@@ -0,0 +1,235 @@
+"""
+Webhook notifications for gate failures and new findings.
+
+A ``[[notifications.webhooks]]`` list in ``caldera.toml`` names endpoints
+to POST to when a run fails quality gates or introduces new high-severity
+findings relative to a baseline run. Payloads are JSON; the ``slack`` and
+``teams`` formats wrap the same content in each service's incoming-webhook
+envelope so no per-team glue scripts are needed.
+
+Example configuration::
+
+    [[notifications.webhooks]]
+    url = "https://hooks.slack.com/services/T000/B000/XXXX"
+    format = "slack"                      # json | slack | teams
+    events = ["gate_failure", "new_findings"]
+    min_severity = "HIGH"                 # for new_findings events
+"""
+
+from __future__ import annotations
+
+import json
+import tomllib
+import urllib.error
+import urllib.request
+from dataclasses import dataclass
+from pathlib import Path
+
+EVENT_GATE_FAILURE = "gate_failure"
+EVENT_NEW_FINDINGS = "new_findings"
+KNOWN_EVENTS = (EVENT_GATE_FAILURE, EVENT_NEW_FINDINGS)
+
+FORMAT_JSON = "json"
+FORMAT_SLACK = "slack"
+FORMAT_TEAMS = "teams"
+KNOWN_FORMATS = (FORMAT_JSON, FORMAT_SLACK, FORMAT_TEAMS)
+
+# Ascending severity rank; unknown severities rank below INFO.
+SEVERITY_ORDER = ("INFO", "LOW", "MEDIUM", "HIGH", "CRITICAL")
+
+DEFAULT_TIMEOUT_SECONDS = 10
+MAX_ITEMS_IN_MESSAGE = 10
+
+
+@dataclass(frozen=True)
+class WebhookConfig:
+    """One configured webhook endpoint."""
+
+    url: str
+    format: str = FORMAT_JSON
+    events: tuple[str, ...] = KNOWN_EVENTS
+    min_severity: str = "HIGH"
+    timeout_seconds: int = DEFAULT_TIMEOUT_SECONDS
+
+    def __post_init__(self) -> None:
+        if not self.url.startswith(("http://", "https://")):
+            raise ValueError(f"Webhook url must be http(s): {self.url}")
+        if self.format not in KNOWN_FORMATS:
+            raise ValueError(f"Unknown webhook format: {self.format}")
+        for event in self.events:
+            if event not in KNOWN_EVENTS:
+                raise ValueError(f"Unknown webhook event: {event}")
+        if self.min_severity not in SEVERITY_ORDER:
+            raise ValueError(f"Unknown min_severity: {self.min_severity}")
+
+
+@dataclass(frozen=True)
+class DeliveryResult:
+    """Outcome of one webhook POST."""
+
+    url: str
+    event: str
+    ok: bool
+    detail: str
+
+
+def load_notification_config(caldera_toml: Path | None = None) -> list[WebhookConfig]:
+    """Load webhook configurations from ``[[notifications.webhooks]]``."""
+    if caldera_toml is None or not caldera_toml.exists():
+        return []
+    config = tomllib.loads(caldera_toml.read_text())
+    webhooks = config.get("notifications", {}).get("webhooks", [])
+    return [
+        WebhookConfig(
+            url=str(entry.get("url", "")),
+            format=str(entry.get("format", FORMAT_JSON)),
+            events=tuple(entry.get("events", list(KNOWN_EVENTS))),
+            min_severity=str(entry.get("min_severity", "HIGH")).upper(),
+            timeout_seconds=int(entry.get("timeout_seconds", DEFAULT_TIMEOUT_SECONDS)),
+        )
+        for entry in webhooks
+    ]
+
+
+def severity_rank(severity: str | None) -> int:
+    try:
+        return SEVERITY_ORDER.index((severity or "").upper())
+    except ValueError:
+        return -1
+
+
+def _finding_key(finding: dict) -> tuple:
+    """Identity of a finding across runs: same rule at the same location."""
+    return (
+        finding.get("tool"),
+        finding.get("rule_id"),
+        finding.get("relative_path"),
+    )
+
+
+def diff_new_findings(
+    current: list[dict], baseline: list[dict], min_severity: str = "HIGH"
+) -> list[dict]:
+    """Findings present now but not in the baseline, at or above min_severity.
+
+    Line numbers are deliberately not part of the identity so findings that
+    merely shifted are not reported as new.
+    """
+    threshold = severity_rank(min_severity)
+    baseline_keys = {_finding_key(finding) for finding in baseline}
+    return [
+        finding
+        for finding in current
+        if severity_rank(finding.get("severity")) >= threshold
+        and _finding_key(finding) not in baseline_keys
+    ]
+
+
+def build_gate_failure_event(repo_id: str, run_ref: str, gate_results: list) -> dict:
+    """Event payload for failed quality gates (GateResult objects)."""
+    failed = [result for result in gate_results if not result.passed]
+    return {
+        "event": EVENT_GATE_FAILURE,
+        "repo_id": repo_id,
+        "run": run_ref,
+        "summary": f"{len(failed)} of {len(gate_results)} quality gates failed",
+        "gates": [
+            {
+                "name": result.name,
+                "actual": result.actual,
+                "limit": result.limit,
+                "message": result.message,
+                "offenders": list(result.offenders[:MAX_ITEMS_IN_MESSAGE]),
+            }
+            for result in failed
+        ],
+    }
+
+
+def build_new_findings_event(repo_id: str, run_ref: str, new_findings: list[dict]) -> dict:
+    """Event payload for findings introduced relative to the baseline."""
+    return {
+        "event": EVENT_NEW_FINDINGS,
+        "repo_id": repo_id,
+        "run": run_ref,
+        "summary": f"{len(new_findings)} new high-severity findings vs baseline",
+        "findings": [
+            {
+                "tool": finding.get("tool"),
+                "rule_id": finding.get("rule_id"),
+                "severity": finding.get("severity"),
+                "relative_path": finding.get("relative_path"),
+                "message": finding.get("message"),
+            }
+            for finding in new_findings[:MAX_ITEMS_IN_MESSAGE]
+        ],
+        "total_findings": len(new_findings),
+    }
+
+
+def _event_lines(event: dict) -> list[str]:
+    lines = [f"Caldera {event['event']} — {event['repo_id']} ({event['run']})", event["summary"]]
+    for gate in event.get("gates", []):
+        lines.append(f"• {gate['name']}: {gate['message']}")
+    for finding in event.get("findings", []):
+        lines.append(
+            f"• [{finding['severity']}] {finding['rule_id']} in {finding['relative_path']}"
+        )
+    return lines
+
+
+def render_payload(event: dict, format: str) -> dict:
+    """Wrap the event in the target service's webhook envelope."""
+    if format == FORMAT_JSON:
+        return event
+    text = "\n".join(_event_lines(event))
+    if format == FORMAT_SLACK:
+        return {"text": text}
+    if format == FORMAT_TEAMS:
+        return {
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "themeColor": "CC0000" if event["event"] == EVENT_GATE_FAILURE else "E8A000",
+            "title": _event_lines(event)[0],
+            "text": "\n\n".join(_event_lines(event)[1:]),
+        }
+    raise ValueError(f"Unknown webhook format: {format}")
+
+
+def post_webhook(url: str, payload: dict, timeout_seconds: int) -> tuple[bool, str]:
+    """POST a JSON payload; returns (ok, detail)."""
+    request = urllib.request.Request(
+        url,
+        data=json.dumps(payload).encode(),
+        headers={"Content-Type": "application/json"},
+        method="POST",
+    )
+    try:
+        with urllib.request.urlopen(request, timeout=timeout_seconds) as response:
+            return True, f"HTTP {response.status}"
+    except urllib.error.HTTPError as exc:
+        return False, f"HTTP {exc.code}"
+    except (urllib.error.URLError, TimeoutError, OSError) as exc:
+        return False, str(exc)
+
+
+def notify(
+    events: list[dict], webhooks: list[WebhookConfig], poster=post_webhook
+) -> list[DeliveryResult]:
+    """Deliver each event to every webhook subscribed to it.
+
+    ``poster`` is injectable so tests (and dry runs) never hit the network.
+    Delivery failures are reported, not raised — a down Slack must not fail
+    the pipeline that already failed its gates.
+    """
+    results: list[DeliveryResult] = []
+    for event in events:
+        for webhook in webhooks:
+            if event["event"] not in webhook.events:
+                continue
+            payload = render_payload(event, webhook.format)
+            ok, detail = poster(webhook.url, payload, webhook.timeout_seconds)
+            results.append(
+                DeliveryResult(url=webhook.url, event=event["event"], ok=ok, detail=detail)
+            )
+    return results
@@ -0,0 +1,217 @@
+"""
+Churn-aware directory ownership and bus-factor analysis.
+
+Aggregates git-blame-scanner file summaries into per-directory contributor
+concentration and joins the result with complexity (lizard) and finding
+density (semgrep) so that single-owner code that is also complex or smelly
+stands out. The landing zone only stores the top author per file, so
+per-author line counts are an approximation: each file's ``top_author_lines``
+is attributed to its top author and the remainder stays unattributed.
+
+- ``bus_factor`` is the smallest number of authors whose attributed lines
+  cover at least half of the directory's lines. When attribution never
+  reaches half (ownership is diffuse), the count of known authors is
+  reported instead — a floor, not an exact value.
+- A directory is ``single-owner`` when one author holds at least
+  ``single_owner_pct`` of its attributed lines.
+- Risk is ``high`` for single-owner directories that are also complex
+  (mean CCN at or above ``risk_ccn``) or smelly (findings per KLOC at or
+  above ``risk_findings_per_kloc``), ``medium`` for single-owner
+  directories that are neither, and ``low`` otherwise.
+
+Thresholds come from ``[ownership]`` in ``caldera.toml``; missing keys fall
+back to the defaults below.
+"""
+
+from __future__ import annotations
+
+import posixpath
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+DEFAULT_SINGLE_OWNER_PCT = 75.0
+DEFAULT_RISK_CCN = 10.0  # mean CCN at which a single-owner directory is risky
+DEFAULT_RISK_FINDINGS_PER_KLOC = 5.0
+DEFAULT_MIN_DIRECTORY_LINES = 50  # skip trivially small directories
+
+_RISK_ORDER = {"high": 0, "medium": 1, "low": 2}
+
+
+@dataclass(frozen=True)
+class OwnershipConfig:
+    """Thresholds for the ownership risk classification."""
+
+    single_owner_pct: float = DEFAULT_SINGLE_OWNER_PCT
+    risk_ccn: float = DEFAULT_RISK_CCN
+    risk_findings_per_kloc: float = DEFAULT_RISK_FINDINGS_PER_KLOC
+    min_directory_lines: int = DEFAULT_MIN_DIRECTORY_LINES
+
+    def __post_init__(self) -> None:
+        if not 0 < self.single_owner_pct <= 100:
+            raise ValueError("single_owner_pct must be in (0, 100]")
+        if self.risk_ccn <= 0:
+            raise ValueError("risk_ccn must be > 0")
+        if self.risk_findings_per_kloc <= 0:
+            raise ValueError("risk_findings_per_kloc must be > 0")
+        if self.min_directory_lines < 0:
+            raise ValueError("min_directory_lines must be >= 0")
+
+
+@dataclass(frozen=True)
+class DirectoryOwnership:
+    """Ownership profile for one directory (direct files only)."""
+
+    directory: str  # repo-relative directory path, or "." for the repo root
+    total_lines: int
+    file_count: int
+    author_lines: tuple[tuple[str, int], ...]  # (author, attributed lines), desc
+    top_author: str | None
+    top_author_pct: float  # share of attributed lines held by the top author
+    bus_factor: int
+    churn_30d: int
+    churn_90d: int
+    mean_ccn: float | None  # None when lizard did not cover the directory
+    findings_per_kloc: float | None  # None when semgrep did not run
+    single_owner: bool
+    risk: str  # "high" | "medium" | "low"
+
+
+def load_ownership_config(caldera_toml: Path | None = None) -> OwnershipConfig:
+    """Load thresholds from the ``[ownership]`` table, falling back to defaults."""
+    if caldera_toml is None or not caldera_toml.exists():
+        return OwnershipConfig()
+    section = tomllib.loads(caldera_toml.read_text()).get("ownership", {})
+    return OwnershipConfig(
+        single_owner_pct=float(section.get("single_owner_pct", DEFAULT_SINGLE_OWNER_PCT)),
+        risk_ccn=float(section.get("risk_ccn", DEFAULT_RISK_CCN)),
+        risk_findings_per_kloc=float(
+            section.get("risk_findings_per_kloc", DEFAULT_RISK_FINDINGS_PER_KLOC)
+        ),
+        min_directory_lines=int(
+            section.get("min_directory_lines", DEFAULT_MIN_DIRECTORY_LINES)
+        ),
+    )
+
+
+def _directory_of(relative_path: str) -> str:
+    return posixpath.dirname(relative_path) or "."
+
+
+def _bus_factor(author_lines: tuple[tuple[str, int], ...], total_lines: int) -> int:
+    """Authors needed (largest first) to cover half of the directory's lines."""
+    if total_lines <= 0 or not author_lines:
+        return 0
+    covered = 0
+    for count, (_, lines) in enumerate(author_lines, start=1):
+        covered += lines
+        if 2 * covered >= total_lines:
+            return count
+    return len(author_lines)
+
+
+def compute_directory_ownership(
+    blame_rows: list[dict],
+    complexity_rows: list[dict] | None = None,
+    finding_rows: list[dict] | None = None,
+    config: OwnershipConfig | None = None,
+) -> list[DirectoryOwnership]:
+    """Aggregate per-file rows into directory ownership profiles.
+
+    ``blame_rows`` are lz_git_blame_summary rows (relative_path, total_lines,
+    top_author, top_author_lines, churn_30d, churn_90d). ``complexity_rows``
+    are lz_lizard_file_metrics rows (relative_path, total_ccn, function_count)
+    and ``finding_rows`` carry per-file finding counts (relative_path,
+    finding_count); both are optional so sparse tool coverage degrades to
+    ``None`` columns rather than failing. Results are sorted worst first:
+    risk, then 90-day churn descending.
+    """
+    config = config or OwnershipConfig()
+
+    directories: dict[str, list[dict]] = {}
+    for row in blame_rows:
+        directories.setdefault(_directory_of(row["relative_path"]), []).append(row)
+
+    ccn_by_dir: dict[str, tuple[float, int]] = {}
+    for row in complexity_rows or []:
+        directory = _directory_of(row["relative_path"])
+        total_ccn, functions = ccn_by_dir.get(directory, (0.0, 0))
+        ccn_by_dir[directory] = (
+            total_ccn + float(row.get("total_ccn") or 0),
+            functions + int(row.get("function_count") or 0),
+        )
+
+    findings_by_dir: dict[str, int] = {}
+    for row in finding_rows or []:
+        directory = _directory_of(row["relative_path"])
+        findings_by_dir[directory] = findings_by_dir.get(directory, 0) + int(
+            row.get("finding_count") or 0
+        )
+
+    results: list[DirectoryOwnership] = []
+    for directory, files in directories.items():
+        total_lines = sum(int(row["total_lines"]) for row in files)
+        if total_lines < config.min_directory_lines:
+            continue
+
+        lines_by_author: dict[str, int] = {}
+        for row in files:
+            author = row.get("top_author")
+            if author:
+                lines_by_author[author] = lines_by_author.get(author, 0) + int(
+                    row.get("top_author_lines") or 0
+                )
+        author_lines = tuple(
+            sorted(lines_by_author.items(), key=lambda item: (-item[1], item[0]))
+        )
+        attributed = sum(lines for _, lines in author_lines)
+        top_author = author_lines[0][0] if author_lines else None
+        top_author_pct = (
+            round(100.0 * author_lines[0][1] / attributed, 1) if attributed else 0.0
+        )
+
+        mean_ccn: float | None = None
+        if directory in ccn_by_dir:
+            total_ccn, functions = ccn_by_dir[directory]
+            mean_ccn = round(total_ccn / functions, 1) if functions else 0.0
+
+        findings_per_kloc: float | None = None
+        if finding_rows is not None:
+            findings_per_kloc = round(
+                1000.0 * findings_by_dir.get(directory, 0) / total_lines, 1
+            )
+
+        single_owner = top_author is not None and top_author_pct >= config.single_owner_pct
+        risky_content = (mean_ccn is not None and mean_ccn >= config.risk_ccn) or (
+            findings_per_kloc is not None
+            and findings_per_kloc >= config.risk_findings_per_kloc
+        )
+        if single_owner and risky_content:
+            risk = "high"
+        elif single_owner:
+            risk = "medium"
+        else:
+            risk = "low"
+
+        results.append(
+            DirectoryOwnership(
+                directory=directory,
+                total_lines=total_lines,
+                file_count=len(files),
+                author_lines=author_lines,
+                top_author=top_author,
+                top_author_pct=top_author_pct,
+                bus_factor=_bus_factor(author_lines, total_lines),
+                churn_30d=sum(int(row.get("churn_30d") or 0) for row in files),
+                churn_90d=sum(int(row.get("churn_90d") or 0) for row in files),
+                mean_ccn=mean_ccn,
+                findings_per_kloc=findings_per_kloc,
+                single_owner=single_owner,
+                risk=risk,
+            )
+        )
+
+    return sorted(
+        results,
+        key=lambda entry: (_RISK_ORDER[entry.risk], -entry.churn_90d, entry.directory),
+    )
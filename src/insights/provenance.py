@@ -0,0 +1,197 @@
+"""
+Scan manifest and provenance attestation.
+
+Records what produced a report: an in-toto v1 Statement whose SLSA-style
+predicate captures the repo commit, every tool run (name, version, schema
+version, and a digest over the tool's own sources and rule packs), the
+configuration hash, and the build environment. Downstream consumers can
+then verify that a report came from known tool code, known rules, and a
+known config — or detect that any of them drifted between two runs.
+
+Signing is optional and deliberately dependency-free: the statement is
+wrapped in a DSSE-shaped envelope and signed with HMAC-SHA256 using a
+shared key file. That authenticates the attestation within a pipeline
+that controls the key; swapping in asymmetric in-toto signatures later
+only replaces ``sign_statement``/``verify_envelope``, not the document.
+
+Tool digests hash every file under the tool's ``scripts/``, ``schemas/``,
+and ``rules/`` directories in sorted order, so a rule-pack edit changes
+the digest even when the tool version string does not.
+"""
+
+from __future__ import annotations
+
+import base64
+import hashlib
+import hmac
+import json
+import platform
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+STATEMENT_TYPE = "https://in-toto.io/Statement/v1"
+PREDICATE_TYPE = "https://slsa.dev/provenance/v1"
+PAYLOAD_TYPE = "application/vnd.in-toto+json"
+
+# Tool subdirectories whose contents define the tool's behavior.
+_DIGESTED_SUBDIRS = ("scripts", "schemas", "rules")
+
+
+@dataclass(frozen=True)
+class ToolProvenance:
+    """One tool run as recorded in the attestation."""
+
+    name: str
+    version: str
+    schema_version: str
+    digest: str | None  # sha256 over the tool's sources; None without a checkout
+
+    def __post_init__(self) -> None:
+        if not self.name:
+            raise ValueError("tool name must not be empty")
+
+
+def canonical_json(document: Any) -> bytes:
+    """Deterministic JSON bytes: sorted keys, no whitespace, UTF-8."""
+    return json.dumps(
+        document, sort_keys=True, separators=(",", ":"), ensure_ascii=False, default=str
+    ).encode()
+
+
+def sha256_file(path: Path) -> str:
+    return hashlib.sha256(path.read_bytes()).hexdigest()
+
+
+def digest_tool_sources(tools_root: Path, tool_name: str) -> str | None:
+    """Sha256 over a tool's scripts, schemas, and rule packs.
+
+    Files are hashed as (relative path, content) pairs in sorted order so
+    the digest is stable across filesystems. Returns None when the tool
+    directory is not present in the checkout.
+    """
+    tool_dir = tools_root / tool_name
+    if not tool_dir.is_dir():
+        return None
+    digest = hashlib.sha256()
+    for subdir in _DIGESTED_SUBDIRS:
+        base = tool_dir / subdir
+        if not base.is_dir():
+            continue
+        for path in sorted(base.rglob("*")):
+            if not path.is_file() or "__pycache__" in path.parts:
+                continue
+            relative = path.relative_to(tool_dir).as_posix()
+            digest.update(relative.encode())
+            digest.update(b"\x00")
+            digest.update(path.read_bytes())
+    return digest.hexdigest()
+
+
+def build_environment() -> dict[str, str]:
+    """The build environment fields recorded in the predicate."""
+    return {
+        "os": platform.platform(),
+        "python": platform.python_version(),
+        "machine": platform.machine(),
+    }
+
+
+def build_statement(
+    rows: list[dict],
+    config_path: Path | None = None,
+    tools_root: Path | None = None,
+    environment: dict[str, str] | None = None,
+) -> dict:
+    """Assemble the in-toto Statement for one collection run.
+
+    ``rows`` is the ``collection_tool_runs`` query output: one row per
+    tool run, each carrying the collection metadata. The subject digest
+    is the sha256 of the canonical predicate, so any change to what is
+    attested changes the subject.
+    """
+    if not rows:
+        raise ValueError("no tool runs to attest")
+
+    first = rows[0]
+    tools = []
+    for row in rows:
+        digest = (
+            digest_tool_sources(tools_root, row["tool_name"])
+            if tools_root is not None
+            else None
+        )
+        tools.append(
+            ToolProvenance(
+                name=row["tool_name"],
+                version=row["tool_version"],
+                schema_version=row["schema_version"],
+                digest=digest,
+            )
+        )
+
+    predicate: dict[str, Any] = {
+        "run": {
+            "collection_run_id": first["collection_run_id"],
+            "status": first["status"],
+            "started_at": str(first["started_at"]),
+            "completed_at": str(first.get("completed_at") or ""),
+        },
+        "repository": {
+            "repo_id": first["repo_id"],
+            "branch": first["branch"],
+            "commit": first["commit"],
+        },
+        "tools": [
+            {
+                "name": tool.name,
+                "version": tool.version,
+                "schema_version": tool.schema_version,
+                "digest": {"sha256": tool.digest} if tool.digest else None,
+            }
+            for tool in tools
+        ],
+        "config": (
+            {"path": config_path.name, "digest": {"sha256": sha256_file(config_path)}}
+            if config_path is not None and config_path.exists()
+            else None
+        ),
+        "environment": environment if environment is not None else build_environment(),
+    }
+    return {
+        "_type": STATEMENT_TYPE,
+        "subject": [
+            {
+                "name": f"caldera-run:{first['collection_run_id']}",
+                "digest": {"sha256": hashlib.sha256(canonical_json(predicate)).hexdigest()},
+            }
+        ],
+        "predicateType": PREDICATE_TYPE,
+        "predicate": predicate,
+    }
+
+
+def sign_statement(statement: dict, key: bytes) -> dict:
+    """Wrap a statement in a DSSE-shaped envelope signed with HMAC-SHA256."""
+    payload = canonical_json(statement)
+    signature = hmac.new(key, payload, hashlib.sha256).hexdigest()
+    keyid = hashlib.sha256(key).hexdigest()[:16]
+    return {
+        "payloadType": PAYLOAD_TYPE,
+        "payload": base64.b64encode(payload).decode(),
+        "signatures": [{"keyid": keyid, "sig": signature}],
+    }
+
+
+def verify_envelope(envelope: dict, key: bytes) -> bool:
+    """True when the envelope's signature matches the payload under ``key``."""
+    try:
+        payload = base64.b64decode(envelope["payload"])
+        signatures = envelope["signatures"]
+    except (KeyError, ValueError):
+        return False
+    expected = hmac.new(key, payload, hashlib.sha256).hexdigest()
+    return any(
+        hmac.compare_digest(signature.get("sig", ""), expected)
+        for signature in signatures
+    )
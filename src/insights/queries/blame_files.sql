@@ -0,0 +1,24 @@
+-- Per-file authorship rows used by the directory ownership analysis
+-- Resolves git-blame-scanner run_pk from any tool's collection; reads the
+-- landing zone directly so the report works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT tr_tool.run_pk AS blame_run_pk
+    FROM lz_tool_runs tr_source
+    LEFT JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+        AND tr_tool.tool_name = 'git-blame-scanner'
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    relative_path,
+    total_lines,
+    unique_authors,
+    top_author,
+    top_author_lines,
+    top_author_pct,
+    churn_30d,
+    churn_90d
+FROM lz_git_blame_summary
+WHERE run_pk = (SELECT blame_run_pk FROM run_map)
+ORDER BY relative_path
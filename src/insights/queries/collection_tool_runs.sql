@@ -0,0 +1,25 @@
+-- Tool runs and collection metadata used by the provenance attestation
+-- Resolves the collection from any tool's run_pk and reads the landing
+-- zone directly so provenance works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT collection_run_id
+    FROM lz_tool_runs
+    WHERE run_pk = {{ run_pk }}
+)
+SELECT
+    c.collection_run_id,
+    c.repo_id,
+    c.branch,
+    c.commit,
+    c.started_at,
+    c.completed_at,
+    c.status,
+    tr.tool_name,
+    tr.tool_version,
+    tr.schema_version,
+    tr.timestamp AS tool_timestamp
+FROM lz_collection_runs c
+JOIN lz_tool_runs tr ON tr.collection_run_id = c.collection_run_id
+WHERE c.collection_run_id = (SELECT collection_run_id FROM run_map)
+ORDER BY tr.tool_name
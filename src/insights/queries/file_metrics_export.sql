@@ -0,0 +1,36 @@
+-- Per-file metrics for a collection run, one row per file, normalized
+-- for warehouse export. scc is the file universe; lizard complexity and
+-- pmd-cpd duplication attach where those tools saw the file.
+
+WITH run_map AS (
+    SELECT tr_tool.tool_name, tr_tool.run_pk
+    FROM lz_tool_runs tr_source
+    JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    scc.relative_path,
+    scc.language,
+    scc.lines_total,
+    scc.code_lines,
+    scc.comment_lines,
+    scc.blank_lines,
+    scc.complexity,
+    liz.nloc,
+    liz.function_count,
+    liz.total_ccn,
+    liz.avg_ccn,
+    liz.max_ccn,
+    cpd.duplicate_lines,
+    cpd.duplicate_blocks,
+    cpd.duplication_percentage
+FROM lz_scc_file_metrics scc
+LEFT JOIN lz_lizard_file_metrics liz
+    ON liz.run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'lizard')
+    AND liz.relative_path = scc.relative_path
+LEFT JOIN lz_pmd_cpd_file_metrics cpd
+    ON cpd.run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'pmd-cpd')
+    AND cpd.relative_path = scc.relative_path
+WHERE scc.run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'scc')
+ORDER BY scc.relative_path
@@ -0,0 +1,38 @@
+-- All linter findings for a collection run, normalized for export
+-- Resolves each tool's run_pk from any tool's collection
+
+WITH run_map AS (
+    SELECT tr_tool.tool_name, tr_tool.run_pk
+    FROM lz_tool_runs tr_source
+    JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT 'semgrep' AS tool, relative_path, rule_id, severity, line_start, line_end, message
+FROM lz_semgrep_smells
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'semgrep')
+UNION ALL
+SELECT 'devskim' AS tool, relative_path, rule_id, severity, line_start, line_end, message
+FROM lz_devskim_findings
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'devskim')
+UNION ALL
+SELECT 'bandit' AS tool, relative_path, rule_id, severity, line_start, line_end, message
+FROM lz_bandit_findings
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'bandit')
+UNION ALL
+SELECT 'shellcheck' AS tool, relative_path, rule_id, severity, line_start, line_end, message
+FROM lz_shellcheck_findings
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'shellcheck')
+UNION ALL
+SELECT 'checkov' AS tool, relative_path, rule_id, severity, line_start, line_end, check_name AS message
+FROM lz_checkov_findings
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'checkov')
+UNION ALL
+SELECT 'golangci' AS tool, relative_path, rule_id, severity, line_start, line_end, message
+FROM lz_golangci_findings
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'golangci')
+UNION ALL
+SELECT 'sqlfluff' AS tool, relative_path, rule_id, severity, line_start, line_end, message
+FROM lz_sqlfluff_findings
+WHERE run_pk = (SELECT run_pk FROM run_map WHERE tool_name = 'sqlfluff')
+ORDER BY relative_path, line_start, tool, rule_id
@@ -0,0 +1,26 @@
+-- Function-level complexity rows used by the complexity delta gate
+-- Resolves lizard run_pk from any tool's collection; reads the landing
+-- zone directly so the gate works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT tr_tool.run_pk AS lizard_run_pk
+    FROM lz_tool_runs tr_source
+    LEFT JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+        AND tr_tool.tool_name = 'lizard'
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    fm.relative_path AS file,
+    fn.function_name,
+    fn.long_name,
+    fn.ccn,
+    fn.nloc,
+    fn.token_count,
+    fn.line_start,
+    fn.line_end
+FROM lz_lizard_function_metrics fn
+JOIN lz_lizard_file_metrics fm
+    ON fm.run_pk = fn.run_pk AND fm.file_id = fn.file_id
+WHERE fn.run_pk = (SELECT lizard_run_pk FROM run_map)
+ORDER BY fm.relative_path, fn.line_start
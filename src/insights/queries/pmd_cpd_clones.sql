@@ -0,0 +1,25 @@
+-- Occurrence-level clone rows used by the new-clone gate
+-- Resolves pmd-cpd run_pk from any tool's collection; reads the landing
+-- zone directly so the gate works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT tr_tool.run_pk AS pmd_run_pk
+    FROM lz_tool_runs tr_source
+    LEFT JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+        AND tr_tool.tool_name = 'pmd-cpd'
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    d.clone_id,
+    d.lines,
+    d.tokens,
+    d.code_fragment,
+    o.relative_path,
+    o.line_start,
+    o.line_end
+FROM lz_pmd_cpd_duplications d
+JOIN lz_pmd_cpd_occurrences o
+    ON o.run_pk = d.run_pk AND o.clone_id = d.clone_id
+WHERE d.run_pk = (SELECT pmd_run_pk FROM run_map)
+ORDER BY d.clone_id, o.relative_path, o.line_start
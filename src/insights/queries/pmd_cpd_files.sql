@@ -0,0 +1,19 @@
+-- Per-file duplication rows used by the remediation-minutes model
+-- Resolves pmd-cpd run_pk from any tool's collection; reads the landing
+-- zone directly so the model works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT tr_tool.run_pk AS pmd_run_pk
+    FROM lz_tool_runs tr_source
+    LEFT JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+        AND tr_tool.tool_name = 'pmd-cpd'
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    relative_path,
+    duplicate_lines,
+    duplication_percentage
+FROM lz_pmd_cpd_file_metrics
+WHERE run_pk = (SELECT pmd_run_pk FROM run_map)
+ORDER BY relative_path
@@ -0,0 +1,37 @@
+-- Per-finding rows used by the policy-as-code gates
+-- Unions the rule-based finding tables into the record shape the shared
+-- --filter grammar evaluates (tool, path, rule, severity, line, message);
+-- resolves each tool's run_pk from any tool's collection and reads the
+-- landing zone directly so policies work before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT
+        MAX(CASE WHEN tr_tool.tool_name = 'semgrep' THEN tr_tool.run_pk END) AS semgrep_run_pk,
+        MAX(CASE WHEN tr_tool.tool_name = 'devskim' THEN tr_tool.run_pk END) AS devskim_run_pk,
+        MAX(CASE WHEN tr_tool.tool_name = 'roslyn-analyzers' THEN tr_tool.run_pk END) AS roslyn_run_pk,
+        MAX(CASE WHEN tr_tool.tool_name = 'sonarqube' THEN tr_tool.run_pk END) AS sonarqube_run_pk
+    FROM lz_tool_runs tr_source
+    JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT 'semgrep' AS tool, relative_path AS path, rule_id AS rule,
+       severity, line_start AS line, message
+FROM lz_semgrep_smells
+WHERE run_pk = (SELECT semgrep_run_pk FROM run_map)
+UNION ALL
+SELECT 'devskim' AS tool, relative_path AS path, rule_id AS rule,
+       severity, line_start AS line, message
+FROM lz_devskim_findings
+WHERE run_pk = (SELECT devskim_run_pk FROM run_map)
+UNION ALL
+SELECT 'roslyn-analyzers' AS tool, relative_path AS path, rule_id AS rule,
+       severity, line_start AS line, message
+FROM lz_roslyn_violations
+WHERE run_pk = (SELECT roslyn_run_pk FROM run_map)
+UNION ALL
+SELECT 'sonarqube' AS tool, relative_path AS path, rule_id AS rule,
+       severity, line_start AS line, message
+FROM lz_sonarqube_issues
+WHERE run_pk = (SELECT sonarqube_run_pk FROM run_map)
+ORDER BY path, line
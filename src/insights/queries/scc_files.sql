@@ -0,0 +1,18 @@
+-- Per-file size rows used by the production/test/fixture bucket report
+-- Resolves scc run_pk from any tool's collection; reads the landing
+-- zone directly so the report works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT tr_tool.run_pk AS scc_run_pk
+    FROM lz_tool_runs tr_source
+    LEFT JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+        AND tr_tool.tool_name = 'scc'
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    relative_path,
+    code_lines
+FROM lz_scc_file_metrics
+WHERE run_pk = (SELECT scc_run_pk FROM run_map)
+ORDER BY relative_path
@@ -0,0 +1,19 @@
+-- Per-file semgrep finding counts used by the directory ownership analysis
+-- Resolves semgrep run_pk from any tool's collection; reads the landing
+-- zone directly so the report works before dbt has built the marts.
+
+WITH run_map AS (
+    SELECT tr_tool.run_pk AS semgrep_run_pk
+    FROM lz_tool_runs tr_source
+    LEFT JOIN lz_tool_runs tr_tool
+        ON tr_tool.collection_run_id = tr_source.collection_run_id
+        AND tr_tool.tool_name = 'semgrep'
+    WHERE tr_source.run_pk = {{ run_pk }}
+)
+SELECT
+    relative_path,
+    COUNT(*) AS finding_count
+FROM lz_semgrep_smells
+WHERE run_pk = (SELECT semgrep_run_pk FROM run_map)
+GROUP BY relative_path
+ORDER BY relative_path
@@ -0,0 +1,191 @@
+"""
+CycloneDX SBOM generation.
+
+Parses the lockfiles and manifests found in a repository (Cargo.lock,
+package-lock.json, go.sum, requirements.txt) into a CycloneDX 1.5 JSON
+document, enriched with license info where the manifest records it. The
+resulting component inventory is the foundation for vulnerability
+adapters to cross-reference findings against declared dependencies.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import tomllib
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+
+SPEC_VERSION = "1.5"
+
+# Manifest filename -> ecosystem (drives which parser runs and the purl type).
+MANIFEST_ECOSYSTEMS = {
+    "Cargo.lock": "cargo",
+    "package-lock.json": "npm",
+    "go.sum": "golang",
+    "requirements.txt": "pypi",
+}
+
+# requirements.txt pinned line: name, optional extras, ==version.
+_REQUIREMENT_PATTERN = re.compile(
+    r"^(?P<name>[A-Za-z0-9][A-Za-z0-9._-]*)(?:\[[^\]]*\])?==(?P<version>[^\s;#]+)"
+)
+
+
+@dataclass(frozen=True)
+class Component:
+    """One resolved dependency from a lockfile or manifest."""
+
+    name: str
+    version: str
+    ecosystem: str
+    license: str | None = None
+
+    @property
+    def purl(self) -> str:
+        """Package URL per the purl spec, e.g. ``pkg:cargo/serde@1.0.195``."""
+        name = self.name
+        if self.ecosystem == "npm" and name.startswith("@"):
+            name = "%40" + name[1:]
+        elif self.ecosystem == "pypi":
+            name = name.lower().replace("_", "-")
+        return f"pkg:{self.ecosystem}/{name}@{self.version}"
+
+
+def parse_cargo_lock(content: str) -> list[Component]:
+    """Parse a Cargo.lock (TOML with ``[[package]]`` entries)."""
+    document = tomllib.loads(content)
+    components = []
+    for package in document.get("package", []):
+        name = package.get("name")
+        version = package.get("version")
+        if name and version:
+            components.append(Component(name=name, version=version, ecosystem="cargo"))
+    return components
+
+
+def parse_package_lock(content: str) -> list[Component]:
+    """Parse a package-lock.json (v2/v3 ``packages`` map, v1 fallback)."""
+    document = json.loads(content)
+    components = []
+    packages = document.get("packages")
+    if packages is not None:
+        for path, entry in packages.items():
+            if not path:  # the root project itself, not a dependency
+                continue
+            name = entry.get("name") or path.rsplit("node_modules/", 1)[-1]
+            version = entry.get("version")
+            if name and version:
+                components.append(Component(
+                    name=name,
+                    version=version,
+                    ecosystem="npm",
+                    license=entry.get("license"),
+                ))
+    else:
+        for name, entry in document.get("dependencies", {}).items():
+            version = entry.get("version")
+            if version:
+                components.append(Component(name=name, version=version, ecosystem="npm"))
+    return components
+
+
+def parse_go_sum(content: str) -> list[Component]:
+    """Parse a go.sum; ``/go.mod`` hash lines are skipped and modules deduped."""
+    seen: set[tuple[str, str]] = set()
+    components = []
+    for line in content.splitlines():
+        parts = line.split()
+        if len(parts) < 2:
+            continue
+        module, version = parts[0], parts[1]
+        if version.endswith("/go.mod"):
+            continue
+        if (module, version) in seen:
+            continue
+        seen.add((module, version))
+        components.append(Component(name=module, version=version, ecosystem="golang"))
+    return components
+
+
+def parse_requirements_txt(content: str) -> list[Component]:
+    """Parse a requirements.txt; only ``==``-pinned entries are resolvable."""
+    components = []
+    for line in content.splitlines():
+        line = line.strip()
+        if not line or line.startswith(("#", "-")):
+            continue
+        match = _REQUIREMENT_PATTERN.match(line)
+        if match:
+            components.append(Component(
+                name=match.group("name"),
+                version=match.group("version"),
+                ecosystem="pypi",
+            ))
+    return components
+
+
+_PARSERS = {
+    "cargo": parse_cargo_lock,
+    "npm": parse_package_lock,
+    "golang": parse_go_sum,
+    "pypi": parse_requirements_txt,
+}
+
+
+def collect_components(repo_path: Path) -> list[Component]:
+    """Discover and parse all supported manifests under ``repo_path``.
+
+    Skips ``.git``, ``vendor``, and ``node_modules`` subtrees; components
+    are deduplicated on (ecosystem, name, version) across manifests.
+    """
+    seen: set[tuple[str, str, str]] = set()
+    components = []
+    for manifest_name, ecosystem in MANIFEST_ECOSYSTEMS.items():
+        for manifest_path in sorted(repo_path.rglob(manifest_name)):
+            relative_parts = manifest_path.relative_to(repo_path).parts
+            if any(part in (".git", "vendor", "node_modules") for part in relative_parts):
+                continue
+            for component in _PARSERS[ecosystem](manifest_path.read_text()):
+                key = (component.ecosystem, component.name, component.version)
+                if key in seen:
+                    continue
+                seen.add(key)
+                components.append(component)
+    return components
+
+
+def build_cyclonedx_document(components: list[Component], repo_name: str) -> dict:
+    """Assemble the CycloneDX 1.5 JSON document for a component inventory."""
+    entries = []
+    for component in sorted(components, key=lambda c: (c.ecosystem, c.name, c.version)):
+        entry = {
+            "type": "library",
+            "name": component.name,
+            "version": component.version,
+            "purl": component.purl,
+        }
+        if component.license:
+            entry["licenses"] = [{"license": {"id": component.license}}]
+        entries.append(entry)
+    return {
+        "bomFormat": "CycloneDX",
+        "specVersion": SPEC_VERSION,
+        "version": 1,
+        "metadata": {
+            "timestamp": datetime.now(timezone.utc).isoformat(),
+            "tools": [{"vendor": "caldera", "name": "insights"}],
+            "component": {"type": "application", "name": repo_name},
+        },
+        "components": entries,
+    }
+
+
+def write_sbom(repo_path: Path, output_path: Path) -> int:
+    """Generate and write the SBOM for a repository; returns component count."""
+    components = collect_components(repo_path)
+    document = build_cyclonedx_document(components, repo_path.resolve().name)
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    output_path.write_text(json.dumps(document, indent=2, ensure_ascii=False))
+    return len(components)
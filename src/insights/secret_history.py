@@ -0,0 +1,178 @@
+"""
+Secret exposure history from git log scanning.
+
+Point-in-time secret scans answer "is a secret in the tree now?"; incident
+response needs the history: when did the secret first land, is it still
+reachable at HEAD, and how much history has to be rewritten to purge it.
+This module walks ``git log --all -p`` (optionally date-limited), matches
+added lines against a small set of high-signal secret patterns, and checks
+HEAD authoritatively with ``git grep`` — a secret deleted in a later
+commit is still exposed in every reachable blob that contains it.
+
+The rewrite scope is everything from the introducing commit to HEAD:
+rewriting any commit changes its hash, which changes every descendant, so
+the count is the honest size of the history surgery (and of the force-push
+blast radius), not just the commits whose trees contain the secret.
+
+Secrets are never reported verbatim — only a masked preview and a stable
+fingerprint for cross-referencing with a dedicated scanner like gitleaks.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import re
+import subprocess
+from dataclasses import dataclass
+from pathlib import Path
+
+# Sentinel prefixing each commit in the parsed git log output.
+_COMMIT_MARKER = "\x01"
+
+# High-signal patterns only; broad heuristics belong to the gitleaks tool.
+# Where a pattern needs context (generic assignments), group 1 is the secret.
+_PATTERNS: tuple[tuple[str, re.Pattern[str]], ...] = (
+    ("aws-access-token", re.compile(r"\bAKIA[0-9A-Z]{16}\b")),
+    ("github-pat", re.compile(r"\bghp_[A-Za-z0-9]{36}\b")),
+    ("slack-token", re.compile(r"\bxox[baprs]-[A-Za-z0-9][A-Za-z0-9-]{10,}\b")),
+    ("private-key", re.compile(r"-----BEGIN (?:RSA |DSA |EC |OPENSSH |PGP )?PRIVATE KEY-----")),
+    (
+        "generic-api-key",
+        re.compile(
+            r"(?i)(?:api[_-]?key|secret[_-]?key|auth[_-]?token|password)"
+            r"""\s*[=:]\s*['"]([A-Za-z0-9_\-/+=]{16,})['"]"""
+        ),
+    ),
+)
+
+_RULE_SEVERITY = {
+    "aws-access-token": "CRITICAL",
+    "github-pat": "HIGH",
+    "slack-token": "HIGH",
+    "private-key": "HIGH",
+    "generic-api-key": "MEDIUM",
+}
+
+
+@dataclass(frozen=True)
+class SecretExposure:
+    """History of one secret: introduction, HEAD status, rewrite scope."""
+
+    rule_id: str
+    file_path: str
+    secret_preview: str  # masked — never the raw secret
+    fingerprint: str  # sha256 prefix over rule + raw secret
+    severity: str
+    first_commit: str
+    first_commit_date: str  # ISO timestamp of the introducing commit
+    in_head: bool
+    rewrite_commit_count: int  # introducing commit through HEAD
+
+    def __post_init__(self) -> None:
+        if not self.first_commit:
+            raise ValueError("first_commit must not be empty")
+        if self.rewrite_commit_count < 0:
+            raise ValueError("rewrite_commit_count must be >= 0")
+
+
+def _mask(secret: str) -> str:
+    if len(secret) <= 8:
+        return secret[:2] + "***"
+    return f"{secret[:4]}...{secret[-2:]} ({len(secret)} chars)"
+
+
+def _fingerprint(rule_id: str, secret: str) -> str:
+    return hashlib.sha256(f"{rule_id}:{secret}".encode()).hexdigest()[:16]
+
+
+def _match_secrets(line: str) -> list[tuple[str, str]]:
+    """Return (rule_id, raw_secret) matches on one line of content."""
+    matches = []
+    for rule_id, pattern in _PATTERNS:
+        for match in pattern.finditer(line):
+            matches.append((rule_id, match.group(match.re.groups)))
+    return matches
+
+
+def _git(repo_path: Path, *args: str) -> subprocess.CompletedProcess[str]:
+    return subprocess.run(
+        ["git", "-C", str(repo_path), *args], capture_output=True, text=True
+    )
+
+
+def scan_secret_history(
+    repo_path: Path, since: str | None = None
+) -> list[SecretExposure]:
+    """Scan reachable history for secret introductions.
+
+    ``since`` limits the walk (any ``git log --since`` expression, e.g.
+    ``"2024-01-01"`` or ``"90 days ago"``). Raises ``ValueError`` when
+    ``repo_path`` is not a git repository. Results are sorted still-at-HEAD
+    first, then by severity and introduction date.
+    """
+    log_args = [
+        "log",
+        "--all",
+        "--reverse",
+        "-p",
+        "--no-merges",
+        "--date=iso-strict",
+        f"--pretty=format:{_COMMIT_MARKER}%H %ad",
+    ]
+    if since:
+        log_args.append(f"--since={since}")
+    result = _git(repo_path, *log_args)
+    if result.returncode != 0:
+        raise ValueError(f"not a git repository: {repo_path}")
+
+    # First introduction per fingerprint; --reverse makes "first wins" work.
+    first_seen: dict[str, tuple[str, str, str, str, str]] = {}
+    commit_hash = ""
+    commit_date = ""
+    current_file = ""
+    for line in result.stdout.splitlines():
+        if line.startswith(_COMMIT_MARKER):
+            commit_hash, _, commit_date = line[1:].partition(" ")
+        elif line.startswith("+++ b/"):
+            current_file = line[6:]
+        elif line.startswith("+") and not line.startswith("+++"):
+            for rule_id, secret in _match_secrets(line[1:]):
+                fingerprint = _fingerprint(rule_id, secret)
+                if fingerprint not in first_seen:
+                    first_seen[fingerprint] = (
+                        rule_id, secret, current_file, commit_hash, commit_date
+                    )
+
+    exposures = []
+    for fingerprint, (rule_id, secret, file_path, commit, date) in first_seen.items():
+        grep = _git(repo_path, "grep", "-qF", secret, "HEAD")
+        count = _git(repo_path, "rev-list", "--count", f"{commit}..HEAD")
+        try:
+            rewrite_count = int(count.stdout.strip()) + 1
+        except ValueError:
+            # Introducing commit is on an unmerged branch; HEAD needs no rewrite.
+            rewrite_count = 0
+        exposures.append(
+            SecretExposure(
+                rule_id=rule_id,
+                file_path=file_path,
+                secret_preview=_mask(secret),
+                fingerprint=fingerprint,
+                severity=_RULE_SEVERITY.get(rule_id, "MEDIUM"),
+                first_commit=commit,
+                first_commit_date=date,
+                in_head=grep.returncode == 0,
+                rewrite_commit_count=rewrite_count,
+            )
+        )
+
+    severity_order = {"CRITICAL": 0, "HIGH": 1, "MEDIUM": 2, "LOW": 3}
+    return sorted(
+        exposures,
+        key=lambda exposure: (
+            not exposure.in_head,
+            severity_order.get(exposure.severity, 4),
+            exposure.first_commit_date,
+            exposure.fingerprint,
+        ),
+    )
@@ -0,0 +1,188 @@
+"""
+Security sub-score, separable from the maintainability score.
+
+The composite health score folds one severity-weighted SAST input into an
+otherwise maintainability-shaped formula, which makes it impossible to
+gate security and quality independently — relaxing the complexity cap
+should never loosen a security gate. This module scores security on its
+own 0-100 scale from three components, using the same penalty/weight
+formula as the health score so the two read the same way:
+
+- ``sast``          severity-weighted SAST findings per 1000 LOC
+  (semgrep, devskim, roslyn-analyzers, sonarqube — the health score's
+  ``security_input``)
+- ``secrets``       severity-weighted secret count; secrets only present
+  in history count at half weight, matching the gitleaks severity model
+- ``dependencies``  CVSS-weighted dependency CVEs with exploitability
+  multipliers: a known-exploited vulnerability counts double, and a CVE
+  in a direct dependency counts 1.5x a transitive one (the best
+  reachability hint available without call-graph analysis)
+
+Weights and caps come from ``[security_score]`` in ``caldera.toml``. The
+companion ``maintainability_score`` in :mod:`insights.health_score`
+drops the security dimension and re-normalizes, so the pair partitions
+the existing health score rather than double-counting findings.
+"""
+
+from __future__ import annotations
+
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+from .config_overrides import resolve_for_path
+from .health_score import SEVERITY_WEIGHTS
+
+DEFAULT_SECURITY_WEIGHTS: dict[str, float] = {
+    "sast": 0.40,
+    "secrets": 0.30,
+    "dependencies": 0.30,
+}
+
+# Raw component value at which its penalty saturates at 1.0.
+DEFAULT_SECURITY_CAPS: dict[str, float] = {
+    "sast": 10.0,  # severity-weighted findings per KLOC
+    "secrets": 5.0,  # severity-weighted secret count
+    "dependencies": 10.0,  # exploitability-weighted CVE score
+}
+
+COMPONENTS = tuple(DEFAULT_SECURITY_WEIGHTS)
+
+# Secrets reachable only through history are still exposed but no longer
+# served from the working tree.
+_HISTORICAL_SECRET_FACTOR = 0.5
+
+_KNOWN_EXPLOITED_FACTOR = 2.0
+_DIRECT_DEPENDENCY_FACTOR = 1.5
+
+# CVSS fallback when the scanner reports a severity but no vector score.
+_CVSS_BY_SEVERITY: dict[str, float] = {
+    "CRITICAL": 9.5,
+    "HIGH": 8.0,
+    "MEDIUM": 5.5,
+    "LOW": 2.5,
+}
+
+
+@dataclass(frozen=True)
+class CveFinding:
+    """One dependency vulnerability with its exploitability hints."""
+
+    cvss_score: float | None  # 0-10, None when the scanner omitted it
+    severity: str | None  # fallback when cvss_score is None
+    known_exploited: bool = False  # e.g. listed in CISA KEV
+    direct: bool = True  # direct dependency vs transitive
+
+    def __post_init__(self) -> None:
+        if self.cvss_score is not None and not 0 <= self.cvss_score <= 10:
+            raise ValueError("cvss_score must be in [0, 10]")
+
+
+@dataclass(frozen=True)
+class SecurityConfig:
+    """Weights and caps for the security score formula."""
+
+    weights: dict[str, float]
+    caps: dict[str, float]
+
+    def __post_init__(self) -> None:
+        for component in COMPONENTS:
+            if component not in self.weights:
+                raise ValueError(f"missing weight for component: {component}")
+            if self.weights[component] < 0:
+                raise ValueError(f"weight for {component} must be >= 0")
+            if self.caps.get(component, 0) <= 0:
+                raise ValueError(f"cap for {component} must be > 0")
+        if sum(self.weights.values()) <= 0:
+            raise ValueError("weights must sum to a positive value")
+
+
+@dataclass(frozen=True)
+class SecurityScore:
+    """Computed security score for one scope (directory or repo)."""
+
+    scope: str  # repo-relative directory path, or "." for repo-wide
+    score: float  # 0-100, higher is more secure
+    penalties: dict[str, float]  # per-component penalty in [0, 1]
+
+
+def load_security_config(
+    caldera_toml: Path | None = None, scope: str | None = None
+) -> SecurityConfig:
+    """Load weights/caps from caldera.toml, falling back to defaults.
+
+    Reads the ``[security_score.weights]`` and ``[security_score.caps]``
+    tables; ``scope`` applies matching ``[overrides]`` entries first, the
+    same resolution the health score uses.
+    """
+    weights = dict(DEFAULT_SECURITY_WEIGHTS)
+    caps = dict(DEFAULT_SECURITY_CAPS)
+    if caldera_toml is not None and caldera_toml.exists():
+        config = tomllib.loads(caldera_toml.read_text())
+        if scope is not None:
+            config = resolve_for_path(config, scope)
+        section = config.get("security_score", {})
+        weights.update(section.get("weights", {}))
+        caps.update(section.get("caps", {}))
+    return SecurityConfig(weights=weights, caps=caps)
+
+
+def secrets_input(secrets: list[tuple[str, bool]]) -> float:
+    """Severity-weighted secret count from (severity, in_head) pairs."""
+    weighted = 0.0
+    for severity, in_head in secrets:
+        weight = SEVERITY_WEIGHTS.get((severity or "MEDIUM").upper(), 1.0)
+        weighted += weight if in_head else weight * _HISTORICAL_SECRET_FACTOR
+    return weighted
+
+
+def dependency_input(cves: list[CveFinding]) -> float:
+    """Exploitability-weighted CVE score (see module docstring)."""
+    weighted = 0.0
+    for cve in cves:
+        cvss = cve.cvss_score
+        if cvss is None:
+            cvss = _CVSS_BY_SEVERITY.get((cve.severity or "MEDIUM").upper(), 5.5)
+        score = cvss / 10.0
+        if cve.known_exploited:
+            score *= _KNOWN_EXPLOITED_FACTOR
+        if cve.direct:
+            score *= _DIRECT_DEPENDENCY_FACTOR
+        weighted += score
+    return weighted
+
+
+def _penalty(raw_value: float, cap: float) -> float:
+    if raw_value <= 0:
+        return 0.0
+    return min(raw_value / cap, 1.0)
+
+
+def compute_security_score(
+    scope: str,
+    metrics: dict[str, float],
+    config: SecurityConfig | None = None,
+) -> SecurityScore:
+    """Score one scope from its raw component inputs.
+
+    ``metrics`` maps component name to raw input (see module docstring);
+    missing components contribute zero penalty, so a run without e.g. a
+    dependency scanner still gets a score from the remaining components.
+    """
+    config = config or SecurityConfig(
+        dict(DEFAULT_SECURITY_WEIGHTS), dict(DEFAULT_SECURITY_CAPS)
+    )
+    penalties = {
+        component: _penalty(metrics.get(component, 0.0), config.caps[component])
+        for component in COMPONENTS
+    }
+    total_weight = sum(config.weights[component] for component in COMPONENTS)
+    weighted_penalty = (
+        sum(config.weights[component] * penalties[component] for component in COMPONENTS)
+        / total_weight
+    )
+    return SecurityScore(
+        scope=scope,
+        score=round(100.0 * (1.0 - weighted_penalty), 1),
+        penalties=penalties,
+    )
@@ -0,0 +1,152 @@
+"""
+Bidirectional SonarQube bridge.
+
+Export: converts Caldera linter findings into SonarQube's generic issue
+import format (`sonar.externalIssuesReportPaths`), so a SonarQube instance
+can display Caldera results next to its own during a migration.
+
+Import: parses a SonarQube issue export (the `api/issues/search` response
+shape) into the same normalized finding dicts the export side consumes,
+and compares the two sets so teams running both systems can reconcile
+results file by file.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from pathlib import Path
+
+ENGINE_ID = "caldera"
+
+# Caldera severity -> SonarQube severity.
+SEVERITY_TO_SONARQUBE = {
+    "CRITICAL": "BLOCKER",
+    "HIGH": "CRITICAL",
+    "MEDIUM": "MAJOR",
+    "LOW": "MINOR",
+}
+
+# SonarQube severity -> Caldera severity (for the import direction).
+SONARQUBE_TO_SEVERITY = {
+    "BLOCKER": "CRITICAL",
+    "CRITICAL": "HIGH",
+    "MAJOR": "MEDIUM",
+    "MINOR": "LOW",
+    "INFO": "LOW",
+}
+
+# Tools whose findings are security-flavored map to VULNERABILITY;
+# everything else is a CODE_SMELL in SonarQube's taxonomy.
+SECURITY_TOOLS = {"bandit", "devskim", "checkov", "gitleaks", "trivy"}
+
+
+@dataclass(frozen=True)
+class ComparisonResult:
+    """Outcome of reconciling Caldera findings against a SonarQube export."""
+
+    matched: int
+    only_caldera: int
+    only_sonarqube: int
+    matched_by_file: dict[str, int] = field(default_factory=dict)
+
+    @property
+    def total_caldera(self) -> int:
+        return self.matched + self.only_caldera
+
+    @property
+    def total_sonarqube(self) -> int:
+        return self.matched + self.only_sonarqube
+
+
+def export_generic_issues(findings: list[dict], engine_id: str = ENGINE_ID) -> dict:
+    """Convert normalized finding rows into the generic issue import format.
+
+    Each row needs ``tool``, ``relative_path``, ``rule_id``, ``severity``,
+    ``line_start``/``line_end``, and ``message`` (the shape produced by the
+    ``findings_export`` query).
+    """
+    issues = []
+    for finding in findings:
+        severity = SEVERITY_TO_SONARQUBE.get((finding.get("severity") or "").upper(), "MAJOR")
+        tool = finding.get("tool", "")
+        line_start = finding.get("line_start") or 1
+        text_range = {"startLine": line_start}
+        line_end = finding.get("line_end")
+        if line_end and line_end >= line_start:
+            text_range["endLine"] = line_end
+        issues.append({
+            "engineId": engine_id,
+            "ruleId": f"{tool}:{finding.get('rule_id', '')}" if tool else finding.get("rule_id", ""),
+            "severity": severity,
+            "type": "VULNERABILITY" if tool in SECURITY_TOOLS else "CODE_SMELL",
+            "primaryLocation": {
+                "message": finding.get("message") or finding.get("rule_id", ""),
+                "filePath": finding.get("relative_path", ""),
+                "textRange": text_range,
+            },
+        })
+    return {"issues": issues}
+
+
+def write_export(findings: list[dict], output_path: Path, engine_id: str = ENGINE_ID) -> int:
+    """Write the generic issue import document; returns the issue count."""
+    document = export_generic_issues(findings, engine_id)
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    output_path.write_text(json.dumps(document, indent=2, ensure_ascii=False))
+    return len(document["issues"])
+
+
+def import_sonarqube_issues(export_path: Path) -> list[dict]:
+    """Parse a SonarQube issue export into normalized finding dicts.
+
+    Accepts the `api/issues/search` response shape (``issues`` array with
+    ``component`` as ``project:path``). Closed issues are skipped.
+    """
+    document = json.loads(export_path.read_text())
+    findings = []
+    for issue in document.get("issues", []):
+        if issue.get("status") in ("CLOSED", "RESOLVED"):
+            continue
+        component = issue.get("component", "")
+        relative_path = component.split(":", 1)[1] if ":" in component else component
+        line = issue.get("line")
+        text_range = issue.get("textRange") or {}
+        findings.append({
+            "tool": "sonarqube",
+            "relative_path": relative_path,
+            "rule_id": issue.get("rule", ""),
+            "severity": SONARQUBE_TO_SEVERITY.get(issue.get("severity", ""), "MEDIUM"),
+            "line_start": text_range.get("startLine", line),
+            "line_end": text_range.get("endLine", line),
+            "message": issue.get("message", ""),
+        })
+    return findings
+
+
+def compare_findings(
+    caldera_findings: list[dict], sonarqube_findings: list[dict]
+) -> ComparisonResult:
+    """Reconcile the two finding sets by location.
+
+    Findings match when they point at the same file and start line; rule
+    identifiers differ between systems by construction, so location is the
+    only stable join key.
+    """
+    def _key(finding: dict) -> tuple[str, int | None]:
+        return (finding.get("relative_path", ""), finding.get("line_start"))
+
+    caldera_keys = {_key(f) for f in caldera_findings}
+    sonarqube_keys = {_key(f) for f in sonarqube_findings}
+    matched_keys = caldera_keys & sonarqube_keys
+
+    matched_by_file: dict[str, int] = {}
+    for path, _line in matched_keys:
+        matched_by_file[path] = matched_by_file.get(path, 0) + 1
+
+    return ComparisonResult(
+        matched=len(matched_keys),
+        only_caldera=len(caldera_keys - sonarqube_keys),
+        only_sonarqube=len(sonarqube_keys - caldera_keys),
+        matched_by_file=matched_by_file,
+    )
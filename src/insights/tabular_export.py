@@ -0,0 +1,116 @@
+"""Tabular exports for analytics pipelines.
+
+Writes findings and per-file metrics as CSV, Parquet, or JSONL so data
+teams can load results into a warehouse or a notebook without writing
+JSON flatteners. CSV and Parquet go through DuckDB's native ``COPY``;
+JSONL streams one record per line in cursor batches, so exporting a
+monorepo-sized run stays flat on memory instead of building the whole
+report in RAM. Datasets are the existing export queries
+(``findings_export``, ``file_metrics_export``); adding a dataset means
+adding a query file and one entry here.
+"""
+
+from __future__ import annotations
+
+import csv
+import json
+from pathlib import Path
+
+from common.record_filter import Node, lookup, matches
+from insights.data_fetcher import DataFetcher
+
+# Dataset name -> query file (without .sql) in insights/queries/.
+DATASETS = {
+    "findings": "findings_export",
+    "file-metrics": "file_metrics_export",
+}
+
+FORMATS = ("csv", "parquet", "jsonl")
+
+_COPY_OPTIONS = {
+    "csv": "FORMAT CSV, HEADER",
+    "parquet": "FORMAT PARQUET",
+}
+
+# Rows fetched per batch when streaming JSONL.
+STREAM_BATCH_SIZE = 10_000
+
+
+def export_dataset(
+    db_path: Path,
+    dataset: str,
+    run_pk: int,
+    output_path: Path,
+    output_format: str,
+    select: tuple[str, ...] | None = None,
+    record_filter: Node | None = None,
+) -> int:
+    """Export one dataset for a run; returns the row count written.
+
+    ``select`` and ``record_filter`` (the shared --select/--filter
+    grammar) project and filter rows on the way out; they stream row by
+    row in Python, so they are limited to the csv and jsonl formats.
+    """
+    if dataset not in DATASETS:
+        raise ValueError(f"unknown dataset: {dataset} (one of: {', '.join(DATASETS)})")
+    if output_format not in FORMATS:
+        raise ValueError(f"unknown format: {output_format} (one of: {', '.join(FORMATS)})")
+    if (select is not None or record_filter is not None) and output_format == "parquet":
+        raise ValueError("--filter/--select require csv or jsonl output")
+    fetcher = DataFetcher(db_path=db_path)
+    sql = fetcher._render_template(fetcher._load_query(DATASETS[dataset]), run_pk=run_pk)
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    if select is not None or record_filter is not None:
+        return _stream_filtered(fetcher, sql, output_path, output_format, select, record_filter)
+    if output_format == "jsonl":
+        return _stream_jsonl(fetcher, sql, output_path)
+    escaped = str(output_path).replace("'", "''")
+    copy_sql = f"COPY ({sql}) TO '{escaped}' ({_COPY_OPTIONS[output_format]})"
+    with fetcher._get_connection() as conn:
+        row = conn.execute(copy_sql).fetchone()
+    return int(row[0]) if row else 0
+
+
+def _stream_jsonl(fetcher: DataFetcher, sql: str, output_path: Path) -> int:
+    """Stream query results as one JSON object per line, batch by batch."""
+    count = 0
+    with fetcher._get_connection() as conn, output_path.open("w") as handle:
+        cursor = conn.execute(sql)
+        columns = [column[0] for column in cursor.description]
+        while batch := cursor.fetchmany(STREAM_BATCH_SIZE):
+            for row in batch:
+                handle.write(json.dumps(dict(zip(columns, row)), default=str) + "\n")
+            count += len(batch)
+    return count
+
+
+def _stream_filtered(
+    fetcher: DataFetcher,
+    sql: str,
+    output_path: Path,
+    output_format: str,
+    select: tuple[str, ...] | None,
+    record_filter: Node | None,
+) -> int:
+    """Stream rows through the shared filter/projection, batch by batch."""
+    count = 0
+    with fetcher._get_connection() as conn, output_path.open("w", newline="") as handle:
+        cursor = conn.execute(sql)
+        columns = [column[0] for column in cursor.description]
+        fields = select if select is not None else tuple(columns)
+        writer = None
+        if output_format == "csv":
+            writer = csv.DictWriter(handle, fieldnames=list(fields))
+            writer.writeheader()
+        while batch := cursor.fetchmany(STREAM_BATCH_SIZE):
+            for row in batch:
+                record = dict(zip(columns, row))
+                if record_filter is not None and not matches(record_filter, record):
+                    continue
+                projected = {field: lookup(record, field) for field in fields}
+                if writer is not None:
+                    writer.writerow(projected)
+                else:
+                    handle.write(json.dumps(projected, default=str) + "\n")
+                count += 1
+    return count
@@ -0,0 +1,119 @@
+"""Tests for the Azure DevOps and Bitbucket CI reporters."""
+
+from __future__ import annotations
+
+from insights.ci_reporters import (
+    BITBUCKET_BATCH_SIZE,
+    BitbucketClient,
+    build_bitbucket_annotations,
+    build_bitbucket_report,
+    publish_bitbucket_report,
+    render_azure_commands,
+)
+from insights.gates import GateResult
+
+
+def _finding(severity: str = "HIGH", path: str = "src/app.py", tool: str = "semgrep") -> dict:
+    return {
+        "tool": tool,
+        "rule_id": "rule-1",
+        "severity": severity,
+        "relative_path": path,
+        "line_start": 10,
+        "message": "something smells",
+    }
+
+
+_FAILED_GATE = GateResult(name="no_fixable_criticals", passed=False, actual=2, limit=0, message="2 > 0")
+_PASSED_GATE = GateResult(name="no_denied_licenses", passed=True, actual=0, limit=0, message="ok")
+
+
+class TestAzureCommands:
+    def test_logissue_command_shape(self) -> None:
+        commands = render_azure_commands([_finding()], [])
+        assert commands[0] == (
+            "##vso[task.logissue type=error;sourcepath=src/app.py;"
+            "linenumber=10;code=rule-1]something smells"
+        )
+
+    def test_severity_mapping(self) -> None:
+        commands = render_azure_commands([_finding("MEDIUM")], [])
+        assert "type=warning" in commands[0]
+
+    def test_failed_gate_sets_failed_result(self) -> None:
+        commands = render_azure_commands([], [_FAILED_GATE, _PASSED_GATE])
+        assert "##vso[task.logissue type=error]gate no_fixable_criticals failed: 2 > 0" in commands
+        assert commands[-1] == "##vso[task.complete result=Failed]Caldera gates failed"
+
+    def test_findings_without_gate_failure_succeed_with_issues(self) -> None:
+        commands = render_azure_commands([_finding()], [_PASSED_GATE])
+        assert commands[-1] == "##vso[task.complete result=SucceededWithIssues]Caldera found issues"
+
+    def test_clean_run_succeeds(self) -> None:
+        assert render_azure_commands([], []) == ["##vso[task.complete result=Succeeded]Caldera clean"]
+
+    def test_cap_keeps_worst_and_reports_truncation(self) -> None:
+        findings = [_finding("LOW") for _ in range(3)] + [_finding("CRITICAL", path="z.py")]
+        commands = render_azure_commands(findings, [], cap=2)
+        assert "sourcepath=z.py" in commands[0]
+        assert any("2 further finding(s) not annotated" in command for command in commands)
+
+
+class TestBitbucketPayloads:
+    def test_report_result_from_gates(self) -> None:
+        assert build_bitbucket_report([], [])["result"] == "PASSED"
+        report = build_bitbucket_report([_finding()], [_FAILED_GATE])
+        assert report["result"] == "FAILED"
+        assert "no_fixable_criticals" in report["details"]
+
+    def test_annotation_shape(self) -> None:
+        annotations, truncated = build_bitbucket_annotations([_finding(tool="trivy")])
+        assert truncated == 0
+        assert annotations == [{
+            "external_id": "caldera-0",
+            "annotation_type": "VULNERABILITY",
+            "severity": "HIGH",
+            "path": "src/app.py",
+            "line": 10,
+            "summary": "something smells",
+        }]
+
+    def test_non_security_tool_is_code_smell(self) -> None:
+        annotations, _ = build_bitbucket_annotations([_finding(tool="lizard")])
+        assert annotations[0]["annotation_type"] == "CODE_SMELL"
+
+    def test_unknown_severity_folds_to_low(self) -> None:
+        annotations, _ = build_bitbucket_annotations([_finding("INFO")])
+        assert annotations[0]["severity"] == "LOW"
+
+
+class StubTransport:
+    def __init__(self) -> None:
+        self.calls: list[tuple[str, str, object]] = []
+
+    def __call__(self, method: str, url: str, payload) -> dict:
+        self.calls.append((method, url, payload))
+        return {}
+
+
+class TestBitbucketClient:
+    def test_publish_puts_report_then_posts_annotations(self) -> None:
+        transport = StubTransport()
+        client = BitbucketClient("acme", "app", transport=transport)
+        report = publish_bitbucket_report(client, "c" * 40, [_finding()], [_FAILED_GATE])
+        assert report.result == "FAILED"
+        assert report.annotation_count == 1
+        methods_urls = [(method, url) for method, url, _ in transport.calls]
+        base = "https://api.bitbucket.org/2.0/repositories/acme/app/commit/" + "c" * 40
+        assert methods_urls == [
+            ("PUT", f"{base}/reports/caldera"),
+            ("POST", f"{base}/reports/caldera/annotations"),
+        ]
+
+    def test_annotations_posted_in_batches(self) -> None:
+        transport = StubTransport()
+        client = BitbucketClient("acme", "app", transport=transport)
+        annotations = [{"external_id": f"caldera-{i}"} for i in range(BITBUCKET_BATCH_SIZE + 1)]
+        assert client.post_annotations("c" * 40, annotations) == 2
+        sizes = [len(payload) for _, _, payload in transport.calls]
+        assert sizes == [BITBUCKET_BATCH_SIZE, 1]
@@ -0,0 +1,102 @@
+"""Tests for the temporal coupling (co-change) analysis."""
+
+import os
+import subprocess
+from pathlib import Path
+
+import pytest
+
+from insights.cochange import (
+    CoChangePair,
+    compute_cochange,
+    read_commit_file_sets,
+)
+
+
+def _sets(*groups: tuple[str, ...]) -> list[frozenset[str]]:
+    return [frozenset(group) for group in groups]
+
+
+class TestComputeCochange:
+    """Tests for pair scoring from parsed commit file sets."""
+
+    def test_coupled_pair_reported(self):
+        file_sets = _sets(*[("src/api.py", "docs/api.md")] * 5)
+        pairs = compute_cochange(file_sets, min_support=5)
+        assert pairs == [
+            CoChangePair("docs/api.md", "src/api.py", 5, 1.0, cross_module=True)
+        ]
+
+    def test_support_threshold(self):
+        file_sets = _sets(*[("src/a.py", "src/b.py")] * 4)
+        assert compute_cochange(file_sets, min_support=5) == []
+
+    def test_confidence_uses_less_changed_file(self):
+        # api.py changes 10 times, api.md only the 5 times it rides along:
+        # confidence is 5/5, not 5/10.
+        file_sets = _sets(*[("src/api.py", "docs/api.md")] * 5, *[("src/api.py",)] * 5)
+        pairs = compute_cochange(file_sets, min_support=5, min_confidence=0.9)
+        assert pairs[0].confidence == 1.0
+
+    def test_confidence_threshold(self):
+        file_sets = _sets(*[("src/a.py", "src/b.py")] * 5, *[("src/a.py",)] * 5, *[("src/b.py",)] * 5)
+        assert compute_cochange(file_sets, min_support=5, min_confidence=0.6) == []
+
+    def test_same_module_flagged_not_dropped(self):
+        file_sets = _sets(*[("src/a.py", "src/b.py")] * 5)
+        pairs = compute_cochange(file_sets, min_support=5)
+        assert pairs[0].cross_module is False
+
+    def test_root_files_form_their_own_module(self):
+        file_sets = _sets(*[("Makefile", "src/build.py")] * 5)
+        assert compute_cochange(file_sets, min_support=5)[0].cross_module is True
+
+    def test_cross_module_sorted_first(self):
+        file_sets = _sets(
+            *[("src/a.py", "src/b.py")] * 9,
+            *[("src/api.py", "docs/api.md")] * 5,
+        )
+        pairs = compute_cochange(file_sets, min_support=5)
+        assert [pair.cross_module for pair in pairs] == [True, False]
+
+
+class TestReadCommitFileSets:
+    """Tests against a real throwaway git repository."""
+
+    @pytest.fixture
+    def repo(self, tmp_path: Path) -> Path:
+        def git(*args: str) -> None:
+            subprocess.run(
+                ["git", "-C", str(tmp_path), *args],
+                check=True,
+                capture_output=True,
+                env={
+                    **os.environ,
+                    "GIT_AUTHOR_NAME": "t",
+                    "GIT_AUTHOR_EMAIL": "t@example.com",
+                    "GIT_COMMITTER_NAME": "t",
+                    "GIT_COMMITTER_EMAIL": "t@example.com",
+                },
+            )
+
+        git("init", "-q")
+        for n in range(3):
+            (tmp_path / "a.py").write_text(f"a = {n}\n")
+            (tmp_path / "b.py").write_text(f"b = {n}\n")
+            git("add", "-A")
+            git("commit", "-q", "-m", f"change {n}")
+        return tmp_path
+
+    def test_one_set_per_commit(self, repo: Path) -> None:
+        file_sets = read_commit_file_sets(repo)
+        assert file_sets == [frozenset({"a.py", "b.py"})] * 3
+
+    def test_max_commits(self, repo: Path) -> None:
+        assert len(read_commit_file_sets(repo, max_commits=2)) == 2
+
+    def test_bulk_commits_dropped(self, repo: Path) -> None:
+        assert read_commit_file_sets(repo, max_files_per_commit=1) == []
+
+    def test_non_repo_raises(self, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="not a git repository"):
+            read_commit_file_sets(tmp_path / "empty")
@@ -0,0 +1,121 @@
+"""Tests for the code-age analysis."""
+
+import os
+import subprocess
+from datetime import datetime, timezone
+from pathlib import Path
+
+import pytest
+
+from insights.code_age import (
+    FunctionAge,
+    blame_line_ages,
+    compute_function_ages,
+    recently_churned,
+    stale_complex,
+)
+
+
+def _age(name, ccn, median, last, file="src/a.py", start=1):
+    return FunctionAge(
+        file=file,
+        function_name=name,
+        ccn=ccn,
+        line_start=start,
+        line_end=start + 10,
+        median_age_days=median,
+        last_touched_days=last,
+    )
+
+
+class TestComputeFunctionAges:
+    """Tests for folding line ages into function ranges."""
+
+    def test_median_and_last_touched(self):
+        rows = [
+            {"file": "src/a.py", "function_name": "f", "ccn": 12, "line_start": 1, "line_end": 3}
+        ]
+        ages = compute_function_ages(rows, {"src/a.py": {1: 400, 2: 100, 3: 10, 9: 1}})
+        assert ages == [FunctionAge("src/a.py", "f", 12, 1, 3, 100, 10)]
+
+    def test_unblamed_files_skipped(self):
+        rows = [
+            {"file": "src/a.py", "function_name": "f", "ccn": 12, "line_start": 1, "line_end": 3}
+        ]
+        assert compute_function_ages(rows, {}) == []
+
+
+class TestStaleComplex:
+    """Tests for the stale-complex section."""
+
+    def test_filters_old_and_complex(self):
+        ages = [
+            _age("old_complex", 15, 800, 700),
+            _age("old_simple", 3, 800, 700),
+            _age("fresh_complex", 15, 800, 5),
+        ]
+        assert [f.function_name for f in stale_complex(ages)] == ["old_complex"]
+
+    def test_coverage_join_demands_untested(self):
+        ages = [_age("tested", 15, 800, 700), _age("untested", 15, 800, 700, start=50)]
+        coverage = {
+            ("src/a.py", "tested", 1): 90.0,
+            ("src/a.py", "untested", 50): 10.0,
+        }
+        stale = stale_complex(ages, coverage_pct_by_function=coverage)
+        assert [f.function_name for f in stale] == ["untested"]
+
+    def test_oldest_first(self):
+        ages = [_age("older", 12, 900, 900), _age("oldest", 12, 990, 990)]
+        assert [f.function_name for f in stale_complex(ages)] == ["oldest", "older"]
+
+
+class TestRecentlyChurned:
+    """Tests for the churn section."""
+
+    def test_filters_recent_and_complex(self):
+        ages = [
+            _age("hot", 20, 400, 3),
+            _age("stale", 20, 400, 200),
+            _age("simple", 2, 400, 3),
+        ]
+        assert [f.function_name for f in recently_churned(ages)] == ["hot"]
+
+    def test_most_complex_first(self):
+        ages = [_age("warm", 11, 100, 9), _age("hot", 25, 100, 9)]
+        assert [f.function_name for f in recently_churned(ages)] == ["hot", "warm"]
+
+
+class TestBlameLineAges:
+    """Tests against a real throwaway git repository."""
+
+    @pytest.fixture
+    def repo(self, tmp_path: Path) -> Path:
+        def git(*args: str) -> None:
+            subprocess.run(
+                ["git", "-C", str(tmp_path), *args],
+                check=True,
+                capture_output=True,
+                env={
+                    **os.environ,
+                    "GIT_AUTHOR_NAME": "t",
+                    "GIT_AUTHOR_EMAIL": "t@example.com",
+                    "GIT_COMMITTER_NAME": "t",
+                    "GIT_COMMITTER_EMAIL": "t@example.com",
+                },
+            )
+
+        git("init", "-q")
+        (tmp_path / "a.py").write_text("one\ntwo\n")
+        git("add", "-A")
+        git("commit", "-q", "-m", "initial")
+        return tmp_path
+
+    def test_every_line_gets_an_age(self, repo: Path) -> None:
+        ages = blame_line_ages(repo, "a.py", as_of=datetime.now(timezone.utc))
+        assert sorted(ages) == [1, 2]
+        assert all(age == 0 for age in ages.values())
+
+    def test_untracked_file_raises(self, repo: Path) -> None:
+        with pytest.raises(ValueError, match="git blame failed"):
+            blame_line_ages(repo, "missing.py")
@@ -0,0 +1,111 @@
+"""Tests for the production/test/fixture code classification."""
+
+import pytest
+
+from insights.code_classification import (
+    classify_path,
+    compute_bucket_metrics,
+    compute_test_ratio,
+)
+
+
+class TestClassifyPath:
+    """Tests for the path heuristics."""
+
+    @pytest.mark.parametrize(
+        "path",
+        [
+            "src/main.py",
+            "src/api/handlers.cs",
+            "Makefile",
+            "src/attestation.py",  # contains "test" as a substring only
+        ],
+    )
+    def test_production(self, path):
+        assert classify_path(path) == "production"
+
+    @pytest.mark.parametrize(
+        "path",
+        [
+            "tests/test_api.py",
+            "src/module/test/helper.py",
+            "src/__tests__/app.test.ts",
+            "pkg/server_test.go",
+            "src/Api/ApiTests.cs",
+            "spec/models/user.spec.js",
+            "tests/conftest.py",
+        ],
+    )
+    def test_test(self, path):
+        assert classify_path(path) == "test"
+
+    @pytest.mark.parametrize(
+        "path",
+        [
+            "tests/fixtures/large_payload.json",
+            "testdata/golden_output.txt",
+            "src/__snapshots__/app.snap",
+            "tests/golden/report.html",
+        ],
+    )
+    def test_fixture(self, path):
+        assert classify_path(path) == "fixture"
+
+    def test_fixture_wins_over_test_directory(self):
+        assert classify_path("tests/fixtures/test_input.py") == "fixture"
+
+
+class TestComputeBucketMetrics:
+    """Tests for the per-bucket aggregation."""
+
+    def test_lines_and_counts_per_bucket(self):
+        scc = [
+            {"relative_path": "src/main.py", "code_lines": 800},
+            {"relative_path": "tests/test_main.py", "code_lines": 400},
+            {"relative_path": "tests/fixtures/data.json", "code_lines": 9000},
+        ]
+        buckets = {bucket.bucket: bucket for bucket in compute_bucket_metrics(scc)}
+        assert buckets["production"].code_lines == 800
+        assert buckets["test"].code_lines == 400
+        assert buckets["fixture"].code_lines == 9000
+
+    def test_mean_ccn_split_per_bucket(self):
+        scc = [
+            {"relative_path": "src/main.py", "code_lines": 100},
+            {"relative_path": "tests/test_main.py", "code_lines": 100},
+        ]
+        lizard = [
+            {"relative_path": "src/main.py", "total_ccn": 40, "function_count": 4},
+            {"relative_path": "tests/test_main.py", "total_ccn": 10, "function_count": 10},
+        ]
+        buckets = {b.bucket: b for b in compute_bucket_metrics(scc, lizard)}
+        assert buckets["production"].mean_ccn == 10.0
+        assert buckets["test"].mean_ccn == 1.0
+
+    def test_empty_buckets_still_reported(self):
+        buckets = compute_bucket_metrics([{"relative_path": "src/main.py", "code_lines": 10}])
+        assert [bucket.bucket for bucket in buckets] == ["production", "test", "fixture"]
+        assert buckets[2].file_count == 0
+        assert buckets[2].mean_ccn is None
+
+
+class TestComputeTestRatio:
+    """Tests for the test-to-code ratio."""
+
+    def test_ratio(self):
+        scc = [
+            {"relative_path": "src/main.py", "code_lines": 1000},
+            {"relative_path": "tests/test_main.py", "code_lines": 500},
+        ]
+        assert compute_test_ratio(compute_bucket_metrics(scc)) == 0.5
+
+    def test_fixtures_excluded_from_ratio(self):
+        scc = [
+            {"relative_path": "src/main.py", "code_lines": 1000},
+            {"relative_path": "tests/fixtures/data.json", "code_lines": 9000},
+        ]
+        assert compute_test_ratio(compute_bucket_metrics(scc)) == 0.0
+
+    def test_no_production_code_yields_none(self):
+        scc = [{"relative_path": "tests/test_main.py", "code_lines": 500}]
+        assert compute_test_ratio(compute_bucket_metrics(scc)) is None
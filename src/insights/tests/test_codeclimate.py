@@ -0,0 +1,76 @@
+"""Tests for the Code Climate issue format reporter."""
+
+from pathlib import Path
+
+from insights.codeclimate import (
+    DEFAULT_CATEGORY,
+    export_codeclimate_issues,
+    fingerprint,
+    write_report,
+)
+
+
+def _finding(**overrides) -> dict:
+    finding = {
+        "tool": "bandit",
+        "relative_path": "src/app.py",
+        "rule_id": "B608",
+        "severity": "HIGH",
+        "line_start": 14,
+        "line_end": 14,
+        "message": "Possible SQL injection",
+    }
+    finding.update(overrides)
+    return finding
+
+
+class TestExport:
+    """Tests for issue object conversion."""
+
+    def test_export_maps_core_fields(self):
+        issues = export_codeclimate_issues([_finding()])
+        assert len(issues) == 1
+        issue = issues[0]
+        assert issue["type"] == "issue"
+        assert issue["check_name"] == "bandit/B608"
+        assert issue["description"] == "Possible SQL injection"
+        assert issue["categories"] == ["Security"]
+        assert issue["severity"] == "critical"  # HIGH -> critical
+        assert issue["location"] == {
+            "path": "src/app.py",
+            "lines": {"begin": 14, "end": 14},
+        }
+
+    def test_export_unknown_tool_falls_back(self):
+        issues = export_codeclimate_issues([_finding(tool="somefuturetool", severity="LOW")])
+        assert issues[0]["categories"] == [DEFAULT_CATEGORY]
+        assert issues[0]["severity"] == "minor"
+
+    def test_export_missing_lines_default_to_one(self):
+        issues = export_codeclimate_issues([_finding(line_start=None, line_end=None)])
+        assert issues[0]["location"]["lines"] == {"begin": 1, "end": 1}
+
+    def test_export_end_never_before_begin(self):
+        issues = export_codeclimate_issues([_finding(line_start=20, line_end=14)])
+        assert issues[0]["location"]["lines"] == {"begin": 20, "end": 20}
+
+
+class TestFingerprint:
+    """Tests for finding identity."""
+
+    def test_fingerprint_is_stable(self):
+        assert fingerprint(_finding()) == fingerprint(_finding())
+
+    def test_fingerprint_varies_by_location(self):
+        assert fingerprint(_finding()) != fingerprint(_finding(line_start=15))
+        assert fingerprint(_finding()) != fingerprint(_finding(relative_path="src/other.py"))
+
+    def test_fingerprint_ignores_message_changes(self):
+        assert fingerprint(_finding()) == fingerprint(_finding(message="reworded"))
+
+
+def test_write_report(tmp_path: Path):
+    output = tmp_path / "reports" / "gl-code-quality-report.json"
+    count = write_report([_finding(), _finding(rule_id="B102")], output)
+    assert count == 2
+    assert output.exists()
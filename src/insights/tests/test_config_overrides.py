@@ -0,0 +1,111 @@
+"""Tests for directory-level configuration overrides."""
+
+import pytest
+from pathlib import Path
+
+from insights.config_overrides import (
+    OverrideRule,
+    deep_merge,
+    glob_match,
+    load_overrides,
+    resolve_for_path,
+)
+
+
+class TestGlobMatch:
+    """Tests for override glob pattern matching."""
+
+    def test_double_star_spans_directories(self):
+        assert glob_match("tests/**", "tests/unit/test_app.py")
+        assert glob_match("tests/**", "tests/conftest.py")
+
+    def test_trailing_double_star_matches_directory_itself(self):
+        assert glob_match("tests/**", "tests")
+
+    def test_single_star_stays_in_segment(self):
+        assert glob_match("src/*.py", "src/app.py")
+        assert not glob_match("src/*.py", "src/nested/app.py")
+
+    def test_leading_double_star(self):
+        assert glob_match("**/generated/**", "src/generated/model.py")
+        assert glob_match("**/generated/**", "generated/model.py")
+
+    def test_non_matching_prefix(self):
+        assert not glob_match("tests/**", "src/tests_helper.py")
+
+    def test_question_mark(self):
+        assert glob_match("src/v?/main.py", "src/v1/main.py")
+        assert not glob_match("src/v?/main.py", "src/v12/main.py")
+
+
+class TestDeepMerge:
+    """Tests for config table merging."""
+
+    def test_scalar_override_wins(self):
+        merged = deep_merge({"a": 1, "b": 2}, {"b": 3})
+        assert merged == {"a": 1, "b": 3}
+
+    def test_nested_tables_merge(self):
+        base = {"health_score": {"caps": {"complexity": 20.0, "size": 1000.0}}}
+        override = {"health_score": {"caps": {"complexity": 40.0}}}
+        merged = deep_merge(base, override)
+        assert merged["health_score"]["caps"] == {"complexity": 40.0, "size": 1000.0}
+
+    def test_base_is_not_mutated(self):
+        base = {"caps": {"complexity": 20.0}}
+        deep_merge(base, {"caps": {"complexity": 40.0}})
+        assert base["caps"]["complexity"] == 20.0
+
+
+class TestResolveForPath:
+    """Tests for effective config resolution."""
+
+    def _config(self):
+        return {
+            "health_score": {"caps": {"complexity": 20.0}},
+            "overrides": {
+                "tests/**": {"health_score": {"caps": {"complexity": 40.0}}},
+                "tests/slow/**": {"health_score": {"caps": {"complexity": 60.0}}},
+            },
+        }
+
+    def test_non_matching_path_keeps_base(self):
+        effective = resolve_for_path(self._config(), "src/app.py")
+        assert effective["health_score"]["caps"]["complexity"] == 20.0
+
+    def test_matching_override_applies(self):
+        effective = resolve_for_path(self._config(), "tests/test_app.py")
+        assert effective["health_score"]["caps"]["complexity"] == 40.0
+
+    def test_later_entries_win(self):
+        effective = resolve_for_path(self._config(), "tests/slow/test_big.py")
+        assert effective["health_score"]["caps"]["complexity"] == 60.0
+
+    def test_overrides_table_is_stripped(self):
+        effective = resolve_for_path(self._config(), "src/app.py")
+        assert "overrides" not in effective
+
+
+class TestLoadOverrides:
+    """Tests for loading rules from caldera.toml."""
+
+    def test_loads_in_declaration_order(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text(
+            '[overrides."tests/**".health_score.caps]\ncomplexity = 40.0\n\n'
+            '[overrides."src/generated/**".health_score.weights]\nduplication = 0.0\n'
+        )
+        rules = load_overrides(config_path)
+        assert [rule.pattern for rule in rules] == ["tests/**", "src/generated/**"]
+        assert rules[0].settings["health_score"]["caps"]["complexity"] == 40.0
+
+    def test_missing_file_yields_no_rules(self, tmp_path: Path):
+        assert load_overrides(tmp_path / "nope.toml") == []
+
+    def test_empty_pattern_rejected(self):
+        with pytest.raises(ValueError, match="non-empty"):
+            OverrideRule(pattern="", settings={})
+
+    def test_non_table_settings_rejected(self):
+        with pytest.raises(ValueError, match="table of config sections"):
+            OverrideRule(pattern="tests/**", settings="relaxed")
@@ -0,0 +1,114 @@
+"""Tests for the remediation-minutes technical debt model."""
+
+import pytest
+from pathlib import Path
+
+from insights.debt import (
+    DebtConfig,
+    DEFAULT_SEVERITY_MINUTES,
+    aggregate_directory_debt,
+    compute_file_debt,
+    format_minutes,
+    load_debt_config,
+)
+
+
+def _config(**overrides) -> DebtConfig:
+    return DebtConfig(severity_minutes=dict(DEFAULT_SEVERITY_MINUTES), **overrides)
+
+
+class TestDebtConfig:
+    """Tests for cost model validation and loading."""
+
+    def test_defaults_are_valid(self):
+        _config()
+
+    def test_negative_cost_rejected(self):
+        with pytest.raises(ValueError, match="severity_minutes for HIGH"):
+            DebtConfig(severity_minutes={"HIGH": -1})
+
+    def test_load_merges_over_defaults(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text(
+            "[debt]\nccn_threshold = 15\n\n[debt.severity_minutes]\nhigh = 45\n"
+        )
+        config = load_debt_config(config_path)
+        assert config.severity_minutes["HIGH"] == 45.0
+        assert config.severity_minutes["LOW"] == 5.0
+        assert config.ccn_threshold == 15
+
+    def test_missing_file_yields_defaults(self, tmp_path: Path):
+        config = load_debt_config(tmp_path / "missing.toml")
+        assert config.severity_minutes == DEFAULT_SEVERITY_MINUTES
+
+
+class TestComputeFileDebt:
+    """Tests for per-file pricing."""
+
+    def test_findings_priced_by_severity(self):
+        rows = [
+            {"relative_path": "src/a.py", "severity": "HIGH", "finding_count": 2},
+            {"relative_path": "src/a.py", "severity": "LOW", "finding_count": 1},
+        ]
+        debt = compute_file_debt(rows, config=_config())[0]
+        assert debt.finding_minutes == 65.0
+        assert debt.total_minutes == 65.0
+
+    def test_unknown_severity_priced_as_medium(self):
+        rows = [{"relative_path": "src/a.py", "severity": "BLOCKER", "finding_count": 1}]
+        assert compute_file_debt(rows, config=_config())[0].finding_minutes == 15.0
+
+    def test_complex_functions_priced_above_threshold(self):
+        functions = [
+            {"file": "src/a.py", "ccn": 12},
+            {"file": "src/a.py", "ccn": 9},  # below threshold, free
+        ]
+        debt = compute_file_debt([], function_rows=functions, config=_config())[0]
+        assert debt.complexity_minutes == 20.0
+
+    def test_duplicated_lines_priced_per_line(self):
+        duplication = [{"relative_path": "src/a.py", "duplicate_lines": 40}]
+        debt = compute_file_debt([], duplication_rows=duplication, config=_config())[0]
+        assert debt.duplication_minutes == 20.0
+
+    def test_sorted_most_expensive_first(self):
+        rows = [
+            {"relative_path": "src/cheap.py", "severity": "LOW", "finding_count": 1},
+            {"relative_path": "src/costly.py", "severity": "CRITICAL", "finding_count": 2},
+        ]
+        paths = [debt.relative_path for debt in compute_file_debt(rows, config=_config())]
+        assert paths == ["src/costly.py", "src/cheap.py"]
+
+    def test_debt_free_files_omitted(self):
+        functions = [{"file": "src/simple.py", "ccn": 2}]
+        assert compute_file_debt([], function_rows=functions, config=_config()) == []
+
+
+class TestAggregateDirectoryDebt:
+    """Tests for the directory rollup."""
+
+    def test_sums_direct_files(self):
+        rows = [
+            {"relative_path": "src/a.py", "severity": "HIGH", "finding_count": 1},
+            {"relative_path": "src/b.py", "severity": "HIGH", "finding_count": 1},
+            {"relative_path": "README.md", "severity": "LOW", "finding_count": 1},
+        ]
+        directories = aggregate_directory_debt(compute_file_debt(rows, config=_config()))
+        assert [(entry.directory, entry.file_count) for entry in directories] == [
+            ("src", 2),
+            (".", 1),
+        ]
+        assert directories[0].finding_minutes == 60.0
+
+
+class TestFormatMinutes:
+    """Tests for the SQALE duration rendering."""
+
+    def test_minutes(self):
+        assert format_minutes(45) == "45m"
+
+    def test_hours(self):
+        assert format_minutes(195) == "3h 15m"
+
+    def test_days_use_eight_hour_day(self):
+        assert format_minutes(9 * 60) == "1d 1h"
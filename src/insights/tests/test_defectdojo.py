@@ -0,0 +1,86 @@
+"""Tests for the DefectDojo exporter."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from insights.codeclimate import fingerprint
+from insights.defectdojo import export_findings, map_cwe, write_export
+
+
+def _finding(**overrides) -> dict:
+    finding = {
+        "tool": "semgrep",
+        "rule_id": "rule-1",
+        "severity": "HIGH",
+        "relative_path": "src/app.py",
+        "line_start": 10,
+        "message": "something smells",
+    }
+    finding.update(overrides)
+    return finding
+
+
+class TestMapCwe:
+    def test_explicit_cwe_field_wins(self) -> None:
+        assert map_cwe(_finding(cwe_id="CWE-89", dd_category="xss")) == 89
+
+    def test_category_mapping(self) -> None:
+        assert map_cwe(_finding(dd_category="sql_injection")) == 89
+        assert map_cwe(_finding(dd_category="command_injection")) == 78
+
+    def test_gitleaks_defaults_to_hardcoded_credentials(self) -> None:
+        assert map_cwe(_finding(tool="gitleaks")) == 798
+
+    def test_unmappable_finding_has_no_cwe(self) -> None:
+        assert map_cwe(_finding()) is None
+
+
+class TestExportFindings:
+    def test_finding_shape(self) -> None:
+        document = export_findings([_finding()], date="2026-08-26")
+        assert len(document["findings"]) == 1
+        entry = document["findings"][0]
+        assert entry["title"] == "semgrep: rule-1"
+        assert entry["severity"] == "High"
+        assert entry["file_path"] == "src/app.py"
+        assert entry["line"] == 10
+        assert entry["static_finding"] is True
+        assert entry["date"] == "2026-08-26"
+        assert "cwe" not in entry
+
+    def test_dedup_key_is_stable_fingerprint(self) -> None:
+        finding = _finding()
+        document = export_findings([finding])
+        assert document["findings"][0]["unique_id_from_tool"] == fingerprint(finding)
+        assert export_findings([finding]) == document  # re-export is identical
+
+    def test_non_security_tools_filtered_by_default(self) -> None:
+        assert export_findings([_finding(tool="lizard")])["findings"] == []
+        document = export_findings([_finding(tool="lizard")], tools={"lizard"})
+        assert len(document["findings"]) == 1
+
+    def test_trivy_component_and_mitigation(self) -> None:
+        finding = _finding(
+            tool="trivy", rule_id=None, vulnerability_id="CVE-2024-1234",
+            pkg_name="libfoo", installed_version="1.0", fixed_version="1.1",
+        )
+        entry = export_findings([finding])["findings"][0]
+        assert entry["vuln_id_from_tool"] == "CVE-2024-1234"
+        assert entry["component_name"] == "libfoo"
+        assert entry["component_version"] == "1.0"
+        assert entry["mitigation"] == "Upgrade to 1.1"
+
+    def test_unknown_severity_defaults_to_medium(self) -> None:
+        entry = export_findings([_finding(severity="BOGUS")])["findings"][0]
+        assert entry["severity"] == "Medium"
+
+
+class TestWriteExport:
+    def test_writes_document_and_returns_count(self, tmp_path: Path) -> None:
+        output = tmp_path / "out" / "defectdojo.json"
+        count = write_export([_finding(), _finding(tool="lizard")], output)
+        assert count == 1
+        document = json.loads(output.read_text())
+        assert len(document["findings"]) == 1
@@ -0,0 +1,144 @@
+"""Tests for the module dependency graph and cycle detection."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from insights.dependency_graph import (
+    DependencyCycle,
+    build_dependency_graph,
+    discover_source_files,
+    find_cycles,
+)
+
+
+def _edges(graph) -> set[tuple[str, str]]:
+    return set(graph.edges)
+
+
+class TestDiscoverSourceFiles:
+    def test_skips_vendored_and_generated_directories(self, tmp_path: Path) -> None:
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "app.py").write_text("")
+        (tmp_path / "node_modules" / "pkg").mkdir(parents=True)
+        (tmp_path / "node_modules" / "pkg" / "index.js").write_text("")
+        (tmp_path / "target").mkdir()
+        (tmp_path / "target" / "gen.rs").write_text("")
+
+        assert discover_source_files(tmp_path) == ["src/app.py"]
+
+
+class TestPythonResolution:
+    def test_absolute_and_from_imports_resolve_to_repo_files(self, tmp_path: Path) -> None:
+        pkg = tmp_path / "pkg"
+        pkg.mkdir()
+        (pkg / "__init__.py").write_text("")
+        (pkg / "core.py").write_text("import os\n")
+        (pkg / "api.py").write_text("import pkg.core\nfrom pkg import core\n")
+
+        graph = build_dependency_graph(tmp_path)
+
+        assert ("pkg/api.py", "pkg/core.py") in _edges(graph)
+        # stdlib import must not create an edge
+        assert all(imported != "os" for _, imported in graph.edges)
+
+    def test_relative_imports_resolve(self, tmp_path: Path) -> None:
+        pkg = tmp_path / "pkg" / "sub"
+        pkg.mkdir(parents=True)
+        (tmp_path / "pkg" / "__init__.py").write_text("")
+        (tmp_path / "pkg" / "base.py").write_text("")
+        (pkg / "__init__.py").write_text("")
+        (pkg / "child.py").write_text("from ..base import thing\nfrom . import sibling\n")
+        (pkg / "sibling.py").write_text("")
+
+        graph = build_dependency_graph(tmp_path)
+
+        assert ("pkg/sub/child.py", "pkg/base.py") in _edges(graph)
+        assert ("pkg/sub/child.py", "pkg/sub/sibling.py") in _edges(graph)
+
+
+class TestJavascriptResolution:
+    def test_relative_specifiers_resolve_with_extensions_and_index(self, tmp_path: Path) -> None:
+        src = tmp_path / "src"
+        (src / "util").mkdir(parents=True)
+        (src / "app.ts").write_text(
+            "import { helper } from './util';\nimport fs from 'fs';\n"
+        )
+        (src / "util" / "index.ts").write_text("import '../config';\n")
+        (src / "config.ts").write_text("")
+
+        graph = build_dependency_graph(tmp_path)
+
+        assert ("src/app.ts", "src/util/index.ts") in _edges(graph)
+        assert ("src/util/index.ts", "src/config.ts") in _edges(graph)
+        # bare specifier 'fs' is external
+        assert all("fs" != imported for _, imported in graph.edges)
+
+
+class TestRustResolution:
+    def test_mod_and_use_crate_resolve(self, tmp_path: Path) -> None:
+        src = tmp_path / "src"
+        src.mkdir()
+        (src / "lib.rs").write_text("pub mod parser;\npub mod emitter;\n")
+        (src / "parser.rs").write_text("use crate::emitter::Emit;\n")
+        (src / "emitter.rs").write_text("")
+
+        graph = build_dependency_graph(tmp_path)
+
+        assert ("src/lib.rs", "src/parser.rs") in _edges(graph)
+        assert ("src/lib.rs", "src/emitter.rs") in _edges(graph)
+        assert ("src/parser.rs", "src/emitter.rs") in _edges(graph)
+
+
+class TestFanInFanOut:
+    def test_counts_reflect_resolved_edges(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("import b\nimport c\n")
+        (tmp_path / "b.py").write_text("import c\n")
+        (tmp_path / "c.py").write_text("")
+
+        graph = build_dependency_graph(tmp_path)
+        by_path = {node.path: node for node in graph.nodes}
+
+        assert by_path["a.py"].fan_out == 2
+        assert by_path["c.py"].fan_in == 2
+        assert by_path["c.py"].fan_out == 0
+
+
+class TestFindCycles:
+    def test_reports_scc_with_membership_and_edges(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("import b\n")
+        (tmp_path / "b.py").write_text("import c\n")
+        (tmp_path / "c.py").write_text("import a\n")
+        (tmp_path / "standalone.py").write_text("import a\n")
+
+        cycles = find_cycles(build_dependency_graph(tmp_path))
+
+        assert len(cycles) == 1
+        assert cycles[0].members == ("a.py", "b.py", "c.py")
+        assert cycles[0].internal_edges == 3
+        assert cycles[0].size == 3
+
+    def test_acyclic_graph_has_no_cycles(self, tmp_path: Path) -> None:
+        (tmp_path / "a.py").write_text("import b\n")
+        (tmp_path / "b.py").write_text("")
+
+        assert find_cycles(build_dependency_graph(tmp_path)) == []
+
+    def test_larger_cycles_sort_first(self, tmp_path: Path) -> None:
+        for name, imports in {
+            "a": "import b", "b": "import a",
+            "x": "import y", "y": "import z", "z": "import x",
+        }.items():
+            (tmp_path / f"{name}.py").write_text(imports + "\n")
+
+        cycles = find_cycles(build_dependency_graph(tmp_path))
+
+        assert [cycle.size for cycle in cycles] == [3, 2]
+
+
+class TestDependencyCycle:
+    def test_rejects_empty_membership(self) -> None:
+        with pytest.raises(ValueError, match="members"):
+            DependencyCycle(members=(), internal_edges=0)
@@ -0,0 +1,114 @@
+"""Tests for the per-function coverage join and risk quadrant."""
+
+import pytest
+from pathlib import Path
+
+from insights.function_coverage import (
+    FunctionCoverage,
+    join_function_coverage,
+    parse_coverage,
+    risk_quadrant,
+)
+
+LCOV = """\
+SF:src/a.py
+DA:1,5
+DA:2,0
+DA:3,1
+end_of_record
+SF:./src/b.py
+DA:10,0
+end_of_record
+"""
+
+COBERTURA = """\
+<coverage>
+  <packages><package><classes>
+    <class filename="src/a.py">
+      <lines>
+        <line number="1" hits="5"/>
+        <line number="2" hits="0"/>
+      </lines>
+    </class>
+  </classes></package></packages>
+</coverage>
+"""
+
+LLVM_COV = """\
+{"data": [{"files": [
+  {"filename": "/repo/src/a.py",
+   "segments": [[1, 1, 4, true, true], [2, 1, 0, true, true], [5, 1, 0, false, true]]}
+]}]}
+"""
+
+
+def _fn(file="src/a.py", name="handler", ccn=12, start=1, end=3):
+    return {
+        "file": file,
+        "function_name": name,
+        "ccn": ccn,
+        "line_start": start,
+        "line_end": end,
+    }
+
+
+class TestParseCoverage:
+    """Tests for the three report formats."""
+
+    def test_lcov(self, tmp_path: Path):
+        report = tmp_path / "coverage.info"
+        report.write_text(LCOV)
+        hits = parse_coverage(report)
+        assert hits["src/a.py"] == {1: 5, 2: 0, 3: 1}
+        assert hits["src/b.py"] == {10: 0}  # leading ./ stripped
+
+    def test_cobertura(self, tmp_path: Path):
+        report = tmp_path / "coverage.xml"
+        report.write_text(COBERTURA)
+        assert parse_coverage(report)["src/a.py"] == {1: 5, 2: 0}
+
+    def test_llvm_cov_relativizes_paths(self, tmp_path: Path):
+        report = tmp_path / "coverage.json"
+        report.write_text(LLVM_COV.replace("/repo", tmp_path.as_posix()))
+        hits = parse_coverage(report, repo_path=tmp_path)
+        # The third segment has has_count=false and contributes nothing.
+        assert hits["src/a.py"] == {1: 4, 2: 0}
+
+    def test_unknown_format_rejected(self, tmp_path: Path):
+        report = tmp_path / "coverage.txt"
+        report.write_text("")
+        with pytest.raises(ValueError, match="unrecognized coverage format"):
+            parse_coverage(report)
+
+
+class TestJoinFunctionCoverage:
+    """Tests for the range intersection."""
+
+    def test_hits_inside_range_counted(self):
+        joined = join_function_coverage([_fn()], {"src/a.py": {1: 5, 2: 0, 3: 1, 9: 0}})
+        assert joined == [FunctionCoverage("src/a.py", "handler", 12, 1, 3, 3, 2)]
+        assert joined[0].coverage_pct == 66.7
+
+    def test_uninstrumented_file_skipped(self):
+        assert join_function_coverage([_fn()], {"src/other.py": {1: 1}}) == []
+
+    def test_uninstrumented_range_yields_none_pct(self):
+        joined = join_function_coverage([_fn(start=50, end=60)], {"src/a.py": {1: 1}})
+        assert joined[0].coverage_pct is None
+
+
+class TestRiskQuadrant:
+    """Tests for the high-CCN low-coverage filter."""
+
+    def test_filters_and_sorts_worst_first(self):
+        functions = [
+            FunctionCoverage("src/a.py", "covered", 20, 1, 5, 10, 9),  # 90%
+            FunctionCoverage("src/a.py", "simple", 3, 10, 15, 10, 0),  # low CCN
+            FunctionCoverage("src/a.py", "bad", 15, 20, 30, 10, 2),  # 20%
+            FunctionCoverage("src/a.py", "worst", 12, 40, 50, 10, 0),  # 0%
+        ]
+        assert [f.function_name for f in risk_quadrant(functions)] == ["worst", "bad"]
+
+    def test_uninstrumented_functions_excluded(self):
+        functions = [FunctionCoverage("src/a.py", "unknown", 20, 1, 5, 0, 0)]
+        assert risk_quadrant(functions) == []
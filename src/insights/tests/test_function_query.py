@@ -0,0 +1,95 @@
+"""Tests for the ad-hoc function metrics query builder."""
+
+import pytest
+
+from insights.function_query import (
+    COLUMNS,
+    Condition,
+    build_functions_sql,
+    parse_where,
+)
+
+
+class TestParseWhere:
+    """Tests for the --where expression parser."""
+
+    def test_numeric_comparison(self):
+        conditions = parse_where("ccn > 20")
+        assert conditions == [Condition(column="ccn", operator=">", value=20)]
+
+    def test_float_value(self):
+        conditions = parse_where("ccn >= 7.5")
+        assert conditions[0].value == 7.5
+
+    def test_quoted_string_equality(self):
+        conditions = parse_where("name = 'main'")
+        assert conditions == [Condition(column="name", operator="=", value="main")]
+
+    def test_bare_string_value(self):
+        conditions = parse_where("file ~ src/")
+        assert conditions == [Condition(column="file", operator="~", value="src/")]
+
+    def test_and_conjunction(self):
+        conditions = parse_where("ccn > 20 and nloc >= 100 AND params != 0")
+        assert [c.column for c in conditions] == ["ccn", "nloc", "params"]
+        assert [c.operator for c in conditions] == [">", ">=", "!="]
+
+    def test_empty_expression_yields_no_conditions(self):
+        assert parse_where(None) == []
+        assert parse_where("   ") == []
+
+    def test_unknown_column_rejected(self):
+        with pytest.raises(ValueError, match="unknown column: severity"):
+            parse_where("severity > 2")
+
+    def test_malformed_clause_rejected(self):
+        with pytest.raises(ValueError, match="cannot parse clause"):
+            parse_where("ccn is big")
+
+
+class TestBuildFunctionsSql:
+    """Tests for SQL rendering."""
+
+    def test_renders_conditions_and_run_map(self):
+        sql = build_functions_sql(19, parse_where("ccn > 20"))
+        assert "WHERE tr_anchor.run_pk = 19" in sql
+        assert "fm.ccn > 20" in sql
+        assert "tr_lizard.tool_name = 'lizard'" in sql
+
+    def test_no_conditions_selects_everything(self):
+        sql = build_functions_sql(19, [])
+        assert "WHERE TRUE" in sql
+
+    def test_sort_descending_by_default(self):
+        sql = build_functions_sql(19, [], sort="nloc")
+        assert "ORDER BY fm.nloc DESC NULLS LAST" in sql
+
+    def test_sort_ascending(self):
+        sql = build_functions_sql(19, [], sort="params", descending=False)
+        assert "ORDER BY fm.params ASC NULLS LAST" in sql
+
+    def test_nesting_maps_to_max_nesting_depth(self):
+        sql = build_functions_sql(19, parse_where("nesting >= 4"), sort="nesting")
+        assert "fm.max_nesting_depth >= 4" in sql
+        assert "ORDER BY fm.max_nesting_depth" in sql
+
+    def test_contains_renders_like(self):
+        sql = build_functions_sql(19, parse_where("file ~ adapters/"))
+        assert "lf.relative_path LIKE '%adapters/%'" in sql
+
+    def test_string_values_are_escaped(self):
+        sql = build_functions_sql(19, parse_where("name = 'O'Brien'"))
+        assert "''" in sql
+
+    def test_unknown_sort_column_rejected(self):
+        with pytest.raises(ValueError, match="unknown sort column"):
+            build_functions_sql(19, [], sort="badness")
+
+    def test_limit_applied(self):
+        sql = build_functions_sql(19, [], limit=5)
+        assert "LIMIT 5" in sql
+
+    def test_every_column_alias_is_queryable(self):
+        for column in COLUMNS:
+            sql = build_functions_sql(19, [], sort=column)
+            assert COLUMNS[column] in sql
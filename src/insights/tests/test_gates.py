@@ -0,0 +1,468 @@
+"""Tests for quality gate evaluation."""
+
+import pytest
+from pathlib import Path
+
+from insights.gates import (
+    CloneGateConfig,
+    ComplexityGateConfig,
+    DEFAULT_GATES,
+    GateConfig,
+    PolicyGateConfig,
+    evaluate_complexity_delta,
+    evaluate_no_new_clones,
+    evaluate_policy_gate,
+    group_clone_rows,
+    evaluate_no_fixable_criticals,
+    gates_passed,
+    is_fixable,
+    load_clone_gate_config,
+    load_complexity_gate_config,
+    load_gates_config,
+    load_policy_gates,
+    match_functions,
+    run_gates,
+)
+
+
+def _vuln(
+    vulnerability_id: str = "CVE-2024-0001",
+    severity: str = "CRITICAL",
+    fixed_version: str | None = "1.2.3",
+    fix_available: bool = True,
+) -> dict:
+    return {
+        "vulnerability_id": vulnerability_id,
+        "package_name": "libexample",
+        "installed_version": "1.0.0",
+        "fixed_version": fixed_version,
+        "severity": severity,
+        "cvss_score": 9.8,
+        "fix_available": fix_available,
+    }
+
+
+def _default_config(**overrides) -> GateConfig:
+    params = dict(DEFAULT_GATES["no_fixable_criticals"])
+    params.update(overrides)
+    return GateConfig(
+        name="no_fixable_criticals",
+        enabled=params["enabled"],
+        severities=tuple(params["severities"]),
+        max_count=params["max_count"],
+    )
+
+
+class TestGatesConfig:
+    """Tests for config validation and loading."""
+
+    def test_defaults_are_valid(self):
+        configs = load_gates_config()
+        assert [config.name for config in configs] == ["no_fixable_criticals"]
+        assert configs[0].enabled
+        assert configs[0].max_count == 0
+
+    def test_negative_max_count_rejected(self):
+        with pytest.raises(ValueError, match="max_count"):
+            _default_config(max_count=-1)
+
+    def test_empty_severities_rejected(self):
+        with pytest.raises(ValueError, match="severities"):
+            _default_config(severities=[])
+
+    def test_load_from_caldera_toml(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text(
+            '[gates.no_fixable_criticals]\nseverities = ["critical", "high"]\nmax_count = 3\n'
+        )
+        configs = load_gates_config(config_path)
+        assert configs[0].severities == ("CRITICAL", "HIGH")
+        assert configs[0].max_count == 3
+        assert configs[0].enabled  # default preserved
+
+    def test_load_unknown_gate_rejected(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text("[gates.no_typos_here]\nenabled = true\n")
+        with pytest.raises(ValueError, match="unknown gate"):
+            load_gates_config(config_path)
+
+    def test_load_missing_file_uses_defaults(self, tmp_path: Path):
+        configs = load_gates_config(tmp_path / "nope.toml")
+        assert configs[0].max_count == DEFAULT_GATES["no_fixable_criticals"]["max_count"]
+
+
+class TestFixability:
+    """Tests for the fixable heuristic."""
+
+    def test_fix_available_flag(self):
+        assert is_fixable(_vuln(fix_available=True, fixed_version=None))
+
+    def test_fixed_version_fallback(self):
+        assert is_fixable(_vuln(fix_available=False, fixed_version="2.0.0"))
+
+    def test_not_fixable(self):
+        assert not is_fixable(_vuln(fix_available=False, fixed_version=None))
+        assert not is_fixable(_vuln(fix_available=False, fixed_version="  "))
+
+
+class TestNoFixableCriticals:
+    """Tests for the fixable-criticals gate."""
+
+    def test_clean_run_passes(self):
+        result = evaluate_no_fixable_criticals([], _default_config())
+        assert result.passed
+        assert result.actual == 0
+
+    def test_fixable_critical_fails(self):
+        result = evaluate_no_fixable_criticals([_vuln()], _default_config())
+        assert not result.passed
+        assert result.actual == 1
+        assert "CVE-2024-0001" in result.offenders[0]
+        assert "libexample" in result.offenders[0]
+
+    def test_unfixable_critical_passes(self):
+        vulns = [_vuln(fix_available=False, fixed_version=None)]
+        assert evaluate_no_fixable_criticals(vulns, _default_config()).passed
+
+    def test_fixable_high_ignored_by_default(self):
+        assert evaluate_no_fixable_criticals([_vuln(severity="HIGH")], _default_config()).passed
+
+    def test_severities_extend_the_gate(self):
+        config = _default_config(severities=["CRITICAL", "HIGH"])
+        result = evaluate_no_fixable_criticals([_vuln(severity="HIGH")], config)
+        assert not result.passed
+
+    def test_max_count_allows_budget(self):
+        config = _default_config(max_count=2)
+        vulns = [_vuln(vulnerability_id=f"CVE-2024-000{i}") for i in range(2)]
+        assert evaluate_no_fixable_criticals(vulns, config).passed
+        vulns.append(_vuln(vulnerability_id="CVE-2024-0009"))
+        assert not evaluate_no_fixable_criticals(vulns, config).passed
+
+
+class TestRunGates:
+    """Tests for the gate runner."""
+
+    def test_disabled_gate_is_skipped(self):
+        configs = [_default_config(enabled=False)]
+        assert run_gates([_vuln()], configs) == []
+
+    def test_gates_passed_aggregates(self):
+        results = run_gates([_vuln()], [_default_config()])
+        assert not gates_passed(results)
+        assert gates_passed(run_gates([], [_default_config()]))
+
+
+def _fn(
+    file: str = "src/a.py",
+    name: str = "foo",
+    ccn: int = 5,
+    nloc: int = 10,
+    token_count: int = 50,
+    long_name: str | None = None,
+) -> dict:
+    return {
+        "file": file,
+        "function_name": name,
+        "long_name": long_name or f"{name}()",
+        "ccn": ccn,
+        "nloc": nloc,
+        "token_count": token_count,
+    }
+
+
+def _complexity_config(**overrides) -> ComplexityGateConfig:
+    params = dict(DEFAULT_GATES["complexity_delta"])
+    params.update(overrides)
+    return ComplexityGateConfig(
+        enabled=params["enabled"],
+        max_ccn_increase=params["max_ccn_increase"],
+        max_ccn=params["max_ccn"],
+    )
+
+
+class TestMatchFunctions:
+    """Tests for base/head function matching."""
+
+    def test_exact_match_by_file_and_signature(self):
+        pairs, added = match_functions([_fn(ccn=3)], [_fn(ccn=9)])
+        assert [(b["ccn"], h["ccn"]) for b, h in pairs] == [(3, 9)]
+        assert added == []
+
+    def test_moved_file_matches_by_signature(self):
+        pairs, added = match_functions([_fn(file="src/old.py")], [_fn(file="src/new.py")])
+        assert len(pairs) == 1
+        assert added == []
+
+    def test_rename_in_place_matches_by_shape(self):
+        base = [_fn(name="old_name", nloc=42, token_count=300)]
+        head = [_fn(name="new_name", nloc=42, token_count=300)]
+        pairs, added = match_functions(base, head)
+        assert len(pairs) == 1
+        assert added == []
+
+    def test_genuinely_new_function_is_unmatched(self):
+        pairs, added = match_functions([_fn()], [_fn(), _fn(name="bar", nloc=7)])
+        assert len(pairs) == 1
+        assert [f["function_name"] for f in added] == ["bar"]
+
+
+class TestComplexityDeltaGate:
+    """Tests for the per-function complexity delta gate."""
+
+    def test_increase_within_delta_passes(self):
+        result = evaluate_complexity_delta([_fn(ccn=5)], [_fn(ccn=9)], _complexity_config())
+        assert result.passed
+
+    def test_increase_beyond_delta_fails(self):
+        result = evaluate_complexity_delta([_fn(ccn=5)], [_fn(ccn=11)], _complexity_config())
+        assert not result.passed
+        assert "CCN 5 -> 11 (+6, limit +5)" in result.offenders[0]
+
+    def test_crossing_ceiling_fails_even_with_small_delta(self):
+        config = _complexity_config(max_ccn=15)
+        result = evaluate_complexity_delta([_fn(ccn=14)], [_fn(ccn=16)], config)
+        assert not result.passed
+        assert "crosses ceiling 15" in result.offenders[0]
+
+    def test_already_over_ceiling_unchanged_passes(self):
+        result = evaluate_complexity_delta([_fn(ccn=20)], [_fn(ccn=21)], _complexity_config())
+        assert result.passed
+
+    def test_new_function_above_ceiling_fails(self):
+        result = evaluate_complexity_delta(
+            [], [_fn(name="fresh", ccn=18, nloc=30)], _complexity_config()
+        )
+        assert not result.passed
+        assert "new function with CCN 18" in result.offenders[0]
+
+    def test_moved_function_is_not_a_false_failure(self):
+        base = [_fn(file="src/old.py", ccn=18)]
+        head = [_fn(file="src/new.py", ccn=18)]
+        assert evaluate_complexity_delta(base, head, _complexity_config()).passed
+
+    def test_run_gates_includes_complexity_when_functions_given(self):
+        results = run_gates(
+            [],
+            [],
+            complexity_functions=([_fn(ccn=3)], [_fn(ccn=12)]),
+            complexity_config=_complexity_config(),
+        )
+        assert [r.name for r in results] == ["complexity_delta"]
+        assert not gates_passed(results)
+
+    def test_config_validation(self):
+        with pytest.raises(ValueError, match="max_ccn_increase"):
+            _complexity_config(max_ccn_increase=0)
+
+
+class TestLoadComplexityGateConfig:
+    def test_defaults_without_file(self):
+        config = load_complexity_gate_config(None)
+        assert config == ComplexityGateConfig(True, 5, 15)
+
+    def test_overrides_from_toml(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text("[gates.complexity_delta]\nmax_ccn_increase = 3\n")
+        config = load_complexity_gate_config(toml)
+        assert config.max_ccn_increase == 3
+        assert config.max_ccn == 15
+
+    def test_count_gate_loader_skips_complexity_entry(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text("[gates.complexity_delta]\nenabled = false\n")
+        names = [config.name for config in load_gates_config(toml)]
+        assert "complexity_delta" not in names
+
+
+def _clone(
+    clone_id: str = "clone-1",
+    lines: int = 20,
+    tokens: int = 120,
+    fragment: str | None = "x = 1\ny = 2",
+    paths: tuple[str, ...] = ("src/a.py", "src/b.py"),
+) -> dict:
+    return {
+        "clone_id": clone_id,
+        "lines": lines,
+        "tokens": tokens,
+        "code_fragment": fragment,
+        "occurrences": [
+            {"path": path, "line_start": 1, "line_end": lines} for path in paths
+        ],
+    }
+
+
+def _clone_config(**overrides) -> CloneGateConfig:
+    params = dict(DEFAULT_GATES["no_new_clones"])
+    params.update(overrides)
+    return CloneGateConfig(enabled=params["enabled"], min_lines=params["min_lines"])
+
+
+class TestGroupCloneRows:
+    def test_groups_occurrences_per_clone(self):
+        rows = [
+            {"clone_id": "c1", "lines": 12, "tokens": 80, "code_fragment": "x",
+             "relative_path": "a.py", "line_start": 1, "line_end": 12},
+            {"clone_id": "c1", "lines": 12, "tokens": 80, "code_fragment": "x",
+             "relative_path": "b.py", "line_start": 5, "line_end": 16},
+        ]
+        clones = group_clone_rows(rows)
+        assert len(clones) == 1
+        assert [o["path"] for o in clones[0]["occurrences"]] == ["a.py", "b.py"]
+
+
+class TestNewCloneGate:
+    """Tests for the new-clone gate."""
+
+    def test_pre_existing_clone_passes(self):
+        clone = _clone()
+        result = evaluate_no_new_clones([clone], [clone], _clone_config())
+        assert result.passed
+
+    def test_moved_legacy_clone_still_matches(self):
+        base = _clone(clone_id="old-id", fragment="x = 1\ny = 2")
+        head = _clone(clone_id="new-id", fragment="  x = 1\n  y = 2\n")
+        head["occurrences"] = [
+            {"path": o["path"], "line_start": 40, "line_end": 59}
+            for o in head["occurrences"]
+        ]
+        assert evaluate_no_new_clones([base], [head], _clone_config()).passed
+
+    def test_new_clone_above_threshold_fails(self):
+        result = evaluate_no_new_clones([], [_clone(lines=15)], _clone_config())
+        assert not result.passed
+        assert "15 lines x 2 occurrences" in result.offenders[0]
+
+    def test_small_new_clone_passes(self):
+        result = evaluate_no_new_clones([], [_clone(lines=6)], _clone_config())
+        assert result.passed
+
+    def test_changed_files_scope_the_gate(self):
+        new = _clone(paths=("src/legacy/a.rs", "src/legacy/b.rs"))
+        config = _clone_config()
+        assert evaluate_no_new_clones([], [new], config, changed_files={"src/other.py"}).passed
+        assert not evaluate_no_new_clones(
+            [], [new], config, changed_files={"src/legacy/a.rs"}
+        ).passed
+
+    def test_run_gates_includes_clone_gate_when_sets_given(self):
+        results = run_gates(
+            [],
+            [],
+            clone_sets=([], [_clone()]),
+            clone_config=_clone_config(),
+        )
+        assert [r.name for r in results] == ["no_new_clones"]
+        assert not gates_passed(results)
+
+    def test_config_validation(self):
+        with pytest.raises(ValueError, match="min_lines"):
+            _clone_config(min_lines=0)
+
+
+class TestLoadCloneGateConfig:
+    def test_defaults_without_file(self):
+        assert load_clone_gate_config(None) == CloneGateConfig(True, 10)
+
+    def test_overrides_from_toml(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text("[gates.no_new_clones]\nmin_lines = 5\n")
+        assert load_clone_gate_config(toml).min_lines == 5
+
+    def test_count_gate_loader_skips_clone_entry(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text("[gates.no_new_clones]\nenabled = false\n")
+        names = [config.name for config in load_gates_config(toml)]
+        assert "no_new_clones" not in names
+
+
+def _finding(
+    path: str = "src/payments/charge.py",
+    severity: str = "HIGH",
+    tool: str = "semgrep",
+    rule: str = "rule-1",
+    line: int = 3,
+) -> dict:
+    return {
+        "tool": tool,
+        "path": path,
+        "rule": rule,
+        "severity": severity,
+        "line": line,
+        "message": "m",
+    }
+
+
+def _policy(
+    where: str = "severity = HIGH AND path GLOB 'src/payments/*'",
+    max_count: int = 0,
+    name: str = "no_high_in_payments",
+    enabled: bool = True,
+) -> PolicyGateConfig:
+    return PolicyGateConfig(name=name, enabled=enabled, where=where, max_count=max_count)
+
+
+class TestPolicyGates:
+    """Tests for user-defined policy-as-code gates."""
+
+    def test_matching_finding_fails_gate(self):
+        result = evaluate_policy_gate([_finding()], _policy())
+        assert not result.passed
+        assert result.actual == 1
+        assert result.offenders == ("src/payments/charge.py:3 [semgrep] rule-1",)
+
+    def test_non_matching_findings_pass(self):
+        findings = [_finding(path="src/docs/readme.py"), _finding(severity="LOW")]
+        assert evaluate_policy_gate(findings, _policy()).passed
+
+    def test_max_count_allows_budget(self):
+        findings = [_finding(line=n) for n in range(3)]
+        assert evaluate_policy_gate(findings, _policy(max_count=3)).passed
+        assert not evaluate_policy_gate(findings, _policy(max_count=2)).passed
+
+    def test_invalid_expression_rejected_at_construction(self):
+        with pytest.raises(ValueError, match="no_high_in_payments"):
+            _policy(where="severity = ")
+
+    def test_run_gates_includes_policy_gates_when_findings_given(self):
+        results = run_gates(
+            [],
+            configs=[],
+            findings=[_finding()],
+            policy_configs=[_policy(), _policy(name="disabled", enabled=False)],
+        )
+        assert [r.name for r in results] == ["no_high_in_payments"]
+
+    def test_run_gates_skips_policies_without_findings(self):
+        assert run_gates([], configs=[], policy_configs=[_policy()]) == []
+
+
+class TestLoadPolicyGates:
+    def test_no_file_yields_no_policies(self):
+        assert load_policy_gates(None) == []
+
+    def test_loads_entries_with_defaults(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text(
+            "[[gates.policies]]\n"
+            'name = "no_high_in_payments"\n'
+            "where = \"severity = HIGH AND path GLOB 'src/payments/**'\"\n"
+        )
+        policies = load_policy_gates(toml)
+        assert policies[0].max_count == 0
+        assert policies[0].enabled is True
+
+    def test_missing_where_rejected(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text('[[gates.policies]]\nname = "x"\n')
+        with pytest.raises(ValueError, match="'name' and 'where'"):
+            load_policy_gates(toml)
+
+    def test_count_gate_loader_ignores_policies(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text(
+            '[[gates.policies]]\nname = "x"\nwhere = "severity = HIGH"\n'
+        )
+        names = [config.name for config in load_gates_config(toml)]
+        assert "x" not in names
@@ -0,0 +1,124 @@
+"""Tests for the GitHub Checks API integration."""
+
+from __future__ import annotations
+
+import pytest
+
+from insights.gates import GateResult
+from insights.github_checks import (
+    ANNOTATION_BATCH_SIZE,
+    ChecksClient,
+    CheckRunError,
+    build_annotations,
+    build_summary,
+    conclusion_from_gates,
+    publish_check_run,
+)
+
+
+def _finding(severity: str = "HIGH", path: str = "src/app.py", line: int = 10) -> dict:
+    return {
+        "tool": "semgrep",
+        "rule_id": "rule-1",
+        "severity": severity,
+        "relative_path": path,
+        "line_start": line,
+        "line_end": line,
+        "message": "something smells",
+    }
+
+
+class TestBuildAnnotations:
+    def test_maps_finding_fields(self) -> None:
+        annotations, truncated = build_annotations([_finding()])
+        assert truncated == 0
+        assert annotations == [{
+            "path": "src/app.py",
+            "start_line": 10,
+            "end_line": 10,
+            "annotation_level": "failure",
+            "title": "semgrep: rule-1",
+            "message": "something smells",
+        }]
+
+    def test_severity_levels(self) -> None:
+        annotations, _ = build_annotations(
+            [_finding("CRITICAL"), _finding("MEDIUM"), _finding("LOW")]
+        )
+        assert [a["annotation_level"] for a in annotations] == ["failure", "warning", "notice"]
+
+    def test_prioritizes_worst_severity_under_cap(self) -> None:
+        findings = [_finding("LOW") for _ in range(3)] + [_finding("CRITICAL", path="z.py")]
+        annotations, truncated = build_annotations(findings, cap=2)
+        assert truncated == 2
+        assert annotations[0]["path"] == "z.py"
+
+    def test_missing_line_defaults_to_one(self) -> None:
+        finding = _finding()
+        finding["line_start"] = None
+        finding["line_end"] = None
+        annotations, _ = build_annotations([finding])
+        assert annotations[0]["start_line"] == 1
+        assert annotations[0]["end_line"] == 1
+
+
+class TestConclusionAndSummary:
+    def test_conclusion_from_gates(self) -> None:
+        passed = GateResult(name="g", passed=True, actual=0, limit=0, message="ok")
+        failed = GateResult(name="g", passed=False, actual=2, limit=0, message="2 > 0")
+        assert conclusion_from_gates([]) == "neutral"
+        assert conclusion_from_gates([passed]) == "success"
+        assert conclusion_from_gates([passed, failed]) == "failure"
+
+    def test_summary_lists_gates_and_truncation(self) -> None:
+        failed = GateResult(name="no_fixable_criticals", passed=False, actual=2, limit=0, message="2 > 0")
+        summary = build_summary([failed], [_finding("HIGH"), _finding("LOW")], truncated=3)
+        assert "2 finding(s)" in summary
+        assert "1 high" in summary and "1 low" in summary
+        assert "3 lower-severity finding(s) not annotated" in summary
+        assert "| no_fixable_criticals | FAIL |" in summary
+
+
+class StubTransport:
+    def __init__(self) -> None:
+        self.calls: list[tuple[str, str, dict | None]] = []
+
+    def __call__(self, method: str, url: str, payload: dict | None) -> dict:
+        self.calls.append((method, url, payload))
+        return {"id": 77}
+
+
+class TestChecksClient:
+    def test_rejects_malformed_repo(self) -> None:
+        with pytest.raises(CheckRunError, match="owner/name"):
+            ChecksClient("not-a-repo")
+
+    def test_create_check_run(self) -> None:
+        transport = StubTransport()
+        client = ChecksClient("acme/app", transport=transport)
+        assert client.create_check_run("a" * 40) == 77
+        method, url, payload = transport.calls[0]
+        assert (method, url) == ("POST", "https://api.github.com/repos/acme/app/check-runs")
+        assert payload["head_sha"] == "a" * 40
+        assert payload["status"] == "in_progress"
+
+    def test_annotations_streamed_in_batches(self) -> None:
+        transport = StubTransport()
+        client = ChecksClient("acme/app", transport=transport)
+        annotations = [{"path": f"f{i}.py"} for i in range(ANNOTATION_BATCH_SIZE + 1)]
+        batches = client.stream_annotations(77, "caldera", "summary", annotations)
+        assert batches == 2
+        sizes = [len(payload["output"]["annotations"]) for _, _, payload in transport.calls]
+        assert sizes == [ANNOTATION_BATCH_SIZE, 1]
+
+    def test_publish_check_run_end_to_end(self) -> None:
+        transport = StubTransport()
+        client = ChecksClient("acme/app", transport=transport)
+        failed = GateResult(name="g", passed=False, actual=1, limit=0, message="1 > 0")
+        report = publish_check_run(client, "b" * 40, [_finding()], [failed])
+        assert report.check_run_id == 77
+        assert report.conclusion == "failure"
+        assert report.annotation_count == 1
+        methods = [method for method, _, _ in transport.calls]
+        assert methods == ["POST", "PATCH", "PATCH"]  # create, annotate, complete
+        assert transport.calls[-1][2] == {"status": "completed", "conclusion": "failure"}
@@ -0,0 +1,119 @@
+"""Tests for the composite repository health score."""
+
+import pytest
+from pathlib import Path
+
+from insights.health_score import (
+    DEFAULT_CAPS,
+    DEFAULT_WEIGHTS,
+    HealthConfig,
+    compute_directory_scores,
+    compute_health_score,
+    load_health_config,
+    security_input,
+)
+
+
+class TestHealthConfig:
+    """Tests for config validation and loading."""
+
+    def test_defaults_are_valid(self):
+        HealthConfig(dict(DEFAULT_WEIGHTS), dict(DEFAULT_CAPS))
+
+    def test_missing_weight_rejected(self):
+        weights = dict(DEFAULT_WEIGHTS)
+        del weights["security"]
+        with pytest.raises(ValueError, match="missing weight"):
+            HealthConfig(weights, dict(DEFAULT_CAPS))
+
+    def test_zero_cap_rejected(self):
+        caps = dict(DEFAULT_CAPS)
+        caps["size"] = 0
+        with pytest.raises(ValueError, match="cap for size"):
+            HealthConfig(dict(DEFAULT_WEIGHTS), caps)
+
+    def test_load_from_caldera_toml(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text(
+            "[health_score.weights]\ncomplexity = 0.5\n\n[health_score.caps]\nsize = 500\n"
+        )
+        config = load_health_config(config_path)
+        assert config.weights["complexity"] == 0.5
+        assert config.weights["duplication"] == DEFAULT_WEIGHTS["duplication"]
+        assert config.caps["size"] == 500
+
+    def test_load_missing_file_uses_defaults(self, tmp_path: Path):
+        config = load_health_config(tmp_path / "nope.toml")
+        assert config.weights == DEFAULT_WEIGHTS
+
+    def test_scope_applies_directory_overrides(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text(
+            "[health_score.caps]\ncomplexity = 20.0\n\n"
+            '[overrides."tests/**".health_score.caps]\ncomplexity = 40.0\n'
+        )
+        assert load_health_config(config_path, scope="src/app").caps["complexity"] == 20.0
+        assert load_health_config(config_path, scope="tests/unit").caps["complexity"] == 40.0
+
+
+class TestScoring:
+    """Tests for the scoring formula."""
+
+    def test_clean_scope_scores_100(self):
+        score = compute_health_score(".", {})
+        assert score.score == 100.0
+        assert all(penalty == 0.0 for penalty in score.penalties.values())
+
+    def test_saturated_scope_scores_0(self):
+        metrics = {
+            "complexity": 999.0,
+            "duplication": 100.0,
+            "security": 50.0,
+            "size": 10_000.0,
+            "dead_code": 100.0,
+        }
+        assert compute_health_score(".", metrics).score == 0.0
+
+    def test_penalty_is_linear_below_cap(self):
+        # Only complexity contributes: CCN 10 with cap 20 -> penalty 0.5
+        score = compute_health_score(".", {"complexity": 10.0})
+        assert score.penalties["complexity"] == 0.5
+        expected = 100.0 * (1.0 - 0.35 * 0.5 / sum(DEFAULT_WEIGHTS.values()))
+        assert score.score == round(expected, 1)
+
+    def test_weights_shift_the_score(self):
+        metrics = {"security": 10.0}  # saturates the security penalty
+        heavy = HealthConfig(
+            {"complexity": 0.0, "duplication": 0.0, "security": 1.0, "size": 0.0, "dead_code": 0.0},
+            dict(DEFAULT_CAPS),
+        )
+        light = HealthConfig(
+            {"complexity": 1.0, "duplication": 0.0, "security": 0.0, "size": 0.0, "dead_code": 0.0},
+            dict(DEFAULT_CAPS),
+        )
+        assert compute_health_score(".", metrics, heavy).score == 0.0
+        assert compute_health_score(".", metrics, light).score == 100.0
+
+    def test_directory_scores_sorted_worst_first(self):
+        scores = compute_directory_scores(
+            {
+                "src/clean": {},
+                "src/messy": {"complexity": 40.0, "duplication": 50.0},
+            }
+        )
+        assert [health.scope for health in scores] == ["src/messy", "src/clean"]
+
+
+class TestSecurityInput:
+    """Tests for the severity-weighted security input."""
+
+    def test_weights_by_severity(self):
+        findings = {"CRITICAL": 1, "LOW": 2}
+        # (4.0 + 2 * 0.5) per 1000 LOC over 2000 LOC -> 2.5
+        assert security_input(findings, 2000) == 2.5
+
+    def test_zero_loc_is_zero(self):
+        assert security_input({"HIGH": 5}, 0) == 0.0
+
+    def test_unknown_severity_counts_as_medium(self):
+        assert security_input({"WEIRD": 2}, 1000) == 2.0
@@ -0,0 +1,174 @@
+"""Tests for the Jira issue sync (one ticket per finding fingerprint)."""
+
+import pytest
+from pathlib import Path
+
+from insights.codeclimate import fingerprint
+from insights.jira_sync import (
+    FINGERPRINT_LABEL_PREFIX,
+    JiraClient,
+    JiraConfig,
+    SyncPlan,
+    apply_plan,
+    issue_fields,
+    load_jira_config,
+    plan_sync,
+    select_findings,
+)
+
+
+def _finding(
+    tool: str = "bandit",
+    rule_id: str = "B102",
+    relative_path: str = "src/app.py",
+    severity: str = "HIGH",
+    line_start: int = 10,
+) -> dict:
+    return {
+        "tool": tool,
+        "rule_id": rule_id,
+        "relative_path": relative_path,
+        "severity": severity,
+        "line_start": line_start,
+        "message": "exec used",
+    }
+
+
+def _config(**overrides) -> JiraConfig:
+    params = dict(url="https://example.atlassian.net", project_key="SEC")
+    params.update(overrides)
+    return JiraConfig(**params)
+
+
+class TestConfig:
+    """Tests for [jira] loading and validation."""
+
+    def test_load_from_caldera_toml(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text(
+            "[jira]\n"
+            'url = "https://example.atlassian.net/"\n'
+            'project_key = "SEC"\n'
+            'min_severity = "critical"\n'
+            'labels = ["caldera-import"]\n'
+            "[jira.fields]\n"
+            'severity = "customfield_10042"\n'
+        )
+        config = load_jira_config(toml)
+        assert config.url == "https://example.atlassian.net"  # trailing slash stripped
+        assert config.min_severity == "CRITICAL"
+        assert config.fields == {"severity": "customfield_10042"}
+
+    def test_missing_section_returns_none(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text("[gates.no_fixable_criticals]\nenabled = true\n")
+        assert load_jira_config(toml) is None
+
+    def test_missing_project_key_rejected(self):
+        with pytest.raises(ValueError, match="project_key"):
+            _config(project_key="")
+
+    def test_severity_threshold_filters_findings(self):
+        findings = [_finding(severity="LOW"), _finding(rule_id="B103", severity="CRITICAL")]
+        selected = select_findings(findings, _config(min_severity="HIGH"))
+        assert [f["rule_id"] for f in selected] == ["B103"]
+
+
+class TestPlanSync:
+    """Tests for the pure sync planner."""
+
+    def test_unknown_finding_is_created(self):
+        plan = plan_sync([_finding()], open_issues={})
+        assert len(plan.create) == 1
+        assert plan.update == () and plan.close == ()
+
+    def test_recurring_finding_is_updated(self):
+        finding = _finding()
+        plan = plan_sync([finding], open_issues={fingerprint(finding): "SEC-1"})
+        assert plan.create == ()
+        assert plan.update == (("SEC-1", finding),)
+
+    def test_disappeared_finding_is_closed(self):
+        plan = plan_sync([], open_issues={"deadbeef": "SEC-2"})
+        assert plan.close == ("SEC-2",)
+
+    def test_duplicate_fingerprints_collapse_to_one_ticket(self):
+        finding = _finding()
+        plan = plan_sync([finding, dict(finding)], open_issues={})
+        assert len(plan.create) == 1
+
+
+class TestIssueFields:
+    """Tests for the Jira payload."""
+
+    def test_payload_carries_fingerprint_label(self):
+        finding = _finding()
+        payload = issue_fields(finding, _config(labels=("caldera-import",)))
+        assert payload["project"] == {"key": "SEC"}
+        assert payload["summary"] == "[bandit] B102 in src/app.py"
+        assert FINGERPRINT_LABEL_PREFIX + fingerprint(finding) in payload["labels"]
+        assert "caldera-import" in payload["labels"]
+
+    def test_field_mapping_applied(self):
+        payload = issue_fields(_finding(), _config(fields={"severity": "customfield_10042"}))
+        assert payload["customfield_10042"] == "HIGH"
+
+
+class TestClientAndApply:
+    """Tests against a stub transport."""
+
+    def _client(self, responses: dict, calls: list) -> JiraClient:
+        def transport(path: str, method: str, payload: dict | None = None) -> dict:
+            calls.append((method, path, payload))
+            return responses.get((method, path), {})
+
+        return JiraClient(_config(), transport=transport)
+
+    def test_open_issues_extracted_from_labels(self):
+        calls: list = []
+        responses = {
+            ("POST", "/rest/api/2/search"): {
+                "total": 1,
+                "issues": [
+                    {
+                        "key": "SEC-7",
+                        "fields": {"labels": ["caldera", FINGERPRINT_LABEL_PREFIX + "abc123"]},
+                    }
+                ],
+            }
+        }
+        client = self._client(responses, calls)
+        assert client.open_caldera_issues() == {"abc123": "SEC-7"}
+
+    def test_apply_plan_creates_comments_and_closes(self):
+        calls: list = []
+        responses = {
+            ("POST", "/rest/api/2/issue"): {"key": "SEC-10"},
+            ("GET", "/rest/api/2/issue/SEC-2/transitions"): {
+                "transitions": [{"id": "31", "name": "Done"}]
+            },
+        }
+        client = self._client(responses, calls)
+        plan = SyncPlan(
+            create=(_finding(),),
+            update=(("SEC-1", _finding(rule_id="B103")),),
+            close=("SEC-2",),
+        )
+
+        summary = apply_plan(plan, client)
+
+        assert summary == {"created": ["SEC-10"], "updated": ["SEC-1"], "closed": ["SEC-2"]}
+        methods_paths = [(method, path) for method, path, _ in calls]
+        assert ("POST", "/rest/api/2/issue/SEC-1/comment") in methods_paths
+        assert ("POST", "/rest/api/2/issue/SEC-2/transitions") in methods_paths
+
+    def test_close_is_noop_without_matching_transition(self):
+        calls: list = []
+        responses = {
+            ("GET", "/rest/api/2/issue/SEC-2/transitions"): {
+                "transitions": [{"id": "11", "name": "In Progress"}]
+            }
+        }
+        client = self._client(responses, calls)
+        client.close_issue("SEC-2")
+        assert [(m, p) for m, p, _ in calls] == [("GET", "/rest/api/2/issue/SEC-2/transitions")]
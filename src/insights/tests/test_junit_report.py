@@ -0,0 +1,110 @@
+"""Tests for the JUnit XML reporter."""
+
+from __future__ import annotations
+
+import xml.etree.ElementTree as ET
+from pathlib import Path
+
+from insights.gates import GateResult
+from insights.junit_report import build_junit_xml, write_junit_report
+
+FINDINGS = [
+    {
+        "tool": "bandit",
+        "rule_id": "B608",
+        "severity": "HIGH",
+        "relative_path": "src/db.py",
+        "line_start": 12,
+        "message": "Possible SQL injection",
+    },
+    {
+        "tool": "bandit",
+        "rule_id": "B608",
+        "severity": "HIGH",
+        "relative_path": "src/api.py",
+        "line_start": 40,
+        "message": "Possible SQL injection",
+    },
+    {
+        "tool": "semgrep",
+        "rule_id": "no-eval",
+        "severity": "MEDIUM",
+        "relative_path": "src/util.py",
+        "line_start": 7,
+        "message": "eval() detected",
+    },
+]
+
+GATES = [
+    GateResult(name="max_critical", passed=True, actual=0, limit=0, message="0 critical (limit 0)", offenders=[]),
+    GateResult(
+        name="max_high",
+        passed=False,
+        actual=3,
+        limit=1,
+        message="3 high vulnerabilities exceed limit 1",
+        offenders=["CVE-2026-1 in requests", "CVE-2026-2 in urllib3"],
+    ),
+]
+
+
+class TestBuildJunitXml:
+    def test_counts_and_suite_name(self) -> None:
+        root = ET.fromstring(build_junit_xml(FINDINGS, GATES))
+        assert root.tag == "testsuite"
+        assert root.get("name") == "caldera"
+        # 2 gates + 2 distinct (tool, rule) groups
+        assert root.get("tests") == "4"
+        # 1 failed gate + 2 rule groups with findings
+        assert root.get("failures") == "3"
+
+    def test_passing_gate_has_no_failure_element(self) -> None:
+        root = ET.fromstring(build_junit_xml([], GATES))
+        cases = {case.get("name"): case for case in root.findall("testcase")}
+        assert cases["max_critical"].find("failure") is None
+        assert cases["max_high"].find("failure") is not None
+
+    def test_failed_gate_lists_offenders(self) -> None:
+        root = ET.fromstring(build_junit_xml([], GATES))
+        case = next(c for c in root.findall("testcase") if c.get("name") == "max_high")
+        failure = case.find("failure")
+        assert failure.get("message") == "3 high vulnerabilities exceed limit 1"
+        assert "CVE-2026-1 in requests" in failure.text
+        assert "CVE-2026-2 in urllib3" in failure.text
+
+    def test_one_case_per_rule_with_findings_listed(self) -> None:
+        root = ET.fromstring(build_junit_xml(FINDINGS, []))
+        cases = {case.get("name"): case for case in root.findall("testcase")}
+        assert set(cases) == {"B608", "no-eval"}
+        assert cases["B608"].get("classname") == "caldera.bandit"
+        failure = cases["B608"].find("failure")
+        assert failure.get("message") == "2 finding(s) for B608"
+        assert "src/db.py:12 Possible SQL injection" in failure.text
+        assert "src/api.py:40 Possible SQL injection" in failure.text
+
+    def test_noisy_rule_is_truncated(self) -> None:
+        many = [dict(FINDINGS[0], line_start=i) for i in range(1, 61)]
+        root = ET.fromstring(build_junit_xml(many, []))
+        failure = root.find("testcase/failure")
+        assert "... and 10 more" in failure.text
+
+    def test_clean_run_renders_passing_gates_only(self) -> None:
+        root = ET.fromstring(build_junit_xml([], [GATES[0]]))
+        assert root.get("tests") == "1"
+        assert root.get("failures") == "0"
+
+    def test_xml_escapes_messages(self) -> None:
+        findings = [dict(FINDINGS[2], message="<script> & friends")]
+        xml = build_junit_xml(findings, [])
+        assert "&lt;script&gt; &amp; friends" in xml
+        assert ET.fromstring(xml).find("testcase/failure").text.endswith("<script> & friends")
+
+
+class TestWriteJunitReport:
+    def test_writes_file_and_returns_case_count(self, tmp_path: Path) -> None:
+        output = tmp_path / "reports" / "caldera.xml"
+        count = write_junit_report(FINDINGS, GATES, output)
+        assert count == 4
+        content = output.read_text()
+        assert content.startswith("<?xml")
+        assert ET.fromstring(content).get("tests") == "4"
@@ -0,0 +1,125 @@
+"""Tests for license detection and policy checking."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from insights.gates import GateConfig, evaluate_no_denied_licenses, run_gates
+from insights.license_scan import (
+    LicenseFinding,
+    LicensePolicy,
+    find_policy_violations,
+    load_license_policy,
+    resolve_dependency_licenses,
+    scan_file_headers,
+)
+
+
+def _gate_config(**overrides) -> GateConfig:
+    config = {
+        "name": "no_denied_licenses",
+        "enabled": True,
+        "severities": ("CRITICAL",),
+        "max_count": 0,
+    }
+    config.update(overrides)
+    return GateConfig(**config)
+
+
+class TestHeaderScan:
+    """Tests for SPDX identifiers in file headers."""
+
+    def test_finds_spdx_header(self, tmp_path: Path):
+        (tmp_path / "main.py").write_text("# SPDX-License-Identifier: MIT\nprint('hi')\n")
+        findings = scan_file_headers(tmp_path)
+        assert findings == [LicenseFinding(license="MIT", source="file", subject="main.py")]
+
+    def test_ignores_identifier_past_header(self, tmp_path: Path):
+        body = "\n" * 30 + "# SPDX-License-Identifier: MIT\n"
+        (tmp_path / "deep.py").write_text(body)
+        assert scan_file_headers(tmp_path) == []
+
+    def test_skips_excluded_dirs_and_binary_files(self, tmp_path: Path):
+        vendored = tmp_path / "vendor"
+        vendored.mkdir()
+        (vendored / "lib.c").write_text("/* SPDX-License-Identifier: GPL-2.0 */\n")
+        (tmp_path / "blob.bin").write_bytes(b"\xff\xfeSPDX-License-Identifier: MIT")
+        assert scan_file_headers(tmp_path) == []
+
+    def test_captures_compound_expression(self, tmp_path: Path):
+        (tmp_path / "dual.rs").write_text("// SPDX-License-Identifier: MIT OR Apache-2.0\n")
+        findings = scan_file_headers(tmp_path)
+        assert findings[0].license == "MIT OR Apache-2.0"
+
+
+class TestDependencyLicenses:
+    """Tests for dependency license resolution via the SBOM parsers."""
+
+    def test_resolves_licenses_from_package_lock(self, tmp_path: Path):
+        (tmp_path / "package-lock.json").write_text(json.dumps({
+            "packages": {
+                "": {"name": "demo", "version": "0.1.0"},
+                "node_modules/leftlib": {"version": "1.0.0", "license": "AGPL-3.0-only"},
+                "node_modules/nolicense": {"version": "2.0.0"},
+            },
+        }))
+        findings = resolve_dependency_licenses(tmp_path)
+        assert findings == [
+            LicenseFinding(license="AGPL-3.0-only", source="dependency", subject="leftlib@1.0.0"),
+        ]
+
+
+class TestPolicy:
+    """Tests for the deny list and violation matching."""
+
+    def test_load_policy_defaults_to_empty(self, tmp_path: Path):
+        assert load_license_policy(tmp_path / "missing.toml").deny == ()
+
+    def test_load_policy_reads_deny_list(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text('[license_policy]\ndeny = ["AGPL-3.0"]\n')
+        assert load_license_policy(toml).deny == ("AGPL-3.0",)
+
+    def test_violation_matches_exact_and_variants(self):
+        policy = LicensePolicy(deny=("AGPL-3.0",))
+        findings = [
+            LicenseFinding(license="AGPL-3.0", source="file", subject="a.py"),
+            LicenseFinding(license="AGPL-3.0-only", source="dependency", subject="x@1.0"),
+            LicenseFinding(license="MIT", source="file", subject="b.py"),
+        ]
+        violations = find_policy_violations(findings, policy)
+        assert [v.subject for v in violations] == ["a.py", "x@1.0"]
+
+    def test_violation_matches_compound_branch(self):
+        policy = LicensePolicy(deny=("AGPL-3.0",))
+        findings = [LicenseFinding(license="MIT OR AGPL-3.0-or-later", source="file", subject="c.py")]
+        assert find_policy_violations(findings, policy) == findings
+
+    def test_invalid_source_rejected(self):
+        with pytest.raises(ValueError, match="source"):
+            LicenseFinding(license="MIT", source="guess", subject="a.py")
+
+
+class TestLicenseGate:
+    """Tests for gate engine enforcement."""
+
+    def test_gate_fails_with_offender_details(self):
+        violations = [LicenseFinding(license="AGPL-3.0", source="dependency", subject="x@1.0")]
+        result = evaluate_no_denied_licenses(violations, _gate_config())
+        assert not result.passed
+        assert result.actual == 1
+        assert result.offenders == ("AGPL-3.0 in x@1.0 (dependency)",)
+
+    def test_gate_passes_when_clean(self):
+        result = evaluate_no_denied_licenses([], _gate_config())
+        assert result.passed
+        assert result.actual == 0
+
+    def test_run_gates_skips_license_gate_without_scan(self):
+        results = run_gates([], configs=[_gate_config()], license_violations=None)
+        assert results == []
+
+    def test_run_gates_evaluates_license_gate_with_scan(self):
+        results = run_gates([], configs=[_gate_config()], license_violations=[])
+        assert [r.name for r in results] == ["no_denied_licenses"]
@@ -0,0 +1,178 @@
+"""Tests for webhook notifications on gate failures and new findings."""
+
+import pytest
+from pathlib import Path
+
+from insights.gates import GateResult
+from insights.notifications import (
+    DeliveryResult,
+    WebhookConfig,
+    build_gate_failure_event,
+    build_new_findings_event,
+    diff_new_findings,
+    load_notification_config,
+    notify,
+    render_payload,
+)
+
+
+def _finding(
+    tool: str = "semgrep",
+    rule_id: str = "python.lang.security.eval",
+    relative_path: str = "src/app.py",
+    severity: str = "HIGH",
+    line_start: int = 10,
+) -> dict:
+    return {
+        "tool": tool,
+        "rule_id": rule_id,
+        "relative_path": relative_path,
+        "severity": severity,
+        "line_start": line_start,
+        "message": "do not use eval",
+    }
+
+
+def _failed_gate() -> GateResult:
+    return GateResult(
+        name="no_fixable_criticals",
+        passed=False,
+        actual=2,
+        limit=0,
+        message="2 fixable CRITICAL vulnerabilities exceed limit 0",
+        offenders=("CVE-2024-0001 in libexample (1.0.0 -> 1.2.3)",),
+    )
+
+
+class TestWebhookConfig:
+    """Tests for config validation and loading."""
+
+    def test_non_http_url_rejected(self):
+        with pytest.raises(ValueError, match="http"):
+            WebhookConfig(url="ftp://example.com/hook")
+
+    def test_unknown_format_rejected(self):
+        with pytest.raises(ValueError, match="format"):
+            WebhookConfig(url="https://example.com/hook", format="carrier-pigeon")
+
+    def test_unknown_event_rejected(self):
+        with pytest.raises(ValueError, match="event"):
+            WebhookConfig(url="https://example.com/hook", events=("gate_failure", "lunch"))
+
+    def test_load_from_caldera_toml(self, tmp_path: Path):
+        toml = tmp_path / "caldera.toml"
+        toml.write_text(
+            "[[notifications.webhooks]]\n"
+            'url = "https://hooks.slack.com/services/T/B/X"\n'
+            'format = "slack"\n'
+            'events = ["gate_failure"]\n'
+            'min_severity = "critical"\n'
+        )
+        webhooks = load_notification_config(toml)
+        assert webhooks == [
+            WebhookConfig(
+                url="https://hooks.slack.com/services/T/B/X",
+                format="slack",
+                events=("gate_failure",),
+                min_severity="CRITICAL",
+            )
+        ]
+
+    def test_missing_config_means_no_webhooks(self, tmp_path: Path):
+        assert load_notification_config(tmp_path / "missing.toml") == []
+        assert load_notification_config(None) == []
+
+
+class TestDiffNewFindings:
+    """Tests for the baseline diff."""
+
+    def test_new_high_severity_finding_reported(self):
+        baseline = [_finding(rule_id="old-rule")]
+        current = [_finding(rule_id="old-rule"), _finding(rule_id="new-rule")]
+        new = diff_new_findings(current, baseline)
+        assert [f["rule_id"] for f in new] == ["new-rule"]
+
+    def test_low_severity_findings_ignored(self):
+        current = [_finding(severity="LOW"), _finding(rule_id="big", severity="CRITICAL")]
+        new = diff_new_findings(current, [], min_severity="HIGH")
+        assert [f["rule_id"] for f in new] == ["big"]
+
+    def test_line_shift_is_not_a_new_finding(self):
+        baseline = [_finding(line_start=10)]
+        current = [_finding(line_start=42)]
+        assert diff_new_findings(current, baseline) == []
+
+    def test_same_rule_in_new_file_is_new(self):
+        baseline = [_finding(relative_path="src/app.py")]
+        current = [_finding(relative_path="src/other.py")]
+        assert len(diff_new_findings(current, baseline)) == 1
+
+
+class TestPayloads:
+    """Tests for event building and format envelopes."""
+
+    def test_gate_failure_event_lists_only_failed_gates(self):
+        passed = GateResult(name="ok", passed=True, actual=0, limit=0, message="fine")
+        event = build_gate_failure_event("repo-a", "run-1", [passed, _failed_gate()])
+        assert event["summary"] == "1 of 2 quality gates failed"
+        assert [gate["name"] for gate in event["gates"]] == ["no_fixable_criticals"]
+
+    def test_new_findings_event_caps_listed_findings(self):
+        findings = [_finding(rule_id=f"rule-{i}") for i in range(25)]
+        event = build_new_findings_event("repo-a", "run-1", findings)
+        assert event["total_findings"] == 25
+        assert len(event["findings"]) == 10
+
+    def test_json_format_passes_event_through(self):
+        event = build_gate_failure_event("repo-a", "run-1", [_failed_gate()])
+        assert render_payload(event, "json") is event
+
+    def test_slack_format_wraps_text(self):
+        event = build_gate_failure_event("repo-a", "run-1", [_failed_gate()])
+        payload = render_payload(event, "slack")
+        assert set(payload) == {"text"}
+        assert "repo-a" in payload["text"]
+        assert "no_fixable_criticals" in payload["text"]
+
+    def test_teams_format_is_a_message_card(self):
+        event = build_new_findings_event("repo-a", "run-1", [_finding()])
+        payload = render_payload(event, "teams")
+        assert payload["@type"] == "MessageCard"
+        assert "python.lang.security.eval" in payload["text"]
+
+
+class TestDelivery:
+    """Tests for webhook fan-out."""
+
+    def test_events_only_sent_to_subscribed_webhooks(self):
+        posted: list[tuple[str, dict]] = []
+
+        def poster(url: str, payload: dict, timeout: int) -> tuple[bool, str]:
+            posted.append((url, payload))
+            return True, "HTTP 200"
+
+        webhooks = [
+            WebhookConfig(url="https://a.example/hook", events=("gate_failure",)),
+            WebhookConfig(url="https://b.example/hook", events=("new_findings",)),
+        ]
+        event = build_gate_failure_event("repo-a", "run-1", [_failed_gate()])
+
+        results = notify([event], webhooks, poster=poster)
+
+        assert [url for url, _ in posted] == ["https://a.example/hook"]
+        assert results == [
+            DeliveryResult(
+                url="https://a.example/hook", event="gate_failure", ok=True, detail="HTTP 200"
+            )
+        ]
+
+    def test_delivery_failure_reported_not_raised(self):
+        webhooks = [WebhookConfig(url="https://down.example/hook")]
+        event = build_gate_failure_event("repo-a", "run-1", [_failed_gate()])
+
+        results = notify(
+            [event], webhooks, poster=lambda url, payload, timeout: (False, "HTTP 503")
+        )
+
+        assert results[0].ok is False
+        assert results[0].detail == "HTTP 503"
@@ -0,0 +1,150 @@
+"""Tests for the directory ownership and bus-factor analysis."""
+
+import pytest
+from pathlib import Path
+
+from insights.ownership import (
+    OwnershipConfig,
+    compute_directory_ownership,
+    load_ownership_config,
+)
+
+
+def _blame(path, lines, author, author_lines, churn_30d=0, churn_90d=0):
+    return {
+        "relative_path": path,
+        "total_lines": lines,
+        "top_author": author,
+        "top_author_lines": author_lines,
+        "churn_30d": churn_30d,
+        "churn_90d": churn_90d,
+    }
+
+
+class TestOwnershipConfig:
+    """Tests for config validation and loading."""
+
+    def test_defaults_are_valid(self):
+        OwnershipConfig()
+
+    def test_invalid_pct_rejected(self):
+        with pytest.raises(ValueError, match="single_owner_pct"):
+            OwnershipConfig(single_owner_pct=0)
+
+    def test_load_from_caldera_toml(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text("[ownership]\nsingle_owner_pct = 60\nrisk_ccn = 8\n")
+        config = load_ownership_config(config_path)
+        assert config.single_owner_pct == 60.0
+        assert config.risk_ccn == 8.0
+        assert config.min_directory_lines == 50
+
+    def test_missing_file_yields_defaults(self, tmp_path: Path):
+        assert load_ownership_config(tmp_path / "missing.toml") == OwnershipConfig()
+
+
+class TestComputeDirectoryOwnership:
+    """Tests for the per-directory aggregation."""
+
+    def test_groups_by_direct_directory(self):
+        rows = [
+            _blame("src/a.py", 100, "alice", 90),
+            _blame("src/b.py", 100, "alice", 80),
+            _blame("docs/guide.md", 100, "bob", 100),
+        ]
+        entries = compute_directory_ownership(rows)
+        assert sorted(entry.directory for entry in entries) == ["docs", "src"]
+        src = next(entry for entry in entries if entry.directory == "src")
+        assert src.total_lines == 200
+        assert src.file_count == 2
+        assert src.top_author == "alice"
+
+    def test_root_files_map_to_dot(self):
+        entries = compute_directory_ownership([_blame("README.md", 100, "alice", 100)])
+        assert entries[0].directory == "."
+
+    def test_bus_factor_counts_authors_to_half(self):
+        rows = [
+            _blame("src/a.py", 100, "alice", 40),
+            _blame("src/b.py", 100, "bob", 40),
+            _blame("src/c.py", 100, "carol", 40),
+        ]
+        # All three authors together cover 120 of 300 lines — never half,
+        # so the diffuse-ownership fallback reports the author count.
+        assert compute_directory_ownership(rows)[0].bus_factor == 3
+
+    def test_bus_factor_one_for_dominant_author(self):
+        rows = [
+            _blame("src/a.py", 100, "alice", 95),
+            _blame("src/b.py", 100, "alice", 90),
+        ]
+        assert compute_directory_ownership(rows)[0].bus_factor == 1
+
+    def test_small_directories_skipped(self):
+        config = OwnershipConfig(min_directory_lines=50)
+        entries = compute_directory_ownership(
+            [_blame("tiny/a.py", 10, "alice", 10)], config=config
+        )
+        assert entries == []
+
+    def test_churn_is_summed(self):
+        rows = [
+            _blame("src/a.py", 100, "alice", 90, churn_30d=2, churn_90d=5),
+            _blame("src/b.py", 100, "alice", 90, churn_30d=1, churn_90d=3),
+        ]
+        entry = compute_directory_ownership(rows)[0]
+        assert (entry.churn_30d, entry.churn_90d) == (3, 8)
+
+
+class TestRiskClassification:
+    """Tests for the single-owner risk flag."""
+
+    def test_single_owner_and_complex_is_high(self):
+        rows = [_blame("src/a.py", 100, "alice", 95)]
+        complexity = [{"relative_path": "src/a.py", "total_ccn": 60, "function_count": 4}]
+        entry = compute_directory_ownership(rows, complexity_rows=complexity)[0]
+        assert entry.single_owner is True
+        assert entry.mean_ccn == 15.0
+        assert entry.risk == "high"
+
+    def test_single_owner_and_smelly_is_high(self):
+        rows = [_blame("src/a.py", 100, "alice", 95)]
+        findings = [{"relative_path": "src/a.py", "finding_count": 3}]
+        entry = compute_directory_ownership(rows, finding_rows=findings)[0]
+        assert entry.findings_per_kloc == 30.0
+        assert entry.risk == "high"
+
+    def test_single_owner_clean_code_is_medium(self):
+        rows = [_blame("src/a.py", 100, "alice", 95)]
+        complexity = [{"relative_path": "src/a.py", "total_ccn": 8, "function_count": 4}]
+        entry = compute_directory_ownership(rows, complexity_rows=complexity)[0]
+        assert entry.risk == "medium"
+
+    def test_shared_ownership_is_low(self):
+        rows = [
+            _blame("src/a.py", 100, "alice", 55),
+            _blame("src/b.py", 100, "bob", 55),
+        ]
+        complexity = [{"relative_path": "src/a.py", "total_ccn": 60, "function_count": 4}]
+        entry = compute_directory_ownership(rows, complexity_rows=complexity)[0]
+        assert entry.single_owner is False
+        assert entry.risk == "low"
+
+    def test_missing_tool_coverage_yields_none_columns(self):
+        entry = compute_directory_ownership([_blame("src/a.py", 100, "alice", 95)])[0]
+        assert entry.mean_ccn is None
+        assert entry.findings_per_kloc is None
+
+    def test_sorted_worst_first(self):
+        rows = [
+            _blame("shared/a.py", 100, "alice", 50),
+            _blame("shared/b.py", 100, "bob", 50),
+            _blame("solo/a.py", 100, "carol", 95, churn_90d=4),
+            _blame("busy/a.py", 100, "dave", 95, churn_90d=9),
+        ]
+        findings = [
+            {"relative_path": "solo/a.py", "finding_count": 2},
+            {"relative_path": "busy/a.py", "finding_count": 2},
+        ]
+        entries = compute_directory_ownership(rows, finding_rows=findings)
+        assert [entry.directory for entry in entries] == ["busy", "solo", "shared"]
@@ -0,0 +1,102 @@
+"""Tests for the scan provenance attestation."""
+
+import pytest
+from pathlib import Path
+
+from insights.provenance import (
+    PREDICATE_TYPE,
+    STATEMENT_TYPE,
+    build_statement,
+    canonical_json,
+    digest_tool_sources,
+    sign_statement,
+    verify_envelope,
+)
+
+
+def _row(tool_name: str = "scc", **overrides) -> dict:
+    row = {
+        "collection_run_id": "run-1",
+        "repo_id": "repo-a",
+        "branch": "main",
+        "commit": "a" * 40,
+        "started_at": "2026-08-01 00:00:00",
+        "completed_at": "2026-08-01 00:05:00",
+        "status": "completed",
+        "tool_name": tool_name,
+        "tool_version": "1.0",
+        "schema_version": "1.0.0",
+        "tool_timestamp": "2026-08-01 00:01:00",
+    }
+    row.update(overrides)
+    return row
+
+
+_ENV = {"os": "test-os", "python": "3.12", "machine": "x86_64"}
+
+
+class TestBuildStatement:
+    def test_statement_shape_and_subject_digest(self):
+        statement = build_statement([_row()], environment=_ENV)
+
+        assert statement["_type"] == STATEMENT_TYPE
+        assert statement["predicateType"] == PREDICATE_TYPE
+        assert statement["subject"][0]["name"] == "caldera-run:run-1"
+        assert len(statement["subject"][0]["digest"]["sha256"]) == 64
+        assert statement["predicate"]["repository"]["commit"] == "a" * 40
+
+    def test_one_tool_entry_per_run(self):
+        statement = build_statement([_row("scc"), _row("lizard")], environment=_ENV)
+        assert [t["name"] for t in statement["predicate"]["tools"]] == ["scc", "lizard"]
+
+    def test_subject_digest_tracks_predicate_changes(self):
+        base = build_statement([_row()], environment=_ENV)
+        drifted = build_statement([_row(tool_version="1.1")], environment=_ENV)
+        assert base["subject"][0]["digest"] != drifted["subject"][0]["digest"]
+
+    def test_config_hash_recorded(self, tmp_path: Path):
+        config = tmp_path / "caldera.toml"
+        config.write_text("[gates]\n")
+        statement = build_statement([_row()], config_path=config, environment=_ENV)
+        assert len(statement["predicate"]["config"]["digest"]["sha256"]) == 64
+
+    def test_empty_run_rejected(self):
+        with pytest.raises(ValueError, match="no tool runs"):
+            build_statement([])
+
+
+class TestDigestToolSources:
+    def test_rule_pack_edit_changes_digest(self, tmp_path: Path):
+        rules = tmp_path / "scc" / "rules"
+        rules.mkdir(parents=True)
+        (rules / "pack.yml").write_text("a: 1\n")
+        before = digest_tool_sources(tmp_path, "scc")
+        (rules / "pack.yml").write_text("a: 2\n")
+        assert digest_tool_sources(tmp_path, "scc") != before
+
+    def test_missing_tool_yields_none(self, tmp_path: Path):
+        assert digest_tool_sources(tmp_path, "absent") is None
+
+
+class TestSigning:
+    def test_roundtrip_verifies(self):
+        statement = build_statement([_row()], environment=_ENV)
+        envelope = sign_statement(statement, b"secret-key")
+        assert verify_envelope(envelope, b"secret-key")
+
+    def test_wrong_key_rejected(self):
+        envelope = sign_statement(build_statement([_row()], environment=_ENV), b"key-a")
+        assert not verify_envelope(envelope, b"key-b")
+
+    def test_tampered_payload_rejected(self):
+        envelope = sign_statement(build_statement([_row()], environment=_ENV), b"key")
+        envelope["payload"] = envelope["payload"][:-4] + "AAA="
+        assert not verify_envelope(envelope, b"key")
+
+    def test_malformed_envelope_rejected(self):
+        assert not verify_envelope({}, b"key")
+
+
+class TestCanonicalJson:
+    def test_key_order_does_not_matter(self):
+        assert canonical_json({"b": 1, "a": 2}) == canonical_json({"a": 2, "b": 1})
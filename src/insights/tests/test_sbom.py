@@ -0,0 +1,126 @@
+"""Tests for CycloneDX SBOM generation."""
+
+import json
+from pathlib import Path
+
+from insights.sbom import (
+    Component,
+    build_cyclonedx_document,
+    collect_components,
+    parse_cargo_lock,
+    parse_go_sum,
+    parse_package_lock,
+    parse_requirements_txt,
+    write_sbom,
+)
+
+CARGO_LOCK = """\
+version = 3
+
+[[package]]
+name = "serde"
+version = "1.0.195"
+
+[[package]]
+name = "tokio"
+version = "1.35.1"
+"""
+
+PACKAGE_LOCK = json.dumps({
+    "name": "demo",
+    "lockfileVersion": 3,
+    "packages": {
+        "": {"name": "demo", "version": "0.1.0"},
+        "node_modules/lodash": {"version": "4.17.21", "license": "MIT"},
+        "node_modules/@types/node": {"version": "20.11.0", "license": "MIT"},
+    },
+})
+
+GO_SUM = """\
+github.com/pkg/errors v0.9.1 h1:abc=
+github.com/pkg/errors v0.9.1/go.mod h1:def=
+golang.org/x/text v0.14.0 h1:ghi=
+"""
+
+REQUIREMENTS = """\
+# pinned deps
+duckdb==0.10.0
+typer[all]==0.9.0
+requests>=2.0
+-r other.txt
+"""
+
+
+class TestParsers:
+    """Tests for the per-ecosystem lockfile parsers."""
+
+    def test_parse_cargo_lock(self):
+        components = parse_cargo_lock(CARGO_LOCK)
+        assert [(c.name, c.version) for c in components] == [
+            ("serde", "1.0.195"),
+            ("tokio", "1.35.1"),
+        ]
+        assert components[0].purl == "pkg:cargo/serde@1.0.195"
+
+    def test_parse_package_lock_skips_root_and_keeps_license(self):
+        components = parse_package_lock(PACKAGE_LOCK)
+        by_name = {c.name: c for c in components}
+        assert set(by_name) == {"lodash", "@types/node"}
+        assert by_name["lodash"].license == "MIT"
+        assert by_name["@types/node"].purl == "pkg:npm/%40types/node@20.11.0"
+
+    def test_parse_go_sum_dedupes_and_skips_go_mod_lines(self):
+        components = parse_go_sum(GO_SUM)
+        assert [(c.name, c.version) for c in components] == [
+            ("github.com/pkg/errors", "v0.9.1"),
+            ("golang.org/x/text", "v0.14.0"),
+        ]
+
+    def test_parse_requirements_keeps_only_pinned(self):
+        components = parse_requirements_txt(REQUIREMENTS)
+        assert [(c.name, c.version) for c in components] == [
+            ("duckdb", "0.10.0"),
+            ("typer", "0.9.0"),
+        ]
+
+    def test_pypi_purl_is_normalized(self):
+        component = Component(name="Flask_Login", version="0.6.3", ecosystem="pypi")
+        assert component.purl == "pkg:pypi/flask-login@0.6.3"
+
+
+class TestDocument:
+    """Tests for CycloneDX document assembly."""
+
+    def test_document_shape(self):
+        components = [Component(name="serde", version="1.0.195", ecosystem="cargo")]
+        document = build_cyclonedx_document(components, "my-repo")
+        assert document["bomFormat"] == "CycloneDX"
+        assert document["specVersion"] == "1.5"
+        assert document["metadata"]["component"]["name"] == "my-repo"
+        assert document["components"][0]["purl"] == "pkg:cargo/serde@1.0.195"
+        assert "licenses" not in document["components"][0]
+
+    def test_document_includes_license_when_known(self):
+        components = [Component(name="lodash", version="4.17.21", ecosystem="npm", license="MIT")]
+        document = build_cyclonedx_document(components, "my-repo")
+        assert document["components"][0]["licenses"] == [{"license": {"id": "MIT"}}]
+
+
+class TestCollection:
+    """Tests for manifest discovery across a repository."""
+
+    def test_collect_skips_excluded_dirs(self, tmp_path: Path):
+        (tmp_path / "requirements.txt").write_text("duckdb==0.10.0\n")
+        vendored = tmp_path / "vendor" / "dep"
+        vendored.mkdir(parents=True)
+        (vendored / "requirements.txt").write_text("left-pad==1.0.0\n")
+        components = collect_components(tmp_path)
+        assert [c.name for c in components] == ["duckdb"]
+
+    def test_write_sbom_round_trips(self, tmp_path: Path):
+        (tmp_path / "Cargo.lock").write_text(CARGO_LOCK)
+        output = tmp_path / "out" / "bom.json"
+        count = write_sbom(tmp_path, output)
+        assert count == 2
+        document = json.loads(output.read_text())
+        assert len(document["components"]) == 2
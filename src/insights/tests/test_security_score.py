@@ -0,0 +1,108 @@
+"""Tests for the standalone security sub-score."""
+
+import pytest
+from pathlib import Path
+
+from insights.health_score import compute_maintainability_score
+from insights.security_score import (
+    DEFAULT_SECURITY_CAPS,
+    DEFAULT_SECURITY_WEIGHTS,
+    CveFinding,
+    SecurityConfig,
+    compute_security_score,
+    dependency_input,
+    load_security_config,
+    secrets_input,
+)
+
+
+class TestSecurityConfig:
+    """Tests for config validation and loading."""
+
+    def test_defaults_are_valid(self):
+        SecurityConfig(dict(DEFAULT_SECURITY_WEIGHTS), dict(DEFAULT_SECURITY_CAPS))
+
+    def test_missing_weight_rejected(self):
+        weights = dict(DEFAULT_SECURITY_WEIGHTS)
+        del weights["secrets"]
+        with pytest.raises(ValueError, match="missing weight"):
+            SecurityConfig(weights, dict(DEFAULT_SECURITY_CAPS))
+
+    def test_load_from_caldera_toml(self, tmp_path: Path):
+        config_path = tmp_path / "caldera.toml"
+        config_path.write_text(
+            "[security_score.weights]\nsast = 0.6\n\n[security_score.caps]\nsecrets = 2\n"
+        )
+        config = load_security_config(config_path)
+        assert config.weights["sast"] == 0.6
+        assert config.caps["secrets"] == 2
+        # Untouched keys keep their defaults.
+        assert config.weights["dependencies"] == 0.30
+
+    def test_missing_file_yields_defaults(self, tmp_path: Path):
+        config = load_security_config(tmp_path / "absent.toml")
+        assert config.weights == DEFAULT_SECURITY_WEIGHTS
+
+
+class TestSecretsInput:
+    def test_head_secrets_count_full_weight(self):
+        assert secrets_input([("CRITICAL", True)]) == 4.0
+
+    def test_historical_secrets_count_half(self):
+        assert secrets_input([("CRITICAL", False)]) == 2.0
+
+    def test_unknown_severity_defaults_to_medium(self):
+        assert secrets_input([(None, True)]) == 1.0
+
+
+class TestDependencyInput:
+    def test_cvss_normalized_and_direct_weighted(self):
+        assert dependency_input([CveFinding(cvss_score=8.0, severity="HIGH")]) == pytest.approx(1.2)
+
+    def test_transitive_not_boosted(self):
+        cve = CveFinding(cvss_score=8.0, severity="HIGH", direct=False)
+        assert dependency_input([cve]) == pytest.approx(0.8)
+
+    def test_known_exploited_doubles(self):
+        cve = CveFinding(cvss_score=5.0, severity=None, known_exploited=True, direct=False)
+        assert dependency_input([cve]) == pytest.approx(1.0)
+
+    def test_missing_cvss_falls_back_to_severity(self):
+        cve = CveFinding(cvss_score=None, severity="CRITICAL", direct=False)
+        assert dependency_input([cve]) == pytest.approx(0.95)
+
+    def test_out_of_range_cvss_rejected(self):
+        with pytest.raises(ValueError, match="cvss_score"):
+            CveFinding(cvss_score=11.0, severity=None)
+
+
+class TestComputeSecurityScore:
+    def test_clean_scope_scores_100(self):
+        assert compute_security_score(".", {}).score == 100.0
+
+    def test_saturated_components_score_0(self):
+        metrics = {"sast": 100.0, "secrets": 100.0, "dependencies": 100.0}
+        assert compute_security_score(".", metrics).score == 0.0
+
+    def test_missing_component_contributes_no_penalty(self):
+        # No dependency scanner ran: only sast (40%) is penalized.
+        score = compute_security_score(".", {"sast": 10.0})
+        assert score.penalties["dependencies"] == 0.0
+        assert score.score == 60.0
+
+    def test_independent_of_maintainability(self):
+        # A complexity disaster does not move the security score, and a
+        # security disaster does not move the maintainability score.
+        security = compute_security_score(".", {"complexity": 100.0})
+        maintainability = compute_maintainability_score(".", {"security": 100.0})
+        assert security.score == 100.0
+        assert maintainability.score == 100.0
+        assert "security" not in maintainability.penalties
+
+
+class TestComputeMaintainabilityScore:
+    def test_reweights_without_security(self):
+        # Saturating every non-security dimension must reach zero even
+        # though the security weight is gone.
+        metrics = {"complexity": 999.0, "duplication": 999.0, "size": 9999.0, "dead_code": 999.0}
+        assert compute_maintainability_score(".", metrics).score == 0.0
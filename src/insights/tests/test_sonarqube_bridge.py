@@ -0,0 +1,118 @@
+"""Tests for the SonarQube export and import bridge."""
+
+from pathlib import Path
+
+from insights.sonarqube_bridge import (
+    compare_findings,
+    export_generic_issues,
+    import_sonarqube_issues,
+    write_export,
+)
+
+
+def _finding(**overrides) -> dict:
+    finding = {
+        "tool": "bandit",
+        "relative_path": "src/app.py",
+        "rule_id": "B608",
+        "severity": "HIGH",
+        "line_start": 14,
+        "line_end": 14,
+        "message": "Possible SQL injection",
+    }
+    finding.update(overrides)
+    return finding
+
+
+class TestExport:
+    """Tests for the generic issue import format export."""
+
+    def test_export_maps_core_fields(self):
+        document = export_generic_issues([_finding()])
+        assert len(document["issues"]) == 1
+        issue = document["issues"][0]
+        assert issue["engineId"] == "caldera"
+        assert issue["ruleId"] == "bandit:B608"
+        assert issue["severity"] == "CRITICAL"  # HIGH -> CRITICAL
+        assert issue["type"] == "VULNERABILITY"  # bandit is a security tool
+        location = issue["primaryLocation"]
+        assert location["filePath"] == "src/app.py"
+        assert location["textRange"] == {"startLine": 14, "endLine": 14}
+
+    def test_export_lint_finding_is_code_smell(self):
+        document = export_generic_issues([_finding(tool="sqlfluff", severity="MEDIUM")])
+        issue = document["issues"][0]
+        assert issue["type"] == "CODE_SMELL"
+        assert issue["severity"] == "MAJOR"
+
+    def test_export_missing_line_defaults_to_one(self):
+        document = export_generic_issues([_finding(line_start=None, line_end=None)])
+        assert document["issues"][0]["primaryLocation"]["textRange"] == {"startLine": 1}
+
+    def test_write_export_round_trips(self, tmp_path: Path):
+        output = tmp_path / "out" / "issues.json"
+        count = write_export([_finding(), _finding(rule_id="B102")], output)
+        assert count == 2
+        assert output.exists()
+
+
+class TestImport:
+    """Tests for parsing SonarQube issue exports."""
+
+    def _export_file(self, tmp_path: Path, issues: list[dict]) -> Path:
+        import json
+
+        path = tmp_path / "sonar.json"
+        path.write_text(json.dumps({"issues": issues}))
+        return path
+
+    def test_import_maps_core_fields(self, tmp_path: Path):
+        path = self._export_file(tmp_path, [
+            {
+                "rule": "python:S3649",
+                "component": "my-project:src/app.py",
+                "severity": "MAJOR",
+                "line": 14,
+                "textRange": {"startLine": 14, "endLine": 16},
+                "message": "Injection",
+                "status": "OPEN",
+            }
+        ])
+        findings = import_sonarqube_issues(path)
+        assert len(findings) == 1
+        assert findings[0]["relative_path"] == "src/app.py"
+        assert findings[0]["severity"] == "MEDIUM"  # MAJOR -> MEDIUM
+        assert findings[0]["line_start"] == 14
+        assert findings[0]["line_end"] == 16
+
+    def test_import_skips_closed_issues(self, tmp_path: Path):
+        path = self._export_file(tmp_path, [
+            {"rule": "r", "component": "p:a.py", "severity": "MINOR", "status": "CLOSED"},
+            {"rule": "r", "component": "p:b.py", "severity": "MINOR", "status": "OPEN"},
+        ])
+        findings = import_sonarqube_issues(path)
+        assert [f["relative_path"] for f in findings] == ["b.py"]
+
+
+class TestCompare:
+    """Tests for reconciling the two finding sets."""
+
+    def test_matches_by_file_and_line(self):
+        caldera = [_finding(), _finding(relative_path="src/other.py", line_start=3)]
+        sonarqube = [
+            {"relative_path": "src/app.py", "line_start": 14},
+            {"relative_path": "src/third.py", "line_start": 9},
+        ]
+        result = compare_findings(caldera, sonarqube)
+        assert result.matched == 1
+        assert result.only_caldera == 1
+        assert result.only_sonarqube == 1
+        assert result.matched_by_file == {"src/app.py": 1}
+        assert result.total_caldera == 2
+        assert result.total_sonarqube == 2
+
+    def test_empty_sets(self):
+        result = compare_findings([], [])
+        assert result.matched == 0
+        assert result.only_caldera == 0
+        assert result.only_sonarqube == 0
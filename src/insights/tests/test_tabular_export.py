@@ -0,0 +1,168 @@
+"""Tests for CSV/Parquet tabular exports."""
+
+from __future__ import annotations
+
+import csv
+from datetime import datetime
+import json
+from pathlib import Path
+
+import duckdb
+import pytest
+
+from common.record_filter import parse_filter, parse_select
+from insights import tabular_export
+from insights.tabular_export import export_dataset
+
+
+@pytest.fixture
+def db_path(tmp_path: Path) -> Path:
+    path = tmp_path / "test.duckdb"
+    conn = duckdb.connect(str(path))
+    schema_sql = (
+        Path(__file__).resolve().parents[2] / "sot-engine" / "persistence" / "schema.sql"
+    ).read_text()
+    conn.execute(schema_sql)
+    conn.execute(
+        """INSERT INTO lz_collection_runs VALUES
+           ('run-1', 'repo-a', 'run-1', 'main', ?, ?, ?, 'completed')""",
+        ["a" * 40, datetime(2026, 8, 1), datetime(2026, 8, 1)],
+    )
+    pks = {}
+    for tool_name in ("scc", "lizard", "pmd-cpd", "semgrep"):
+        conn.execute(
+            """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+                   tool_version, schema_version, branch, commit, timestamp)
+               VALUES ('run-1', 'repo-a', ?, ?, '1.0', '1.0.0', 'main', ?, ?)""",
+            [f"run-1-{tool_name}", tool_name, "a" * 40, datetime(2026, 8, 1)],
+        )
+        pks[tool_name] = conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = ?", [tool_name]
+        ).fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_scc_file_metrics (run_pk, file_id, directory_id, relative_path,
+               language, lines_total, code_lines, comment_lines, blank_lines, bytes, complexity)
+           VALUES (?, 'f-1', 'd-1', 'src/a.py', 'Python', 100, 80, 10, 10, 1000, 5),
+                  (?, 'f-2', 'd-1', 'src/b.py', 'Python', 50, 40, 5, 5, 500, 2)""",
+        [pks["scc"], pks["scc"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_lizard_file_metrics
+           (run_pk, file_id, relative_path, language, nloc, function_count, total_ccn, avg_ccn, max_ccn)
+           VALUES (?, 'f-1', 'src/a.py', 'Python', 80, 4, 20, 5.0, 9)""",
+        [pks["lizard"]],
+    )
+    conn.execute(
+        """INSERT INTO lz_semgrep_smells
+           (run_pk, file_id, directory_id, relative_path, rule_id, dd_category,
+            severity, line_start, line_end, column_start, column_end, message, code_snippet)
+           VALUES (?, 'f-1', 'd-1', 'src/a.py', 'rule-1', 'smell', 'MEDIUM', 3, 3, 1, 5, 'smelly', NULL)""",
+        [pks["semgrep"]],
+    )
+    conn.close()
+    return path
+
+
+@pytest.fixture
+def run_pk(db_path: Path) -> int:
+    conn = duckdb.connect(str(db_path), read_only=True)
+    try:
+        return conn.execute(
+            "SELECT run_pk FROM lz_tool_runs WHERE tool_name = 'scc'"
+        ).fetchone()[0]
+    finally:
+        conn.close()
+
+
+class TestExportDataset:
+    def test_findings_csv(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "findings.csv"
+        count = export_dataset(db_path, "findings", run_pk, output, "csv")
+        assert count == 1
+        rows = list(csv.DictReader(output.open()))
+        assert rows[0]["tool"] == "semgrep"
+        assert rows[0]["relative_path"] == "src/a.py"
+        assert rows[0]["severity"] == "MEDIUM"
+
+    def test_file_metrics_csv_joins_tools(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "files.csv"
+        count = export_dataset(db_path, "file-metrics", run_pk, output, "csv")
+        assert count == 2
+        rows = {row["relative_path"]: row for row in csv.DictReader(output.open())}
+        assert rows["src/a.py"]["total_ccn"] == "20"
+        assert rows["src/b.py"]["total_ccn"] == ""  # lizard did not see b.py
+
+    def test_parquet_round_trip(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "out" / "files.parquet"
+        count = export_dataset(db_path, "file-metrics", run_pk, output, "parquet")
+        assert count == 2
+        conn = duckdb.connect()
+        rows = conn.execute(
+            "SELECT relative_path, code_lines FROM read_parquet(?) ORDER BY relative_path",
+            [str(output)],
+        ).fetchall()
+        assert rows == [("src/a.py", 80), ("src/b.py", 40)]
+
+    def test_jsonl_one_record_per_line(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "files.jsonl"
+        count = export_dataset(db_path, "file-metrics", run_pk, output, "jsonl")
+        assert count == 2
+        records = [json.loads(line) for line in output.read_text().splitlines()]
+        assert [record["relative_path"] for record in records] == ["src/a.py", "src/b.py"]
+        assert records[0]["total_ccn"] == 20
+        assert records[1]["total_ccn"] is None  # lizard did not see b.py
+
+    def test_jsonl_streams_in_batches(
+        self, db_path: Path, run_pk: int, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        # With a batch size of 1 every row crosses the fetchmany boundary.
+        monkeypatch.setattr(tabular_export, "STREAM_BATCH_SIZE", 1)
+        output = tmp_path / "files.jsonl"
+        assert export_dataset(db_path, "file-metrics", run_pk, output, "jsonl") == 2
+        assert len(output.read_text().splitlines()) == 2
+
+    def test_select_projects_jsonl_columns(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "files.jsonl"
+        count = export_dataset(
+            db_path, "file-metrics", run_pk, output, "jsonl",
+            select=parse_select("relative_path,code_lines"),
+        )
+        assert count == 2
+        records = [json.loads(line) for line in output.read_text().splitlines()]
+        assert records[0] == {"relative_path": "src/a.py", "code_lines": 80}
+
+    def test_filter_drops_rows(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "files.csv"
+        count = export_dataset(
+            db_path, "file-metrics", run_pk, output, "csv",
+            record_filter=parse_filter("code_lines > 50"),
+        )
+        assert count == 1
+        rows = list(csv.DictReader(output.open()))
+        assert [row["relative_path"] for row in rows] == ["src/a.py"]
+
+    def test_filter_and_select_combine(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        output = tmp_path / "findings.csv"
+        count = export_dataset(
+            db_path, "findings", run_pk, output, "csv",
+            select=parse_select("relative_path,severity"),
+            record_filter=parse_filter("severity = MEDIUM"),
+        )
+        assert count == 1
+        rows = list(csv.DictReader(output.open()))
+        assert rows == [{"relative_path": "src/a.py", "severity": "MEDIUM"}]
+
+    def test_filter_rejected_for_parquet(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="csv or jsonl"):
+            export_dataset(
+                db_path, "file-metrics", run_pk, tmp_path / "x.parquet", "parquet",
+                select=parse_select("relative_path"),
+            )
+
+    def test_unknown_dataset_rejected(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="unknown dataset"):
+            export_dataset(db_path, "velocity", run_pk, tmp_path / "x.csv", "csv")
+
+    def test_unknown_format_rejected(self, db_path: Path, run_pk: int, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="unknown format"):
+            export_dataset(db_path, "findings", run_pk, tmp_path / "x.xlsx", "xlsx")
@@ -0,0 +1,134 @@
+"""Tests for the top-N offenders worklist."""
+
+import pytest
+from pathlib import Path
+
+from insights.top_offenders import (
+    METRIC_SECTIONS,
+    METRICS,
+    extract_excerpt,
+    report_link,
+    top_offenders,
+)
+
+
+class FakeFetcher:
+    """Returns canned rows per query name and records calls."""
+
+    def __init__(self, rows_by_query):
+        self.rows_by_query = rows_by_query
+        self.calls = []
+
+    def fetch(self, query_name, run_pk, **params):
+        self.calls.append((query_name, run_pk, params))
+        return self.rows_by_query.get(query_name, [])
+
+
+def _ccn_row(name="main", ccn=45, path="src/app.py"):
+    return {
+        "function_name": name,
+        "relative_path": path,
+        "ccn": ccn,
+        "nloc": 120,
+        "risk_level": "critical",
+        "line_start": 10,
+        "line_end": 90,
+    }
+
+
+class TestTopOffenders:
+    """Tests for the per-metric builders."""
+
+    def test_ccn_ranks_functions(self):
+        fetcher = FakeFetcher({
+            "function_complexity_hotspots": [_ccn_row(), _ccn_row(name="helper", ccn=12)],
+        })
+        offenders = top_offenders(fetcher, "ccn", run_pk=19, n=25)
+
+        assert len(offenders) == 2
+        assert offenders[0].subject == "main (src/app.py)"
+        assert offenders[0].value == 45.0
+        assert offenders[0].line_start == 10
+        assert "CCN 45" in offenders[0].detail
+
+    def test_duplication_uses_clone_rows(self):
+        fetcher = FakeFetcher({
+            "duplication_hotspots": [{
+                "clone_id": "c1",
+                "files_affected": 3,
+                "total_duplicated_lines": 240,
+                "occurrence_count": 4,
+                "risk_level": "high",
+            }],
+        })
+        offenders = top_offenders(fetcher, "duplication", run_pk=19)
+
+        assert offenders[0].value == 240.0
+        assert "3 files" in offenders[0].subject
+
+    def test_findings_aggregates_per_file(self):
+        rows = [
+            {"tool": "semgrep", "relative_path": "src/a.py", "line_start": 5},
+            {"tool": "bandit", "relative_path": "src/a.py", "line_start": 9},
+            {"tool": "semgrep", "relative_path": "src/b.py", "line_start": 1},
+        ]
+        fetcher = FakeFetcher({"findings_export": rows})
+        offenders = top_offenders(fetcher, "findings", run_pk=19, n=2)
+
+        assert offenders[0].subject == "src/a.py"
+        assert offenders[0].value == 2.0
+        assert "bandit, semgrep" in offenders[0].detail
+
+    def test_churn_sorts_by_90d_window(self):
+        rows = [
+            {"relative_path": "src/stable.py", "churn_90d": 1, "churn_30d": 0, "risk_score": 2},
+            {"relative_path": "src/hot.py", "churn_90d": 40, "churn_30d": 12, "risk_score": 8},
+            {"relative_path": "src/dead.py", "churn_90d": 0, "churn_30d": 0, "risk_score": 1},
+        ]
+        fetcher = FakeFetcher({"knowledge_risk_hotspots": rows})
+        offenders = top_offenders(fetcher, "churn", run_pk=19, n=5)
+
+        assert [offender.subject for offender in offenders] == ["src/hot.py", "src/stable.py"]
+
+    def test_n_caps_the_list(self):
+        fetcher = FakeFetcher({
+            "function_complexity_hotspots": [_ccn_row(name=f"f{i}") for i in range(10)],
+        })
+        assert len(top_offenders(fetcher, "ccn", run_pk=19, n=3)) == 3
+
+    def test_unknown_metric_rejected(self):
+        with pytest.raises(ValueError, match="unknown metric"):
+            top_offenders(FakeFetcher({}), "velocity", run_pk=19)
+
+    def test_non_positive_n_rejected(self):
+        with pytest.raises(ValueError, match="n must be"):
+            top_offenders(FakeFetcher({}), "ccn", run_pk=19, n=0)
+
+
+class TestExcerpts:
+    """Tests for code excerpt extraction."""
+
+    def test_reads_requested_lines(self, tmp_path: Path):
+        (tmp_path / "src").mkdir()
+        (tmp_path / "src" / "app.py").write_text("one\ntwo\nthree\nfour\n")
+
+        excerpt = extract_excerpt(tmp_path, "src/app.py", 2, 3)
+        assert excerpt == [(2, "two"), (3, "three")]
+
+    def test_caps_long_ranges(self, tmp_path: Path):
+        (tmp_path / "big.py").write_text("\n".join(f"line{i}" for i in range(1, 50)))
+
+        excerpt = extract_excerpt(tmp_path, "big.py", 1, 40, max_lines=5)
+        assert len(excerpt) == 5
+
+    def test_missing_file_yields_empty(self, tmp_path: Path):
+        assert extract_excerpt(tmp_path, "nope.py", 1, 2) == []
+
+
+class TestReportLink:
+    """Tests for report anchor links."""
+
+    def test_each_metric_has_a_section(self):
+        for metric in METRICS:
+            link = report_link(Path("report.html"), metric)
+            assert link == f"report.html#{METRIC_SECTIONS[metric]}"
@@ -0,0 +1,163 @@
+"""
+Top-N offenders across metrics, as a prioritized worklist.
+
+Backs the ``insights top`` command: for a chosen metric (ccn, duplication,
+findings, churn) it pulls the existing hotspot queries, reduces them to a
+short ranked list of files/functions, and optionally attaches code
+excerpts and an anchor link into the generated HTML report. The goal is a
+worklist a team can act on, not an exhaustive dump.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+# HTML report section anchor per metric (section ids in the generated report).
+METRIC_SECTIONS: dict[str, str] = {
+    "ccn": "function_complexity",
+    "duplication": "code_duplication",
+    "findings": "code_quality_rules",
+    "churn": "knowledge_risk",
+}
+
+METRICS = tuple(METRIC_SECTIONS)
+
+
+@dataclass(frozen=True)
+class TopOffender:
+    """One ranked entry in the worklist."""
+
+    subject: str  # file path, or "function (file)" for function-level metrics
+    value: float
+    detail: str  # human-readable context for the value
+    path: str | None = None  # repo-relative path, when known
+    line_start: int | None = None
+    line_end: int | None = None
+
+
+def _top_ccn(fetcher: Any, run_pk: int, n: int) -> list[TopOffender]:
+    """Worst functions by cyclomatic complexity."""
+    rows = fetcher.fetch("function_complexity_hotspots", run_pk=run_pk, limit=n)
+    return [
+        TopOffender(
+            subject=f"{row.get('function_name', '')} ({row.get('relative_path', '')})",
+            value=float(row.get("ccn") or 0),
+            detail=f"CCN {row.get('ccn')}, {row.get('nloc')} NLOC, risk {row.get('risk_level')}",
+            path=row.get("relative_path"),
+            line_start=row.get("line_start"),
+            line_end=row.get("line_end"),
+        )
+        for row in rows[:n]
+    ]
+
+
+def _top_duplication(fetcher: Any, run_pk: int, n: int) -> list[TopOffender]:
+    """Worst clones by total duplicated lines."""
+    rows = fetcher.fetch("duplication_hotspots", run_pk=run_pk, limit=n)
+    return [
+        TopOffender(
+            subject=f"clone {row.get('clone_id')} ({row.get('files_affected')} files)",
+            value=float(row.get("total_duplicated_lines") or 0),
+            detail=(
+                f"{row.get('total_duplicated_lines')} duplicated lines, "
+                f"{row.get('occurrence_count')} occurrences, risk {row.get('risk_level')}"
+            ),
+        )
+        for row in rows[:n]
+    ]
+
+
+def _top_findings(fetcher: Any, run_pk: int, n: int) -> list[TopOffender]:
+    """Files with the most linter/security findings across tools."""
+    rows = fetcher.fetch("findings_export", run_pk=run_pk)
+    counts: Counter[str] = Counter()
+    tools_by_path: dict[str, set[str]] = {}
+    first_line: dict[str, int | None] = {}
+    for row in rows:
+        path = row.get("relative_path") or ""
+        counts[path] += 1
+        tools_by_path.setdefault(path, set()).add(row.get("tool", ""))
+        first_line.setdefault(path, row.get("line_start"))
+    return [
+        TopOffender(
+            subject=path,
+            value=float(count),
+            detail=f"{count} findings from {', '.join(sorted(tools_by_path[path]))}",
+            path=path,
+            line_start=first_line.get(path),
+            line_end=first_line.get(path),
+        )
+        for path, count in counts.most_common(n)
+    ]
+
+
+def _top_churn(fetcher: Any, run_pk: int, n: int) -> list[TopOffender]:
+    """Files with the highest recent churn (90-day window)."""
+    rows = fetcher.fetch("knowledge_risk_hotspots", run_pk=run_pk, limit=n * 4)
+    rows = sorted(rows, key=lambda row: row.get("churn_90d") or 0, reverse=True)
+    return [
+        TopOffender(
+            subject=row.get("relative_path", ""),
+            value=float(row.get("churn_90d") or 0),
+            detail=(
+                f"{row.get('churn_90d')} changes in 90d "
+                f"({row.get('churn_30d')} in 30d), risk {row.get('risk_score')}"
+            ),
+            path=row.get("relative_path"),
+        )
+        for row in rows[:n]
+        if (row.get("churn_90d") or 0) > 0
+    ]
+
+
+_BUILDERS = {
+    "ccn": _top_ccn,
+    "duplication": _top_duplication,
+    "findings": _top_findings,
+    "churn": _top_churn,
+}
+
+
+def top_offenders(fetcher: Any, metric: str, run_pk: int, n: int = 25) -> list[TopOffender]:
+    """Return the top-N offenders for one metric, worst first.
+
+    Raises ValueError for unknown metrics so the CLI can report the
+    supported set.
+    """
+    if metric not in _BUILDERS:
+        raise ValueError(
+            f"unknown metric: {metric} (expected one of: {', '.join(METRICS)})"
+        )
+    if n < 1:
+        raise ValueError("n must be >= 1")
+    return _BUILDERS[metric](fetcher, run_pk, n)
+
+
+def extract_excerpt(
+    repo_path: Path,
+    relative_path: str,
+    line_start: int,
+    line_end: int | None = None,
+    max_lines: int = 5,
+) -> list[tuple[int, str]]:
+    """Read the offending lines from the working tree.
+
+    Returns (line_number, text) pairs, capped at max_lines; an empty list
+    when the file is missing or binary so excerpts degrade gracefully.
+    """
+    target = repo_path / relative_path
+    try:
+        lines = target.read_text(encoding="utf-8").splitlines()
+    except (UnicodeDecodeError, OSError):
+        return []
+    start = max(line_start, 1)
+    end = min(line_end or line_start, start + max_lines - 1, len(lines))
+    return [(number, lines[number - 1]) for number in range(start, end + 1)]
+
+
+def report_link(report_path: Path, metric: str) -> str:
+    """Anchor link into the matching section of a generated HTML report."""
+    return f"{report_path}#{METRIC_SECTIONS[metric]}"
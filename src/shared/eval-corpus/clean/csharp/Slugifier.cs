@@ -0,0 +1,56 @@
+using System;
+using System.Globalization;
+using System.Text;
+
+namespace Example.Text
+{
+    /// <summary>
+    /// Converts arbitrary titles into URL-safe slugs.
+    /// </summary>
+    public static class Slugifier
+    {
+        private const int MaxSlugLength = 80;
+
+        /// <summary>
+        /// Produces a lowercase, hyphen-separated slug from a title.
+        /// </summary>
+        /// <param name="title">The human-readable title.</param>
+        /// <returns>A URL-safe slug, never longer than 80 characters.</returns>
+        public static string Slugify(string title)
+        {
+            if (string.IsNullOrWhiteSpace(title))
+            {
+                return string.Empty;
+            }
+
+            var normalized = title.Normalize(NormalizationForm.FormD);
+            var builder = new StringBuilder(normalized.Length);
+            var previousWasHyphen = false;
+
+            foreach (var ch in normalized)
+            {
+                var category = CharUnicodeInfo.GetUnicodeCategory(ch);
+                if (category == UnicodeCategory.NonSpacingMark)
+                {
+                    continue;
+                }
+
+                if (char.IsLetterOrDigit(ch))
+                {
+                    builder.Append(char.ToLowerInvariant(ch));
+                    previousWasHyphen = false;
+                }
+                else if (!previousWasHyphen && builder.Length > 0)
+                {
+                    builder.Append('-');
+                    previousWasHyphen = true;
+                }
+            }
+
+            var slug = builder.ToString().TrimEnd('-');
+            return slug.Length <= MaxSlugLength
+                ? slug
+                : slug.Substring(0, MaxSlugLength).TrimEnd('-');
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Package ratelimiter provides a token-bucket rate limiter.
+package ratelimiter
+
+import (
+	"sync"
+	"time"
+)
+
+// Limiter is a thread-safe token bucket.
+type Limiter struct {
+	mu         sync.Mutex
+	capacity   float64
+	tokens     float64
+	refillRate float64 // tokens per second
+	lastRefill time.Time
+	now        func() time.Time
+}
+
+// New creates a limiter holding at most capacity tokens, refilled at
+// refillRate tokens per second.
+func New(capacity, refillRate float64) *Limiter {
+	return &Limiter{
+		capacity:   capacity,
+		tokens:     capacity,
+		refillRate: refillRate,
+		lastRefill: time.Now(),
+		now:        time.Now,
+	}
+}
+
+// Allow reports whether one request may proceed, consuming a token.
+func (l *Limiter) Allow() bool {
+	return l.AllowN(1)
+}
+
+// AllowN reports whether n requests may proceed, consuming n tokens.
+func (l *Limiter) AllowN(n float64) bool {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	l.refill()
+	if l.tokens < n {
+		return false
+	}
+	l.tokens -= n
+	return true
+}
+
+// Available returns the current token count after refill.
+func (l *Limiter) Available() float64 {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	l.refill()
+	return l.tokens
+}
+
+func (l *Limiter) refill() {
+	now := l.now()
+	elapsed := now.Sub(l.lastRefill).Seconds()
+	if elapsed <= 0 {
+		return
+	}
+	l.tokens += elapsed * l.refillRate
+	if l.tokens > l.capacity {
+		l.tokens = l.capacity
+	}
+	l.lastRefill = now
+}
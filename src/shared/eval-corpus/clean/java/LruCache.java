@@ -0,0 +1,55 @@
+package com.example.cache;
+
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.Optional;
+
+/**
+ * A bounded least-recently-used cache backed by {@link LinkedHashMap}.
+ *
+ * @param <K> key type
+ * @param <V> value type
+ */
+public final class LruCache<K, V> {
+
+    private final int capacity;
+    private final LinkedHashMap<K, V> entries;
+
+    /**
+     * Creates a cache holding at most {@code capacity} entries.
+     *
+     * @param capacity maximum number of entries, must be positive
+     */
+    public LruCache(int capacity) {
+        if (capacity <= 0) {
+            throw new IllegalArgumentException("capacity must be positive");
+        }
+        this.capacity = capacity;
+        this.entries = new LinkedHashMap<>(capacity, 0.75f, true) {
+            @Override
+            protected boolean removeEldestEntry(Map.Entry<K, V> eldest) {
+                return size() > LruCache.this.capacity;
+            }
+        };
+    }
+
+    /** Stores a value, evicting the least recently used entry if full. */
+    public synchronized void put(K key, V value) {
+        entries.put(key, value);
+    }
+
+    /** Returns the cached value, marking the entry as recently used. */
+    public synchronized Optional<V> get(K key) {
+        return Optional.ofNullable(entries.get(key));
+    }
+
+    /** Removes one entry if present. */
+    public synchronized boolean evict(K key) {
+        return entries.remove(key) != null;
+    }
+
+    /** Current number of cached entries. */
+    public synchronized int size() {
+        return entries.size();
+    }
+}
@@ -0,0 +1,67 @@
+/** A minimal typed event bus with unsubscribe support. */
+"use strict";
+
+class EventBus {
+  constructor() {
+    this._handlers = new Map();
+  }
+
+  /**
+   * Subscribe to an event; returns a function that removes the handler.
+   * @param {string} event
+   * @param {(payload: unknown) => void} handler
+   * @returns {() => void}
+   */
+  on(event, handler) {
+    if (typeof handler !== "function") {
+      throw new TypeError("handler must be a function");
+    }
+    if (!this._handlers.has(event)) {
+      this._handlers.set(event, new Set());
+    }
+    this._handlers.get(event).add(handler);
+    return () => {
+      const handlers = this._handlers.get(event);
+      if (handlers) {
+        handlers.delete(handler);
+        if (handlers.size === 0) {
+          this._handlers.delete(event);
+        }
+      }
+    };
+  }
+
+  /**
+   * Emit an event to all subscribers; handler errors are isolated.
+   * @param {string} event
+   * @param {unknown} payload
+   * @returns {number} number of handlers invoked
+   */
+  emit(event, payload) {
+    const handlers = this._handlers.get(event);
+    if (!handlers) {
+      return 0;
+    }
+    let invoked = 0;
+    for (const handler of [...handlers]) {
+      try {
+        handler(payload);
+        invoked += 1;
+      } catch (error) {
+        console.error(`handler for "${event}" failed:`, error);
+      }
+    }
+    return invoked;
+  }
+
+  /** Number of subscribers across all events. */
+  get size() {
+    let total = 0;
+    for (const handlers of this._handlers.values()) {
+      total += handlers.size;
+    }
+    return total;
+  }
+}
+
+module.exports = { EventBus };
@@ -0,0 +1,67 @@
+"""A small in-process task scheduler with bounded retries."""
+from __future__ import annotations
+
+import heapq
+import logging
+import time
+from dataclasses import dataclass, field
+from typing import Callable
+
+logger = logging.getLogger(__name__)
+
+MAX_RETRIES = 3
+RETRY_BACKOFF_SECONDS = 2.0
+
+
+@dataclass(order=True)
+class ScheduledTask:
+    """A task queued for execution at a specific time."""
+
+    run_at: float
+    name: str = field(compare=False)
+    action: Callable[[], None] = field(compare=False)
+    attempts: int = field(default=0, compare=False)
+
+
+class TaskScheduler:
+    """Runs callables at scheduled times with bounded retry on failure."""
+
+    def __init__(self, clock: Callable[[], float] = time.monotonic) -> None:
+        self._clock = clock
+        self._queue: list[ScheduledTask] = []
+
+    def schedule(self, name: str, action: Callable[[], None], delay: float = 0.0) -> None:
+        """Queue a task to run after the given delay in seconds."""
+        if delay < 0:
+            raise ValueError("delay must be non-negative")
+        heapq.heappush(self._queue, ScheduledTask(self._clock() + delay, name, action))
+
+    def pending(self) -> int:
+        """Number of tasks still waiting to run."""
+        return len(self._queue)
+
+    def run_due(self) -> int:
+        """Run every task whose time has come; returns how many ran."""
+        executed = 0
+        now = self._clock()
+        while self._queue and self._queue[0].run_at <= now:
+            task = heapq.heappop(self._queue)
+            try:
+                task.action()
+                executed += 1
+            except Exception:
+                logger.exception("task %s failed (attempt %d)", task.name, task.attempts + 1)
+                self._requeue(task)
+        return executed
+
+    def _requeue(self, task: ScheduledTask) -> None:
+        """Retry a failed task with linear backoff, up to MAX_RETRIES."""
+        if task.attempts + 1 >= MAX_RETRIES:
+            logger.warning("task %s dropped after %d attempts", task.name, MAX_RETRIES)
+            return
+        heapq.heappush(self._queue, ScheduledTask(
+            run_at=self._clock() + RETRY_BACKOFF_SECONDS * (task.attempts + 1),
+            name=task.name,
+            action=task.action,
+            attempts=task.attempts + 1,
+        ))
@@ -0,0 +1,66 @@
+"""SQLite-backed user store using parameterized queries throughout."""
+from __future__ import annotations
+
+import sqlite3
+from contextlib import closing
+from dataclasses import dataclass
+from pathlib import Path
+
+
+@dataclass(frozen=True)
+class User:
+    """One stored user record."""
+
+    user_id: int
+    email: str
+    display_name: str
+
+
+class UserStore:
+    """Minimal CRUD over a users table; every query is parameterized."""
+
+    def __init__(self, db_path: Path) -> None:
+        self._db_path = db_path
+        with closing(self._connect()) as conn:
+            conn.execute(
+                """CREATE TABLE IF NOT EXISTS users (
+                    user_id INTEGER PRIMARY KEY,
+                    email TEXT NOT NULL UNIQUE,
+                    display_name TEXT NOT NULL
+                )"""
+            )
+            conn.commit()
+
+    def _connect(self) -> sqlite3.Connection:
+        return sqlite3.connect(self._db_path)
+
+    def add(self, email: str, display_name: str) -> int:
+        """Insert a user and return its id."""
+        with closing(self._connect()) as conn:
+            cursor = conn.execute(
+                "INSERT INTO users (email, display_name) VALUES (?, ?)",
+                (email, display_name),
+            )
+            conn.commit()
+            return int(cursor.lastrowid or 0)
+
+    def find_by_email(self, email: str) -> User | None:
+        """Look up one user by exact email."""
+        with closing(self._connect()) as conn:
+            row = conn.execute(
+                "SELECT user_id, email, display_name FROM users WHERE email = ?",
+                (email,),
+            ).fetchone()
+        if row is None:
+            return None
+        return User(user_id=row[0], email=row[1], display_name=row[2])
+
+    def rename(self, user_id: int, display_name: str) -> bool:
+        """Update a user's display name; returns whether a row changed."""
+        with closing(self._connect()) as conn:
+            cursor = conn.execute(
+                "UPDATE users SET display_name = ? WHERE user_id = ?",
+                (display_name, user_id),
+            )
+            conn.commit()
+            return cursor.rowcount > 0
@@ -0,0 +1,86 @@
+//! A small in-memory inventory with reservation accounting.
+
+use std::collections::HashMap;
+
+/// Error cases for inventory operations.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InventoryError {
+    UnknownSku,
+    InsufficientStock { available: u32, requested: u32 },
+}
+
+/// Stock levels keyed by SKU, tracking on-hand and reserved units.
+#[derive(Default)]
+pub struct Inventory {
+    on_hand: HashMap<String, u32>,
+    reserved: HashMap<String, u32>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add units of a SKU to stock, creating the SKU if needed.
+    pub fn receive(&mut self, sku: &str, quantity: u32) {
+        *self.on_hand.entry(sku.to_string()).or_insert(0) += quantity;
+    }
+
+    /// Units that can still be reserved for a SKU.
+    pub fn available(&self, sku: &str) -> u32 {
+        let on_hand = self.on_hand.get(sku).copied().unwrap_or(0);
+        let reserved = self.reserved.get(sku).copied().unwrap_or(0);
+        on_hand.saturating_sub(reserved)
+    }
+
+    /// Reserve units for an order, failing when stock is short.
+    pub fn reserve(&mut self, sku: &str, quantity: u32) -> Result<(), InventoryError> {
+        if !self.on_hand.contains_key(sku) {
+            return Err(InventoryError::UnknownSku);
+        }
+        let available = self.available(sku);
+        if quantity > available {
+            return Err(InventoryError::InsufficientStock {
+                available,
+                requested: quantity,
+            });
+        }
+        *self.reserved.entry(sku.to_string()).or_insert(0) += quantity;
+        Ok(())
+    }
+
+    /// Release a reservation, e.g. when an order is cancelled.
+    pub fn release(&mut self, sku: &str, quantity: u32) {
+        if let Some(reserved) = self.reserved.get_mut(sku) {
+            *reserved = reserved.saturating_sub(quantity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserve_respects_available_stock() {
+        let mut inventory = Inventory::new();
+        inventory.receive("widget", 5);
+        assert!(inventory.reserve("widget", 3).is_ok());
+        assert_eq!(
+            inventory.reserve("widget", 3),
+            Err(InventoryError::InsufficientStock {
+                available: 2,
+                requested: 3
+            })
+        );
+    }
+
+    #[test]
+    fn release_frees_reserved_units() {
+        let mut inventory = Inventory::new();
+        inventory.receive("widget", 2);
+        inventory.reserve("widget", 2).unwrap();
+        inventory.release("widget", 1);
+        assert_eq!(inventory.available("widget"), 1);
+    }
+}
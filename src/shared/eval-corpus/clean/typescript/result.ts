@@ -0,0 +1,54 @@
+/** A small Result type for explicit error handling without exceptions. */
+
+export type Result<T, E> =
+  | { readonly ok: true; readonly value: T }
+  | { readonly ok: false; readonly error: E };
+
+/** Wrap a success value. */
+export function ok<T, E = never>(value: T): Result<T, E> {
+  return { ok: true, value };
+}
+
+/** Wrap an error value. */
+export function err<E, T = never>(error: E): Result<T, E> {
+  return { ok: false, error };
+}
+
+/** Transform the success value, passing errors through untouched. */
+export function map<T, U, E>(result: Result<T, E>, fn: (value: T) => U): Result<U, E> {
+  return result.ok ? ok(fn(result.value)) : result;
+}
+
+/** Chain a fallible computation onto a successful result. */
+export function andThen<T, U, E>(
+  result: Result<T, E>,
+  fn: (value: T) => Result<U, E>,
+): Result<U, E> {
+  return result.ok ? fn(result.value) : result;
+}
+
+/** Extract the value or fall back to a default. */
+export function unwrapOr<T, E>(result: Result<T, E>, fallback: T): T {
+  return result.ok ? result.value : fallback;
+}
+
+/** Run a throwing function and capture the outcome as a Result. */
+export function tryCatch<T>(fn: () => T): Result<T, Error> {
+  try {
+    return ok(fn());
+  } catch (cause) {
+    return err(cause instanceof Error ? cause : new Error(String(cause)));
+  }
+}
+
+/** Combine results, failing on the first error encountered. */
+export function all<T, E>(results: ReadonlyArray<Result<T, E>>): Result<T[], E> {
+  const values: T[] = [];
+  for (const result of results) {
+    if (!result.ok) {
+      return result;
+    }
+    values.push(result.value);
+  }
+  return ok(values);
+}
@@ -0,0 +1,6 @@
+/// Caf� menu pricing (builds on unicode.rs).
+fn caf�_total(prices: &[f64]) -> f64 {
+    let caf�: f64 = prices.iter().sum();
+    println!("caf� total: {caf�}");
+    caf�
+}
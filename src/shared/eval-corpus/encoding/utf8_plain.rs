@@ -0,0 +1,6 @@
+/// Café menu pricing (builds on unicode.rs).
+fn café_total(prices: &[f64]) -> f64 {
+    let café: f64 = prices.iter().sum();
+    println!("café total: {café} €");
+    café
+}
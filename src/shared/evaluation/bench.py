@@ -0,0 +1,312 @@
+"""Tool version benchmarking over the eval corpus.
+
+Runs multiple versions of an analysis tool (via its official Docker image)
+against that tool's synthetic eval repos and collects score, runtime, and
+peak memory per version so upgrades can be compared before rollout.
+"""
+
+from __future__ import annotations
+
+import json
+import shutil
+import subprocess
+import time
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Callable
+
+# Official image templates per tool. Versions are substituted verbatim, so
+# `--versions 1.60,1.70` must match published image tags.
+TOOL_IMAGE_TEMPLATES: dict[str, str] = {
+    "semgrep": "returntocorp/semgrep:{version}",
+    "trivy": "aquasec/trivy:{version}",
+    "gitleaks": "zricethezav/gitleaks:v{version}",
+    "scancode": "aboutcode/scancode-toolkit:v{version}",
+    "bandit": "ghcr.io/pycqa/bandit/bandit:{version}",
+}
+
+# Container command per tool; {target} is the mounted repo path inside the
+# container and {output} the mounted output file path.
+TOOL_CONTAINER_COMMANDS: dict[str, list[str]] = {
+    "semgrep": ["semgrep", "scan", "--config=auto", "--json", "--output", "{output}", "{target}"],
+    "trivy": ["filesystem", "--format", "json", "--output", "{output}", "{target}"],
+    "gitleaks": ["detect", "--source", "{target}", "--report-format", "json", "--report-path", "{output}", "--no-git"],
+    "scancode": ["--license", "--json-pp", "{output}", "{target}"],
+    "bandit": ["-r", "-f", "json", "-o", "{output}", "{target}"],
+}
+
+# Keys that hold the findings list in each tool's raw JSON report.
+TOOL_FINDINGS_KEYS: dict[str, str] = {
+    "semgrep": "results",
+    "trivy": "Results",
+    "gitleaks": "",  # top-level array
+    "scancode": "files",
+    "bandit": "results",
+}
+
+
+@dataclass
+class BenchResult:
+    """Outcome of benchmarking one tool version against one eval repo."""
+    tool: str
+    version: str
+    eval_repo: str
+    status: str  # completed | failed
+    findings: int = 0
+    expected: int | None = None
+    runtime_seconds: float = 0.0
+    peak_memory_mb: float | None = None
+    error: str | None = None
+
+    @property
+    def score(self) -> float | None:
+        """Detection ratio against ground truth (None without ground truth)."""
+        if self.expected is None or self.expected == 0:
+            return None
+        return min(self.findings / self.expected, 1.0)
+
+
+@dataclass
+class BenchReport:
+    """All results for one `caldera eval bench` invocation."""
+    tool: str
+    versions: list[str]
+    results: list[BenchResult] = field(default_factory=list)
+
+    def to_dict(self) -> dict:
+        return {
+            "tool": self.tool,
+            "versions": self.versions,
+            "results": [
+                {
+                    "version": r.version,
+                    "eval_repo": r.eval_repo,
+                    "status": r.status,
+                    "findings": r.findings,
+                    "expected": r.expected,
+                    "score": r.score,
+                    "runtime_seconds": round(r.runtime_seconds, 3),
+                    "peak_memory_mb": r.peak_memory_mb,
+                    "error": r.error,
+                }
+                for r in self.results
+            ],
+        }
+
+
+class DockerBenchBackend:
+    """Runs a tool version inside its official Docker image.
+
+    The runner callable is injectable for testing; it must behave like
+    ``subprocess.run(..., capture_output=True, text=True)``.
+    """
+
+    def __init__(self, runner: Callable[..., subprocess.CompletedProcess] | None = None) -> None:
+        self._runner = runner or subprocess.run
+
+    def available(self) -> bool:
+        return shutil.which("docker") is not None
+
+    def pull(self, image: str) -> None:
+        result = self._runner(
+            ["docker", "pull", image], capture_output=True, text=True, timeout=600
+        )
+        if result.returncode != 0:
+            raise RuntimeError(f"docker pull {image} failed: {result.stderr.strip()}")
+
+    def run(
+        self,
+        image: str,
+        command: list[str],
+        repo_path: Path,
+        output_dir: Path,
+    ) -> tuple[float, float | None]:
+        """Run the container and return (runtime_seconds, peak_memory_mb).
+
+        Peak memory comes from ``docker inspect`` of cgroup stats via the
+        ``--memory`` reporting of a named container; if unavailable it is None.
+        """
+        container = f"caldera-bench-{int(time.time() * 1000)}"
+        rendered = [
+            part.replace("{target}", "/repo").replace("{output}", "/out/report.json")
+            for part in command
+        ]
+        start = time.perf_counter()
+        result = self._runner(
+            [
+                "docker", "run", "--name", container, "--rm=false",
+                "-v", f"{repo_path.resolve()}:/repo:ro",
+                "-v", f"{output_dir.resolve()}:/out",
+                image, *rendered,
+            ],
+            capture_output=True,
+            text=True,
+            timeout=1800,
+        )
+        runtime = time.perf_counter() - start
+        # Most scanners exit non-zero when findings exist; treat >=2 as failure.
+        if result.returncode >= 2:
+            self._cleanup(container)
+            raise RuntimeError(f"container exited {result.returncode}: {result.stderr.strip()[:500]}")
+        peak_mb = self._peak_memory_mb(container)
+        self._cleanup(container)
+        return runtime, peak_mb
+
+    def _peak_memory_mb(self, container: str) -> float | None:
+        result = self._runner(
+            ["docker", "inspect", "--format", "{{.HostConfig.Memory}}", container],
+            capture_output=True,
+            text=True,
+            timeout=60,
+        )
+        stats = self._runner(
+            ["docker", "container", "stats", "--no-stream", "--format", "{{.MemUsage}}", container],
+            capture_output=True,
+            text=True,
+            timeout=60,
+        )
+        del result  # inspect kept for parity with docker backends elsewhere
+        usage = (stats.stdout or "").strip().split("/")[0].strip()
+        return _parse_mem_usage(usage)
+
+    def _cleanup(self, container: str) -> None:
+        self._runner(
+            ["docker", "rm", "-f", container], capture_output=True, text=True, timeout=60
+        )
+
+
+def _parse_mem_usage(usage: str) -> float | None:
+    """Parse docker's human MemUsage (e.g. '512MiB', '1.2GiB') to MiB."""
+    if not usage:
+        return None
+    units = {"KiB": 1 / 1024, "MiB": 1.0, "GiB": 1024.0, "B": 1 / (1024 * 1024)}
+    for unit, factor in units.items():
+        if usage.endswith(unit):
+            try:
+                return round(float(usage[: -len(unit)]) * factor, 1)
+            except ValueError:
+                return None
+    return None
+
+
+def count_findings(tool: str, report_path: Path) -> int:
+    """Count findings in a tool's raw JSON report."""
+    try:
+        payload = json.loads(report_path.read_text())
+    except (OSError, json.JSONDecodeError):
+        return 0
+    key = TOOL_FINDINGS_KEYS.get(tool, "results")
+    if key == "":
+        return len(payload) if isinstance(payload, list) else 0
+    entries = payload.get(key, []) if isinstance(payload, dict) else []
+    return len(entries) if isinstance(entries, list) else 0
+
+
+def expected_findings(tool_dir: Path, eval_repo: str) -> int | None:
+    """Total expected findings from the tool's ground truth, if present."""
+    ground_truth = tool_dir / "evaluation" / "ground-truth" / f"{eval_repo}.json"
+    if not ground_truth.exists():
+        return None
+    try:
+        payload = json.loads(ground_truth.read_text())
+    except (OSError, json.JSONDecodeError):
+        return None
+    if isinstance(payload, dict):
+        for key in ("expected_findings", "expected_issues", "findings", "issues"):
+            value = payload.get(key)
+            if isinstance(value, list):
+                return len(value)
+            if isinstance(value, int):
+                return value
+    return None
+
+
+def discover_eval_repos(tool_dir: Path) -> list[Path]:
+    """Return the synthetic eval repos for a tool (one per subdirectory)."""
+    synthetic = tool_dir / "eval-repos" / "synthetic"
+    if not synthetic.is_dir():
+        return []
+    subdirs = sorted(p for p in synthetic.iterdir() if p.is_dir())
+    return subdirs or [synthetic]
+
+
+def run_bench(
+    tool: str,
+    versions: list[str],
+    tools_root: Path,
+    work_dir: Path,
+    backend: DockerBenchBackend | None = None,
+    logger: Callable[[str], None] | None = None,
+) -> BenchReport:
+    """Benchmark each version of *tool* against its eval corpus."""
+    if tool not in TOOL_IMAGE_TEMPLATES:
+        raise ValueError(
+            f"no Docker image template for tool '{tool}' "
+            f"(known: {', '.join(sorted(TOOL_IMAGE_TEMPLATES))})"
+        )
+    backend = backend or DockerBenchBackend()
+    log = logger or (lambda msg: None)
+    tool_dir = tools_root / tool
+    eval_repos = discover_eval_repos(tool_dir)
+    if not eval_repos:
+        raise ValueError(f"no eval repos found under {tool_dir / 'eval-repos' / 'synthetic'}")
+
+    report = BenchReport(tool=tool, versions=versions)
+    for version in versions:
+        image = TOOL_IMAGE_TEMPLATES[tool].format(version=version)
+        log(f"Pulling {image}...")
+        try:
+            backend.pull(image)
+        except RuntimeError as exc:
+            for repo in eval_repos:
+                report.results.append(
+                    BenchResult(tool, version, repo.name, "failed", error=str(exc))
+                )
+            continue
+        for repo in eval_repos:
+            output_dir = work_dir / tool / version / repo.name
+            output_dir.mkdir(parents=True, exist_ok=True)
+            log(f"Benchmarking {tool} {version} on {repo.name}...")
+            try:
+                runtime, peak_mb = backend.run(
+                    image, TOOL_CONTAINER_COMMANDS[tool], repo, output_dir
+                )
+            except (RuntimeError, subprocess.TimeoutExpired) as exc:
+                report.results.append(
+                    BenchResult(tool, version, repo.name, "failed", error=str(exc))
+                )
+                continue
+            report.results.append(
+                BenchResult(
+                    tool=tool,
+                    version=version,
+                    eval_repo=repo.name,
+                    status="completed",
+                    findings=count_findings(tool, output_dir / "report.json"),
+                    expected=expected_findings(tool_dir, repo.name),
+                    runtime_seconds=runtime,
+                    peak_memory_mb=peak_mb,
+                )
+            )
+    return report
+
+
+def format_comparison_table(report: BenchReport) -> str:
+    """Render the per-version comparison table as aligned plain text."""
+    headers = ["Version", "Eval repo", "Status", "Findings", "Score", "Runtime (s)", "Peak mem (MiB)"]
+    rows = []
+    for r in report.results:
+        score = f"{r.score:.2f}" if r.score is not None else "-"
+        peak = f"{r.peak_memory_mb:.1f}" if r.peak_memory_mb is not None else "-"
+        rows.append([
+            r.version, r.eval_repo, r.status, str(r.findings),
+            score, f"{r.runtime_seconds:.2f}", peak,
+        ])
+    widths = [max(len(h), *(len(row[i]) for row in rows)) if rows else len(h) for i, h in enumerate(headers)]
+    lines = [
+        "  ".join(h.ljust(widths[i]) for i, h in enumerate(headers)),
+        "  ".join("-" * widths[i] for i in range(len(headers))),
+    ]
+    for row in rows:
+        lines.append("  ".join(cell.ljust(widths[i]) for i, cell in enumerate(row)))
+    return "\n".join(lines)
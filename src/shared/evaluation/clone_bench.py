@@ -0,0 +1,252 @@
+"""External clone-benchmark import for clone-detector evaluation.
+
+Imports established clone-detection datasets (BigCloneBench-style id
+pair lists, or generic file/line pair CSVs) into the same
+``clone_ground_truth.json`` format the mutation engine writes, so the
+native detector and pmd-cpd can be scored against public ground truth
+and compared with published results — not only our synthetic fixtures.
+
+Two dataset layouts are supported:
+
+- BigCloneBench reduced layout: a pairs CSV with
+  ``function_id_1,function_id_2,clone_type`` columns plus a
+  ``functions/`` directory of ``<id>.java`` files. Each pair spans the
+  two whole function files.
+- Generic layout: a pairs CSV with
+  ``file_a,start_a,end_a,file_b,start_b,end_b,clone_type`` columns
+  referencing files that ship with the dataset.
+
+BigCloneBench labels only a curated subset of true clone pairs, so
+scores against it are recall-only per clone type — the convention used
+in the literature. Surfaced via ``insights eval-clone-bench``.
+"""
+
+from __future__ import annotations
+
+import csv
+import shutil
+from dataclasses import dataclass
+from pathlib import Path
+
+from .confusion import clone_locality
+from .mutate import CloneRecord, write_ground_truth
+
+PAIRS_CSV_NAME = "clone_pairs.csv"
+FUNCTIONS_DIR_NAME = "functions"
+
+# BigCloneBench categories mapped onto the standard 1/2/3 taxonomy.
+# WT3/T4 (weakly type-3 / type-4) pairs share little syntax and are out
+# of scope for token-based detectors, so they are skipped.
+CLONE_TYPE_MAP = {
+    "T1": 1,
+    "T2": 2,
+    "VST3": 3,
+    "ST3": 3,
+    "MT3": 3,
+    "1": 1,
+    "2": 2,
+    "3": 3,
+}
+
+
+@dataclass(frozen=True)
+class ImportStats:
+    """Outcome of one benchmark import."""
+
+    pairs_imported: int
+    pairs_skipped: int
+    functions_copied: int
+
+    def to_dict(self) -> dict:
+        return {
+            "pairs_imported": self.pairs_imported,
+            "pairs_skipped": self.pairs_skipped,
+            "functions_copied": self.functions_copied,
+        }
+
+
+def _read_pairs(pairs_csv: Path) -> list[dict]:
+    with pairs_csv.open(newline="") as handle:
+        rows = list(csv.DictReader(handle))
+    if not rows:
+        raise ValueError(f"{pairs_csv}: no clone pairs found")
+    return rows
+
+
+def _map_clone_type(raw: str) -> int | None:
+    return CLONE_TYPE_MAP.get(raw.strip().upper())
+
+
+def _file_line_count(path: Path) -> int:
+    return len(path.read_text().splitlines())
+
+
+def import_benchmark(
+    dataset_dir: Path,
+    output_dir: Path,
+    pairs_csv: Path | None = None,
+    limit: int | None = None,
+) -> tuple[list[CloneRecord], ImportStats]:
+    """Materialize a benchmark dataset as a scorable eval repo.
+
+    Copies referenced source files into ``output_dir`` and writes
+    ``clone_ground_truth.json`` beside them. Returns the imported
+    records and import statistics; pairs with unmapped clone types or
+    missing source files are skipped, not fatal.
+    """
+    pairs_csv = pairs_csv or dataset_dir / PAIRS_CSV_NAME
+    if not pairs_csv.exists():
+        raise ValueError(f"Pairs CSV not found: {pairs_csv}")
+    rows = _read_pairs(pairs_csv)
+
+    id_layout = "function_id_1" in rows[0]
+    if not id_layout and "file_a" not in rows[0]:
+        raise ValueError(
+            f"{pairs_csv}: expected function_id_1/function_id_2 or file_a/file_b columns"
+        )
+
+    output_dir.mkdir(parents=True, exist_ok=True)
+    records: list[CloneRecord] = []
+    copied: set[str] = set()
+    skipped = 0
+    for index, row in enumerate(rows):
+        if limit is not None and len(records) >= limit:
+            break
+        clone_type = _map_clone_type(row.get("clone_type", ""))
+        if clone_type is None:
+            skipped += 1
+            continue
+        if id_layout:
+            pair = _import_id_pair(row, dataset_dir, output_dir, copied)
+        else:
+            pair = _import_file_pair(row, dataset_dir, output_dir, copied)
+        if pair is None:
+            skipped += 1
+            continue
+        source_file, source_lines, clone_file, clone_lines = pair
+        records.append(CloneRecord(
+            clone_id=f"bench-{index:05d}",
+            clone_type=clone_type,
+            source_file=source_file,
+            source_lines=source_lines,
+            clone_file=clone_file,
+            clone_lines=clone_lines,
+            function_name=Path(source_file).stem,
+        ))
+
+    write_ground_truth(output_dir, records)
+    return records, ImportStats(
+        pairs_imported=len(records),
+        pairs_skipped=skipped,
+        functions_copied=len(copied),
+    )
+
+
+def _copy_source(relative: str, dataset_dir: Path, output_dir: Path, copied: set[str]) -> bool:
+    source = dataset_dir / relative
+    if not source.exists():
+        return False
+    if relative not in copied:
+        destination = output_dir / relative
+        destination.parent.mkdir(parents=True, exist_ok=True)
+        shutil.copy2(source, destination)
+        copied.add(relative)
+    return True
+
+
+def _import_id_pair(
+    row: dict,
+    dataset_dir: Path,
+    output_dir: Path,
+    copied: set[str],
+) -> tuple[str, tuple[int, int], str, tuple[int, int]] | None:
+    files = []
+    for key in ("function_id_1", "function_id_2"):
+        relative = f"{FUNCTIONS_DIR_NAME}/{row[key].strip()}.java"
+        if not _copy_source(relative, dataset_dir, output_dir, copied):
+            return None
+        files.append((relative, (1, _file_line_count(output_dir / relative))))
+    (source_file, source_lines), (clone_file, clone_lines) = files
+    return source_file, source_lines, clone_file, clone_lines
+
+
+def _import_file_pair(
+    row: dict,
+    dataset_dir: Path,
+    output_dir: Path,
+    copied: set[str],
+) -> tuple[str, tuple[int, int], str, tuple[int, int]] | None:
+    for key in ("file_a", "file_b"):
+        if not _copy_source(row[key].strip(), dataset_dir, output_dir, copied):
+            return None
+    return (
+        row["file_a"].strip(),
+        (int(row["start_a"]), int(row["end_a"])),
+        row["file_b"].strip(),
+        (int(row["start_b"]), int(row["end_b"])),
+    )
+
+
+def _overlaps(lines: tuple[int, int], start: int, end: int) -> bool:
+    return start <= lines[1] and end >= lines[0]
+
+
+def _pair_detected(record: CloneRecord, detected: list[dict]) -> bool:
+    for pair in detected:
+        forward = (
+            pair["file_a"] == record.source_file
+            and pair["file_b"] == record.clone_file
+            and _overlaps(record.source_lines, pair.get("start_a", 1), pair.get("end_a", 10**9))
+            and _overlaps(record.clone_lines, pair.get("start_b", 1), pair.get("end_b", 10**9))
+        )
+        backward = (
+            pair["file_a"] == record.clone_file
+            and pair["file_b"] == record.source_file
+            and _overlaps(record.clone_lines, pair.get("start_a", 1), pair.get("end_a", 10**9))
+            and _overlaps(record.source_lines, pair.get("start_b", 1), pair.get("end_b", 10**9))
+        )
+        if forward or backward:
+            return True
+    return False
+
+
+def recall_by_type(records: list[CloneRecord], detected: list[dict]) -> dict:
+    """Score detector output against benchmark ground truth.
+
+    ``detected`` pairs carry ``file_a``/``file_b`` (repo-relative) and
+    optional ``start_a``/``end_a``/``start_b``/``end_b`` line ranges;
+    a benchmark pair counts as found when a detected pair covers both
+    sides in either order. Recall only — the benchmark does not label
+    all non-clones, so precision is not measurable against it. Broken
+    down per clone type and per locality (cross-file vs intra-file).
+    """
+    report: dict = {"by_type": {}, "by_locality": {}, "overall": {}}
+    total_found = 0
+    for clone_type in sorted({record.clone_type for record in records}):
+        of_type = [record for record in records if record.clone_type == clone_type]
+        found = sum(1 for record in of_type if _pair_detected(record, detected))
+        total_found += found
+        report["by_type"][f"type_{clone_type}"] = {
+            "expected": len(of_type),
+            "found": found,
+            "recall": round(found / len(of_type), 4) if of_type else 0.0,
+        }
+    for locality in sorted({
+        clone_locality(record.source_file, record.clone_file) for record in records
+    }):
+        of_locality = [
+            record for record in records
+            if clone_locality(record.source_file, record.clone_file) == locality
+        ]
+        found = sum(1 for record in of_locality if _pair_detected(record, detected))
+        report["by_locality"][locality] = {
+            "expected": len(of_locality),
+            "found": found,
+            "recall": round(found / len(of_locality), 4) if of_locality else 0.0,
+        }
+    report["overall"] = {
+        "expected": len(records),
+        "found": total_found,
+        "recall": round(total_found / len(records), 4) if records else 0.0,
+    }
+    return report
@@ -0,0 +1,108 @@
+"""Confusion matrices and per-category eval breakdowns.
+
+Aggregate F1 hides where a tool fails; these helpers slice eval
+results into categories (tests vs src paths, cross-file vs intra-file
+clones) and emit a full confusion matrix per slice, so weaknesses like
+"secrets detection misses everything under tests/" are visible in the
+report instead of averaged away.
+
+Used by the semgrep rule scorer and the clone benchmark scorer; the
+categorizers are generic enough for any path- or pair-based tool.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from pathlib import PurePosixPath
+
+TEST_PATH_SEGMENTS = {"test", "tests", "spec", "specs", "__tests__"}
+
+
+@dataclass
+class ConfusionMatrix:
+    """Running TP/FP/FN/TN counts with derived rates."""
+
+    true_positives: int = 0
+    false_positives: int = 0
+    false_negatives: int = 0
+    true_negatives: int = 0
+
+    @property
+    def precision(self) -> float:
+        flagged = self.true_positives + self.false_positives
+        return self.true_positives / flagged if flagged else 0.0
+
+    @property
+    def recall(self) -> float:
+        expected = self.true_positives + self.false_negatives
+        return self.true_positives / expected if expected else 0.0
+
+    @property
+    def f1(self) -> float:
+        denominator = self.precision + self.recall
+        if denominator == 0:
+            return 0.0
+        return 2 * self.precision * self.recall / denominator
+
+    @property
+    def accuracy(self) -> float:
+        total = (
+            self.true_positives + self.false_positives
+            + self.false_negatives + self.true_negatives
+        )
+        return (self.true_positives + self.true_negatives) / total if total else 0.0
+
+    def __add__(self, other: ConfusionMatrix) -> ConfusionMatrix:
+        return ConfusionMatrix(
+            true_positives=self.true_positives + other.true_positives,
+            false_positives=self.false_positives + other.false_positives,
+            false_negatives=self.false_negatives + other.false_negatives,
+            true_negatives=self.true_negatives + other.true_negatives,
+        )
+
+    def to_dict(self) -> dict:
+        return {
+            "true_positives": self.true_positives,
+            "false_positives": self.false_positives,
+            "false_negatives": self.false_negatives,
+            "true_negatives": self.true_negatives,
+            "precision": round(self.precision, 4),
+            "recall": round(self.recall, 4),
+            "f1": round(self.f1, 4),
+            "accuracy": round(self.accuracy, 4),
+        }
+
+
+def path_category(path: str) -> str:
+    """Categorize a repo-relative path as ``tests`` or ``src``.
+
+    A path counts as tests when any directory segment is a test
+    directory or the filename follows test naming conventions.
+    """
+    parts = PurePosixPath(path.replace("\\", "/")).parts
+    if any(part.lower() in TEST_PATH_SEGMENTS for part in parts[:-1]):
+        return "tests"
+    name = parts[-1].lower() if parts else ""
+    stem = name.rsplit(".", 1)[0]
+    if stem.startswith("test_") or stem.endswith(("_test", ".test", ".spec")):
+        return "tests"
+    return "src"
+
+
+def clone_locality(source_file: str, clone_file: str) -> str:
+    """Categorize a clone pair as ``intra_file`` or ``cross_file``."""
+    return "intra_file" if source_file == clone_file else "cross_file"
+
+
+def breakdown(matrices: dict[str, ConfusionMatrix]) -> dict:
+    """Render per-category matrices plus their overall sum."""
+    overall = ConfusionMatrix()
+    for matrix in matrices.values():
+        overall = overall + matrix
+    return {
+        "by_category": {
+            category: matrices[category].to_dict()
+            for category in sorted(matrices)
+        },
+        "overall": overall.to_dict(),
+    }
@@ -0,0 +1,230 @@
+"""Determinism checking for analysis tools.
+
+Runs each tool repeatedly on the same corpus and diffs canonicalized
+outputs to quantify flakiness (CPD clone ordering, semgrep timeouts...).
+Canonicalization strips volatile envelope fields (run ids, timestamps,
+durations) and sorts findings, so only real nondeterminism is flagged —
+a tool that merely emits results in a different order is reported as
+order-unstable rather than nondeterministic.
+
+Surfaced via ``insights eval-determinism``; the runner is injectable so
+tests (and callers with pre-collected outputs) can bypass subprocess
+execution, mirroring the bench runner design.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Callable
+
+from shared.evaluation.noise import extract_findings
+
+# Envelope fields expected to differ between runs.
+VOLATILE_KEYS = {
+    "run_id",
+    "timestamp",
+    "generated_at",
+    "created_at",
+    "duration_ms",
+    "duration_seconds",
+    "analysis_duration_ms",
+    "elapsed_seconds",
+}
+
+RUN_TIMEOUT_SECONDS = 1800
+MAX_DIVERGENT_PATHS = 10
+
+
+@dataclass
+class DeterminismResult:
+    """Flakiness verdict for one tool across repeated runs."""
+
+    tool: str
+    runs: int
+    unique_outputs: int
+    order_unstable: bool
+    divergent_paths: list[str] = field(default_factory=list)
+    flaky_rules: dict[str, list[int]] = field(default_factory=dict)
+    error: str | None = None
+
+    @property
+    def deterministic(self) -> bool:
+        return self.unique_outputs == 1 and self.error is None
+
+    def to_dict(self) -> dict:
+        return {
+            "tool": self.tool,
+            "runs": self.runs,
+            "unique_outputs": self.unique_outputs,
+            "deterministic": self.deterministic,
+            "order_unstable": self.order_unstable,
+            "divergent_paths": self.divergent_paths,
+            "flaky_rules": self.flaky_rules,
+            "error": self.error,
+        }
+
+
+def canonicalize(payload: object) -> object:
+    """Strip volatile fields and sort lists for order-independent diffing."""
+    if isinstance(payload, dict):
+        return {
+            key: canonicalize(value)
+            for key, value in sorted(payload.items())
+            if key not in VOLATILE_KEYS
+        }
+    if isinstance(payload, list):
+        return sorted(
+            (canonicalize(item) for item in payload),
+            key=lambda item: json.dumps(item, sort_keys=True, default=str),
+        )
+    return payload
+
+
+def canonical_dump(payload: object, preserve_order: bool = False) -> str:
+    """Serialize a payload for comparison.
+
+    With ``preserve_order`` lists keep their order, which distinguishes
+    order instability from genuine output differences.
+    """
+    if preserve_order:
+        def _strip(value: object) -> object:
+            if isinstance(value, dict):
+                return {
+                    key: _strip(item)
+                    for key, item in sorted(value.items())
+                    if key not in VOLATILE_KEYS
+                }
+            if isinstance(value, list):
+                return [_strip(item) for item in value]
+            return value
+
+        return json.dumps(_strip(payload), sort_keys=True, default=str)
+    return json.dumps(canonicalize(payload), sort_keys=True, default=str)
+
+
+def divergent_paths(first: object, second: object, prefix: str = "") -> list[str]:
+    """Paths into the structure where two canonicalized payloads differ."""
+    if isinstance(first, dict) and isinstance(second, dict):
+        paths = []
+        for key in sorted(set(first) | set(second)):
+            if key not in first or key not in second:
+                paths.append(f"{prefix}/{key}")
+            else:
+                paths.extend(divergent_paths(first[key], second[key], f"{prefix}/{key}"))
+            if len(paths) >= MAX_DIVERGENT_PATHS:
+                return paths[:MAX_DIVERGENT_PATHS]
+        return paths
+    if isinstance(first, list) and isinstance(second, list):
+        if len(first) != len(second):
+            return [f"{prefix}[len {len(first)} != {len(second)}]"]
+        paths = []
+        for index, (a, b) in enumerate(zip(first, second)):
+            paths.extend(divergent_paths(a, b, f"{prefix}[{index}]"))
+            if len(paths) >= MAX_DIVERGENT_PATHS:
+                return paths[:MAX_DIVERGENT_PATHS]
+        return paths
+    if first != second:
+        return [prefix or "/"]
+    return []
+
+
+def check_tool(tool: str, payloads: list[dict]) -> DeterminismResult:
+    """Diff repeated outputs of one tool."""
+    canonical = [canonical_dump(payload) for payload in payloads]
+    ordered = [canonical_dump(payload, preserve_order=True) for payload in payloads]
+    unique = len(set(canonical))
+
+    result = DeterminismResult(
+        tool=tool,
+        runs=len(payloads),
+        unique_outputs=unique,
+        order_unstable=unique == 1 and len(set(ordered)) > 1,
+    )
+    if unique > 1:
+        baseline = canonicalize(payloads[0])
+        for payload in payloads[1:]:
+            candidate = canonicalize(payload)
+            if candidate != baseline:
+                result.divergent_paths = divergent_paths(baseline, candidate)
+                break
+
+        # Per-rule finding counts across runs; varying counts name the
+        # flaky rules directly.
+        counts_per_run: list[dict[str, int]] = []
+        for payload in payloads:
+            counts: dict[str, int] = {}
+            for _, rule in extract_findings(payload):
+                counts[rule] = counts.get(rule, 0) + 1
+            counts_per_run.append(counts)
+        all_rules = {rule for counts in counts_per_run for rule in counts}
+        for rule in sorted(all_rules):
+            series = [counts.get(rule, 0) for counts in counts_per_run]
+            if len(set(series)) > 1:
+                result.flaky_rules[rule] = series
+    return result
+
+
+def make_tool_runner(
+    tools_dir: Path,
+    logger: Callable[[str], None] | None = None,
+) -> Callable[[str], dict]:
+    """Build a runner that executes ``make analyze`` for a tool and loads
+    its newest output envelope."""
+
+    def _run(tool: str) -> dict:
+        tool_dir = tools_dir / tool
+        if not (tool_dir / "Makefile").exists():
+            raise FileNotFoundError(f"no Makefile for tool {tool} in {tools_dir}")
+        if logger:
+            logger(f"Running {tool} analyze...")
+        subprocess.run(
+            ["make", "-C", str(tool_dir), "analyze"],
+            check=True,
+            capture_output=True,
+            text=True,
+            timeout=RUN_TIMEOUT_SECONDS,
+        )
+        outputs = sorted(
+            tool_dir.glob("outputs/*/output.json"),
+            key=lambda path: path.stat().st_mtime,
+        )
+        if not outputs:
+            raise FileNotFoundError(f"{tool} produced no outputs/*/output.json")
+        return json.loads(outputs[-1].read_text())
+
+    return _run
+
+
+def run_determinism(
+    tools: list[str],
+    runs: int,
+    runner: Callable[[str], dict],
+) -> list[DeterminismResult]:
+    """Execute each tool ``runs`` times and diff the outputs.
+
+    Runner failures are captured per tool so one broken tool does not
+    abort the whole sweep.
+    """
+    if runs < 2:
+        raise ValueError("runs must be >= 2")
+    results = []
+    for tool in tools:
+        payloads = []
+        error = None
+        for _ in range(runs):
+            try:
+                payloads.append(runner(tool))
+            except Exception as exc:
+                error = str(exc)
+                break
+        if error is not None:
+            results.append(DeterminismResult(
+                tool=tool, runs=len(payloads), unique_outputs=0,
+                order_unstable=False, error=error,
+            ))
+        else:
+            results.append(check_tool(tool, payloads))
+    return results
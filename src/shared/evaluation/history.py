@@ -0,0 +1,163 @@
+"""Eval result history with trend tracking.
+
+Persists eval runs (tool, version, corpus revision, score, decision,
+timings) so score changes over time are visible immediately when a tool
+upgrade or adapter change regresses quality. Stored in SQLite rather
+than the landing zone: eval history must work inside tool venvs that do
+not ship duckdb, and it outlives any single analysis database.
+
+Surfaced via ``insights eval-record`` (ingest a scorecard.json) and
+``insights eval-trend`` (chart score history per tool).
+"""
+
+from __future__ import annotations
+
+import json
+import sqlite3
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+
+DEFAULT_DB_NAME = "eval-history.sqlite"
+SPARK_BLOCKS = "▁▂▃▄▅▆▇█"
+
+_SCHEMA = """
+CREATE TABLE IF NOT EXISTS eval_runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tool TEXT NOT NULL,
+    tool_version TEXT NOT NULL,
+    corpus_revision TEXT NOT NULL,
+    score REAL NOT NULL,
+    decision TEXT NOT NULL,
+    wall_seconds REAL,
+    recorded_at TEXT NOT NULL
+)
+"""
+
+
+@dataclass(frozen=True)
+class EvalRun:
+    """One persisted eval result."""
+
+    tool: str
+    tool_version: str
+    corpus_revision: str
+    score: float
+    decision: str
+    wall_seconds: float | None
+    recorded_at: str
+
+    def to_dict(self) -> dict:
+        return {
+            "tool": self.tool,
+            "tool_version": self.tool_version,
+            "corpus_revision": self.corpus_revision,
+            "score": self.score,
+            "decision": self.decision,
+            "wall_seconds": self.wall_seconds,
+            "recorded_at": self.recorded_at,
+        }
+
+
+class EvalHistory:
+    """SQLite-backed store of eval runs per tool."""
+
+    def __init__(self, db_path: Path) -> None:
+        self._db_path = db_path
+        with self._connect() as conn:
+            conn.execute(_SCHEMA)
+
+    def _connect(self) -> sqlite3.Connection:
+        return sqlite3.connect(self._db_path)
+
+    def record(self, run: EvalRun) -> None:
+        """Persist one eval run."""
+        with self._connect() as conn:
+            conn.execute(
+                """INSERT INTO eval_runs
+                   (tool, tool_version, corpus_revision, score, decision,
+                    wall_seconds, recorded_at)
+                   VALUES (?, ?, ?, ?, ?, ?, ?)""",
+                (
+                    run.tool,
+                    run.tool_version,
+                    run.corpus_revision,
+                    run.score,
+                    run.decision,
+                    run.wall_seconds,
+                    run.recorded_at,
+                ),
+            )
+
+    def record_scorecard(
+        self,
+        scorecard_path: Path,
+        tool_version: str = "unknown",
+        corpus_revision: str = "unknown",
+        wall_seconds: float | None = None,
+    ) -> EvalRun:
+        """Ingest a tool's scorecard.json as one eval run."""
+        scorecard = json.loads(scorecard_path.read_text())
+        summary = scorecard.get("summary", {})
+        score = summary.get("score", scorecard.get("score"))
+        if score is None:
+            raise ValueError(f"{scorecard_path}: no score found in scorecard")
+        run = EvalRun(
+            tool=scorecard.get("tool", scorecard_path.parent.name),
+            tool_version=tool_version,
+            corpus_revision=corpus_revision,
+            score=float(score),
+            decision=str(summary.get("decision", scorecard.get("decision", "UNKNOWN"))),
+            wall_seconds=wall_seconds,
+            recorded_at=datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        )
+        self.record(run)
+        return run
+
+    def tools(self) -> list[str]:
+        """Distinct tools with recorded runs."""
+        with self._connect() as conn:
+            rows = conn.execute("SELECT DISTINCT tool FROM eval_runs ORDER BY tool").fetchall()
+        return [row[0] for row in rows]
+
+    def trend(self, tool: str, limit: int = 20) -> list[EvalRun]:
+        """Most recent runs for a tool, oldest first."""
+        with self._connect() as conn:
+            rows = conn.execute(
+                """SELECT tool, tool_version, corpus_revision, score, decision,
+                          wall_seconds, recorded_at
+                   FROM eval_runs WHERE tool = ?
+                   ORDER BY id DESC LIMIT ?""",
+                (tool, limit),
+            ).fetchall()
+        return [EvalRun(*row) for row in reversed(rows)]
+
+    def regressions(self, threshold: float = 0.05) -> list[dict]:
+        """Tools whose latest score dropped by more than threshold."""
+        found = []
+        for tool in self.tools():
+            runs = self.trend(tool, limit=2)
+            if len(runs) < 2:
+                continue
+            previous, latest = runs[-2], runs[-1]
+            drop = previous.score - latest.score
+            if drop > threshold:
+                found.append({
+                    "tool": tool,
+                    "previous_score": previous.score,
+                    "latest_score": latest.score,
+                    "drop": round(drop, 4),
+                    "previous_version": previous.tool_version,
+                    "latest_version": latest.tool_version,
+                })
+        return found
+
+
+def sparkline(scores: list[float]) -> str:
+    """Render scores (0..1) as a unicode sparkline."""
+    if not scores:
+        return ""
+    return "".join(
+        SPARK_BLOCKS[min(int(score * len(SPARK_BLOCKS)), len(SPARK_BLOCKS) - 1)]
+        for score in scores
+    )
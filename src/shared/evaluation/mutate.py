@@ -0,0 +1,246 @@
+"""Clone seeding for evaluating clone detectors on real repositories.
+
+Copies functions from a target repo and re-injects mutated variants,
+writing ground truth alongside, so pmd-cpd / jscpd precision and recall
+can be measured on realistic codebases instead of only synthetic files.
+
+Clone types follow the standard taxonomy:
+
+- Type 1: exact copy (whitespace/comments preserved)
+- Type 2: identifiers renamed and literals changed
+- Type 3: near-miss — statements reordered and one removed
+
+Only Python functions are mutated (the engine needs a real parser to
+rename identifiers safely); other languages can still be covered by
+pointing the tool at repos with seeded Python clones. Injection is
+deterministic for a given seed.
+"""
+
+from __future__ import annotations
+
+import ast
+import json
+import random
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+
+MIN_FUNCTION_LINES = 5
+CLONE_FILE_MARKER = "_clone"
+GROUND_TRUTH_NAME = "clone_ground_truth.json"
+CLONE_TYPES = (1, 2, 3)
+
+
+@dataclass(frozen=True)
+class SourceFunction:
+    """A function extracted from the target repo."""
+
+    file: str  # repo-relative path
+    name: str
+    line_start: int
+    line_end: int
+    source: str
+
+
+@dataclass(frozen=True)
+class CloneRecord:
+    """Ground truth for one seeded clone."""
+
+    clone_id: str
+    clone_type: int
+    source_file: str
+    source_lines: tuple[int, int]
+    clone_file: str
+    clone_lines: tuple[int, int]
+    function_name: str
+
+    def to_dict(self) -> dict:
+        return {
+            "clone_id": self.clone_id,
+            "clone_type": self.clone_type,
+            "source_file": self.source_file,
+            "source_lines": list(self.source_lines),
+            "clone_file": self.clone_file,
+            "clone_lines": list(self.clone_lines),
+            "function_name": self.function_name,
+        }
+
+
+class _Renamer(ast.NodeTransformer):
+    """Renames local identifiers for Type 2 mutation."""
+
+    def __init__(self, mapping: dict[str, str]) -> None:
+        self._mapping = mapping
+
+    def visit_Name(self, node: ast.Name) -> ast.Name:
+        if node.id in self._mapping:
+            node.id = self._mapping[node.id]
+        return node
+
+    def visit_arg(self, node: ast.arg) -> ast.arg:
+        if node.arg in self._mapping:
+            node.arg = self._mapping[node.arg]
+        return node
+
+    def visit_Constant(self, node: ast.Constant) -> ast.Constant:
+        if isinstance(node.value, bool):
+            return node
+        if isinstance(node.value, int):
+            node.value = node.value + 1
+        elif isinstance(node.value, float):
+            node.value = node.value + 1.0
+        elif isinstance(node.value, str) and node.value:
+            node.value = node.value + "_x"
+        return node
+
+
+def collect_functions(repo_path: Path) -> list[SourceFunction]:
+    """Extract every Python function of at least MIN_FUNCTION_LINES lines."""
+    functions = []
+    for source_file in sorted(repo_path.rglob("*.py")):
+        relative_path = source_file.relative_to(repo_path).as_posix()
+        if CLONE_FILE_MARKER in source_file.stem or relative_path.startswith(".git/"):
+            continue
+        try:
+            text = source_file.read_text(encoding="utf-8")
+            tree = ast.parse(text)
+        except (UnicodeDecodeError, OSError, SyntaxError):
+            continue
+        for node in ast.walk(tree):
+            if not isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)):
+                continue
+            line_end = node.end_lineno or node.lineno
+            if line_end - node.lineno + 1 < MIN_FUNCTION_LINES:
+                continue
+            segment = ast.get_source_segment(text, node)
+            if segment:
+                functions.append(SourceFunction(
+                    file=relative_path,
+                    name=node.name,
+                    line_start=node.lineno,
+                    line_end=line_end,
+                    source=segment,
+                ))
+    return functions
+
+
+def _parse_function(source: str) -> ast.FunctionDef | ast.AsyncFunctionDef:
+    tree = ast.parse(source)
+    node = tree.body[0]
+    if not isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)):
+        raise ValueError("source is not a single function")
+    return node
+
+
+def _local_names(node: ast.FunctionDef | ast.AsyncFunctionDef) -> set[str]:
+    """Names safe to rename: the function itself, its args, assigned locals."""
+    names = {node.name}
+    for arg in ast.walk(node):
+        if isinstance(arg, ast.arg):
+            names.add(arg.arg)
+        elif isinstance(arg, ast.Name) and isinstance(arg.ctx, ast.Store):
+            names.add(arg.id)
+    return names
+
+
+def mutate_type1(source: str) -> str:
+    """Exact copy."""
+    return source
+
+
+def mutate_type2(source: str) -> str:
+    """Rename identifiers and perturb literals."""
+    node = _parse_function(source)
+    mapping = {name: f"{name}_m" for name in _local_names(node)}
+    node.name = mapping[node.name]
+    mutated = _Renamer(mapping).visit(node)
+    ast.fix_missing_locations(mutated)
+    return ast.unparse(mutated)
+
+
+def mutate_type3(source: str, rng: random.Random) -> str:
+    """Near-miss: swap two adjacent statements, drop one when possible."""
+    node = _parse_function(source)
+    node.name = f"{node.name}_m"
+    body = list(node.body)
+    if len(body) >= 3:
+        index = rng.randrange(len(body) - 2)
+        body[index], body[index + 1] = body[index + 1], body[index]
+    if len(body) >= 4:
+        del body[rng.randrange(1, len(body) - 1)]
+    node.body = body
+    ast.fix_missing_locations(node)
+    return ast.unparse(node)
+
+
+def inject_clones(
+    repo_path: Path,
+    count: int = 50,
+    clone_types: tuple[int, ...] = CLONE_TYPES,
+    seed: int = 0,
+) -> list[CloneRecord]:
+    """Seed mutated function clones into the repo and return ground truth.
+
+    Each clone is written to ``<stem>_clone<N>.py`` next to its source
+    file with a header naming its origin. Raises ValueError when the
+    repo has no functions large enough to clone.
+    """
+    if count < 1:
+        raise ValueError("count must be >= 1")
+    invalid = set(clone_types) - set(CLONE_TYPES)
+    if invalid or not clone_types:
+        raise ValueError(f"clone types must be a subset of {CLONE_TYPES}, got {clone_types}")
+
+    functions = collect_functions(repo_path)
+    if not functions:
+        raise ValueError(f"no functions of >= {MIN_FUNCTION_LINES} lines found in {repo_path}")
+
+    rng = random.Random(seed)
+    records = []
+    for index in range(count):
+        function = rng.choice(functions)
+        clone_type = clone_types[index % len(clone_types)]
+        if clone_type == 1:
+            mutated = mutate_type1(function.source)
+        elif clone_type == 2:
+            mutated = mutate_type2(function.source)
+        else:
+            mutated = mutate_type3(function.source, rng)
+
+        source_path = repo_path / function.file
+        clone_path = source_path.with_name(f"{source_path.stem}{CLONE_FILE_MARKER}{index}.py")
+        header = (
+            f"# Seeded type-{clone_type} clone of {function.file}:{function.name} "
+            f"(eval-mutate, seed={seed})\n"
+        )
+        clone_path.write_text(header + mutated + "\n")
+
+        header_lines = header.count("\n")
+        records.append(CloneRecord(
+            clone_id=f"clone-{index:03d}",
+            clone_type=clone_type,
+            source_file=function.file,
+            source_lines=(function.line_start, function.line_end),
+            clone_file=clone_path.relative_to(repo_path).as_posix(),
+            clone_lines=(header_lines + 1, header_lines + len(mutated.splitlines())),
+            function_name=function.name,
+        ))
+    return records
+
+
+def write_ground_truth(
+    repo_path: Path,
+    records: list[CloneRecord],
+    seed: int = 0,
+    output_path: Path | None = None,
+) -> Path:
+    """Write seeded-clone ground truth JSON and return its path."""
+    output_path = output_path or repo_path / GROUND_TRUTH_NAME
+    output_path.write_text(json.dumps({
+        "schema_version": "1.0",
+        "generated_at": datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        "seed": seed,
+        "total_clones": len(records),
+        "clones": [record.to_dict() for record in records],
+    }, indent=2))
+    return output_path
@@ -0,0 +1,132 @@
+"""False-positive noise scoring over the clean eval corpus.
+
+The clean corpus (``src/shared/eval-corpus/clean``) contains idiomatic
+multi-language code with zero expected findings, so every finding a tool
+reports against it is a false positive. This module turns a directory of
+tool envelopes produced against that corpus into noise scores — findings
+per KLOC — per adapter and per rule, surfaced via ``insights eval-score``.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from pathlib import Path
+
+CLEAN_CORPUS_DIR = Path(__file__).resolve().parents[1] / "eval-corpus" / "clean"
+
+# Keys that identify a finding-like dict in a tool envelope.
+PATH_KEYS = ("path", "file_path", "relative_path", "file")
+RULE_KEYS = ("rule_id", "check_id", "dd_smell_id", "smell_id", "rule")
+LINE_KEYS = ("line_start", "line", "start_line")
+
+
+@dataclass
+class NoiseScore:
+    """False-positive tally for one tool against the clean corpus."""
+
+    tool: str
+    findings: int = 0
+    corpus_lines: int = 0
+    per_rule: dict[str, int] = field(default_factory=dict)
+
+    @property
+    def noise_per_kloc(self) -> float:
+        if self.corpus_lines == 0:
+            return 0.0
+        return self.findings / (self.corpus_lines / 1000.0)
+
+    def to_dict(self) -> dict:
+        return {
+            "tool": self.tool,
+            "findings": self.findings,
+            "corpus_lines": self.corpus_lines,
+            "noise_per_kloc": round(self.noise_per_kloc, 2),
+            "per_rule": dict(sorted(self.per_rule.items(), key=lambda item: -item[1])),
+        }
+
+
+def count_corpus_lines(corpus_dir: Path = CLEAN_CORPUS_DIR) -> int:
+    """Total non-blank source lines across the corpus (README excluded)."""
+    total = 0
+    for source_file in sorted(corpus_dir.rglob("*")):
+        if not source_file.is_file() or source_file.suffix == ".md":
+            continue
+        try:
+            text = source_file.read_text(encoding="utf-8")
+        except (UnicodeDecodeError, OSError):
+            continue
+        total += sum(1 for line in text.splitlines() if line.strip())
+    return total
+
+
+def extract_findings(payload: object, current_path: str = "") -> list[tuple[str, str]]:
+    """Walk a tool envelope and collect (path, rule) pairs generically.
+
+    Tool data sections differ (``files[].smells[]``, ``findings[]``,
+    ``violations[]``...), so this walks the whole structure: a dict with
+    a path-like key updates the file context, and a dict with a
+    rule-like key plus a line-like key (or its own path) counts as one
+    finding attributed to the nearest path.
+    """
+    findings: list[tuple[str, str]] = []
+    if isinstance(payload, dict):
+        for key in PATH_KEYS:
+            value = payload.get(key)
+            if isinstance(value, str) and value:
+                current_path = value
+                break
+        rule = next(
+            (payload[key] for key in RULE_KEYS if isinstance(payload.get(key), str)),
+            None,
+        )
+        has_location = current_path and any(
+            isinstance(payload.get(key), int) for key in LINE_KEYS
+        )
+        if rule is not None and has_location:
+            findings.append((current_path, rule))
+        for value in payload.values():
+            if isinstance(value, (dict, list)):
+                findings.extend(extract_findings(value, current_path))
+    elif isinstance(payload, list):
+        for item in payload:
+            findings.extend(extract_findings(item, current_path))
+    return findings
+
+
+def score_outputs(
+    outputs_dir: Path,
+    corpus_dir: Path = CLEAN_CORPUS_DIR,
+) -> list[NoiseScore]:
+    """Score every ``<tool>.json`` envelope in outputs_dir.
+
+    Each file is expected to be the tool's output from a run against the
+    clean corpus; all extracted findings count as false positives.
+    """
+    corpus_lines = count_corpus_lines(corpus_dir)
+    scores = []
+    for output_file in sorted(outputs_dir.glob("*.json")):
+        payload = json.loads(output_file.read_text())
+        score = NoiseScore(tool=output_file.stem, corpus_lines=corpus_lines)
+        for _, rule in extract_findings(payload):
+            score.findings += 1
+            score.per_rule[rule] = score.per_rule.get(rule, 0) + 1
+        scores.append(score)
+    return sorted(scores, key=lambda score: -score.noise_per_kloc)
+
+
+def noise_report(scores: list[NoiseScore]) -> dict:
+    """Build a JSON-serializable noise report."""
+    return {
+        "corpus": str(CLEAN_CORPUS_DIR),
+        "tools_scored": len(scores),
+        "noisiest_rules": sorted(
+            (
+                {"tool": score.tool, "rule": rule, "findings": count}
+                for score in scores
+                for rule, count in score.per_rule.items()
+            ),
+            key=lambda entry: -entry["findings"],
+        )[:20],
+        "scores": [score.to_dict() for score in scores],
+    }
@@ -0,0 +1,137 @@
+"""Resource profiling for tool eval runs.
+
+Captures wall time, CPU time (user + system), and peak RSS per tool per
+eval repo via ``os.wait4`` rusage, so eval reports can weigh accuracy
+against cost on large corpora. Surfaced via ``insights eval-profile``;
+``merge_into_report`` attaches profiles to an existing evaluation
+report dict.
+"""
+
+from __future__ import annotations
+
+import os
+import shlex
+import subprocess
+import sys
+import threading
+import time
+from dataclasses import dataclass
+from pathlib import Path
+
+DEFAULT_TIMEOUT_SECONDS = 1800
+
+
+@dataclass(frozen=True)
+class ResourceProfile:
+    """Measured cost of one tool run against one eval repo."""
+
+    tool: str
+    eval_repo: str
+    wall_seconds: float
+    cpu_user_seconds: float
+    cpu_system_seconds: float
+    peak_rss_mb: float
+    exit_code: int
+
+    @property
+    def cpu_seconds(self) -> float:
+        return self.cpu_user_seconds + self.cpu_system_seconds
+
+    def to_dict(self) -> dict:
+        return {
+            "tool": self.tool,
+            "eval_repo": self.eval_repo,
+            "wall_seconds": round(self.wall_seconds, 3),
+            "cpu_seconds": round(self.cpu_seconds, 3),
+            "cpu_user_seconds": round(self.cpu_user_seconds, 3),
+            "cpu_system_seconds": round(self.cpu_system_seconds, 3),
+            "peak_rss_mb": round(self.peak_rss_mb, 1),
+            "exit_code": self.exit_code,
+        }
+
+
+def _rss_to_mb(ru_maxrss: int) -> float:
+    # ru_maxrss is KB on Linux, bytes on macOS.
+    if sys.platform == "darwin":
+        return ru_maxrss / (1024 * 1024)
+    return ru_maxrss / 1024
+
+
+def profile_command(
+    command: list[str] | str,
+    cwd: Path | None = None,
+    timeout: float = DEFAULT_TIMEOUT_SECONDS,
+    tool: str = "",
+    eval_repo: str = "",
+) -> ResourceProfile:
+    """Run a command and measure its resource usage via wait4 rusage.
+
+    The child is killed when the timeout elapses; the profile then
+    carries the partial measurements with the kill exit code.
+    """
+    argv = shlex.split(command) if isinstance(command, str) else list(command)
+    start = time.monotonic()
+    process = subprocess.Popen(
+        argv,
+        cwd=cwd,
+        stdout=subprocess.DEVNULL,
+        stderr=subprocess.DEVNULL,
+    )
+    watchdog = threading.Timer(timeout, process.kill)
+    watchdog.start()
+    try:
+        _, status, rusage = os.wait4(process.pid, 0)
+    finally:
+        watchdog.cancel()
+    exit_code = os.waitstatus_to_exitcode(status)
+    # Popen has been reaped by wait4; record the code so __del__ stays quiet.
+    process.returncode = exit_code
+
+    return ResourceProfile(
+        tool=tool,
+        eval_repo=eval_repo,
+        wall_seconds=time.monotonic() - start,
+        cpu_user_seconds=rusage.ru_utime,
+        cpu_system_seconds=rusage.ru_stime,
+        peak_rss_mb=_rss_to_mb(rusage.ru_maxrss),
+        exit_code=exit_code,
+    )
+
+
+def profile_eval_repos(
+    tool: str,
+    command_template: str,
+    eval_repos: list[Path],
+    timeout: float = DEFAULT_TIMEOUT_SECONDS,
+) -> list[ResourceProfile]:
+    """Profile one tool across eval repos.
+
+    ``command_template`` may contain ``{repo}``, replaced with each eval
+    repo path before execution.
+    """
+    profiles = []
+    for eval_repo in eval_repos:
+        command = command_template.replace("{repo}", str(eval_repo))
+        profiles.append(profile_command(
+            command,
+            timeout=timeout,
+            tool=tool,
+            eval_repo=eval_repo.name,
+        ))
+    return profiles
+
+
+def merge_into_report(report: dict, profiles: list[ResourceProfile]) -> dict:
+    """Attach resource profiles to an evaluation report dict.
+
+    Adds a ``resource_profiles`` list and summary totals without
+    touching existing keys.
+    """
+    merged = dict(report)
+    merged["resource_profiles"] = [profile.to_dict() for profile in profiles]
+    merged["resource_summary"] = {
+        "total_wall_seconds": round(sum(p.wall_seconds for p in profiles), 3),
+        "total_cpu_seconds": round(sum(p.cpu_seconds for p in profiles), 3),
+        "max_peak_rss_mb": round(max((p.peak_rss_mb for p in profiles), default=0.0), 1),
+    }
+    return merged
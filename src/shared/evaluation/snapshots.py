@@ -0,0 +1,132 @@
+"""Golden snapshot storage for tool output regression testing.
+
+Stores a canonicalized copy of each tool's output per eval repo under
+``src/tools/<tool>/evaluation/snapshots/``. `caldera eval regress` compares a
+fresh output against the stored snapshot and fails on any unexpected change
+in parsed results; `--update-snapshots` re-baselines after intended changes.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+# Envelope metadata fields that legitimately change between runs and must
+# not trigger regressions.
+VOLATILE_METADATA_FIELDS = {"run_id", "repo_id", "timestamp", "branch", "commit"}
+
+# Data fields that measure execution rather than results.
+VOLATILE_DATA_FIELDS = {"analysis_duration_ms", "scan_time_ms", "duration_ms", "generated_at"}
+
+# Cap on reported differences so massive drifts stay readable.
+MAX_REPORTED_DIFFS = 50
+
+
+def canonicalize(payload: dict) -> dict:
+    """Return a stable, comparison-safe copy of a tool output envelope.
+
+    Volatile metadata (run IDs, timestamps) is replaced with fixed
+    placeholders and timing fields are dropped, so two runs over identical
+    inputs canonicalize identically while result changes still show up.
+    """
+    canonical = _scrub(payload)
+    metadata = canonical.get("metadata")
+    if isinstance(metadata, dict):
+        for field in VOLATILE_METADATA_FIELDS:
+            if field in metadata:
+                metadata[field] = f"<{field}>"
+    return canonical
+
+
+def _scrub(value: Any) -> Any:
+    if isinstance(value, dict):
+        return {
+            key: _scrub(item)
+            for key, item in sorted(value.items())
+            if key not in VOLATILE_DATA_FIELDS
+        }
+    if isinstance(value, list):
+        return [_scrub(item) for item in value]
+    return value
+
+
+def diff_payloads(expected: Any, actual: Any, path: str = "$") -> list[str]:
+    """Return human-readable differences between two canonicalized payloads."""
+    diffs: list[str] = []
+    _diff(expected, actual, path, diffs)
+    if len(diffs) > MAX_REPORTED_DIFFS:
+        overflow = len(diffs) - MAX_REPORTED_DIFFS
+        diffs = diffs[:MAX_REPORTED_DIFFS] + [f"... and {overflow} more differences"]
+    return diffs
+
+
+def _diff(expected: Any, actual: Any, path: str, diffs: list[str]) -> None:
+    if len(diffs) > MAX_REPORTED_DIFFS:
+        return
+    if type(expected) is not type(actual):
+        diffs.append(f"{path}: type changed from {type(expected).__name__} to {type(actual).__name__}")
+        return
+    if isinstance(expected, dict):
+        for key in sorted(set(expected) | set(actual)):
+            key_path = f"{path}.{key}"
+            if key not in actual:
+                diffs.append(f"{key_path}: removed")
+            elif key not in expected:
+                diffs.append(f"{key_path}: added")
+            else:
+                _diff(expected[key], actual[key], key_path, diffs)
+        return
+    if isinstance(expected, list):
+        if len(expected) != len(actual):
+            diffs.append(f"{path}: length changed from {len(expected)} to {len(actual)}")
+        for idx, (exp_item, act_item) in enumerate(zip(expected, actual)):
+            _diff(exp_item, act_item, f"{path}[{idx}]", diffs)
+        return
+    if expected != actual:
+        diffs.append(f"{path}: {expected!r} -> {actual!r}")
+
+
+class SnapshotStore:
+    """Reads and writes golden snapshots for one tool."""
+
+    def __init__(self, tool_dir: Path) -> None:
+        self._tool_dir = tool_dir
+        self._snapshot_dir = tool_dir / "evaluation" / "snapshots"
+
+    @property
+    def snapshot_dir(self) -> Path:
+        return self._snapshot_dir
+
+    def path_for(self, name: str) -> Path:
+        return self._snapshot_dir / f"{name}.json"
+
+    def names(self) -> list[str]:
+        if not self._snapshot_dir.is_dir():
+            return []
+        return sorted(p.stem for p in self._snapshot_dir.glob("*.json"))
+
+    def load(self, name: str) -> dict | None:
+        path = self.path_for(name)
+        if not path.exists():
+            return None
+        return json.loads(path.read_text())
+
+    def save(self, name: str, payload: dict) -> Path:
+        """Canonicalize and store *payload* as the golden snapshot for *name*."""
+        self._snapshot_dir.mkdir(parents=True, exist_ok=True)
+        path = self.path_for(name)
+        path.write_text(json.dumps(canonicalize(payload), indent=2, sort_keys=True) + "\n")
+        return path
+
+    def check(self, name: str, payload: dict) -> list[str]:
+        """Compare *payload* against the stored snapshot.
+
+        Returns a list of differences; an empty list means no regression.
+        A missing snapshot is reported as a single difference so new eval
+        repos are baselined explicitly via --update-snapshots.
+        """
+        expected = self.load(name)
+        if expected is None:
+            return [f"no snapshot '{name}' — run with --update-snapshots to baseline"]
+        return diff_payloads(expected, canonicalize(payload))
@@ -0,0 +1,175 @@
+"""Large-repo stress corpus generation for adapter robustness testing.
+
+Generates repos that stress scale rather than accuracy: very high file
+counts, deeply nested directory trees, single multi-megabyte files, and
+pathological sources (deep_nesting.rs-style block nesting at 50 levels,
+kilometer-long lines). Pass/fail is judged on runtime and peak memory
+via :mod:`shared.evaluation.profiling`, not on findings — the corpus
+contains nothing worth finding.
+
+Generation is deterministic for a given spec. Surfaced via
+``insights eval-stress``.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from pathlib import Path
+
+from .profiling import ResourceProfile, profile_command
+
+MANIFEST_NAME = "stress_manifest.json"
+FILES_PER_DIRECTORY = 100
+NESTED_BLOCK_INDENT = "    "
+
+PRESETS = {
+    "smoke": {"file_count": 200, "max_depth": 10, "big_file_mb": 1, "nesting_levels": 20},
+    "full": {"file_count": 100_000, "max_depth": 30, "big_file_mb": 10, "nesting_levels": 50},
+}
+
+
+@dataclass(frozen=True)
+class StressSpec:
+    """Shape of one generated stress repo."""
+
+    file_count: int = 100_000
+    max_depth: int = 30
+    big_file_mb: int = 10
+    nesting_levels: int = 50
+
+    def __post_init__(self) -> None:
+        if self.file_count < 1:
+            raise ValueError(f"file_count must be positive, got {self.file_count}")
+        if self.max_depth < 1:
+            raise ValueError(f"max_depth must be positive, got {self.max_depth}")
+
+    @classmethod
+    def from_preset(cls, preset: str) -> StressSpec:
+        if preset not in PRESETS:
+            known = ", ".join(sorted(PRESETS))
+            raise ValueError(f"Unknown preset: {preset!r} (known: {known})")
+        return cls(**PRESETS[preset])
+
+
+@dataclass(frozen=True)
+class StressCriteria:
+    """Resource budget a tool must stay within on the stress repo."""
+
+    max_wall_seconds: float
+    max_peak_rss_mb: float
+
+
+def _small_file_source(index: int) -> str:
+    return (
+        f'"""Stress fixture {index}."""\n\n\n'
+        f"def handler_{index}(payload: dict) -> dict:\n"
+        f"    result = dict(payload)\n"
+        f'    result["sequence"] = {index}\n'
+        f"    return result\n"
+    )
+
+
+def _deeply_nested_source(levels: int) -> str:
+    lines = ["fn deeply_nested(input: i64) -> i64 {", f"{NESTED_BLOCK_INDENT}let mut value = input;"]
+    for level in range(levels):
+        lines.append(f"{NESTED_BLOCK_INDENT * (level + 1)}if value > {level} {{")
+        lines.append(f"{NESTED_BLOCK_INDENT * (level + 2)}value += 1;")
+    for level in range(levels, 0, -1):
+        lines.append(f"{NESTED_BLOCK_INDENT * level}}}")
+    lines += [f"{NESTED_BLOCK_INDENT}value", "}", ""]
+    return "\n".join(lines)
+
+
+def _long_line_source() -> str:
+    condition = " || ".join(f"flag_{i}" for i in range(2000))
+    return f"const GIANT_CONDITION: &str = \"{condition}\";\n"
+
+
+def generate_stress_repo(output_dir: Path, spec: StressSpec) -> dict:
+    """Generate a stress repo and return its manifest dict.
+
+    Layout: ``many/`` holds ``file_count`` small files bucketed into
+    subdirectories, ``deep/`` a directory chain ``max_depth`` levels
+    deep with one file at the bottom, ``big/blob.py`` a
+    ``big_file_mb``-MB source file, and ``pathological/`` the nested
+    and long-line cases.
+    """
+    output_dir.mkdir(parents=True, exist_ok=True)
+
+    many = output_dir / "many"
+    for index in range(spec.file_count):
+        bucket = many / f"bucket_{index // FILES_PER_DIRECTORY:04d}"
+        bucket.mkdir(parents=True, exist_ok=True)
+        (bucket / f"module_{index:06d}.py").write_text(_small_file_source(index))
+
+    deep = output_dir / "deep"
+    for level in range(spec.max_depth):
+        deep = deep / f"level_{level:02d}"
+    deep.mkdir(parents=True)
+    (deep / "bottom.py").write_text(_small_file_source(spec.file_count))
+
+    big_dir = output_dir / "big"
+    big_dir.mkdir(exist_ok=True)
+    chunk = "".join(f"VALUE_{i:06d} = {i}\n" for i in range(1000))
+    repeats = -(-(spec.big_file_mb * 1024 * 1024) // len(chunk))  # ceil: at least big_file_mb
+    (big_dir / "blob.py").write_text(chunk * repeats)
+
+    pathological = output_dir / "pathological"
+    pathological.mkdir(exist_ok=True)
+    (pathological / "deep_nesting.rs").write_text(_deeply_nested_source(spec.nesting_levels))
+    (pathological / "long_line.rs").write_text(_long_line_source())
+
+    return {
+        "file_count": spec.file_count,
+        "max_depth": spec.max_depth,
+        "big_file_mb": spec.big_file_mb,
+        "nesting_levels": spec.nesting_levels,
+        "total_files": spec.file_count + 4,
+    }
+
+
+def evaluate_profile(profile: ResourceProfile, criteria: StressCriteria) -> dict:
+    """Judge one profiled run against the resource budget.
+
+    A run passes only when it exits cleanly and stays within both the
+    wall-clock and peak-RSS budgets; each criterion is reported
+    separately so the report shows what blew up.
+    """
+    checks = {
+        "exit_ok": profile.exit_code == 0,
+        "wall_within_budget": profile.wall_seconds <= criteria.max_wall_seconds,
+        "rss_within_budget": profile.peak_rss_mb <= criteria.max_peak_rss_mb,
+    }
+    return {
+        "tool": profile.tool,
+        "passed": all(checks.values()),
+        "checks": checks,
+        "profile": profile.to_dict(),
+        "budget": {
+            "max_wall_seconds": criteria.max_wall_seconds,
+            "max_peak_rss_mb": criteria.max_peak_rss_mb,
+        },
+    }
+
+
+def run_stress(
+    tool: str,
+    command_template: str,
+    repo_path: Path,
+    criteria: StressCriteria,
+    timeout: float | None = None,
+) -> dict:
+    """Profile one tool against a stress repo and judge the result.
+
+    ``command_template`` may contain ``{repo}``; the timeout defaults
+    to twice the wall budget so hung tools fail fast instead of
+    running forever.
+    """
+    command = command_template.replace("{repo}", str(repo_path))
+    profile = profile_command(
+        command,
+        timeout=timeout if timeout is not None else criteria.max_wall_seconds * 2,
+        tool=tool,
+        eval_repo=repo_path.name,
+    )
+    return evaluate_profile(profile, criteria)
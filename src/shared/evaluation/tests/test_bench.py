@@ -0,0 +1,131 @@
+"""Tests for the tool version bench runner.
+
+Tests cover:
+- Memory usage parsing from docker stats output
+- Findings counting across tool report shapes
+- run_bench() end-to-end with a stubbed Docker backend
+- Comparison table formatting
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.bench import (
+    BenchReport,
+    BenchResult,
+    count_findings,
+    discover_eval_repos,
+    format_comparison_table,
+    run_bench,
+    _parse_mem_usage,
+)
+
+
+class TestParseMemUsage:
+    def test_parses_mib(self) -> None:
+        assert _parse_mem_usage("512MiB") == 512.0
+
+    def test_parses_gib_to_mib(self) -> None:
+        assert _parse_mem_usage("1.5GiB") == 1536.0
+
+    def test_returns_none_for_garbage(self) -> None:
+        assert _parse_mem_usage("") is None
+        assert _parse_mem_usage("lots") is None
+
+
+class TestCountFindings:
+    def test_counts_semgrep_results(self, tmp_path: Path) -> None:
+        report = tmp_path / "report.json"
+        report.write_text(json.dumps({"results": [{"check_id": "a"}, {"check_id": "b"}]}))
+        assert count_findings("semgrep", report) == 2
+
+    def test_counts_top_level_array(self, tmp_path: Path) -> None:
+        report = tmp_path / "report.json"
+        report.write_text(json.dumps([{"RuleID": "x"}]))
+        assert count_findings("gitleaks", report) == 1
+
+    def test_missing_report_counts_zero(self, tmp_path: Path) -> None:
+        assert count_findings("semgrep", tmp_path / "missing.json") == 0
+
+
+class _StubBackend:
+    """Docker backend stand-in that writes a canned report."""
+
+    def __init__(self, findings_by_version: dict[str, int]) -> None:
+        self.findings_by_version = findings_by_version
+        self.pulled: list[str] = []
+
+    def available(self) -> bool:
+        return True
+
+    def pull(self, image: str) -> None:
+        self.pulled.append(image)
+
+    def run(self, image, command, repo_path, output_dir) -> tuple[float, float]:
+        version = image.rsplit(":", 1)[-1]
+        results = [{"check_id": f"r{i}"} for i in range(self.findings_by_version[version])]
+        (output_dir / "report.json").write_text(json.dumps({"results": results}))
+        return 1.25, 256.0
+
+
+@pytest.fixture
+def tools_root(tmp_path: Path) -> Path:
+    tool_dir = tmp_path / "semgrep"
+    (tool_dir / "eval-repos" / "synthetic" / "python").mkdir(parents=True)
+    (tool_dir / "eval-repos" / "synthetic" / "python" / "a.py").write_text("x = 1\n")
+    return tmp_path
+
+
+class TestRunBench:
+    def test_produces_result_per_version_and_repo(self, tools_root: Path, tmp_path: Path) -> None:
+        backend = _StubBackend({"1.60": 3, "1.70": 5})
+        report = run_bench(
+            "semgrep",
+            ["1.60", "1.70"],
+            tools_root=tools_root,
+            work_dir=tmp_path / "work",
+            backend=backend,
+        )
+
+        assert len(report.results) == 2
+        by_version = {r.version: r for r in report.results}
+        assert by_version["1.60"].findings == 3
+        assert by_version["1.70"].findings == 5
+        assert all(r.status == "completed" for r in report.results)
+        assert backend.pulled == ["returntocorp/semgrep:1.60", "returntocorp/semgrep:1.70"]
+
+    def test_unknown_tool_raises(self, tools_root: Path, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="no Docker image template"):
+            run_bench("nonexistent", ["1.0"], tools_root=tools_root, work_dir=tmp_path)
+
+    def test_discover_eval_repos_lists_language_dirs(self, tools_root: Path) -> None:
+        repos = discover_eval_repos(tools_root / "semgrep")
+        assert [r.name for r in repos] == ["python"]
+
+
+class TestFormatComparisonTable:
+    def test_renders_all_rows_with_headers(self) -> None:
+        report = BenchReport(
+            tool="semgrep",
+            versions=["1.60"],
+            results=[
+                BenchResult(
+                    tool="semgrep", version="1.60", eval_repo="python",
+                    status="completed", findings=4, expected=5,
+                    runtime_seconds=2.5, peak_memory_mb=300.0,
+                )
+            ],
+        )
+        table = format_comparison_table(report)
+        assert "Version" in table
+        assert "1.60" in table
+        assert "0.80" in table  # score 4/5
+        assert "300.0" in table
@@ -0,0 +1,120 @@
+"""Tests for external clone-benchmark import.
+
+Tests cover:
+- BigCloneBench id-pair layout import
+- Generic file/line pair layout import
+- Clone type mapping and skip behavior
+- Recall scoring against detector output
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.clone_bench import import_benchmark, recall_by_type
+from shared.evaluation.mutate import GROUND_TRUTH_NAME
+
+FUNCTION_SOURCE = "\n".join(f"int line{i};" for i in range(6)) + "\n"
+
+
+def _id_dataset(root: Path) -> Path:
+    dataset = root / "dataset"
+    functions = dataset / "functions"
+    functions.mkdir(parents=True)
+    for function_id in ("101", "102", "103"):
+        (functions / f"{function_id}.java").write_text(FUNCTION_SOURCE)
+    (dataset / "clone_pairs.csv").write_text(
+        "function_id_1,function_id_2,clone_type\n"
+        "101,102,T1\n"
+        "101,103,ST3\n"
+        "102,103,WT3/T4\n"  # type-4: skipped
+        "101,999,T2\n"  # missing source: skipped
+    )
+    return dataset
+
+
+class TestIdLayoutImport:
+    def test_imports_pairs_and_writes_ground_truth(self, tmp_path: Path) -> None:
+        dataset = _id_dataset(tmp_path)
+        output = tmp_path / "eval-repo"
+
+        records, stats = import_benchmark(dataset, output)
+
+        assert stats.pairs_imported == 2
+        assert stats.pairs_skipped == 2
+        assert stats.functions_copied == 3
+        assert (output / "functions" / "101.java").exists()
+        truth = json.loads((output / GROUND_TRUTH_NAME).read_text())
+        assert truth["total_clones"] == 2
+        assert {record.clone_type for record in records} == {1, 3}
+        assert records[0].source_lines == (1, 6)
+
+    def test_limit_caps_imported_pairs(self, tmp_path: Path) -> None:
+        records, _ = import_benchmark(_id_dataset(tmp_path), tmp_path / "out", limit=1)
+        assert len(records) == 1
+
+
+class TestFilePairImport:
+    def test_generic_layout(self, tmp_path: Path) -> None:
+        dataset = tmp_path / "dataset"
+        (dataset / "src").mkdir(parents=True)
+        (dataset / "src" / "a.py").write_text(FUNCTION_SOURCE)
+        (dataset / "src" / "b.py").write_text(FUNCTION_SOURCE)
+        (dataset / "clone_pairs.csv").write_text(
+            "file_a,start_a,end_a,file_b,start_b,end_b,clone_type\n"
+            "src/a.py,1,5,src/b.py,2,6,2\n"
+        )
+
+        records, stats = import_benchmark(dataset, tmp_path / "out")
+
+        assert stats.pairs_imported == 1
+        assert records[0].clone_file == "src/b.py"
+        assert records[0].clone_lines == (2, 6)
+
+    def test_unrecognized_columns_fail(self, tmp_path: Path) -> None:
+        dataset = tmp_path / "dataset"
+        dataset.mkdir()
+        (dataset / "clone_pairs.csv").write_text("foo,bar\n1,2\n")
+
+        with pytest.raises(ValueError, match="expected function_id_1"):
+            import_benchmark(dataset, tmp_path / "out")
+
+    def test_missing_csv_fails(self, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="Pairs CSV not found"):
+            import_benchmark(tmp_path, tmp_path / "out")
+
+
+class TestRecallScoring:
+    def test_recall_per_type_and_overall(self, tmp_path: Path) -> None:
+        records, _ = import_benchmark(_id_dataset(tmp_path), tmp_path / "out")
+        detected = [
+            # Reversed order still counts.
+            {"file_a": "functions/102.java", "file_b": "functions/101.java"},
+        ]
+
+        report = recall_by_type(records, detected)
+
+        assert report["by_type"]["type_1"]["recall"] == 1.0
+        assert report["by_type"]["type_3"]["found"] == 0
+        assert report["by_locality"]["cross_file"]["expected"] == 2
+        assert report["overall"] == {"expected": 2, "found": 1, "recall": 0.5}
+
+    def test_line_overlap_required_when_ranges_given(self, tmp_path: Path) -> None:
+        records, _ = import_benchmark(_id_dataset(tmp_path), tmp_path / "out")
+        detected = [{
+            "file_a": "functions/101.java",
+            "file_b": "functions/102.java",
+            "start_a": 50, "end_a": 60,
+            "start_b": 1, "end_b": 6,
+        }]
+
+        report = recall_by_type(records, detected)
+
+        assert report["by_type"]["type_1"]["found"] == 0
@@ -0,0 +1,87 @@
+"""Tests for confusion matrices and eval categorizers.
+
+Tests cover:
+- Derived precision/recall/F1/accuracy rates
+- Matrix addition
+- Path categorization (tests vs src)
+- Clone locality categorization
+- Breakdown rendering
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.confusion import (
+    ConfusionMatrix,
+    breakdown,
+    clone_locality,
+    path_category,
+)
+
+
+class TestConfusionMatrix:
+    def test_derived_rates(self) -> None:
+        matrix = ConfusionMatrix(
+            true_positives=8, false_positives=2, false_negatives=2, true_negatives=8
+        )
+        assert matrix.precision == 0.8
+        assert matrix.recall == 0.8
+        assert matrix.f1 == 0.8
+        assert matrix.accuracy == 0.8
+
+    def test_empty_matrix_rates_are_zero(self) -> None:
+        matrix = ConfusionMatrix()
+        assert matrix.precision == 0.0
+        assert matrix.recall == 0.0
+        assert matrix.f1 == 0.0
+        assert matrix.accuracy == 0.0
+
+    def test_addition_sums_counts(self) -> None:
+        total = ConfusionMatrix(true_positives=1) + ConfusionMatrix(
+            true_positives=2, false_negatives=3
+        )
+        assert total.true_positives == 3
+        assert total.false_negatives == 3
+
+
+class TestPathCategory:
+    def test_test_directories(self) -> None:
+        assert path_category("src/tests/test_parser.py") == "tests"
+        assert path_category("frontend/__tests__/app.spec.ts") == "tests"
+
+    def test_test_filenames(self) -> None:
+        assert path_category("src/parser_test.go") == "tests"
+        assert path_category("test_utils.py") == "tests"
+
+    def test_production_paths(self) -> None:
+        assert path_category("src/parser.py") == "src"
+        assert path_category("src/contest/entry.py") == "src"
+
+    def test_windows_separators_normalized(self) -> None:
+        assert path_category("src\\tests\\test_a.py") == "tests"
+
+
+class TestCloneLocality:
+    def test_same_file_is_intra(self) -> None:
+        assert clone_locality("src/a.py", "src/a.py") == "intra_file"
+
+    def test_different_files_are_cross(self) -> None:
+        assert clone_locality("src/a.py", "src/b.py") == "cross_file"
+
+
+class TestBreakdown:
+    def test_per_category_plus_overall(self) -> None:
+        report = breakdown({
+            "src": ConfusionMatrix(true_positives=3, false_negatives=1),
+            "tests": ConfusionMatrix(false_positives=2),
+        })
+
+        assert report["by_category"]["src"]["recall"] == 0.75
+        assert report["by_category"]["tests"]["false_positives"] == 2
+        assert report["overall"]["true_positives"] == 3
+        assert report["overall"]["false_positives"] == 2
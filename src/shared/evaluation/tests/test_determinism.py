@@ -0,0 +1,104 @@
+"""Tests for the tool determinism checker.
+
+Tests cover:
+- Canonicalization (volatile fields, list sorting)
+- Divergent path reporting
+- Order instability vs real nondeterminism
+- run_determinism with a stubbed runner
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.determinism import (
+    canonical_dump,
+    canonicalize,
+    check_tool,
+    divergent_paths,
+    run_determinism,
+)
+
+
+def _payload(smells: list[dict], run_id: str = "run-1") -> dict:
+    return {
+        "metadata": {"tool_name": "semgrep", "run_id": run_id, "timestamp": "2025-01-01T00:00:00Z"},
+        "data": {"files": [{"path": "a.py", "smells": smells}]},
+    }
+
+
+class TestCanonicalize:
+    def test_volatile_fields_are_stripped(self) -> None:
+        first = _payload([], run_id="run-1")
+        second = _payload([], run_id="run-2")
+        assert canonical_dump(first) == canonical_dump(second)
+
+    def test_list_order_is_normalized(self) -> None:
+        smells = [{"rule_id": "a", "line_start": 1}, {"rule_id": "b", "line_start": 2}]
+        assert canonicalize(smells) == canonicalize(list(reversed(smells)))
+
+
+class TestDivergentPaths:
+    def test_reports_path_to_difference(self) -> None:
+        first = {"data": {"summary": {"total": 3}}}
+        second = {"data": {"summary": {"total": 4}}}
+        assert divergent_paths(first, second) == ["/data/summary/total"]
+
+    def test_reports_length_mismatch(self) -> None:
+        assert divergent_paths({"x": [1]}, {"x": [1, 2]}) == ["/x[len 1 != 2]"]
+
+
+class TestCheckTool:
+    def test_identical_runs_are_deterministic(self) -> None:
+        smells = [{"rule_id": "a", "line_start": 1}]
+        result = check_tool("semgrep", [_payload(smells), _payload(smells)])
+        assert result.deterministic is True
+        assert result.order_unstable is False
+
+    def test_reordered_output_is_order_unstable(self) -> None:
+        smells = [{"rule_id": "a", "line_start": 1}, {"rule_id": "b", "line_start": 2}]
+        result = check_tool(
+            "pmd-cpd",
+            [_payload(smells), _payload(list(reversed(smells)))],
+        )
+        assert result.deterministic is True
+        assert result.order_unstable is True
+
+    def test_varying_findings_flag_the_rule(self) -> None:
+        stable = {"rule_id": "stable", "line_start": 1}
+        flaky = {"rule_id": "flaky", "line_start": 9}
+        result = check_tool(
+            "semgrep",
+            [_payload([stable, flaky]), _payload([stable])],
+        )
+        assert result.deterministic is False
+        assert result.flaky_rules == {"flaky": [1, 0]}
+        assert result.divergent_paths
+
+
+class TestRunDeterminism:
+    def test_stubbed_runner_and_error_isolation(self) -> None:
+        calls: list[str] = []
+
+        def runner(tool: str) -> dict:
+            calls.append(tool)
+            if tool == "broken":
+                raise RuntimeError("timeout")
+            return _payload([{"rule_id": "a", "line_start": 1}], run_id=f"run-{len(calls)}")
+
+        results = run_determinism(["semgrep", "broken"], runs=3, runner=runner)
+
+        assert results[0].deterministic is True
+        assert results[0].runs == 3
+        assert results[1].error == "timeout"
+        assert results[1].deterministic is False
+
+    def test_rejects_single_run(self) -> None:
+        with pytest.raises(ValueError, match="runs must be >= 2"):
+            run_determinism(["semgrep"], runs=1, runner=lambda tool: {})
@@ -0,0 +1,101 @@
+"""Tests for the eval history store.
+
+Tests cover:
+- Recording runs and scorecards
+- Trend ordering and limits
+- Regression detection
+- Sparkline rendering
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.history import EvalHistory, EvalRun, sparkline
+
+
+def _run(tool: str, score: float, version: str = "1.0", recorded_at: str = "2026-01-01T00:00:00Z") -> EvalRun:
+    return EvalRun(
+        tool=tool,
+        tool_version=version,
+        corpus_revision="abc123",
+        score=score,
+        decision="PASS",
+        wall_seconds=10.0,
+        recorded_at=recorded_at,
+    )
+
+
+class TestEvalHistory:
+    def test_record_and_trend_oldest_first(self, tmp_path: Path) -> None:
+        history = EvalHistory(tmp_path / "history.sqlite")
+        history.record(_run("semgrep", 0.7))
+        history.record(_run("semgrep", 0.8))
+        history.record(_run("semgrep", 0.9))
+
+        runs = history.trend("semgrep")
+
+        assert [run.score for run in runs] == [0.7, 0.8, 0.9]
+        assert history.tools() == ["semgrep"]
+
+    def test_trend_limit_keeps_most_recent(self, tmp_path: Path) -> None:
+        history = EvalHistory(tmp_path / "history.sqlite")
+        for index in range(5):
+            history.record(_run("scc", index / 10))
+
+        runs = history.trend("scc", limit=2)
+
+        assert [run.score for run in runs] == [0.3, 0.4]
+
+    def test_record_scorecard_reads_summary(self, tmp_path: Path) -> None:
+        scorecard = tmp_path / "scorecard.json"
+        scorecard.write_text(json.dumps({
+            "tool": "semgrep",
+            "summary": {"score": 0.82, "decision": "PASS"},
+        }))
+        history = EvalHistory(tmp_path / "history.sqlite")
+
+        run = history.record_scorecard(scorecard, tool_version="1.70", corpus_revision="def456")
+
+        assert run.tool == "semgrep"
+        assert run.score == 0.82
+        assert history.trend("semgrep")[0].tool_version == "1.70"
+
+    def test_record_scorecard_without_score_fails(self, tmp_path: Path) -> None:
+        scorecard = tmp_path / "scorecard.json"
+        scorecard.write_text(json.dumps({"tool": "semgrep"}))
+        history = EvalHistory(tmp_path / "history.sqlite")
+
+        with pytest.raises(ValueError, match="no score"):
+            history.record_scorecard(scorecard)
+
+    def test_regressions_flag_score_drops(self, tmp_path: Path) -> None:
+        history = EvalHistory(tmp_path / "history.sqlite")
+        history.record(_run("semgrep", 0.9, version="1.60"))
+        history.record(_run("semgrep", 0.7, version="1.70"))
+        history.record(_run("scc", 0.9))
+        history.record(_run("scc", 0.89))
+
+        regressions = history.regressions(threshold=0.05)
+
+        assert len(regressions) == 1
+        assert regressions[0]["tool"] == "semgrep"
+        assert regressions[0]["latest_version"] == "1.70"
+
+
+class TestSparkline:
+    def test_scores_map_to_blocks(self) -> None:
+        chart = sparkline([0.0, 0.5, 1.0])
+        assert len(chart) == 3
+        assert chart[0] == "▁"
+        assert chart[-1] == "█"
+
+    def test_empty_series(self) -> None:
+        assert sparkline([]) == ""
@@ -0,0 +1,119 @@
+"""Tests for the clone seeding mutation engine.
+
+Tests cover:
+- Function collection with size threshold
+- Type 1/2/3 mutations
+- Deterministic injection and ground truth output
+"""
+
+from __future__ import annotations
+
+import ast
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.mutate import (
+    collect_functions,
+    inject_clones,
+    mutate_type1,
+    mutate_type2,
+    mutate_type3,
+    write_ground_truth,
+)
+import random
+
+SAMPLE_FUNCTION = '''def compute_total(items, tax_rate):
+    """Sum item prices with tax."""
+    subtotal = 0
+    for item in items:
+        subtotal += item["price"]
+    tax = subtotal * tax_rate
+    total = subtotal + tax
+    return round(total, 2)
+'''
+
+
+def _seed_repo(tmp_path: Path) -> Path:
+    repo = tmp_path / "repo"
+    (repo / "src").mkdir(parents=True)
+    (repo / "src" / "billing.py").write_text(SAMPLE_FUNCTION)
+    (repo / "src" / "tiny.py").write_text("def one_liner():\n    return 1\n")
+    return repo
+
+
+class TestCollectFunctions:
+    def test_skips_functions_below_threshold(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+
+        functions = collect_functions(repo)
+
+        assert [function.name for function in functions] == ["compute_total"]
+        assert functions[0].file == "src/billing.py"
+        assert functions[0].line_start == 1
+
+    def test_skips_previously_seeded_clone_files(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+        (repo / "src" / "billing_clone0.py").write_text(SAMPLE_FUNCTION)
+
+        functions = collect_functions(repo)
+
+        assert len(functions) == 1
+
+
+class TestMutations:
+    def test_type1_is_exact_copy(self) -> None:
+        assert mutate_type1(SAMPLE_FUNCTION) == SAMPLE_FUNCTION
+
+    def test_type2_renames_identifiers_and_changes_literals(self) -> None:
+        mutated = mutate_type2(SAMPLE_FUNCTION)
+
+        assert "def compute_total_m(" in mutated
+        assert "subtotal_m" in mutated
+        assert "round(total_m, 3)" in mutated  # literal 2 became 3
+        ast.parse(mutated)  # still valid Python
+
+    def test_type3_produces_valid_near_miss(self) -> None:
+        mutated = mutate_type3(SAMPLE_FUNCTION, random.Random(0))
+
+        assert mutated != SAMPLE_FUNCTION
+        assert "def compute_total_m(" in mutated
+        tree = ast.parse(mutated)
+        assert len(tree.body[0].body) < len(ast.parse(SAMPLE_FUNCTION).body[0].body)
+
+
+class TestInjectClones:
+    def test_injection_is_deterministic_and_writes_ground_truth(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+
+        records = inject_clones(repo, count=3, clone_types=(1, 2, 3), seed=42)
+        output_path = write_ground_truth(repo, records, seed=42)
+
+        assert [record.clone_type for record in records] == [1, 2, 3]
+        for record in records:
+            clone_file = repo / record.clone_file
+            assert clone_file.exists()
+            assert "Seeded type-" in clone_file.read_text().splitlines()[0]
+
+        ground_truth = json.loads(output_path.read_text())
+        assert ground_truth["total_clones"] == 3
+        assert ground_truth["seed"] == 42
+        assert ground_truth["clones"][0]["source_file"] == "src/billing.py"
+
+    def test_rejects_invalid_clone_types(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+
+        with pytest.raises(ValueError, match="clone types"):
+            inject_clones(repo, count=1, clone_types=(4,))
+
+    def test_rejects_repo_without_functions(self, tmp_path: Path) -> None:
+        empty = tmp_path / "empty"
+        empty.mkdir()
+
+        with pytest.raises(ValueError, match="no functions"):
+            inject_clones(empty, count=1)
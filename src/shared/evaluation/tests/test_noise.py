@@ -0,0 +1,130 @@
+"""Tests for clean-corpus noise scoring.
+
+Tests cover:
+- Corpus line counting
+- Generic finding extraction across envelope shapes
+- Per-tool and per-rule noise scores
+- Report structure
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.noise import (
+    count_corpus_lines,
+    extract_findings,
+    noise_report,
+    score_outputs,
+)
+
+
+class TestCountCorpusLines:
+    def test_counts_non_blank_lines_excluding_markdown(self, tmp_path: Path) -> None:
+        (tmp_path / "python").mkdir()
+        (tmp_path / "python" / "mod.py").write_text("a = 1\n\nb = 2\n")
+        (tmp_path / "README.md").write_text("# ignored\n")
+
+        assert count_corpus_lines(tmp_path) == 2
+
+    def test_shipped_corpus_is_multi_language(self) -> None:
+        from shared.evaluation.noise import CLEAN_CORPUS_DIR
+
+        languages = {entry.name for entry in CLEAN_CORPUS_DIR.iterdir() if entry.is_dir()}
+        assert {"python", "rust", "go", "java"} <= languages
+        assert count_corpus_lines() > 200
+
+
+class TestExtractFindings:
+    def test_files_with_smells_shape(self) -> None:
+        payload = {
+            "data": {
+                "files": [
+                    {
+                        "path": "python/task_scheduler.py",
+                        "smells": [
+                            {"rule_id": "DD-D1", "dd_smell_id": "D1_EMPTY_CATCH", "line_start": 5},
+                            {"rule_id": "DD-D2", "dd_smell_id": "D2_CATCH_ALL", "line_start": 9},
+                        ],
+                    },
+                ],
+            },
+        }
+        findings = extract_findings(payload)
+        assert findings == [
+            ("python/task_scheduler.py", "DD-D1"),
+            ("python/task_scheduler.py", "DD-D2"),
+        ]
+
+    def test_flat_findings_shape(self) -> None:
+        payload = {
+            "data": {
+                "findings": [
+                    {"file_path": "go/ratelimiter.go", "rule": "G101", "line": 3},
+                ],
+            },
+        }
+        assert extract_findings(payload) == [("go/ratelimiter.go", "G101")]
+
+    def test_dicts_without_location_are_not_findings(self) -> None:
+        payload = {"data": {"summary": {"rule_id": "meta", "total": 3}}}
+        assert extract_findings(payload) == []
+
+
+class TestScoreOutputs:
+    def _corpus(self, tmp_path: Path) -> Path:
+        corpus = tmp_path / "corpus"
+        (corpus / "python").mkdir(parents=True)
+        (corpus / "python" / "mod.py").write_text("\n".join(f"x{i} = {i}" for i in range(500)))
+        return corpus
+
+    def test_noise_per_kloc_and_per_rule(self, tmp_path: Path) -> None:
+        corpus = self._corpus(tmp_path)
+        outputs = tmp_path / "outputs"
+        outputs.mkdir()
+        (outputs / "semgrep.json").write_text(json.dumps({
+            "data": {
+                "files": [
+                    {
+                        "path": "python/mod.py",
+                        "smells": [
+                            {"rule_id": "noisy", "line_start": 1},
+                            {"rule_id": "noisy", "line_start": 2},
+                        ],
+                    },
+                ],
+            },
+        }))
+        (outputs / "scc.json").write_text(json.dumps({"data": {"files": []}}))
+
+        scores = score_outputs(outputs, corpus)
+
+        assert [score.tool for score in scores] == ["semgrep", "scc"]
+        assert scores[0].findings == 2
+        assert scores[0].noise_per_kloc == 4.0  # 2 findings / 0.5 KLOC
+        assert scores[0].per_rule == {"noisy": 2}
+        assert scores[1].findings == 0
+
+    def test_report_lists_noisiest_rules(self, tmp_path: Path) -> None:
+        corpus = self._corpus(tmp_path)
+        outputs = tmp_path / "outputs"
+        outputs.mkdir()
+        (outputs / "semgrep.json").write_text(json.dumps({
+            "data": {
+                "files": [
+                    {"path": "python/mod.py", "smells": [{"rule_id": "noisy", "line_start": 1}]},
+                ],
+            },
+        }))
+
+        report = noise_report(score_outputs(outputs, corpus))
+
+        assert report["tools_scored"] == 1
+        assert report["noisiest_rules"][0] == {
+            "tool": "semgrep", "rule": "noisy", "findings": 1,
+        }
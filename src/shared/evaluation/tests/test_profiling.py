@@ -0,0 +1,85 @@
+"""Tests for eval run resource profiling.
+
+Tests cover:
+- profile_command rusage capture and exit codes
+- Timeout kill behavior
+- Template expansion across eval repos
+- Report merging
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.profiling import (
+    ResourceProfile,
+    merge_into_report,
+    profile_command,
+    profile_eval_repos,
+)
+
+
+class TestProfileCommand:
+    def test_captures_wall_cpu_and_rss(self) -> None:
+        profile = profile_command(
+            [sys.executable, "-c", "sum(range(2_000_000))"],
+            tool="demo",
+            eval_repo="synthetic",
+        )
+
+        assert profile.exit_code == 0
+        assert profile.wall_seconds > 0
+        assert profile.cpu_seconds > 0
+        assert profile.peak_rss_mb > 1
+
+    def test_nonzero_exit_code_is_reported(self) -> None:
+        profile = profile_command([sys.executable, "-c", "raise SystemExit(3)"])
+        assert profile.exit_code == 3
+
+    def test_timeout_kills_the_child(self) -> None:
+        profile = profile_command(
+            [sys.executable, "-c", "import time; time.sleep(30)"],
+            timeout=0.2,
+        )
+        assert profile.exit_code != 0
+        assert profile.wall_seconds < 10
+
+
+class TestProfileEvalRepos:
+    def test_template_expands_per_repo(self, tmp_path: Path) -> None:
+        repo_a = tmp_path / "repo-a"
+        repo_b = tmp_path / "repo-b"
+        repo_a.mkdir()
+        repo_b.mkdir()
+
+        profiles = profile_eval_repos(
+            "demo",
+            f"{sys.executable} -c pass",
+            [repo_a, repo_b],
+        )
+
+        assert [profile.eval_repo for profile in profiles] == ["repo-a", "repo-b"]
+        assert all(profile.tool == "demo" for profile in profiles)
+
+
+class TestMergeIntoReport:
+    def test_adds_profiles_and_summary(self) -> None:
+        profile = ResourceProfile(
+            tool="scc",
+            eval_repo="synthetic",
+            wall_seconds=1.5,
+            cpu_user_seconds=1.0,
+            cpu_system_seconds=0.2,
+            peak_rss_mb=64.0,
+            exit_code=0,
+        )
+        report = merge_into_report({"score": 0.9}, [profile])
+
+        assert report["score"] == 0.9
+        assert report["resource_profiles"][0]["peak_rss_mb"] == 64.0
+        assert report["resource_summary"]["total_cpu_seconds"] == 1.2
+        assert report["resource_summary"]["max_peak_rss_mb"] == 64.0
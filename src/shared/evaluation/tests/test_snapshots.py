@@ -0,0 +1,113 @@
+"""Tests for golden snapshot storage and comparison.
+
+Tests cover:
+- Canonicalization of volatile metadata and timing fields
+- Snapshot save/load round-trips
+- Regression detection via SnapshotStore.check()
+- Difference reporting shape
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.snapshots import SnapshotStore, canonicalize, diff_payloads
+
+
+def _envelope(**data_overrides) -> dict:
+    data = {
+        "tool": "semgrep",
+        "summary": {"total_issues": 2},
+        "files": [{"path": "src/a.py", "issue_count": 2}],
+        "analysis_duration_ms": 431,
+    }
+    data.update(data_overrides)
+    return {
+        "metadata": {
+            "tool_name": "semgrep",
+            "tool_version": "1.60.0",
+            "run_id": "11111111-1111-1111-1111-111111111111",
+            "repo_id": "22222222-2222-2222-2222-222222222222",
+            "branch": "main",
+            "commit": "a" * 40,
+            "timestamp": "2026-08-26T12:00:00Z",
+            "schema_version": "1.0.0",
+        },
+        "data": data,
+    }
+
+
+class TestCanonicalize:
+    def test_replaces_volatile_metadata(self) -> None:
+        canonical = canonicalize(_envelope())
+        assert canonical["metadata"]["run_id"] == "<run_id>"
+        assert canonical["metadata"]["timestamp"] == "<timestamp>"
+        # Non-volatile metadata is preserved: a tool version bump that
+        # changes results should be visible in context.
+        assert canonical["metadata"]["tool_version"] == "1.60.0"
+
+    def test_drops_timing_fields(self) -> None:
+        canonical = canonicalize(_envelope())
+        assert "analysis_duration_ms" not in canonical["data"]
+
+    def test_two_runs_over_same_inputs_are_identical(self) -> None:
+        first = _envelope()
+        second = _envelope()
+        second["metadata"]["run_id"] = "33333333-3333-3333-3333-333333333333"
+        second["metadata"]["timestamp"] = "2026-08-27T09:00:00Z"
+        second["data"]["analysis_duration_ms"] = 999
+        assert canonicalize(first) == canonicalize(second)
+
+
+class TestDiffPayloads:
+    def test_reports_value_change_with_path(self) -> None:
+        diffs = diff_payloads({"a": {"b": 1}}, {"a": {"b": 2}})
+        assert diffs == ["$.a.b: 1 -> 2"]
+
+    def test_reports_added_and_removed_keys(self) -> None:
+        diffs = diff_payloads({"a": 1}, {"b": 1})
+        assert "$.a: removed" in diffs
+        assert "$.b: added" in diffs
+
+    def test_reports_list_length_change(self) -> None:
+        diffs = diff_payloads({"files": [1, 2]}, {"files": [1]})
+        assert any("length changed from 2 to 1" in d for d in diffs)
+
+
+class TestSnapshotStore:
+    def test_save_then_check_passes(self, tmp_path: Path) -> None:
+        store = SnapshotStore(tmp_path)
+        store.save("python", _envelope())
+        assert store.check("python", _envelope()) == []
+
+    def test_check_detects_changed_results(self, tmp_path: Path) -> None:
+        store = SnapshotStore(tmp_path)
+        store.save("python", _envelope())
+        changed = _envelope(summary={"total_issues": 5})
+        diffs = store.check("python", changed)
+        assert diffs
+        assert any("total_issues" in d for d in diffs)
+
+    def test_check_ignores_volatile_fields(self, tmp_path: Path) -> None:
+        store = SnapshotStore(tmp_path)
+        store.save("python", _envelope())
+        rerun = _envelope()
+        rerun["metadata"]["run_id"] = "44444444-4444-4444-4444-444444444444"
+        rerun["data"]["analysis_duration_ms"] = 1
+        assert store.check("python", rerun) == []
+
+    def test_missing_snapshot_reported(self, tmp_path: Path) -> None:
+        store = SnapshotStore(tmp_path)
+        diffs = store.check("go", _envelope())
+        assert len(diffs) == 1
+        assert "--update-snapshots" in diffs[0]
+
+    def test_names_lists_stored_snapshots(self, tmp_path: Path) -> None:
+        store = SnapshotStore(tmp_path)
+        store.save("python", _envelope())
+        store.save("go", _envelope())
+        assert store.names() == ["go", "python"]
@@ -0,0 +1,114 @@
+"""Tests for stress corpus generation.
+
+Tests cover:
+- Repo generation layout (many files, deep chain, big file, pathological cases)
+- Spec validation and presets
+- Resource budget pass/fail judging
+- run_stress end to end with a trivial command
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.profiling import ResourceProfile
+from shared.evaluation.stress import (
+    StressCriteria,
+    StressSpec,
+    evaluate_profile,
+    generate_stress_repo,
+    run_stress,
+)
+
+SMALL_SPEC = StressSpec(file_count=25, max_depth=5, big_file_mb=1, nesting_levels=8)
+
+
+def _profile(wall: float = 1.0, rss: float = 100.0, exit_code: int = 0) -> ResourceProfile:
+    return ResourceProfile(
+        tool="demo",
+        eval_repo="stress",
+        wall_seconds=wall,
+        cpu_user_seconds=wall,
+        cpu_system_seconds=0.0,
+        peak_rss_mb=rss,
+        exit_code=exit_code,
+    )
+
+
+class TestGeneration:
+    def test_layout_matches_spec(self, tmp_path: Path) -> None:
+        manifest = generate_stress_repo(tmp_path / "repo", SMALL_SPEC)
+
+        many_files = list((tmp_path / "repo" / "many").rglob("*.py"))
+        assert len(many_files) == 25
+        assert manifest["total_files"] == 29
+
+        bottom = tmp_path / "repo" / "deep"
+        for level in range(5):
+            bottom = bottom / f"level_{level:02d}"
+        assert (bottom / "bottom.py").exists()
+
+        blob = tmp_path / "repo" / "big" / "blob.py"
+        assert blob.stat().st_size >= 1024 * 1024
+
+        nested = (tmp_path / "repo" / "pathological" / "deep_nesting.rs").read_text()
+        assert nested.count("if value >") == 8
+        long_line = (tmp_path / "repo" / "pathological" / "long_line.rs").read_text()
+        assert max(len(line) for line in long_line.splitlines()) > 10_000
+
+    def test_generation_is_deterministic(self, tmp_path: Path) -> None:
+        generate_stress_repo(tmp_path / "a", SMALL_SPEC)
+        generate_stress_repo(tmp_path / "b", SMALL_SPEC)
+        file_a = tmp_path / "a" / "many" / "bucket_0000" / "module_000003.py"
+        file_b = tmp_path / "b" / "many" / "bucket_0000" / "module_000003.py"
+        assert file_a.read_text() == file_b.read_text()
+
+    def test_invalid_spec_fails(self) -> None:
+        with pytest.raises(ValueError, match="file_count"):
+            StressSpec(file_count=0)
+
+    def test_unknown_preset_fails(self) -> None:
+        with pytest.raises(ValueError, match="Unknown preset"):
+            StressSpec.from_preset("huge")
+
+    def test_smoke_preset_loads(self) -> None:
+        assert StressSpec.from_preset("smoke").file_count == 200
+
+
+class TestEvaluateProfile:
+    def test_within_budget_passes(self) -> None:
+        result = evaluate_profile(_profile(), StressCriteria(10.0, 500.0))
+        assert result["passed"]
+        assert result["checks"] == {
+            "exit_ok": True,
+            "wall_within_budget": True,
+            "rss_within_budget": True,
+        }
+
+    def test_rss_over_budget_fails(self) -> None:
+        result = evaluate_profile(_profile(rss=900.0), StressCriteria(10.0, 500.0))
+        assert not result["passed"]
+        assert not result["checks"]["rss_within_budget"]
+        assert result["checks"]["wall_within_budget"]
+
+    def test_nonzero_exit_fails(self) -> None:
+        result = evaluate_profile(_profile(exit_code=2), StressCriteria(10.0, 500.0))
+        assert not result["passed"]
+
+
+class TestRunStress:
+    def test_profiles_and_judges_a_command(self, tmp_path: Path) -> None:
+        result = run_stress(
+            "demo",
+            f"{sys.executable} -c pass",
+            tmp_path,
+            StressCriteria(max_wall_seconds=30.0, max_peak_rss_mb=2000.0),
+        )
+        assert result["passed"]
+        assert result["profile"]["exit_code"] == 0
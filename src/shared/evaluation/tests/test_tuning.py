@@ -0,0 +1,133 @@
+"""Tests for threshold auto-tuning.
+
+Tests cover:
+- Threshold sweeps over numeric and severity values
+- F1 maximization under an FP budget
+- Over-budget fallback
+- Config snippet rendering
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.tuning import (
+    config_snippet,
+    load_scored_runs,
+    sweep_thresholds,
+    tune_parameter,
+)
+
+
+def _finding(value: int | str, true_positive: bool) -> dict:
+    return {"value": value, "true_positive": true_positive}
+
+
+class TestSweepThresholds:
+    def test_numeric_sweep_trades_fp_for_fn(self) -> None:
+        findings = [
+            _finding(5, False),
+            _finding(10, True),
+            _finding(20, True),
+        ]
+        swept = {c.value: c for c in sweep_thresholds(findings, missed=0, kind="numeric")}
+
+        assert swept[5].false_positives == 1
+        assert swept[5].true_positives == 2
+        assert swept[10].false_positives == 0
+        assert swept[10].true_positives == 2
+        assert swept[20].false_negatives == 1
+
+    def test_missed_counts_as_fn_at_every_threshold(self) -> None:
+        swept = sweep_thresholds([_finding(10, True)], missed=3, kind="numeric")
+        assert swept[0].false_negatives == 3
+
+    def test_severity_values_rank_by_level(self) -> None:
+        findings = [
+            _finding("INFO", False),
+            _finding("MEDIUM", True),
+            _finding("HIGH", True),
+        ]
+        swept = sweep_thresholds(findings, missed=0, kind="severity")
+
+        assert [c.value for c in swept] == ["INFO", "MEDIUM", "HIGH"]
+        medium = swept[1]
+        assert medium.false_positives == 0
+        assert medium.true_positives == 2
+
+    def test_unknown_severity_fails(self) -> None:
+        with pytest.raises(ValueError, match="Unknown severity"):
+            sweep_thresholds([_finding("BOGUS", True)], missed=0, kind="severity")
+
+
+class TestTuneParameter:
+    def test_picks_highest_f1_within_budget(self) -> None:
+        run = {
+            "parameter": "lizard-ccn",
+            "findings": [
+                _finding(5, False),
+                _finding(5, False),
+                _finding(10, True),
+                _finding(15, True),
+            ],
+        }
+        result = tune_parameter(run, fp_budget=1)
+
+        assert result.within_budget
+        assert result.recommended is not None
+        assert result.recommended.value == 10
+        assert result.recommended.false_positives == 0
+
+    def test_over_budget_falls_back_to_lowest_fp(self) -> None:
+        run = {
+            "parameter": "cpd-min-tokens",
+            "findings": [
+                _finding(50, False),
+                _finding(75, False),
+                _finding(75, True),
+            ],
+        }
+        result = tune_parameter(run, fp_budget=0)
+
+        assert not result.within_budget
+        assert result.recommended is not None
+        assert result.recommended.value == 75
+
+    def test_unknown_parameter_fails(self) -> None:
+        with pytest.raises(ValueError, match="Unknown parameter"):
+            tune_parameter({"parameter": "bogus", "findings": []})
+
+
+class TestConfigSnippet:
+    def test_renders_recommended_settings(self) -> None:
+        result = tune_parameter({
+            "parameter": "semgrep-severity",
+            "findings": [_finding("LOW", False), _finding("HIGH", True)],
+        }, fp_budget=0)
+        snippet = config_snippet([result], fp_budget=0)
+
+        assert "semgrep:" in snippet
+        assert 'min_severity: "HIGH"' in snippet
+        assert "f1=1.00" in snippet
+
+
+class TestLoadScoredRuns:
+    def test_loads_directory_of_runs(self, tmp_path: Path) -> None:
+        (tmp_path / "lizard.json").write_text(json.dumps({
+            "parameter": "lizard-ccn",
+            "findings": [_finding(10, True)],
+        }))
+        runs = load_scored_runs(tmp_path)
+        assert len(runs) == 1
+        assert runs[0]["parameter"] == "lizard-ccn"
+
+    def test_empty_directory_fails(self, tmp_path: Path) -> None:
+        with pytest.raises(ValueError, match="No scored-run"):
+            load_scored_runs(tmp_path)
@@ -0,0 +1,92 @@
+"""Tests for the synthetic vulnerability injector.
+
+Tests cover:
+- Template rendering and placeholder substitution
+- Donor file discovery (language filter, seeded files skipped)
+- Injection with accurate sink line numbers
+- Manifest output
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/shared to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.evaluation.vuln_inject import (
+    TEMPLATES,
+    find_donor_files,
+    inject_vulns,
+    render_template,
+    write_manifest,
+)
+
+
+def _seed_repo(tmp_path: Path) -> Path:
+    repo = tmp_path / "repo"
+    (repo / "src").mkdir(parents=True)
+    (repo / "src" / "app.py").write_text("def existing():\n    return 1\n")
+    (repo / "src" / "lib.rs").write_text("pub fn existing() -> i32 {\n    1\n}\n")
+    (repo / "src" / "notes.md").write_text("# not a donor\n")
+    return repo
+
+
+class TestTemplates:
+    def test_every_template_has_placeholder_free_render(self) -> None:
+        for template in TEMPLATES:
+            rendered = render_template(template, "order", "orders")
+            assert "__NOUN__" not in rendered
+            assert "__TABLE__" not in rendered
+            assert template.sink_offset < len(rendered.splitlines())
+
+
+class TestFindDonorFiles:
+    def test_filters_by_language_and_skips_seeded(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+        (repo / "src" / "seeded.py").write_text("# seeded-vuln: sqli (vuln-000)\n")
+
+        donors = find_donor_files(repo, {"python"})
+
+        assert [donor.name for donor in donors] == ["app.py"]
+
+
+class TestInjectVulns:
+    def test_sink_lines_point_at_vulnerable_code(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+
+        records = inject_vulns(repo, count=3, vuln_types=("sqli", "cmdi", "traversal"), seed=7)
+
+        assert len(records) == 3
+        for record in records:
+            lines = (repo / record.file).read_text().splitlines()
+            sink_line = lines[record.line - 1]
+            assert any(
+                marker in sink_line
+                for marker in ("SELECT", "report-tool", "full_path", "os.system")
+            ), f"{record.vuln_id}: unexpected sink line {sink_line!r}"
+
+    def test_rejects_unknown_types_and_empty_repos(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+        with pytest.raises(ValueError, match="vuln types"):
+            inject_vulns(repo, count=1, vuln_types=("xss",))
+
+        empty = tmp_path / "empty"
+        empty.mkdir()
+        with pytest.raises(ValueError, match="no donor files"):
+            inject_vulns(empty, count=1)
+
+    def test_manifest_round_trip(self, tmp_path: Path) -> None:
+        repo = _seed_repo(tmp_path)
+        records = inject_vulns(repo, count=2, vuln_types=("sqli",), seed=1)
+
+        manifest_path = write_manifest(repo, records, seed=1)
+
+        manifest = json.loads(manifest_path.read_text())
+        assert manifest["total_vulns"] == 2
+        assert manifest["seed"] == 1
+        assert {vuln["smell_id"] for vuln in manifest["vulns"]} == {"SQL_INJECTION"}
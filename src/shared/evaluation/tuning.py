@@ -0,0 +1,226 @@
+"""Threshold auto-tuning from scored eval runs.
+
+Searches the threshold space of tunable tool parameters (lizard CCN
+cutoff, pmd-cpd minimum token count, semgrep severity filter) against
+labeled eval findings, and recommends the setting that maximizes F1
+while staying inside a configurable false-positive budget. Emits a
+ready-to-use config snippet for the winning settings.
+
+Input is one scored-run JSON per parameter::
+
+    {
+      "parameter": "lizard-ccn",
+      "findings": [{"value": 15, "true_positive": true}, ...],
+      "missed": 2
+    }
+
+``findings`` are everything the tool reported at its most permissive
+setting, labeled against ground truth; ``missed`` counts ground-truth
+positives the tool never reported at any setting (they stay false
+negatives at every threshold). Surfaced via ``insights eval-tune``.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from pathlib import Path
+
+SEVERITY_RANKS = {
+    "INFO": 0,
+    "LOW": 1,
+    "MINOR": 1,
+    "MEDIUM": 2,
+    "MAJOR": 2,
+    "WARNING": 2,
+    "HIGH": 3,
+    "CRITICAL": 3,
+    "ERROR": 3,
+}
+
+DEFAULT_FP_BUDGET = 10
+
+
+@dataclass(frozen=True)
+class ParameterSpec:
+    """One tunable tool parameter."""
+
+    parameter: str
+    tool: str
+    config_key: str
+    kind: str  # "numeric" or "severity"
+
+
+PARAMETERS = {
+    "lizard-ccn": ParameterSpec("lizard-ccn", "lizard", "ccn_threshold", "numeric"),
+    "cpd-min-tokens": ParameterSpec("cpd-min-tokens", "pmd-cpd", "minimum_tokens", "numeric"),
+    "semgrep-severity": ParameterSpec("semgrep-severity", "semgrep", "min_severity", "severity"),
+}
+
+
+@dataclass(frozen=True)
+class ThresholdCandidate:
+    """Confusion counts for one candidate threshold."""
+
+    value: int | str
+    true_positives: int
+    false_positives: int
+    false_negatives: int
+
+    @property
+    def precision(self) -> float:
+        flagged = self.true_positives + self.false_positives
+        return self.true_positives / flagged if flagged else 0.0
+
+    @property
+    def recall(self) -> float:
+        expected = self.true_positives + self.false_negatives
+        return self.true_positives / expected if expected else 0.0
+
+    @property
+    def f1(self) -> float:
+        denominator = self.precision + self.recall
+        if denominator == 0:
+            return 0.0
+        return 2 * self.precision * self.recall / denominator
+
+    def to_dict(self) -> dict:
+        return {
+            "value": self.value,
+            "true_positives": self.true_positives,
+            "false_positives": self.false_positives,
+            "false_negatives": self.false_negatives,
+            "precision": round(self.precision, 4),
+            "recall": round(self.recall, 4),
+            "f1": round(self.f1, 4),
+        }
+
+
+@dataclass
+class TuningResult:
+    """Sweep outcome for one parameter."""
+
+    spec: ParameterSpec
+    candidates: list[ThresholdCandidate] = field(default_factory=list)
+    recommended: ThresholdCandidate | None = None
+    within_budget: bool = True
+
+    def to_dict(self) -> dict:
+        return {
+            "parameter": self.spec.parameter,
+            "tool": self.spec.tool,
+            "config_key": self.spec.config_key,
+            "recommended": self.recommended.to_dict() if self.recommended else None,
+            "within_budget": self.within_budget,
+            "candidates": [candidate.to_dict() for candidate in self.candidates],
+        }
+
+
+def _rank(value: int | str) -> int:
+    if isinstance(value, str):
+        try:
+            return SEVERITY_RANKS[value.upper()]
+        except KeyError:
+            raise ValueError(f"Unknown severity: {value}") from None
+    return value
+
+
+def sweep_thresholds(
+    findings: list[dict],
+    missed: int,
+    kind: str,
+) -> list[ThresholdCandidate]:
+    """Score every candidate threshold observed in the findings.
+
+    A finding is flagged at threshold t when its value ranks >= t;
+    raising the threshold trades false positives for false negatives.
+    ``missed`` ground-truth positives count as false negatives at every
+    threshold.
+    """
+    labeled = [(_rank(f["value"]), f["value"], bool(f["true_positive"])) for f in findings]
+    if kind == "severity":
+        candidates = sorted(
+            {value for _, value, _ in labeled},
+            key=lambda value: _rank(value),
+        )
+    else:
+        candidates = sorted({value for _, value, _ in labeled})
+
+    swept = []
+    for candidate in candidates:
+        cutoff = _rank(candidate)
+        tp = sum(1 for rank, _, true in labeled if true and rank >= cutoff)
+        fp = sum(1 for rank, _, true in labeled if not true and rank >= cutoff)
+        fn = sum(1 for rank, _, true in labeled if true and rank < cutoff) + missed
+        swept.append(ThresholdCandidate(
+            value=candidate,
+            true_positives=tp,
+            false_positives=fp,
+            false_negatives=fn,
+        ))
+    return swept
+
+
+def tune_parameter(scored_run: dict, fp_budget: int = DEFAULT_FP_BUDGET) -> TuningResult:
+    """Recommend the best threshold for one scored run.
+
+    Picks the highest-F1 candidate with false positives within budget;
+    when no candidate fits the budget, falls back to the lowest-FP
+    candidate and flags the result as over budget.
+    """
+    parameter = scored_run.get("parameter", "")
+    if parameter not in PARAMETERS:
+        known = ", ".join(sorted(PARAMETERS))
+        raise ValueError(f"Unknown parameter: {parameter!r} (known: {known})")
+    spec = PARAMETERS[parameter]
+    candidates = sweep_thresholds(
+        scored_run.get("findings", []),
+        int(scored_run.get("missed", 0)),
+        spec.kind,
+    )
+    result = TuningResult(spec=spec, candidates=candidates)
+    if not candidates:
+        result.within_budget = False
+        return result
+
+    affordable = [c for c in candidates if c.false_positives <= fp_budget]
+    if affordable:
+        result.recommended = max(affordable, key=lambda c: (c.f1, -c.false_positives))
+    else:
+        result.within_budget = False
+        result.recommended = min(candidates, key=lambda c: (c.false_positives, -c.f1))
+    return result
+
+
+def load_scored_runs(path: Path) -> list[dict]:
+    """Load scored-run JSON files from a file or directory."""
+    if path.is_dir():
+        files = sorted(path.glob("*.json"))
+    else:
+        files = [path]
+    if not files:
+        raise ValueError(f"No scored-run JSON files found in {path}")
+    return [json.loads(file.read_text()) for file in files]
+
+
+def tune(scored_runs: list[dict], fp_budget: int = DEFAULT_FP_BUDGET) -> list[TuningResult]:
+    """Tune every parameter present in the scored runs."""
+    return [tune_parameter(run, fp_budget) for run in scored_runs]
+
+
+def config_snippet(results: list[TuningResult], fp_budget: int) -> str:
+    """Render recommended settings as a ready-to-paste YAML snippet."""
+    lines = [f"# Recommended by eval-tune (fp_budget={fp_budget})"]
+    for result in sorted(results, key=lambda r: r.spec.tool):
+        if result.recommended is None:
+            continue
+        recommended = result.recommended
+        note = "" if result.within_budget else "  # over FP budget; lowest-FP fallback"
+        value = recommended.value
+        rendered = value if isinstance(value, int) else f'"{value}"'
+        lines.append(f"{result.spec.tool}:")
+        lines.append(
+            f"  {result.spec.config_key}: {rendered}"
+            f"  # f1={recommended.f1:.2f} fp={recommended.false_positives}{note}"
+        )
+    return "\n".join(lines) + "\n"
@@ -0,0 +1,255 @@
+"""Synthetic vulnerability injection for SAST evaluation.
+
+Generalizes the hand-written fixtures (sql_injection.rs and friends):
+parameterized vulnerability templates are appended to donor files in a
+target repo with a ground-truth manifest recording the exact sink lines,
+so semgrep rule coverage can be measured at scale instead of against a
+handful of static files.
+
+Supported patterns per language (python, rust):
+
+- ``sqli``       query built from unsanitized input (format!/%-interpolation)
+- ``cmdi``       shell command concatenated from input
+- ``traversal``  file path joined from unsanitized input
+
+Templates use ``__NOUN__``/``__TABLE__`` placeholders filled from seeded
+pools so every injection site is unique; injection is deterministic for
+a given seed. This exists purely to evaluate detection tooling.
+"""
+
+from __future__ import annotations
+
+import json
+import random
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+
+MANIFEST_NAME = "vuln_ground_truth.json"
+VULN_TYPES = ("sqli", "cmdi", "traversal")
+SEEDED_MARKER = "seeded-vuln"
+
+_NOUNS = ("order", "invoice", "customer", "shipment", "ticket", "account")
+_TABLES = ("orders", "invoices", "customers", "shipments", "tickets", "accounts")
+
+_EXTENSION_LANGUAGES = {".py": "python", ".rs": "rust"}
+
+
+@dataclass(frozen=True)
+class VulnTemplate:
+    """One parameterized vulnerability pattern.
+
+    ``sink_offset`` is the 0-based line offset of the vulnerable line
+    within the rendered snippet (Semgrep semantics: the line where the
+    tainted value is constructed).
+    """
+
+    vuln_type: str
+    language: str
+    smell_id: str
+    code: str
+    sink_offset: int
+
+
+@dataclass(frozen=True)
+class InjectedVuln:
+    """Ground truth for one injected vulnerability."""
+
+    vuln_id: str
+    vuln_type: str
+    smell_id: str
+    language: str
+    file: str
+    line: int
+    function_name: str
+
+    def to_dict(self) -> dict:
+        return {
+            "vuln_id": self.vuln_id,
+            "vuln_type": self.vuln_type,
+            "smell_id": self.smell_id,
+            "language": self.language,
+            "file": self.file,
+            "line": self.line,
+            "function_name": self.function_name,
+        }
+
+
+TEMPLATES: tuple[VulnTemplate, ...] = (
+    VulnTemplate(
+        vuln_type="sqli",
+        language="python",
+        smell_id="SQL_INJECTION",
+        code=(
+            "def fetch___NOUN___by_name(cursor, name):\n"
+            "    query = \"SELECT * FROM __TABLE__ WHERE name = '%s'\" % name\n"
+            "    cursor.execute(query)\n"
+            "    return cursor.fetchall()\n"
+        ),
+        sink_offset=1,
+    ),
+    VulnTemplate(
+        vuln_type="cmdi",
+        language="python",
+        smell_id="COMMAND_INJECTION",
+        code=(
+            "def run___NOUN___report(filename):\n"
+            "    import os\n"
+            "    os.system(\"report-tool --input \" + filename)\n"
+        ),
+        sink_offset=2,
+    ),
+    VulnTemplate(
+        vuln_type="traversal",
+        language="python",
+        smell_id="PATH_TRAVERSAL",
+        code=(
+            "def read___NOUN___file(base_dir, user_path):\n"
+            "    full_path = base_dir + \"/\" + user_path\n"
+            "    with open(full_path) as handle:\n"
+            "        return handle.read()\n"
+        ),
+        sink_offset=1,
+    ),
+    VulnTemplate(
+        vuln_type="sqli",
+        language="rust",
+        smell_id="SQL_INJECTION",
+        code=(
+            "fn fetch___NOUN___by_name(conn: &Connection, name: &str) -> Vec<Row> {\n"
+            "    let query = format!(\"SELECT * FROM __TABLE__ WHERE name = '{}'\", name);\n"
+            "    conn.execute(&query).unwrap()\n"
+            "}\n"
+        ),
+        sink_offset=1,
+    ),
+    VulnTemplate(
+        vuln_type="cmdi",
+        language="rust",
+        smell_id="COMMAND_INJECTION",
+        code=(
+            "fn run___NOUN___report(filename: &str) -> std::process::Output {\n"
+            "    let command = format!(\"report-tool --input {}\", filename);\n"
+            "    std::process::Command::new(\"sh\").arg(\"-c\").arg(command).output().unwrap()\n"
+            "}\n"
+        ),
+        sink_offset=1,
+    ),
+    VulnTemplate(
+        vuln_type="traversal",
+        language="rust",
+        smell_id="PATH_TRAVERSAL",
+        code=(
+            "fn read___NOUN___file(base_dir: &str, user_path: &str) -> String {\n"
+            "    let full_path = format!(\"{}/{}\", base_dir, user_path);\n"
+            "    std::fs::read_to_string(full_path).unwrap()\n"
+            "}\n"
+        ),
+        sink_offset=1,
+    ),
+)
+
+
+def render_template(template: VulnTemplate, noun: str, table: str) -> str:
+    """Fill template placeholders with concrete identifiers."""
+    return template.code.replace("__NOUN__", noun).replace("__TABLE__", table)
+
+
+def find_donor_files(repo_path: Path, languages: set[str]) -> list[Path]:
+    """Donor files by extension, skipping files already seeded."""
+    donors = []
+    for source_file in sorted(repo_path.rglob("*")):
+        if not source_file.is_file():
+            continue
+        if ".git" in source_file.relative_to(repo_path).parts:
+            continue
+        language = _EXTENSION_LANGUAGES.get(source_file.suffix)
+        if language is None or language not in languages:
+            continue
+        try:
+            if SEEDED_MARKER in source_file.read_text(encoding="utf-8"):
+                continue
+        except (UnicodeDecodeError, OSError):
+            continue
+        donors.append(source_file)
+    return donors
+
+
+def inject_vulns(
+    repo_path: Path,
+    count: int = 20,
+    vuln_types: tuple[str, ...] = VULN_TYPES,
+    seed: int = 0,
+) -> list[InjectedVuln]:
+    """Append parameterized vulnerable functions to donor files.
+
+    Cycles through the requested vulnerability types, picks donor files
+    and identifiers deterministically from the seed, and returns ground
+    truth with absolute sink line numbers.
+
+    Raises:
+        ValueError: On unknown vulnerability types or no donor files
+    """
+    if count < 1:
+        raise ValueError("count must be >= 1")
+    invalid = set(vuln_types) - set(VULN_TYPES)
+    if invalid or not vuln_types:
+        raise ValueError(f"vuln types must be a subset of {VULN_TYPES}, got {vuln_types}")
+
+    templates = [t for t in TEMPLATES if t.vuln_type in vuln_types]
+    donors = find_donor_files(repo_path, {t.language for t in templates})
+    if not donors:
+        raise ValueError(f"no donor files for {sorted({t.language for t in templates})} in {repo_path}")
+
+    rng = random.Random(seed)
+    donors_by_language: dict[str, list[Path]] = {}
+    for donor in donors:
+        donors_by_language.setdefault(_EXTENSION_LANGUAGES[donor.suffix], []).append(donor)
+
+    ordered = [t for t in templates if t.language in donors_by_language]
+    if not ordered:
+        raise ValueError("no donor files match any requested template language")
+
+    records = []
+    for index in range(count):
+        template = ordered[index % len(ordered)]
+        donor = rng.choice(donors_by_language[template.language])
+        noun = rng.choice(_NOUNS)
+        snippet = render_template(template, noun, rng.choice(_TABLES))
+
+        comment = "#" if template.language == "python" else "//"
+        header = f"{comment} {SEEDED_MARKER}: {template.vuln_type} (vuln-{index:03d})\n"
+        existing = donor.read_text(encoding="utf-8")
+        if existing and not existing.endswith("\n"):
+            existing += "\n"
+        donor.write_text(existing + "\n" + header + snippet)
+
+        base_line = existing.count("\n") + 1  # blank separator line
+        records.append(InjectedVuln(
+            vuln_id=f"vuln-{index:03d}",
+            vuln_type=template.vuln_type,
+            smell_id=template.smell_id,
+            language=template.language,
+            file=donor.relative_to(repo_path).as_posix(),
+            line=base_line + 1 + template.sink_offset + 1,
+            function_name=snippet.splitlines()[0].split("(")[0].removeprefix("def ").removeprefix("fn "),
+        ))
+    return records
+
+
+def write_manifest(
+    repo_path: Path,
+    records: list[InjectedVuln],
+    seed: int = 0,
+    output_path: Path | None = None,
+) -> Path:
+    """Write the injected-vulnerability manifest and return its path."""
+    output_path = output_path or repo_path / MANIFEST_NAME
+    output_path.write_text(json.dumps({
+        "schema_version": "1.0",
+        "generated_at": datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        "seed": seed,
+        "total_vulns": len(records),
+        "vulns": [record.to_dict() for record in records],
+    }, indent=2))
+    return output_path
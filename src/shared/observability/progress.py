@@ -0,0 +1,95 @@
+"""
+Structured progress events stream (NDJSON).
+
+Emits machine-readable pipeline events (``tool_started``, ``file_parsed``,
+``finding_emitted``, ``tool_finished``, ...) as one JSON object per line so CI
+wrappers can follow live progress and per-tool timing without scraping the
+human-readable log. Events go to stderr by default, or to a file/named pipe.
+
+Usage:
+    from shared.observability.progress import configure_emitter, get_emitter
+
+    configure_emitter("ndjson")  # once, at startup
+    get_emitter().emit("tool_started", tool="scc", run_id=run_id)
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, TextIO
+
+
+class ProgressEmitter:
+    """No-op emitter used when structured progress is not requested."""
+
+    def emit(self, event: str, **fields: Any) -> None:
+        pass
+
+    def close(self) -> None:
+        pass
+
+
+class NdjsonProgressEmitter(ProgressEmitter):
+    """Writes one JSON object per event, each on its own line.
+
+    Every event carries ``event`` and an ISO-8601 UTC ``ts``; all other
+    fields are caller-supplied. The stream is flushed per event so named
+    pipes and ``tail -f`` consumers see events immediately.
+    """
+
+    def __init__(self, stream: TextIO | None = None, path: Path | None = None) -> None:
+        if path is not None:
+            self._stream: TextIO = path.open("a", encoding="utf-8")
+            self._owns_stream = True
+        else:
+            self._stream = stream if stream is not None else sys.stderr
+            self._owns_stream = False
+
+    def emit(self, event: str, **fields: Any) -> None:
+        record: dict[str, Any] = {
+            "event": event,
+            "ts": datetime.now(timezone.utc).isoformat(),
+        }
+        record.update({k: v for k, v in fields.items() if v is not None})
+        self._stream.write(json.dumps(record, default=str) + "\n")
+        self._stream.flush()
+
+    def close(self) -> None:
+        if self._owns_stream:
+            self._stream.close()
+
+
+_emitter: ProgressEmitter | None = None
+
+
+def configure_emitter(mode: str | None, path: Path | None = None) -> ProgressEmitter:
+    """Install the process-wide emitter. ``mode=None`` installs the no-op."""
+    global _emitter
+    if _emitter is not None:
+        _emitter.close()
+    if mode == "ndjson":
+        _emitter = NdjsonProgressEmitter(path=path)
+    elif mode is None:
+        _emitter = ProgressEmitter()
+    else:
+        raise ValueError(f"unknown progress mode: {mode}")
+    return _emitter
+
+
+def get_emitter() -> ProgressEmitter:
+    """Return the process-wide progress emitter (no-op until configured)."""
+    global _emitter
+    if _emitter is None:
+        _emitter = ProgressEmitter()
+    return _emitter
+
+
+def reset_emitter() -> None:
+    """Reset the global emitter (for tests)."""
+    global _emitter
+    if _emitter is not None:
+        _emitter.close()
+    _emitter = None
@@ -0,0 +1,83 @@
+"""Tests for the NDJSON progress events emitter.
+
+Tests cover:
+- NDJSON record shape (event, ts, caller fields; None dropped)
+- File-backed emitter writing to a path
+- Global emitter lifecycle (configure/get/reset)
+"""
+
+from __future__ import annotations
+
+import io
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.observability.progress import (
+    NdjsonProgressEmitter,
+    ProgressEmitter,
+    configure_emitter,
+    get_emitter,
+    reset_emitter,
+)
+
+
+def test_emit_writes_one_json_object_per_line() -> None:
+    stream = io.StringIO()
+    emitter = NdjsonProgressEmitter(stream=stream)
+    emitter.emit("tool_started", tool="scc", index=1, total=18)
+    emitter.emit("tool_finished", tool="scc", duration_seconds=1.5)
+
+    lines = stream.getvalue().strip().splitlines()
+    assert len(lines) == 2
+    first = json.loads(lines[0])
+    assert first["event"] == "tool_started"
+    assert first["tool"] == "scc"
+    assert first["index"] == 1
+    assert "ts" in first
+
+
+def test_emit_drops_none_fields() -> None:
+    stream = io.StringIO()
+    NdjsonProgressEmitter(stream=stream).emit("tool_started", tool="scc", output=None)
+    record = json.loads(stream.getvalue())
+    assert "output" not in record
+
+
+def test_file_backed_emitter_appends_to_path(tmp_path: Path) -> None:
+    target = tmp_path / "progress.ndjson"
+    emitter = NdjsonProgressEmitter(path=target)
+    emitter.emit("pipeline_started", run_id="r1")
+    emitter.close()
+
+    record = json.loads(target.read_text().strip())
+    assert record["event"] == "pipeline_started"
+    assert record["run_id"] == "r1"
+
+
+def test_get_emitter_defaults_to_noop() -> None:
+    reset_emitter()
+    emitter = get_emitter()
+    assert type(emitter) is ProgressEmitter
+    emitter.emit("anything", ignored=True)  # must not raise
+
+
+def test_configure_emitter_installs_and_resets() -> None:
+    reset_emitter()
+    installed = configure_emitter("ndjson")
+    assert get_emitter() is installed
+    assert isinstance(installed, NdjsonProgressEmitter)
+    configure_emitter(None)
+    assert type(get_emitter()) is ProgressEmitter
+    reset_emitter()
+
+
+def test_configure_emitter_rejects_unknown_mode() -> None:
+    reset_emitter()
+    with pytest.raises(ValueError):
+        configure_emitter("xml")
@@ -0,0 +1,72 @@
+"""Tests for the pipeline tracer.
+
+Tests cover:
+- Span nesting and finished-span recording
+- Error status propagation on exceptions
+- Global tracer lifecycle (get_tracer/reset_tracer)
+- tracing_enabled() gating via environment
+"""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import pytest
+
+# Add src/ to path for imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent.parent))
+
+from shared.observability import tracing
+from shared.observability.tracing import PipelineTracer, get_tracer, reset_tracer, tracing_enabled
+
+
+def test_span_records_name_attributes_and_duration() -> None:
+    tracer = PipelineTracer()
+    with tracer.span("run_tools", run_id="r1") as record:
+        pass
+
+    assert record.name == "run_tools"
+    assert record.attributes == {"run_id": "r1"}
+    assert record.status == "ok"
+    assert record.duration_seconds is not None
+    assert tracer.finished_spans == [record]
+
+
+def test_nested_spans_attach_as_children() -> None:
+    tracer = PipelineTracer()
+    with tracer.span("run_tools") as parent:
+        with tracer.span("tool_run", tool_name="scc") as child:
+            pass
+
+    assert parent.children == [child]
+    # Only root spans land in finished_spans.
+    assert tracer.finished_spans == [parent]
+
+
+def test_exception_marks_span_errored_and_propagates() -> None:
+    tracer = PipelineTracer()
+    with pytest.raises(RuntimeError):
+        with tracer.span("adapter_ingest", tool_name="bandit"):
+            raise RuntimeError("boom")
+
+    assert tracer.finished_spans[0].status == "error"
+
+
+def test_get_tracer_returns_singleton_until_reset() -> None:
+    reset_tracer()
+    first = get_tracer()
+    assert get_tracer() is first
+    reset_tracer()
+    assert get_tracer() is not first
+
+
+def test_tracing_disabled_without_env_flag(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.delenv("CALDERA_OTEL_ENABLED", raising=False)
+    assert not tracing_enabled()
+
+
+def test_tracing_requires_otel_packages(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setenv("CALDERA_OTEL_ENABLED", "1")
+    monkeypatch.setattr(tracing, "OTEL_AVAILABLE", False)
+    assert not tracing_enabled()
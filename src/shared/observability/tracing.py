@@ -0,0 +1,126 @@
+"""
+OpenTelemetry tracing for the orchestration pipeline.
+
+Wraps the optional ``opentelemetry-api``/``opentelemetry-sdk`` packages: when
+they are installed (and ``CALDERA_OTEL_ENABLED=1``), orchestrator stages emit
+real spans exported via OTLP; otherwise every call is a cheap no-op so the
+orchestrator never grows a hard dependency on OTel.
+
+Usage:
+    from shared.observability.tracing import get_tracer
+
+    tracer = get_tracer()
+    with tracer.span("run_tools", run_id=run_id):
+        with tracer.span("tool", tool_name="scc"):
+            ...
+"""
+
+from __future__ import annotations
+
+import os
+import time
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+from typing import Any, Iterator
+
+try:
+    from opentelemetry import trace as otel_trace
+    from opentelemetry.sdk.resources import Resource
+    from opentelemetry.sdk.trace import TracerProvider
+    from opentelemetry.sdk.trace.export import BatchSpanProcessor
+    from opentelemetry.exporter.otlp.proto.http.trace_exporter import OTLPSpanExporter
+    OTEL_AVAILABLE = True
+except ImportError:
+    OTEL_AVAILABLE = False
+
+SERVICE_NAME = "caldera-orchestrator"
+
+
+@dataclass
+class SpanRecord:
+    """Span captured by the fallback tracer (also used in tests)."""
+    name: str
+    attributes: dict[str, Any]
+    started_at: float
+    duration_seconds: float | None = None
+    status: str = "ok"
+    children: list["SpanRecord"] = field(default_factory=list)
+
+
+class PipelineTracer:
+    """Records spans in-process; subclassed by the OTel-backed tracer.
+
+    The in-process record keeps the last trace inspectable (and testable)
+    regardless of whether an exporter is configured.
+    """
+
+    def __init__(self) -> None:
+        self.finished_spans: list[SpanRecord] = []
+        self._stack: list[SpanRecord] = []
+
+    @contextmanager
+    def span(self, name: str, **attributes: Any) -> Iterator[SpanRecord]:
+        record = SpanRecord(name=name, attributes=dict(attributes), started_at=time.time())
+        if self._stack:
+            self._stack[-1].children.append(record)
+        self._stack.append(record)
+        start = time.perf_counter()
+        try:
+            yield record
+        except Exception:
+            record.status = "error"
+            raise
+        finally:
+            record.duration_seconds = time.perf_counter() - start
+            self._stack.pop()
+            if not self._stack:
+                self.finished_spans.append(record)
+
+
+class OtelPipelineTracer(PipelineTracer):
+    """PipelineTracer that mirrors every span to an OpenTelemetry tracer."""
+
+    def __init__(self, endpoint: str | None = None) -> None:
+        super().__init__()
+        resource = Resource.create({"service.name": SERVICE_NAME})
+        provider = TracerProvider(resource=resource)
+        exporter = OTLPSpanExporter(endpoint=endpoint) if endpoint else OTLPSpanExporter()
+        provider.add_span_processor(BatchSpanProcessor(exporter))
+        otel_trace.set_tracer_provider(provider)
+        self._tracer = otel_trace.get_tracer(SERVICE_NAME)
+
+    @contextmanager
+    def span(self, name: str, **attributes: Any) -> Iterator[SpanRecord]:
+        with self._tracer.start_as_current_span(name) as otel_span:
+            for key, value in attributes.items():
+                if value is not None:
+                    otel_span.set_attribute(f"caldera.{key}", value)
+            with super().span(name, **attributes) as record:
+                yield record
+            if record.status == "error":
+                otel_span.set_status(otel_trace.StatusCode.ERROR)
+
+
+_tracer: PipelineTracer | None = None
+
+
+def tracing_enabled() -> bool:
+    """True when OTel packages are installed and tracing is switched on."""
+    return OTEL_AVAILABLE and os.environ.get("CALDERA_OTEL_ENABLED") == "1"
+
+
+def get_tracer() -> PipelineTracer:
+    """Return the process-wide pipeline tracer (OTel-backed when enabled)."""
+    global _tracer
+    if _tracer is None:
+        if tracing_enabled():
+            _tracer = OtelPipelineTracer(os.environ.get("CALDERA_OTEL_ENDPOINT"))
+        else:
+            _tracer = PipelineTracer()
+    return _tracer
+
+
+def reset_tracer() -> None:
+    """Reset the global tracer (for tests)."""
+    global _tracer
+    _tracer = None
@@ -0,0 +1,156 @@
+"""
+Graceful cancellation for orchestrator scans.
+
+Ctrl-C used to leave orphaned java/semgrep processes behind: tools run
+through ``make``, which spawns its own children, and killing only the
+direct child strands the grandchildren. This module gives the
+orchestrator one cancellation token that
+
+* handles SIGINT/SIGTERM exactly once (a second signal falls back to the
+  default handler, so a stuck shutdown can still be killed),
+* runs every tool in its own process group and terminates the whole
+  group on cancel — SIGTERM first, SIGKILL for stragglers after a grace
+  period,
+* surfaces the interruption as ``RunInterrupted`` at the next safe
+  point, so the orchestrator can flush what it has (checkpoint, partial
+  ingest) and mark the collection run ``interrupted`` instead of leaving
+  it ``running`` forever.
+
+Signal-handler safety note: the handler usually interrupts a
+``process.wait()`` on the very process it is tearing down, and that wait
+holds Popen's non-reentrant waitpid lock. Everything the handler touches
+therefore uses non-blocking ``poll()`` — calling ``wait()`` from the
+handler deadlocks the main thread.
+
+Temp workspaces are cleaned by the orchestrator's existing ``finally``
+path once ``RunInterrupted`` propagates.
+"""
+
+from __future__ import annotations
+
+import os
+import signal
+import subprocess
+import time
+
+# Seconds between SIGTERM and SIGKILL for a cancelled process group.
+KILL_GRACE_SECONDS = 5.0
+
+_POLL_INTERVAL_SECONDS = 0.05
+
+
+class RunInterrupted(Exception):
+    """The run was cancelled by SIGINT/SIGTERM."""
+
+    def __init__(self, signal_name: str) -> None:
+        super().__init__(f"run interrupted by {signal_name}")
+        self.signal_name = signal_name
+
+
+class CancellationToken:
+    """Shared cancel flag plus the process groups to tear down."""
+
+    def __init__(self) -> None:
+        self._cancelled = False
+        # pid -> Popen. Mutated only by the main thread; the signal handler
+        # just snapshots the values, so no lock is needed (and none may be
+        # taken from a handler anyway).
+        self._processes: dict[int, subprocess.Popen] = {}
+        self.signal_name: str | None = None
+
+    def install(self) -> None:
+        """Install one-shot SIGINT/SIGTERM handlers (main thread only)."""
+        for signum in (signal.SIGINT, signal.SIGTERM):
+            signal.signal(signum, self._handle)
+
+    def _handle(self, signum: int, _frame) -> None:
+        # Restore defaults so a second Ctrl-C kills us the ordinary way.
+        signal.signal(signal.SIGINT, signal.default_int_handler)
+        signal.signal(signal.SIGTERM, signal.SIG_DFL)
+        self.cancel(signal.Signals(signum).name)
+
+    def cancel(self, signal_name: str) -> None:
+        """Flag the run as cancelled and tear down running tool trees."""
+        self.signal_name = signal_name
+        self._cancelled = True
+        self.terminate_children()
+
+    @property
+    def cancelled(self) -> bool:
+        return self._cancelled
+
+    def raise_if_cancelled(self) -> None:
+        if self._cancelled:
+            raise RunInterrupted(self.signal_name or "signal")
+
+    def register(self, process: subprocess.Popen) -> None:
+        self._processes[process.pid] = process
+
+    def unregister(self, process: subprocess.Popen) -> None:
+        self._processes.pop(process.pid, None)
+
+    def terminate_children(self, grace_seconds: float = KILL_GRACE_SECONDS) -> None:
+        """SIGTERM every registered process group; SIGKILL stragglers.
+
+        Signals go to the group, not the process: tools run under
+        ``make``, and the java/semgrep grandchildren must die with it.
+        Only ``poll()`` is used here — this runs inside the signal
+        handler, where a blocking ``wait()`` on an already-waited
+        process would deadlock (see module docstring).
+        """
+        processes = list(self._processes.values())
+        for process in processes:
+            _signal_group(process, signal.SIGTERM)
+        deadline = time.monotonic() + grace_seconds
+        for process in processes:
+            while process.poll() is None and time.monotonic() < deadline:
+                time.sleep(_POLL_INTERVAL_SECONDS)
+            if process.poll() is None:
+                _signal_group(process, signal.SIGKILL)
+        # Bounded reap so callers observe the exit; an interrupted wait()
+        # on the main thread reaps anything still pending once we return.
+        deadline = time.monotonic() + grace_seconds
+        for process in processes:
+            while process.poll() is None and time.monotonic() < deadline:
+                time.sleep(_POLL_INTERVAL_SECONDS)
+
+
+def _signal_group(process: subprocess.Popen, signum: int) -> None:
+    try:
+        os.killpg(os.getpgid(process.pid), signum)
+    except (ProcessLookupError, PermissionError):
+        pass
+
+
+def run_cancellable(
+    cmd: list[str],
+    token: CancellationToken | None,
+    *,
+    check: bool = False,
+    **popen_kwargs,
+) -> int:
+    """``subprocess.run`` that a CancellationToken can tear down cleanly.
+
+    The child starts in a new session (its own process group) and stays
+    registered with the token while it runs, so cancellation kills the
+    entire tree. Raises ``RunInterrupted`` after the child is gone if
+    the token fired, and ``CalledProcessError`` like ``check=True``
+    otherwise.
+    """
+    if token is None:
+        result = subprocess.run(cmd, check=check, **popen_kwargs)
+        return result.returncode
+    process = subprocess.Popen(cmd, start_new_session=True, **popen_kwargs)
+    token.register(process)
+    try:
+        returncode = process.wait()
+    except BaseException:
+        _signal_group(process, signal.SIGKILL)
+        process.wait()
+        raise
+    finally:
+        token.unregister(process)
+    token.raise_if_cancelled()
+    if check and returncode != 0:
+        raise subprocess.CalledProcessError(returncode, cmd)
+    return returncode
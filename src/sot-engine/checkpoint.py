@@ -0,0 +1,119 @@
+"""
+Checkpointing for long orchestrator scans.
+
+Records per-tool completion (output path + timing) to a JSON file after each
+tool finishes, so a scan that dies partway — OOM-killed tool, network blip —
+can be restarted with ``--resume`` and skip the tools that already produced
+valid outputs. Tool execution dominates scan wall-clock time; ingest and dbt
+are cheap and always re-run on resume.
+
+Checkpoints live under ``/tmp/caldera_checkpoints/<run-id>.json`` by default
+and are written atomically (tmp file + rename) so a crash mid-write never
+corrupts the previous checkpoint.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+
+DEFAULT_CHECKPOINT_DIR = Path("/tmp/caldera_checkpoints")
+
+
+@dataclass
+class RunCheckpoint:
+    """Per-run record of completed tool work."""
+
+    run_id: str
+    repo_id: str
+    commit: str
+    checkpoint_dir: Path = DEFAULT_CHECKPOINT_DIR
+    completed_tools: dict[str, dict] = field(default_factory=dict)
+
+    @property
+    def path(self) -> Path:
+        return self.checkpoint_dir / f"{self.run_id}.json"
+
+    @classmethod
+    def start(
+        cls,
+        run_id: str,
+        repo_id: str,
+        commit: str,
+        checkpoint_dir: Path = DEFAULT_CHECKPOINT_DIR,
+    ) -> "RunCheckpoint":
+        """Begin a fresh checkpoint, discarding any stale one for this run_id."""
+        checkpoint = cls(run_id, repo_id, commit, checkpoint_dir)
+        checkpoint._save()
+        return checkpoint
+
+    @classmethod
+    def load(
+        cls,
+        run_id: str,
+        repo_id: str,
+        commit: str,
+        checkpoint_dir: Path = DEFAULT_CHECKPOINT_DIR,
+    ) -> "RunCheckpoint":
+        """Load the checkpoint for a run to resume.
+
+        Raises FileNotFoundError when no checkpoint exists, and ValueError
+        when the checkpoint belongs to a different repo or commit (resuming
+        would mix outputs from different inputs).
+        """
+        path = checkpoint_dir / f"{run_id}.json"
+        if not path.exists():
+            raise FileNotFoundError(f"no checkpoint for run {run_id} at {path}")
+        data = json.loads(path.read_text())
+        if data.get("repo_id") != repo_id or data.get("commit") != commit:
+            raise ValueError(
+                f"checkpoint for run {run_id} was taken for "
+                f"{data.get('repo_id')}@{str(data.get('commit'))[:8]}, "
+                f"not {repo_id}@{commit[:8]}"
+            )
+        return cls(
+            run_id=run_id,
+            repo_id=repo_id,
+            commit=commit,
+            checkpoint_dir=checkpoint_dir,
+            completed_tools=data.get("completed_tools", {}),
+        )
+
+    def mark_tool_done(
+        self, tool_name: str, output_path: Path, duration_seconds: float
+    ) -> None:
+        """Record a completed tool and persist the checkpoint immediately."""
+        self.completed_tools[tool_name] = {
+            "output_path": str(output_path),
+            "duration_seconds": round(duration_seconds, 3),
+            "completed_at": datetime.now(timezone.utc).isoformat(),
+        }
+        self._save()
+
+    def completed_output(self, tool_name: str) -> Path | None:
+        """Return the recorded output path if the tool finished and its output
+        still exists on disk; otherwise None (the tool must re-run)."""
+        entry = self.completed_tools.get(tool_name)
+        if not entry:
+            return None
+        output_path = Path(entry["output_path"])
+        return output_path if output_path.exists() else None
+
+    def clear(self) -> None:
+        """Remove the checkpoint file (called after a fully successful run)."""
+        self.path.unlink(missing_ok=True)
+
+    def _save(self) -> None:
+        self.checkpoint_dir.mkdir(parents=True, exist_ok=True)
+        payload = {
+            "run_id": self.run_id,
+            "repo_id": self.repo_id,
+            "commit": self.commit,
+            "completed_tools": self.completed_tools,
+        }
+        tmp_path = self.path.with_suffix(".json.tmp")
+        tmp_path.write_text(json.dumps(payload, indent=2))
+        os.replace(tmp_path, self.path)
@@ -8,5 +8,6 @@ select
     params,
     token_count,
     line_start,
-    line_end
+    line_end,
+    max_nesting_depth
 from {{ source('lz', 'lz_lizard_function_metrics') }}
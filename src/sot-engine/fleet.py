@@ -0,0 +1,316 @@
+"""Fleet scanning: batch multi-repo analysis from a manifest.
+
+Reads a ``repos.yaml`` manifest, scans every listed repository through
+the orchestrator (clone/extract, scan, persist, cleanup) with bounded
+parallelism, and writes an organization-wide aggregate report with a
+health score per repo and portfolio-level duplication.
+
+Each repo gets its own landing-zone database under the fleet workdir —
+DuckDB allows one writer per file, so parallel scans must not share
+one. The aggregate report reads those databases afterwards.
+
+Manifest format::
+
+    fleet:
+      max_parallel: 2        # optional, default 2
+      run_tools: true        # optional, default true
+    repos:
+      - repo_id: my-app
+        source: https://github.com/org/my-app.git#main
+        branch: main
+      - repo_id: lib
+        source: /path/to/lib
+
+Usage:
+    python src/sot-engine/fleet.py scan repos.yaml --workdir /tmp/fleet
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import subprocess
+import sys
+import time
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+
+import yaml
+
+DEFAULT_MAX_PARALLEL = 2
+REPORT_NAME = "fleet-report.json"
+SCAN_TIMEOUT_SECONDS = 3600
+
+# Health score deductions, per unit. The score starts at 100; a failed
+# scan is 0. Duplication dominates because it is the one portfolio-level
+# metric every repo reports.
+DUPLICATION_PENALTY_PER_PCT = 2.0
+MAX_DUPLICATION_PENALTY = 60.0
+LOW_COMMENT_THRESHOLD = 0.05
+LOW_COMMENT_PENALTY = 10.0
+
+
+@dataclass(frozen=True)
+class FleetRepo:
+    """One manifest entry."""
+
+    repo_id: str
+    source: str
+    branch: str = "main"
+
+    def __post_init__(self) -> None:
+        if not self.repo_id:
+            raise ValueError("Manifest repo entry missing repo_id")
+        if not self.source:
+            raise ValueError(f"Manifest repo {self.repo_id!r} missing source")
+
+
+@dataclass
+class FleetRepoResult:
+    """Outcome of scanning one repo."""
+
+    repo_id: str
+    status: str  # completed | failed
+    db_path: str
+    duration_seconds: float = 0.0
+    error: str = ""
+    metrics: dict = field(default_factory=dict)
+
+    def to_dict(self) -> dict:
+        return {
+            "repo_id": self.repo_id,
+            "status": self.status,
+            "db_path": self.db_path,
+            "duration_seconds": round(self.duration_seconds, 1),
+            "error": self.error,
+            "metrics": self.metrics,
+            "health_score": health_score(self),
+        }
+
+
+def load_manifest(manifest_path: Path) -> tuple[list[FleetRepo], dict]:
+    """Parse the fleet manifest into repo entries and settings."""
+    manifest = yaml.safe_load(manifest_path.read_text())
+    if not isinstance(manifest, dict) or not isinstance(manifest.get("repos"), list):
+        raise ValueError(f"{manifest_path}: manifest must have a 'repos' list")
+    repos = [
+        FleetRepo(
+            repo_id=str(entry.get("repo_id", "")),
+            source=str(entry.get("source", "")),
+            branch=str(entry.get("branch", "main")),
+        )
+        for entry in manifest["repos"]
+    ]
+    seen: set[str] = set()
+    for repo in repos:
+        if repo.repo_id in seen:
+            raise ValueError(f"{manifest_path}: duplicate repo_id {repo.repo_id!r}")
+        seen.add(repo.repo_id)
+    settings = manifest.get("fleet", {}) or {}
+    settings.setdefault("max_parallel", DEFAULT_MAX_PARALLEL)
+    settings.setdefault("run_tools", True)
+    return repos, settings
+
+
+def _orchestrator_command(repo: FleetRepo, db_path: Path, settings: dict) -> list[str]:
+    orchestrator = Path(__file__).parent / "orchestrator.py"
+    command = [
+        sys.executable,
+        str(orchestrator),
+        "--repo-path", repo.source,
+        "--repo-id", repo.repo_id,
+        "--branch", repo.branch,
+        "--db-path", str(db_path),
+        "--no-progress",
+    ]
+    if settings.get("run_tools", True):
+        command.append("--run-tools")
+    return command
+
+
+def scan_repo(
+    repo: FleetRepo,
+    workdir: Path,
+    settings: dict,
+    runner=None,
+) -> FleetRepoResult:
+    """Scan one repo into its own landing-zone database.
+
+    ``runner`` takes the orchestrator command and returns an exit code;
+    injectable so fleets can be tested without running real scans.
+    """
+    db_path = workdir / f"{repo.repo_id}.duckdb"
+    command = _orchestrator_command(repo, db_path, settings)
+    start = time.monotonic()
+    try:
+        if runner is not None:
+            exit_code = runner(command)
+        else:
+            completed = subprocess.run(
+                command,
+                capture_output=True,
+                text=True,
+                timeout=SCAN_TIMEOUT_SECONDS,
+            )
+            exit_code = completed.returncode
+            if exit_code != 0:
+                tail = (completed.stderr or completed.stdout).strip().splitlines()[-3:]
+                return FleetRepoResult(
+                    repo_id=repo.repo_id,
+                    status="failed",
+                    db_path=str(db_path),
+                    duration_seconds=time.monotonic() - start,
+                    error=" / ".join(tail),
+                )
+    except subprocess.TimeoutExpired:
+        return FleetRepoResult(
+            repo_id=repo.repo_id,
+            status="failed",
+            db_path=str(db_path),
+            duration_seconds=time.monotonic() - start,
+            error=f"scan timed out after {SCAN_TIMEOUT_SECONDS}s",
+        )
+    status = "completed" if exit_code == 0 else "failed"
+    return FleetRepoResult(
+        repo_id=repo.repo_id,
+        status=status,
+        db_path=str(db_path),
+        duration_seconds=time.monotonic() - start,
+        error="" if status == "completed" else f"exit code {exit_code}",
+    )
+
+
+def repo_metrics(db_path: Path) -> dict:
+    """Read portfolio metrics for one repo from its landing zone.
+
+    Returns an empty dict when the database or the tables are missing
+    (e.g. the relevant tools did not run).
+    """
+    import duckdb
+
+    if not db_path.exists():
+        return {}
+    metrics: dict = {}
+    conn = duckdb.connect(str(db_path), read_only=True)
+    try:
+        try:
+            row = conn.execute(
+                """SELECT COUNT(*), SUM(code_lines), AVG(comment_ratio)
+                   FROM lz_scc_file_metrics"""
+            ).fetchone()
+            if row and row[0]:
+                metrics["total_files"] = int(row[0])
+                metrics["total_code_lines"] = int(row[1] or 0)
+                metrics["comment_ratio"] = round(float(row[2] or 0.0), 4)
+        except duckdb.Error:
+            pass
+        try:
+            row = conn.execute(
+                """SELECT SUM(total_lines), SUM(duplicate_lines)
+                   FROM lz_pmd_cpd_file_metrics"""
+            ).fetchone()
+            if row and row[0]:
+                metrics["duplication_percentage"] = round(
+                    (row[1] or 0) / row[0] * 100, 2
+                )
+        except duckdb.Error:
+            pass
+    finally:
+        conn.close()
+    return metrics
+
+
+def health_score(result: FleetRepoResult) -> float:
+    """Score one repo 0-100 from its scan outcome and metrics.
+
+    Failed scans score 0. Duplication deducts
+    DUPLICATION_PENALTY_PER_PCT points per percent (capped); a comment
+    ratio under LOW_COMMENT_THRESHOLD deducts a flat penalty.
+    """
+    if result.status != "completed":
+        return 0.0
+    score = 100.0
+    duplication = result.metrics.get("duplication_percentage")
+    if duplication is not None:
+        score -= min(duplication * DUPLICATION_PENALTY_PER_PCT, MAX_DUPLICATION_PENALTY)
+    comment_ratio = result.metrics.get("comment_ratio")
+    if comment_ratio is not None and comment_ratio < LOW_COMMENT_THRESHOLD:
+        score -= LOW_COMMENT_PENALTY
+    return round(max(score, 0.0), 1)
+
+
+def scan_fleet(
+    manifest_path: Path,
+    workdir: Path,
+    runner=None,
+    metrics_loader=repo_metrics,
+) -> dict:
+    """Scan every manifest repo and build the aggregate report."""
+    repos, settings = load_manifest(manifest_path)
+    workdir.mkdir(parents=True, exist_ok=True)
+
+    with ThreadPoolExecutor(max_workers=int(settings["max_parallel"])) as executor:
+        results = list(executor.map(
+            lambda repo: scan_repo(repo, workdir, settings, runner=runner),
+            repos,
+        ))
+
+    for result in results:
+        if result.status == "completed":
+            try:
+                result.metrics = metrics_loader(Path(result.db_path))
+            except Exception as exc:  # metrics are best-effort
+                result.error = f"metrics unavailable: {exc}"
+
+    completed = [r for r in results if r.status == "completed"]
+    total_lines = sum(r.metrics.get("total_code_lines", 0) for r in completed)
+    weighted_duplication = sum(
+        r.metrics.get("duplication_percentage", 0.0) * r.metrics.get("total_code_lines", 0)
+        for r in completed
+    )
+    return {
+        "generated_at": datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        "manifest": str(manifest_path),
+        "summary": {
+            "repos_total": len(results),
+            "repos_completed": len(completed),
+            "repos_failed": len(results) - len(completed),
+            "portfolio_code_lines": total_lines,
+            "portfolio_duplication_percentage": (
+                round(weighted_duplication / total_lines, 2) if total_lines else 0.0
+            ),
+        },
+        "repos": sorted(
+            (result.to_dict() for result in results),
+            key=lambda entry: entry["health_score"],
+        ),
+    }
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description="Caldera fleet scanner.")
+    parser.add_argument("command", choices=["scan"])
+    parser.add_argument("manifest", help="Fleet manifest YAML")
+    parser.add_argument("--workdir", default="/tmp/caldera_fleet")
+    parser.add_argument("--report", help="Report path (default <workdir>/fleet-report.json)")
+    args = parser.parse_args()
+
+    workdir = Path(args.workdir)
+    report = scan_fleet(Path(args.manifest), workdir)
+    report_path = Path(args.report) if args.report else workdir / REPORT_NAME
+    report_path.parent.mkdir(parents=True, exist_ok=True)
+    report_path.write_text(json.dumps(report, indent=2))
+
+    summary = report["summary"]
+    print(
+        f"Scanned {summary['repos_completed']}/{summary['repos_total']} repos; "
+        f"portfolio duplication {summary['portfolio_duplication_percentage']}%"
+    )
+    print(f"Report: {report_path}")
+    return 0 if summary["repos_failed"] == 0 else 1
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
@@ -8,6 +8,7 @@ import os
 import subprocess
 import sys
 import time
+import tomllib
 import uuid
 from dataclasses import dataclass
 from datetime import datetime, timezone
@@ -28,11 +29,32 @@ except ImportError:
 sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
 sys.path.insert(0, str(Path(__file__).resolve().parents[2]))
 
-from persistence.adapters import CoverageAdapter, DependenseeAdapter, DevskimAdapter, DotcoverAdapter, GitBlameScannerAdapter, GitFameAdapter, GitSizerAdapter, GitleaksAdapter, LayoutAdapter, LizardAdapter, PmdCpdAdapter, RoslynAdapter, ScancodeAdapter, SccAdapter, SemgrepAdapter, SonarqubeAdapter, SymbolScannerAdapter, TrivyAdapter
+from common.input_resolver import (
+    DEFAULT_MAX_WORKSPACE_MB,
+    InputResolutionError,
+    resolve_input,
+)
+from shared.observability.progress import configure_emitter, get_emitter
+from shared.observability.tracing import get_tracer
+
+from cancellation import CancellationToken, RunInterrupted, run_cancellable
+from checkpoint import DEFAULT_CHECKPOINT_DIR, RunCheckpoint
+from resource_limits import (
+    ResourceLimits,
+    ResourceLimitsConfig,
+    check_output_size,
+    load_resource_limits,
+)
+from persistence.backend import apply_migrations, connect_database
+from persistence.adapters import BanditAdapter, CheckovAdapter, CoverageAdapter, DependenseeAdapter, DevskimAdapter, DotcoverAdapter, GitBlameScannerAdapter, GitFameAdapter, GitSizerAdapter, GitleaksAdapter, GolangciAdapter, JscpdAdapter, LayoutAdapter, LizardAdapter, PmdCpdAdapter, RoslynAdapter, RustApiAdapter, RustDeadcodeAdapter, ScancodeAdapter, SccAdapter, SemgrepAdapter, ShellcheckAdapter, SonarqubeAdapter, SqlfluffAdapter, SymbolScannerAdapter, TodoScannerAdapter, TrivyAdapter
 from persistence.adapters.base_adapter import BaseAdapter
 from persistence.entities import CollectionRun, ToolRun
+from persistence.raw_store import DEFAULT_RAW_STORE_ROOT, RawResultStore
+from profiling import build_profile, format_profile, write_profile
 from persistence.repositories import (
+    BanditRepository,
     BaseRepository,
+    CheckovRepository,
     CollectionRunRepository,
     CoverageRepository,
     DependenseeRepository,
@@ -42,15 +64,22 @@ from persistence.repositories import (
     GitFameRepository,
     GitSizerRepository,
     GitleaksRepository,
+    GolangciRepository,
+    JscpdRepository,
     LayoutRepository,
     LizardRepository,
     PmdCpdRepository,
     RoslynRepository,
+    RustApiRepository,
+    RustDeadcodeRepository,
     ScancodeRepository,
     SccRepository,
     SemgrepRepository,
+    ShellcheckRepository,
     SonarqubeRepository,
+    SqlfluffRepository,
     SymbolScannerRepository,
+    TodoScannerRepository,
     ToolRunRepository,
     TrivyRepository,
 )
@@ -73,7 +102,11 @@ class ToolIngestionConfig:
     validate_metadata: bool = True  # Whether to validate standard metadata structure
 
 
-def ensure_schema(conn: duckdb.DuckDBPyConnection, schema_path: Path) -> None:
+def ensure_schema(conn, schema_path: Path) -> None:
+    if getattr(conn, "dialect", "duckdb") == "postgres":
+        apply_migrations(conn, schema_path)
+        return
+
     exists = conn.execute(
         """
         SELECT 1 FROM information_schema.tables
@@ -192,6 +225,8 @@ def run_tool_make(
     output_dir: Path,
     logger: OrchestratorLogger,
     extra_env: dict[str, str] | None = None,
+    token: CancellationToken | None = None,
+    limits: ResourceLimits | None = None,
 ) -> None:
     env = os.environ.copy()
     env.update(
@@ -215,14 +250,22 @@ def run_tool_make(
     env["COMMIT"] = commit if _commit_is_git_commit(repo_path, commit) else ("0" * 40)
     if extra_env:
         env.update(extra_env)
-    subprocess.run(
+    # Each tool runs in its own process group so cancellation can kill the
+    # whole tree (make and any java/semgrep grandchildren), not just make.
+    # Configured rlimits are set in the child before exec and inherited by
+    # everything it spawns.
+    limits = limits or ResourceLimits()
+    run_cancellable(
         ["make", "analyze"],
+        token,
         cwd=tool_root,
         env=env,
         stdout=logger.log_pipe(),
         stderr=logger.log_pipe(),
+        preexec_fn=limits.preexec_fn(),
         check=True,
     )
+    check_output_size(output_dir, limits, tool_root.name)
 
 
 def _default_output_path(tool: ToolConfig, run_id: str, output_root: Path | None) -> Path:
@@ -242,6 +285,59 @@ def _discover_outputs(output_root: Path, run_id: str) -> dict[str, Path]:
     return outputs
 
 
+# Native analyzers only: quick single-binary tools, suitable for
+# pre-commit and PR scans. The other built-in presets are "standard"
+# (follows [tools] enabled) and "deep" (every tool, including the
+# git history analyzers) for nightly runs.
+_FAST_PROFILE_TOOLS = (
+    "layout-scanner",
+    "scc",
+    "lizard",
+    "gitleaks",
+    "todo-scanner",
+)
+
+
+def _resolve_profile_tools(profile: str, caldera_toml: Path) -> set[str] | None:
+    """Tool set for a named scan profile; None means run everything.
+
+    A ``[profile.<name>] tools`` table in caldera.toml takes precedence,
+    so the built-in fast/standard/deep presets can be tuned per repo and
+    extra profiles defined. ``standard`` defers to ``[tools] enabled``.
+    """
+    if caldera_toml.exists():
+        table = tomllib.loads(caldera_toml.read_text()).get("profile", {}).get(profile)
+        if table is not None:
+            tools = table.get("tools")
+            if tools is None:
+                raise SystemExit(f"[profile.{profile}] in {caldera_toml} has no 'tools' list")
+            return {str(name) for name in tools}
+    if profile == "fast":
+        return set(_FAST_PROFILE_TOOLS)
+    if profile == "standard":
+        return _tools_enabled_from_config(caldera_toml)
+    if profile == "deep":
+        return None
+    raise SystemExit(
+        f"Unknown profile '{profile}': built-ins are fast, standard, deep; "
+        f"others need a [profile.{profile}] table in caldera.toml"
+    )
+
+
+def _tools_enabled_from_config(caldera_toml: Path) -> set[str] | None:
+    """The ``[tools] enabled`` list from caldera.toml, or None when absent.
+
+    ``caldera init`` generates this section; tools not on the list are
+    skipped just like ``--skip-tools`` entries.
+    """
+    if not caldera_toml.exists():
+        return None
+    enabled = tomllib.loads(caldera_toml.read_text()).get("tools", {}).get("enabled")
+    if enabled is None:
+        return None
+    return {str(name) for name in enabled}
+
+
 # Tool configurations for the orchestrator
 TOOL_CONFIGS = [
     ToolConfig("layout-scanner", "src/tools/layout-scanner", {"NO_GITIGNORE": "1"}),
@@ -264,7 +360,16 @@ TOOL_CONFIGS = [
     ToolConfig("symbol-scanner", "src/tools/symbol-scanner"),
     ToolConfig("scancode", "src/tools/scancode"),
     ToolConfig("pmd-cpd", "src/tools/pmd-cpd"),
+    ToolConfig("jscpd", "src/tools/jscpd"),
     ToolConfig("devskim", "src/tools/devskim"),
+    ToolConfig("bandit", "src/tools/bandit"),
+    ToolConfig("shellcheck", "src/tools/shellcheck"),
+    ToolConfig("checkov", "src/tools/checkov"),
+    ToolConfig("golangci", "src/tools/golangci"),
+    ToolConfig("sqlfluff", "src/tools/sqlfluff"),
+    ToolConfig("todo-scanner", "src/tools/todo-scanner"),
+    ToolConfig("rust-deadcode", "src/tools/rust-deadcode"),
+    ToolConfig("rust-api", "src/tools/rust-api"),
     ToolConfig("dotcover", "src/tools/dotcover"),
     ToolConfig("git-fame", "src/tools/git-fame"),
     ToolConfig("git-sizer", "src/tools/git-sizer"),
@@ -281,7 +386,7 @@ def _get_or_create_collection_run(
     """Get existing or create new collection run. Returns (collection_run_id, run_id)."""
     existing = collection_repo.get_by_repo_commit(args.repo_id, args.commit)
     if existing:
-        if not args.replace:
+        if not args.replace and not getattr(args, "resume", False):
             raise SystemExit("Collection run exists for repo+commit. Use --replace to overwrite.")
         if existing.run_id != args.run_id:
             logger.info(f"Replacing existing run_id {existing.run_id} (overrides {args.run_id})")
@@ -315,16 +420,36 @@ def _run_tools(
     logger: OrchestratorLogger,
     output_root: Path | None,
     show_progress: bool = True,
+    checkpoint: RunCheckpoint | None = None,
+    token: CancellationToken | None = None,
+    limits_config: ResourceLimitsConfig | None = None,
 ) -> dict[str, Path]:
-    """Run all configured tools and return their output paths."""
+    """Run all configured tools and return their output paths.
+
+    When a checkpoint is given, tools it records as completed (with outputs
+    still on disk) are skipped, and each finished tool is checkpointed so an
+    interrupted scan can be resumed with --resume.
+    """
     outputs: dict[str, Path] = {}
     total_tools = len(tool_configs)
     use_rich = show_progress and RICH_AVAILABLE and sys.stdout.isatty()
     console = Console() if use_rich else None
 
     for idx, tool in enumerate(tool_configs, 1):
+        if token:
+            token.raise_if_cancelled()
         output_path = _default_output_path(tool, run_id, output_root)
+        if checkpoint:
+            resumed_output = checkpoint.completed_output(tool.name)
+            if resumed_output:
+                logger.info(f"[{idx}/{total_tools}] {tool.name} (resumed from checkpoint)")
+                get_emitter().emit("tool_skipped", tool=tool.name, run_id=run_id, reason="checkpoint")
+                outputs[tool.name] = resumed_output
+                continue
         tool_start = time.perf_counter()
+        get_emitter().emit(
+            "tool_started", tool=tool.name, run_id=run_id, index=idx, total=total_tools
+        )
 
         if use_rich and console:
             with Progress(
@@ -334,6 +459,25 @@ def _run_tools(
                 transient=True,
             ) as progress:
                 progress.add_task(f"[{idx}/{total_tools}] {tool.name}...", total=None)
+                with get_tracer().span("tool_run", tool_name=tool.name, run_id=run_id):
+                    run_tool_make(
+                        Path(tool.path),
+                        repo_path,
+                        repo_name,
+                        run_id,
+                        repo_id,
+                        branch,
+                        commit,
+                        output_path.parent,
+                        logger,
+                        extra_env=tool.extra_env,
+                        token=token,
+                        limits=limits_config.for_tool(tool.name) if limits_config else None,
+                    )
+            duration = time.perf_counter() - tool_start
+            console.print(f"[green]✓[/] [{idx}/{total_tools}] {tool.name} ({duration:.1f}s)")
+        else:
+            with get_tracer().span("tool_run", tool_name=tool.name, run_id=run_id):
                 run_tool_make(
                     Path(tool.path),
                     repo_path,
@@ -345,25 +489,21 @@ def _run_tools(
                     output_path.parent,
                     logger,
                     extra_env=tool.extra_env,
+                    token=token,
+                    limits=limits_config.for_tool(tool.name) if limits_config else None,
                 )
             duration = time.perf_counter() - tool_start
-            console.print(f"[green]✓[/] [{idx}/{total_tools}] {tool.name} ({duration:.1f}s)")
-        else:
-            run_tool_make(
-                Path(tool.path),
-                repo_path,
-                repo_name,
-                run_id,
-                repo_id,
-                branch,
-                commit,
-                output_path.parent,
-                logger,
-                extra_env=tool.extra_env,
-            )
-            duration = time.perf_counter() - tool_start
             logger.info(f"[{idx}/{total_tools}] {tool.name} ({duration:.1f}s)")
 
+        get_emitter().emit(
+            "tool_finished",
+            tool=tool.name,
+            run_id=run_id,
+            duration_seconds=round(duration, 3),
+            output_path=str(output_path),
+        )
+        if checkpoint:
+            checkpoint.mark_tool_done(tool.name, output_path, duration)
         outputs[tool.name] = output_path
     return outputs
 
@@ -383,7 +523,16 @@ TOOL_INGESTION_CONFIGS = [
     ToolIngestionConfig("symbol-scanner", SymbolScannerAdapter, SymbolScannerRepository),
     ToolIngestionConfig("scancode", ScancodeAdapter, ScancodeRepository),
     ToolIngestionConfig("pmd-cpd", PmdCpdAdapter, PmdCpdRepository),
+    ToolIngestionConfig("jscpd", JscpdAdapter, JscpdRepository),
     ToolIngestionConfig("devskim", DevskimAdapter, DevskimRepository),
+    ToolIngestionConfig("bandit", BanditAdapter, BanditRepository),
+    ToolIngestionConfig("shellcheck", ShellcheckAdapter, ShellcheckRepository),
+    ToolIngestionConfig("checkov", CheckovAdapter, CheckovRepository),
+    ToolIngestionConfig("golangci", GolangciAdapter, GolangciRepository),
+    ToolIngestionConfig("sqlfluff", SqlfluffAdapter, SqlfluffRepository),
+    ToolIngestionConfig("todo-scanner", TodoScannerAdapter, TodoScannerRepository),
+    ToolIngestionConfig("rust-deadcode", RustDeadcodeAdapter, RustDeadcodeRepository),
+    ToolIngestionConfig("rust-api", RustApiAdapter, RustApiRepository),
     ToolIngestionConfig("dotcover", DotcoverAdapter, DotcoverRepository),
     ToolIngestionConfig("dependensee", DependenseeAdapter, DependenseeRepository),
     ToolIngestionConfig("coverage-ingest", CoverageAdapter, CoverageRepository),
@@ -409,7 +558,16 @@ def ingest_outputs(
     symbol_scanner_output: Path | None = None,
     scancode_output: Path | None = None,
     pmd_cpd_output: Path | None = None,
+    jscpd_output: Path | None = None,
     devskim_output: Path | None = None,
+    bandit_output: Path | None = None,
+    shellcheck_output: Path | None = None,
+    checkov_output: Path | None = None,
+    golangci_output: Path | None = None,
+    sqlfluff_output: Path | None = None,
+    todo_scanner_output: Path | None = None,
+    rust_deadcode_output: Path | None = None,
+    rust_api_output: Path | None = None,
     dotcover_output: Path | None = None,
     git_fame_output: Path | None = None,
     git_sizer_output: Path | None = None,
@@ -418,6 +576,7 @@ def ingest_outputs(
     coverage_output: Path | None = None,
     schema_path: Path = None,
     logger: OrchestratorLogger | None = None,
+    strict: bool = False,
 ) -> None:
     ensure_schema(conn, schema_path)
     run_repo = ToolRunRepository(conn)
@@ -432,7 +591,10 @@ def ingest_outputs(
         payload.get("metadata", {}), repo_id, run_id,
         expected_commit=commit,
     )
-    LayoutAdapter(run_repo, layout_repo, repo_path, log_fn).persist(payload)
+    layout_adapter = LayoutAdapter(run_repo, layout_repo, repo_path, log_fn)
+    layout_adapter.strict = strict
+    layout_adapter.persist(payload)
+    _log_adapter_diagnostics(layout_adapter, log_fn)
 
     # Map tool names to output paths
     tool_outputs: dict[str, Path | None] = {
@@ -446,7 +608,16 @@ def ingest_outputs(
         "symbol-scanner": symbol_scanner_output,
         "scancode": scancode_output,
         "pmd-cpd": pmd_cpd_output,
+        "jscpd": jscpd_output,
         "devskim": devskim_output,
+        "bandit": bandit_output,
+        "shellcheck": shellcheck_output,
+        "checkov": checkov_output,
+        "golangci": golangci_output,
+        "sqlfluff": sqlfluff_output,
+        "todo-scanner": todo_scanner_output,
+        "rust-deadcode": rust_deadcode_output,
+        "rust-api": rust_api_output,
         "dotcover": dotcover_output,
         "git-fame": git_fame_output,
         "git-blame-scanner": git_blame_scanner_output,
@@ -462,23 +633,69 @@ def ingest_outputs(
             continue
 
         payload = load_payload(output_path)
-        if config.validate_metadata:
-            validate_payload(
-                payload.get("metadata", {}), repo_id, run_id,
-                expected_commit=commit,
-                expected_tool=config.name,
-            )
+        get_emitter().emit("ingest_started", tool=config.name, run_id=run_id)
+        ingest_start = time.perf_counter()
+        with get_tracer().span("adapter_ingest", tool_name=config.name, run_id=run_id):
+            _ingest_single(config, payload, conn, run_repo, layout_repo, repo_path,
+                           repo_id, run_id, commit, log_fn, strict)
+        get_emitter().emit(
+            "ingest_finished",
+            tool=config.name,
+            run_id=run_id,
+            duration_seconds=round(time.perf_counter() - ingest_start, 3),
+        )
+
+
+def _ingest_single(
+    config: ToolIngestionConfig,
+    payload: dict,
+    conn: duckdb.DuckDBPyConnection,
+    run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    repo_path: Path,
+    repo_id: str,
+    run_id: str,
+    commit: str,
+    log_fn: Callable[[str], None] | None,
+    strict: bool = False,
+) -> None:
+    if config.validate_metadata:
+        validate_payload(
+            payload.get("metadata", {}), repo_id, run_id,
+            expected_commit=commit,
+            expected_tool=config.name,
+        )
+
+    # Create adapter with appropriate repository
+    tool_repo = config.repo_class(conn) if config.repo_class else None
+    adapter = config.adapter_class(
+        run_repo,
+        layout_repo,
+        tool_repo,
+        repo_path,
+        log_fn,
+    )
+    adapter.strict = strict
+    adapter.persist(payload)
+    _log_adapter_diagnostics(adapter, log_fn)
 
-        # Create adapter with appropriate repository
-        tool_repo = config.repo_class(conn) if config.repo_class else None
-        adapter = config.adapter_class(
-            run_repo,
-            layout_repo,
-            tool_repo,
-            repo_path,
-            log_fn,
+
+def _log_adapter_diagnostics(
+    adapter: BaseAdapter, log_fn: Callable[[str], None] | None
+) -> None:
+    """Surface per-entry failures captured during a non-strict persist."""
+    if not adapter.diagnostics:
+        return
+    if log_fn:
+        log_fn(
+            f"WARN: {adapter.tool_name} persisted with "
+            f"{len(adapter.diagnostics)} isolated error(s)"
         )
-        adapter.persist(payload)
+    get_emitter().emit(
+        "adapter_diagnostics",
+        tool=adapter.tool_name,
+        count=len(adapter.diagnostics),
+    )
 
 
 def _resolve_dbt_cmd(dbt_bin: Path, repo_root: Path) -> list[str]:
@@ -525,8 +742,15 @@ def run_dbt(
 
 def main() -> int:
     parser = argparse.ArgumentParser(description="Caldera SoT orchestrator.")
-    parser.add_argument("--repo-path", required=True)
+    parser.add_argument(
+        "--repo-path", required=True,
+        help="Local directory, git URL (optionally url#branch), or archive (.tar.gz/.zip)",
+    )
     parser.add_argument("--repo-id", required=True)
+    parser.add_argument(
+        "--max-workspace-mb", type=int, default=DEFAULT_MAX_WORKSPACE_MB,
+        help="Disk budget for cloned/extracted temp workspaces",
+    )
     parser.add_argument("--run-id", default=str(uuid.uuid4()))
     parser.add_argument("--branch", default="main")
     parser.add_argument("--commit", default="0" * 40)
@@ -545,7 +769,16 @@ def main() -> int:
     parser.add_argument("--symbol-scanner-output", type=str)
     parser.add_argument("--scancode-output", type=str)
     parser.add_argument("--pmd-cpd-output", type=str)
+    parser.add_argument("--jscpd-output", type=str)
     parser.add_argument("--devskim-output", type=str)
+    parser.add_argument("--bandit-output", type=str)
+    parser.add_argument("--shellcheck-output", type=str)
+    parser.add_argument("--checkov-output", type=str)
+    parser.add_argument("--golangci-output", type=str)
+    parser.add_argument("--sqlfluff-output", type=str)
+    parser.add_argument("--todo-scanner-output", type=str)
+    parser.add_argument("--rust-deadcode-output", type=str)
+    parser.add_argument("--rust-api-output", type=str)
     parser.add_argument("--dotcover-output", type=str)
     parser.add_argument("--git-fame-output", type=str)
     parser.add_argument("--git-sizer-output", type=str)
@@ -555,14 +788,70 @@ def main() -> int:
     parser.add_argument("--run-tools", action="store_true")
     parser.add_argument("--run-dbt", action="store_true")
     parser.add_argument("--replace", action="store_true")
+    parser.add_argument(
+        "--strict",
+        action="store_true",
+        help="Fail ingestion on any bad entry instead of isolating it as a diagnostic",
+    )
+    parser.add_argument(
+        "--resume",
+        action="store_true",
+        help="Resume an interrupted scan from its checkpoint (requires --run-id)",
+    )
+    parser.add_argument(
+        "--checkpoint-dir", default=str(DEFAULT_CHECKPOINT_DIR),
+        help="Directory for per-run tool checkpoints",
+    )
+    parser.add_argument(
+        "--timings",
+        action="store_true",
+        help="Print a per-phase/per-tool performance breakdown after the run",
+    )
+    parser.add_argument(
+        "--archive-raw",
+        action="store_true",
+        help="Compress raw tool outputs into the raw result store after ingest",
+    )
+    parser.add_argument(
+        "--raw-store-root", default=str(DEFAULT_RAW_STORE_ROOT),
+        help="Directory for compressed raw outputs (default: ~/.caldera/raw)",
+    )
+    parser.add_argument(
+        "--config", default=None,
+        help="Path to caldera.toml (defaults to repo root; supplies [limits] resource caps)",
+    )
+    parser.add_argument(
+        "--profile", default=None,
+        help="Scan preset: fast/standard/deep or a [profile.<name>] from caldera.toml",
+    )
     parser.add_argument("--no-progress", action="store_true", help="Disable rich progress display")
+    parser.add_argument(
+        "--progress",
+        choices=["ndjson"],
+        help="Emit machine-readable progress events (one JSON object per line)",
+    )
+    parser.add_argument(
+        "--progress-file",
+        help="Write progress events to this file or named pipe instead of stderr",
+    )
     parser.add_argument("--dbt-bin", default="src/sot-engine/.venv-dbt/bin/dbt")
     parser.add_argument("--dbt-project-dir", default="src/sot-engine/dbt")
     parser.add_argument("--dbt-profiles-dir", default="src/sot-engine/dbt")
     parser.add_argument("--log-path", default=None)
     args = parser.parse_args()
 
-    repo_path = Path(args.repo_path)
+    try:
+        with get_tracer().span("resolve_input", repo_path=args.repo_path):
+            resolved_input = resolve_input(args.repo_path, max_workspace_mb=args.max_workspace_mb)
+    except InputResolutionError as exc:
+        _log.error("Cannot resolve scan input: %s", exc)
+        return 1
+    repo_path = resolved_input.path
+    if resolved_input.temporary:
+        _log.info(
+            "Materialized %s input in temp workspace %s",
+            resolved_input.source_kind, repo_path,
+        )
     if _is_fallback_commit(args.commit):
         args.commit = _compute_content_hash(repo_path)
         _log.info("Non-git repo: computed content hash %s…", args.commit[:12])
@@ -573,6 +862,13 @@ def main() -> int:
     if not log_path.is_absolute():
         log_path = repo_root / log_path
     logger = OrchestratorLogger(log_path)
+    config_path = Path(args.config) if args.config else repo_root / "caldera.toml"
+    limits_config = load_resource_limits(config_path)
+    token = CancellationToken()
+    token.install()
+    configure_emitter(
+        args.progress, Path(args.progress_file) if args.progress_file else None
+    )
 
     layout_output = Path(args.layout_output) if args.layout_output else None
     scc_output = Path(args.scc_output) if args.scc_output else None
@@ -585,7 +881,16 @@ def main() -> int:
     symbol_scanner_output = Path(args.symbol_scanner_output) if args.symbol_scanner_output else None
     scancode_output = Path(args.scancode_output) if args.scancode_output else None
     pmd_cpd_output = Path(args.pmd_cpd_output) if args.pmd_cpd_output else None
+    jscpd_output = Path(args.jscpd_output) if args.jscpd_output else None
     devskim_output = Path(args.devskim_output) if args.devskim_output else None
+    bandit_output = Path(args.bandit_output) if args.bandit_output else None
+    shellcheck_output = Path(args.shellcheck_output) if args.shellcheck_output else None
+    checkov_output = Path(args.checkov_output) if args.checkov_output else None
+    golangci_output = Path(args.golangci_output) if args.golangci_output else None
+    sqlfluff_output = Path(args.sqlfluff_output) if args.sqlfluff_output else None
+    todo_scanner_output = Path(args.todo_scanner_output) if args.todo_scanner_output else None
+    rust_deadcode_output = Path(args.rust_deadcode_output) if args.rust_deadcode_output else None
+    rust_api_output = Path(args.rust_api_output) if args.rust_api_output else None
     dotcover_output = Path(args.dotcover_output) if args.dotcover_output else None
     git_fame_output = Path(args.git_fame_output) if args.git_fame_output else None
     git_sizer_output = Path(args.git_sizer_output) if args.git_sizer_output else None
@@ -596,7 +901,7 @@ def main() -> int:
     try:
         logger.info(f"Log file: {logger.log_path}")
         logger.info(f"Repo: {repo_path} (repo_id={args.repo_id})")
-        conn = duckdb.connect(args.db_path)
+        conn = connect_database(args.db_path)
         ensure_schema(conn, schema_path)
         collection_repo = CollectionRunRepository(conn)
 
@@ -605,6 +910,10 @@ def main() -> int:
         )
 
         logger.info(f"Run: {args.run_id} @ {args.branch}:{args.commit}")
+        get_emitter().emit(
+            "pipeline_started", run_id=args.run_id, repo_id=args.repo_id,
+            branch=args.branch, commit=args.commit,
+        )
 
         output_root = Path(args.output_root).resolve() if args.output_root else None
 
@@ -615,7 +924,23 @@ def main() -> int:
                 output_root,
             )
 
+        raw_output_paths: dict[str, Path] = {}
+        resumed_tools: list[str] = []
+        checkpoint: RunCheckpoint | None = None
         if args.run_tools:
+            checkpoint_dir = Path(args.checkpoint_dir)
+            if args.resume:
+                checkpoint = RunCheckpoint.load(
+                    args.run_id, args.repo_id, args.commit, checkpoint_dir
+                )
+                resumed_tools = list(checkpoint.completed_tools)
+                logger.info(
+                    f"Resuming from checkpoint: {len(resumed_tools)} tool(s) already done"
+                )
+            else:
+                checkpoint = RunCheckpoint.start(
+                    args.run_id, args.repo_id, args.commit, checkpoint_dir
+                )
             start = time.perf_counter()
             logger.info("Step 1/3: Run tools (layout, scc, lizard, roslyn-analyzers, semgrep, sonarqube, trivy, gitleaks)")
             skip_tools = {
@@ -623,18 +948,36 @@ def main() -> int:
                 for name in (args.skip_tools.split(",") if args.skip_tools else [])
                 if name.strip()
             }
-            outputs = _run_tools(
-                [tool for tool in TOOL_CONFIGS if tool.name not in skip_tools],
-                repo_path,
-                repo_name,
-                args.run_id,
-                args.repo_id,
-                args.branch,
-                args.commit,
-                logger,
-                output_root,
-                show_progress=not args.no_progress,
+            enabled_tools = (
+                _resolve_profile_tools(args.profile, config_path)
+                if args.profile
+                else _tools_enabled_from_config(config_path)
             )
+            if enabled_tools is not None:
+                disabled = {t.name for t in TOOL_CONFIGS} - enabled_tools
+                if disabled:
+                    source = f"profile '{args.profile}'" if args.profile else "[tools] enabled"
+                    logger.info(
+                        f"Skipping {len(disabled)} tool(s) not in {source}: "
+                        f"{', '.join(sorted(disabled))}"
+                    )
+                skip_tools |= disabled
+            with get_tracer().span("run_tools", run_id=args.run_id, repo_id=args.repo_id):
+                outputs = _run_tools(
+                    [tool for tool in TOOL_CONFIGS if tool.name not in skip_tools],
+                    repo_path,
+                    repo_name,
+                    args.run_id,
+                    args.repo_id,
+                    args.branch,
+                    args.commit,
+                    logger,
+                    output_root,
+                    show_progress=not args.no_progress,
+                    checkpoint=checkpoint,
+                    token=token,
+                    limits_config=limits_config,
+                )
             layout_output = outputs.get("layout-scanner", layout_output)
             scc_output = outputs.get("scc", scc_output)
             lizard_output = outputs.get("lizard", lizard_output)
@@ -646,7 +989,16 @@ def main() -> int:
             symbol_scanner_output = outputs.get("symbol-scanner", symbol_scanner_output)
             scancode_output = outputs.get("scancode", scancode_output)
             pmd_cpd_output = outputs.get("pmd-cpd", pmd_cpd_output)
+            jscpd_output = outputs.get("jscpd", jscpd_output)
             devskim_output = outputs.get("devskim", devskim_output)
+            bandit_output = outputs.get("bandit", bandit_output)
+            shellcheck_output = outputs.get("shellcheck", shellcheck_output)
+            checkov_output = outputs.get("checkov", checkov_output)
+            golangci_output = outputs.get("golangci", golangci_output)
+            sqlfluff_output = outputs.get("sqlfluff", sqlfluff_output)
+            todo_scanner_output = outputs.get("todo-scanner", todo_scanner_output)
+            rust_deadcode_output = outputs.get("rust-deadcode", rust_deadcode_output)
+            rust_api_output = outputs.get("rust-api", rust_api_output)
             dotcover_output = outputs.get("dotcover", dotcover_output)
             git_fame_output = outputs.get("git-fame", git_fame_output)
             git_sizer_output = outputs.get("git-sizer", git_sizer_output)
@@ -655,10 +1007,12 @@ def main() -> int:
             logger.info(f"Completed tools in {_format_duration(time.perf_counter() - start)}")
             for name, path in outputs.items():
                 logger.info(f"{name} output: {path}")
+            raw_output_paths = outputs
         elif output_root:
             # Bundle/ingest mode: discover outputs under output_root when tools
             # were executed elsewhere (e.g. another machine or container).
             discovered = _discover_outputs(output_root, args.run_id)
+            raw_output_paths = discovered
             layout_output = discovered.get("layout-scanner", layout_output)
             scc_output = discovered.get("scc", scc_output)
             lizard_output = discovered.get("lizard", lizard_output)
@@ -670,7 +1024,16 @@ def main() -> int:
             symbol_scanner_output = discovered.get("symbol-scanner", symbol_scanner_output)
             scancode_output = discovered.get("scancode", scancode_output)
             pmd_cpd_output = discovered.get("pmd-cpd", pmd_cpd_output)
+            jscpd_output = discovered.get("jscpd", jscpd_output)
             devskim_output = discovered.get("devskim", devskim_output)
+            bandit_output = discovered.get("bandit", bandit_output)
+            shellcheck_output = discovered.get("shellcheck", shellcheck_output)
+            checkov_output = discovered.get("checkov", checkov_output)
+            golangci_output = discovered.get("golangci", golangci_output)
+            sqlfluff_output = discovered.get("sqlfluff", sqlfluff_output)
+            todo_scanner_output = discovered.get("todo-scanner", todo_scanner_output)
+            rust_deadcode_output = discovered.get("rust-deadcode", rust_deadcode_output)
+            rust_api_output = discovered.get("rust-api", rust_api_output)
             dotcover_output = discovered.get("dotcover", dotcover_output)
             git_fame_output = discovered.get("git-fame", git_fame_output)
             git_sizer_output = discovered.get("git-sizer", git_sizer_output)
@@ -678,67 +1041,117 @@ def main() -> int:
             dependensee_output = discovered.get("dependensee", dependensee_output)
             coverage_output = discovered.get("coverage-ingest", coverage_output)
 
+        token.raise_if_cancelled()
         start = time.perf_counter()
         logger.info("Step 2/3: Ingest outputs into DuckDB")
-        ingest_outputs(
-            conn,
-            args.repo_id,
-            collection_run_id,
-            args.run_id,
-            args.branch,
-            args.commit,
-            repo_path,
-            layout_output,
-            scc_output,
-            lizard_output,
-            roslyn_output,
-            semgrep_output,
-            sonarqube_output,
-            trivy_output,
-            gitleaks_output,
-            symbol_scanner_output,
-            scancode_output,
-            pmd_cpd_output,
-            devskim_output,
-            dotcover_output,
-            git_fame_output,
-            git_sizer_output,
-            git_blame_scanner_output,
-            dependensee_output,
-            coverage_output,
-            schema_path,
-            logger,
-        )
+        with get_tracer().span("ingest_outputs", run_id=args.run_id, repo_id=args.repo_id):
+            ingest_outputs(
+                conn,
+                args.repo_id,
+                collection_run_id,
+                args.run_id,
+                args.branch,
+                args.commit,
+                repo_path,
+                layout_output,
+                scc_output,
+                lizard_output,
+                roslyn_output,
+                semgrep_output,
+                sonarqube_output,
+                trivy_output,
+                gitleaks_output,
+                symbol_scanner_output,
+                scancode_output,
+                pmd_cpd_output,
+                jscpd_output,
+                devskim_output,
+                bandit_output,
+                shellcheck_output,
+                checkov_output,
+                golangci_output,
+                sqlfluff_output,
+                todo_scanner_output,
+                rust_deadcode_output,
+                rust_api_output,
+                dotcover_output,
+                git_fame_output,
+                git_sizer_output,
+                git_blame_scanner_output,
+                dependensee_output,
+                coverage_output,
+                schema_path,
+                logger,
+                strict=args.strict,
+            )
         logger.info(
             f"Ingested into {args.db_path} in {_format_duration(time.perf_counter() - start)}"
         )
+        if args.archive_raw and raw_output_paths:
+            raw_store = RawResultStore(Path(args.raw_store_root))
+            raw_bytes = archived_bytes = archived_count = 0
+            for tool_name, output_path in sorted(raw_output_paths.items()):
+                if not output_path.exists():
+                    continue
+                raw_bytes += output_path.stat().st_size
+                archive_path = raw_store.archive(args.run_id, tool_name, output_path)
+                archived_bytes += archive_path.stat().st_size
+                archived_count += 1
+            if archived_count:
+                logger.info(
+                    f"Archived {archived_count} raw output(s): "
+                    f"{raw_bytes:,} -> {archived_bytes:,} bytes in {raw_store.root}"
+                )
         conn.close()
         conn = None
 
+        token.raise_if_cancelled()
         if args.run_dbt:
             start = time.perf_counter()
             logger.info("Step 3/3: Build marts (dbt run/test)")
-            run_dbt(
-                Path(args.dbt_bin),
-                Path(args.dbt_project_dir),
-                Path(args.dbt_profiles_dir),
-                logger,
-            )
+            with get_tracer().span("run_dbt", run_id=args.run_id):
+                run_dbt(
+                    Path(args.dbt_bin),
+                    Path(args.dbt_project_dir),
+                    Path(args.dbt_profiles_dir),
+                    logger,
+                )
             logger.info(
                 f"dbt completed in {_format_duration(time.perf_counter() - start)}"
             )
 
-        conn = duckdb.connect(args.db_path)
+        conn = connect_database(args.db_path)
         collection_repo = CollectionRunRepository(conn)
         collection_repo.mark_status(
             collection_run_id, "completed", datetime.now(timezone.utc)
         )
+        if checkpoint:
+            checkpoint.clear()
+        get_emitter().emit("pipeline_finished", run_id=args.run_id, status="completed")
+        if args.timings:
+            profile = build_profile(get_tracer().finished_spans, resumed_tools=resumed_tools)
+            profile_path = log_path.with_suffix(".profile.json")
+            write_profile(profile, profile_path)
+            print(format_profile(profile))
+            logger.info(f"Timing profile: {profile_path}")
         logger.info("Done.")
         return 0
+    except RunInterrupted as exc:
+        # Partial results (tool outputs, any rows already ingested) stay on
+        # disk; the checkpoint is kept so the scan can continue with --resume.
+        logger.info(f"Run interrupted by {exc.signal_name}; checkpoint kept for --resume")
+        get_emitter().emit("pipeline_finished", run_id=args.run_id, status="interrupted")
+        if "collection_run_id" in locals():
+            conn = connect_database(args.db_path)
+            CollectionRunRepository(conn).mark_status(
+                collection_run_id, "interrupted", datetime.now(timezone.utc)
+            )
+        return 130
     except Exception:
         try:
+            get_emitter().emit("pipeline_finished", run_id=args.run_id, status="failed")
             if "collection_run_id" in locals():
-                conn = duckdb.connect(args.db_path)
+                conn = connect_database(args.db_path)
                 CollectionRunRepository(conn).mark_status(
                     collection_run_id, "failed", datetime.now(timezone.utc)
                 )
@@ -748,6 +1161,7 @@ def main() -> int:
         if "conn" in locals() and conn:
             conn.close()
         logger.close()
+        resolved_input.cleanup()
 
 
 if __name__ == "__main__":
@@ -1,4 +1,6 @@
+from .bandit_adapter import BanditAdapter
 from .base_adapter import BaseAdapter
+from .checkov_adapter import CheckovAdapter
 from .coverage_adapter import CoverageAdapter
 from .dependensee_adapter import DependenseeAdapter
 from .devskim_adapter import DevskimAdapter
@@ -7,19 +9,28 @@ from .git_blame_scanner_adapter import GitBlameScannerAdapter
 from .git_fame_adapter import GitFameAdapter
 from .git_sizer_adapter import GitSizerAdapter
 from .gitleaks_adapter import GitleaksAdapter
+from .golangci_adapter import GolangciAdapter
 from .layout_adapter import LayoutAdapter
 from .lizard_adapter import LizardAdapter
+from .jscpd_adapter import JscpdAdapter
 from .pmd_cpd_adapter import PmdCpdAdapter
 from .roslyn_adapter import RoslynAdapter
+from .rust_api_adapter import RustApiAdapter
+from .rust_deadcode_adapter import RustDeadcodeAdapter
 from .scancode_adapter import ScancodeAdapter
 from .scc_adapter import SccAdapter
 from .semgrep_adapter import SemgrepAdapter
+from .shellcheck_adapter import ShellcheckAdapter
 from .sonarqube_adapter import SonarqubeAdapter
+from .sqlfluff_adapter import SqlfluffAdapter
 from .symbol_scanner_adapter import SymbolScannerAdapter
+from .todo_scanner_adapter import TodoScannerAdapter
 from .trivy_adapter import TrivyAdapter
 
 __all__ = [
+    "BanditAdapter",
     "BaseAdapter",
+    "CheckovAdapter",
     "CoverageAdapter",
     "DependenseeAdapter",
     "DevskimAdapter",
@@ -28,14 +39,21 @@ __all__ = [
     "GitFameAdapter",
     "GitSizerAdapter",
     "GitleaksAdapter",
+    "GolangciAdapter",
     "LayoutAdapter",
     "LizardAdapter",
+    "JscpdAdapter",
     "PmdCpdAdapter",
     "RoslynAdapter",
+    "RustApiAdapter",
+    "RustDeadcodeAdapter",
     "ScancodeAdapter",
     "SccAdapter",
     "SemgrepAdapter",
+    "ShellcheckAdapter",
     "SonarqubeAdapter",
+    "SqlfluffAdapter",
     "SymbolScannerAdapter",
+    "TodoScannerAdapter",
     "TrivyAdapter",
 ]
@@ -4,7 +4,7 @@ from __future__ import annotations
 from abc import ABC, abstractmethod
 from datetime import datetime
 from pathlib import Path
-from typing import Any, Callable
+from typing import Any, Callable, Iterable
 
 import duckdb
 
@@ -34,8 +34,18 @@ class BaseAdapter(ABC):
     - table_ddl property
     - _do_persist() method
     - validate_quality() method
+
+    Error isolation: with ``strict = False`` a bad entry (quality error or
+    entity validation failure) is captured as a diagnostic and the rest of
+    the payload is still persisted. The orchestrator runs adapters in that
+    mode unless ``--strict`` is passed; direct adapter use keeps the
+    fail-fast default. Structural failures (JSON schema, landing zone
+    schema) always fail fast — they invalidate the whole payload.
     """
 
+    #: When True, any per-entry error aborts the whole persist (fail-fast).
+    strict: bool = True
+
     @property
     @abstractmethod
     def tool_name(self) -> str:
@@ -86,6 +96,8 @@ class BaseAdapter(ABC):
         self._layout_repo = layout_repo
         self._repo_root = repo_root
         self._logger = logger
+        #: Per-entry failures captured during the last persist() call.
+        self.diagnostics: list[dict[str, str]] = []
 
     @property
     def _conn(self) -> duckdb.DuckDBPyConnection:
@@ -177,20 +189,62 @@ class BaseAdapter(ABC):
         return errors
 
     def _raise_quality_errors(self, errors: list[str]) -> None:
-        """Log and raise quality validation errors.
+        """Log quality validation errors; raise only in strict mode.
 
         Common helper to reduce duplication in validate_quality() implementations.
+        In the default (non-strict) mode, errors become diagnostics and
+        persistence continues — entity ``__post_init__`` validation still
+        protects the landing zone from the offending entries.
 
         Args:
             errors: List of error messages to log and raise
 
         Raises:
-            ValueError: If errors list is non-empty
+            ValueError: If errors list is non-empty and strict mode is on
         """
         if errors:
             for error in errors:
                 self._log(f"DATA_QUALITY_ERROR: {error}")
-            raise ValueError(f"{self.tool_name} data quality validation failed ({len(errors)} errors)")
+            if self.strict:
+                raise ValueError(
+                    f"{self.tool_name} data quality validation failed ({len(errors)} errors)"
+                )
+            self.diagnostics.extend(
+                {"scope": "quality", "error": error} for error in errors
+            )
+
+    def map_entries_isolated(
+        self,
+        entries: Iterable[dict],
+        mapper: Callable[[dict], Iterable[Any]],
+        label_field: str = "path",
+    ) -> list[Any]:
+        """Map payload entries to entities, isolating per-entry failures.
+
+        Applies ``mapper`` to each entry (typically one file). An entry whose
+        mapping raises is recorded as a diagnostic and skipped so all other
+        entries still produce results; in strict mode the exception
+        propagates instead.
+
+        Args:
+            entries: Payload entries (usually file dicts)
+            mapper: Maps one entry to zero or more entities
+            label_field: Entry field used to label diagnostics
+
+        Returns:
+            Flat list of mapped entities from all successful entries
+        """
+        entities: list[Any] = []
+        for idx, entry in enumerate(entries):
+            try:
+                entities.extend(mapper(entry))
+            except Exception as exc:
+                if self.strict:
+                    raise
+                label = str(entry.get(label_field) or f"entry[{idx}]")
+                self.diagnostics.append({"scope": "entry", "entry": label, "error": str(exc)})
+                self._log(f"WARN: {self.tool_name} skipped {label}: {exc}")
+        return entities
 
     def validate_schema(self, payload: dict) -> None:
         """Validate payload against JSON schema.
@@ -246,6 +300,7 @@ class BaseAdapter(ABC):
         Returns:
             run_pk: Primary key of the inserted tool run
         """
+        self.diagnostics = []
         self.validate_schema(payload)
         self.ensure_lz_tables()
         self.validate_lz_schema()
@@ -0,0 +1,164 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any, Callable, Iterable
+
+from .base_adapter import BaseAdapter
+from ..entities import CheckovFinding
+from ..repositories import CheckovRepository, LayoutRepository, ToolRunRepository
+from ..validation import (
+    check_required,
+    validate_file_paths_in_entries,
+)
+
+SCHEMA_PATH = Path(__file__).resolve().parents[3] / "tools" / "checkov" / "schemas" / "output.schema.json"
+LZ_TABLES = {
+    "lz_checkov_findings": {
+        "run_pk": "BIGINT",
+        "file_id": "VARCHAR",
+        "directory_id": "VARCHAR",
+        "relative_path": "VARCHAR",
+        "rule_id": "VARCHAR",
+        "check_name": "VARCHAR",
+        "framework": "VARCHAR",
+        "dd_category": "VARCHAR",
+        "resource": "VARCHAR",
+        "severity": "VARCHAR",
+        "line_start": "INTEGER",
+        "line_end": "INTEGER",
+        "guideline": "VARCHAR",
+    }
+}
+TABLE_DDL = {
+    "lz_checkov_findings": """
+        CREATE TABLE IF NOT EXISTS lz_checkov_findings (
+            run_pk BIGINT NOT NULL,
+            file_id VARCHAR NOT NULL,
+            directory_id VARCHAR NOT NULL,
+            relative_path VARCHAR NOT NULL,
+            rule_id VARCHAR NOT NULL,
+            check_name VARCHAR,
+            framework VARCHAR,
+            dd_category VARCHAR,
+            resource VARCHAR NOT NULL,
+            severity VARCHAR,
+            line_start INTEGER,
+            line_end INTEGER,
+            guideline VARCHAR,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, file_id, rule_id, resource, line_start)
+        )
+    """,
+}
+QUALITY_RULES = ["paths", "line_numbers", "required_fields"]
+
+
+class CheckovAdapter(BaseAdapter):
+    """Adapter for persisting Checkov IaC policy output to the landing zone."""
+
+    @property
+    def tool_name(self) -> str:
+        return "checkov"
+
+    @property
+    def schema_path(self) -> Path:
+        return SCHEMA_PATH
+
+    @property
+    def lz_tables(self) -> dict[str, dict[str, str]]:
+        return LZ_TABLES
+
+    @property
+    def table_ddl(self) -> dict[str, str]:
+        return TABLE_DDL
+
+    def __init__(
+        self,
+        run_repo: ToolRunRepository,
+        layout_repo: LayoutRepository,
+        checkov_repo: CheckovRepository,
+        repo_root: Path | None = None,
+        logger: Callable[[str], None] | None = None,
+    ) -> None:
+        super().__init__(run_repo, layout_repo, repo_root=repo_root, logger=logger)
+        self._checkov_repo = checkov_repo
+
+    def _do_persist(self, payload: dict) -> int:
+        """Persist checkov output to landing zone."""
+        metadata = payload.get("metadata") or {}
+        data = payload.get("data") or {}
+
+        run_pk = self._create_tool_run(metadata)
+        layout_run_pk = self._get_layout_run_pk(metadata["run_id"])
+
+        files = data.get("files", [])
+        self.validate_quality(files)
+        findings = list(self._map_findings(run_pk, layout_run_pk, files))
+        self._checkov_repo.insert_findings(findings)
+        return run_pk
+
+    def validate_quality(self, files: Any) -> None:
+        """Validate data quality rules for checkov file entries."""
+        errors: list[str] = []
+        errors.extend(validate_file_paths_in_entries(
+            files,
+            path_field="path",
+            repo_root=self._repo_root,
+            entry_prefix="checkov file",
+        ))
+        for f_idx, file_entry in enumerate(files):
+            for i_idx, issue in enumerate(file_entry.get("issues", [])):
+                prefix = f"file[{f_idx}].issues[{i_idx}]"
+                errors.extend(check_required(issue.get("rule_id"), f"{prefix}.rule_id"))
+                errors.extend(check_required(issue.get("framework"), f"{prefix}.framework"))
+                errors.extend(
+                    self.check_line_range(
+                        issue.get("line_start"), issue.get("line_end"), prefix
+                    )
+                )
+
+        self._raise_quality_errors(errors)
+
+    def _map_findings(
+        self, run_pk: int, layout_run_pk: int, files: Iterable[dict]
+    ) -> Iterable[CheckovFinding]:
+        """Map file issue entries to CheckovFinding entities."""
+        seen: set[tuple[str, str, str, int | None]] = set()
+        for file_entry in files:
+            relative_path = self._normalize_path(file_entry.get("path", ""))
+            issues = file_entry.get("issues", [])
+            if not issues:
+                continue
+
+            try:
+                file_id, directory_id = self._layout_repo.get_file_record(
+                    layout_run_pk, relative_path
+                )
+            except KeyError:
+                self._log(f"WARN: skipping file not in layout: {relative_path}")
+                continue
+
+            for issue in issues:
+                resource = issue.get("resource", "")
+                key = (file_id, issue.get("rule_id", ""), resource, issue.get("line_start"))
+                if key in seen:
+                    self._log(
+                        f"WARN: skipping duplicate finding {key[1]} at {relative_path}:{key[3]}"
+                    )
+                    continue
+                seen.add(key)
+                yield CheckovFinding(
+                    run_pk=run_pk,
+                    file_id=file_id,
+                    directory_id=directory_id,
+                    relative_path=relative_path,
+                    rule_id=issue.get("rule_id", ""),
+                    check_name=issue.get("check_name"),
+                    framework=issue.get("framework"),
+                    dd_category=issue.get("dd_category"),
+                    resource=resource,
+                    severity=issue.get("severity"),
+                    line_start=issue.get("line_start"),
+                    line_end=issue.get("line_end"),
+                    guideline=issue.get("guideline"),
+                )
@@ -93,7 +93,11 @@ class DevskimAdapter(BaseAdapter):
 
         files = data.get("files", [])
         self.validate_quality(files)
-        findings = list(self._map_findings(run_pk, layout_run_pk, files))
+        seen: set[tuple[str, str, int | None]] = set()
+        findings = self.map_entries_isolated(
+            files,
+            lambda file_entry: self._map_file_findings(run_pk, layout_run_pk, file_entry, seen),
+        )
         self._devskim_repo.insert_findings(findings)
         return run_pk
 
@@ -122,43 +126,45 @@ class DevskimAdapter(BaseAdapter):
 
         self._raise_quality_errors(errors)
 
-    def _map_findings(
-        self, run_pk: int, layout_run_pk: int, files: Iterable[dict]
+    def _map_file_findings(
+        self,
+        run_pk: int,
+        layout_run_pk: int,
+        file_entry: dict,
+        seen: set[tuple[str, str, int | None]],
     ) -> Iterable[DevskimFinding]:
-        """Map file entries with issues to DevskimFinding entities."""
-        seen: set[tuple[str, str, int | None]] = set()
-        for file_entry in files:
-            relative_path = self._normalize_path(file_entry.get("path", ""))
-
-            try:
-                file_id, directory_id = self._layout_repo.get_file_record(
-                    layout_run_pk, relative_path
+        """Map one file entry's issues to DevskimFinding entities."""
+        relative_path = self._normalize_path(file_entry.get("path", ""))
+
+        try:
+            file_id, directory_id = self._layout_repo.get_file_record(
+                layout_run_pk, relative_path
+            )
+        except KeyError:
+            # Skip files not in layout (external dependencies, generated files, etc.)
+            self._log(f"WARN: skipping file not in layout: {relative_path}")
+            return
+
+        for issue in file_entry.get("issues", []):
+            key = (file_id, issue.get("rule_id", ""), issue.get("line_start"))
+            if key in seen:
+                self._log(
+                    f"WARN: skipping duplicate issue {key[1]} at {relative_path}:{key[2]}"
                 )
-            except KeyError:
-                # Skip files not in layout (external dependencies, generated files, etc.)
-                self._log(f"WARN: skipping file not in layout: {relative_path}")
                 continue
-
-            for issue in file_entry.get("issues", []):
-                key = (file_id, issue.get("rule_id", ""), issue.get("line_start"))
-                if key in seen:
-                    self._log(
-                        f"WARN: skipping duplicate issue {key[1]} at {relative_path}:{key[2]}"
-                    )
-                    continue
-                seen.add(key)
-                yield DevskimFinding(
-                    run_pk=run_pk,
-                    file_id=file_id,
-                    directory_id=directory_id,
-                    relative_path=relative_path,
-                    rule_id=issue.get("rule_id", ""),
-                    dd_category=issue.get("dd_category"),
-                    severity=issue.get("severity"),
-                    line_start=issue.get("line_start"),
-                    line_end=issue.get("line_end"),
-                    column_start=issue.get("column_start"),
-                    column_end=issue.get("column_end"),
-                    message=issue.get("message"),
-                    code_snippet=issue.get("code_snippet"),
-                )
+            seen.add(key)
+            yield DevskimFinding(
+                run_pk=run_pk,
+                file_id=file_id,
+                directory_id=directory_id,
+                relative_path=relative_path,
+                rule_id=issue.get("rule_id", ""),
+                dd_category=issue.get("dd_category"),
+                severity=issue.get("severity"),
+                line_start=issue.get("line_start"),
+                line_end=issue.get("line_end"),
+                column_start=issue.get("column_start"),
+                column_end=issue.get("column_end"),
+                message=issue.get("message"),
+                code_snippet=issue.get("code_snippet"),
+            )
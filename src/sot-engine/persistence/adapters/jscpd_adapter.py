@@ -0,0 +1,128 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Callable
+
+from .pmd_cpd_adapter import PmdCpdAdapter
+from ..repositories import JscpdRepository, LayoutRepository, ToolRunRepository
+
+
+SCHEMA_PATH = Path(__file__).resolve().parents[3] / "tools" / "jscpd" / "schemas" / "output.schema.json"
+
+LZ_TABLES = {
+    "lz_jscpd_file_metrics": {
+        "run_pk": "BIGINT",
+        "file_id": "VARCHAR",
+        "directory_id": "VARCHAR",
+        "relative_path": "VARCHAR",
+        "language": "VARCHAR",
+        "total_lines": "INTEGER",
+        "duplicate_lines": "INTEGER",
+        "duplicate_blocks": "INTEGER",
+        "duplication_percentage": "DOUBLE",
+    },
+    "lz_jscpd_duplications": {
+        "run_pk": "BIGINT",
+        "clone_id": "VARCHAR",
+        "lines": "INTEGER",
+        "tokens": "INTEGER",
+        "occurrence_count": "INTEGER",
+        "is_cross_file": "BOOLEAN",
+        "code_fragment": "VARCHAR",
+    },
+    "lz_jscpd_occurrences": {
+        "run_pk": "BIGINT",
+        "clone_id": "VARCHAR",
+        "file_id": "VARCHAR",
+        "directory_id": "VARCHAR",
+        "relative_path": "VARCHAR",
+        "line_start": "INTEGER",
+        "line_end": "INTEGER",
+        "column_start": "INTEGER",
+        "column_end": "INTEGER",
+    },
+}
+
+TABLE_DDL = {
+    "lz_jscpd_file_metrics": """
+        CREATE TABLE IF NOT EXISTS lz_jscpd_file_metrics (
+            run_pk BIGINT NOT NULL,
+            file_id VARCHAR NOT NULL,
+            directory_id VARCHAR NOT NULL,
+            relative_path VARCHAR NOT NULL,
+            language VARCHAR,
+            total_lines INTEGER NOT NULL,
+            duplicate_lines INTEGER NOT NULL,
+            duplicate_blocks INTEGER NOT NULL,
+            duplication_percentage DOUBLE NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, file_id)
+        )
+    """,
+    "lz_jscpd_duplications": """
+        CREATE TABLE IF NOT EXISTS lz_jscpd_duplications (
+            run_pk BIGINT NOT NULL,
+            clone_id VARCHAR NOT NULL,
+            lines INTEGER NOT NULL,
+            tokens INTEGER NOT NULL,
+            occurrence_count INTEGER NOT NULL,
+            is_cross_file BOOLEAN NOT NULL,
+            code_fragment TEXT,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, clone_id)
+        )
+    """,
+    "lz_jscpd_occurrences": """
+        CREATE TABLE IF NOT EXISTS lz_jscpd_occurrences (
+            run_pk BIGINT NOT NULL,
+            clone_id VARCHAR NOT NULL,
+            file_id VARCHAR NOT NULL,
+            directory_id VARCHAR NOT NULL,
+            relative_path VARCHAR NOT NULL,
+            line_start INTEGER NOT NULL,
+            line_end INTEGER NOT NULL,
+            column_start INTEGER,
+            column_end INTEGER,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, clone_id, file_id, line_start)
+        )
+    """,
+}
+
+QUALITY_RULES = ["paths", "line_numbers", "required_fields"]
+
+
+class JscpdAdapter(PmdCpdAdapter):
+    """Adapter for persisting jscpd JS/TS duplication output to the landing zone.
+
+    jscpd emits the same clone model as pmd-cpd (per-file duplication metrics
+    plus clone groups with occurrences), so the mapping and quality logic is
+    inherited wholesale — only the tool identity, schema, and landing zone
+    tables differ.
+    """
+
+    @property
+    def tool_name(self) -> str:
+        return "jscpd"
+
+    @property
+    def schema_path(self) -> Path:
+        return SCHEMA_PATH
+
+    @property
+    def lz_tables(self) -> dict[str, dict[str, str]]:
+        return LZ_TABLES
+
+    @property
+    def table_ddl(self) -> dict[str, str]:
+        return TABLE_DDL
+
+    def __init__(
+        self,
+        run_repo: ToolRunRepository,
+        layout_repo: LayoutRepository,
+        jscpd_repo: JscpdRepository,
+        repo_root: Path | None = None,
+        logger: Callable[[str], None] | None = None,
+    ) -> None:
+        super().__init__(run_repo, layout_repo, jscpd_repo, repo_root, logger)
@@ -7,6 +7,7 @@ from typing import Any, Callable, Iterable
 from .base_adapter import BaseAdapter
 from ..entities import LayoutDirectory, LayoutFile
 from ..repositories import LayoutRepository, ToolRunRepository
+from common.ignore_rules import IgnoreRules
 from shared.path_utils import is_repo_relative_path, normalize_dir_path, normalize_file_path
 from ..validation import (
     check_non_negative,
@@ -114,6 +115,14 @@ class LayoutAdapter(BaseAdapter):
         files_map = data.get("files", {})
         directories_map = data.get("directories", {})
 
+        # Apply .calderaignore before anything is validated or persisted.
+        # Files dropped here never reach the layout tables, so every other
+        # adapter skips them via its "file not in layout" handling.
+        ignore_rules = self._load_ignore_rules()
+        if ignore_rules:
+            files_map = self._filter_ignored(files_map, ignore_rules, is_dir=False)
+            directories_map = self._filter_ignored(directories_map, ignore_rules, is_dir=True)
+
         self.validate_quality(files_map, directories_map)
 
         # Map files to LayoutFile entities
@@ -126,6 +135,30 @@ class LayoutAdapter(BaseAdapter):
 
         return run_pk
 
+    def _load_ignore_rules(self) -> IgnoreRules:
+        """Load .calderaignore rules from the repo root, if one is known."""
+        if self._repo_root is None:
+            return IgnoreRules([])
+        return IgnoreRules.load(self._repo_root)
+
+    def _filter_ignored(
+        self, entries_map: dict, ignore_rules: IgnoreRules, is_dir: bool
+    ) -> dict:
+        """Drop entries matching the ignore rules, logging each skip."""
+        kept = {}
+        kind = "directory" if is_dir else "file"
+        for key, entry in entries_map.items():
+            raw_path = entry.get("path", key)
+            if is_dir:
+                normalized = normalize_dir_path(raw_path, self._repo_root)
+            else:
+                normalized = normalize_file_path(raw_path, self._repo_root)
+            if normalized and normalized != "." and ignore_rules.is_ignored(normalized, is_dir=is_dir):
+                self._log(f"INFO: skipping ignored {kind} per .calderaignore: {normalized}")
+                continue
+            kept[key] = entry
+        return kept
+
     def validate_quality(self, files_map: Any, directories_map: Any = None) -> None:
         """Validate data quality rules for layout files and directories."""
         if directories_map is None:
@@ -57,6 +57,7 @@ TABLE_DDL = {
             token_count INTEGER,
             line_start INTEGER,
             line_end INTEGER,
+            max_nesting_depth INTEGER,
             PRIMARY KEY (run_pk, file_id, function_name, line_start)
         )
     """,
@@ -222,6 +223,7 @@ class LizardAdapter(BaseAdapter):
                         token_count=func.get("token_count"),
                         line_start=line_start,
                         line_end=line_end,
+                        max_nesting_depth=func.get("max_nesting_depth"),
                     )
                 )
 
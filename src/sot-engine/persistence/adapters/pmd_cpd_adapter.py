@@ -160,7 +160,7 @@ class PmdCpdAdapter(BaseAdapter):
             files,
             path_field="path",
             repo_root=self._repo_root,
-            entry_prefix="pmd-cpd file",
+            entry_prefix=f"{self.tool_name} file",
         ))
 
         # Validate duplications
@@ -0,0 +1,149 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any, Callable, Iterable
+
+from .base_adapter import BaseAdapter
+from ..entities import RustApiItem
+from ..repositories import LayoutRepository, RustApiRepository, ToolRunRepository
+from ..validation import (
+    check_required,
+    validate_file_paths_in_entries,
+)
+
+SCHEMA_PATH = Path(__file__).resolve().parents[3] / "tools" / "rust-api" / "schemas" / "output.schema.json"
+LZ_TABLES = {
+    "lz_rust_api_items": {
+        "run_pk": "BIGINT",
+        "file_id": "VARCHAR",
+        "directory_id": "VARCHAR",
+        "relative_path": "VARCHAR",
+        "item_name": "VARCHAR",
+        "item_kind": "VARCHAR",
+        "signature": "VARCHAR",
+        "line": "INTEGER",
+        "crate": "VARCHAR",
+    }
+}
+TABLE_DDL = {
+    "lz_rust_api_items": """
+        CREATE TABLE IF NOT EXISTS lz_rust_api_items (
+            run_pk BIGINT NOT NULL,
+            file_id VARCHAR NOT NULL,
+            directory_id VARCHAR NOT NULL,
+            relative_path VARCHAR NOT NULL,
+            item_name VARCHAR NOT NULL,
+            item_kind VARCHAR NOT NULL,
+            signature TEXT NOT NULL,
+            line INTEGER NOT NULL,
+            crate VARCHAR NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, file_id, item_kind, item_name, line)
+        )
+    """,
+}
+QUALITY_RULES = ["paths", "line_numbers", "required_fields"]
+
+
+class RustApiAdapter(BaseAdapter):
+    """Adapter for persisting rust-api surface output to the landing zone."""
+
+    @property
+    def tool_name(self) -> str:
+        return "rust-api"
+
+    @property
+    def schema_path(self) -> Path:
+        return SCHEMA_PATH
+
+    @property
+    def lz_tables(self) -> dict[str, dict[str, str]]:
+        return LZ_TABLES
+
+    @property
+    def table_ddl(self) -> dict[str, str]:
+        return TABLE_DDL
+
+    def __init__(
+        self,
+        run_repo: ToolRunRepository,
+        layout_repo: LayoutRepository,
+        rust_api_repo: RustApiRepository,
+        repo_root: Path | None = None,
+        logger: Callable[[str], None] | None = None,
+    ) -> None:
+        super().__init__(run_repo, layout_repo, repo_root=repo_root, logger=logger)
+        self._rust_api_repo = rust_api_repo
+
+    def _do_persist(self, payload: dict) -> int:
+        """Persist rust-api output to landing zone."""
+        metadata = payload.get("metadata") or {}
+        data = payload.get("data") or {}
+
+        run_pk = self._create_tool_run(metadata)
+        layout_run_pk = self._get_layout_run_pk(metadata["run_id"])
+
+        files = data.get("files", [])
+        self.validate_quality(files)
+        items = list(self._map_items(run_pk, layout_run_pk, files))
+        self._rust_api_repo.insert_items(items)
+        return run_pk
+
+    def validate_quality(self, files: Any) -> None:
+        """Validate data quality rules for rust-api file entries."""
+        errors: list[str] = []
+        errors.extend(validate_file_paths_in_entries(
+            files,
+            path_field="path",
+            repo_root=self._repo_root,
+            entry_prefix="rust-api file",
+        ))
+        for f_idx, file_entry in enumerate(files):
+            for i_idx, item in enumerate(file_entry.get("items", [])):
+                prefix = f"file[{f_idx}].items[{i_idx}]"
+                errors.extend(check_required(item.get("name"), f"{prefix}.name"))
+                errors.extend(check_required(item.get("signature"), f"{prefix}.signature"))
+                errors.extend(
+                    self.check_line_range(item.get("line"), item.get("line"), prefix)
+                )
+
+        self._raise_quality_errors(errors)
+
+    def _map_items(
+        self, run_pk: int, layout_run_pk: int, files: Iterable[dict]
+    ) -> Iterable[RustApiItem]:
+        """Map file item entries to RustApiItem entities."""
+        seen: set[tuple[str, str, str, int | None]] = set()
+        for file_entry in files:
+            relative_path = self._normalize_path(file_entry.get("path", ""))
+            items = file_entry.get("items", [])
+            if not items:
+                continue
+
+            try:
+                file_id, directory_id = self._layout_repo.get_file_record(
+                    layout_run_pk, relative_path
+                )
+            except KeyError:
+                self._log(f"WARN: skipping file not in layout: {relative_path}")
+                continue
+
+            for item in items:
+                key = (file_id, item.get("kind", ""), item.get("name", ""), item.get("line"))
+                if key in seen:
+                    self._log(
+                        f"WARN: skipping duplicate item {key[2]} at {relative_path}:{key[3]}"
+                    )
+                    continue
+                seen.add(key)
+                yield RustApiItem(
+                    run_pk=run_pk,
+                    file_id=file_id,
+                    directory_id=directory_id,
+                    relative_path=relative_path,
+                    item_name=item.get("name", ""),
+                    item_kind=item.get("kind", ""),
+                    signature=item.get("signature", ""),
+                    line=item.get("line", 1),
+                    crate=item.get("crate", "unknown"),
+                )
@@ -0,0 +1,152 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any, Callable, Iterable
+
+from .base_adapter import BaseAdapter
+from ..entities import RustDeadcodeFinding
+from ..repositories import LayoutRepository, RustDeadcodeRepository, ToolRunRepository
+from ..validation import (
+    check_required,
+    validate_file_paths_in_entries,
+)
+
+SCHEMA_PATH = Path(__file__).resolve().parents[3] / "tools" / "rust-deadcode" / "schemas" / "output.schema.json"
+LZ_TABLES = {
+    "lz_rust_deadcode_findings": {
+        "run_pk": "BIGINT",
+        "file_id": "VARCHAR",
+        "directory_id": "VARCHAR",
+        "relative_path": "VARCHAR",
+        "symbol_name": "VARCHAR",
+        "symbol_kind": "VARCHAR",
+        "source": "VARCHAR",
+        "confidence": "VARCHAR",
+        "line": "INTEGER",
+        "message": "VARCHAR",
+    }
+}
+TABLE_DDL = {
+    "lz_rust_deadcode_findings": """
+        CREATE TABLE IF NOT EXISTS lz_rust_deadcode_findings (
+            run_pk BIGINT NOT NULL,
+            file_id VARCHAR NOT NULL,
+            directory_id VARCHAR NOT NULL,
+            relative_path VARCHAR NOT NULL,
+            symbol_name VARCHAR NOT NULL,
+            symbol_kind VARCHAR,
+            source VARCHAR NOT NULL,
+            confidence VARCHAR NOT NULL,
+            line INTEGER NOT NULL,
+            message TEXT,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, file_id, symbol_name, line)
+        )
+    """,
+}
+QUALITY_RULES = ["paths", "line_numbers", "required_fields"]
+
+
+class RustDeadcodeAdapter(BaseAdapter):
+    """Adapter for persisting rust-deadcode analysis output to the landing zone."""
+
+    @property
+    def tool_name(self) -> str:
+        return "rust-deadcode"
+
+    @property
+    def schema_path(self) -> Path:
+        return SCHEMA_PATH
+
+    @property
+    def lz_tables(self) -> dict[str, dict[str, str]]:
+        return LZ_TABLES
+
+    @property
+    def table_ddl(self) -> dict[str, str]:
+        return TABLE_DDL
+
+    def __init__(
+        self,
+        run_repo: ToolRunRepository,
+        layout_repo: LayoutRepository,
+        rust_deadcode_repo: RustDeadcodeRepository,
+        repo_root: Path | None = None,
+        logger: Callable[[str], None] | None = None,
+    ) -> None:
+        super().__init__(run_repo, layout_repo, repo_root=repo_root, logger=logger)
+        self._rust_deadcode_repo = rust_deadcode_repo
+
+    def _do_persist(self, payload: dict) -> int:
+        """Persist rust-deadcode output to landing zone."""
+        metadata = payload.get("metadata") or {}
+        data = payload.get("data") or {}
+
+        run_pk = self._create_tool_run(metadata)
+        layout_run_pk = self._get_layout_run_pk(metadata["run_id"])
+
+        files = data.get("files", [])
+        self.validate_quality(files)
+        findings = list(self._map_findings(run_pk, layout_run_pk, files))
+        self._rust_deadcode_repo.insert_findings(findings)
+        return run_pk
+
+    def validate_quality(self, files: Any) -> None:
+        """Validate data quality rules for rust-deadcode file entries."""
+        errors: list[str] = []
+        errors.extend(validate_file_paths_in_entries(
+            files,
+            path_field="path",
+            repo_root=self._repo_root,
+            entry_prefix="rust-deadcode file",
+        ))
+        for f_idx, file_entry in enumerate(files):
+            for i_idx, finding in enumerate(file_entry.get("findings", [])):
+                prefix = f"file[{f_idx}].findings[{i_idx}]"
+                errors.extend(check_required(finding.get("symbol_name"), f"{prefix}.symbol_name"))
+                errors.extend(check_required(finding.get("confidence"), f"{prefix}.confidence"))
+                errors.extend(
+                    self.check_line_range(finding.get("line"), finding.get("line"), prefix)
+                )
+
+        self._raise_quality_errors(errors)
+
+    def _map_findings(
+        self, run_pk: int, layout_run_pk: int, files: Iterable[dict]
+    ) -> Iterable[RustDeadcodeFinding]:
+        """Map file finding entries to RustDeadcodeFinding entities."""
+        seen: set[tuple[str, str, int | None]] = set()
+        for file_entry in files:
+            relative_path = self._normalize_path(file_entry.get("path", ""))
+            findings = file_entry.get("findings", [])
+            if not findings:
+                continue
+
+            try:
+                file_id, directory_id = self._layout_repo.get_file_record(
+                    layout_run_pk, relative_path
+                )
+            except KeyError:
+                self._log(f"WARN: skipping file not in layout: {relative_path}")
+                continue
+
+            for finding in findings:
+                key = (file_id, finding.get("symbol_name", ""), finding.get("line"))
+                if key in seen:
+                    self._log(
+                        f"WARN: skipping duplicate finding {key[1]} at {relative_path}:{key[2]}"
+                    )
+                    continue
+                seen.add(key)
+                yield RustDeadcodeFinding(
+                    run_pk=run_pk,
+                    file_id=file_id,
+                    directory_id=directory_id,
+                    relative_path=relative_path,
+                    symbol_name=finding.get("symbol_name", ""),
+                    symbol_kind=finding.get("symbol_kind"),
+                    source=finding.get("source", ""),
+                    confidence=finding.get("confidence", ""),
+                    line=finding.get("line"),
+                    message=finding.get("message"),
+                )
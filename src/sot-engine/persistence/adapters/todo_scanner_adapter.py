@@ -0,0 +1,154 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any, Callable, Iterable
+
+from .base_adapter import BaseAdapter
+from ..entities import TodoComment
+from ..repositories import LayoutRepository, TodoScannerRepository, ToolRunRepository
+from ..validation import (
+    check_required,
+    validate_file_paths_in_entries,
+)
+
+SCHEMA_PATH = Path(__file__).resolve().parents[3] / "tools" / "todo-scanner" / "schemas" / "output.schema.json"
+LZ_TABLES = {
+    "lz_todo_comments": {
+        "run_pk": "BIGINT",
+        "file_id": "VARCHAR",
+        "directory_id": "VARCHAR",
+        "relative_path": "VARCHAR",
+        "marker": "VARCHAR",
+        "severity": "VARCHAR",
+        "line": "INTEGER",
+        "comment_text": "VARCHAR",
+        "author_email": "VARCHAR",
+        "introduced_at": "VARCHAR",
+        "age_days": "INTEGER",
+    }
+}
+TABLE_DDL = {
+    "lz_todo_comments": """
+        CREATE TABLE IF NOT EXISTS lz_todo_comments (
+            run_pk BIGINT NOT NULL,
+            file_id VARCHAR NOT NULL,
+            directory_id VARCHAR NOT NULL,
+            relative_path VARCHAR NOT NULL,
+            marker VARCHAR NOT NULL,
+            severity VARCHAR,
+            line INTEGER NOT NULL,
+            comment_text TEXT,
+            author_email VARCHAR,
+            introduced_at VARCHAR,
+            age_days INTEGER,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (run_pk, file_id, marker, line)
+        )
+    """,
+}
+QUALITY_RULES = ["paths", "line_numbers", "required_fields"]
+
+
+class TodoScannerAdapter(BaseAdapter):
+    """Adapter for persisting todo-scanner debt comment output to the landing zone."""
+
+    @property
+    def tool_name(self) -> str:
+        return "todo-scanner"
+
+    @property
+    def schema_path(self) -> Path:
+        return SCHEMA_PATH
+
+    @property
+    def lz_tables(self) -> dict[str, dict[str, str]]:
+        return LZ_TABLES
+
+    @property
+    def table_ddl(self) -> dict[str, str]:
+        return TABLE_DDL
+
+    def __init__(
+        self,
+        run_repo: ToolRunRepository,
+        layout_repo: LayoutRepository,
+        todo_scanner_repo: TodoScannerRepository,
+        repo_root: Path | None = None,
+        logger: Callable[[str], None] | None = None,
+    ) -> None:
+        super().__init__(run_repo, layout_repo, repo_root=repo_root, logger=logger)
+        self._todo_scanner_repo = todo_scanner_repo
+
+    def _do_persist(self, payload: dict) -> int:
+        """Persist todo-scanner output to landing zone."""
+        metadata = payload.get("metadata") or {}
+        data = payload.get("data") or {}
+
+        run_pk = self._create_tool_run(metadata)
+        layout_run_pk = self._get_layout_run_pk(metadata["run_id"])
+
+        files = data.get("files", [])
+        self.validate_quality(files)
+        comments = list(self._map_comments(run_pk, layout_run_pk, files))
+        self._todo_scanner_repo.insert_comments(comments)
+        return run_pk
+
+    def validate_quality(self, files: Any) -> None:
+        """Validate data quality rules for todo-scanner file entries."""
+        errors: list[str] = []
+        errors.extend(validate_file_paths_in_entries(
+            files,
+            path_field="path",
+            repo_root=self._repo_root,
+            entry_prefix="todo-scanner file",
+        ))
+        for f_idx, file_entry in enumerate(files):
+            for c_idx, comment in enumerate(file_entry.get("comments", [])):
+                prefix = f"file[{f_idx}].comments[{c_idx}]"
+                errors.extend(check_required(comment.get("marker"), f"{prefix}.marker"))
+                errors.extend(
+                    self.check_line_range(comment.get("line"), comment.get("line"), prefix)
+                )
+
+        self._raise_quality_errors(errors)
+
+    def _map_comments(
+        self, run_pk: int, layout_run_pk: int, files: Iterable[dict]
+    ) -> Iterable[TodoComment]:
+        """Map file comment entries to TodoComment entities."""
+        seen: set[tuple[str, str, int | None]] = set()
+        for file_entry in files:
+            relative_path = self._normalize_path(file_entry.get("path", ""))
+            comments = file_entry.get("comments", [])
+            if not comments:
+                continue
+
+            try:
+                file_id, directory_id = self._layout_repo.get_file_record(
+                    layout_run_pk, relative_path
+                )
+            except KeyError:
+                self._log(f"WARN: skipping file not in layout: {relative_path}")
+                continue
+
+            for comment in comments:
+                key = (file_id, comment.get("marker", ""), comment.get("line"))
+                if key in seen:
+                    self._log(
+                        f"WARN: skipping duplicate comment {key[1]} at {relative_path}:{key[2]}"
+                    )
+                    continue
+                seen.add(key)
+                yield TodoComment(
+                    run_pk=run_pk,
+                    file_id=file_id,
+                    directory_id=directory_id,
+                    relative_path=relative_path,
+                    marker=comment.get("marker", ""),
+                    severity=comment.get("severity"),
+                    line=comment.get("line"),
+                    comment_text=comment.get("text"),
+                    author_email=comment.get("author_email"),
+                    introduced_at=comment.get("introduced_at"),
+                    age_days=comment.get("age_days"),
+                )
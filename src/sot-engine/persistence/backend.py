@@ -0,0 +1,164 @@
+"""Database backend selection for the landing zone.
+
+The landing zone runs on DuckDB by default, which is single-writer: fine
+for a laptop, a problem for server deployments where several CI runners
+persist concurrently. This module lets ``--db-path`` also be a
+``postgresql://`` DSN. Postgres connections are wrapped so the
+repositories' DuckDB-style SQL (qmark placeholders, the unquoted
+``commit`` column) runs unchanged, and the schema is applied through a
+versioned migration runner guarded by an advisory lock so concurrent
+runners race safely.
+
+Migrations live in ``persistence/migrations/`` as ``NNNN_name.sql``
+files; version 0001 is always ``schema.sql`` itself, translated to the
+Postgres dialect at apply time so the two schemas cannot drift.
+"""
+
+from __future__ import annotations
+
+import re
+from datetime import datetime, timezone
+from pathlib import Path
+
+MIGRATIONS_DIR = Path(__file__).parent / "migrations"
+INITIAL_MIGRATION = "0001_initial"
+
+# Postgres reserves `commit`; DuckDB does not. Quote it wherever it is
+# used as an identifier (never in transaction control — repositories
+# commit via the driver, not via SQL).
+_COMMIT_IDENTIFIER = re.compile(r"\bcommit\b", re.IGNORECASE)
+
+# DuckDB type names that Postgres spells differently.
+_TYPE_TRANSLATIONS = (
+    (re.compile(r"\bDOUBLE\b(?!\s+PRECISION)", re.IGNORECASE), "DOUBLE PRECISION"),
+    (re.compile(r"\bUBIGINT\b", re.IGNORECASE), "NUMERIC(20)"),
+)
+
+
+def parse_database_url(url: str) -> tuple[str, str]:
+    """Classify a database target as ``("duckdb", path)`` or ``("postgres", dsn)``."""
+    if url.startswith(("postgresql://", "postgres://")):
+        return "postgres", url
+    return "duckdb", url
+
+
+def translate_placeholders(sql: str) -> str:
+    """Rewrite qmark placeholders to ``%s``, leaving string literals alone."""
+    parts = sql.split("'")
+    # Even-indexed parts are outside single-quoted literals.
+    return "'".join(
+        part.replace("?", "%s") if index % 2 == 0 else part
+        for index, part in enumerate(parts)
+    )
+
+
+def translate_sql(sql: str) -> str:
+    """Translate repository/schema SQL from DuckDB to the Postgres dialect."""
+    translated = _COMMIT_IDENTIFIER.sub('"commit"', sql)
+    for pattern, replacement in _TYPE_TRANSLATIONS:
+        translated = pattern.sub(replacement, translated)
+    return translate_placeholders(translated)
+
+
+class _PostgresResult:
+    """fetchone/fetchall facade over a closed-over row list."""
+
+    def __init__(self, rows: list) -> None:
+        self._rows = rows
+
+    def fetchone(self):
+        return self._rows[0] if self._rows else None
+
+    def fetchall(self) -> list:
+        return self._rows
+
+
+class PostgresConnection:
+    """DuckDB-shaped facade over a psycopg connection.
+
+    Repositories call ``conn.execute(sql, params).fetchall()``; this
+    translates each statement to the Postgres dialect, runs it, and
+    returns a result facade. Autocommit matches DuckDB's behaviour so
+    every insert is visible to other runners immediately.
+    """
+
+    dialect = "postgres"
+
+    def __init__(self, raw_connection) -> None:
+        self._raw = raw_connection
+        self._raw.autocommit = True
+
+    def execute(self, sql: str, params=None) -> _PostgresResult:
+        with self._raw.cursor() as cursor:
+            cursor.execute(translate_sql(sql), params or [])
+            rows = cursor.fetchall() if cursor.description is not None else []
+        return _PostgresResult(rows)
+
+    def close(self) -> None:
+        self._raw.close()
+
+
+def connect_database(url: str):
+    """Connect to a DuckDB file or a Postgres DSN."""
+    dialect, target = parse_database_url(url)
+    if dialect == "duckdb":
+        import duckdb
+
+        return duckdb.connect(target)
+    try:
+        import psycopg
+    except ImportError:
+        raise RuntimeError(
+            "psycopg is required for postgresql:// database URLs "
+            "(pip install psycopg[binary])"
+        )
+    return PostgresConnection(psycopg.connect(target))
+
+
+def _migration_files(migrations_dir: Path) -> list[tuple[str, Path]]:
+    if not migrations_dir.is_dir():
+        return []
+    return sorted(
+        (path.stem, path)
+        for path in migrations_dir.glob("[0-9][0-9][0-9][0-9]_*.sql")
+    )
+
+
+def apply_migrations(
+    conn: PostgresConnection,
+    schema_path: Path,
+    migrations_dir: Path = MIGRATIONS_DIR,
+) -> list[str]:
+    """Apply pending migrations to a Postgres landing zone.
+
+    Version 0001 is schema.sql translated at apply time; later versions
+    come from the migrations directory. An advisory lock serialises
+    concurrent runners, so all but the first see the work already done.
+    """
+    conn.execute("SELECT pg_advisory_lock(hashtext('caldera_migrations'))")
+    try:
+        conn.execute(
+            """CREATE TABLE IF NOT EXISTS schema_migrations (
+                   version VARCHAR PRIMARY KEY,
+                   applied_at TIMESTAMP NOT NULL
+               )"""
+        )
+        applied = {
+            row[0] for row in conn.execute("SELECT version FROM schema_migrations").fetchall()
+        }
+        pending: list[tuple[str, str]] = []
+        if INITIAL_MIGRATION not in applied:
+            pending.append((INITIAL_MIGRATION, schema_path.read_text()))
+        for version, path in _migration_files(migrations_dir):
+            if version not in applied and version != INITIAL_MIGRATION:
+                pending.append((version, path.read_text()))
+
+        for version, sql in pending:
+            conn.execute(sql)
+            conn.execute(
+                "INSERT INTO schema_migrations VALUES (?, ?)",
+                [version, datetime.now(timezone.utc)],
+            )
+        return [version for version, _ in pending]
+    finally:
+        conn.execute("SELECT pg_advisory_unlock(hashtext('caldera_migrations'))")
@@ -233,6 +233,7 @@ class LizardFunctionMetric:
     token_count: int | None
     line_start: int | None
     line_end: int | None
+    max_nesting_depth: int | None = None
 
     def __post_init__(self) -> None:
         _validate_positive_pk(self.run_pk)
@@ -242,6 +243,7 @@ class LizardFunctionMetric:
             "nloc": self.nloc,
             "params": self.params,
             "token_count": self.token_count,
+            "max_nesting_depth": self.max_nesting_depth,
         })
         _validate_line_range(self.line_start, self.line_end)
 
@@ -344,6 +346,35 @@ class RoslynViolation:
         _validate_line_range(self.line_start, self.line_end)
 
 
+@dataclass(frozen=True)
+class BanditFinding:
+    """Individual security finding from Bandit Python analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    rule_id: str
+    dd_category: str | None
+    severity: str | None
+    confidence: str | None
+    cwe_id: str | None
+    line_start: int | None
+    line_end: int | None
+    column_start: int | None
+    message: str | None
+    code_snippet: str | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.rule_id, "rule_id")
+        _validate_line_range(self.line_start, self.line_end)
+        if self.severity is not None:
+            valid_severities = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+            if self.severity not in valid_severities:
+                raise ValueError(f"severity must be one of {valid_severities}")
+
+
 @dataclass(frozen=True)
 class DevskimFinding:
     """Individual security finding from DevSkim analysis."""
@@ -373,6 +404,200 @@ class DevskimFinding:
 
 
 
+@dataclass(frozen=True)
+class CheckovFinding:
+    """Individual policy violation from Checkov IaC analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    rule_id: str
+    check_name: str | None
+    framework: str | None
+    dd_category: str | None
+    resource: str
+    severity: str | None
+    line_start: int | None
+    line_end: int | None
+    guideline: str | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.rule_id, "rule_id")
+        _validate_line_range(self.line_start, self.line_end)
+        if self.severity is not None:
+            valid_severities = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+            if self.severity not in valid_severities:
+                raise ValueError(f"severity must be one of {valid_severities}")
+
+
+@dataclass(frozen=True)
+class GolangciFinding:
+    """Individual issue from golangci-lint Go analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    rule_id: str
+    dd_category: str | None
+    severity: str | None
+    line_start: int | None
+    line_end: int | None
+    column_start: int | None
+    message: str | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.rule_id, "rule_id")
+        _validate_line_range(self.line_start, self.line_end)
+        if self.severity is not None:
+            valid_severities = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+            if self.severity not in valid_severities:
+                raise ValueError(f"severity must be one of {valid_severities}")
+
+
+@dataclass(frozen=True)
+class ShellcheckFinding:
+    """Individual finding from ShellCheck shell script analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    rule_id: str
+    level: str | None
+    dd_category: str | None
+    severity: str | None
+    line_start: int | None
+    line_end: int | None
+    column_start: int | None
+    column_end: int | None
+    message: str | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.rule_id, "rule_id")
+        _validate_line_range(self.line_start, self.line_end)
+        if self.severity is not None:
+            valid_severities = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+            if self.severity not in valid_severities:
+                raise ValueError(f"severity must be one of {valid_severities}")
+
+
+@dataclass(frozen=True)
+class SqlfluffFinding:
+    """Individual violation from SQLFluff SQL analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    rule_id: str
+    rule_name: str | None
+    dd_category: str | None
+    severity: str | None
+    line_start: int | None
+    line_end: int | None
+    column_start: int | None
+    message: str | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.rule_id, "rule_id")
+        _validate_line_range(self.line_start, self.line_end)
+        if self.severity is not None:
+            valid_severities = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+            if self.severity not in valid_severities:
+                raise ValueError(f"severity must be one of {valid_severities}")
+
+
+@dataclass(frozen=True)
+class RustDeadcodeFinding:
+    """Likely-dead Rust item from rust-deadcode analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    symbol_name: str
+    symbol_kind: str | None
+    source: str                   # compiler or reference_scan
+    confidence: str               # HIGH, MEDIUM, LOW
+    line: int
+    message: str | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.symbol_name, "symbol_name")
+        valid_sources = {"compiler", "reference_scan"}
+        if self.source not in valid_sources:
+            raise ValueError(f"source must be one of {valid_sources}")
+        valid_confidences = {"HIGH", "MEDIUM", "LOW"}
+        if self.confidence not in valid_confidences:
+            raise ValueError(f"confidence must be one of {valid_confidences}")
+        if self.line < 1:
+            raise ValueError("line must be >= 1")
+
+
+@dataclass(frozen=True)
+class RustApiItem:
+    """One public API item from rust-api surface extraction."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    item_name: str
+    item_kind: str                # fn, struct, enum, trait, union, const, static, type, mod
+    signature: str
+    line: int
+    crate: str
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.item_name, "item_name")
+        _validate_required_string(self.signature, "signature")
+        valid_kinds = {"fn", "struct", "enum", "trait", "union", "const", "static", "type", "mod"}
+        if self.item_kind not in valid_kinds:
+            raise ValueError(f"item_kind must be one of {valid_kinds}")
+        if self.line < 1:
+            raise ValueError("line must be >= 1")
+
+
+@dataclass(frozen=True)
+class TodoComment:
+    """Individual tech-debt marker from todo-scanner analysis."""
+    run_pk: int
+    file_id: str
+    directory_id: str
+    relative_path: str
+    marker: str                   # TODO, FIXME, HACK, XXX
+    severity: str | None
+    line: int
+    comment_text: str | None
+    author_email: str | None      # null when blame attribution unavailable
+    introduced_at: str | None     # YYYY-MM-DD per git blame
+    age_days: int | None
+
+    def __post_init__(self) -> None:
+        _validate_positive_pk(self.run_pk)
+        _validate_relative_path(self.relative_path, "relative_path")
+        _validate_required_string(self.marker, "marker")
+        valid_markers = {"TODO", "FIXME", "HACK", "XXX"}
+        if self.marker not in valid_markers:
+            raise ValueError(f"marker must be one of {valid_markers}")
+        if self.line < 1:
+            raise ValueError("line must be >= 1")
+        if self.severity is not None:
+            valid_severities = {"CRITICAL", "HIGH", "MEDIUM", "LOW"}
+            if self.severity not in valid_severities:
+                raise ValueError(f"severity must be one of {valid_severities}")
+        if self.age_days is not None and self.age_days < 0:
+            raise ValueError("age_days must be >= 0")
+
+
 @dataclass(frozen=True)
 class SonarqubeIssue:
     """Individual issue instance from SonarQube analysis."""
@@ -0,0 +1,309 @@
+"""External tool-adapter plugins (JSON over stdio).
+
+Third-party analyzers can plug into the persistence layer without
+forking Caldera. A plugin lives in its own directory with a
+``caldera-plugin.json`` manifest:
+
+    {
+      "name": "acme-analyzer",
+      "version": "1.0.0",
+      "command": ["python3", "adapter.py"],
+      "output_schema": "schemas/output.schema.json",
+      "tables": {
+        "lz_acme_findings": {
+          "file_path": "VARCHAR",
+          "severity": "VARCHAR",
+          "finding_count": "INTEGER"
+        }
+      }
+    }
+
+Protocol (one JSON request on stdin, one JSON response on stdout):
+
+- ``handshake``: the host sends ``{"op": "handshake", "protocol_version": 1}``;
+  the plugin replies with its name, version, protocol_version and
+  capabilities. The host refuses plugins that speak a different protocol
+  version or lack the ``transform`` capability.
+- ``transform``: the host sends ``{"op": "transform", "payload": <tool
+  output envelope>}``; the plugin replies
+  ``{"tables": {"lz_acme_findings": [{...row}, ...]}}``.
+
+The host owns the database: plugins never see the DuckDB connection.
+They declare their landing zone tables in the manifest (identifiers and
+column types are validated against an allowlist, and the host generates
+the DDL itself) and return plain rows. Plugin processes run with a
+stripped environment, their manifest directory as working directory, and
+a hard timeout.
+"""
+from __future__ import annotations
+
+import json
+import os
+import re
+import subprocess
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Callable
+
+from .adapters.base_adapter import BaseAdapter
+from .repositories import LayoutRepository, ToolRunRepository
+
+PROTOCOL_VERSION = 1
+MANIFEST_NAME = "caldera-plugin.json"
+DEFAULT_TIMEOUT_SECONDS = 300
+MAX_TIMEOUT_SECONDS = 900
+
+_IDENTIFIER_RE = re.compile(r"^[a-z][a-z0-9_]*$")
+_PLUGIN_NAME_RE = re.compile(r"^[a-z][a-z0-9-]*$")
+_ALLOWED_COLUMN_TYPES = {"VARCHAR", "BIGINT", "INTEGER", "DOUBLE", "BOOLEAN", "TIMESTAMP"}
+
+
+class PluginError(RuntimeError):
+    """Raised when a plugin manifest is invalid or a plugin process misbehaves."""
+
+
+@dataclass(frozen=True)
+class PluginManifest:
+    """Parsed and validated caldera-plugin.json."""
+
+    name: str
+    version: str
+    command: tuple[str, ...]
+    output_schema: str
+    tables: dict[str, dict[str, str]]
+    plugin_dir: Path
+    timeout_seconds: int = DEFAULT_TIMEOUT_SECONDS
+
+    def __post_init__(self) -> None:
+        if not _PLUGIN_NAME_RE.match(self.name):
+            raise PluginError(f"plugin name invalid: {self.name!r}")
+        if not self.version:
+            raise PluginError(f"plugin {self.name}: version must be non-empty")
+        if not self.command:
+            raise PluginError(f"plugin {self.name}: command must be non-empty")
+        if not self.output_schema:
+            raise PluginError(f"plugin {self.name}: output_schema must be non-empty")
+        if not self.tables:
+            raise PluginError(f"plugin {self.name}: at least one table is required")
+        for table, columns in self.tables.items():
+            if not table.startswith("lz_") or not _IDENTIFIER_RE.match(table):
+                raise PluginError(
+                    f"plugin {self.name}: table name must be a lowercase lz_ identifier: {table!r}"
+                )
+            if not columns:
+                raise PluginError(f"plugin {self.name}: table {table} declares no columns")
+            for column, column_type in columns.items():
+                if column == "run_pk" or not _IDENTIFIER_RE.match(column):
+                    raise PluginError(
+                        f"plugin {self.name}: invalid column name in {table}: {column!r}"
+                    )
+                if column_type not in _ALLOWED_COLUMN_TYPES:
+                    raise PluginError(
+                        f"plugin {self.name}: column type not allowed for "
+                        f"{table}.{column}: {column_type!r}"
+                    )
+        if not 1 <= self.timeout_seconds <= MAX_TIMEOUT_SECONDS:
+            raise PluginError(
+                f"plugin {self.name}: timeout_seconds must be between 1 and {MAX_TIMEOUT_SECONDS}"
+            )
+
+    @classmethod
+    def load(cls, manifest_path: Path) -> PluginManifest:
+        """Load and validate a manifest file."""
+        try:
+            raw = json.loads(manifest_path.read_text())
+        except (OSError, json.JSONDecodeError) as exc:
+            raise PluginError(f"cannot read plugin manifest {manifest_path}: {exc}") from exc
+        if not isinstance(raw, dict):
+            raise PluginError(f"plugin manifest {manifest_path} must be a JSON object")
+        command = raw.get("command") or []
+        if not isinstance(command, list) or not all(isinstance(arg, str) for arg in command):
+            raise PluginError(f"plugin manifest {manifest_path}: command must be a string array")
+        return cls(
+            name=str(raw.get("name", "")),
+            version=str(raw.get("version", "")),
+            command=tuple(command),
+            output_schema=str(raw.get("output_schema", "")),
+            tables=raw.get("tables", {}),
+            plugin_dir=manifest_path.parent,
+            timeout_seconds=int(raw.get("timeout_seconds", DEFAULT_TIMEOUT_SECONDS)),
+        )
+
+
+def discover_plugins(plugins_dir: Path) -> list[PluginManifest]:
+    """Find plugin manifests in immediate subdirectories of plugins_dir.
+
+    Directories without a manifest are skipped; a directory with an
+    invalid manifest raises so misconfiguration fails loudly.
+    """
+    if not plugins_dir.is_dir():
+        return []
+    manifests = []
+    for entry in sorted(plugins_dir.iterdir()):
+        manifest_path = entry / MANIFEST_NAME
+        if entry.is_dir() and manifest_path.exists():
+            manifests.append(PluginManifest.load(manifest_path))
+    return manifests
+
+
+def invoke_plugin(manifest: PluginManifest, request: dict) -> dict:
+    """Run the plugin process for one request and return its JSON response.
+
+    The process gets only PATH in its environment, runs in the plugin
+    directory, and is killed after the manifest timeout.
+    """
+    try:
+        result = subprocess.run(
+            list(manifest.command),
+            input=json.dumps(request),
+            capture_output=True,
+            text=True,
+            timeout=manifest.timeout_seconds,
+            cwd=manifest.plugin_dir,
+            env={"PATH": os.environ.get("PATH", "")},
+        )
+    except subprocess.TimeoutExpired as exc:
+        raise PluginError(
+            f"plugin {manifest.name} timed out after {manifest.timeout_seconds}s"
+        ) from exc
+    except OSError as exc:
+        raise PluginError(f"plugin {manifest.name} failed to start: {exc}") from exc
+    if result.returncode != 0:
+        stderr_tail = result.stderr.strip().splitlines()[-3:]
+        raise PluginError(
+            f"plugin {manifest.name} exited with {result.returncode}: "
+            + " | ".join(stderr_tail)
+        )
+    try:
+        response = json.loads(result.stdout)
+    except json.JSONDecodeError as exc:
+        raise PluginError(f"plugin {manifest.name} returned invalid JSON: {exc}") from exc
+    if not isinstance(response, dict):
+        raise PluginError(f"plugin {manifest.name} response must be a JSON object")
+    return response
+
+
+def handshake(manifest: PluginManifest) -> dict:
+    """Negotiate capabilities with the plugin, raising on mismatch."""
+    response = invoke_plugin(
+        manifest, {"op": "handshake", "protocol_version": PROTOCOL_VERSION}
+    )
+    if response.get("protocol_version") != PROTOCOL_VERSION:
+        raise PluginError(
+            f"plugin {manifest.name} speaks protocol "
+            f"{response.get('protocol_version')!r}, host requires {PROTOCOL_VERSION}"
+        )
+    capabilities = response.get("capabilities") or []
+    if "transform" not in capabilities:
+        raise PluginError(
+            f"plugin {manifest.name} does not advertise the 'transform' capability"
+        )
+    return response
+
+
+class PluginAdapter(BaseAdapter):
+    """Adapter that delegates payload transformation to an external plugin.
+
+    The host keeps all of BaseAdapter's guarantees: JSON schema
+    validation (against the schema the plugin ships), landing zone table
+    creation and validation, and the tool run lifecycle. Only the
+    payload-to-rows mapping crosses the process boundary.
+    """
+
+    def __init__(
+        self,
+        manifest: PluginManifest,
+        run_repo: ToolRunRepository,
+        layout_repo: LayoutRepository | None = None,
+        *,
+        repo_root: Path | None = None,
+        logger: Callable[[str], None] | None = None,
+    ) -> None:
+        super().__init__(run_repo, layout_repo, repo_root=repo_root, logger=logger)
+        self._manifest = manifest
+        self._negotiated = False
+
+    @property
+    def tool_name(self) -> str:
+        return self._manifest.name
+
+    @property
+    def schema_path(self) -> Path:
+        return self._manifest.plugin_dir / self._manifest.output_schema
+
+    @property
+    def lz_tables(self) -> dict[str, dict[str, str]]:
+        return {
+            table: {"run_pk": "BIGINT", **columns}
+            for table, columns in self._manifest.tables.items()
+        }
+
+    @property
+    def table_ddl(self) -> dict[str, str]:
+        ddl = {}
+        for table, columns in self._manifest.tables.items():
+            column_lines = ",\n".join(
+                f"            {column} {column_type}"
+                for column, column_type in columns.items()
+            )
+            ddl[table] = (
+                f"CREATE TABLE IF NOT EXISTS {table} (\n"
+                f"            run_pk BIGINT NOT NULL,\n"
+                f"{column_lines}\n"
+                f"        )"
+            )
+        return ddl
+
+    def _do_persist(self, payload: dict) -> int:
+        """Persist plugin output: handshake once, transform, insert rows."""
+        metadata = payload.get("metadata") or {}
+
+        if not self._negotiated:
+            handshake(self._manifest)
+            self._negotiated = True
+
+        response = invoke_plugin(
+            self._manifest,
+            {"op": "transform", "protocol_version": PROTOCOL_VERSION, "payload": payload},
+        )
+        rows_by_table = response.get("tables") or {}
+        self.validate_quality(rows_by_table)
+
+        run_pk = self._create_tool_run(metadata)
+        for table, columns in self._manifest.tables.items():
+            rows = rows_by_table.get(table) or []
+            if not rows:
+                continue
+            column_names = ["run_pk", *columns]
+            placeholders = ", ".join("?" for _ in column_names)
+            self._conn.executemany(
+                f"INSERT INTO {table} ({', '.join(column_names)}) VALUES ({placeholders})",
+                [[run_pk, *(row.get(column) for column in columns)] for row in rows],
+            )
+            self._log(f"Inserted {len(rows)} rows into {table} from plugin {self._manifest.name}")
+        return run_pk
+
+    def validate_quality(self, data: Any) -> None:
+        """Validate the plugin's transform response against its manifest."""
+        errors: list[str] = []
+        if not isinstance(data, dict):
+            self._raise_quality_errors(["transform response 'tables' must be an object"])
+            return
+        for table, rows in data.items():
+            columns = self._manifest.tables.get(table)
+            if columns is None:
+                errors.append(f"undeclared table in response: {table}")
+                continue
+            if not isinstance(rows, list):
+                errors.append(f"{table}: rows must be an array")
+                continue
+            for idx, row in enumerate(rows):
+                if not isinstance(row, dict):
+                    errors.append(f"{table}[{idx}]: row must be an object")
+                    continue
+                unexpected = set(row) - set(columns)
+                if unexpected:
+                    errors.append(
+                        f"{table}[{idx}]: undeclared columns: {sorted(unexpected)}"
+                    )
+        self._raise_quality_errors(errors)
@@ -0,0 +1,135 @@
+"""Compressed raw-output archive with lazy loading.
+
+Keeping the full semgrep JSON for every historical run as plain files
+multiplies disk usage for data that is read back rarely, if ever. This
+store archives raw tool outputs compressed — zstd when the ``zstandard``
+package is installed, stdlib LZMA otherwise, with the codec recorded in
+the suffix so either side can read archives written by the other — and
+hands reports a ``LazyRawOutput`` that decompresses and parses only when
+a section actually touches the data.
+
+Archives live under ``<root>/<run-id>/<tool>.json.zst`` (or ``.xz``);
+``delete_run`` lets retention GC drop them together with the run's
+database rows.
+"""
+
+from __future__ import annotations
+
+import json
+import lzma
+from pathlib import Path
+from typing import Any
+
+try:
+    import zstandard
+
+    ZSTD_AVAILABLE = True
+except ImportError:
+    ZSTD_AVAILABLE = False
+
+DEFAULT_RAW_STORE_ROOT = Path.home() / ".caldera" / "raw"
+
+_SUFFIXES = (".json.zst", ".json.xz")
+
+
+def _compress(data: bytes) -> tuple[bytes, str]:
+    if ZSTD_AVAILABLE:
+        return zstandard.ZstdCompressor(level=9).compress(data), ".json.zst"
+    return lzma.compress(data, preset=6), ".json.xz"
+
+
+def _decompress(path: Path) -> bytes:
+    if path.name.endswith(".zst"):
+        if not ZSTD_AVAILABLE:
+            raise RuntimeError(
+                f"{path} is zstd-compressed but the zstandard package is not installed"
+            )
+        return zstandard.ZstdDecompressor().decompress(path.read_bytes())
+    return lzma.decompress(path.read_bytes())
+
+
+class LazyRawOutput:
+    """One archived output, decompressed and parsed on first access."""
+
+    def __init__(self, path: Path) -> None:
+        self.path = path
+        self._data: Any = None
+        self._loaded = False
+
+    @property
+    def data(self) -> Any:
+        if not self._loaded:
+            self._data = json.loads(_decompress(self.path))
+            self._loaded = True
+        return self._data
+
+    @property
+    def loaded(self) -> bool:
+        return self._loaded
+
+    @property
+    def compressed_size(self) -> int:
+        return self.path.stat().st_size
+
+
+class RawResultStore:
+    """Archive of compressed raw tool outputs, one directory per run."""
+
+    def __init__(self, root: Path = DEFAULT_RAW_STORE_ROOT) -> None:
+        self.root = Path(root)
+
+    def archive(self, run_id: str, tool_name: str, output_path: Path) -> Path:
+        """Compress one tool output into the store; returns the archive path.
+
+        Re-archiving the same (run, tool) replaces the previous archive,
+        including one written with the other codec.
+        """
+        data = Path(output_path).read_bytes()
+        compressed, suffix = _compress(data)
+        run_dir = self.root / run_id
+        run_dir.mkdir(parents=True, exist_ok=True)
+        for stale in run_dir.glob(f"{tool_name}.json.*"):
+            stale.unlink()
+        target = run_dir / f"{tool_name}{suffix}"
+        target.write_bytes(compressed)
+        return target
+
+    def find(self, run_id: str, tool_name: str) -> Path | None:
+        for suffix in _SUFFIXES:
+            candidate = self.root / run_id / f"{tool_name}{suffix}"
+            if candidate.exists():
+                return candidate
+        return None
+
+    def load(self, run_id: str, tool_name: str) -> LazyRawOutput | None:
+        """A lazy handle to an archived output, or None if not archived."""
+        path = self.find(run_id, tool_name)
+        return LazyRawOutput(path) if path else None
+
+    def list_runs(self) -> list[str]:
+        if not self.root.exists():
+            return []
+        return sorted(p.name for p in self.root.iterdir() if p.is_dir())
+
+    def list_tools(self, run_id: str) -> list[str]:
+        run_dir = self.root / run_id
+        if not run_dir.exists():
+            return []
+        names = set()
+        for path in run_dir.iterdir():
+            for suffix in _SUFFIXES:
+                if path.name.endswith(suffix):
+                    names.add(path.name[: -len(suffix)])
+        return sorted(names)
+
+    def delete_run(self, run_id: str) -> int:
+        """Remove a run's archives (retention GC); returns bytes freed."""
+        run_dir = self.root / run_id
+        if not run_dir.exists():
+            return 0
+        freed = 0
+        for path in run_dir.iterdir():
+            freed += path.stat().st_size
+            path.unlink()
+        run_dir.rmdir()
+        return freed
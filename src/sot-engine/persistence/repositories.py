@@ -5,6 +5,8 @@ from typing import Any, Callable, Iterable, Sequence, TypeVar
 import duckdb
 
 from .entities import (
+    BanditFinding,
+    CheckovFinding,
     CodeSymbol,
     CollectionRun,
     CoverageSummary,
@@ -24,6 +26,7 @@ from .entities import (
     GitSizerMetric,
     GitSizerViolation,
     GitleaksSecret,
+    GolangciFinding,
     LayoutDirectory,
     LayoutFile,
     LizardExcludedFile,
@@ -33,13 +36,18 @@ from .entities import (
     PmdCpdFileMetric,
     PmdCpdOccurrence,
     RoslynViolation,
+    RustApiItem,
+    RustDeadcodeFinding,
     ScancodeFileLicense,
     ScancodeSummary,
     SccFileMetric,
     SemgrepSmell,
+    ShellcheckFinding,
     SonarqubeIssue,
     SonarqubeMetric,
+    SqlfluffFinding,
     SymbolCall,
+    TodoComment,
     ToolRun,
     TrivyIacMisconfig,
     TrivyTarget,
@@ -75,9 +83,19 @@ _VALID_LZ_TABLES = frozenset([
     "lz_file_imports",
     "lz_scancode_file_licenses",
     "lz_scancode_summary",
+    "lz_bandit_findings",
+    "lz_shellcheck_findings",
+    "lz_checkov_findings",
+    "lz_golangci_findings",
+    "lz_sqlfluff_findings",
+    "lz_todo_comments",
+    "lz_rust_deadcode_findings",
     "lz_pmd_cpd_file_metrics",
     "lz_pmd_cpd_duplications",
     "lz_pmd_cpd_occurrences",
+    "lz_jscpd_file_metrics",
+    "lz_jscpd_duplications",
+    "lz_jscpd_occurrences",
     "lz_dotcover_assembly_coverage",
     "lz_dotcover_type_coverage",
     "lz_dotcover_method_coverage",
@@ -379,7 +397,7 @@ class LizardRepository(BaseRepository):
     )
     _FUNC_COLUMNS = (
         "run_pk", "file_id", "function_name", "long_name", "ccn", "nloc",
-        "params", "token_count", "line_start", "line_end",
+        "params", "token_count", "line_start", "line_end", "max_nesting_depth",
     )
     _EXCLUDED_COLUMNS = (
         "run_pk", "file_path", "reason", "language", "details",
@@ -403,7 +421,7 @@ class LizardRepository(BaseRepository):
             rows,
             lambda r: (
                 r.run_pk, r.file_id, r.function_name, r.long_name, r.ccn, r.nloc,
-                r.params, r.token_count, r.line_start, r.line_end,
+                r.params, r.token_count, r.line_start, r.line_end, r.max_nesting_depth,
             ),
         )
 
@@ -476,6 +494,26 @@ class RoslynRepository(BaseRepository):
         )
 
 
+class BanditRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "rule_id",
+        "dd_category", "severity", "confidence", "cwe_id", "line_start",
+        "line_end", "column_start", "message", "code_snippet",
+    )
+
+    def insert_findings(self, rows: Iterable[BanditFinding]) -> None:
+        self._insert_bulk(
+            "lz_bandit_findings",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.rule_id,
+                r.dd_category, r.severity, r.confidence, r.cwe_id, r.line_start,
+                r.line_end, r.column_start, r.message, r.code_snippet,
+            ),
+        )
+
+
 class DevskimRepository(BaseRepository):
     _COLUMNS = (
         "run_pk", "file_id", "directory_id", "relative_path", "rule_id",
@@ -496,6 +534,142 @@ class DevskimRepository(BaseRepository):
         )
 
 
+class CheckovRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "rule_id",
+        "check_name", "framework", "dd_category", "resource", "severity",
+        "line_start", "line_end", "guideline",
+    )
+
+    def insert_findings(self, rows: Iterable[CheckovFinding]) -> None:
+        self._insert_bulk(
+            "lz_checkov_findings",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.rule_id,
+                r.check_name, r.framework, r.dd_category, r.resource, r.severity,
+                r.line_start, r.line_end, r.guideline,
+            ),
+        )
+
+
+class GolangciRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "rule_id",
+        "dd_category", "severity", "line_start", "line_end",
+        "column_start", "message",
+    )
+
+    def insert_findings(self, rows: Iterable[GolangciFinding]) -> None:
+        self._insert_bulk(
+            "lz_golangci_findings",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.rule_id,
+                r.dd_category, r.severity, r.line_start, r.line_end,
+                r.column_start, r.message,
+            ),
+        )
+
+
+class ShellcheckRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "rule_id",
+        "level", "dd_category", "severity", "line_start", "line_end",
+        "column_start", "column_end", "message",
+    )
+
+    def insert_findings(self, rows: Iterable[ShellcheckFinding]) -> None:
+        self._insert_bulk(
+            "lz_shellcheck_findings",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.rule_id,
+                r.level, r.dd_category, r.severity, r.line_start, r.line_end,
+                r.column_start, r.column_end, r.message,
+            ),
+        )
+
+
+class SqlfluffRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "rule_id",
+        "rule_name", "dd_category", "severity", "line_start", "line_end",
+        "column_start", "message",
+    )
+
+    def insert_findings(self, rows: Iterable[SqlfluffFinding]) -> None:
+        self._insert_bulk(
+            "lz_sqlfluff_findings",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.rule_id,
+                r.rule_name, r.dd_category, r.severity, r.line_start, r.line_end,
+                r.column_start, r.message,
+            ),
+        )
+
+
+class RustDeadcodeRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "symbol_name",
+        "symbol_kind", "source", "confidence", "line", "message",
+    )
+
+    def insert_findings(self, rows: Iterable[RustDeadcodeFinding]) -> None:
+        self._insert_bulk(
+            "lz_rust_deadcode_findings",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.symbol_name,
+                r.symbol_kind, r.source, r.confidence, r.line, r.message,
+            ),
+        )
+
+
+class RustApiRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "item_name",
+        "item_kind", "signature", "line", "crate",
+    )
+
+    def insert_items(self, rows: Iterable[RustApiItem]) -> None:
+        self._insert_bulk(
+            "lz_rust_api_items",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.item_name,
+                r.item_kind, r.signature, r.line, r.crate,
+            ),
+        )
+
+
+class TodoScannerRepository(BaseRepository):
+    _COLUMNS = (
+        "run_pk", "file_id", "directory_id", "relative_path", "marker",
+        "severity", "line", "comment_text", "author_email", "introduced_at",
+        "age_days",
+    )
+
+    def insert_comments(self, rows: Iterable[TodoComment]) -> None:
+        self._insert_bulk(
+            "lz_todo_comments",
+            self._COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.marker,
+                r.severity, r.line, r.comment_text, r.author_email, r.introduced_at,
+                r.age_days,
+            ),
+        )
+
+
 class SonarqubeRepository(BaseRepository):
     _ISSUE_COLUMNS = (
         "run_pk", "file_id", "directory_id", "relative_path", "issue_key",
@@ -797,6 +971,50 @@ class PmdCpdRepository(BaseRepository):
         )
 
 
+class JscpdRepository(PmdCpdRepository):
+    """Repository for jscpd duplication analysis data.
+
+    jscpd shares pmd-cpd's clone model, so the bulk-insert shapes are
+    inherited; only the landing zone table names differ.
+    """
+
+    def insert_file_metrics(self, rows: Iterable[PmdCpdFileMetric]) -> None:
+        """Insert per-file duplication metrics."""
+        self._insert_bulk(
+            "lz_jscpd_file_metrics",
+            self._FILE_COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.file_id, r.directory_id, r.relative_path, r.language,
+                r.total_lines, r.duplicate_lines, r.duplicate_blocks, r.duplication_percentage,
+            ),
+        )
+
+    def insert_duplications(self, rows: Iterable[PmdCpdDuplication]) -> None:
+        """Insert clone group records."""
+        self._insert_bulk(
+            "lz_jscpd_duplications",
+            self._DUPLICATION_COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.clone_id, r.lines, r.tokens, r.occurrence_count,
+                r.is_cross_file, r.code_fragment,
+            ),
+        )
+
+    def insert_occurrences(self, rows: Iterable[PmdCpdOccurrence]) -> None:
+        """Insert individual clone locations."""
+        self._insert_bulk(
+            "lz_jscpd_occurrences",
+            self._OCCURRENCE_COLUMNS,
+            rows,
+            lambda r: (
+                r.run_pk, r.clone_id, r.file_id, r.directory_id, r.relative_path,
+                r.line_start, r.line_end, r.column_start, r.column_end,
+            ),
+        )
+
+
 class DotcoverRepository(BaseRepository):
     """Repository for dotcover code coverage data."""
 
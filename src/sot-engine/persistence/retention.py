@@ -0,0 +1,170 @@
+"""Run retention and landing-zone garbage collection.
+
+Long-lived result databases accumulate a collection run per scan and
+never shrink. This module prunes runs past a configurable retention
+policy — keep the N newest runs per repo, drop runs older than a cutoff —
+and compacts the store afterwards. Runs still in ``running`` status are
+never pruned. Executed by ``caldera store gc`` or on a cron in daemon
+mode.
+
+Policy lives in ``[retention]`` in ``caldera.toml``::
+
+    [retention]
+    keep_runs_per_repo = 20   # newest runs kept per repo (0 = unlimited)
+    max_age_days = 180        # runs started earlier are pruned (0 = no cutoff)
+"""
+
+from __future__ import annotations
+
+import tomllib
+from dataclasses import dataclass
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+from .repositories import _VALID_LZ_TABLES
+
+DEFAULT_KEEP_RUNS = 20
+DEFAULT_MAX_AGE_DAYS = 0  # no age cutoff unless configured
+
+# Tool-specific tables carry run_pk; lz_tool_runs itself is deleted last,
+# lz_collection_runs after that.
+_RUN_PK_TABLES = sorted(_VALID_LZ_TABLES - {"lz_tool_runs"})
+
+
+@dataclass(frozen=True)
+class RetentionPolicy:
+    """How much run history to keep."""
+
+    keep_runs_per_repo: int = DEFAULT_KEEP_RUNS
+    max_age_days: int = DEFAULT_MAX_AGE_DAYS
+
+    def __post_init__(self) -> None:
+        if self.keep_runs_per_repo < 0:
+            raise ValueError("keep_runs_per_repo must be >= 0")
+        if self.max_age_days < 0:
+            raise ValueError("max_age_days must be >= 0")
+
+
+@dataclass(frozen=True)
+class GcPlan:
+    """Collection runs selected for pruning, with the reason per run."""
+
+    prune: tuple[tuple[str, str], ...]  # (collection_run_id, reason)
+
+    @property
+    def run_ids(self) -> tuple[str, ...]:
+        return tuple(run_id for run_id, _ in self.prune)
+
+
+def load_retention_policy(caldera_toml: Path | None = None) -> RetentionPolicy:
+    """Load ``[retention]`` from caldera.toml, falling back to defaults."""
+    if caldera_toml is None or not caldera_toml.exists():
+        return RetentionPolicy()
+    config = tomllib.loads(caldera_toml.read_text()).get("retention", {})
+    return RetentionPolicy(
+        keep_runs_per_repo=int(config.get("keep_runs_per_repo", DEFAULT_KEEP_RUNS)),
+        max_age_days=int(config.get("max_age_days", DEFAULT_MAX_AGE_DAYS)),
+    )
+
+
+def plan_gc(conn, policy: RetentionPolicy, now: datetime | None = None) -> GcPlan:
+    """Select prunable collection runs under the policy.
+
+    Per repo, the newest ``keep_runs_per_repo`` runs survive; anything
+    older than ``max_age_days`` is pruned regardless. Runs in ``running``
+    status are always kept — pruning a run mid-scan would corrupt it.
+    """
+    now = now or datetime.now(timezone.utc)
+    rows = conn.execute(
+        """SELECT collection_run_id, repo_id, started_at, status
+           FROM lz_collection_runs
+           ORDER BY repo_id, started_at DESC"""
+    ).fetchall()
+
+    cutoff = (
+        (now - timedelta(days=policy.max_age_days)).replace(tzinfo=None)
+        if policy.max_age_days
+        else None
+    )
+    prune: list[tuple[str, str]] = []
+    seen_per_repo: dict[str, int] = {}
+    for collection_run_id, repo_id, started_at, status in rows:
+        if status == "running":
+            continue
+        seen_per_repo[repo_id] = seen_per_repo.get(repo_id, 0) + 1
+        if policy.keep_runs_per_repo and seen_per_repo[repo_id] > policy.keep_runs_per_repo:
+            prune.append((collection_run_id, f"beyond newest {policy.keep_runs_per_repo}"))
+        elif cutoff is not None and started_at is not None and started_at < cutoff:
+            prune.append((collection_run_id, f"older than {policy.max_age_days} days"))
+    return GcPlan(prune=tuple(prune))
+
+
+def execute_gc(conn, plan: GcPlan) -> dict[str, int]:
+    """Delete the planned runs and all their rows; returns rows per table."""
+    if not plan.prune:
+        return {}
+    run_ids = list(plan.run_ids)
+    placeholders = ", ".join("?" for _ in run_ids)
+    deleted: dict[str, int] = {}
+    for table in _RUN_PK_TABLES:
+        result = conn.execute(
+            f"""DELETE FROM {table}
+                WHERE run_pk IN (
+                    SELECT run_pk FROM lz_tool_runs
+                    WHERE collection_run_id IN ({placeholders})
+                )""",
+            run_ids,
+        ).fetchall()
+        count = result[0][0] if result and result[0] else 0
+        if count:
+            deleted[table] = count
+    for table in ("lz_tool_runs", "lz_collection_runs"):
+        result = conn.execute(
+            f"DELETE FROM {table} WHERE collection_run_id IN ({placeholders})",
+            run_ids,
+        ).fetchall()
+        count = result[0][0] if result and result[0] else 0
+        if count:
+            deleted[table] = count
+    return deleted
+
+
+def compact(conn) -> None:
+    """Reclaim space after deletes (DuckDB checkpoint; no-op elsewhere)."""
+    if getattr(conn, "dialect", "duckdb") != "duckdb":
+        return
+    conn.execute("CHECKPOINT")
+
+
+def run_gc(
+    db_path: str,
+    policy: RetentionPolicy,
+    dry_run: bool = False,
+    do_compact: bool = True,
+) -> dict:
+    """Plan and (unless dry-run) execute GC against a database.
+
+    Returns a summary with the pruned runs, per-table row counts, and
+    database size before/after for file-backed stores.
+    """
+    from .backend import connect_database
+
+    size_before = Path(db_path).stat().st_size if Path(db_path).is_file() else None
+    conn = connect_database(db_path)
+    try:
+        plan = plan_gc(conn, policy)
+        deleted: dict[str, int] = {}
+        if not dry_run and plan.prune:
+            deleted = execute_gc(conn, plan)
+            if do_compact:
+                compact(conn)
+    finally:
+        conn.close()
+    size_after = Path(db_path).stat().st_size if Path(db_path).is_file() else None
+    return {
+        "dry_run": dry_run,
+        "pruned_runs": [{"collection_run_id": r, "reason": reason} for r, reason in plan.prune],
+        "rows_deleted": deleted,
+        "size_before_bytes": size_before,
+        "size_after_bytes": size_after,
+    }
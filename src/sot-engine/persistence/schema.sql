@@ -108,6 +108,7 @@ CREATE TABLE lz_lizard_function_metrics (
     token_count INTEGER,
     line_start INTEGER,
     line_end INTEGER,
+    max_nesting_depth INTEGER,
     PRIMARY KEY (run_pk, file_id, function_name, line_start)
 );
 
@@ -176,6 +177,139 @@ CREATE TABLE lz_roslyn_violations (
     PRIMARY KEY (run_pk, file_id, rule_id, line_start, column_start)
 );
 
+CREATE TABLE lz_bandit_findings (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    rule_id VARCHAR NOT NULL,
+    dd_category VARCHAR,
+    severity VARCHAR,
+    confidence VARCHAR,
+    cwe_id VARCHAR,
+    line_start INTEGER,
+    line_end INTEGER,
+    column_start INTEGER,
+    message TEXT,
+    code_snippet TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, rule_id, line_start)
+);
+
+CREATE TABLE lz_checkov_findings (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    rule_id VARCHAR NOT NULL,
+    check_name VARCHAR,
+    framework VARCHAR,
+    dd_category VARCHAR,
+    resource VARCHAR NOT NULL,
+    severity VARCHAR,
+    line_start INTEGER,
+    line_end INTEGER,
+    guideline VARCHAR,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, rule_id, resource, line_start)
+);
+
+CREATE TABLE lz_shellcheck_findings (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    rule_id VARCHAR NOT NULL,
+    level VARCHAR,
+    dd_category VARCHAR,
+    severity VARCHAR,
+    line_start INTEGER,
+    line_end INTEGER,
+    column_start INTEGER,
+    column_end INTEGER,
+    message TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, rule_id, line_start)
+);
+
+CREATE TABLE lz_golangci_findings (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    rule_id VARCHAR NOT NULL,
+    dd_category VARCHAR,
+    severity VARCHAR,
+    line_start INTEGER,
+    line_end INTEGER,
+    column_start INTEGER,
+    message TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, rule_id, line_start)
+);
+
+CREATE TABLE lz_sqlfluff_findings (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    rule_id VARCHAR NOT NULL,
+    rule_name VARCHAR,
+    dd_category VARCHAR,
+    severity VARCHAR,
+    line_start INTEGER,
+    line_end INTEGER,
+    column_start INTEGER,
+    message TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, rule_id, line_start)
+);
+
+CREATE TABLE lz_rust_deadcode_findings (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    symbol_name VARCHAR NOT NULL,
+    symbol_kind VARCHAR,
+    source VARCHAR NOT NULL,
+    confidence VARCHAR NOT NULL,
+    line INTEGER NOT NULL,
+    message TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, symbol_name, line)
+);
+
+CREATE TABLE lz_rust_api_items (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    item_name VARCHAR NOT NULL,
+    item_kind VARCHAR NOT NULL,
+    signature TEXT NOT NULL,
+    line INTEGER NOT NULL,
+    crate VARCHAR NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, item_kind, item_name, line)
+);
+
+CREATE TABLE lz_todo_comments (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    marker VARCHAR NOT NULL,
+    severity VARCHAR,
+    line INTEGER NOT NULL,
+    comment_text TEXT,
+    author_email VARCHAR,
+    introduced_at VARCHAR,
+    age_days INTEGER,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id, marker, line)
+);
+
 CREATE TABLE lz_devskim_findings (
     run_pk BIGINT NOT NULL,
     file_id VARCHAR NOT NULL,
@@ -461,6 +595,50 @@ CREATE TABLE lz_pmd_cpd_occurrences (
     PRIMARY KEY (run_pk, clone_id, file_id, line_start)
 );
 
+-- =============================================================================
+-- jscpd: JS/TS code duplication detection (same clone model as pmd-cpd)
+-- =============================================================================
+
+CREATE TABLE lz_jscpd_file_metrics (
+    run_pk BIGINT NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    language VARCHAR,
+    total_lines INTEGER NOT NULL,
+    duplicate_lines INTEGER NOT NULL,
+    duplicate_blocks INTEGER NOT NULL,
+    duplication_percentage DOUBLE NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, file_id)
+);
+
+CREATE TABLE lz_jscpd_duplications (
+    run_pk BIGINT NOT NULL,
+    clone_id VARCHAR NOT NULL,
+    lines INTEGER NOT NULL,
+    tokens INTEGER NOT NULL,
+    occurrence_count INTEGER NOT NULL,
+    is_cross_file BOOLEAN NOT NULL,
+    code_fragment TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, clone_id)
+);
+
+CREATE TABLE lz_jscpd_occurrences (
+    run_pk BIGINT NOT NULL,
+    clone_id VARCHAR NOT NULL,
+    file_id VARCHAR NOT NULL,
+    directory_id VARCHAR NOT NULL,
+    relative_path VARCHAR NOT NULL,
+    line_start INTEGER NOT NULL,
+    line_end INTEGER NOT NULL,
+    column_start INTEGER,
+    column_end INTEGER,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (run_pk, clone_id, file_id, line_start)
+);
+
 -- =============================================================================
 -- dotcover: Code coverage metrics
 -- =============================================================================
@@ -619,3 +797,21 @@ CREATE TABLE lz_coverage_summary (
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     PRIMARY KEY (run_pk, file_id)
 );
+
+-- =============================================================================
+-- Triage: persisted reviewer decisions, keyed by finding fingerprint
+-- =============================================================================
+
+-- Also created on demand by `caldera triage` (CREATE TABLE IF NOT EXISTS)
+-- so databases predating this table keep working.
+CREATE TABLE IF NOT EXISTS lz_triage_decisions (
+    fingerprint VARCHAR NOT NULL,
+    decision VARCHAR NOT NULL,
+    reason VARCHAR,
+    tool VARCHAR,
+    rule_id VARCHAR,
+    relative_path VARCHAR,
+    line INTEGER,
+    decided_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (fingerprint)
+);
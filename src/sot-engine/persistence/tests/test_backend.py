@@ -0,0 +1,97 @@
+"""Tests for the Postgres backend: SQL translation and migrations."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from persistence.backend import (
+    INITIAL_MIGRATION,
+    _PostgresResult,
+    apply_migrations,
+    parse_database_url,
+    translate_placeholders,
+    translate_sql,
+)
+
+
+class StubPostgresConnection:
+    """Records executed SQL; answers the migration bookkeeping queries."""
+
+    dialect = "postgres"
+
+    def __init__(self) -> None:
+        self.executed: list[tuple[str, list | None]] = []
+        self.applied: set[str] = set()
+
+    def execute(self, sql: str, params=None) -> _PostgresResult:
+        self.executed.append((sql, params))
+        if "SELECT version FROM schema_migrations" in sql:
+            return _PostgresResult([(version,) for version in self.applied])
+        if "INSERT INTO schema_migrations" in sql:
+            self.applied.add(params[0])
+        return _PostgresResult([])
+
+
+def test_parse_database_url_classifies_dsn_and_path() -> None:
+    assert parse_database_url("postgresql://user@host/db") == (
+        "postgres",
+        "postgresql://user@host/db",
+    )
+    assert parse_database_url("postgres://host/db")[0] == "postgres"
+    assert parse_database_url("/tmp/caldera.duckdb") == ("duckdb", "/tmp/caldera.duckdb")
+
+
+def test_placeholders_translated_outside_string_literals() -> None:
+    sql = "SELECT ? WHERE note = 'what?' AND x = ?"
+    assert translate_placeholders(sql) == "SELECT %s WHERE note = 'what?' AND x = %s"
+
+
+def test_translate_sql_quotes_commit_and_fixes_types() -> None:
+    sql = "SELECT commit, ratio DOUBLE FROM lz_tool_runs WHERE commit = ?"
+    translated = translate_sql(sql)
+    assert '"commit"' in translated
+    assert "DOUBLE PRECISION" in translated
+    assert translated.endswith("%s")
+
+
+def test_translate_sql_leaves_double_precision_alone() -> None:
+    assert translate_sql("x DOUBLE PRECISION").count("PRECISION") == 1
+
+
+def test_apply_migrations_runs_schema_then_files_in_order(tmp_path: Path) -> None:
+    schema = tmp_path / "schema.sql"
+    schema.write_text("CREATE TABLE t (x INT);")
+    migrations = tmp_path / "migrations"
+    migrations.mkdir()
+    (migrations / "0002_add_col.sql").write_text("ALTER TABLE t ADD COLUMN y INT;")
+    (migrations / "notes.sql").write_text("-- not a migration")
+    conn = StubPostgresConnection()
+
+    applied = apply_migrations(conn, schema, migrations_dir=migrations)
+
+    assert applied == [INITIAL_MIGRATION, "0002_add_col"]
+    statements = [sql for sql, _ in conn.executed]
+    assert any("pg_advisory_lock" in sql for sql in statements)
+    assert statements[-1].startswith("SELECT pg_advisory_unlock")
+
+
+def test_apply_migrations_is_idempotent(tmp_path: Path) -> None:
+    schema = tmp_path / "schema.sql"
+    schema.write_text("CREATE TABLE t (x INT);")
+    conn = StubPostgresConnection()
+
+    apply_migrations(conn, schema, migrations_dir=tmp_path / "none")
+    assert apply_migrations(conn, schema, migrations_dir=tmp_path / "none") == []
+
+
+def test_repository_sql_survives_translation() -> None:
+    # The exact insert shape repositories use for lz_tool_runs.
+    sql = (
+        "INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name, "
+        "tool_version, schema_version, branch, commit, timestamp) "
+        "VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+    translated = translate_sql(sql)
+    assert '"commit"' in translated
+    assert translated.count("%s") == 9
+    assert "?" not in translated
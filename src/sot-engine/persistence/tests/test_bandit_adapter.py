@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import BanditAdapter
+from persistence.repositories import (
+    BanditRepository,
+    LayoutRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "bandit_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_bandit_adapter_inserts_findings(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps issues to BanditFinding entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/db.py"),
+            ("f-000000000002", "d-000000000002", "src/settings.py"),
+            ("f-000000000003", "d-000000000002", "src/safe.py"),
+        ],
+    )
+
+    bandit_repo = BanditRepository(duckdb_conn)
+    adapter = BanditAdapter(tool_run_repo, layout_repo, bandit_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, rule_id, dd_category, severity, cwe_id, line_start
+           FROM lz_bandit_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 3  # 3 issues in fixture
+    rule_ids = {row[1] for row in result}
+    assert "B608" in rule_ids
+    assert "B105" in rule_ids
+    cwe_ids = {row[4] for row in result}
+    assert "CWE-89" in cwe_ids
+
+
+def test_bandit_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    bandit_repo = BanditRepository(duckdb_conn)
+    adapter = BanditAdapter(tool_run_repo, layout_repo, bandit_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_bandit_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/db.py"),
+            # src/settings.py intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    bandit_repo = BanditRepository(duckdb_conn)
+    adapter = BanditAdapter(tool_run_repo, layout_repo, bandit_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "settings.py" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_bandit_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "src/db.py" in paths
+    assert "src/settings.py" not in paths
@@ -0,0 +1,112 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import CheckovAdapter
+from persistence.repositories import (
+    CheckovRepository,
+    LayoutRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "checkov_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_checkov_adapter_inserts_findings(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps violations to CheckovFinding entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "terraform/insecure_s3.tf"),
+            ("f-000000000002", "d-000000000003", "kubernetes/privileged_pod.yaml"),
+            ("f-000000000003", "d-000000000002", "terraform/secure_bucket.tf"),
+        ],
+    )
+
+    checkov_repo = CheckovRepository(duckdb_conn)
+    adapter = CheckovAdapter(tool_run_repo, layout_repo, checkov_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, rule_id, framework, resource, severity
+           FROM lz_checkov_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 3  # 3 violations in fixture
+    rule_ids = {row[1] for row in result}
+    assert "CKV_AWS_19" in rule_ids
+    assert "CKV_K8S_16" in rule_ids
+    resources = {row[3] for row in result}
+    assert "aws_s3_bucket.data" in resources
+    assert "Pod.default.debug-shell" in resources
+    frameworks = {row[2] for row in result}
+    assert frameworks == {"terraform", "kubernetes"}
+
+
+def test_checkov_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    checkov_repo = CheckovRepository(duckdb_conn)
+    adapter = CheckovAdapter(tool_run_repo, layout_repo, checkov_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_checkov_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "terraform/insecure_s3.tf"),
+            # kubernetes/privileged_pod.yaml intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    checkov_repo = CheckovRepository(duckdb_conn)
+    adapter = CheckovAdapter(tool_run_repo, layout_repo, checkov_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "privileged_pod" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_checkov_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "terraform/insecure_s3.tf" in paths
+    assert "kubernetes/privileged_pod.yaml" not in paths
@@ -223,3 +223,86 @@ def test_devskim_adapter_deduplicates_findings(
     ).fetchone()
 
     assert result[0] == 3  # Original 3 findings, duplicate was skipped
+
+
+def test_devskim_adapter_isolates_bad_entries_when_not_strict(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify a pathological file entry becomes a diagnostic, not a failure."""
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "devskim_output.json"
+    payload = copy.deepcopy(json.loads(fixture_path.read_text()))
+
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    # Break one file's issue so DevskimFinding validation fails for it
+    payload["data"]["files"][0]["issues"][0]["rule_id"] = ""
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/crypto.cs"),
+            ("f-000000000002", "d-000000000002", "src/serializer.cs"),
+            ("f-000000000003", "d-000000000002", "src/safe.cs"),
+        ],
+    )
+
+    logs: list[str] = []
+    devskim_repo = DevskimRepository(duckdb_conn)
+    adapter = DevskimAdapter(
+        tool_run_repo,
+        layout_repo,
+        devskim_repo,
+        logger=logs.append,
+    )
+    adapter.strict = False
+    run_pk = adapter.persist(payload)
+
+    # The broken file entry is captured as a diagnostic...
+    assert any(diag["scope"] == "entry" for diag in adapter.diagnostics)
+    assert any("skipped src/crypto.cs" in log for log in logs)
+
+    # ...while the other file's findings are still persisted
+    result = duckdb_conn.execute(
+        "SELECT relative_path FROM lz_devskim_findings WHERE run_pk = ?",
+        [run_pk],
+    ).fetchall()
+    paths = {row[0] for row in result}
+    assert "src/serializer.cs" in paths
+    assert "src/crypto.cs" not in paths
+
+
+def test_devskim_adapter_strict_mode_fails_fast(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify strict mode restores the old fail-fast behavior."""
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "devskim_output.json"
+    payload = copy.deepcopy(json.loads(fixture_path.read_text()))
+    payload["data"]["files"][0]["issues"][0]["rule_id"] = ""
+
+    seed_layout(
+        payload["metadata"]["repo_id"],
+        payload["metadata"]["run_id"],
+        [
+            ("f-000000000001", "d-000000000002", "src/crypto.cs"),
+            ("f-000000000002", "d-000000000002", "src/serializer.cs"),
+        ],
+    )
+
+    devskim_repo = DevskimRepository(duckdb_conn)
+    adapter = DevskimAdapter(
+        tool_run_repo,
+        layout_repo,
+        devskim_repo,
+    )
+    assert adapter.strict  # fail-fast is the direct-use default
+
+    with pytest.raises(ValueError):
+        adapter.persist(payload)
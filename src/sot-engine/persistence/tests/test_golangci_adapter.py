@@ -0,0 +1,112 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import GolangciAdapter
+from persistence.repositories import (
+    GolangciRepository,
+    LayoutRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "golangci_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_golangci_adapter_inserts_findings(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps issues to GolangciFinding entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "internal/db/query.go"),
+            ("f-000000000002", "d-000000000003", "internal/report/report.go"),
+            ("f-000000000003", "d-000000000004", "pkg/util/util.go"),
+        ],
+    )
+
+    golangci_repo = GolangciRepository(duckdb_conn)
+    adapter = GolangciAdapter(tool_run_repo, layout_repo, golangci_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, rule_id, dd_category, severity, line_start, line_end
+           FROM lz_golangci_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 4  # 4 issues in fixture
+    rule_ids = {row[1] for row in result}
+    assert rule_ids == {"gosec", "errcheck", "dupl"}
+    gosec_rows = [row for row in result if row[1] == "gosec"]
+    assert gosec_rows[0][0] == "internal/db/query.go"
+    assert gosec_rows[0][3] == "HIGH"
+    dupl_rows = [row for row in result if row[1] == "dupl"]
+    assert len(dupl_rows) == 2
+    assert {(row[4], row[5]) for row in dupl_rows} == {(17, 58), (61, 102)}
+
+
+def test_golangci_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    golangci_repo = GolangciRepository(duckdb_conn)
+    adapter = GolangciAdapter(tool_run_repo, layout_repo, golangci_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_golangci_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "internal/db/query.go"),
+            # internal/report/report.go intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    golangci_repo = GolangciRepository(duckdb_conn)
+    adapter = GolangciAdapter(tool_run_repo, layout_repo, golangci_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "report.go" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_golangci_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "internal/db/query.go" in paths
+    assert "internal/report/report.go" not in paths
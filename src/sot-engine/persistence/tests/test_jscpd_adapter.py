@@ -0,0 +1,127 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import JscpdAdapter
+from persistence.repositories import (
+    JscpdRepository,
+    LayoutRepository,
+    ToolRunRepository,
+)
+
+
+def _seed_jscpd_layout(seed_layout, payload: dict) -> None:
+    seed_layout(
+        payload["metadata"]["repo_id"],
+        payload["metadata"]["run_id"],
+        [
+            ("f-000000000001", "d-000000000002", "src/app.ts"),
+            ("f-000000000002", "d-000000000002", "src/utils.ts"),
+            ("f-000000000003", "d-000000000002", "src/clean.ts"),
+        ],
+    )
+
+
+def test_jscpd_adapter_inserts_file_metrics(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly persists per-file duplication metrics."""
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "jscpd_output.json"
+    payload = json.loads(fixture_path.read_text())
+
+    _seed_jscpd_layout(seed_layout, payload)
+
+    jscpd_repo = JscpdRepository(duckdb_conn)
+    adapter = JscpdAdapter(
+        tool_run_repo,
+        layout_repo,
+        jscpd_repo,
+    )
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, total_lines, duplicate_lines, duplication_percentage
+           FROM lz_jscpd_file_metrics WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 3  # 3 files in fixture
+    metrics_by_path = {row[0]: row for row in result}
+
+    assert metrics_by_path["src/app.ts"][1] == 120  # total_lines
+    assert metrics_by_path["src/app.ts"][2] == 30  # duplicate_lines
+    assert metrics_by_path["src/app.ts"][3] == 25.0  # duplication_percentage
+
+
+def test_jscpd_adapter_inserts_duplications_and_occurrences(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter persists clone groups and their occurrences."""
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "jscpd_output.json"
+    payload = json.loads(fixture_path.read_text())
+
+    _seed_jscpd_layout(seed_layout, payload)
+
+    jscpd_repo = JscpdRepository(duckdb_conn)
+    adapter = JscpdAdapter(
+        tool_run_repo,
+        layout_repo,
+        jscpd_repo,
+    )
+    run_pk = adapter.persist(payload)
+
+    dups = duckdb_conn.execute(
+        """SELECT clone_id, lines, tokens, occurrence_count, is_cross_file
+           FROM lz_jscpd_duplications WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(dups) == 2  # 2 duplications in fixture
+    dups_by_id = {row[0]: row for row in dups}
+
+    # JSCPD-0001 is cross-file (app.ts + utils.ts)
+    assert dups_by_id["JSCPD-0001"][1] == 15  # lines
+    assert dups_by_id["JSCPD-0001"][2] == 82  # tokens
+    assert dups_by_id["JSCPD-0001"][3] == 2  # occurrence_count
+    assert dups_by_id["JSCPD-0001"][4] is True  # is_cross_file
+
+    # JSCPD-0002 is same-file (both in app.ts)
+    assert dups_by_id["JSCPD-0002"][4] is False  # is_cross_file
+
+    occs = duckdb_conn.execute(
+        """SELECT clone_id, relative_path FROM lz_jscpd_occurrences
+           WHERE run_pk = ? ORDER BY clone_id, line_start""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(occs) == 4  # 2 occurrences per duplication
+    assert {r[1] for r in occs if r[0] == "JSCPD-0001"} == {"src/app.ts", "src/utils.ts"}
+
+
+def test_jscpd_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists."""
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "jscpd_output.json"
+    payload = json.loads(fixture_path.read_text())
+
+    jscpd_repo = JscpdRepository(duckdb_conn)
+    adapter = JscpdAdapter(
+        tool_run_repo,
+        layout_repo,
+        jscpd_repo,
+    )
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
@@ -0,0 +1,192 @@
+from __future__ import annotations
+
+import json
+import textwrap
+from pathlib import Path
+
+import pytest
+
+from persistence.plugins import (
+    PluginAdapter,
+    PluginError,
+    PluginManifest,
+    discover_plugins,
+    handshake,
+)
+from persistence.repositories import ToolRunRepository
+
+HANDSHAKE_SCRIPT = textwrap.dedent(
+    """
+    import json, sys
+    request = json.load(sys.stdin)
+    if request["op"] == "handshake":
+        print(json.dumps({
+            "name": "acme-analyzer",
+            "version": "1.0.0",
+            "protocol_version": 1,
+            "capabilities": ["transform"],
+        }))
+    else:
+        rows = [
+            {
+                "file_path": finding["path"],
+                "severity": finding["severity"],
+                "finding_count": finding["count"],
+            }
+            for finding in request["payload"]["data"]["findings"]
+        ]
+        print(json.dumps({"tables": {"lz_acme_findings": rows}}))
+    """
+)
+
+
+def _write_plugin(plugin_dir: Path, script: str = HANDSHAKE_SCRIPT) -> PluginManifest:
+    """Write a runnable stub plugin and return its loaded manifest."""
+    plugin_dir.mkdir(parents=True, exist_ok=True)
+    (plugin_dir / "plugin.py").write_text(script)
+    (plugin_dir / "schemas").mkdir(exist_ok=True)
+    (plugin_dir / "schemas" / "output.schema.json").write_text(json.dumps({"type": "object"}))
+    manifest_path = plugin_dir / "caldera-plugin.json"
+    manifest_path.write_text(json.dumps({
+        "name": "acme-analyzer",
+        "version": "1.0.0",
+        "command": ["python3", "plugin.py"],
+        "output_schema": "schemas/output.schema.json",
+        "tables": {
+            "lz_acme_findings": {
+                "file_path": "VARCHAR",
+                "severity": "VARCHAR",
+                "finding_count": "INTEGER",
+            },
+        },
+    }))
+    return PluginManifest.load(manifest_path)
+
+
+def _payload() -> dict:
+    return {
+        "metadata": {
+            "tool_name": "acme-analyzer",
+            "tool_version": "1.0.0",
+            "run_id": "run-plugin-001",
+            "repo_id": "test-repo",
+            "branch": "main",
+            "commit": "a" * 40,
+            "timestamp": "2025-01-15T10:00:00Z",
+            "schema_version": "1.0.0",
+        },
+        "data": {
+            "findings": [
+                {"path": "src/app.py", "severity": "HIGH", "count": 3},
+                {"path": "src/util.py", "severity": "LOW", "count": 1},
+            ],
+        },
+    }
+
+
+def test_manifest_rejects_non_lz_table_name(tmp_path: Path) -> None:
+    with pytest.raises(PluginError, match="lz_ identifier"):
+        PluginManifest(
+            name="acme",
+            version="1.0.0",
+            command=("python3", "plugin.py"),
+            output_schema="schemas/output.schema.json",
+            tables={"acme_findings": {"file_path": "VARCHAR"}},
+            plugin_dir=tmp_path,
+        )
+
+
+def test_manifest_rejects_unknown_column_type(tmp_path: Path) -> None:
+    with pytest.raises(PluginError, match="column type not allowed"):
+        PluginManifest(
+            name="acme",
+            version="1.0.0",
+            command=("python3", "plugin.py"),
+            output_schema="schemas/output.schema.json",
+            tables={"lz_acme_findings": {"file_path": "TEXT; DROP TABLE x"}},
+            plugin_dir=tmp_path,
+        )
+
+
+def test_manifest_rejects_empty_command(tmp_path: Path) -> None:
+    with pytest.raises(PluginError, match="command must be non-empty"):
+        PluginManifest(
+            name="acme",
+            version="1.0.0",
+            command=(),
+            output_schema="schemas/output.schema.json",
+            tables={"lz_acme_findings": {"file_path": "VARCHAR"}},
+            plugin_dir=tmp_path,
+        )
+
+
+def test_discover_plugins_skips_directories_without_manifest(tmp_path: Path) -> None:
+    _write_plugin(tmp_path / "acme")
+    (tmp_path / "not-a-plugin").mkdir()
+
+    manifests = discover_plugins(tmp_path)
+
+    assert [manifest.name for manifest in manifests] == ["acme-analyzer"]
+    assert discover_plugins(tmp_path / "missing") == []
+
+
+def test_handshake_accepts_matching_protocol(tmp_path: Path) -> None:
+    manifest = _write_plugin(tmp_path / "acme")
+
+    response = handshake(manifest)
+
+    assert response["capabilities"] == ["transform"]
+
+
+def test_handshake_rejects_protocol_mismatch(tmp_path: Path) -> None:
+    script = textwrap.dedent(
+        """
+        import json, sys
+        json.load(sys.stdin)
+        print(json.dumps({"protocol_version": 99, "capabilities": ["transform"]}))
+        """
+    )
+    manifest = _write_plugin(tmp_path / "acme", script)
+
+    with pytest.raises(PluginError, match="protocol"):
+        handshake(manifest)
+
+
+def test_plugin_adapter_persists_rows(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    tmp_path: Path,
+) -> None:
+    manifest = _write_plugin(tmp_path / "acme")
+    adapter = PluginAdapter(manifest, tool_run_repo)
+
+    run_pk = adapter.persist(_payload())
+
+    result = duckdb_conn.execute(
+        """SELECT file_path, severity, finding_count
+           FROM lz_acme_findings WHERE run_pk = ? ORDER BY file_path""",
+        [run_pk],
+    ).fetchall()
+    assert result == [("src/app.py", "HIGH", 3), ("src/util.py", "LOW", 1)]
+
+
+def test_plugin_adapter_rejects_undeclared_table(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    tmp_path: Path,
+) -> None:
+    script = textwrap.dedent(
+        """
+        import json, sys
+        request = json.load(sys.stdin)
+        if request["op"] == "handshake":
+            print(json.dumps({"protocol_version": 1, "capabilities": ["transform"]}))
+        else:
+            print(json.dumps({"tables": {"lz_other_table": [{"x": 1}]}}))
+        """
+    )
+    manifest = _write_plugin(tmp_path / "acme", script)
+    adapter = PluginAdapter(manifest, tool_run_repo)
+
+    with pytest.raises(ValueError, match="data quality validation failed"):
+        adapter.persist(_payload())
@@ -0,0 +1,88 @@
+"""Tests for the compressed raw-output archive."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from persistence.raw_store import LazyRawOutput, RawResultStore
+
+OUTPUT = {"schema_version": "1.0.0", "data": {"results": [{"path": "src/a.py"}] * 50}}
+
+
+def _write_output(tmp_path: Path) -> Path:
+    output_path = tmp_path / "output.json"
+    output_path.write_text(json.dumps(OUTPUT))
+    return output_path
+
+
+class TestArchive:
+    def test_archive_compresses_output(self, tmp_path: Path) -> None:
+        output_path = _write_output(tmp_path)
+        store = RawResultStore(tmp_path / "raw")
+        archive_path = store.archive("run-1", "semgrep", output_path)
+        assert archive_path.exists()
+        # Repetitive JSON must shrink, whichever codec is available.
+        assert archive_path.stat().st_size < output_path.stat().st_size
+
+    def test_rearchive_replaces_previous(self, tmp_path: Path) -> None:
+        output_path = _write_output(tmp_path)
+        store = RawResultStore(tmp_path / "raw")
+        store.archive("run-1", "semgrep", output_path)
+        store.archive("run-1", "semgrep", output_path)
+        assert store.list_tools("run-1") == ["semgrep"]
+
+
+class TestLazyLoad:
+    def test_round_trip(self, tmp_path: Path) -> None:
+        output_path = _write_output(tmp_path)
+        store = RawResultStore(tmp_path / "raw")
+        store.archive("run-1", "semgrep", output_path)
+        handle = store.load("run-1", "semgrep")
+        assert handle is not None
+        assert handle.data == OUTPUT
+
+    def test_nothing_decompressed_until_accessed(self, tmp_path: Path) -> None:
+        output_path = _write_output(tmp_path)
+        store = RawResultStore(tmp_path / "raw")
+        store.archive("run-1", "semgrep", output_path)
+        handle = store.load("run-1", "semgrep")
+        assert handle is not None
+        assert not handle.loaded
+        handle.data
+        assert handle.loaded
+
+    def test_missing_archive_returns_none(self, tmp_path: Path) -> None:
+        store = RawResultStore(tmp_path / "raw")
+        assert store.load("run-1", "semgrep") is None
+
+
+class TestListing:
+    def test_list_runs_and_tools(self, tmp_path: Path) -> None:
+        output_path = _write_output(tmp_path)
+        store = RawResultStore(tmp_path / "raw")
+        store.archive("run-1", "semgrep", output_path)
+        store.archive("run-1", "scc", output_path)
+        store.archive("run-2", "semgrep", output_path)
+        assert store.list_runs() == ["run-1", "run-2"]
+        assert store.list_tools("run-1") == ["scc", "semgrep"]
+
+    def test_empty_store(self, tmp_path: Path) -> None:
+        store = RawResultStore(tmp_path / "raw")
+        assert store.list_runs() == []
+        assert store.list_tools("run-1") == []
+
+
+class TestDeleteRun:
+    def test_delete_reports_bytes_freed(self, tmp_path: Path) -> None:
+        output_path = _write_output(tmp_path)
+        store = RawResultStore(tmp_path / "raw")
+        archive_path = store.archive("run-1", "semgrep", output_path)
+        archived_size = archive_path.stat().st_size
+        freed = store.delete_run("run-1")
+        assert freed == archived_size
+        assert store.list_runs() == []
+
+    def test_delete_unknown_run_is_noop(self, tmp_path: Path) -> None:
+        store = RawResultStore(tmp_path / "raw")
+        assert store.delete_run("run-1") == 0
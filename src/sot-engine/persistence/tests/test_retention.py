@@ -0,0 +1,136 @@
+"""Tests for run retention and landing-zone garbage collection."""
+
+from __future__ import annotations
+
+from datetime import datetime
+from pathlib import Path
+
+import duckdb
+import pytest
+
+from persistence.retention import (
+    GcPlan,
+    RetentionPolicy,
+    execute_gc,
+    load_retention_policy,
+    plan_gc,
+)
+
+NOW = datetime(2026, 8, 26, 12, 0, 0)
+
+
+def _seed_run(
+    conn: duckdb.DuckDBPyConnection,
+    collection_run_id: str,
+    repo_id: str,
+    started_at: datetime,
+    status: str = "completed",
+) -> None:
+    conn.execute(
+        "INSERT INTO lz_collection_runs VALUES (?, ?, ?, 'main', ?, ?, ?, ?)",
+        [
+            collection_run_id,
+            repo_id,
+            collection_run_id,
+            collection_run_id[:8].ljust(40, "0"),
+            started_at,
+            started_at,
+            status,
+        ],
+    )
+    conn.execute(
+        """INSERT INTO lz_tool_runs (collection_run_id, repo_id, run_id, tool_name,
+               tool_version, schema_version, branch, commit, timestamp)
+           VALUES (?, ?, ?, 'scc', '3.1.0', '1.0.0', 'main', ?, ?)""",
+        [collection_run_id, repo_id, collection_run_id, "a" * 40, started_at],
+    )
+    run_pk = conn.execute(
+        "SELECT run_pk FROM lz_tool_runs WHERE collection_run_id = ?",
+        [collection_run_id],
+    ).fetchone()[0]
+    conn.execute(
+        """INSERT INTO lz_scc_file_metrics (run_pk, file_id, directory_id, relative_path,
+               language, lines_total, code_lines, comment_lines, blank_lines, bytes, complexity)
+           VALUES (?, 'f1', 'd1', 'src/main.py', 'Python', 10, 8, 1, 1, 100, 2)""",
+        [run_pk],
+    )
+
+
+class TestPolicy:
+    def test_negative_values_rejected(self) -> None:
+        with pytest.raises(ValueError, match="keep_runs_per_repo"):
+            RetentionPolicy(keep_runs_per_repo=-1)
+        with pytest.raises(ValueError, match="max_age_days"):
+            RetentionPolicy(max_age_days=-1)
+
+    def test_load_from_caldera_toml(self, tmp_path: Path) -> None:
+        toml = tmp_path / "caldera.toml"
+        toml.write_text("[retention]\nkeep_runs_per_repo = 5\nmax_age_days = 30\n")
+        assert load_retention_policy(toml) == RetentionPolicy(
+            keep_runs_per_repo=5, max_age_days=30
+        )
+
+    def test_missing_config_uses_defaults(self, tmp_path: Path) -> None:
+        assert load_retention_policy(tmp_path / "missing.toml") == RetentionPolicy()
+
+
+class TestPlanGc:
+    def test_keeps_newest_n_runs_per_repo(self, duckdb_conn) -> None:
+        for day in (1, 2, 3):
+            _seed_run(duckdb_conn, f"run-{day}", "repo-a", datetime(2026, 8, day))
+
+        plan = plan_gc(duckdb_conn, RetentionPolicy(keep_runs_per_repo=2), now=NOW)
+
+        assert plan.run_ids == ("run-1",)
+
+    def test_age_cutoff_prunes_old_runs(self, duckdb_conn) -> None:
+        _seed_run(duckdb_conn, "run-old", "repo-a", datetime(2025, 1, 1))
+        _seed_run(duckdb_conn, "run-new", "repo-a", datetime(2026, 8, 20))
+
+        plan = plan_gc(
+            duckdb_conn,
+            RetentionPolicy(keep_runs_per_repo=0, max_age_days=90),
+            now=NOW,
+        )
+
+        assert plan.run_ids == ("run-old",)
+        assert "older than 90 days" in plan.prune[0][1]
+
+    def test_running_runs_never_pruned(self, duckdb_conn) -> None:
+        _seed_run(duckdb_conn, "run-live", "repo-a", datetime(2024, 1, 1), status="running")
+
+        plan = plan_gc(
+            duckdb_conn,
+            RetentionPolicy(keep_runs_per_repo=0, max_age_days=30),
+            now=NOW,
+        )
+
+        assert plan.run_ids == ()
+
+    def test_retention_is_per_repo(self, duckdb_conn) -> None:
+        _seed_run(duckdb_conn, "a-1", "repo-a", datetime(2026, 8, 1))
+        _seed_run(duckdb_conn, "b-1", "repo-b", datetime(2026, 8, 1))
+
+        plan = plan_gc(duckdb_conn, RetentionPolicy(keep_runs_per_repo=1), now=NOW)
+
+        assert plan.run_ids == ()
+
+
+class TestExecuteGc:
+    def test_deletes_run_rows_in_all_tables(self, duckdb_conn) -> None:
+        _seed_run(duckdb_conn, "run-1", "repo-a", datetime(2026, 8, 1))
+        _seed_run(duckdb_conn, "run-2", "repo-a", datetime(2026, 8, 2))
+
+        deleted = execute_gc(duckdb_conn, GcPlan(prune=(("run-1", "old"),)))
+
+        assert deleted["lz_collection_runs"] == 1
+        assert deleted["lz_tool_runs"] == 1
+        assert deleted["lz_scc_file_metrics"] == 1
+        remaining = duckdb_conn.execute(
+            "SELECT collection_run_id FROM lz_collection_runs"
+        ).fetchall()
+        assert remaining == [("run-2",)]
+        assert duckdb_conn.execute("SELECT COUNT(*) FROM lz_scc_file_metrics").fetchone()[0] == 1
+
+    def test_empty_plan_is_a_noop(self, duckdb_conn) -> None:
+        assert execute_gc(duckdb_conn, GcPlan(prune=())) == {}
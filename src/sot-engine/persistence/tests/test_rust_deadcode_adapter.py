@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import RustDeadcodeAdapter
+from persistence.repositories import (
+    LayoutRepository,
+    RustDeadcodeRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "rust_deadcode_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_rust_deadcode_adapter_inserts_findings(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps findings to RustDeadcodeFinding entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/lib.rs"),
+            ("f-000000000002", "d-000000000002", "src/internal.rs"),
+        ],
+    )
+
+    rust_deadcode_repo = RustDeadcodeRepository(duckdb_conn)
+    adapter = RustDeadcodeAdapter(tool_run_repo, layout_repo, rust_deadcode_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, symbol_name, source, confidence, line
+           FROM lz_rust_deadcode_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 3  # 3 findings in fixture
+    by_symbol = {row[1]: row for row in result}
+    assert set(by_symbol) == {"orphan_helper", "stale_private", "format_label"}
+    assert by_symbol["orphan_helper"][2] == "compiler"
+    assert by_symbol["orphan_helper"][3] == "HIGH"
+    assert by_symbol["format_label"][0] == "src/internal.rs"
+    assert by_symbol["format_label"][3] == "LOW"
+
+
+def test_rust_deadcode_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    rust_deadcode_repo = RustDeadcodeRepository(duckdb_conn)
+    adapter = RustDeadcodeAdapter(tool_run_repo, layout_repo, rust_deadcode_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_rust_deadcode_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/lib.rs"),
+            # src/internal.rs intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    rust_deadcode_repo = RustDeadcodeRepository(duckdb_conn)
+    adapter = RustDeadcodeAdapter(tool_run_repo, layout_repo, rust_deadcode_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "internal" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_rust_deadcode_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "src/lib.rs" in paths
+    assert "src/internal.rs" not in paths
@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import ShellcheckAdapter
+from persistence.repositories import (
+    LayoutRepository,
+    ShellcheckRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "shellcheck_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_shellcheck_adapter_inserts_findings(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps issues to ShellcheckFinding entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "scripts/deploy.sh"),
+            ("f-000000000002", "d-000000000002", "scripts/ci_helpers.sh"),
+            ("f-000000000003", "d-000000000002", "scripts/safe_script.sh"),
+        ],
+    )
+
+    shellcheck_repo = ShellcheckRepository(duckdb_conn)
+    adapter = ShellcheckAdapter(tool_run_repo, layout_repo, shellcheck_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, rule_id, level, dd_category, severity, line_start
+           FROM lz_shellcheck_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 3  # 3 issues in fixture
+    rule_ids = {row[1] for row in result}
+    assert "SC2086" in rule_ids
+    assert "SC2164" in rule_ids
+    levels = {row[2] for row in result}
+    assert "warning" in levels
+
+
+def test_shellcheck_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    shellcheck_repo = ShellcheckRepository(duckdb_conn)
+    adapter = ShellcheckAdapter(tool_run_repo, layout_repo, shellcheck_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_shellcheck_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "scripts/deploy.sh"),
+            # scripts/ci_helpers.sh intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    shellcheck_repo = ShellcheckRepository(duckdb_conn)
+    adapter = ShellcheckAdapter(tool_run_repo, layout_repo, shellcheck_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "ci_helpers.sh" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_shellcheck_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "scripts/deploy.sh" in paths
+    assert "scripts/ci_helpers.sh" not in paths
@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import SqlfluffAdapter
+from persistence.repositories import (
+    LayoutRepository,
+    SqlfluffRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "sqlfluff_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_sqlfluff_adapter_inserts_findings(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps violations to SqlfluffFinding entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "db/schema.sql"),
+            ("f-000000000002", "d-000000000003", "db/migrations/001_create_orders.sql"),
+            ("f-000000000003", "d-000000000002", "db/clean_query.sql"),
+        ],
+    )
+
+    sqlfluff_repo = SqlfluffRepository(duckdb_conn)
+    adapter = SqlfluffAdapter(tool_run_repo, layout_repo, sqlfluff_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, rule_id, rule_name, dd_category, severity
+           FROM lz_sqlfluff_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 3  # 3 violations in fixture
+    rule_ids = {row[1] for row in result}
+    assert rule_ids == {"CP01", "AM04"}
+    am04_rows = [row for row in result if row[1] == "AM04"]
+    assert am04_rows[0][0] == "db/migrations/001_create_orders.sql"
+    assert am04_rows[0][2] == "ambiguous.column_count"
+    assert am04_rows[0][3] == "ambiguous_sql"
+
+
+def test_sqlfluff_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    sqlfluff_repo = SqlfluffRepository(duckdb_conn)
+    adapter = SqlfluffAdapter(tool_run_repo, layout_repo, sqlfluff_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_sqlfluff_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "db/schema.sql"),
+            # db/migrations/001_create_orders.sql intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    sqlfluff_repo = SqlfluffRepository(duckdb_conn)
+    adapter = SqlfluffAdapter(tool_run_repo, layout_repo, sqlfluff_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "001_create_orders" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_sqlfluff_findings WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "db/schema.sql" in paths
+    assert "db/migrations/001_create_orders.sql" not in paths
@@ -0,0 +1,114 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+from persistence.adapters import TodoScannerAdapter
+from persistence.repositories import (
+    LayoutRepository,
+    TodoScannerRepository,
+    ToolRunRepository,
+)
+
+
+def _load_fixture() -> dict:
+    fixture_path = Path(__file__).resolve().parents[1] / "fixtures" / "todo_scanner_output.json"
+    return json.loads(fixture_path.read_text())
+
+
+def test_todo_scanner_adapter_inserts_comments(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter correctly maps debt comments to TodoComment entities."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/worker.py"),
+            ("f-000000000002", "d-000000000002", "src/comments_only.rs"),
+        ],
+    )
+
+    todo_scanner_repo = TodoScannerRepository(duckdb_conn)
+    adapter = TodoScannerAdapter(tool_run_repo, layout_repo, todo_scanner_repo)
+    run_pk = adapter.persist(payload)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path, marker, severity, author_email, introduced_at, age_days
+           FROM lz_todo_comments WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    assert len(result) == 4  # 4 comments in fixture
+    markers = {row[1] for row in result}
+    assert markers == {"TODO", "FIXME", "HACK"}
+    fixme_rows = [row for row in result if row[1] == "FIXME"]
+    assert fixme_rows[0][0] == "src/worker.py"
+    assert fixme_rows[0][2] == "HIGH"
+    assert fixme_rows[0][3] == "dev@example.com"
+    assert fixme_rows[0][4] == "2026-05-01"
+    assert fixme_rows[0][5] == 117
+    unattributed = [row for row in result if row[0] == "src/comments_only.rs"]
+    assert unattributed[0][3] is None
+    assert unattributed[0][5] is None
+
+
+def test_todo_scanner_adapter_raises_on_missing_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+) -> None:
+    """Verify adapter raises KeyError when no layout run exists for collection."""
+    payload = _load_fixture()
+
+    todo_scanner_repo = TodoScannerRepository(duckdb_conn)
+    adapter = TodoScannerAdapter(tool_run_repo, layout_repo, todo_scanner_repo)
+
+    with pytest.raises(KeyError):
+        adapter.persist(payload)
+
+
+def test_todo_scanner_adapter_skips_files_not_in_layout(
+    duckdb_conn,
+    tool_run_repo: ToolRunRepository,
+    layout_repo: LayoutRepository,
+    seed_layout,
+) -> None:
+    """Verify adapter warns and skips files not found in layout."""
+    payload = _load_fixture()
+    repo_id = payload["metadata"]["repo_id"]
+    run_id = payload["metadata"]["run_id"]
+
+    seed_layout(
+        repo_id,
+        run_id,
+        [
+            ("f-000000000001", "d-000000000002", "src/worker.py"),
+            # src/comments_only.rs intentionally omitted
+        ],
+    )
+
+    logs: list[str] = []
+    todo_scanner_repo = TodoScannerRepository(duckdb_conn)
+    adapter = TodoScannerAdapter(tool_run_repo, layout_repo, todo_scanner_repo, logger=logs.append)
+    run_pk = adapter.persist(payload)
+
+    assert any("skipping file not in layout" in log and "comments_only" in log for log in logs)
+
+    result = duckdb_conn.execute(
+        """SELECT relative_path FROM lz_todo_comments WHERE run_pk = ?""",
+        [run_pk],
+    ).fetchall()
+
+    paths = {row[0] for row in result}
+    assert "src/worker.py" in paths
+    assert "src/comments_only.rs" not in paths
@@ -0,0 +1,115 @@
+"""Scan performance breakdown from the pipeline tracer.
+
+"Why does my scan take 40 minutes" is unanswerable from the log alone:
+durations are scattered across lines and the slow tool is whichever one
+you did not scroll to. This module folds the in-process span records the
+tracer already collects (see shared.observability.tracing) into one
+profile — time per phase, tools slowest-first, cache effectiveness — and
+renders it as the table ``caldera scan --timings`` prints after a run.
+
+Per-file timings are only available for in-process analyzers that
+attach them to their spans; external tools are opaque subprocesses and
+appear as a single duration.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from shared.observability.tracing import SpanRecord
+
+# Top-level phases, in pipeline order, as named in orchestrator spans.
+_PHASE_LABELS = {
+    "resolve_input": "discovery",
+    "run_tools": "tools",
+    "ingest_outputs": "ingest",
+    "run_dbt": "marts (dbt)",
+}
+
+
+def build_profile(
+    spans: list[SpanRecord],
+    resumed_tools: list[str] | None = None,
+) -> dict:
+    """Fold finished spans into a phase/tool/cache breakdown."""
+    phases = []
+    tools = []
+    slow_files = []
+    for span in spans:
+        label = _PHASE_LABELS.get(span.name, span.name)
+        phases.append(
+            {
+                "phase": label,
+                "duration_seconds": round(span.duration_seconds or 0.0, 3),
+                "status": span.status,
+            }
+        )
+        for child in span.children:
+            if child.name != "tool_run":
+                continue
+            tools.append(
+                {
+                    "tool": child.attributes.get("tool_name", "?"),
+                    "duration_seconds": round(child.duration_seconds or 0.0, 3),
+                    "status": child.status,
+                }
+            )
+            for grandchild in child.children:
+                if grandchild.name == "file" and "file_path" in grandchild.attributes:
+                    slow_files.append(
+                        {
+                            "tool": child.attributes.get("tool_name", "?"),
+                            "file_path": grandchild.attributes["file_path"],
+                            "duration_seconds": round(grandchild.duration_seconds or 0.0, 3),
+                        }
+                    )
+    tools.sort(key=lambda t: -t["duration_seconds"])
+    slow_files.sort(key=lambda f: -f["duration_seconds"])
+    resumed = sorted(resumed_tools or [])
+    return {
+        "total_seconds": round(sum(p["duration_seconds"] for p in phases), 3),
+        "phases": phases,
+        "tools": tools,
+        "slowest_files": slow_files[:20],
+        "cache": {
+            "tools_resumed_from_checkpoint": len(resumed),
+            "resumed_tools": resumed,
+            "tools_executed": len(tools),
+        },
+    }
+
+
+def format_profile(profile: dict) -> str:
+    """Render the profile as the text block printed after a timed scan."""
+    lines = [f"Scan profile — {profile['total_seconds']:.1f}s total", ""]
+    lines.append("Phases:")
+    for phase in profile["phases"]:
+        marker = "" if phase["status"] == "ok" else f"  [{phase['status']}]"
+        lines.append(f"  {phase['phase']:<14} {phase['duration_seconds']:>8.1f}s{marker}")
+    if profile["tools"]:
+        lines.append("")
+        lines.append("Tools (slowest first):")
+        for tool in profile["tools"]:
+            marker = "" if tool["status"] == "ok" else f"  [{tool['status']}]"
+            lines.append(f"  {tool['tool']:<20} {tool['duration_seconds']:>8.1f}s{marker}")
+    if profile["slowest_files"]:
+        lines.append("")
+        lines.append("Slowest files:")
+        for entry in profile["slowest_files"]:
+            lines.append(
+                f"  {entry['duration_seconds']:>7.2f}s  {entry['tool']}  {entry['file_path']}"
+            )
+    cache = profile["cache"]
+    lines.append("")
+    lines.append(
+        f"Cache: {cache['tools_resumed_from_checkpoint']} tool(s) resumed from "
+        f"checkpoint, {cache['tools_executed']} executed"
+    )
+    return "\n".join(lines)
+
+
+def write_profile(profile: dict, path: Path) -> None:
+    """Persist the profile as JSON next to the run's other artifacts."""
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(json.dumps(profile, indent=2))
@@ -0,0 +1,134 @@
+"""Per-tool resource caps for spawned analysis tools.
+
+One runaway semgrep or java process can OOM the CI agent or fill the
+disk with a multi-gigabyte output file. This module loads caps from
+``[limits]`` in ``caldera.toml`` and applies them to each tool launched
+by the orchestrator:
+
+* ``max_memory_mb`` / ``max_cpu_seconds`` become rlimits
+  (``RLIMIT_AS`` / ``RLIMIT_CPU``) set in the child before exec; every
+  process in the tool's tree inherits them. rlimits are per process, not
+  per tree — a full cgroup would cap the tree jointly, but needs root
+  and a cgroup v2 mount, so the portable rlimit is what we enforce.
+* ``max_output_mb`` caps any single file the tool writes
+  (``RLIMIT_FSIZE``) and is re-checked against the output directory
+  after the run, failing the tool when exceeded.
+
+Per-tool tables override the defaults::
+
+    [limits]
+    max_memory_mb = 0      # 0 disables a cap
+    max_cpu_seconds = 0
+    max_output_mb = 0
+
+    [limits.semgrep]
+    max_memory_mb = 4096
+"""
+
+from __future__ import annotations
+
+import resource
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Callable
+
+_MB = 1024 * 1024
+
+# Keys recognised in [limits] and [limits.<tool>] tables.
+_LIMIT_KEYS = ("max_memory_mb", "max_cpu_seconds", "max_output_mb")
+
+
+class ToolOutputLimitExceeded(RuntimeError):
+    """A tool's output directory grew past its configured cap."""
+
+
+@dataclass(frozen=True)
+class ResourceLimits:
+    """Caps for one spawned tool; 0 means unlimited."""
+
+    max_memory_mb: int = 0
+    max_cpu_seconds: int = 0
+    max_output_mb: int = 0
+
+    def __post_init__(self) -> None:
+        for key in _LIMIT_KEYS:
+            if getattr(self, key) < 0:
+                raise ValueError(f"{key} must be >= 0")
+
+    @property
+    def unlimited(self) -> bool:
+        return not any(getattr(self, key) for key in _LIMIT_KEYS)
+
+    def preexec_fn(self) -> Callable[[], None] | None:
+        """An rlimit-setting callable for ``subprocess.Popen(preexec_fn=...)``.
+
+        Returns ``None`` when every cap is disabled so callers skip the
+        (fork-unsafe-with-threads) preexec machinery entirely.
+        """
+        if self.unlimited:
+            return None
+        memory_bytes = self.max_memory_mb * _MB
+        cpu_seconds = self.max_cpu_seconds
+        output_bytes = self.max_output_mb * _MB
+
+        def apply_rlimits() -> None:
+            if memory_bytes:
+                resource.setrlimit(resource.RLIMIT_AS, (memory_bytes, memory_bytes))
+            if cpu_seconds:
+                resource.setrlimit(resource.RLIMIT_CPU, (cpu_seconds, cpu_seconds))
+            if output_bytes:
+                resource.setrlimit(resource.RLIMIT_FSIZE, (output_bytes, output_bytes))
+
+        return apply_rlimits
+
+
+@dataclass(frozen=True)
+class ResourceLimitsConfig:
+    """Default caps plus per-tool overrides from ``[limits]``."""
+
+    defaults: ResourceLimits = ResourceLimits()
+    per_tool: tuple[tuple[str, ResourceLimits], ...] = ()
+
+    def for_tool(self, tool_name: str) -> ResourceLimits:
+        for name, limits in self.per_tool:
+            if name == tool_name:
+                return limits
+        return self.defaults
+
+
+def load_resource_limits(caldera_toml: Path | None = None) -> ResourceLimitsConfig:
+    """Load ``[limits]`` from caldera.toml, falling back to no caps."""
+    if caldera_toml is None or not caldera_toml.exists():
+        return ResourceLimitsConfig()
+    config = tomllib.loads(caldera_toml.read_text()).get("limits", {})
+    defaults = _parse_limits(config, ResourceLimits())
+    per_tool = tuple(
+        (name, _parse_limits(overrides, defaults))
+        for name, overrides in sorted(config.items())
+        if isinstance(overrides, dict)
+    )
+    return ResourceLimitsConfig(defaults=defaults, per_tool=per_tool)
+
+
+def _parse_limits(table: dict, base: ResourceLimits) -> ResourceLimits:
+    return ResourceLimits(
+        **{key: int(table.get(key, getattr(base, key))) for key in _LIMIT_KEYS}
+    )
+
+
+def check_output_size(output_dir: Path, limits: ResourceLimits, tool_name: str) -> int:
+    """Total bytes under ``output_dir``; raises when past ``max_output_mb``.
+
+    RLIMIT_FSIZE caps single files, but a tool can still fill the disk
+    with many files — this is the backstop across the whole directory.
+    """
+    if not output_dir.exists():
+        return 0
+    total = sum(path.stat().st_size for path in output_dir.rglob("*") if path.is_file())
+    if limits.max_output_mb and total > limits.max_output_mb * _MB:
+        raise ToolOutputLimitExceeded(
+            f"{tool_name} wrote {total / _MB:.1f} MB to {output_dir}, "
+            f"over the {limits.max_output_mb} MB cap"
+        )
+    return total
@@ -0,0 +1,150 @@
+"""Tests for graceful cancellation and child-process-group cleanup."""
+
+from __future__ import annotations
+
+import os
+import signal
+import subprocess
+import sys
+import threading
+import time
+from pathlib import Path
+
+import pytest
+
+sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
+
+from cancellation import CancellationToken, RunInterrupted, run_cancellable
+
+
+def _pid_alive(pid: int) -> bool:
+    try:
+        os.kill(pid, 0)
+    except ProcessLookupError:
+        return False
+    return True
+
+
+def test_run_cancellable_returns_exit_code() -> None:
+    token = CancellationToken()
+    assert run_cancellable(["true"], token) == 0
+    assert run_cancellable(["false"], token) == 1
+
+
+def test_run_cancellable_check_raises_on_failure() -> None:
+    token = CancellationToken()
+    with pytest.raises(subprocess.CalledProcessError):
+        run_cancellable(["false"], token, check=True)
+
+
+def test_run_cancellable_without_token_falls_back_to_run() -> None:
+    assert run_cancellable(["true"], None) == 0
+
+
+def test_cancel_kills_whole_process_group() -> None:
+    token = CancellationToken()
+    # The shell forks a grandchild; killing only the direct child would
+    # leave it orphaned — exactly the bug this module fixes.
+    process = subprocess.Popen(
+        ["sh", "-c", "sleep 30 & echo $!; wait"],
+        stdout=subprocess.PIPE,
+        text=True,
+        start_new_session=True,
+    )
+    token.register(process)
+    grandchild_pid = int(process.stdout.readline())
+    assert _pid_alive(grandchild_pid)
+
+    token.cancel("SIGINT")
+
+    assert process.poll() is not None
+    deadline = time.monotonic() + 5.0
+    while _pid_alive(grandchild_pid) and time.monotonic() < deadline:
+        time.sleep(0.05)
+    assert not _pid_alive(grandchild_pid)
+    process.stdout.close()
+
+
+def test_run_cancellable_raises_after_cancel() -> None:
+    token = CancellationToken()
+
+    def cancel_soon() -> None:
+        time.sleep(0.2)
+        token.cancel("SIGINT")
+
+    thread = threading.Thread(target=cancel_soon)
+    thread.start()
+    with pytest.raises(RunInterrupted) as exc_info:
+        run_cancellable(
+            ["sleep", "30"],
+            token,
+            stdout=subprocess.DEVNULL,
+            stderr=subprocess.DEVNULL,
+        )
+    thread.join()
+    assert exc_info.value.signal_name == "SIGINT"
+
+
+def test_terminate_children_escalates_to_sigkill() -> None:
+    token = CancellationToken()
+    # A child that traps SIGTERM must still die via the SIGKILL escalation.
+    process = subprocess.Popen(
+        ["sh", "-c", "trap '' TERM; sleep 30"],
+        start_new_session=True,
+    )
+    token.register(process)
+    time.sleep(0.2)  # let the trap install
+    start = time.monotonic()
+    token.terminate_children(grace_seconds=0.5)
+    assert process.poll() == -signal.SIGKILL
+    assert time.monotonic() - start < 10
+
+
+def test_register_unregister_bookkeeping() -> None:
+    token = CancellationToken()
+    process = subprocess.Popen(["sleep", "30"], start_new_session=True)
+    token.register(process)
+    token.unregister(process)
+    # Nothing registered: terminate_children must be a no-op.
+    token.terminate_children(grace_seconds=0.1)
+    assert process.poll() is None
+    os.killpg(os.getpgid(process.pid), signal.SIGKILL)
+    process.wait()
+
+
+def test_raise_if_cancelled_noop_when_clear() -> None:
+    token = CancellationToken()
+    token.raise_if_cancelled()
+    assert not token.cancelled
+
+
+def test_installed_handler_interrupts_blocking_wait() -> None:
+    # End-to-end: the handler fires while the main thread is blocked in
+    # process.wait() — the scenario where a blocking handler would deadlock.
+    script = (
+        "import sys\n"
+        f"sys.path.insert(0, {str(Path(__file__).resolve().parents[1])!r})\n"
+        "from cancellation import CancellationToken, RunInterrupted, run_cancellable\n"
+        "token = CancellationToken()\n"
+        "token.install()\n"
+        "print('ready', flush=True)\n"
+        "try:\n"
+        "    run_cancellable(['sleep', '30'], token)\n"
+        "except RunInterrupted as exc:\n"
+        "    print('interrupted', exc.signal_name, flush=True)\n"
+        "    sys.exit(130)\n"
+    )
+    process = subprocess.Popen(
+        [sys.executable, "-c", script], stdout=subprocess.PIPE, text=True
+    )
+    try:
+        assert process.stdout.readline().strip() == "ready"
+        time.sleep(0.3)
+        process.send_signal(signal.SIGINT)
+        output = process.stdout.read()
+        assert process.wait(timeout=15) == 130
+        assert "interrupted SIGINT" in output
+    finally:
+        if process.poll() is None:
+            process.kill()
+        process.stdout.close()
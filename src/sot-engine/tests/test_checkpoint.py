@@ -0,0 +1,70 @@
+"""Tests for scan checkpointing and resume."""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
+
+from checkpoint import RunCheckpoint
+
+COMMIT_A = "a" * 40
+COMMIT_B = "b" * 40
+
+
+def test_mark_tool_done_persists_immediately(tmp_path: Path) -> None:
+    output = tmp_path / "output.json"
+    output.write_text("{}")
+    checkpoint = RunCheckpoint.start("run-1", "repo-a", COMMIT_A, tmp_path)
+    checkpoint.mark_tool_done("scc", output, 1.234)
+
+    on_disk = json.loads((tmp_path / "run-1.json").read_text())
+    assert on_disk["completed_tools"]["scc"]["output_path"] == str(output)
+    assert on_disk["completed_tools"]["scc"]["duration_seconds"] == 1.234
+
+
+def test_load_round_trips_completed_tools(tmp_path: Path) -> None:
+    output = tmp_path / "output.json"
+    output.write_text("{}")
+    RunCheckpoint.start("run-1", "repo-a", COMMIT_A, tmp_path).mark_tool_done(
+        "lizard", output, 2.0
+    )
+
+    resumed = RunCheckpoint.load("run-1", "repo-a", COMMIT_A, tmp_path)
+    assert resumed.completed_output("lizard") == output
+    assert resumed.completed_output("scc") is None
+
+
+def test_completed_output_requires_file_on_disk(tmp_path: Path) -> None:
+    output = tmp_path / "output.json"
+    output.write_text("{}")
+    checkpoint = RunCheckpoint.start("run-1", "repo-a", COMMIT_A, tmp_path)
+    checkpoint.mark_tool_done("scc", output, 1.0)
+    output.unlink()
+
+    assert checkpoint.completed_output("scc") is None
+
+
+def test_load_missing_checkpoint_raises(tmp_path: Path) -> None:
+    with pytest.raises(FileNotFoundError):
+        RunCheckpoint.load("run-x", "repo-a", COMMIT_A, tmp_path)
+
+
+def test_load_rejects_repo_or_commit_mismatch(tmp_path: Path) -> None:
+    RunCheckpoint.start("run-1", "repo-a", COMMIT_A, tmp_path)
+    with pytest.raises(ValueError):
+        RunCheckpoint.load("run-1", "repo-a", COMMIT_B, tmp_path)
+    with pytest.raises(ValueError):
+        RunCheckpoint.load("run-1", "repo-b", COMMIT_A, tmp_path)
+
+
+def test_clear_removes_checkpoint_file(tmp_path: Path) -> None:
+    checkpoint = RunCheckpoint.start("run-1", "repo-a", COMMIT_A, tmp_path)
+    assert checkpoint.path.exists()
+    checkpoint.clear()
+    assert not checkpoint.path.exists()
+    checkpoint.clear()  # idempotent
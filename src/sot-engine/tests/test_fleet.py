@@ -0,0 +1,154 @@
+"""Tests for fleet scanning with a manifest and aggregate report."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+import pytest
+
+sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
+
+from fleet import (
+    FleetRepo,
+    FleetRepoResult,
+    health_score,
+    load_manifest,
+    scan_fleet,
+)
+
+MANIFEST = """\
+fleet:
+  max_parallel: 3
+repos:
+  - repo_id: app
+    source: https://github.com/org/app.git
+    branch: develop
+  - repo_id: lib
+    source: /repos/lib
+"""
+
+
+def _write_manifest(tmp_path: Path, content: str = MANIFEST) -> Path:
+    manifest = tmp_path / "repos.yaml"
+    manifest.write_text(content)
+    return manifest
+
+
+def test_load_manifest_parses_repos_and_settings(tmp_path: Path) -> None:
+    repos, settings = load_manifest(_write_manifest(tmp_path))
+
+    assert repos == [
+        FleetRepo(repo_id="app", source="https://github.com/org/app.git", branch="develop"),
+        FleetRepo(repo_id="lib", source="/repos/lib"),
+    ]
+    assert settings["max_parallel"] == 3
+    assert settings["run_tools"] is True
+
+
+def test_load_manifest_rejects_duplicate_repo_ids(tmp_path: Path) -> None:
+    manifest = _write_manifest(
+        tmp_path,
+        "repos:\n  - {repo_id: app, source: /a}\n  - {repo_id: app, source: /b}\n",
+    )
+    with pytest.raises(ValueError, match="duplicate repo_id"):
+        load_manifest(manifest)
+
+
+def test_load_manifest_rejects_missing_repos_list(tmp_path: Path) -> None:
+    with pytest.raises(ValueError, match="'repos' list"):
+        load_manifest(_write_manifest(tmp_path, "fleet: {}\n"))
+
+
+def test_scan_fleet_runs_each_repo_with_its_own_db(tmp_path: Path) -> None:
+    commands: list[list[str]] = []
+
+    def runner(command: list[str]) -> int:
+        commands.append(command)
+        return 0
+
+    report = scan_fleet(
+        _write_manifest(tmp_path),
+        workdir=tmp_path / "fleet",
+        runner=runner,
+        metrics_loader=lambda db_path: {},
+    )
+
+    assert report["summary"]["repos_completed"] == 2
+    db_paths = {cmd[cmd.index("--db-path") + 1] for cmd in commands}
+    assert len(db_paths) == 2  # one landing zone per repo, never shared
+    branches = {cmd[cmd.index("--branch") + 1] for cmd in commands}
+    assert branches == {"develop", "main"}
+
+
+def test_scan_fleet_reports_failures_without_aborting(tmp_path: Path) -> None:
+    def runner(command: list[str]) -> int:
+        repo_id = command[command.index("--repo-id") + 1]
+        return 1 if repo_id == "app" else 0
+
+    report = scan_fleet(
+        _write_manifest(tmp_path),
+        workdir=tmp_path / "fleet",
+        runner=runner,
+        metrics_loader=lambda db_path: {},
+    )
+
+    assert report["summary"]["repos_failed"] == 1
+    by_id = {entry["repo_id"]: entry for entry in report["repos"]}
+    assert by_id["app"]["status"] == "failed"
+    assert by_id["app"]["health_score"] == 0.0
+    assert by_id["lib"]["status"] == "completed"
+
+
+def test_portfolio_duplication_is_weighted_by_code_lines(tmp_path: Path) -> None:
+    metrics = {
+        "app": {"total_code_lines": 9000, "duplication_percentage": 10.0},
+        "lib": {"total_code_lines": 1000, "duplication_percentage": 0.0},
+    }
+
+    report = scan_fleet(
+        _write_manifest(tmp_path),
+        workdir=tmp_path / "fleet",
+        runner=lambda command: 0,
+        metrics_loader=lambda db_path: metrics[db_path.stem],
+    )
+
+    summary = report["summary"]
+    assert summary["portfolio_code_lines"] == 10000
+    assert summary["portfolio_duplication_percentage"] == 9.0
+
+
+def test_repos_sorted_worst_health_first(tmp_path: Path) -> None:
+    metrics = {
+        "app": {"duplication_percentage": 20.0},
+        "lib": {"duplication_percentage": 1.0},
+    }
+
+    report = scan_fleet(
+        _write_manifest(tmp_path),
+        workdir=tmp_path / "fleet",
+        runner=lambda command: 0,
+        metrics_loader=lambda db_path: metrics[db_path.stem],
+    )
+
+    assert [entry["repo_id"] for entry in report["repos"]] == ["app", "lib"]
+
+
+def test_health_score_penalties() -> None:
+    clean = FleetRepoResult("a", "completed", "a.duckdb", metrics={})
+    assert health_score(clean) == 100.0
+
+    duplicated = FleetRepoResult(
+        "b", "completed", "b.duckdb", metrics={"duplication_percentage": 5.0}
+    )
+    assert health_score(duplicated) == 90.0
+
+    uncommented = FleetRepoResult(
+        "c", "completed", "c.duckdb", metrics={"comment_ratio": 0.01}
+    )
+    assert health_score(uncommented) == 90.0
+
+    worst = FleetRepoResult(
+        "d", "completed", "d.duckdb", metrics={"duplication_percentage": 90.0}
+    )
+    assert health_score(worst) == 40.0  # duplication penalty is capped
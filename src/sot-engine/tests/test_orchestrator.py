@@ -13,11 +13,14 @@ sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
 from orchestrator import (
     OrchestratorLogger,
     ToolConfig,
+    _FAST_PROFILE_TOOLS,
     _compute_content_hash,
     _default_output_path,
     _format_duration,
     _is_fallback_commit,
     _resolve_dbt_cmd,
+    _resolve_profile_tools,
+    _tools_enabled_from_config,
     ingest_outputs,
     ensure_schema,
     run_tool_make,
@@ -450,3 +453,44 @@ def test_compute_content_hash_empty_dir(tmp_path: Path) -> None:
     h = _compute_content_hash(empty)
     assert len(h) == 40
     assert all(c in "0123456789abcdef" for c in h)
+
+
+def test_tools_enabled_from_config(tmp_path: Path) -> None:
+    config = tmp_path / "caldera.toml"
+    config.write_text('[tools]\nenabled = ["scc", "lizard"]\n')
+    assert _tools_enabled_from_config(config) == {"scc", "lizard"}
+    assert _tools_enabled_from_config(tmp_path / "missing.toml") is None
+    config.write_text("[limits]\nmax_memory_mb = 1\n")
+    assert _tools_enabled_from_config(config) is None
+
+
+def test_resolve_profile_builtins(tmp_path: Path) -> None:
+    missing = tmp_path / "caldera.toml"
+    assert _resolve_profile_tools("fast", missing) == set(_FAST_PROFILE_TOOLS)
+    assert _resolve_profile_tools("deep", missing) is None
+    assert _resolve_profile_tools("standard", missing) is None
+
+
+def test_resolve_profile_standard_follows_tools_enabled(tmp_path: Path) -> None:
+    config = tmp_path / "caldera.toml"
+    config.write_text('[tools]\nenabled = ["scc"]\n')
+    assert _resolve_profile_tools("standard", config) == {"scc"}
+
+
+def test_resolve_profile_config_overrides_builtin(tmp_path: Path) -> None:
+    config = tmp_path / "caldera.toml"
+    config.write_text('[profile.fast]\ntools = ["scc"]\n\n[profile.nightly]\ntools = ["scc", "lizard"]\n')
+    assert _resolve_profile_tools("fast", config) == {"scc"}
+    assert _resolve_profile_tools("nightly", config) == {"scc", "lizard"}
+
+
+def test_resolve_profile_unknown_name_rejected(tmp_path: Path) -> None:
+    with pytest.raises(SystemExit, match="Unknown profile"):
+        _resolve_profile_tools("nightly", tmp_path / "caldera.toml")
+
+
+def test_resolve_profile_table_without_tools_rejected(tmp_path: Path) -> None:
+    config = tmp_path / "caldera.toml"
+    config.write_text("[profile.fast]\ndescription = 'oops'\n")
+    with pytest.raises(SystemExit, match="no 'tools' list"):
+        _resolve_profile_tools("fast", config)
@@ -0,0 +1,104 @@
+"""Tests for the scan performance profile."""
+
+from __future__ import annotations
+
+import json
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
+
+from profiling import build_profile, format_profile, write_profile
+from shared.observability.tracing import SpanRecord
+
+
+def _tool_span(name: str, duration: float, files: list[tuple[str, float]] | None = None) -> SpanRecord:
+    children = [
+        SpanRecord("file", {"file_path": path}, 0.0, duration_seconds=seconds)
+        for path, seconds in (files or [])
+    ]
+    return SpanRecord(
+        "tool_run", {"tool_name": name}, 0.0, duration_seconds=duration, children=children
+    )
+
+
+def _spans() -> list[SpanRecord]:
+    return [
+        SpanRecord("resolve_input", {}, 0.0, duration_seconds=1.5),
+        SpanRecord(
+            "run_tools",
+            {},
+            0.0,
+            duration_seconds=100.0,
+            children=[
+                _tool_span("scc", 5.0),
+                _tool_span("semgrep", 90.0, files=[("src/big.py", 12.5), ("src/a.py", 0.1)]),
+            ],
+        ),
+        SpanRecord("ingest_outputs", {}, 0.0, duration_seconds=8.0),
+        SpanRecord("run_dbt", {}, 0.0, duration_seconds=20.0, status="error"),
+    ]
+
+
+def test_phases_use_pipeline_labels_in_order():
+    profile = build_profile(_spans())
+    assert [p["phase"] for p in profile["phases"]] == [
+        "discovery",
+        "tools",
+        "ingest",
+        "marts (dbt)",
+    ]
+    assert profile["total_seconds"] == 129.5
+
+
+def test_tools_sorted_slowest_first():
+    profile = build_profile(_spans())
+    assert [t["tool"] for t in profile["tools"]] == ["semgrep", "scc"]
+    assert profile["tools"][0]["duration_seconds"] == 90.0
+
+
+def test_slowest_files_come_from_file_spans():
+    profile = build_profile(_spans())
+    assert profile["slowest_files"][0] == {
+        "tool": "semgrep",
+        "file_path": "src/big.py",
+        "duration_seconds": 12.5,
+    }
+
+
+def test_cache_counts_resumed_tools():
+    profile = build_profile(_spans(), resumed_tools=["lizard", "gitleaks"])
+    assert profile["cache"]["tools_resumed_from_checkpoint"] == 2
+    assert profile["cache"]["resumed_tools"] == ["gitleaks", "lizard"]
+    assert profile["cache"]["tools_executed"] == 2
+
+
+def test_phase_status_survives_into_profile():
+    profile = build_profile(_spans())
+    dbt = [p for p in profile["phases"] if p["phase"] == "marts (dbt)"][0]
+    assert dbt["status"] == "error"
+
+
+def test_format_profile_mentions_phases_tools_and_cache():
+    text = format_profile(build_profile(_spans(), resumed_tools=["lizard"]))
+    assert "Phases:" in text
+    assert "discovery" in text
+    assert "semgrep" in text
+    assert "src/big.py" in text
+    assert "1 tool(s) resumed from checkpoint" in text
+    assert "[error]" in text
+
+
+def test_empty_spans_produce_empty_profile():
+    profile = build_profile([])
+    assert profile["total_seconds"] == 0.0
+    assert profile["phases"] == []
+    assert profile["tools"] == []
+    assert "Cache:" in format_profile(profile)
+
+
+def test_write_profile_round_trips(tmp_path: Path):
+    profile = build_profile(_spans())
+    target = tmp_path / "nested" / "run.profile.json"
+    write_profile(profile, target)
+    assert json.loads(target.read_text()) == profile
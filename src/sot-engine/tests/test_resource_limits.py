@@ -0,0 +1,107 @@
+"""Tests for per-tool resource caps (rlimits + output-size backstop)."""
+
+from __future__ import annotations
+
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+sys.path.insert(0, str(Path(__file__).resolve().parents[1]))
+
+from resource_limits import (
+    ResourceLimits,
+    ResourceLimitsConfig,
+    ToolOutputLimitExceeded,
+    check_output_size,
+    load_resource_limits,
+)
+
+
+def test_load_defaults_when_no_config() -> None:
+    config = load_resource_limits(None)
+    assert config.defaults == ResourceLimits()
+    assert config.for_tool("semgrep").unlimited
+
+
+def test_load_missing_file_gives_no_caps(tmp_path: Path) -> None:
+    config = load_resource_limits(tmp_path / "caldera.toml")
+    assert config.defaults.unlimited
+
+
+def test_load_from_caldera_toml_with_per_tool_override(tmp_path: Path) -> None:
+    toml = tmp_path / "caldera.toml"
+    toml.write_text(
+        "[limits]\n"
+        "max_memory_mb = 2048\n"
+        "max_output_mb = 256\n"
+        "[limits.semgrep]\n"
+        "max_memory_mb = 4096\n"
+    )
+    config = load_resource_limits(toml)
+    assert config.defaults.max_memory_mb == 2048
+    assert config.defaults.max_cpu_seconds == 0
+    # Per-tool tables override the defaults; unset keys inherit them.
+    semgrep = config.for_tool("semgrep")
+    assert semgrep.max_memory_mb == 4096
+    assert semgrep.max_output_mb == 256
+    assert config.for_tool("scc") == config.defaults
+
+
+def test_negative_limit_rejected() -> None:
+    with pytest.raises(ValueError, match="max_memory_mb"):
+        ResourceLimits(max_memory_mb=-1)
+
+
+def test_preexec_fn_none_when_unlimited() -> None:
+    assert ResourceLimits().preexec_fn() is None
+    assert ResourceLimits(max_cpu_seconds=60).preexec_fn() is not None
+
+
+def test_memory_rlimit_kills_runaway_allocation() -> None:
+    limits = ResourceLimits(max_memory_mb=256)
+    result = subprocess.run(
+        [sys.executable, "-c", "x = bytearray(512 * 1024 * 1024)"],
+        preexec_fn=limits.preexec_fn(),
+        capture_output=True,
+    )
+    assert result.returncode != 0
+    assert b"MemoryError" in result.stderr
+
+
+def test_fsize_rlimit_caps_output_file(tmp_path: Path) -> None:
+    limits = ResourceLimits(max_output_mb=1)
+    target = tmp_path / "output.json"
+    result = subprocess.run(
+        [sys.executable, "-c", f"open({str(target)!r}, 'wb').write(b'x' * (4 * 1024 * 1024))"],
+        preexec_fn=limits.preexec_fn(),
+        capture_output=True,
+    )
+    assert result.returncode != 0  # SIGXFSZ
+    assert target.stat().st_size <= 1024 * 1024
+
+
+def test_check_output_size_under_cap(tmp_path: Path) -> None:
+    (tmp_path / "output.json").write_bytes(b"x" * 1024)
+    limits = ResourceLimits(max_output_mb=1)
+    assert check_output_size(tmp_path, limits, "scc") == 1024
+
+
+def test_check_output_size_over_cap_raises(tmp_path: Path) -> None:
+    # Many small files dodge RLIMIT_FSIZE; the directory backstop catches them.
+    for idx in range(3):
+        (tmp_path / f"part{idx}.json").write_bytes(b"x" * 512 * 1024)
+    limits = ResourceLimits(max_output_mb=1)
+    with pytest.raises(ToolOutputLimitExceeded, match="semgrep"):
+        check_output_size(tmp_path, limits, "semgrep")
+
+
+def test_check_output_size_missing_dir(tmp_path: Path) -> None:
+    assert check_output_size(tmp_path / "absent", ResourceLimits(max_output_mb=1), "scc") == 0
+
+
+def test_unlimited_config_never_raises(tmp_path: Path) -> None:
+    (tmp_path / "big.json").write_bytes(b"x" * 2 * 1024 * 1024)
+    config = ResourceLimitsConfig()
+    assert check_output_size(tmp_path, config.for_tool("scc"), "scc") == 2 * 1024 * 1024
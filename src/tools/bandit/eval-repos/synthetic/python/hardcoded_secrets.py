@@ -0,0 +1,35 @@
+"""Synthetic hardcoded secret scenarios for Bandit evaluation.
+
+Expected findings: B105 (hardcoded_password_string),
+B106 (hardcoded_password_funcarg), B107 (hardcoded_password_default).
+"""
+
+# VULNERABLE: hardcoded password string (B105)
+DATABASE_PASSWORD = "sup3rs3cret!"
+
+# VULNERABLE: hardcoded API token (B105)
+API_TOKEN = "sk-test-51HZkXWq9vQp2mN8eL4cT7yB"
+
+
+class DatabaseClient:
+    def __init__(self):
+        # VULNERABLE: hardcoded password assignment (B105)
+        self.password = "admin123"
+
+    def connect(self, host: str):
+        # VULNERABLE: hardcoded password as function argument (B106)
+        return self._open(host, user="admin", password="changeme")
+
+    def reconnect(self, host: str, password: str = "fallback-pw"):
+        # VULNERABLE: hardcoded password default (B107)
+        return self._open(host, user="admin", password=password)
+
+    def _open(self, host: str, user: str, password: str):
+        return (host, user, password)
+
+
+def connect_from_env(host: str):
+    # SAFE: password from environment, no finding expected
+    import os
+
+    return (host, os.environ.get("DB_USER"), os.environ.get("DB_PASSWORD"))
@@ -0,0 +1,51 @@
+"""Synthetic heavy duplication with embedded insecure calls.
+
+Mirrors pmd-cpd's heavy_duplication fixtures: each block is a near-exact
+copy so the duplication matrix and the security matrix overlap on Python.
+Expected Bandit findings: B324 (insecure md5) in every block.
+"""
+import hashlib
+
+
+def process_orders(records):
+    results = []
+    for record in records:
+        if record.get("status") != "active":
+            continue
+        digest = hashlib.md5(record["id"].encode()).hexdigest()
+        total = sum(item["price"] * item["qty"] for item in record["items"])
+        results.append({"key": digest, "total": round(total, 2)})
+    return results
+
+
+def process_invoices(records):
+    results = []
+    for record in records:
+        if record.get("status") != "active":
+            continue
+        digest = hashlib.md5(record["id"].encode()).hexdigest()
+        total = sum(item["price"] * item["qty"] for item in record["items"])
+        results.append({"key": digest, "total": round(total, 2)})
+    return results
+
+
+def process_refunds(records):
+    results = []
+    for record in records:
+        if record.get("status") != "active":
+            continue
+        digest = hashlib.md5(record["id"].encode()).hexdigest()
+        total = sum(item["price"] * item["qty"] for item in record["items"])
+        results.append({"key": digest, "total": round(total, 2)})
+    return results
+
+
+def process_credits(records):
+    results = []
+    for record in records:
+        if record.get("status") != "active":
+            continue
+        digest = hashlib.md5(record["id"].encode()).hexdigest()
+        total = sum(item["price"] * item["qty"] for item in record["items"])
+        results.append({"key": digest, "total": round(total, 2)})
+    return results
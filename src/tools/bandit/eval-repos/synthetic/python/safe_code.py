@@ -0,0 +1,18 @@
+"""Negative control: clean Python code with no expected Bandit findings."""
+import hashlib
+import os
+import sqlite3
+
+
+def hash_content(content: bytes) -> str:
+    return hashlib.sha256(content).hexdigest()
+
+
+def lookup_user(conn: sqlite3.Connection, user_id: int):
+    return conn.execute(
+        "SELECT id, name FROM users WHERE id = ?", (user_id,)
+    ).fetchone()
+
+
+def database_url() -> str:
+    return os.environ["DATABASE_URL"]
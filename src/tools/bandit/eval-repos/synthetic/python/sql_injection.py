@@ -0,0 +1,37 @@
+"""Synthetic SQL injection scenarios for Bandit evaluation.
+
+Expected findings: B608 (hardcoded_sql_expressions) on every query built
+by string interpolation or concatenation.
+"""
+import sqlite3
+
+
+def get_user_by_name(conn: sqlite3.Connection, username: str):
+    # VULNERABLE: f-string interpolation into SQL (B608)
+    query = f"SELECT * FROM users WHERE username = '{username}'"
+    return conn.execute(query).fetchall()
+
+
+def delete_account(conn: sqlite3.Connection, account_id: str):
+    # VULNERABLE: string concatenation into SQL (B608)
+    query = "DELETE FROM accounts WHERE id = " + account_id
+    conn.execute(query)
+
+
+def search_products(conn: sqlite3.Connection, term: str):
+    # VULNERABLE: percent-format interpolation into SQL (B608)
+    query = "SELECT * FROM products WHERE name LIKE '%%%s%%'" % term
+    return conn.execute(query).fetchall()
+
+
+def update_email(conn: sqlite3.Connection, user_id: int, email: str):
+    # VULNERABLE: str.format interpolation into SQL (B608)
+    query = "UPDATE users SET email = '{}' WHERE id = {}".format(email, user_id)
+    conn.execute(query)
+
+
+def get_user_safe(conn: sqlite3.Connection, username: str):
+    # SAFE: parameterized query, no finding expected
+    return conn.execute(
+        "SELECT * FROM users WHERE username = ?", (username,)
+    ).fetchall()
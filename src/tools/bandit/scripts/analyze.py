@@ -0,0 +1,136 @@
+#!/usr/bin/env python3
+"""CLI entry point for Bandit security analysis.
+
+Standard wrapper that translates orchestrator CLI args to bandit_analyzer
+and produces Caldera envelope output format.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+# Add shared src to path for imports
+sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
+from common.cli_parser import add_common_args, validate_common_args
+from common.envelope_formatter import create_envelope, get_current_timestamp
+from common.path_normalization import normalize_file_path, normalize_dir_path
+
+from .bandit_analyzer import AnalysisResult, analyze_repository
+
+TOOL_NAME = "bandit"
+SCHEMA_VERSION = "1.0.0"
+
+
+def result_to_data_dict(result: AnalysisResult, repo_root: Path | None = None) -> dict[str, Any]:
+    """Convert AnalysisResult to the 'data' portion of envelope format."""
+    files = []
+    for f in result.files:
+        issues = []
+        for issue in f.issues:
+            issues.append({
+                "rule_id": issue.rule_id,
+                "test_name": issue.test_name,
+                "dd_category": issue.dd_category,
+                "line_start": issue.line_start,
+                "line_end": issue.line_end,
+                "column_start": issue.column_start,
+                "severity": issue.severity,
+                "confidence": issue.confidence,
+                "cwe_id": issue.cwe_id,
+                "message": issue.message,
+                "code_snippet": issue.code_snippet,
+            })
+        files.append({
+            "path": normalize_file_path(f.path, repo_root),
+            "lines": f.lines,
+            "issue_count": f.issue_count,
+            "issue_density": round(f.issue_density, 4),
+            "by_category": f.by_category,
+            "by_severity": f.by_severity,
+            "issues": issues,
+        })
+
+    directories = []
+    for d in result.directories:
+        directories.append({
+            "path": normalize_dir_path(d.path, repo_root),
+            "direct": {
+                "file_count": d.direct.file_count,
+                "issue_count": d.direct.issue_count,
+                "by_category": d.direct.by_category,
+                "by_severity": d.direct.by_severity,
+            },
+            "recursive": {
+                "file_count": d.recursive.file_count,
+                "issue_count": d.recursive.issue_count,
+                "by_category": d.recursive.by_category,
+                "by_severity": d.recursive.by_severity,
+            },
+        })
+
+    return {
+        "tool": TOOL_NAME,
+        "tool_version": result.bandit_version,
+        "summary": {
+            "total_files": len(result.files),
+            "total_directories": len(result.directories),
+            "files_with_issues": sum(1 for f in result.files if f.issue_count > 0),
+            "total_issues": len(result.findings),
+            "total_lines": sum(f.lines for f in result.files),
+            "issues_by_category": result.by_category,
+            "issues_by_severity": result.by_severity,
+        },
+        "files": files,
+        "directories": directories,
+        "analysis_duration_ms": result.analysis_duration_ms,
+    }
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Analyze Python security issues using Bandit")
+    add_common_args(parser)
+    parser.add_argument(
+        "--json-only",
+        action="store_true",
+        help="Only output JSON, no summary",
+    )
+    args = parser.parse_args()
+
+    common = validate_common_args(args)
+
+    print(f"Analyzing: {common.repo_path}")
+    result = analyze_repository(common.repo_path, common.repo_name)
+
+    print(f"Files analyzed: {len(result.files)}")
+    print(f"Issues found: {len(result.findings)}")
+    print(f"Duration: {result.analysis_duration_ms}ms")
+
+    data = result_to_data_dict(result, repo_root=common.repo_path)
+    output_dict = create_envelope(
+        data,
+        tool_name=TOOL_NAME,
+        tool_version=result.bandit_version,
+        run_id=common.run_id,
+        repo_id=common.repo_id,
+        branch=common.branch,
+        commit=common.commit,
+        timestamp=get_current_timestamp(),
+        schema_version=SCHEMA_VERSION,
+    )
+
+    common.output_path.write_text(json.dumps(output_dict, indent=2, ensure_ascii=False))
+    print(f"Output: {common.output_path}")
+
+    if not args.json_only:
+        for severity in ("HIGH", "MEDIUM", "LOW"):
+            count = result.by_severity.get(severity, 0)
+            if count:
+                print(f"  {severity}: {count}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,289 @@
+"""Bandit security analysis wrapper.
+
+Runs Bandit against a Python repository, maps Bandit test IDs to DD
+security categories, and aggregates findings per file and per directory.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# Bandit test ID -> DD security category.
+# Grouped by Bandit's test family numbering (B1xx general, B3xx blacklist
+# calls, B5xx transport, B6xx injection).
+RULE_TO_CATEGORY_MAP: dict[str, str] = {
+    # Hardcoded secrets
+    "B105": "hardcoded_secrets",
+    "B106": "hardcoded_secrets",
+    "B107": "hardcoded_secrets",
+    # Code/deserialization injection
+    "B102": "code_injection",
+    "B301": "insecure_deserialization",
+    "B302": "insecure_deserialization",
+    "B307": "code_injection",
+    "B506": "insecure_deserialization",
+    # Weak cryptography
+    "B303": "insecure_crypto",
+    "B304": "insecure_crypto",
+    "B305": "insecure_crypto",
+    "B311": "insecure_random",
+    "B324": "insecure_crypto",
+    # Insecure transport
+    "B501": "insecure_transport",
+    "B502": "insecure_transport",
+    "B503": "insecure_transport",
+    "B504": "insecure_transport",
+    "B507": "insecure_transport",
+    # Injection
+    "B601": "command_injection",
+    "B602": "command_injection",
+    "B603": "command_injection",
+    "B604": "command_injection",
+    "B605": "command_injection",
+    "B606": "command_injection",
+    "B607": "command_injection",
+    "B608": "sql_injection",
+    "B609": "command_injection",
+    "B610": "sql_injection",
+    "B611": "sql_injection",
+    # Path handling
+    "B108": "path_traversal",
+    "B310": "path_traversal",
+}
+
+DEFAULT_CATEGORY = "security_misc"
+
+# Bandit reports LOW/MEDIUM/HIGH; keep Caldera's severity vocabulary.
+SEVERITY_MAP = {
+    "LOW": "LOW",
+    "MEDIUM": "MEDIUM",
+    "HIGH": "HIGH",
+}
+
+
+@dataclass(frozen=True)
+class SecurityFinding:
+    """A single Bandit issue."""
+    rule_id: str
+    test_name: str
+    dd_category: str
+    file_path: str
+    line_start: int
+    line_end: int
+    column_start: int | None
+    severity: str
+    confidence: str
+    cwe_id: str | None
+    message: str
+    code_snippet: str | None
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of Bandit findings."""
+    path: str
+    lines: int
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    issues: list[SecurityFinding] = field(default_factory=list)
+
+    @property
+    def issue_density(self) -> float:
+        if self.lines <= 0:
+            return 0.0
+        return self.issue_count / self.lines * 100
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete Bandit analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    bandit_version: str
+    findings: list[SecurityFinding] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    analysis_duration_ms: int = 0
+
+
+def get_bandit_version() -> str:
+    """Return the installed Bandit version, or 'unknown'."""
+    try:
+        result = subprocess.run(
+            ["bandit", "--version"],
+            capture_output=True,
+            text=True,
+            timeout=30,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return "unknown"
+    # First line looks like "bandit 1.7.9"
+    first_line = (result.stdout or "").splitlines()[:1]
+    if first_line and first_line[0].startswith("bandit"):
+        return first_line[0].split()[-1]
+    return "unknown"
+
+
+def run_bandit(repo_path: Path) -> dict:
+    """Run bandit recursively and return its parsed JSON report.
+
+    Bandit exits 1 when issues are found, so only exit codes >= 2 are
+    treated as execution failures.
+    """
+    result = subprocess.run(
+        ["bandit", "-r", "-f", "json", "-q", str(repo_path)],
+        capture_output=True,
+        text=True,
+        timeout=1800,
+    )
+    if result.returncode >= 2:
+        raise RuntimeError(f"bandit failed (exit {result.returncode}): {result.stderr.strip()}")
+    return json.loads(result.stdout or "{}")
+
+
+def map_finding(raw: dict) -> SecurityFinding:
+    """Map one raw Bandit result entry to a SecurityFinding."""
+    rule_id = raw.get("test_id", "")
+    line_range = raw.get("line_range") or [raw.get("line_number", 1)]
+    cwe = raw.get("issue_cwe") or {}
+    cwe_id = f"CWE-{cwe['id']}" if cwe.get("id") else None
+    return SecurityFinding(
+        rule_id=rule_id,
+        test_name=raw.get("test_name", ""),
+        dd_category=RULE_TO_CATEGORY_MAP.get(rule_id, DEFAULT_CATEGORY),
+        file_path=raw.get("filename", ""),
+        line_start=raw.get("line_number", line_range[0]),
+        line_end=line_range[-1],
+        column_start=raw.get("col_offset"),
+        severity=SEVERITY_MAP.get(raw.get("issue_severity", ""), "LOW"),
+        confidence=raw.get("issue_confidence", "LOW"),
+        cwe_id=cwe_id,
+        message=raw.get("issue_text", ""),
+        code_snippet=raw.get("code"),
+    )
+
+
+def _count_lines(path: Path) -> int:
+    try:
+        return len(path.read_text(encoding="utf-8", errors="replace").splitlines())
+    except OSError:
+        return 0
+
+
+def build_file_stats(
+    findings: list[SecurityFinding], repo_path: Path
+) -> list[FileStats]:
+    """Aggregate findings per Python file, including clean files."""
+    by_file: dict[str, FileStats] = {}
+    for path in sorted(repo_path.rglob("*.py")):
+        if ".git" in path.parts:
+            continue
+        rel = path.relative_to(repo_path).as_posix()
+        by_file[rel] = FileStats(path=rel, lines=_count_lines(path))
+
+    for finding in findings:
+        rel = _relativize(finding.file_path, repo_path)
+        stats = by_file.setdefault(rel, FileStats(path=rel, lines=0))
+        stats.issue_count += 1
+        stats.by_category[finding.dd_category] = stats.by_category.get(finding.dd_category, 0) + 1
+        stats.by_severity[finding.severity] = stats.by_severity.get(finding.severity, 0) + 1
+        stats.issues.append(finding)
+    return list(by_file.values())
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Compute direct and recursive rollups for every ancestor directory."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    for stats in files:
+        parent = str(Path(stats.path).parent.as_posix())
+        if parent == ".":
+            parent = "."
+        _accumulate(direct[parent], stats)
+        ancestors = [parent]
+        while parent not in (".", ""):
+            parent = str(Path(parent).parent.as_posix())
+            ancestors.append(parent)
+        for ancestor in ancestors:
+            _accumulate(recursive[ancestor], stats)
+
+    entries = []
+    for path in sorted(recursive):
+        entries.append(
+            DirectoryEntry(
+                path=path,
+                direct=direct.get(path, DirectoryStats()),
+                recursive=recursive[path],
+            )
+        )
+    return entries
+
+
+def _accumulate(target: DirectoryStats, stats: FileStats) -> None:
+    target.file_count += 1
+    target.issue_count += stats.issue_count
+    for category, count in stats.by_category.items():
+        target.by_category[category] = target.by_category.get(category, 0) + count
+    for severity, count in stats.by_severity.items():
+        target.by_severity[severity] = target.by_severity.get(severity, 0) + count
+
+
+def _relativize(raw_path: str, repo_path: Path) -> str:
+    path = Path(raw_path)
+    try:
+        return path.resolve().relative_to(repo_path.resolve()).as_posix()
+    except ValueError:
+        return path.as_posix().lstrip("./")
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Run Bandit and build the full aggregated analysis result."""
+    start = time.perf_counter()
+    report = run_bandit(repo_path)
+    findings = [map_finding(raw) for raw in report.get("results", [])]
+    files = build_file_stats(findings, repo_path)
+    directories = build_directory_stats(files)
+
+    by_category: dict[str, int] = {}
+    by_severity: dict[str, int] = {}
+    for finding in findings:
+        by_category[finding.dd_category] = by_category.get(finding.dd_category, 0) + 1
+        by_severity[finding.severity] = by_severity.get(finding.severity, 0) + 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        bandit_version=get_bandit_version(),
+        findings=findings,
+        files=files,
+        directories=directories,
+        by_category=by_category,
+        by_severity=by_severity,
+        analysis_duration_ms=int((time.perf_counter() - start) * 1000),
+    )
@@ -0,0 +1,11 @@
+"""Pytest configuration for Bandit tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add bandit tool directory to path so 'scripts' can be imported as a package
+bandit_root = Path(__file__).parent.parent
+sys.path.insert(0, str(bandit_root))
+sys.path.insert(0, str(bandit_root / "scripts"))
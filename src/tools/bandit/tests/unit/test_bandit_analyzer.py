@@ -0,0 +1,97 @@
+"""Unit tests for bandit_analyzer mapping and aggregation."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from bandit_analyzer import (
+    DEFAULT_CATEGORY,
+    RULE_TO_CATEGORY_MAP,
+    build_directory_stats,
+    build_file_stats,
+    map_finding,
+)
+
+
+def _raw_result(**overrides) -> dict:
+    raw = {
+        "test_id": "B608",
+        "test_name": "hardcoded_sql_expressions",
+        "filename": "src/db.py",
+        "line_number": 12,
+        "line_range": [12, 13],
+        "col_offset": 4,
+        "issue_severity": "MEDIUM",
+        "issue_confidence": "HIGH",
+        "issue_cwe": {"id": 89, "link": "https://cwe.mitre.org/data/definitions/89.html"},
+        "issue_text": "Possible SQL injection vector",
+        "code": "query = f\"SELECT * FROM users WHERE name = '{name}'\"",
+    }
+    raw.update(overrides)
+    return raw
+
+
+def test_map_finding_maps_core_fields() -> None:
+    finding = map_finding(_raw_result())
+
+    assert finding.rule_id == "B608"
+    assert finding.dd_category == "sql_injection"
+    assert finding.line_start == 12
+    assert finding.line_end == 13
+    assert finding.severity == "MEDIUM"
+    assert finding.confidence == "HIGH"
+    assert finding.cwe_id == "CWE-89"
+
+
+def test_map_finding_unknown_rule_falls_back_to_misc() -> None:
+    finding = map_finding(_raw_result(test_id="B999"))
+    assert finding.dd_category == DEFAULT_CATEGORY
+
+
+def test_map_finding_handles_missing_cwe() -> None:
+    finding = map_finding(_raw_result(issue_cwe=None))
+    assert finding.cwe_id is None
+
+
+def test_category_map_covers_eval_scenarios() -> None:
+    """Rules exercised by the synthetic eval corpus must be mapped."""
+    assert RULE_TO_CATEGORY_MAP["B608"] == "sql_injection"
+    assert RULE_TO_CATEGORY_MAP["B105"] == "hardcoded_secrets"
+    assert RULE_TO_CATEGORY_MAP["B106"] == "hardcoded_secrets"
+    assert RULE_TO_CATEGORY_MAP["B107"] == "hardcoded_secrets"
+    assert RULE_TO_CATEGORY_MAP["B324"] == "insecure_crypto"
+
+
+def test_build_file_stats_includes_clean_files(tmp_path: Path) -> None:
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "dirty.py").write_text("import pickle\n")
+    (tmp_path / "src" / "clean.py").write_text("x = 1\n")
+
+    finding = map_finding(
+        _raw_result(filename=str(tmp_path / "src" / "dirty.py"), test_id="B301")
+    )
+    files = build_file_stats([finding], tmp_path)
+
+    by_path = {f.path: f for f in files}
+    assert by_path["src/dirty.py"].issue_count == 1
+    assert by_path["src/dirty.py"].by_category == {"insecure_deserialization": 1}
+    assert by_path["src/clean.py"].issue_count == 0
+
+
+def test_build_directory_stats_recursive_gte_direct(tmp_path: Path) -> None:
+    (tmp_path / "src" / "nested").mkdir(parents=True)
+    (tmp_path / "src" / "a.py").write_text("x = 1\n")
+    (tmp_path / "src" / "nested" / "b.py").write_text("import pickle\n")
+
+    finding = map_finding(
+        _raw_result(filename=str(tmp_path / "src" / "nested" / "b.py"), test_id="B301")
+    )
+    files = build_file_stats([finding], tmp_path)
+    directories = build_directory_stats(files)
+
+    by_path = {d.path: d for d in directories}
+    src = by_path["src"]
+    assert src.recursive.issue_count >= src.direct.issue_count
+    assert src.recursive.issue_count == 1
+    assert src.direct.issue_count == 0
+    assert by_path["src/nested"].direct.issue_count == 1
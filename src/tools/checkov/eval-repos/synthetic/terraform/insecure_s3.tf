@@ -0,0 +1,15 @@
+# Unencrypted, publicly readable bucket — expected CKV_AWS_18/19/20 family findings.
+
+resource "aws_s3_bucket" "data" {
+  bucket = "caldera-eval-insecure-data"
+  acl    = "public-read"
+}
+
+resource "aws_s3_bucket_public_access_block" "data" {
+  bucket = aws_s3_bucket.data.id
+
+  block_public_acls       = false
+  block_public_policy     = false
+  ignore_public_acls      = false
+  restrict_public_buckets = false
+}
@@ -0,0 +1,20 @@
+# Security group open to the world — expected CKV_AWS_24/260 family findings.
+
+resource "aws_security_group" "wide_open" {
+  name        = "caldera-eval-wide-open"
+  description = "Allows SSH from anywhere"
+
+  ingress {
+    from_port   = 22
+    to_port     = 22
+    protocol    = "tcp"
+    cidr_blocks = ["0.0.0.0/0"]
+  }
+
+  egress {
+    from_port   = 0
+    to_port     = 0
+    protocol    = "-1"
+    cidr_blocks = ["0.0.0.0/0"]
+  }
+}
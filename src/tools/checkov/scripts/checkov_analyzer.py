@@ -0,0 +1,310 @@
+"""Checkov infrastructure-as-code analysis wrapper.
+
+Runs Checkov against a repository's Terraform and Kubernetes manifests,
+maps policy violations to DD categories, and aggregates findings per file
+and per directory with resource-level locations preserved.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# Keyword -> DD category, matched against the lowercased check name.
+# Checkov has thousands of policies, so categorisation is by failure mode
+# rather than by individual check ID. First match wins.
+KEYWORD_TO_CATEGORY_MAP: list[tuple[str, str]] = [
+    ("encrypt", "missing_encryption"),
+    ("kms", "missing_encryption"),
+    ("public", "public_exposure"),
+    ("0.0.0.0", "network_exposure"),
+    ("security group", "network_exposure"),
+    ("ingress", "network_exposure"),
+    ("logging", "missing_logging"),
+    ("log", "missing_logging"),
+    ("iam", "iam_misconfiguration"),
+    ("privilege", "iam_misconfiguration"),
+    ("root", "iam_misconfiguration"),
+    ("secret", "hardcoded_secrets"),
+    ("versioning", "resilience"),
+    ("backup", "resilience"),
+    ("limit", "resource_limits"),
+    ("capabilit", "container_hardening"),
+    ("readonlyrootfilesystem", "container_hardening"),
+    ("run as", "container_hardening"),
+]
+
+DEFAULT_CATEGORY = "iac_misc"
+
+# Frameworks we ask Checkov to evaluate.
+FRAMEWORKS = ("terraform", "kubernetes")
+
+TERRAFORM_SUFFIXES = {".tf"}
+
+YAML_SUFFIXES = {".yaml", ".yml"}
+
+
+@dataclass(frozen=True)
+class IacFinding:
+    """A single failed Checkov policy check."""
+    rule_id: str
+    check_name: str
+    framework: str
+    dd_category: str
+    file_path: str
+    resource: str
+    line_start: int
+    line_end: int
+    severity: str | None
+    guideline: str | None
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of Checkov findings."""
+    path: str
+    lines: int
+    issue_count: int = 0
+    passed_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_framework: dict[str, int] = field(default_factory=dict)
+    issues: list[IacFinding] = field(default_factory=list)
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_framework: dict[str, int] = field(default_factory=dict)
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete Checkov analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    checkov_version: str
+    findings: list[IacFinding] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_framework: dict[str, int] = field(default_factory=dict)
+    total_passed: int = 0
+    analysis_duration_ms: int = 0
+
+
+def get_checkov_version() -> str:
+    """Return the installed Checkov version, or 'unknown'."""
+    try:
+        result = subprocess.run(
+            ["checkov", "--version"],
+            capture_output=True,
+            text=True,
+            timeout=60,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return "unknown"
+    version = (result.stdout or "").strip()
+    return version or "unknown"
+
+
+def _is_kubernetes_manifest(path: Path) -> bool:
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        return False
+    return "apiVersion:" in text and "kind:" in text
+
+
+def discover_iac_files(repo_path: Path) -> list[str]:
+    """Find Terraform files and Kubernetes manifests, as repo-relative paths."""
+    files: list[str] = []
+    for path in sorted(repo_path.rglob("*")):
+        if not path.is_file() or ".git" in path.parts:
+            continue
+        if path.suffix in TERRAFORM_SUFFIXES:
+            files.append(path.relative_to(repo_path).as_posix())
+        elif path.suffix in YAML_SUFFIXES and _is_kubernetes_manifest(path):
+            files.append(path.relative_to(repo_path).as_posix())
+    return files
+
+
+def run_checkov(repo_path: Path) -> list[dict]:
+    """Run checkov and return the list of per-framework report dicts.
+
+    Checkov exits 1 when failed checks exist, so only other non-zero codes
+    are treated as execution failures. Single-framework runs emit one dict
+    instead of a list; both shapes are normalised here.
+    """
+    result = subprocess.run(
+        [
+            "checkov",
+            "--directory", str(repo_path),
+            "--framework", *FRAMEWORKS,
+            "--output", "json",
+            "--quiet",
+            "--compact",
+        ],
+        capture_output=True,
+        text=True,
+        timeout=1800,
+    )
+    if result.returncode not in (0, 1):
+        raise RuntimeError(
+            f"checkov failed (exit {result.returncode}): {result.stderr.strip()}"
+        )
+    report = json.loads(result.stdout or "[]")
+    if isinstance(report, dict):
+        return [report]
+    return report
+
+
+def categorize_check(check_name: str) -> str:
+    """Map a Checkov check name to a DD category by failure-mode keyword."""
+    lowered = check_name.lower()
+    for keyword, category in KEYWORD_TO_CATEGORY_MAP:
+        if keyword in lowered:
+            return category
+    return DEFAULT_CATEGORY
+
+
+def map_check(raw: dict, framework: str) -> IacFinding:
+    """Map one raw failed check entry to an IacFinding."""
+    check_name = raw.get("check_name", "")
+    line_range = raw.get("file_line_range") or [1, 1]
+    severity = raw.get("severity")
+    return IacFinding(
+        rule_id=raw.get("check_id", ""),
+        check_name=check_name,
+        framework=framework,
+        dd_category=categorize_check(check_name),
+        # Checkov reports paths with a leading slash relative to the scan root.
+        file_path=raw.get("file_path", "").lstrip("/"),
+        resource=raw.get("resource", ""),
+        line_start=line_range[0],
+        line_end=line_range[-1],
+        severity=severity.upper() if severity else None,
+        guideline=raw.get("guideline"),
+    )
+
+
+def _count_lines(path: Path) -> int:
+    try:
+        return len(path.read_text(encoding="utf-8", errors="replace").splitlines())
+    except OSError:
+        return 0
+
+
+def build_file_stats(
+    findings: list[IacFinding],
+    passed_paths: list[str],
+    iac_files: list[str],
+    repo_path: Path,
+) -> list[FileStats]:
+    """Aggregate findings per IaC file, including clean files."""
+    by_file: dict[str, FileStats] = {}
+    for rel in iac_files:
+        by_file[rel] = FileStats(path=rel, lines=_count_lines(repo_path / rel))
+
+    for rel in passed_paths:
+        stats = by_file.setdefault(rel, FileStats(path=rel, lines=0))
+        stats.passed_count += 1
+
+    for finding in findings:
+        stats = by_file.setdefault(finding.file_path, FileStats(path=finding.file_path, lines=0))
+        stats.issue_count += 1
+        stats.by_category[finding.dd_category] = stats.by_category.get(finding.dd_category, 0) + 1
+        stats.by_framework[finding.framework] = stats.by_framework.get(finding.framework, 0) + 1
+        stats.issues.append(finding)
+    return list(by_file.values())
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Compute direct and recursive rollups for every ancestor directory."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    for stats in files:
+        parent = str(Path(stats.path).parent.as_posix())
+        if parent == ".":
+            parent = "."
+        _accumulate(direct[parent], stats)
+        ancestors = [parent]
+        while parent not in (".", ""):
+            parent = str(Path(parent).parent.as_posix())
+            ancestors.append(parent)
+        for ancestor in ancestors:
+            _accumulate(recursive[ancestor], stats)
+
+    entries = []
+    for path in sorted(recursive):
+        entries.append(
+            DirectoryEntry(
+                path=path,
+                direct=direct.get(path, DirectoryStats()),
+                recursive=recursive[path],
+            )
+        )
+    return entries
+
+
+def _accumulate(target: DirectoryStats, stats: FileStats) -> None:
+    target.file_count += 1
+    target.issue_count += stats.issue_count
+    for category, count in stats.by_category.items():
+        target.by_category[category] = target.by_category.get(category, 0) + count
+    for framework, count in stats.by_framework.items():
+        target.by_framework[framework] = target.by_framework.get(framework, 0) + count
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Run Checkov and build the full aggregated analysis result."""
+    start = time.perf_counter()
+    reports = run_checkov(repo_path)
+
+    findings: list[IacFinding] = []
+    passed_paths: list[str] = []
+    for report in reports:
+        framework = report.get("check_type", "unknown")
+        results = report.get("results") or {}
+        for raw in results.get("failed_checks", []):
+            findings.append(map_check(raw, framework))
+        for raw in results.get("passed_checks", []):
+            passed_paths.append(raw.get("file_path", "").lstrip("/"))
+
+    iac_files = discover_iac_files(repo_path)
+    files = build_file_stats(findings, passed_paths, iac_files, repo_path)
+    directories = build_directory_stats(files)
+
+    by_category: dict[str, int] = {}
+    by_framework: dict[str, int] = {}
+    for finding in findings:
+        by_category[finding.dd_category] = by_category.get(finding.dd_category, 0) + 1
+        by_framework[finding.framework] = by_framework.get(finding.framework, 0) + 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        checkov_version=get_checkov_version(),
+        findings=findings,
+        files=files,
+        directories=directories,
+        by_category=by_category,
+        by_framework=by_framework,
+        total_passed=len(passed_paths),
+        analysis_duration_ms=int((time.perf_counter() - start) * 1000),
+    )
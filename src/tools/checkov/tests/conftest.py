@@ -0,0 +1,11 @@
+"""Pytest configuration for Checkov tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add checkov tool directory to path so 'scripts' can be imported as a package
+checkov_root = Path(__file__).parent.parent
+sys.path.insert(0, str(checkov_root))
+sys.path.insert(0, str(checkov_root / "scripts"))
@@ -0,0 +1,91 @@
+"""Unit tests for checkov_analyzer mapping, categorisation, and aggregation."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from checkov_analyzer import (
+    DEFAULT_CATEGORY,
+    build_directory_stats,
+    build_file_stats,
+    categorize_check,
+    discover_iac_files,
+    map_check,
+)
+
+
+def _raw_check(**overrides) -> dict:
+    raw = {
+        "check_id": "CKV_AWS_20",
+        "check_name": "Ensure the S3 bucket does not allow READ permissions to everyone",
+        "file_path": "/terraform/insecure_s3.tf",
+        "file_line_range": [3, 6],
+        "resource": "aws_s3_bucket.data",
+        "severity": None,
+        "guideline": "https://docs.prismacloud.io/policy-reference/s3-policies",
+    }
+    raw.update(overrides)
+    return raw
+
+
+def test_map_check_maps_core_fields() -> None:
+    finding = map_check(_raw_check(), "terraform")
+
+    assert finding.rule_id == "CKV_AWS_20"
+    assert finding.framework == "terraform"
+    assert finding.file_path == "terraform/insecure_s3.tf"
+    assert finding.resource == "aws_s3_bucket.data"
+    assert finding.line_start == 3
+    assert finding.line_end == 6
+    assert finding.severity is None
+
+
+def test_map_check_uppercases_severity() -> None:
+    finding = map_check(_raw_check(severity="high"), "terraform")
+    assert finding.severity == "HIGH"
+
+
+def test_categorize_check_by_keyword() -> None:
+    assert categorize_check("Ensure all data stored in the S3 bucket is encrypted") == "missing_encryption"
+    assert categorize_check("Ensure no security groups allow ingress from 0.0.0.0/0 to port 22") == "network_exposure"
+    assert categorize_check("Ensure S3 bucket does not allow public access") == "public_exposure"
+    assert categorize_check("Something entirely unrelated") == DEFAULT_CATEGORY
+
+
+def test_discover_iac_files_filters_plain_yaml(tmp_path: Path) -> None:
+    (tmp_path / "terraform").mkdir()
+    (tmp_path / "terraform" / "main.tf").write_text('resource "aws_s3_bucket" "b" {}\n')
+    (tmp_path / "pod.yaml").write_text("apiVersion: v1\nkind: Pod\n")
+    (tmp_path / "config.yaml").write_text("key: value\n")
+
+    files = discover_iac_files(tmp_path)
+
+    assert files == ["pod.yaml", "terraform/main.tf"]
+
+
+def test_build_file_stats_counts_passed_and_failed(tmp_path: Path) -> None:
+    (tmp_path / "main.tf").write_text('resource "aws_s3_bucket" "b" {}\n')
+
+    finding = map_check(_raw_check(file_path="/main.tf"), "terraform")
+    files = build_file_stats([finding], ["main.tf", "main.tf"], ["main.tf"], tmp_path)
+
+    assert len(files) == 1
+    stats = files[0]
+    assert stats.issue_count == 1
+    assert stats.passed_count == 2
+    assert stats.by_framework == {"terraform": 1}
+
+
+def test_build_directory_stats_recursive_gte_direct(tmp_path: Path) -> None:
+    (tmp_path / "infra" / "prod").mkdir(parents=True)
+    (tmp_path / "infra" / "prod" / "s3.tf").write_text('resource "aws_s3_bucket" "b" {}\n')
+
+    finding = map_check(_raw_check(file_path="/infra/prod/s3.tf"), "terraform")
+    files = build_file_stats([finding], [], ["infra/prod/s3.tf"], tmp_path)
+    directories = build_directory_stats(files)
+
+    by_path = {d.path: d for d in directories}
+    infra = by_path["infra"]
+    assert infra.recursive.issue_count == 1
+    assert infra.direct.issue_count == 0
+    assert by_path["infra/prod"].direct.issue_count == 1
@@ -0,0 +1,3 @@
+module example.com/caldera-synthetic
+
+go 1.22
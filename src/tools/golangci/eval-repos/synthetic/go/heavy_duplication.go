@@ -0,0 +1,111 @@
+// Go file with heavy duplication - two near-identical report generators
+// (dupl target). Mirrors heavy_duplication.rs from the pmd-cpd corpus.
+package synthetic
+
+import "strings"
+
+// ReportItem is the record both generators format.
+type ReportItem struct {
+	ID        string
+	Name      string
+	Email     string
+	Status    string
+	CreatedAt string
+}
+
+// GenerateUserReport formats a user report.
+func GenerateUserReport(users []ReportItem) string {
+	var lines []string
+	lines = append(lines, strings.Repeat("=", 60))
+	lines = append(lines, "USER REPORT")
+	lines = append(lines, strings.Repeat("=", 60))
+	lines = append(lines, "")
+
+	for _, item := range users {
+		id := item.ID
+		if id == "" {
+			id = "N/A"
+		}
+		name := item.Name
+		if name == "" {
+			name = "Unknown"
+		}
+		email := item.Email
+		if email == "" {
+			email = "N/A"
+		}
+		status := item.Status
+		if status == "" {
+			status = "active"
+		}
+		createdAt := item.CreatedAt
+		if createdAt == "" {
+			createdAt = "Unknown"
+		}
+		lines = append(lines, "ID:       "+id)
+		lines = append(lines, "Name:     "+name)
+		lines = append(lines, "Email:    "+email)
+		lines = append(lines, "Status:   "+status)
+		lines = append(lines, "Created:  "+createdAt)
+		lines = append(lines, strings.Repeat("-", 40))
+	}
+
+	lines = append(lines, "")
+	lines = append(lines, "Total records: "+itoa(len(users)))
+	return strings.Join(lines, "\n")
+}
+
+// GenerateCustomerReport formats a customer report. Deliberately a
+// copy-paste of GenerateUserReport with the heading swapped.
+func GenerateCustomerReport(customers []ReportItem) string {
+	var lines []string
+	lines = append(lines, strings.Repeat("=", 60))
+	lines = append(lines, "CUSTOMER REPORT")
+	lines = append(lines, strings.Repeat("=", 60))
+	lines = append(lines, "")
+
+	for _, item := range customers {
+		id := item.ID
+		if id == "" {
+			id = "N/A"
+		}
+		name := item.Name
+		if name == "" {
+			name = "Unknown"
+		}
+		email := item.Email
+		if email == "" {
+			email = "N/A"
+		}
+		status := item.Status
+		if status == "" {
+			status = "active"
+		}
+		createdAt := item.CreatedAt
+		if createdAt == "" {
+			createdAt = "Unknown"
+		}
+		lines = append(lines, "ID:       "+id)
+		lines = append(lines, "Name:     "+name)
+		lines = append(lines, "Email:    "+email)
+		lines = append(lines, "Status:   "+status)
+		lines = append(lines, "Created:  "+createdAt)
+		lines = append(lines, strings.Repeat("-", 40))
+	}
+
+	lines = append(lines, "")
+	lines = append(lines, "Total records: "+itoa(len(customers)))
+	return strings.Join(lines, "\n")
+}
+
+func itoa(n int) string {
+	if n == 0 {
+		return "0"
+	}
+	digits := ""
+	for n > 0 {
+		digits = string(rune('0'+n%10)) + digits
+		n /= 10
+	}
+	return digits
+}
@@ -0,0 +1,183 @@
+// Massive Go file - high cyclomatic complexity (gocyclo, gocognit targets).
+// Mirrors massive.rs from the lizard corpus.
+package synthetic
+
+import (
+	"errors"
+	"strings"
+)
+
+// Order states used by the dispatcher below.
+const (
+	StateNew        = "new"
+	StatePaid       = "paid"
+	StateShipped    = "shipped"
+	StateDelivered  = "delivered"
+	StateCancelled  = "cancelled"
+	StateRefunded   = "refunded"
+	StateOnHold     = "on_hold"
+	StateBackorder  = "backorder"
+	StateReturned   = "returned"
+	StateLost       = "lost"
+	StateUnknown    = "unknown"
+	PriorityUrgent  = 3
+	PriorityHigh    = 2
+	PriorityNormal  = 1
+	PriorityLow     = 0
+)
+
+// Order is the synthetic domain object driven through the state machine.
+type Order struct {
+	State     string
+	Priority  int
+	Amount    float64
+	Country   string
+	Items     int
+	Expedited bool
+	Fragile   bool
+	Oversized bool
+}
+
+// DispatchOrder is deliberately one enormous branchy function so that
+// gocyclo and gocognit both fire on it.
+func DispatchOrder(order *Order, action string) (string, error) {
+	if order == nil {
+		return "", errors.New("nil order")
+	}
+	switch order.State {
+	case StateNew:
+		if action == "pay" {
+			if order.Amount <= 0 {
+				return "", errors.New("non-positive amount")
+			}
+			if order.Amount > 10000 && !order.Expedited {
+				order.State = StateOnHold
+				return "held for review", nil
+			}
+			order.State = StatePaid
+			return "paid", nil
+		}
+		if action == "cancel" {
+			order.State = StateCancelled
+			return "cancelled", nil
+		}
+		return "", errors.New("invalid action for new order")
+	case StatePaid:
+		if action == "ship" {
+			if order.Items <= 0 {
+				order.State = StateBackorder
+				return "backordered", nil
+			}
+			if order.Fragile && order.Oversized {
+				if order.Priority >= PriorityHigh {
+					order.State = StateShipped
+					return "shipped with special handling", nil
+				}
+				order.State = StateOnHold
+				return "awaiting special handling slot", nil
+			}
+			order.State = StateShipped
+			return "shipped", nil
+		}
+		if action == "refund" {
+			order.State = StateRefunded
+			return "refunded", nil
+		}
+		if action == "cancel" {
+			if order.Amount > 500 {
+				order.State = StateOnHold
+				return "cancellation under review", nil
+			}
+			order.State = StateCancelled
+			return "cancelled", nil
+		}
+		return "", errors.New("invalid action for paid order")
+	case StateShipped:
+		switch action {
+		case "deliver":
+			order.State = StateDelivered
+			return "delivered", nil
+		case "return":
+			order.State = StateReturned
+			return "returned", nil
+		case "lost":
+			order.State = StateLost
+			if order.Amount > 100 {
+				return "lost, claim opened", nil
+			}
+			return "lost", nil
+		default:
+			return "", errors.New("invalid action for shipped order")
+		}
+	case StateOnHold:
+		if action == "release" {
+			if order.Priority == PriorityUrgent {
+				order.State = StateShipped
+				return "released and shipped", nil
+			}
+			order.State = StatePaid
+			return "released", nil
+		}
+		if action == "cancel" {
+			order.State = StateCancelled
+			return "cancelled from hold", nil
+		}
+		return "", errors.New("invalid action for held order")
+	case StateBackorder:
+		if action == "restock" {
+			order.State = StatePaid
+			return "restocked", nil
+		}
+		if action == "refund" {
+			order.State = StateRefunded
+			return "refunded from backorder", nil
+		}
+		return "", errors.New("invalid action for backordered order")
+	case StateReturned:
+		if action == "refund" {
+			order.State = StateRefunded
+			return "refunded after return", nil
+		}
+		return "", errors.New("invalid action for returned order")
+	default:
+		return "", errors.New("unknown state: " + order.State)
+	}
+}
+
+// ClassifyShipping piles on more branches: destination, weight class,
+// priority, and surcharges all interact.
+func ClassifyShipping(order *Order) string {
+	if order == nil {
+		return StateUnknown
+	}
+	var class strings.Builder
+	switch strings.ToUpper(order.Country) {
+	case "US", "CA":
+		class.WriteString("domestic")
+	case "DE", "FR", "NL", "BE":
+		class.WriteString("eu")
+	case "":
+		return StateUnknown
+	default:
+		class.WriteString("intl")
+	}
+	if order.Oversized {
+		class.WriteString("-freight")
+	} else if order.Fragile {
+		class.WriteString("-fragile")
+	} else if order.Items > 10 {
+		class.WriteString("-bulk")
+	} else {
+		class.WriteString("-parcel")
+	}
+	if order.Expedited {
+		if order.Priority >= PriorityHigh {
+			class.WriteString("-overnight")
+		} else {
+			class.WriteString("-express")
+		}
+	} else if order.Priority == PriorityLow {
+		class.WriteString("-deferred")
+	}
+	return class.String()
+}
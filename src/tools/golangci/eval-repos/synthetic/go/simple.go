@@ -0,0 +1,18 @@
+// Clean Go file - negative control with no expected findings.
+package synthetic
+
+// Add returns the sum of two integers.
+func Add(a, b int) int {
+	return a + b
+}
+
+// Clamp bounds value to the inclusive [low, high] range.
+func Clamp(value, low, high int) int {
+	if value < low {
+		return low
+	}
+	if value > high {
+		return high
+	}
+	return value
+}
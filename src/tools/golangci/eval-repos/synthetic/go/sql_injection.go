@@ -0,0 +1,37 @@
+// Go file with SQL built by string formatting (gosec G201/G202 targets)
+// and unchecked errors (errcheck). Mirrors sql_injection.rs from the
+// semgrep corpus.
+package synthetic
+
+import (
+	"database/sql"
+	"fmt"
+	"os"
+)
+
+// FindUserByName interpolates user input straight into the query (G201).
+func FindUserByName(db *sql.DB, name string) (*sql.Rows, error) {
+	query := fmt.Sprintf("SELECT id, name FROM users WHERE name = '%s'", name)
+	return db.Query(query)
+}
+
+// DeleteSession concatenates the token into the statement (G202) and
+// ignores the execution error (errcheck).
+func DeleteSession(db *sql.DB, token string) {
+	db.Exec("DELETE FROM sessions WHERE token = '" + token + "'")
+}
+
+// AuditLog writes without checking the error (errcheck).
+func AuditLog(message string) {
+	file, err := os.OpenFile("audit.log", os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0o644)
+	if err != nil {
+		return
+	}
+	file.WriteString(message + "\n")
+	file.Close()
+}
+
+// FindUserByID is the safe counterpart using a parameterized query.
+func FindUserByID(db *sql.DB, id int64) (*sql.Rows, error) {
+	return db.Query("SELECT id, name FROM users WHERE id = ?", id)
+}
@@ -0,0 +1,279 @@
+"""golangci-lint Go analysis wrapper.
+
+Runs golangci-lint over a repository, maps linter names to DD categories,
+and aggregates findings per file and per directory. A single golangci-lint
+run bundles lint (govet, staticcheck), complexity (gocyclo), duplication
+(dupl), and security (gosec) coverage for Go code.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# golangci-lint linter name -> DD category.
+# Grouped by the class of problem the linter detects.
+LINTER_TO_CATEGORY_MAP: dict[str, str] = {
+    # Security issues
+    "gosec": "security",
+    # Unchecked errors
+    "errcheck": "error_handling",
+    # Correctness bugs
+    "govet": "correctness",
+    "staticcheck": "correctness",
+    # Complexity hotspots
+    "gocyclo": "complexity",
+    "gocognit": "complexity",
+    # Copy-paste duplication
+    "dupl": "duplication",
+    # Dead or ineffectual code
+    "unused": "dead_code",
+    "deadcode": "dead_code",
+    "ineffassign": "dead_code",
+    # Needless complexity that simplifies away
+    "gosimple": "simplification",
+    # Formatting drift
+    "gofmt": "formatting",
+    "goimports": "formatting",
+    # Style nits
+    "revive": "style",
+    "golint": "style",
+    "misspell": "style",
+}
+
+DEFAULT_CATEGORY = "go_misc"
+
+# Linter -> Caldera's severity vocabulary (golangci-lint's own Severity
+# field is usually empty, so the linter is the best signal).
+LINTER_TO_SEVERITY = {
+    "gosec": "HIGH",
+    "errcheck": "MEDIUM",
+    "govet": "MEDIUM",
+    "staticcheck": "MEDIUM",
+}
+
+DEFAULT_SEVERITY = "LOW"
+
+EXCLUDED_DIRS = {".git", "vendor"}
+
+
+@dataclass(frozen=True)
+class GoFinding:
+    """A single golangci-lint issue."""
+    rule_id: str
+    dd_category: str
+    file_path: str
+    line_start: int
+    line_end: int
+    column_start: int | None
+    severity: str
+    message: str
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of golangci-lint findings."""
+    path: str
+    lines: int
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    issues: list[GoFinding] = field(default_factory=list)
+
+    @property
+    def issue_density(self) -> float:
+        if self.lines <= 0:
+            return 0.0
+        return self.issue_count / self.lines * 100
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete golangci-lint analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    golangci_version: str
+    findings: list[GoFinding] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    analysis_duration_ms: int = 0
+
+
+def get_golangci_version() -> str:
+    """Return the installed golangci-lint version, or 'unknown'."""
+    try:
+        result = subprocess.run(
+            ["golangci-lint", "--version"],
+            capture_output=True,
+            text=True,
+            timeout=30,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return "unknown"
+    # Output looks like "golangci-lint has version 1.61.0 built ..."
+    parts = (result.stdout or "").split()
+    for index, token in enumerate(parts[:-1]):
+        if token == "version":
+            return parts[index + 1]
+    return "unknown"
+
+
+def discover_go_files(repo_path: Path) -> list[str]:
+    """Find Go source files as repo-relative paths, skipping vendor trees."""
+    files: list[str] = []
+    for path in sorted(repo_path.rglob("*.go")):
+        if not path.is_file() or EXCLUDED_DIRS.intersection(path.parts):
+            continue
+        files.append(path.relative_to(repo_path).as_posix())
+    return files
+
+
+def run_golangci(repo_path: Path) -> list[dict]:
+    """Run golangci-lint on the repository and return raw issue entries.
+
+    golangci-lint exits 1 when issues are found, so only other non-zero
+    exit codes are treated as execution failures.
+    """
+    result = subprocess.run(
+        ["golangci-lint", "run", "--out-format", "json", "./..."],
+        capture_output=True,
+        text=True,
+        timeout=1800,
+        cwd=repo_path,
+    )
+    if result.returncode not in (0, 1):
+        raise RuntimeError(
+            f"golangci-lint failed (exit {result.returncode}): {result.stderr.strip()}"
+        )
+    report = json.loads(result.stdout or "{}")
+    return report.get("Issues") or []
+
+
+def map_issue(raw: dict) -> GoFinding:
+    """Map one raw golangci-lint issue to a GoFinding."""
+    linter = raw.get("FromLinter", "")
+    pos = raw.get("Pos", {})
+    line = pos.get("Line", 1)
+    line_range = raw.get("LineRange") or {}
+    return GoFinding(
+        rule_id=linter,
+        dd_category=LINTER_TO_CATEGORY_MAP.get(linter, DEFAULT_CATEGORY),
+        file_path=Path(pos.get("Filename", "")).as_posix(),
+        line_start=line_range.get("From", line),
+        line_end=line_range.get("To", line),
+        column_start=pos.get("Column") or None,
+        severity=LINTER_TO_SEVERITY.get(linter, DEFAULT_SEVERITY),
+        message=raw.get("Text", ""),
+    )
+
+
+def _count_lines(path: Path) -> int:
+    try:
+        return len(path.read_text(encoding="utf-8", errors="replace").splitlines())
+    except OSError:
+        return 0
+
+
+def build_file_stats(
+    findings: list[GoFinding], go_files: list[str], repo_path: Path
+) -> list[FileStats]:
+    """Aggregate findings per Go file, including clean files."""
+    by_file: dict[str, FileStats] = {}
+    for rel in go_files:
+        by_file[rel] = FileStats(path=rel, lines=_count_lines(repo_path / rel))
+
+    for finding in findings:
+        stats = by_file.setdefault(finding.file_path, FileStats(path=finding.file_path, lines=0))
+        stats.issue_count += 1
+        stats.by_category[finding.dd_category] = stats.by_category.get(finding.dd_category, 0) + 1
+        stats.by_severity[finding.severity] = stats.by_severity.get(finding.severity, 0) + 1
+        stats.issues.append(finding)
+    return list(by_file.values())
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Compute direct and recursive rollups for every ancestor directory."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    for stats in files:
+        parent = str(Path(stats.path).parent.as_posix())
+        if parent == ".":
+            parent = "."
+        _accumulate(direct[parent], stats)
+        ancestors = [parent]
+        while parent not in (".", ""):
+            parent = str(Path(parent).parent.as_posix())
+            ancestors.append(parent)
+        for ancestor in ancestors:
+            _accumulate(recursive[ancestor], stats)
+
+    entries = []
+    for path in sorted(recursive):
+        entries.append(
+            DirectoryEntry(
+                path=path,
+                direct=direct.get(path, DirectoryStats()),
+                recursive=recursive[path],
+            )
+        )
+    return entries
+
+
+def _accumulate(target: DirectoryStats, stats: FileStats) -> None:
+    target.file_count += 1
+    target.issue_count += stats.issue_count
+    for category, count in stats.by_category.items():
+        target.by_category[category] = target.by_category.get(category, 0) + count
+    for severity, count in stats.by_severity.items():
+        target.by_severity[severity] = target.by_severity.get(severity, 0) + count
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Run golangci-lint and build the full aggregated analysis result."""
+    start = time.perf_counter()
+    go_files = discover_go_files(repo_path)
+    findings = [map_issue(raw) for raw in run_golangci(repo_path)] if go_files else []
+    files = build_file_stats(findings, go_files, repo_path)
+    directories = build_directory_stats(files)
+
+    by_category: dict[str, int] = {}
+    by_severity: dict[str, int] = {}
+    for finding in findings:
+        by_category[finding.dd_category] = by_category.get(finding.dd_category, 0) + 1
+        by_severity[finding.severity] = by_severity.get(finding.severity, 0) + 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        golangci_version=get_golangci_version(),
+        findings=findings,
+        files=files,
+        directories=directories,
+        by_category=by_category,
+        by_severity=by_severity,
+        analysis_duration_ms=int((time.perf_counter() - start) * 1000),
+    )
@@ -0,0 +1,11 @@
+"""Pytest configuration for golangci-lint tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add golangci tool directory to path so 'scripts' can be imported as a package
+golangci_root = Path(__file__).parent.parent
+sys.path.insert(0, str(golangci_root))
+sys.path.insert(0, str(golangci_root / "scripts"))
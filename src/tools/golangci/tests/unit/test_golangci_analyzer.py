@@ -0,0 +1,114 @@
+"""Unit tests for golangci_analyzer discovery, mapping, and aggregation."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from golangci_analyzer import (
+    DEFAULT_CATEGORY,
+    LINTER_TO_CATEGORY_MAP,
+    build_directory_stats,
+    build_file_stats,
+    discover_go_files,
+    map_issue,
+)
+
+
+def _raw_issue(**overrides) -> dict:
+    raw = {
+        "FromLinter": "gosec",
+        "Text": "G201: SQL string formatting",
+        "Severity": "",
+        "Pos": {
+            "Filename": "sql_injection.go",
+            "Offset": 312,
+            "Line": 14,
+            "Column": 11,
+        },
+    }
+    raw.update(overrides)
+    return raw
+
+
+def test_map_issue_maps_core_fields() -> None:
+    finding = map_issue(_raw_issue())
+
+    assert finding.rule_id == "gosec"
+    assert finding.dd_category == "security"
+    assert finding.file_path == "sql_injection.go"
+    assert finding.line_start == 14
+    assert finding.line_end == 14
+    assert finding.column_start == 11
+    assert finding.severity == "HIGH"
+
+
+def test_map_issue_uses_line_range_when_present() -> None:
+    finding = map_issue(_raw_issue(FromLinter="dupl", LineRange={"From": 17, "To": 58}))
+
+    assert finding.line_start == 17
+    assert finding.line_end == 58
+    assert finding.dd_category == "duplication"
+
+
+def test_map_issue_unknown_linter_falls_back_to_misc() -> None:
+    finding = map_issue(_raw_issue(FromLinter="somefuturelinter"))
+    assert finding.dd_category == DEFAULT_CATEGORY
+    assert finding.severity == "LOW"
+
+
+def test_linter_severities() -> None:
+    assert map_issue(_raw_issue(FromLinter="gosec")).severity == "HIGH"
+    assert map_issue(_raw_issue(FromLinter="errcheck")).severity == "MEDIUM"
+    assert map_issue(_raw_issue(FromLinter="govet")).severity == "MEDIUM"
+    assert map_issue(_raw_issue(FromLinter="gofmt")).severity == "LOW"
+
+
+def test_category_map_covers_eval_scenarios() -> None:
+    """Linters exercised by the synthetic eval corpus must be mapped."""
+    assert LINTER_TO_CATEGORY_MAP["gocyclo"] == "complexity"
+    assert LINTER_TO_CATEGORY_MAP["dupl"] == "duplication"
+    assert LINTER_TO_CATEGORY_MAP["gosec"] == "security"
+    assert LINTER_TO_CATEGORY_MAP["errcheck"] == "error_handling"
+
+
+def test_discover_go_files_skips_vendor(tmp_path: Path) -> None:
+    (tmp_path / "pkg").mkdir()
+    (tmp_path / "vendor" / "dep").mkdir(parents=True)
+    (tmp_path / "main.go").write_text("package main\n")
+    (tmp_path / "pkg" / "util.go").write_text("package pkg\n")
+    (tmp_path / "vendor" / "dep" / "dep.go").write_text("package dep\n")
+    (tmp_path / "notes.txt").write_text("not go\n")
+
+    files = discover_go_files(tmp_path)
+
+    assert files == ["main.go", "pkg/util.go"]
+
+
+def test_build_file_stats_includes_clean_files(tmp_path: Path) -> None:
+    (tmp_path / "dirty.go").write_text("package p\n\nfunc f() {}\n")
+    (tmp_path / "clean.go").write_text("package p\n")
+
+    finding = map_issue(_raw_issue(Pos={"Filename": "dirty.go", "Line": 3, "Column": 1}))
+    files = build_file_stats([finding], ["dirty.go", "clean.go"], tmp_path)
+
+    by_path = {f.path: f for f in files}
+    assert by_path["dirty.go"].issue_count == 1
+    assert by_path["dirty.go"].by_category == {"security": 1}
+    assert by_path["clean.go"].issue_count == 0
+
+
+def test_build_directory_stats_recursive_gte_direct(tmp_path: Path) -> None:
+    (tmp_path / "pkg" / "db").mkdir(parents=True)
+    (tmp_path / "pkg" / "a.go").write_text("package pkg\n")
+    (tmp_path / "pkg" / "db" / "b.go").write_text("package db\n")
+
+    finding = map_issue(_raw_issue(Pos={"Filename": "pkg/db/b.go", "Line": 1, "Column": 1}))
+    files = build_file_stats([finding], ["pkg/a.go", "pkg/db/b.go"], tmp_path)
+    directories = build_directory_stats(files)
+
+    by_path = {d.path: d for d in directories}
+    pkg = by_path["pkg"]
+    assert pkg.recursive.issue_count >= pkg.direct.issue_count
+    assert pkg.recursive.issue_count == 1
+    assert pkg.direct.issue_count == 0
+    assert by_path["pkg/db"].direct.issue_count == 1
@@ -0,0 +1,27 @@
+// Cross-file clone: this retry helper is duplicated from cross_file_a.ts.
+
+export async function fetchWithRetry(
+  url: string,
+  maxAttempts: number,
+  backoffMs: number,
+): Promise<Response> {
+  let lastError: unknown;
+  for (let attempt = 1; attempt <= maxAttempts; attempt += 1) {
+    try {
+      const response = await fetch(url);
+      if (response.ok) {
+        return response;
+      }
+      lastError = new Error(`status ${response.status} from ${url}`);
+    } catch (error) {
+      lastError = error;
+    }
+    const delay = backoffMs * Math.pow(2, attempt - 1);
+    await new Promise((resolve) => setTimeout(resolve, delay));
+  }
+  throw lastError;
+}
+
+export function buildOrderUrl(baseUrl: string, orderId: string): string {
+  return `${baseUrl.replace(/\/$/, "")}/orders/${encodeURIComponent(orderId)}`;
+}
@@ -0,0 +1,74 @@
+// Heavy in-file duplication: the same validation block repeated verbatim.
+
+export interface Order {
+  id: string;
+  customerId: string;
+  items: { sku: string; quantity: number; unitPrice: number }[];
+  discountCode?: string;
+}
+
+export function validateOrderA(order: Order): string[] {
+  const errors: string[] = [];
+  if (!order.id || order.id.trim().length === 0) {
+    errors.push("order id is required");
+  }
+  if (!order.customerId || order.customerId.trim().length === 0) {
+    errors.push("customer id is required");
+  }
+  if (!order.items || order.items.length === 0) {
+    errors.push("order must contain at least one item");
+  }
+  for (const item of order.items ?? []) {
+    if (item.quantity <= 0) {
+      errors.push(`invalid quantity for ${item.sku}`);
+    }
+    if (item.unitPrice < 0) {
+      errors.push(`invalid unit price for ${item.sku}`);
+    }
+  }
+  return errors;
+}
+
+export function validateOrderB(order: Order): string[] {
+  const errors: string[] = [];
+  if (!order.id || order.id.trim().length === 0) {
+    errors.push("order id is required");
+  }
+  if (!order.customerId || order.customerId.trim().length === 0) {
+    errors.push("customer id is required");
+  }
+  if (!order.items || order.items.length === 0) {
+    errors.push("order must contain at least one item");
+  }
+  for (const item of order.items ?? []) {
+    if (item.quantity <= 0) {
+      errors.push(`invalid quantity for ${item.sku}`);
+    }
+    if (item.unitPrice < 0) {
+      errors.push(`invalid unit price for ${item.sku}`);
+    }
+  }
+  return errors;
+}
+
+export function validateOrderC(order: Order): string[] {
+  const errors: string[] = [];
+  if (!order.id || order.id.trim().length === 0) {
+    errors.push("order id is required");
+  }
+  if (!order.customerId || order.customerId.trim().length === 0) {
+    errors.push("customer id is required");
+  }
+  if (!order.items || order.items.length === 0) {
+    errors.push("order must contain at least one item");
+  }
+  for (const item of order.items ?? []) {
+    if (item.quantity <= 0) {
+      errors.push(`invalid quantity for ${item.sku}`);
+    }
+    if (item.unitPrice < 0) {
+      errors.push(`invalid unit price for ${item.sku}`);
+    }
+  }
+  return errors;
+}
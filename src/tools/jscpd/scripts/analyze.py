@@ -0,0 +1,123 @@
+#!/usr/bin/env python3
+"""CLI entry point for jscpd duplication analysis.
+
+Standard wrapper that translates orchestrator CLI args to jscpd_analyzer
+and produces Caldera envelope output format.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+# Add shared src to path for imports
+sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
+from common.cli_parser import add_common_args, validate_common_args
+from common.envelope_formatter import create_envelope, get_current_timestamp
+from common.path_normalization import normalize_file_path
+
+from .jscpd_analyzer import DEFAULT_MIN_TOKENS, AnalysisResult, analyze_repository
+
+TOOL_NAME = "jscpd"
+SCHEMA_VERSION = "1.0.0"
+
+
+def result_to_data_dict(result: AnalysisResult, repo_root: Path | None = None) -> dict[str, Any]:
+    """Convert AnalysisResult to the 'data' portion of envelope format."""
+    files = []
+    for f in result.files:
+        files.append({
+            "path": normalize_file_path(f.path, repo_root),
+            "language": f.language,
+            "total_lines": f.total_lines,
+            "duplicate_lines": f.duplicate_lines,
+            "duplicate_blocks": f.duplicate_blocks,
+            "duplication_percentage": round(f.duplication_percentage, 2),
+        })
+
+    duplications = []
+    for clone in result.clones:
+        duplications.append({
+            "clone_id": clone.clone_id,
+            "lines": clone.lines,
+            "tokens": clone.tokens,
+            "occurrences": [
+                {
+                    "file": normalize_file_path(occ.file_path, repo_root),
+                    "line_start": occ.line_start,
+                    "line_end": occ.line_end,
+                    "column_start": occ.column_start,
+                    "column_end": occ.column_end,
+                }
+                for occ in clone.occurrences
+            ],
+            "code_fragment": clone.code_fragment,
+        })
+
+    total_lines = sum(f.total_lines for f in result.files)
+    duplicate_lines = sum(f.duplicate_lines for f in result.files)
+    cross_file = sum(
+        1
+        for clone in result.clones
+        if len({occ.file_path for occ in clone.occurrences}) > 1
+    )
+
+    return {
+        "tool": TOOL_NAME,
+        "config": {"min_tokens": result.min_tokens},
+        "summary": {
+            "total_files": len(result.files),
+            "total_clones": len(result.clones),
+            "duplication_percentage": round(
+                duplicate_lines * 100.0 / total_lines, 2
+            ) if total_lines else 0.0,
+            "cross_file_clones": cross_file,
+        },
+        "files": files,
+        "duplications": duplications,
+        "analysis_duration_ms": result.analysis_duration_ms,
+    }
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Analyze JS/TS duplication using jscpd")
+    add_common_args(parser)
+    parser.add_argument(
+        "--min-tokens",
+        type=int,
+        default=DEFAULT_MIN_TOKENS,
+        help=f"Minimum token threshold for clones (default: {DEFAULT_MIN_TOKENS})",
+    )
+    args = parser.parse_args()
+
+    common = validate_common_args(args)
+
+    print(f"Analyzing: {common.repo_path}")
+    result = analyze_repository(common.repo_path, common.repo_name, args.min_tokens)
+
+    print(f"Clones found: {len(result.clones)}")
+    print(f"Files with duplication: {len(result.files)}")
+    print(f"Duration: {result.analysis_duration_ms}ms")
+
+    data = result_to_data_dict(result, repo_root=common.repo_path)
+    output_dict = create_envelope(
+        data,
+        tool_name=TOOL_NAME,
+        tool_version=result.jscpd_version,
+        run_id=common.run_id,
+        repo_id=common.repo_id,
+        branch=common.branch,
+        commit=common.commit,
+        timestamp=get_current_timestamp(),
+        schema_version=SCHEMA_VERSION,
+    )
+
+    common.output_path.write_text(json.dumps(output_dict, indent=2, ensure_ascii=False))
+    print(f"Output: {common.output_path}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,225 @@
+"""jscpd duplication analysis wrapper.
+
+Runs jscpd against a JS/TS repository and maps its report into the same
+clone model pmd-cpd uses (clone groups with occurrences plus per-file
+duplication metrics), so frontend duplication detection does not require
+the JVM-based CPD.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import tempfile
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# File extensions handed to jscpd; keeps the scan focused on frontend code.
+JSCPD_FORMATS = "javascript,typescript,jsx,tsx"
+
+# jscpd format identifier -> canonical language name.
+FORMAT_TO_LANGUAGE = {
+    "javascript": "JavaScript",
+    "typescript": "TypeScript",
+    "jsx": "JSX",
+    "tsx": "TSX",
+}
+
+DEFAULT_MIN_TOKENS = 50
+
+# Truncation limit for code fragments (matches pmd-cpd's schema cap).
+FRAGMENT_MAX_CHARS = 500
+
+
+@dataclass(frozen=True)
+class CloneOccurrence:
+    """One location of a duplicated block."""
+    file_path: str
+    line_start: int
+    line_end: int
+    column_start: int | None
+    column_end: int | None
+
+
+@dataclass(frozen=True)
+class CloneGroup:
+    """A duplicated block and everywhere it appears."""
+    clone_id: str
+    language: str | None
+    lines: int
+    tokens: int
+    occurrences: tuple[CloneOccurrence, ...]
+    code_fragment: str | None
+
+
+@dataclass
+class FileDuplication:
+    """Per-file duplication metrics derived from clone occurrences."""
+    path: str
+    language: str | None
+    total_lines: int
+    duplicate_lines: int = 0
+    duplicate_blocks: int = 0
+
+    @property
+    def duplication_percentage(self) -> float:
+        if self.total_lines <= 0:
+            return 0.0
+        return min(100.0, self.duplicate_lines * 100.0 / self.total_lines)
+
+
+@dataclass
+class AnalysisResult:
+    """Complete jscpd analysis result."""
+    repo_name: str
+    jscpd_version: str
+    min_tokens: int
+    clones: list[CloneGroup] = field(default_factory=list)
+    files: list[FileDuplication] = field(default_factory=list)
+    analysis_duration_ms: int = 0
+
+
+def get_jscpd_version() -> str:
+    """Return the installed jscpd version, or 'unknown'."""
+    try:
+        result = subprocess.run(
+            ["npx", "--no-install", "jscpd", "--version"],
+            capture_output=True,
+            text=True,
+            timeout=60,
+        )
+        version = result.stdout.strip()
+        return version or "unknown"
+    except (OSError, subprocess.TimeoutExpired):
+        return "unknown"
+
+
+def run_jscpd(repo_path: Path, min_tokens: int = DEFAULT_MIN_TOKENS) -> dict:
+    """Run jscpd and return its parsed JSON report.
+
+    Raises RuntimeError when jscpd fails to produce a report.
+    """
+    with tempfile.TemporaryDirectory(prefix="jscpd-") as output_dir:
+        result = subprocess.run(
+            [
+                "npx", "--no-install", "jscpd",
+                "--min-tokens", str(min_tokens),
+                "--format", JSCPD_FORMATS,
+                "--reporters", "json",
+                "--output", output_dir,
+                "--silent",
+                str(repo_path),
+            ],
+            capture_output=True,
+            text=True,
+        )
+        report_path = Path(output_dir) / "jscpd-report.json"
+        if not report_path.exists():
+            raise RuntimeError(
+                f"jscpd produced no report (exit {result.returncode}): {result.stderr.strip()}"
+            )
+        return json.loads(report_path.read_text())
+
+
+def _relativize(raw_path: str, repo_path: Path) -> str:
+    path = Path(raw_path)
+    try:
+        return path.resolve().relative_to(repo_path.resolve()).as_posix()
+    except ValueError:
+        return raw_path.replace("\\", "/").lstrip("./")
+
+
+def _map_occurrence(side: dict, repo_path: Path) -> CloneOccurrence:
+    start_loc = side.get("startLoc") or {}
+    end_loc = side.get("endLoc") or {}
+    return CloneOccurrence(
+        file_path=_relativize(side.get("name", ""), repo_path),
+        line_start=int(side.get("start", start_loc.get("line", 1))),
+        line_end=int(side.get("end", end_loc.get("line", 1))),
+        column_start=start_loc.get("column"),
+        column_end=end_loc.get("column"),
+    )
+
+
+def map_duplicates(duplicates: list[dict], repo_path: Path) -> list[CloneGroup]:
+    """Map jscpd duplicate entries to clone groups.
+
+    jscpd reports each duplication as a first/second file pair; each pair
+    becomes one clone group with two occurrences.
+    """
+    clones: list[CloneGroup] = []
+    for idx, duplicate in enumerate(duplicates, 1):
+        fragment = duplicate.get("fragment")
+        if fragment:
+            fragment = fragment[:FRAGMENT_MAX_CHARS]
+        clones.append(
+            CloneGroup(
+                clone_id=f"JSCPD-{idx:04d}",
+                language=FORMAT_TO_LANGUAGE.get(duplicate.get("format", "")),
+                lines=int(duplicate.get("lines", 0)),
+                tokens=int(duplicate.get("tokens", 0)),
+                occurrences=(
+                    _map_occurrence(duplicate.get("firstFile") or {}, repo_path),
+                    _map_occurrence(duplicate.get("secondFile") or {}, repo_path),
+                ),
+                code_fragment=fragment,
+            )
+        )
+    return clones
+
+
+def _count_lines(path: Path) -> int:
+    try:
+        return len(path.read_text(encoding="utf-8", errors="replace").splitlines())
+    except OSError:
+        return 0
+
+
+def build_file_metrics(
+    clones: list[CloneGroup], repo_path: Path
+) -> list[FileDuplication]:
+    """Derive per-file duplicate line counts by unioning occurrence ranges."""
+    lines_by_file: dict[str, set[int]] = defaultdict(set)
+    blocks_by_file: dict[str, int] = defaultdict(int)
+    language_by_file: dict[str, str | None] = {}
+
+    for clone in clones:
+        for occurrence in clone.occurrences:
+            lines_by_file[occurrence.file_path].update(
+                range(occurrence.line_start, occurrence.line_end + 1)
+            )
+            blocks_by_file[occurrence.file_path] += 1
+            language_by_file.setdefault(occurrence.file_path, clone.language)
+
+    metrics: list[FileDuplication] = []
+    for file_path in sorted(lines_by_file):
+        metrics.append(
+            FileDuplication(
+                path=file_path,
+                language=language_by_file.get(file_path),
+                total_lines=_count_lines(repo_path / file_path),
+                duplicate_lines=len(lines_by_file[file_path]),
+                duplicate_blocks=blocks_by_file[file_path],
+            )
+        )
+    return metrics
+
+
+def analyze_repository(
+    repo_path: Path, repo_name: str, min_tokens: int = DEFAULT_MIN_TOKENS
+) -> AnalysisResult:
+    """Run jscpd and build the complete analysis result."""
+    started = time.perf_counter()
+    report = run_jscpd(repo_path, min_tokens)
+    clones = map_duplicates(report.get("duplicates", []), repo_path)
+    files = build_file_metrics(clones, repo_path)
+    return AnalysisResult(
+        repo_name=repo_name,
+        jscpd_version=get_jscpd_version(),
+        min_tokens=min_tokens,
+        clones=clones,
+        files=files,
+        analysis_duration_ms=int((time.perf_counter() - started) * 1000),
+    )
@@ -0,0 +1,11 @@
+"""Pytest configuration for jscpd tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add jscpd tool directory to path so 'scripts' can be imported as a package
+jscpd_root = Path(__file__).parent.parent
+sys.path.insert(0, str(jscpd_root))
+sys.path.insert(0, str(jscpd_root / "scripts"))
@@ -0,0 +1,116 @@
+"""Unit tests for the jscpd analyzer mapping logic."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from scripts.jscpd_analyzer import (
+    CloneGroup,
+    CloneOccurrence,
+    build_file_metrics,
+    map_duplicates,
+)
+
+
+def _duplicate(first: str, second: str, lines: int = 10, tokens: int = 60) -> dict:
+    return {
+        "format": "typescript",
+        "lines": lines,
+        "tokens": tokens,
+        "firstFile": {
+            "name": first,
+            "start": 5,
+            "end": 5 + lines - 1,
+            "startLoc": {"line": 5, "column": 1},
+            "endLoc": {"line": 5 + lines - 1, "column": 2},
+        },
+        "secondFile": {
+            "name": second,
+            "start": 30,
+            "end": 30 + lines - 1,
+            "startLoc": {"line": 30, "column": 1},
+            "endLoc": {"line": 30 + lines - 1, "column": 2},
+        },
+        "fragment": "const x = 1;\n" * 50,
+    }
+
+
+def test_map_duplicates_builds_clone_groups(tmp_path: Path) -> None:
+    clones = map_duplicates(
+        [_duplicate(str(tmp_path / "a.ts"), str(tmp_path / "b.ts"))], tmp_path
+    )
+
+    assert len(clones) == 1
+    clone = clones[0]
+    assert clone.clone_id == "JSCPD-0001"
+    assert clone.language == "TypeScript"
+    assert clone.lines == 10
+    assert clone.tokens == 60
+    assert len(clone.occurrences) == 2
+    assert clone.occurrences[0].file_path == "a.ts"
+    assert clone.occurrences[1].file_path == "b.ts"
+    assert clone.occurrences[0].line_start == 5
+    assert clone.occurrences[1].line_end == 39
+
+
+def test_map_duplicates_truncates_fragment(tmp_path: Path) -> None:
+    clones = map_duplicates(
+        [_duplicate(str(tmp_path / "a.ts"), str(tmp_path / "a.ts"))], tmp_path
+    )
+    assert len(clones[0].code_fragment) == 500
+
+
+def test_clone_ids_are_sequential(tmp_path: Path) -> None:
+    duplicates = [
+        _duplicate(str(tmp_path / "a.ts"), str(tmp_path / "b.ts")),
+        _duplicate(str(tmp_path / "b.ts"), str(tmp_path / "c.ts")),
+    ]
+    clones = map_duplicates(duplicates, tmp_path)
+    assert [clone.clone_id for clone in clones] == ["JSCPD-0001", "JSCPD-0002"]
+
+
+def test_build_file_metrics_unions_overlapping_ranges(tmp_path: Path) -> None:
+    (tmp_path / "a.ts").write_text("\n".join(f"line {i}" for i in range(1, 101)))
+    clones = [
+        CloneGroup(
+            clone_id="JSCPD-0001",
+            language="TypeScript",
+            lines=10,
+            tokens=60,
+            occurrences=(
+                CloneOccurrence("a.ts", 1, 10, None, None),
+                CloneOccurrence("a.ts", 5, 14, None, None),
+            ),
+            code_fragment=None,
+        )
+    ]
+
+    metrics = build_file_metrics(clones, tmp_path)
+
+    assert len(metrics) == 1
+    metric = metrics[0]
+    assert metric.path == "a.ts"
+    assert metric.total_lines == 100
+    # Lines 1-14 union, not 20
+    assert metric.duplicate_lines == 14
+    assert metric.duplicate_blocks == 2
+    assert metric.duplication_percentage == 14.0
+
+
+def test_file_metrics_handle_missing_files(tmp_path: Path) -> None:
+    clones = [
+        CloneGroup(
+            clone_id="JSCPD-0001",
+            language=None,
+            lines=3,
+            tokens=50,
+            occurrences=(
+                CloneOccurrence("gone.ts", 1, 3, None, None),
+                CloneOccurrence("gone.ts", 10, 12, None, None),
+            ),
+            code_fragment=None,
+        )
+    ]
+    metrics = build_file_metrics(clones, tmp_path)
+    assert metrics[0].total_lines == 0
+    assert metrics[0].duplication_percentage == 0.0
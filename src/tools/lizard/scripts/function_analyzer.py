@@ -120,6 +120,7 @@ def is_likely_minified(filepath: Path, sample_size: int = 8192) -> bool:
 sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
 from common.git_utilities import resolve_commit
 from common.envelope_formatter import create_envelope
+from common.file_prefilter import CATEGORY_BINARY, CATEGORY_SINGLE_LINE, classify_file
 
 
 # =============================================================================
@@ -360,6 +361,7 @@ class FunctionInfo:
     token_count: int
     parameter_count: int
     length: int
+    max_nesting_depth: int | None = None
 
 
 @dataclass
@@ -466,6 +468,8 @@ class AnalysisSummary:
     excluded_by_minified: int = 0
     excluded_by_size: int = 0
     excluded_by_language: int = 0
+    excluded_by_binary: int = 0
+    excluded_by_single_line: int = 0
     structure: DirectoryStructure | None = None
     ccn_distribution: Distribution | None = None
     nloc_distribution: Distribution | None = None
@@ -1026,6 +1030,17 @@ def analyze_directory(
                     ))
                     continue
 
+            # Binary and machine-generated single-line files (shared prefilter)
+            decision = classify_file(filepath, rel_path)
+            if decision.category in (CATEGORY_BINARY, CATEGORY_SINGLE_LINE):
+                excluded_files.append(ExcludedFile(
+                    path=rel_path,
+                    reason='binary' if decision.category == CATEGORY_BINARY else 'single-line',
+                    language=lang,
+                    details=decision.reason
+                ))
+                continue
+
             # Skip very large files
             try:
                 file_size = filepath.stat().st_size
@@ -1053,6 +1068,8 @@ def analyze_directory(
     excluded_by_minified = sum(1 for e in excluded_files if e.reason == 'minified')
     excluded_by_size = sum(1 for e in excluded_files if e.reason == 'large')
     excluded_by_language = sum(1 for e in excluded_files if e.reason == 'language')
+    excluded_by_binary = sum(1 for e in excluded_files if e.reason == 'binary')
+    excluded_by_single_line = sum(1 for e in excluded_files if e.reason == 'single-line')
 
     # Report discovery results
     if show_progress:
@@ -1065,6 +1082,10 @@ def analyze_directory(
             skip_info.append(f"{excluded_by_size} large")
         if excluded_by_language > 0:
             skip_info.append(f"{excluded_by_language} filtered")
+        if excluded_by_binary > 0:
+            skip_info.append(f"{excluded_by_binary} binary")
+        if excluded_by_single_line > 0:
+            skip_info.append(f"{excluded_by_single_line} single-line")
         skip_str = f" (skipped: {', '.join(skip_info)})" if skip_info else ""
         print(f"    Found {len(source_files):,} source files{skip_str}")
 
@@ -1076,8 +1097,12 @@ def analyze_directory(
     if show_progress:
         print(f"    {len(source_files):,} files with {threads} threads...")
 
-    # Analyze with Lizard
-    analysis = lizard.analyze(source_files, threads=threads)
+    # Analyze with Lizard; the NS extension adds max nesting depth per function
+    try:
+        extensions = lizard.get_extensions(["NS"])
+    except Exception:
+        extensions = None
+    analysis = lizard.analyze(source_files, threads=threads, exts=extensions)
     total_files = len(source_files)
 
     all_functions = []
@@ -1105,6 +1130,7 @@ def analyze_directory(
                 token_count=func.token_count,
                 parameter_count=len(func.parameters) if hasattr(func, 'parameters') else func.parameter_count,
                 length=func.length,
+                max_nesting_depth=getattr(func, 'max_nesting_depth', None),
             )
             functions.append(func_info)
             all_functions.append(func_info)
@@ -1176,6 +1202,8 @@ def analyze_directory(
         excluded_by_minified=excluded_by_minified,
         excluded_by_size=excluded_by_size,
         excluded_by_language=excluded_by_language,
+        excluded_by_binary=excluded_by_binary,
+        excluded_by_single_line=excluded_by_single_line,
         structure=dir_structure,
         ccn_distribution=compute_distribution(ccn_values) if ccn_values else Distribution(),
         nloc_distribution=compute_distribution(nloc_values) if nloc_values else Distribution(),
@@ -13,21 +13,25 @@ Usage:
 from __future__ import annotations
 
 import argparse
+import io
 import json
 import os
 import shutil
 import subprocess
 import sys
+import tempfile
 import time
 from dataclasses import dataclass, field
 from datetime import datetime, timezone
 from pathlib import Path
 from typing import Any
-from xml.etree import ElementTree as ET
 
 # Add src directory to path for common imports
 sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
 
+from common.file_prefilter import partition_files, skip_summary
+from common.streaming import ParseDiagnostics, recover_xml_elements
+from common.tool_env import parse_int, tool_environment
 from shared.path_utils import normalize_file_path
 
 
@@ -145,6 +149,7 @@ class AnalysisResult:
     duplications: list[Duplication]
     statistics: dict[str, Any]
     errors: list[str] = field(default_factory=list)
+    parse_diagnostics: dict[str, Any] = field(default_factory=dict)
 
     def to_caldera_envelope(self) -> dict[str, Any]:
         """Convert to Caldera envelope format."""
@@ -172,6 +177,7 @@ class AnalysisResult:
                 "duplications": [d.to_dict() for d in self.duplications],
                 "statistics": self.statistics,
                 "errors": self.errors,
+                "parse_diagnostics": self.parse_diagnostics,
             },
         }
 
@@ -197,14 +203,28 @@ def generate_clone_id(index: int) -> str:
 
 
 def run_cpd(
-    repo_path: Path,
+    files: list[Path],
     pmd_home: Path,
     language: str,
     min_tokens: int = 50,
     ignore_identifiers: bool = False,
     ignore_literals: bool = False,
-) -> tuple[str, str]:
-    """Run PMD CPD for a specific language and return XML output."""
+) -> tuple[Path | None, str]:
+    """Run PMD CPD over an explicit file list; returns (XML report path, stderr).
+
+    Taking a file list instead of ``--dir`` lets the prefilter keep
+    binary/minified files out of CPD entirely, not just out of our
+    metrics. CPD's stdout goes straight to a temp file so a large
+    report never lands in a Python string; the caller streams it with
+    ``parse_cpd_xml`` and unlinks it. Returns ``None`` when CPD
+    produced no report.
+    """
+    with tempfile.NamedTemporaryFile(
+        "w", suffix=".txt", prefix="cpd-files-", delete=False
+    ) as handle:
+        handle.write("\n".join(str(f) for f in files))
+        file_list_path = handle.name
+
     cpd_cmd = [
         str(pmd_home / "bin" / "pmd"),
         "cpd",
@@ -214,8 +234,8 @@ def run_cpd(
         str(min_tokens),
         "--language",
         language,
-        "--dir",
-        str(repo_path),
+        "--file-list",
+        file_list_path,
     ]
 
     if ignore_identifiers:
@@ -223,56 +243,86 @@ def run_cpd(
     if ignore_literals:
         cpd_cmd.append("--ignore-literals")
 
+    report_fd, report_name = tempfile.mkstemp(suffix=".xml", prefix="cpd-report-")
+    report_path = Path(report_name)
+
     try:
-        env = os.environ.copy()
+        # Pin locale and timezone so CPD's number formatting does not
+        # depend on the agent that ran the scan.
+        env = tool_environment()
         java_path = find_java()
         if java_path:
             env["PATH"] = f"{Path(java_path).parent}:{env.get('PATH', '')}"
 
-        result = subprocess.run(
-            cpd_cmd,
-            capture_output=True,
-            text=True,
-            timeout=300,
-            env=env,
-        )
-        if result.stdout.strip():
-            return result.stdout, ""
-        if result.returncode != 0:
-            return "", result.stderr or result.stdout
-        return result.stdout, result.stderr
+        with os.fdopen(report_fd, "w") as report_handle:
+            result = subprocess.run(
+                cpd_cmd,
+                stdout=report_handle,
+                stderr=subprocess.PIPE,
+                text=True,
+                timeout=300,
+                env=env,
+            )
+        if report_path.stat().st_size > 0:
+            return report_path, ""
+        report_path.unlink(missing_ok=True)
+        return None, result.stderr
     except subprocess.TimeoutExpired:
-        return "", f"CPD timed out for language {language}"
+        report_path.unlink(missing_ok=True)
+        return None, f"CPD timed out for language {language}"
     except Exception as e:
-        return "", str(e)
+        report_path.unlink(missing_ok=True)
+        return None, str(e)
+    finally:
+        try:
+            os.unlink(file_list_path)
+        except OSError:
+            pass
 
 
 def parse_cpd_xml(
-    xml_content: str, repo_root: Path, start_id: int = 0
+    xml_source: str | Path,
+    repo_root: Path,
+    start_id: int = 0,
+    diagnostics: ParseDiagnostics | None = None,
 ) -> list[Duplication]:
-    """Parse CPD XML output into Duplication objects."""
-    duplications = []
-
-    if not xml_content.strip():
-        return duplications
-
-    try:
-        root = ET.fromstring(xml_content)
-    except ET.ParseError:
-        return duplications
-
-    for idx, dup_elem in enumerate(root.findall(".//duplication")):
-        lines = int(dup_elem.get("lines", 0))
-        tokens = int(dup_elem.get("tokens", 0))
+    """Parse CPD XML output into Duplication objects.
+
+    ``xml_source`` is either a path to a report file or raw XML text.
+    Either way the document is pull-parsed one ``<duplication>`` at a
+    time, so a monorepo-sized report never sits in memory as a tree.
+    Recovery parsing salvages complete blocks from damaged reports
+    (interleaved stderr, truncation); drops land in ``diagnostics``.
+    """
+    duplications: list[Duplication] = []
+    if diagnostics is None:
+        diagnostics = ParseDiagnostics()
+
+    if isinstance(xml_source, Path):
+        if not xml_source.exists() or xml_source.stat().st_size == 0:
+            return duplications
+        source: Path | io.StringIO = xml_source
+    else:
+        if not xml_source.strip():
+            return duplications
+        source = io.StringIO(xml_source)
+
+    for idx, dup_elem in enumerate(
+        recover_xml_elements(source, "duplication", diagnostics)
+    ):
+        # parse_int tolerates locale-formatted numbers from runs on
+        # agents that predate the pinned tool environment.
+        lines = parse_int(dup_elem.get("lines", 0))
+        tokens = parse_int(dup_elem.get("tokens", 0))
 
         occurrences = []
         for file_elem in dup_elem.findall("file"):
             raw_path = file_elem.get("path", "")
             normalized_path = normalize_file_path(raw_path, repo_root)
-            line = int(file_elem.get("line", 0))
-            column = int(file_elem.get("column", 0))
-            end_line = int(file_elem.get("endline", line + lines - 1))
-            end_column = int(file_elem.get("endcolumn", 0))
+            line = parse_int(file_elem.get("line", 0))
+            column = parse_int(file_elem.get("column", 0))
+            end_line = parse_int(file_elem.get("endline", line + lines - 1))
+            end_column = parse_int(file_elem.get("endcolumn", 0))
 
             occurrences.append(
                 DuplicationOccurrence(
@@ -470,12 +520,23 @@ def analyze(
             errors=["No source files found"],
         )
 
+    # Prefilter binary/minified/single-line files before CPD sees them
+    prefilter_skipped = []
+    for lang in list(files_by_lang):
+        kept, skipped = partition_files(files_by_lang[lang], repo_path_obj)
+        prefilter_skipped.extend(skipped)
+        if kept:
+            files_by_lang[lang] = kept
+        else:
+            del files_by_lang[lang]
+
     # Run CPD for each language
     all_duplications: list[Duplication] = []
+    parse_diagnostics = ParseDiagnostics()
 
     for lang in files_by_lang:
-        xml_output, stderr = run_cpd(
-            repo_path_obj,
+        report_path, stderr = run_cpd(
+            files_by_lang[lang],
             pmd_home_obj,
             lang,
             min_tokens,
@@ -486,8 +547,17 @@ def analyze(
         if stderr:
             errors.append(f"CPD error for {lang}: {stderr.strip()[:200]}")
 
-        dups = parse_cpd_xml(xml_output, repo_path_obj, len(all_duplications))
-        all_duplications.extend(dups)
+        if report_path is not None:
+            try:
+                dups = parse_cpd_xml(
+                    report_path,
+                    repo_path_obj,
+                    len(all_duplications),
+                    diagnostics=parse_diagnostics,
+                )
+                all_duplications.extend(dups)
+            finally:
+                report_path.unlink(missing_ok=True)
 
     # Calculate metrics
     file_metrics = calculate_file_metrics(repo_path_obj, all_duplications, files_by_lang)
@@ -516,11 +586,13 @@ def analyze(
             "total_clones": len(all_duplications),
             "duplication_percentage": round(overall_dup_pct, 2),
             "cross_file_clones": statistics.get("cross_file_clones", 0),
+            "prefilter": skip_summary(prefilter_skipped),
         },
         files=file_metrics,
         duplications=all_duplications,
         statistics=statistics,
         errors=errors,
+        parse_diagnostics=parse_diagnostics.to_dict(),
     )
 
 
@@ -54,6 +54,47 @@ class TestParseCpdXml:
     def test_parse_invalid_xml(self, tmp_path: Path) -> None:
         assert parse_cpd_xml("<bad xml>", tmp_path) == []
 
+    def test_parse_from_report_path_streams(self, tmp_path: Path) -> None:
+        xml = """<?xml version="1.0" encoding="UTF-8"?>
+<pmd-cpd>
+  <duplication lines="6" tokens="33">
+    <file path="{repo}/a.py" line="1" column="0" endline="6" endcolumn="0"/>
+    <file path="{repo}/b.py" line="9" column="0" endline="14" endcolumn="0"/>
+    <codefragment>return value</codefragment>
+  </duplication>
+</pmd-cpd>""".format(repo=tmp_path)
+        report = tmp_path / "report.xml"
+        report.write_text(xml)
+        dups = parse_cpd_xml(report, tmp_path)
+        assert len(dups) == 1
+        assert dups[0].code_fragment == "return value"
+        assert [o.file for o in dups[0].occurrences] == ["a.py", "b.py"]
+
+    def test_parse_salvages_records_from_damaged_report(self, tmp_path: Path) -> None:
+        from common.streaming import ParseDiagnostics
+
+        xml = """<?xml version="1.0" encoding="UTF-8"?>
+<pmd-cpd>
+  <duplication lines="5" tokens="30">
+    <file path="{repo}/a.py" line="1" column="0" endline="5" endcolumn="0"/>
+    <file path="{repo}/b.py" line="1" column="0" endline="5" endcolumn="0"/>
+  </duplication>
+WARNING: CPD worker thread restarted
+  <duplication lines="7" tokens="40">
+    <file path="{repo}/c.py" line="1" column="0" endline="7" endc""".format(repo=tmp_path)
+        diagnostics = ParseDiagnostics()
+        dups = parse_cpd_xml(xml, tmp_path, diagnostics=diagnostics)
+        assert len(dups) == 1
+        assert dups[0].lines == 5
+        assert diagnostics.records_recovered == 1
+        assert diagnostics.truncated
+
+    def test_parse_missing_or_empty_report_path(self, tmp_path: Path) -> None:
+        assert parse_cpd_xml(tmp_path / "absent.xml", tmp_path) == []
+        empty = tmp_path / "empty.xml"
+        empty.write_text("")
+        assert parse_cpd_xml(empty, tmp_path) == []
+
     def test_parse_start_id_offset(self, tmp_path: Path) -> None:
         xml = """<?xml version="1.0" encoding="UTF-8"?>
 <pmd-cpd>
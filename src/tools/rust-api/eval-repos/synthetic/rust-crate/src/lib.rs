@@ -0,0 +1,30 @@
+/// Part of the public surface.
+pub fn connect(addr: &str) -> Result<Client, Error> {
+    let _ = addr;
+    todo!()
+}
+
+/// Multi-line signature; the analyzer must join it into one.
+pub fn configure(
+    retries: u32,
+    timeout_ms: u64,
+) -> Client {
+    let _ = (retries, timeout_ms);
+    todo!()
+}
+
+pub struct Client;
+
+pub struct Error;
+
+pub trait Transport {
+    fn send(&self, payload: &[u8]);
+}
+
+pub const MAX_RETRIES: u32 = 3;
+
+/// Restricted visibility — must NOT appear in the surface.
+pub(crate) fn internal_reset() {}
+
+/// Private — must NOT appear in the surface.
+fn helper() {}
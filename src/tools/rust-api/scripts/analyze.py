@@ -0,0 +1,174 @@
+#!/usr/bin/env python3
+"""CLI entry point for Rust API surface extraction.
+
+Standard wrapper that translates orchestrator CLI args to
+rust_api_analyzer and produces Caldera envelope output format. With
+``--baseline`` pointing at a previous run's output.json, the data section
+also carries the surface diff (additions, removals, signature changes).
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+# Add shared src to path for imports
+sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
+from common.cli_parser import add_common_args, validate_common_args
+from common.envelope_formatter import create_envelope, get_current_timestamp
+from common.path_normalization import normalize_file_path
+
+from .rust_api_analyzer import (
+    AnalysisResult,
+    ApiItem,
+    analyze_repository,
+    diff_surfaces,
+)
+
+TOOL_NAME = "rust-api"
+TOOL_VERSION = "1.0.0"
+SCHEMA_VERSION = "1.0.0"
+
+
+def _item_dict(item: ApiItem) -> dict[str, Any]:
+    return {
+        "name": item.name,
+        "kind": item.kind,
+        "signature": item.signature,
+        "line": item.line,
+        "crate": item.crate,
+    }
+
+
+def load_baseline_items(baseline_path: Path) -> list[ApiItem]:
+    """Re-hydrate the API surface from a previous run's output.json."""
+    envelope = json.loads(baseline_path.read_text())
+    items = []
+    for file_entry in envelope.get("data", {}).get("files", []):
+        for item in file_entry.get("items", []):
+            items.append(ApiItem(
+                name=item["name"],
+                kind=item["kind"],
+                signature=item["signature"],
+                file_path=file_entry["path"],
+                line=item.get("line", 1),
+                crate=item.get("crate", "unknown"),
+            ))
+    return items
+
+
+def result_to_data_dict(
+    result: AnalysisResult,
+    repo_root: Path | None = None,
+    baseline_items: list[ApiItem] | None = None,
+) -> dict[str, Any]:
+    """Convert AnalysisResult to the 'data' portion of envelope format."""
+    by_file: dict[str, list[ApiItem]] = {}
+    for item in result.items:
+        by_file.setdefault(item.file_path, []).append(item)
+
+    files = [
+        {
+            "path": normalize_file_path(path, repo_root),
+            "item_count": len(items),
+            "items": [_item_dict(item) for item in items],
+        }
+        for path, items in sorted(by_file.items())
+    ]
+
+    data: dict[str, Any] = {
+        "tool": TOOL_NAME,
+        "tool_version": TOOL_VERSION,
+        "summary": {
+            "total_files": result.total_files,
+            "total_items": len(result.items),
+            "items_by_kind": result.by_kind,
+            "items_by_crate": result.by_crate,
+        },
+        "files": files,
+        "analysis_duration_ms": result.analysis_duration_ms,
+    }
+
+    if baseline_items is not None:
+        diff = diff_surfaces(baseline_items, result.items)
+        data["api_diff"] = {
+            "added": [_item_dict(item) for item in diff.added],
+            "removed": [_item_dict(item) for item in diff.removed],
+            "changed": [
+                {
+                    "name": change.name,
+                    "kind": change.kind,
+                    "crate": change.crate,
+                    "old_signature": change.old_signature,
+                    "new_signature": change.new_signature,
+                }
+                for change in diff.changed
+            ],
+            "breaking_count": diff.breaking_count,
+        }
+        data["summary"]["breaking_count"] = diff.breaking_count
+    return data
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Extract the public Rust API surface")
+    add_common_args(parser)
+    parser.add_argument(
+        "--baseline",
+        type=Path,
+        default=None,
+        help="Previous run's output.json to diff the surface against",
+    )
+    parser.add_argument(
+        "--json-only",
+        action="store_true",
+        help="Only output JSON, no summary",
+    )
+    args = parser.parse_args()
+
+    common = validate_common_args(args)
+
+    baseline_items = None
+    if args.baseline is not None:
+        if not args.baseline.exists():
+            print(f"Error: baseline not found: {args.baseline}", file=sys.stderr)
+            sys.exit(1)
+        baseline_items = load_baseline_items(args.baseline)
+
+    print(f"Analyzing: {common.repo_path}")
+    result = analyze_repository(common.repo_path, common.repo_name)
+
+    print(f"Rust files: {result.total_files}")
+    print(f"Public items: {len(result.items)}")
+    print(f"Duration: {result.analysis_duration_ms}ms")
+
+    data = result_to_data_dict(result, repo_root=common.repo_path, baseline_items=baseline_items)
+    output_dict = create_envelope(
+        data,
+        tool_name=TOOL_NAME,
+        tool_version=TOOL_VERSION,
+        run_id=common.run_id,
+        repo_id=common.repo_id,
+        branch=common.branch,
+        commit=common.commit,
+        timestamp=get_current_timestamp(),
+        schema_version=SCHEMA_VERSION,
+    )
+
+    common.output_path.write_text(json.dumps(output_dict, indent=2, ensure_ascii=False))
+    print(f"Output: {common.output_path}")
+
+    if not args.json_only and baseline_items is not None:
+        diff = data["api_diff"]
+        print(f"  added: {len(diff['added'])}")
+        print(f"  removed: {len(diff['removed'])}")
+        print(f"  changed: {len(diff['changed'])}")
+        if diff["breaking_count"]:
+            print(f"  BREAKING: {diff['breaking_count']} removal(s)/signature change(s)")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,225 @@
+"""Public API surface extraction for Rust crates.
+
+Collects every ``pub`` item (functions, types, traits, consts) with a
+whitespace-normalized signature, keyed by ``(crate, kind, name)``. Two
+surfaces from different runs diff into additions, removals, and signature
+changes; removals and changes are breaking for downstream crates, which
+is exactly what a release review wants flagged.
+
+Extraction is textual — ``pub(crate)``/``pub(super)`` items are excluded
+because they are not part of the external surface, and macro-generated
+items are invisible. That keeps the tool dependency-free and fast, at the
+cost of missing API produced by macros.
+"""
+
+from __future__ import annotations
+
+import re
+import time
+import tomllib
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# Externally visible item kinds; `pub(...)` restricted visibility is excluded.
+PUB_ITEM_PATTERN = re.compile(
+    r"^\s*pub\s+(?:unsafe\s+|async\s+|const\s+|extern\s+\"[^\"]*\"\s+)*"
+    r"(fn|struct|enum|trait|union|const|static|type|mod)\s+"
+    r"([A-Za-z_][A-Za-z0-9_]*)"
+)
+
+# A signature ends at the body or the terminating semicolon.
+_SIGNATURE_DELIMITER = re.compile(r"[{;]")
+
+# How many continuation lines a multi-line signature may span.
+_MAX_SIGNATURE_LINES = 16
+
+EXCLUDED_DIRS = {".git", "vendor", "node_modules", "target"}
+
+
+@dataclass(frozen=True)
+class ApiItem:
+    """One public item with its normalized signature."""
+    name: str
+    kind: str
+    signature: str
+    file_path: str
+    line: int
+    crate: str
+
+    @property
+    def key(self) -> tuple[str, str, str]:
+        return (self.crate, self.kind, self.name)
+
+
+@dataclass(frozen=True)
+class ApiChange:
+    """A signature change between two runs for the same item."""
+    name: str
+    kind: str
+    crate: str
+    old_signature: str
+    new_signature: str
+
+
+@dataclass
+class ApiDiff:
+    """Surface delta between a baseline run and the current run."""
+    added: list[ApiItem] = field(default_factory=list)
+    removed: list[ApiItem] = field(default_factory=list)
+    changed: list[ApiChange] = field(default_factory=list)
+
+    @property
+    def breaking_count(self) -> int:
+        """Removals and signature changes break downstream crates."""
+        return len(self.removed) + len(self.changed)
+
+
+@dataclass
+class AnalysisResult:
+    """Complete API surface of a repository."""
+    repo_name: str
+    repo_path: str
+    items: list[ApiItem] = field(default_factory=list)
+    by_kind: dict[str, int] = field(default_factory=dict)
+    by_crate: dict[str, int] = field(default_factory=dict)
+    total_files: int = 0
+    analysis_duration_ms: int = 0
+
+
+def discover_rust_files(repo_path: Path) -> list[str]:
+    """Find .rs files, repo-relative with POSIX separators."""
+    files = []
+    for path in sorted(repo_path.rglob("*.rs")):
+        relative = path.relative_to(repo_path)
+        if any(part in EXCLUDED_DIRS for part in relative.parts):
+            continue
+        files.append(relative.as_posix())
+    return files
+
+
+def find_crate_name(repo_path: Path, relative_path: str) -> str:
+    """Resolve the crate a file belongs to via its nearest Cargo.toml."""
+    directory = (repo_path / relative_path).parent
+    while True:
+        manifest = directory / "Cargo.toml"
+        if manifest.exists():
+            try:
+                parsed = tomllib.loads(manifest.read_text())
+            except (tomllib.TOMLDecodeError, OSError):
+                return directory.name
+            return parsed.get("package", {}).get("name", directory.name)
+        if directory == repo_path or directory.parent == directory:
+            return "unknown"
+        directory = directory.parent
+
+
+def normalize_signature(raw: str) -> str:
+    """Collapse a declaration to a whitespace-normalized signature.
+
+    The body brace / terminating semicolon and anything after it are
+    dropped, so formatting-only edits do not register as API changes.
+    """
+    delimiter = _SIGNATURE_DELIMITER.search(raw)
+    if delimiter:
+        raw = raw[: delimiter.start()]
+    return " ".join(raw.split()).rstrip()
+
+
+def extract_items(repo_path: Path, relative_path: str, crate: str) -> list[ApiItem]:
+    """Extract pub items from one file, joining multi-line signatures."""
+    try:
+        lines = (repo_path / relative_path).read_text(encoding="utf-8").splitlines()
+    except (UnicodeDecodeError, OSError):
+        return []
+
+    items = []
+    for index, line in enumerate(lines):
+        match = PUB_ITEM_PATTERN.match(line)
+        if not match:
+            continue
+        declaration = line
+        lookahead = index + 1
+        while (
+            not _SIGNATURE_DELIMITER.search(declaration)
+            and lookahead < len(lines)
+            and lookahead - index < _MAX_SIGNATURE_LINES
+        ):
+            declaration += " " + lines[lookahead]
+            lookahead += 1
+        items.append(ApiItem(
+            name=match.group(2),
+            kind=match.group(1),
+            signature=normalize_signature(declaration),
+            file_path=relative_path,
+            line=index + 1,
+            crate=crate,
+        ))
+    return items
+
+
+def extract_api_surface(repo_path: Path, files: list[str]) -> list[ApiItem]:
+    """Extract the full public surface, ordered by file then line."""
+    items = []
+    for relative_path in files:
+        crate = find_crate_name(repo_path, relative_path)
+        items.extend(extract_items(repo_path, relative_path, crate))
+    return items
+
+
+def diff_surfaces(baseline: list[ApiItem], current: list[ApiItem]) -> ApiDiff:
+    """Diff two surfaces into additions, removals, and signature changes.
+
+    Items are matched by ``(crate, kind, name)``; a matched item with a
+    different signature counts as changed. Duplicate keys (e.g. the same
+    name behind cfg gates) keep the first occurrence.
+    """
+    old_by_key: dict[tuple[str, str, str], ApiItem] = {}
+    for item in baseline:
+        old_by_key.setdefault(item.key, item)
+    new_by_key: dict[tuple[str, str, str], ApiItem] = {}
+    for item in current:
+        new_by_key.setdefault(item.key, item)
+
+    diff = ApiDiff()
+    for key, item in sorted(new_by_key.items()):
+        old = old_by_key.get(key)
+        if old is None:
+            diff.added.append(item)
+        elif old.signature != item.signature:
+            diff.changed.append(ApiChange(
+                name=item.name,
+                kind=item.kind,
+                crate=item.crate,
+                old_signature=old.signature,
+                new_signature=item.signature,
+            ))
+    diff.removed.extend(
+        item for key, item in sorted(old_by_key.items()) if key not in new_by_key
+    )
+    return diff
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Extract the public API surface of a repository."""
+    start = time.monotonic()
+    repo_path = repo_path.resolve()
+
+    files = discover_rust_files(repo_path)
+    items = extract_api_surface(repo_path, files)
+
+    by_kind: dict[str, int] = defaultdict(int)
+    by_crate: dict[str, int] = defaultdict(int)
+    for item in items:
+        by_kind[item.kind] += 1
+        by_crate[item.crate] += 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        items=items,
+        by_kind=dict(by_kind),
+        by_crate=dict(by_crate),
+        total_files=len(files),
+        analysis_duration_ms=int((time.monotonic() - start) * 1000),
+    )
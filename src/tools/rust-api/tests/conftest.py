@@ -0,0 +1,11 @@
+"""Pytest configuration for rust-api tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add rust-api tool directory to path so 'scripts' can be imported as a package
+rust_api_root = Path(__file__).parent.parent
+sys.path.insert(0, str(rust_api_root))
+sys.path.insert(0, str(rust_api_root / "scripts"))
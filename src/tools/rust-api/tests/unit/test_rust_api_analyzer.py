@@ -0,0 +1,127 @@
+"""Unit tests for rust_api_analyzer extraction and surface diffing."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from rust_api_analyzer import (
+    ApiItem,
+    diff_surfaces,
+    discover_rust_files,
+    extract_items,
+    normalize_signature,
+)
+
+
+def _item(**overrides) -> ApiItem:
+    item = {
+        "name": "connect",
+        "kind": "fn",
+        "signature": "pub fn connect(addr: &str) -> Result<Client, Error>",
+        "file_path": "src/lib.rs",
+        "line": 3,
+        "crate": "caldera-synthetic",
+    }
+    item.update(overrides)
+    return ApiItem(**item)
+
+
+def test_extract_items_keeps_pub_and_skips_restricted(tmp_path: Path) -> None:
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "lib.rs").write_text(
+        "pub fn exposed(x: u32) -> u32 { x }\n"
+        "pub(crate) fn internal() {}\n"
+        "fn hidden() {}\n"
+        "pub struct Client;\n"
+        "pub trait Send2 {}\n"
+    )
+    items = extract_items(tmp_path, "src/lib.rs", "demo")
+
+    assert [(item.name, item.kind) for item in items] == [
+        ("exposed", "fn"),
+        ("Client", "struct"),
+        ("Send2", "trait"),
+    ]
+    assert items[0].signature == "pub fn exposed(x: u32) -> u32"
+
+
+def test_extract_items_joins_multiline_signatures(tmp_path: Path) -> None:
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "lib.rs").write_text(
+        "pub fn connect(\n"
+        "    addr: &str,\n"
+        "    timeout: Duration,\n"
+        ") -> Result<Client, Error> {\n"
+        "    todo!()\n"
+        "}\n"
+    )
+    items = extract_items(tmp_path, "src/lib.rs", "demo")
+
+    assert items[0].signature == (
+        "pub fn connect( addr: &str, timeout: Duration, ) -> Result<Client, Error>"
+    )
+    assert items[0].line == 1
+
+
+def test_extract_items_handles_async_and_unsafe_modifiers(tmp_path: Path) -> None:
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "lib.rs").write_text(
+        "pub async fn fetch() {}\n"
+        "pub unsafe fn poke(ptr: *mut u8);\n"
+        "pub const MAX: usize = 8;\n"
+    )
+    items = extract_items(tmp_path, "src/lib.rs", "demo")
+
+    assert [(item.name, item.kind) for item in items] == [
+        ("fetch", "fn"),
+        ("poke", "fn"),
+        ("MAX", "const"),
+    ]
+
+
+def test_normalize_signature_ignores_formatting_only_changes() -> None:
+    assert normalize_signature("pub fn f(a: u32)   ->   u32 {") == normalize_signature(
+        "pub fn f(a: u32) -> u32{"
+    )
+
+
+def test_discover_rust_files_skips_target_and_vendor(tmp_path: Path) -> None:
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "lib.rs").write_text("")
+    (tmp_path / "target" / "debug").mkdir(parents=True)
+    (tmp_path / "target" / "debug" / "gen.rs").write_text("")
+
+    assert discover_rust_files(tmp_path) == ["src/lib.rs"]
+
+
+def test_diff_surfaces_added_removed_changed() -> None:
+    baseline = [
+        _item(),
+        _item(name="close", signature="pub fn close(self)"),
+        _item(name="Gone", kind="struct", signature="pub struct Gone"),
+    ]
+    current = [
+        _item(signature="pub fn connect(addr: &str, tls: bool) -> Result<Client, Error>"),
+        _item(name="close", signature="pub fn close(self)"),
+        _item(name="fresh", signature="pub fn fresh()"),
+    ]
+    diff = diff_surfaces(baseline, current)
+
+    assert [item.name for item in diff.added] == ["fresh"]
+    assert [item.name for item in diff.removed] == ["Gone"]
+    assert [change.name for change in diff.changed] == ["connect"]
+    assert diff.changed[0].old_signature.endswith("-> Result<Client, Error>")
+    assert diff.breaking_count == 2
+
+
+def test_diff_surfaces_same_name_different_kind_not_matched() -> None:
+    baseline = [_item(name="Config", kind="struct", signature="pub struct Config")]
+    current = [_item(name="Config", kind="trait", signature="pub trait Config")]
+    diff = diff_surfaces(baseline, current)
+
+    assert [item.kind for item in diff.added] == ["trait"]
+    assert [item.kind for item in diff.removed] == ["struct"]
+    assert diff.changed == []
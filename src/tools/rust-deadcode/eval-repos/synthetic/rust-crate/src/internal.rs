@@ -0,0 +1,22 @@
+use crate::compute_total;
+
+/// pub but only referenced inside this crate; expect a LOW
+/// cross-crate finding (over-exposed, could be pub(crate)).
+pub fn format_label(total: i64) -> String {
+    format!("total: {total}")
+}
+
+pub(crate) fn checked_total(values: &[i64]) -> Option<i64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(compute_total(values))
+    }
+}
+
+pub fn render(values: &[i64]) -> String {
+    match checked_total(values) {
+        Some(total) => format_label(total),
+        None => String::from("empty"),
+    }
+}
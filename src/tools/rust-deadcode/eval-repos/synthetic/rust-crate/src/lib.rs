@@ -0,0 +1,26 @@
+pub mod internal;
+
+use internal::format_label;
+
+/// Referenced from internal.rs, but never from outside the crate;
+/// expect a LOW cross-crate finding rather than MEDIUM.
+pub fn compute_total(values: &[i64]) -> i64 {
+    values.iter().sum()
+}
+
+/// Never referenced anywhere, but exported, so only the reference scan
+/// catches it; expect a MEDIUM finding.
+pub fn orphan_helper(value: i64) -> i64 {
+    value * 2
+}
+
+/// Private and unused; the compiler flags this directly.
+fn stale_private(value: i64) -> i64 {
+    value - 1
+}
+
+/// Unreferenced public entry point; the reference scan reports MEDIUM
+/// because nothing in the workspace calls it.
+pub fn describe(values: &[i64]) -> String {
+    format_label(compute_total(values))
+}
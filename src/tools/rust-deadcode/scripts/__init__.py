@@ -0,0 +1 @@
+# Makes scripts a package for module execution
@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+"""CLI entry point for Rust dead code analysis.
+
+Standard wrapper that translates orchestrator CLI args to
+rust_deadcode_analyzer and produces Caldera envelope output format.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+# Add shared src to path for imports
+sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
+from common.cli_parser import add_common_args, validate_common_args
+from common.envelope_formatter import create_envelope, get_current_timestamp
+from common.path_normalization import normalize_file_path, normalize_dir_path
+
+from .rust_deadcode_analyzer import AnalysisResult, analyze_repository
+
+TOOL_NAME = "rust-deadcode"
+TOOL_VERSION = "1.0.0"
+SCHEMA_VERSION = "1.0.0"
+
+
+def result_to_data_dict(result: AnalysisResult, repo_root: Path | None = None) -> dict[str, Any]:
+    """Convert AnalysisResult to the 'data' portion of envelope format."""
+    files = []
+    for f in result.files:
+        findings = []
+        for finding in f.findings:
+            findings.append({
+                "symbol_name": finding.symbol_name,
+                "symbol_kind": finding.symbol_kind,
+                "line": finding.line,
+                "source": finding.source,
+                "confidence": finding.confidence,
+                "message": finding.message,
+            })
+        files.append({
+            "path": normalize_file_path(f.path, repo_root),
+            "lines": f.lines,
+            "finding_count": f.finding_count,
+            "by_confidence": f.by_confidence,
+            "findings": findings,
+        })
+
+    directories = []
+    for d in result.directories:
+        directories.append({
+            "path": normalize_dir_path(d.path, repo_root),
+            "direct": {
+                "file_count": d.direct.file_count,
+                "finding_count": d.direct.finding_count,
+                "by_confidence": d.direct.by_confidence,
+            },
+            "recursive": {
+                "file_count": d.recursive.file_count,
+                "finding_count": d.recursive.finding_count,
+                "by_confidence": d.recursive.by_confidence,
+            },
+        })
+
+    return {
+        "tool": TOOL_NAME,
+        "tool_version": TOOL_VERSION,
+        "summary": {
+            "total_files": len(result.files),
+            "total_directories": len(result.directories),
+            "total_symbols": result.total_symbols,
+            "total_lines": result.total_lines,
+            "total_findings": len(result.findings),
+            "cargo_available": result.cargo_available,
+            "findings_by_confidence": result.by_confidence,
+            "findings_by_kind": result.by_kind,
+            "dead_symbols_per_kloc": round(result.dead_symbols_per_kloc, 4),
+        },
+        "files": files,
+        "directories": directories,
+        "analysis_duration_ms": result.analysis_duration_ms,
+    }
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Detect likely-dead Rust code")
+    add_common_args(parser)
+    parser.add_argument(
+        "--json-only",
+        action="store_true",
+        help="Only output JSON, no summary",
+    )
+    args = parser.parse_args()
+
+    common = validate_common_args(args)
+
+    print(f"Analyzing: {common.repo_path}")
+    result = analyze_repository(common.repo_path, common.repo_name)
+
+    print(f"Rust files: {len(result.files)}")
+    print(f"Symbols: {result.total_symbols}")
+    print(f"Likely-dead findings: {len(result.findings)}")
+    print(f"Duration: {result.analysis_duration_ms}ms")
+
+    data = result_to_data_dict(result, repo_root=common.repo_path)
+    output_dict = create_envelope(
+        data,
+        tool_name=TOOL_NAME,
+        tool_version=TOOL_VERSION,
+        run_id=common.run_id,
+        repo_id=common.repo_id,
+        branch=common.branch,
+        commit=common.commit,
+        timestamp=get_current_timestamp(),
+        schema_version=SCHEMA_VERSION,
+    )
+
+    common.output_path.write_text(json.dumps(output_dict, indent=2, ensure_ascii=False))
+    print(f"Output: {common.output_path}")
+
+    if not args.json_only:
+        for confidence in ("HIGH", "MEDIUM", "LOW"):
+            count = result.by_confidence.get(confidence, 0)
+            if count:
+                print(f"  {confidence}: {count}")
+
+
+if __name__ == "__main__":
+    main()
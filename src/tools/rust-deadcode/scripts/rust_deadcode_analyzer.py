@@ -0,0 +1,383 @@
+"""Dead code detection for Rust targets.
+
+Combines three evidence sources into likely-dead findings with confidence
+levels:
+
+- ``cargo build --message-format=json`` dead_code warnings (HIGH — the
+  compiler proved the item unused within its crate),
+- workspace-wide reference scanning for items with no use outside their
+  own definition (MEDIUM — textual, so macros and reflection can fool it),
+- cross-crate reference checks for ``pub`` items only ever used inside
+  their defining crate (LOW — not dead, but over-exposed and a removal
+  candidate once callers are checked).
+
+The summary's ``dead_symbols_per_kloc`` feeds the insights health score.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+import shutil
+import subprocess
+import time
+import tomllib
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# Item kinds considered for deadness.
+ITEM_PATTERN = re.compile(
+    r"^\s*(pub(?:\s*\(\s*crate\s*\))?\s+)?(fn|struct|enum|trait|const|static|type)\s+"
+    r"([A-Za-z_][A-Za-z0-9_]*)"
+)
+
+CONFIDENCE_LEVELS = ("HIGH", "MEDIUM", "LOW")
+
+EXCLUDED_DIRS = {".git", "vendor", "node_modules", "target"}
+
+
+@dataclass(frozen=True)
+class SymbolDef:
+    """One item definition found in Rust source."""
+    name: str
+    kind: str
+    visibility: str  # "pub", "pub(crate)", or "private"
+    file_path: str
+    line: int
+    crate: str
+
+
+@dataclass(frozen=True)
+class DeadCodeFinding:
+    """One likely-dead item with the evidence that flagged it."""
+    file_path: str
+    line: int
+    symbol_name: str
+    symbol_kind: str
+    source: str  # "compiler" or "reference_scan"
+    confidence: str  # HIGH, MEDIUM, LOW
+    message: str
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of dead code findings."""
+    path: str
+    lines: int
+    finding_count: int = 0
+    by_confidence: dict[str, int] = field(default_factory=dict)
+    findings: list[DeadCodeFinding] = field(default_factory=list)
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    finding_count: int = 0
+    by_confidence: dict[str, int] = field(default_factory=dict)
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete dead code analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    cargo_available: bool
+    total_symbols: int
+    total_lines: int
+    findings: list[DeadCodeFinding] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_confidence: dict[str, int] = field(default_factory=dict)
+    by_kind: dict[str, int] = field(default_factory=dict)
+    analysis_duration_ms: int = 0
+
+    @property
+    def dead_symbols_per_kloc(self) -> float:
+        if self.total_lines <= 0:
+            return 0.0
+        return len(self.findings) * 1000.0 / self.total_lines
+
+
+def discover_rust_files(repo_path: Path) -> list[str]:
+    """Find .rs files, repo-relative with POSIX separators."""
+    files = []
+    for path in sorted(repo_path.rglob("*.rs")):
+        relative = path.relative_to(repo_path)
+        if any(part in EXCLUDED_DIRS for part in relative.parts):
+            continue
+        files.append(relative.as_posix())
+    return files
+
+
+def find_crate_name(repo_path: Path, relative_path: str) -> str:
+    """Resolve the crate a file belongs to via its nearest Cargo.toml."""
+    directory = (repo_path / relative_path).parent
+    while True:
+        manifest = directory / "Cargo.toml"
+        if manifest.exists():
+            try:
+                parsed = tomllib.loads(manifest.read_text())
+            except (tomllib.TOMLDecodeError, OSError):
+                return directory.name
+            return parsed.get("package", {}).get("name", directory.name)
+        if directory == repo_path or directory.parent == directory:
+            return "unknown"
+        directory = directory.parent
+
+
+def collect_symbol_defs(repo_path: Path, files: list[str]) -> list[SymbolDef]:
+    """Extract item definitions from all Rust files."""
+    defs = []
+    for relative_path in files:
+        try:
+            content = (repo_path / relative_path).read_text(encoding="utf-8")
+        except (UnicodeDecodeError, OSError):
+            continue
+        crate = find_crate_name(repo_path, relative_path)
+        for line_number, line in enumerate(content.splitlines(), start=1):
+            match = ITEM_PATTERN.match(line)
+            if not match:
+                continue
+            visibility_token = (match.group(1) or "").strip()
+            if visibility_token.startswith("pub") and "crate" in visibility_token:
+                visibility = "pub(crate)"
+            elif visibility_token == "pub":
+                visibility = "pub"
+            else:
+                visibility = "private"
+            defs.append(SymbolDef(
+                name=match.group(3),
+                kind=match.group(2),
+                visibility=visibility,
+                file_path=relative_path,
+                line=line_number,
+                crate=crate,
+            ))
+    return defs
+
+
+def count_references(
+    repo_path: Path, files: list[str], symbols: list[SymbolDef]
+) -> dict[str, dict[str, int]]:
+    """Count identifier occurrences per symbol, split by crate.
+
+    Returns symbol name -> crate name -> occurrence count. The defining
+    occurrence is included and subtracted by the caller.
+    """
+    names = {symbol.name for symbol in symbols}
+    counts: dict[str, dict[str, int]] = defaultdict(lambda: defaultdict(int))
+    for relative_path in files:
+        try:
+            content = (repo_path / relative_path).read_text(encoding="utf-8")
+        except (UnicodeDecodeError, OSError):
+            continue
+        crate = find_crate_name(repo_path, relative_path)
+        for token in re.findall(r"[A-Za-z_][A-Za-z0-9_]*", content):
+            if token in names:
+                counts[token][crate] += 1
+    return {name: dict(crates) for name, crates in counts.items()}
+
+
+def run_cargo_build(repo_path: Path) -> list[DeadCodeFinding]:
+    """Collect dead_code warnings from ``cargo build --message-format=json``.
+
+    Returns an empty list when cargo is unavailable or the tree has no
+    manifest; the reference scan still runs in that case.
+    """
+    if not (repo_path / "Cargo.toml").exists():
+        return []
+    try:
+        result = subprocess.run(
+            ["cargo", "build", "--message-format=json"],
+            cwd=repo_path,
+            capture_output=True,
+            text=True,
+        )
+    except OSError:
+        return []
+
+    findings = []
+    for line in result.stdout.splitlines():
+        try:
+            event = json.loads(line)
+        except json.JSONDecodeError:
+            continue
+        if event.get("reason") != "compiler-message":
+            continue
+        message = event.get("message") or {}
+        code = (message.get("code") or {}).get("code")
+        if code != "dead_code":
+            continue
+        spans = [s for s in message.get("spans", []) if s.get("is_primary")]
+        if not spans:
+            continue
+        span = spans[0]
+        text = message.get("message", "dead code")
+        symbol_match = re.search(r"`([A-Za-z_][A-Za-z0-9_]*)`", text)
+        findings.append(DeadCodeFinding(
+            file_path=Path(span.get("file_name", "")).as_posix(),
+            line=span.get("line_start", 1),
+            symbol_name=symbol_match.group(1) if symbol_match else "",
+            symbol_kind=text.split()[0] if text else "item",
+            source="compiler",
+            confidence="HIGH",
+            message=text,
+        ))
+    return findings
+
+
+def scan_references(
+    symbols: list[SymbolDef], reference_counts: dict[str, dict[str, int]]
+) -> list[DeadCodeFinding]:
+    """Flag unreferenced items and crate-internal pub items."""
+    findings = []
+    for symbol in symbols:
+        crates = reference_counts.get(symbol.name, {})
+        total = sum(crates.values())
+        own = crates.get(symbol.crate, 0)
+        # The definition itself counts once; anything beyond it is a use.
+        if total <= 1:
+            findings.append(DeadCodeFinding(
+                file_path=symbol.file_path,
+                line=symbol.line,
+                symbol_name=symbol.name,
+                symbol_kind=symbol.kind,
+                source="reference_scan",
+                confidence="MEDIUM",
+                message=f"{symbol.kind} `{symbol.name}` has no references in the workspace",
+            ))
+        elif symbol.visibility == "pub" and total == own:
+            findings.append(DeadCodeFinding(
+                file_path=symbol.file_path,
+                line=symbol.line,
+                symbol_name=symbol.name,
+                symbol_kind=symbol.kind,
+                source="reference_scan",
+                confidence="LOW",
+                message=(
+                    f"pub {symbol.kind} `{symbol.name}` is never referenced "
+                    f"outside crate `{symbol.crate}`"
+                ),
+            ))
+    return findings
+
+
+def merge_findings(
+    compiler: list[DeadCodeFinding], reference: list[DeadCodeFinding]
+) -> list[DeadCodeFinding]:
+    """Combine the sources, preferring compiler evidence on overlap."""
+    flagged = {(f.file_path, f.symbol_name) for f in compiler if f.symbol_name}
+    merged = list(compiler)
+    merged.extend(
+        f for f in reference if (f.file_path, f.symbol_name) not in flagged
+    )
+    return sorted(merged, key=lambda f: (f.file_path, f.line, f.symbol_name))
+
+
+def _count_lines(repo_path: Path, relative_path: str) -> int:
+    try:
+        return len((repo_path / relative_path).read_text(encoding="utf-8").splitlines())
+    except (UnicodeDecodeError, OSError):
+        return 0
+
+
+def build_file_stats(
+    repo_path: Path, files: list[str], findings: list[DeadCodeFinding]
+) -> list[FileStats]:
+    """Aggregate findings per file; clean files are included with zeros."""
+    by_file: dict[str, list[DeadCodeFinding]] = defaultdict(list)
+    for finding in findings:
+        by_file[finding.file_path].append(finding)
+
+    stats = []
+    for relative_path in files:
+        file_findings = by_file.get(relative_path, [])
+        by_confidence: dict[str, int] = defaultdict(int)
+        for finding in file_findings:
+            by_confidence[finding.confidence] += 1
+        stats.append(FileStats(
+            path=relative_path,
+            lines=_count_lines(repo_path, relative_path),
+            finding_count=len(file_findings),
+            by_confidence=dict(by_confidence),
+            findings=file_findings,
+        ))
+    return stats
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Roll file aggregates up into direct and recursive directory stats."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    def _accumulate(stats: DirectoryStats, file_stats: FileStats) -> None:
+        stats.file_count += 1
+        stats.finding_count += file_stats.finding_count
+        for confidence, count in file_stats.by_confidence.items():
+            stats.by_confidence[confidence] = stats.by_confidence.get(confidence, 0) + count
+
+    for file_stats in files:
+        parts = file_stats.path.split("/")
+        parent = "/".join(parts[:-1]) if len(parts) > 1 else "."
+        _accumulate(direct[parent], file_stats)
+        ancestor_parts = parts[:-1]
+        _accumulate(recursive["."], file_stats)
+        for depth in range(1, len(ancestor_parts) + 1):
+            _accumulate(recursive["/".join(ancestor_parts[:depth])], file_stats)
+
+    return [
+        DirectoryEntry(
+            path=path,
+            direct=direct.get(path, DirectoryStats()),
+            recursive=recursive[path],
+        )
+        for path in sorted(recursive)
+    ]
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Run the full dead code analysis over a repository."""
+    start = time.monotonic()
+    repo_path = repo_path.resolve()
+
+    files = discover_rust_files(repo_path)
+    symbols = collect_symbol_defs(repo_path, files)
+    reference_counts = count_references(repo_path, files, symbols)
+
+    compiler_findings = run_cargo_build(repo_path)
+    reference_findings = scan_references(symbols, reference_counts)
+    findings = merge_findings(compiler_findings, reference_findings)
+
+    file_stats = build_file_stats(repo_path, files, findings)
+
+    by_confidence: dict[str, int] = defaultdict(int)
+    by_kind: dict[str, int] = defaultdict(int)
+    for finding in findings:
+        by_confidence[finding.confidence] += 1
+        by_kind[finding.symbol_kind] += 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        cargo_available=(
+            shutil.which("cargo") is not None and (repo_path / "Cargo.toml").exists()
+        ),
+        total_symbols=len(symbols),
+        total_lines=sum(f.lines for f in file_stats),
+        findings=findings,
+        files=file_stats,
+        directories=build_directory_stats(file_stats),
+        by_confidence=dict(by_confidence),
+        by_kind=dict(by_kind),
+        analysis_duration_ms=int((time.monotonic() - start) * 1000),
+    )
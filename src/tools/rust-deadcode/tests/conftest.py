@@ -0,0 +1,11 @@
+"""Pytest configuration for rust-deadcode tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add rust-deadcode tool directory to path so 'scripts' can be imported as a package
+rust_deadcode_root = Path(__file__).parent.parent
+sys.path.insert(0, str(rust_deadcode_root))
+sys.path.insert(0, str(rust_deadcode_root / "scripts"))
@@ -0,0 +1,139 @@
+"""Unit tests for rust_deadcode_analyzer collection, scanning, and merging."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from rust_deadcode_analyzer import (
+    DeadCodeFinding,
+    SymbolDef,
+    build_directory_stats,
+    build_file_stats,
+    collect_symbol_defs,
+    discover_rust_files,
+    merge_findings,
+    scan_references,
+)
+
+
+def _symbol(**overrides) -> SymbolDef:
+    symbol = {
+        "name": "orphan_helper",
+        "kind": "fn",
+        "visibility": "pub",
+        "file_path": "src/lib.rs",
+        "line": 12,
+        "crate": "caldera-synthetic",
+    }
+    symbol.update(overrides)
+    return SymbolDef(**symbol)
+
+
+def test_collect_symbol_defs_parses_kinds_and_visibility(tmp_path: Path) -> None:
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "lib.rs").write_text(
+        "pub fn exposed() {}\n"
+        "pub(crate) struct Inner;\n"
+        "fn hidden() {}\n"
+        "pub enum Mode { A }\n"
+    )
+    defs = collect_symbol_defs(tmp_path, ["src/lib.rs"])
+
+    assert [(d.name, d.kind, d.visibility) for d in defs] == [
+        ("exposed", "fn", "pub"),
+        ("Inner", "struct", "pub(crate)"),
+        ("hidden", "fn", "private"),
+        ("Mode", "enum", "pub"),
+    ]
+    assert defs[0].line == 1
+
+
+def test_discover_rust_files_skips_target_and_vendor(tmp_path: Path) -> None:
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "lib.rs").write_text("")
+    (tmp_path / "target" / "debug").mkdir(parents=True)
+    (tmp_path / "target" / "debug" / "gen.rs").write_text("")
+
+    assert discover_rust_files(tmp_path) == ["src/lib.rs"]
+
+
+def test_scan_references_flags_unreferenced_as_medium() -> None:
+    findings = scan_references(
+        [_symbol()], {"orphan_helper": {"caldera-synthetic": 1}}
+    )
+
+    assert len(findings) == 1
+    assert findings[0].confidence == "MEDIUM"
+    assert findings[0].source == "reference_scan"
+    assert "no references" in findings[0].message
+
+
+def test_scan_references_flags_crate_internal_pub_as_low() -> None:
+    findings = scan_references(
+        [_symbol(name="format_label")],
+        {"format_label": {"caldera-synthetic": 3}},
+    )
+
+    assert findings[0].confidence == "LOW"
+    assert "outside crate" in findings[0].message
+
+
+def test_scan_references_cross_crate_use_is_clean() -> None:
+    findings = scan_references(
+        [_symbol(name="compute_total")],
+        {"compute_total": {"caldera-synthetic": 2, "other-crate": 1}},
+    )
+    assert findings == []
+
+
+def test_scan_references_private_symbol_gets_no_low_finding() -> None:
+    findings = scan_references(
+        [_symbol(name="checked_total", visibility="pub(crate)")],
+        {"checked_total": {"caldera-synthetic": 2}},
+    )
+    assert findings == []
+
+
+def test_merge_findings_prefers_compiler_evidence() -> None:
+    compiler = [DeadCodeFinding(
+        file_path="src/lib.rs", line=12, symbol_name="orphan_helper",
+        symbol_kind="function", source="compiler", confidence="HIGH",
+        message="function `orphan_helper` is never used",
+    )]
+    reference = [
+        DeadCodeFinding(
+            file_path="src/lib.rs", line=12, symbol_name="orphan_helper",
+            symbol_kind="fn", source="reference_scan", confidence="MEDIUM",
+            message="fn `orphan_helper` has no references in the workspace",
+        ),
+        DeadCodeFinding(
+            file_path="src/lib.rs", line=17, symbol_name="stale_private",
+            symbol_kind="fn", source="reference_scan", confidence="MEDIUM",
+            message="fn `stale_private` has no references in the workspace",
+        ),
+    ]
+    merged = merge_findings(compiler, reference)
+
+    assert [(f.symbol_name, f.confidence) for f in merged] == [
+        ("orphan_helper", "HIGH"),
+        ("stale_private", "MEDIUM"),
+    ]
+
+
+def test_build_stats_rollups(tmp_path: Path) -> None:
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "lib.rs").write_text("pub fn orphan_helper() {}\n")
+    finding = DeadCodeFinding(
+        file_path="src/lib.rs", line=1, symbol_name="orphan_helper",
+        symbol_kind="fn", source="reference_scan", confidence="MEDIUM",
+        message="fn `orphan_helper` has no references in the workspace",
+    )
+    files = build_file_stats(tmp_path, ["src/lib.rs"], [finding])
+    directories = {d.path: d for d in build_directory_stats(files)}
+
+    assert files[0].finding_count == 1
+    assert files[0].by_confidence == {"MEDIUM": 1}
+    assert directories["src"].direct.finding_count == 1
+    assert directories["."].recursive.by_confidence == {"MEDIUM": 1}
@@ -0,0 +1,354 @@
+#!/usr/bin/env python3
+"""
+Per-rule precision/recall scoring for Semgrep rules.
+
+Scores every Semgrep rule that fired against the annotated fixtures
+(ground truth lines per smell, SAFE-marked comments in the eval repos)
+and reports rules that fire on SAFE-marked code. The output exists to
+make rule pruning a data decision: low-precision rules and rules with
+SAFE hits are candidates for removal.
+
+Usage:
+    python scripts/rule_scoring.py \\
+        --analysis data/runs/<run-id>/analysis.json \\
+        --ground-truth-dir evaluation/ground-truth \\
+        --eval-repos eval-repos/synthetic \\
+        --output evaluation/rule-scores.json
+"""
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+
+# Add scripts directory to path for imports
+sys.path.insert(0, str(Path(__file__).parent))
+# Add src/ to path for shared evaluation helpers
+sys.path.insert(0, str(Path(__file__).parents[3]))
+
+from checks import get_file_from_analysis, load_all_ground_truth, load_analysis
+from shared.evaluation.confusion import ConfusionMatrix, breakdown, path_category
+
+# A detection within this many lines of an expected line counts as a match
+# (mirrors check_line_accuracy tolerance).
+LINE_TOLERANCE = 2
+
+# A finding within this many lines after a SAFE marker counts as a SAFE hit.
+SAFE_MARKER_REACH = 2
+
+SAFE_MARKER = "SAFE:"
+
+
+@dataclass
+class RuleScore:
+    """Precision/recall tally for one Semgrep rule."""
+
+    rule_id: str
+    smell_ids: set[str] = field(default_factory=set)
+    true_positives: int = 0
+    false_positives: int = 0
+    false_negatives: int = 0
+    safe_hits: list[dict] = field(default_factory=list)
+
+    @property
+    def precision(self) -> float:
+        detected = self.true_positives + self.false_positives
+        return self.true_positives / detected if detected else 0.0
+
+    @property
+    def recall(self) -> float:
+        expected = self.true_positives + self.false_negatives
+        return self.true_positives / expected if expected else 0.0
+
+    def to_dict(self) -> dict:
+        return {
+            "rule_id": self.rule_id,
+            "smell_ids": sorted(self.smell_ids),
+            "true_positives": self.true_positives,
+            "false_positives": self.false_positives,
+            "false_negatives": self.false_negatives,
+            "precision": round(self.precision, 4),
+            "recall": round(self.recall, 4),
+            "safe_hits": self.safe_hits,
+        }
+
+
+def collect_safe_lines(eval_repos_dir: Path) -> dict[str, set[int]]:
+    """Scan fixture files for SAFE-marked lines.
+
+    A ``SAFE:`` comment marks the code it annotates as intentionally
+    clean; the marker line plus the next SAFE_MARKER_REACH lines form
+    the safe region. Returns {filename: safe line numbers}.
+    """
+    safe_lines: dict[str, set[int]] = {}
+    if not eval_repos_dir.is_dir():
+        return safe_lines
+    for fixture in sorted(eval_repos_dir.rglob("*")):
+        if not fixture.is_file():
+            continue
+        try:
+            lines = fixture.read_text(encoding="utf-8").splitlines()
+        except (UnicodeDecodeError, OSError):
+            continue
+        regions: set[int] = set()
+        for line_number, line in enumerate(lines, start=1):
+            if SAFE_MARKER in line:
+                regions.update(range(line_number, line_number + SAFE_MARKER_REACH + 1))
+        if regions:
+            safe_lines[fixture.name] = regions
+    return safe_lines
+
+
+def score_rules(
+    analysis: dict,
+    ground_truth: dict[str, dict],
+    safe_lines: dict[str, set[int]] | None = None,
+    tolerance: int = LINE_TOLERANCE,
+) -> dict[str, RuleScore]:
+    """Score each fired rule against expected lines per smell.
+
+    A detection is a true positive when its line is within ``tolerance``
+    of an unclaimed expected line for the smell its rule maps to, a
+    false positive otherwise. Expected lines no rule claimed become
+    false negatives, attributed to every rule that produced that smell
+    in the same file (or tracked under the smell id when none did).
+    """
+    safe_lines = safe_lines or {}
+    scores: dict[str, RuleScore] = {}
+
+    for lang_gt in ground_truth.values():
+        for filename, file_gt in lang_gt.get("files", {}).items():
+            file_info = get_file_from_analysis(analysis, filename)
+            detected_smells = (file_info or {}).get("smells", [])
+
+            expected_by_smell = {
+                expected["smell_id"]: list(expected.get("lines", []))
+                for expected in file_gt.get("expected_smells", [])
+                if expected.get("smell_id")
+            }
+
+            # Claim expected lines detection by detection, so duplicate
+            # findings on one line do not all count as true positives.
+            unclaimed = {smell: list(lines) for smell, lines in expected_by_smell.items()}
+            rules_by_smell: dict[str, set[str]] = {}
+
+            for smell in detected_smells:
+                rule_id = smell.get("rule_id", "unknown")
+                smell_id = smell.get("dd_smell_id", "unknown")
+                line = smell.get("line_start", 0)
+                score = scores.setdefault(rule_id, RuleScore(rule_id=rule_id))
+                score.smell_ids.add(smell_id)
+                rules_by_smell.setdefault(smell_id, set()).add(rule_id)
+
+                matched = next(
+                    (
+                        expected_line
+                        for expected_line in unclaimed.get(smell_id, [])
+                        if abs(expected_line - line) <= tolerance
+                    ),
+                    None,
+                )
+                if matched is not None:
+                    unclaimed[smell_id].remove(matched)
+                    score.true_positives += 1
+                else:
+                    score.false_positives += 1
+
+                if line in safe_lines.get(filename, set()):
+                    score.safe_hits.append({
+                        "file": filename,
+                        "line": line,
+                        "smell_id": smell_id,
+                    })
+
+            for smell_id, missed_lines in unclaimed.items():
+                if not missed_lines:
+                    continue
+                rules = rules_by_smell.get(smell_id)
+                if rules:
+                    for rule_id in rules:
+                        scores[rule_id].false_negatives += len(missed_lines)
+                else:
+                    # No rule produced this smell anywhere in the file;
+                    # track the miss under the smell id so it is visible.
+                    placeholder = scores.setdefault(
+                        f"<unfired:{smell_id}>",
+                        RuleScore(rule_id=f"<unfired:{smell_id}>"),
+                    )
+                    placeholder.smell_ids.add(smell_id)
+                    placeholder.false_negatives += len(missed_lines)
+
+    return scores
+
+
+def score_categories(
+    analysis: dict,
+    ground_truth: dict[str, dict],
+    tolerance: int = LINE_TOLERANCE,
+) -> dict[str, ConfusionMatrix]:
+    """Build one confusion matrix per path category (tests vs src).
+
+    Uses the same line-claiming rules as ``score_rules`` but attributes
+    counts to where the finding lives instead of which rule fired, so
+    the report shows whether misses cluster in test code or production
+    code.
+    """
+    matrices: dict[str, ConfusionMatrix] = {}
+
+    for lang_gt in ground_truth.values():
+        for filename, file_gt in lang_gt.get("files", {}).items():
+            file_info = get_file_from_analysis(analysis, filename)
+            category = path_category((file_info or {}).get("path", filename))
+            matrix = matrices.setdefault(category, ConfusionMatrix())
+
+            unclaimed = {
+                expected["smell_id"]: list(expected.get("lines", []))
+                for expected in file_gt.get("expected_smells", [])
+                if expected.get("smell_id")
+            }
+            for smell in (file_info or {}).get("smells", []):
+                smell_id = smell.get("dd_smell_id", "unknown")
+                line = smell.get("line_start", 0)
+                matched = next(
+                    (
+                        expected_line
+                        for expected_line in unclaimed.get(smell_id, [])
+                        if abs(expected_line - line) <= tolerance
+                    ),
+                    None,
+                )
+                if matched is not None:
+                    unclaimed[smell_id].remove(matched)
+                    matrix.true_positives += 1
+                else:
+                    matrix.false_positives += 1
+            matrix.false_negatives += sum(len(lines) for lines in unclaimed.values())
+
+    return matrices
+
+
+def generate_report(
+    scores: dict[str, RuleScore],
+    categories: dict[str, ConfusionMatrix] | None = None,
+) -> dict:
+    """Build the rule scoring report, noisiest rules first."""
+    ordered = sorted(
+        scores.values(),
+        key=lambda score: (score.precision, -score.false_positives, score.rule_id),
+    )
+    noisy = [
+        score.rule_id
+        for score in ordered
+        if score.safe_hits or (score.false_positives and score.precision < 0.5)
+    ]
+    report = {
+        "generated_at": datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        "tool": "semgrep",
+        "summary": {
+            "rules_scored": len(ordered),
+            "rules_with_safe_hits": sum(1 for score in ordered if score.safe_hits),
+            "pruning_candidates": noisy,
+        },
+        "rules": [score.to_dict() for score in ordered],
+    }
+    if categories is not None:
+        report["confusion"] = breakdown(categories)
+    return report
+
+
+def generate_report_md(report: dict) -> str:
+    """Render the rule scoring report as Markdown."""
+    lines = [
+        "# Semgrep Per-Rule Scoring",
+        "",
+        f"Generated: {report['generated_at']}",
+        "",
+        f"Rules scored: {report['summary']['rules_scored']}",
+        "",
+        "| Rule | Smells | TP | FP | FN | Precision | Recall | SAFE hits |",
+        "|------|--------|----|----|----|-----------|--------|-----------|",
+    ]
+    for rule in report["rules"]:
+        lines.append(
+            f"| {rule['rule_id']} | {', '.join(rule['smell_ids'])} "
+            f"| {rule['true_positives']} | {rule['false_positives']} "
+            f"| {rule['false_negatives']} | {rule['precision']:.2f} "
+            f"| {rule['recall']:.2f} | {len(rule['safe_hits'])} |"
+        )
+    confusion = report.get("confusion")
+    if confusion:
+        lines += [
+            "",
+            "## Per-category confusion",
+            "",
+            "| Category | TP | FP | FN | Precision | Recall | F1 |",
+            "|----------|----|----|----|-----------|--------|----|",
+        ]
+        rows = dict(confusion["by_category"])
+        rows["overall"] = confusion["overall"]
+        for category, matrix in rows.items():
+            lines.append(
+                f"| {category} | {matrix['true_positives']} | {matrix['false_positives']} "
+                f"| {matrix['false_negatives']} | {matrix['precision']:.2f} "
+                f"| {matrix['recall']:.2f} | {matrix['f1']:.2f} |"
+            )
+    safe_offenders = [rule for rule in report["rules"] if rule["safe_hits"]]
+    if safe_offenders:
+        lines += ["", "## Rules firing on SAFE-marked code", ""]
+        for rule in safe_offenders:
+            for hit in rule["safe_hits"]:
+                lines.append(
+                    f"- `{rule['rule_id']}` at {hit['file']}:{hit['line']} ({hit['smell_id']})"
+                )
+    candidates = report["summary"]["pruning_candidates"]
+    if candidates:
+        lines += ["", "## Pruning candidates", ""]
+        lines += [f"- `{rule_id}`" for rule_id in candidates]
+    lines.append("")
+    return "\n".join(lines)
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Score Semgrep rules against ground truth")
+    parser.add_argument("--analysis", required=True, help="Path to analysis JSON")
+    parser.add_argument(
+        "--ground-truth-dir",
+        default=str(Path(__file__).parent.parent / "evaluation" / "ground-truth"),
+        help="Directory with per-language ground truth JSON",
+    )
+    parser.add_argument(
+        "--eval-repos",
+        default=str(Path(__file__).parent.parent / "eval-repos" / "synthetic"),
+        help="Fixture directory scanned for SAFE markers",
+    )
+    parser.add_argument(
+        "--output",
+        default=str(Path(__file__).parent.parent / "evaluation" / "rule-scores.json"),
+        help="Output JSON path (a .md report is written alongside)",
+    )
+    args = parser.parse_args()
+
+    analysis = load_analysis(args.analysis)
+    ground_truth = load_all_ground_truth(args.ground_truth_dir)
+    safe_lines = collect_safe_lines(Path(args.eval_repos))
+
+    scores = score_rules(analysis, ground_truth, safe_lines)
+    categories = score_categories(analysis, ground_truth)
+    report = generate_report(scores, categories)
+
+    output_path = Path(args.output)
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+    with open(output_path, "w") as f:
+        json.dump(report, f, indent=2)
+    output_path.with_suffix(".md").write_text(generate_report_md(report))
+
+    print(f"Scored {report['summary']['rules_scored']} rules")
+    print(f"Rules with SAFE hits: {report['summary']['rules_with_safe_hits']}")
+    print(f"Report saved to: {output_path}")
+
+
+if __name__ == "__main__":
+    main()
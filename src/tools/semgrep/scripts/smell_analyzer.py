@@ -21,6 +21,12 @@ from datetime import datetime, timezone
 from pathlib import Path
 from typing import Any
 
+# Add src directory to path for common imports
+sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
+
+from common.streaming import ParseDiagnostics, recover_json_array
+from common.tool_env import tool_environment
+
 
 # =============================================================================
 # Terminal Colors and Formatting
@@ -1136,7 +1142,9 @@ def _build_semgrep_env() -> dict[str, str]:
     data_dir.mkdir(parents=True, exist_ok=True)
     cache_dir.mkdir(parents=True, exist_ok=True)
 
-    env = os.environ.copy()
+    # Pinned locale/timezone keeps semgrep's output formatting identical
+    # across agents.
+    env = tool_environment()
     env.setdefault("SEMGREP_SEND_METRICS", "off")
     env.setdefault("SEMGREP_METRICS", "off")
     env.setdefault("SEMGREP_DISABLE_VERSION_CHECK", "1")
@@ -1278,8 +1286,20 @@ def run_semgrep(
 
     cmd.append(target_path)
 
+    report_fd, report_name = tempfile.mkstemp(suffix=".json", prefix="semgrep-report-")
+    os.close(report_fd)
+    report_path = Path(report_name)
+
     def _run_command(command: list[str]) -> subprocess.CompletedProcess:
-        return subprocess.run(command, capture_output=True, text=True, env=_build_semgrep_env())
+        # Semgrep writes the JSON report straight to a temp file so a
+        # multi-GB result never lands in a Python string; stderr is
+        # still captured for the fallback heuristics below.
+        return subprocess.run(
+            command + ["--output", str(report_path)],
+            capture_output=True,
+            text=True,
+            env=_build_semgrep_env(),
+        )
 
     def _is_cert_error(stderr: str) -> bool:
         lowered = stderr.lower()
@@ -1345,15 +1365,23 @@ def run_semgrep(
             local_cmd = _build_local_command(include_community=False)
             result = _run_command(local_cmd)
 
+    # Stream the report instead of loading it whole: findings are
+    # decoded one at a time with a bounded buffer, and recovery parsing
+    # salvages whatever complete records a truncated or stderr-polluted
+    # report still contains.
+    diagnostics = ParseDiagnostics()
     try:
-        parsed = json.loads(result.stdout)
-    except json.JSONDecodeError:
+        results = list(recover_json_array(report_path, "results", diagnostics))
+        error_entries = list(recover_json_array(report_path, "errors", diagnostics))
+    except OSError:
         if result.returncode not in (0, 1):  # 1 means findings were found
             print(f"Semgrep error: {result.stderr}", file=sys.stderr)
-        return {"results": [], "errors": []}
-    if result.returncode not in (0, 1) and parsed.get("errors"):
+        return {"results": [], "errors": [], "parse_diagnostics": diagnostics.to_dict()}
+    finally:
+        report_path.unlink(missing_ok=True)
+    if result.returncode not in (0, 1) and (error_entries or not diagnostics.clean):
         print(f"Semgrep error: {result.stderr}", file=sys.stderr)
-    return parsed
+    return {"results": results, "errors": error_entries, "parse_diagnostics": diagnostics.to_dict()}
 
 
 def map_rule_to_smell(rule_id: str, metadata: dict | None = None) -> tuple[str, str]:
@@ -0,0 +1,222 @@
+"""Tests for scripts/rule_scoring.py - per-rule precision/recall scoring.
+
+Covers:
+- collect_safe_lines marker regions
+- score_rules TP/FP/FN attribution
+- SAFE hit tracking
+- score_categories per-category confusion matrices
+- generate_report / generate_report_md rendering
+"""
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).parent.parent.parent / "scripts"))
+
+from rule_scoring import (
+    RuleScore,
+    collect_safe_lines,
+    generate_report,
+    generate_report_md,
+    score_categories,
+    score_rules,
+)
+
+
+def _analysis(smells_by_file: dict[str, list[dict]]) -> dict:
+    return {
+        "files": [
+            {"path": f"eval-repos/synthetic/python/{filename}", "smells": smells}
+            for filename, smells in smells_by_file.items()
+        ],
+    }
+
+
+def _ground_truth(expected_by_file: dict[str, list[dict]]) -> dict[str, dict]:
+    return {
+        "python": {
+            "files": {
+                filename: {"expected_smells": expected}
+                for filename, expected in expected_by_file.items()
+            },
+        },
+    }
+
+
+class TestCollectSafeLines:
+    def test_marker_covers_following_lines(self, tmp_path: Path) -> None:
+        fixture = tmp_path / "sql_injection.rs"
+        fixture.write_text("bad()\n// SAFE: parameterized\nquery(params)\n")
+
+        safe = collect_safe_lines(tmp_path)
+
+        assert 2 in safe["sql_injection.rs"]
+        assert 3 in safe["sql_injection.rs"]
+        assert 1 not in safe["sql_injection.rs"]
+
+    def test_missing_directory_yields_nothing(self, tmp_path: Path) -> None:
+        assert collect_safe_lines(tmp_path / "nope") == {}
+
+
+class TestScoreRules:
+    def test_true_positive_within_tolerance(self) -> None:
+        analysis = _analysis({
+            "empty_catch.py": [
+                {"rule_id": "DD-D1", "dd_smell_id": "D1_EMPTY_CATCH", "line_start": 12},
+            ],
+        })
+        ground_truth = _ground_truth({
+            "empty_catch.py": [
+                {"smell_id": "D1_EMPTY_CATCH", "count": 1, "lines": [11]},
+            ],
+        })
+
+        scores = score_rules(analysis, ground_truth)
+
+        assert scores["DD-D1"].true_positives == 1
+        assert scores["DD-D1"].false_positives == 0
+        assert scores["DD-D1"].precision == 1.0
+
+    def test_false_positive_and_negative(self) -> None:
+        analysis = _analysis({
+            "empty_catch.py": [
+                {"rule_id": "DD-D1", "dd_smell_id": "D1_EMPTY_CATCH", "line_start": 90},
+            ],
+        })
+        ground_truth = _ground_truth({
+            "empty_catch.py": [
+                {"smell_id": "D1_EMPTY_CATCH", "count": 1, "lines": [11]},
+            ],
+        })
+
+        scores = score_rules(analysis, ground_truth)
+
+        assert scores["DD-D1"].false_positives == 1
+        assert scores["DD-D1"].false_negatives == 1
+        assert scores["DD-D1"].recall == 0.0
+
+    def test_duplicate_detections_claim_line_once(self) -> None:
+        analysis = _analysis({
+            "empty_catch.py": [
+                {"rule_id": "DD-D1", "dd_smell_id": "D1_EMPTY_CATCH", "line_start": 11},
+                {"rule_id": "DD-D1", "dd_smell_id": "D1_EMPTY_CATCH", "line_start": 11},
+            ],
+        })
+        ground_truth = _ground_truth({
+            "empty_catch.py": [
+                {"smell_id": "D1_EMPTY_CATCH", "count": 1, "lines": [11]},
+            ],
+        })
+
+        scores = score_rules(analysis, ground_truth)
+
+        assert scores["DD-D1"].true_positives == 1
+        assert scores["DD-D1"].false_positives == 1
+
+    def test_unfired_smell_tracked_as_placeholder(self) -> None:
+        analysis = _analysis({"empty_catch.py": []})
+        ground_truth = _ground_truth({
+            "empty_catch.py": [
+                {"smell_id": "D1_EMPTY_CATCH", "count": 2, "lines": [11, 22]},
+            ],
+        })
+
+        scores = score_rules(analysis, ground_truth)
+
+        assert scores["<unfired:D1_EMPTY_CATCH>"].false_negatives == 2
+
+    def test_safe_hits_recorded(self) -> None:
+        analysis = _analysis({
+            "sql_injection.py": [
+                {"rule_id": "sqli-rule", "dd_smell_id": "SQL_INJECTION", "line_start": 48},
+            ],
+        })
+        ground_truth = _ground_truth({
+            "sql_injection.py": [
+                {"smell_id": "SQL_INJECTION", "count": 1, "lines": [13]},
+            ],
+        })
+        safe_lines = {"sql_injection.py": {47, 48, 49}}
+
+        scores = score_rules(analysis, ground_truth, safe_lines)
+
+        assert scores["sqli-rule"].safe_hits == [
+            {"file": "sql_injection.py", "line": 48, "smell_id": "SQL_INJECTION"},
+        ]
+
+
+class TestScoreCategories:
+    def test_counts_split_by_path_category(self) -> None:
+        analysis = {
+            "files": [
+                {
+                    "path": "eval-repos/synthetic/python/empty_catch.py",
+                    "smells": [
+                        {"rule_id": "DD-D1", "dd_smell_id": "D1_EMPTY_CATCH", "line_start": 11},
+                    ],
+                },
+                {
+                    "path": "eval-repos/synthetic/python/tests/test_config.py",
+                    "smells": [
+                        {"rule_id": "DD-S1", "dd_smell_id": "HARDCODED_SECRET", "line_start": 90},
+                    ],
+                },
+            ],
+        }
+        ground_truth = _ground_truth({
+            "empty_catch.py": [
+                {"smell_id": "D1_EMPTY_CATCH", "count": 1, "lines": [11]},
+            ],
+            "test_config.py": [
+                {"smell_id": "HARDCODED_SECRET", "count": 1, "lines": [5]},
+            ],
+        })
+
+        categories = score_categories(analysis, ground_truth)
+
+        assert categories["src"].true_positives == 1
+        assert categories["src"].false_positives == 0
+        assert categories["tests"].false_positives == 1
+        assert categories["tests"].false_negatives == 1
+
+
+class TestReport:
+    def _scores(self) -> dict[str, RuleScore]:
+        noisy = RuleScore(rule_id="noisy-rule")
+        noisy.smell_ids.add("SQL_INJECTION")
+        noisy.false_positives = 3
+        noisy.safe_hits.append({"file": "sql_injection.rs", "line": 48, "smell_id": "SQL_INJECTION"})
+        clean = RuleScore(rule_id="clean-rule")
+        clean.smell_ids.add("D1_EMPTY_CATCH")
+        clean.true_positives = 4
+        return {"noisy-rule": noisy, "clean-rule": clean}
+
+    def test_noisy_rules_sorted_first_and_flagged(self) -> None:
+        report = generate_report(self._scores())
+
+        assert report["rules"][0]["rule_id"] == "noisy-rule"
+        assert report["summary"]["pruning_candidates"] == ["noisy-rule"]
+        assert report["summary"]["rules_with_safe_hits"] == 1
+
+    def test_markdown_highlights_safe_hits(self) -> None:
+        markdown = generate_report_md(generate_report(self._scores()))
+
+        assert "## Rules firing on SAFE-marked code" in markdown
+        assert "`noisy-rule` at sql_injection.rs:48" in markdown
+        assert "| clean-rule |" in markdown
+
+    def test_confusion_section_included_when_categories_given(self) -> None:
+        from shared.evaluation.confusion import ConfusionMatrix
+
+        categories = {
+            "src": ConfusionMatrix(true_positives=3, false_negatives=1),
+            "tests": ConfusionMatrix(false_positives=2),
+        }
+        report = generate_report(self._scores(), categories)
+        markdown = generate_report_md(report)
+
+        assert report["confusion"]["by_category"]["src"]["recall"] == 0.75
+        assert report["confusion"]["overall"]["false_positives"] == 2
+        assert "## Per-category confusion" in markdown
+        assert "| tests |" in markdown
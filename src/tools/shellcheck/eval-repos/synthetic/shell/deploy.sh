@@ -0,0 +1,18 @@
+#!/bin/bash
+# Deployment helper with deliberate quoting mistakes for eval.
+
+TARGET_DIR=$1
+ARTIFACTS=$(ls build/*.tar.gz)
+
+# SC2086: unquoted expansions undergo word splitting
+cp $ARTIFACTS $TARGET_DIR
+
+# SC2002: useless use of cat
+cat config/deploy.env | grep -v '^#' > /tmp/deploy.env
+
+# SC2086 again inside a loop
+for artifact in $ARTIFACTS; do
+    tar -xzf $artifact -C $TARGET_DIR
+done
+
+echo "Deployed to $TARGET_DIR"
@@ -0,0 +1,12 @@
+#!/bin/bash
+# Negative control: idiomatic script with no expected findings.
+
+set -euo pipefail
+
+main() {
+    local target_dir="$1"
+    mkdir -p "$target_dir"
+    printf 'Prepared %s\n' "$target_dir"
+}
+
+main "$@"
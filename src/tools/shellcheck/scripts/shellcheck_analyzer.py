@@ -0,0 +1,296 @@
+"""ShellCheck shell script analysis wrapper.
+
+Discovers shell scripts in a repository (by extension and shebang), runs
+ShellCheck against them in one batch, maps SC codes to DD categories, and
+aggregates findings per file and per directory.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# ShellCheck SC code -> DD category.
+# Grouped by the failure mode the code family describes.
+CODE_TO_CATEGORY_MAP: dict[int, str] = {
+    # Unquoted expansions (word splitting / globbing surprises)
+    2046: "unquoted_expansion",
+    2048: "unquoted_expansion",
+    2068: "unquoted_expansion",
+    2086: "unquoted_expansion",
+    # Deprecated or legacy syntax
+    2006: "deprecated_syntax",
+    # Missing error handling
+    2015: "error_handling",
+    2164: "error_handling",
+    # Dead or useless code
+    2002: "useless_command",
+    2034: "unused_variable",
+    # Injection-adjacent patterns
+    2059: "format_injection",
+    2091: "command_misuse",
+    2092: "command_misuse",
+    # Sourcing files that cannot be statically resolved
+    1090: "dynamic_source",
+    1091: "dynamic_source",
+    # Dangerous expansions in destructive commands
+    2115: "dangerous_expansion",
+}
+
+DEFAULT_CATEGORY = "shell_misc"
+
+# ShellCheck levels -> Caldera's severity vocabulary.
+LEVEL_TO_SEVERITY = {
+    "error": "HIGH",
+    "warning": "MEDIUM",
+    "info": "LOW",
+    "style": "LOW",
+}
+
+SCRIPT_SUFFIXES = {".sh", ".bash", ".ksh"}
+
+SHEBANG_SHELLS = ("sh", "bash", "ksh", "dash")
+
+
+@dataclass(frozen=True)
+class ShellFinding:
+    """A single ShellCheck comment."""
+    rule_id: str
+    level: str
+    dd_category: str
+    file_path: str
+    line_start: int
+    line_end: int
+    column_start: int | None
+    column_end: int | None
+    severity: str
+    message: str
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of ShellCheck findings."""
+    path: str
+    lines: int
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    issues: list[ShellFinding] = field(default_factory=list)
+
+    @property
+    def issue_density(self) -> float:
+        if self.lines <= 0:
+            return 0.0
+        return self.issue_count / self.lines * 100
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete ShellCheck analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    shellcheck_version: str
+    findings: list[ShellFinding] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    analysis_duration_ms: int = 0
+
+
+def get_shellcheck_version() -> str:
+    """Return the installed ShellCheck version, or 'unknown'."""
+    try:
+        result = subprocess.run(
+            ["shellcheck", "--version"],
+            capture_output=True,
+            text=True,
+            timeout=30,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return "unknown"
+    # Output contains a line like "version: 0.9.0"
+    for line in (result.stdout or "").splitlines():
+        if line.startswith("version:"):
+            return line.split(":", 1)[1].strip()
+    return "unknown"
+
+
+def _has_shell_shebang(path: Path) -> bool:
+    try:
+        with path.open("r", encoding="utf-8", errors="replace") as handle:
+            first_line = handle.readline()
+    except OSError:
+        return False
+    if not first_line.startswith("#!"):
+        return False
+    interpreter = first_line[2:].strip().split()
+    if not interpreter:
+        return False
+    command = Path(interpreter[0]).name
+    if command == "env" and len(interpreter) > 1:
+        command = Path(interpreter[1]).name
+    return command in SHEBANG_SHELLS
+
+
+def discover_scripts(repo_path: Path) -> list[str]:
+    """Find shell scripts by extension or shebang, as repo-relative paths."""
+    scripts: list[str] = []
+    for path in sorted(repo_path.rglob("*")):
+        if not path.is_file() or ".git" in path.parts:
+            continue
+        if path.suffix in SCRIPT_SUFFIXES or (not path.suffix and _has_shell_shebang(path)):
+            scripts.append(path.relative_to(repo_path).as_posix())
+    return scripts
+
+
+def run_shellcheck(repo_path: Path, scripts: list[str]) -> list[dict]:
+    """Run shellcheck on the given scripts and return raw comment entries.
+
+    Scripts are passed relative to repo_path so reported file paths come
+    back repo-relative. ShellCheck exits 1 when issues are found, so only
+    exit codes >= 2 are treated as execution failures.
+    """
+    if not scripts:
+        return []
+    result = subprocess.run(
+        ["shellcheck", "--format=json1", "--severity=style", *scripts],
+        capture_output=True,
+        text=True,
+        timeout=1800,
+        cwd=repo_path,
+    )
+    if result.returncode >= 2:
+        raise RuntimeError(
+            f"shellcheck failed (exit {result.returncode}): {result.stderr.strip()}"
+        )
+    report = json.loads(result.stdout or "{}")
+    return report.get("comments", [])
+
+
+def map_comment(raw: dict) -> ShellFinding:
+    """Map one raw ShellCheck comment to a ShellFinding."""
+    code = raw.get("code", 0)
+    level = raw.get("level", "style")
+    return ShellFinding(
+        rule_id=f"SC{code}",
+        level=level,
+        dd_category=CODE_TO_CATEGORY_MAP.get(code, DEFAULT_CATEGORY),
+        file_path=raw.get("file", ""),
+        line_start=raw.get("line", 1),
+        line_end=raw.get("endLine", raw.get("line", 1)),
+        column_start=raw.get("column"),
+        column_end=raw.get("endColumn"),
+        severity=LEVEL_TO_SEVERITY.get(level, "LOW"),
+        message=raw.get("message", ""),
+    )
+
+
+def _count_lines(path: Path) -> int:
+    try:
+        return len(path.read_text(encoding="utf-8", errors="replace").splitlines())
+    except OSError:
+        return 0
+
+
+def build_file_stats(
+    findings: list[ShellFinding], scripts: list[str], repo_path: Path
+) -> list[FileStats]:
+    """Aggregate findings per shell script, including clean scripts."""
+    by_file: dict[str, FileStats] = {}
+    for rel in scripts:
+        by_file[rel] = FileStats(path=rel, lines=_count_lines(repo_path / rel))
+
+    for finding in findings:
+        stats = by_file.setdefault(finding.file_path, FileStats(path=finding.file_path, lines=0))
+        stats.issue_count += 1
+        stats.by_category[finding.dd_category] = stats.by_category.get(finding.dd_category, 0) + 1
+        stats.by_severity[finding.severity] = stats.by_severity.get(finding.severity, 0) + 1
+        stats.issues.append(finding)
+    return list(by_file.values())
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Compute direct and recursive rollups for every ancestor directory."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    for stats in files:
+        parent = str(Path(stats.path).parent.as_posix())
+        if parent == ".":
+            parent = "."
+        _accumulate(direct[parent], stats)
+        ancestors = [parent]
+        while parent not in (".", ""):
+            parent = str(Path(parent).parent.as_posix())
+            ancestors.append(parent)
+        for ancestor in ancestors:
+            _accumulate(recursive[ancestor], stats)
+
+    entries = []
+    for path in sorted(recursive):
+        entries.append(
+            DirectoryEntry(
+                path=path,
+                direct=direct.get(path, DirectoryStats()),
+                recursive=recursive[path],
+            )
+        )
+    return entries
+
+
+def _accumulate(target: DirectoryStats, stats: FileStats) -> None:
+    target.file_count += 1
+    target.issue_count += stats.issue_count
+    for category, count in stats.by_category.items():
+        target.by_category[category] = target.by_category.get(category, 0) + count
+    for severity, count in stats.by_severity.items():
+        target.by_severity[severity] = target.by_severity.get(severity, 0) + count
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Run ShellCheck and build the full aggregated analysis result."""
+    start = time.perf_counter()
+    scripts = discover_scripts(repo_path)
+    findings = [map_comment(raw) for raw in run_shellcheck(repo_path, scripts)]
+    files = build_file_stats(findings, scripts, repo_path)
+    directories = build_directory_stats(files)
+
+    by_category: dict[str, int] = {}
+    by_severity: dict[str, int] = {}
+    for finding in findings:
+        by_category[finding.dd_category] = by_category.get(finding.dd_category, 0) + 1
+        by_severity[finding.severity] = by_severity.get(finding.severity, 0) + 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        shellcheck_version=get_shellcheck_version(),
+        findings=findings,
+        files=files,
+        directories=directories,
+        by_category=by_category,
+        by_severity=by_severity,
+        analysis_duration_ms=int((time.perf_counter() - start) * 1000),
+    )
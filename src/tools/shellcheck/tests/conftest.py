@@ -0,0 +1,11 @@
+"""Pytest configuration for ShellCheck tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add shellcheck tool directory to path so 'scripts' can be imported as a package
+shellcheck_root = Path(__file__).parent.parent
+sys.path.insert(0, str(shellcheck_root))
+sys.path.insert(0, str(shellcheck_root / "scripts"))
@@ -0,0 +1,103 @@
+"""Unit tests for shellcheck_analyzer discovery, mapping, and aggregation."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from shellcheck_analyzer import (
+    CODE_TO_CATEGORY_MAP,
+    DEFAULT_CATEGORY,
+    build_directory_stats,
+    build_file_stats,
+    discover_scripts,
+    map_comment,
+)
+
+
+def _raw_comment(**overrides) -> dict:
+    raw = {
+        "file": "scripts/deploy.sh",
+        "line": 8,
+        "endLine": 8,
+        "column": 4,
+        "endColumn": 14,
+        "level": "info",
+        "code": 2086,
+        "message": "Double quote to prevent globbing and word splitting.",
+    }
+    raw.update(overrides)
+    return raw
+
+
+def test_map_comment_maps_core_fields() -> None:
+    finding = map_comment(_raw_comment())
+
+    assert finding.rule_id == "SC2086"
+    assert finding.dd_category == "unquoted_expansion"
+    assert finding.line_start == 8
+    assert finding.line_end == 8
+    assert finding.column_start == 4
+    assert finding.severity == "LOW"
+    assert finding.level == "info"
+
+
+def test_map_comment_unknown_code_falls_back_to_misc() -> None:
+    finding = map_comment(_raw_comment(code=9999))
+    assert finding.dd_category == DEFAULT_CATEGORY
+
+
+def test_map_comment_level_to_severity() -> None:
+    assert map_comment(_raw_comment(level="error")).severity == "HIGH"
+    assert map_comment(_raw_comment(level="warning")).severity == "MEDIUM"
+    assert map_comment(_raw_comment(level="style")).severity == "LOW"
+
+
+def test_category_map_covers_eval_scenarios() -> None:
+    """Codes exercised by the synthetic eval corpus must be mapped."""
+    assert CODE_TO_CATEGORY_MAP[2086] == "unquoted_expansion"
+    assert CODE_TO_CATEGORY_MAP[2002] == "useless_command"
+    assert CODE_TO_CATEGORY_MAP[2006] == "deprecated_syntax"
+    assert CODE_TO_CATEGORY_MAP[2034] == "unused_variable"
+    assert CODE_TO_CATEGORY_MAP[2164] == "error_handling"
+
+
+def test_discover_scripts_by_extension_and_shebang(tmp_path: Path) -> None:
+    (tmp_path / "scripts").mkdir()
+    (tmp_path / "scripts" / "build.sh").write_text("#!/bin/bash\necho ok\n")
+    (tmp_path / "scripts" / "run").write_text("#!/usr/bin/env bash\necho ok\n")
+    (tmp_path / "scripts" / "notes.txt").write_text("not a script\n")
+    (tmp_path / "main.py").write_text("#!/usr/bin/env python3\n")
+
+    scripts = discover_scripts(tmp_path)
+
+    assert scripts == ["scripts/build.sh", "scripts/run"]
+
+
+def test_build_file_stats_includes_clean_scripts(tmp_path: Path) -> None:
+    (tmp_path / "dirty.sh").write_text("#!/bin/sh\ncp $1 /tmp\n")
+    (tmp_path / "clean.sh").write_text("#!/bin/sh\necho ok\n")
+
+    finding = map_comment(_raw_comment(file="dirty.sh"))
+    files = build_file_stats([finding], ["dirty.sh", "clean.sh"], tmp_path)
+
+    by_path = {f.path: f for f in files}
+    assert by_path["dirty.sh"].issue_count == 1
+    assert by_path["dirty.sh"].by_category == {"unquoted_expansion": 1}
+    assert by_path["clean.sh"].issue_count == 0
+
+
+def test_build_directory_stats_recursive_gte_direct(tmp_path: Path) -> None:
+    (tmp_path / "ci" / "nested").mkdir(parents=True)
+    (tmp_path / "ci" / "a.sh").write_text("#!/bin/sh\necho ok\n")
+    (tmp_path / "ci" / "nested" / "b.sh").write_text("#!/bin/sh\ncp $1 /tmp\n")
+
+    finding = map_comment(_raw_comment(file="ci/nested/b.sh"))
+    files = build_file_stats([finding], ["ci/a.sh", "ci/nested/b.sh"], tmp_path)
+    directories = build_directory_stats(files)
+
+    by_path = {d.path: d for d in directories}
+    ci = by_path["ci"]
+    assert ci.recursive.issue_count >= ci.direct.issue_count
+    assert ci.recursive.issue_count == 1
+    assert ci.direct.issue_count == 0
+    assert by_path["ci/nested"].direct.issue_count == 1
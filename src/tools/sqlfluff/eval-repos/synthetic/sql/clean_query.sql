@@ -0,0 +1,7 @@
+-- Negative control - clean ANSI SQL with no expected findings.
+SELECT
+    users.id,
+    users.name
+FROM users
+WHERE users.email IS NOT NULL
+ORDER BY users.id;
@@ -0,0 +1,9 @@
+-- Migration with mixed keyword capitalisation (CP01) and SELECT * (AM04).
+CREATE TABLE orders (
+    id INTEGER PRIMARY KEY,
+    user_id integer,
+    amount decimal(10, 2),
+    Status varchar(20)
+);
+
+select * from orders;
@@ -0,0 +1,10 @@
+-- Schema definition with deliberate lint violations:
+-- lowercase keywords (CP01), implicit aliasing (AL01), trailing whitespace (LT01).
+create table users (
+    id integer primary key,
+    name varchar(100),
+    email varchar(255),
+    created_at timestamp
+);
+
+select u.id, u.name from users u where u.email is not null;
@@ -0,0 +1,282 @@
+"""SQLFluff SQL analysis wrapper.
+
+Discovers SQL files (including migration directories), lints them with
+SQLFluff under a configurable dialect, maps rule codes to DD categories,
+and aggregates findings per file and per directory. Closes the gap where
+schema and migration code was completely unscanned.
+"""
+
+from __future__ import annotations
+
+import json
+import subprocess
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from pathlib import Path
+
+# SQLFluff rule code prefix -> DD category.
+# Codes look like LT01, CP02, RF03; the prefix names the rule bundle.
+PREFIX_TO_CATEGORY_MAP: dict[str, str] = {
+    "LT": "formatting",       # layout
+    "CP": "style",            # capitalisation
+    "AL": "aliasing",
+    "RF": "reference_error",  # references
+    "AM": "ambiguous_sql",    # ambiguous constructs
+    "ST": "structure",
+    "CV": "convention",
+    "JJ": "templating",       # jinja
+}
+
+DEFAULT_CATEGORY = "sql_misc"
+
+# Parse/templating failures mean the file could not be analyzed at all.
+CRITICAL_CODES = {"PRS", "TMP"}
+
+DEFAULT_DIALECT = "ansi"
+
+SQL_SUFFIXES = {".sql", ".ddl", ".dml"}
+
+
+@dataclass(frozen=True)
+class SqlFinding:
+    """A single SQLFluff violation."""
+    rule_id: str
+    rule_name: str | None
+    dd_category: str
+    file_path: str
+    line_start: int
+    line_end: int
+    column_start: int | None
+    severity: str
+    message: str
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of SQLFluff findings."""
+    path: str
+    lines: int
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    issues: list[SqlFinding] = field(default_factory=list)
+
+    @property
+    def issue_density(self) -> float:
+        if self.lines <= 0:
+            return 0.0
+        return self.issue_count / self.lines * 100
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    issue_count: int = 0
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete SQLFluff analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    sqlfluff_version: str
+    dialect: str
+    findings: list[SqlFinding] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_category: dict[str, int] = field(default_factory=dict)
+    by_severity: dict[str, int] = field(default_factory=dict)
+    analysis_duration_ms: int = 0
+
+
+def get_sqlfluff_version() -> str:
+    """Return the installed SQLFluff version, or 'unknown'."""
+    try:
+        result = subprocess.run(
+            ["sqlfluff", "--version"],
+            capture_output=True,
+            text=True,
+            timeout=30,
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return "unknown"
+    # Output looks like "sqlfluff, version 3.1.0"
+    parts = (result.stdout or "").split()
+    return parts[-1] if parts else "unknown"
+
+
+def discover_sql_files(repo_path: Path) -> list[str]:
+    """Find SQL files by extension, as repo-relative paths.
+
+    Migration directories need no special casing: their `.sql` files are
+    picked up by the same walk.
+    """
+    files: list[str] = []
+    for path in sorted(repo_path.rglob("*")):
+        if not path.is_file() or ".git" in path.parts:
+            continue
+        if path.suffix.lower() in SQL_SUFFIXES:
+            files.append(path.relative_to(repo_path).as_posix())
+    return files
+
+
+def run_sqlfluff(repo_path: Path, sql_files: list[str], dialect: str) -> list[dict]:
+    """Run sqlfluff on the given files and return raw per-file entries.
+
+    Files are passed relative to repo_path so reported paths come back
+    repo-relative. A `.sqlfluff` config in the repository still takes
+    precedence over the CLI dialect, matching SQLFluff's own resolution.
+    SQLFluff exits 1 when violations are found, so only other non-zero
+    exit codes are treated as execution failures.
+    """
+    if not sql_files:
+        return []
+    result = subprocess.run(
+        ["sqlfluff", "lint", "--format", "json", "--dialect", dialect, *sql_files],
+        capture_output=True,
+        text=True,
+        timeout=1800,
+        cwd=repo_path,
+    )
+    if result.returncode not in (0, 1, 65):
+        raise RuntimeError(
+            f"sqlfluff failed (exit {result.returncode}): {result.stderr.strip()}"
+        )
+    return json.loads(result.stdout or "[]")
+
+
+def categorize_rule(code: str) -> str:
+    """Map a SQLFluff rule code to its DD category by prefix."""
+    prefix = code[:2].upper() if code else ""
+    return PREFIX_TO_CATEGORY_MAP.get(prefix, DEFAULT_CATEGORY)
+
+
+def map_violation(file_path: str, raw: dict) -> SqlFinding:
+    """Map one raw SQLFluff violation to a SqlFinding."""
+    code = raw.get("code", "")
+    if code in CRITICAL_CODES:
+        severity = "HIGH"
+    elif raw.get("warning"):
+        severity = "LOW"
+    else:
+        severity = "MEDIUM"
+    line = raw.get("start_line_no", raw.get("line_no", 1))
+    return SqlFinding(
+        rule_id=code,
+        rule_name=raw.get("name") or None,
+        dd_category=categorize_rule(code),
+        file_path=file_path,
+        line_start=line,
+        line_end=raw.get("end_line_no", line),
+        column_start=raw.get("start_line_pos", raw.get("line_pos")),
+        severity=severity,
+        message=raw.get("description", ""),
+    )
+
+
+def _count_lines(path: Path) -> int:
+    try:
+        return len(path.read_text(encoding="utf-8", errors="replace").splitlines())
+    except OSError:
+        return 0
+
+
+def build_file_stats(
+    findings: list[SqlFinding], sql_files: list[str], repo_path: Path
+) -> list[FileStats]:
+    """Aggregate findings per SQL file, including clean files."""
+    by_file: dict[str, FileStats] = {}
+    for rel in sql_files:
+        by_file[rel] = FileStats(path=rel, lines=_count_lines(repo_path / rel))
+
+    for finding in findings:
+        stats = by_file.setdefault(finding.file_path, FileStats(path=finding.file_path, lines=0))
+        stats.issue_count += 1
+        stats.by_category[finding.dd_category] = stats.by_category.get(finding.dd_category, 0) + 1
+        stats.by_severity[finding.severity] = stats.by_severity.get(finding.severity, 0) + 1
+        stats.issues.append(finding)
+    return list(by_file.values())
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Compute direct and recursive rollups for every ancestor directory."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    for stats in files:
+        parent = str(Path(stats.path).parent.as_posix())
+        if parent == ".":
+            parent = "."
+        _accumulate(direct[parent], stats)
+        ancestors = [parent]
+        while parent not in (".", ""):
+            parent = str(Path(parent).parent.as_posix())
+            ancestors.append(parent)
+        for ancestor in ancestors:
+            _accumulate(recursive[ancestor], stats)
+
+    entries = []
+    for path in sorted(recursive):
+        entries.append(
+            DirectoryEntry(
+                path=path,
+                direct=direct.get(path, DirectoryStats()),
+                recursive=recursive[path],
+            )
+        )
+    return entries
+
+
+def _accumulate(target: DirectoryStats, stats: FileStats) -> None:
+    target.file_count += 1
+    target.issue_count += stats.issue_count
+    for category, count in stats.by_category.items():
+        target.by_category[category] = target.by_category.get(category, 0) + count
+    for severity, count in stats.by_severity.items():
+        target.by_severity[severity] = target.by_severity.get(severity, 0) + count
+
+
+def analyze_repository(
+    repo_path: Path, repo_name: str, dialect: str = DEFAULT_DIALECT
+) -> AnalysisResult:
+    """Run SQLFluff and build the full aggregated analysis result."""
+    start = time.perf_counter()
+    sql_files = discover_sql_files(repo_path)
+    findings: list[SqlFinding] = []
+    for entry in run_sqlfluff(repo_path, sql_files, dialect):
+        file_path = Path(entry.get("filepath", "")).as_posix()
+        for raw in entry.get("violations", []):
+            findings.append(map_violation(file_path, raw))
+    files = build_file_stats(findings, sql_files, repo_path)
+    directories = build_directory_stats(files)
+
+    by_category: dict[str, int] = {}
+    by_severity: dict[str, int] = {}
+    for finding in findings:
+        by_category[finding.dd_category] = by_category.get(finding.dd_category, 0) + 1
+        by_severity[finding.severity] = by_severity.get(finding.severity, 0) + 1
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        sqlfluff_version=get_sqlfluff_version(),
+        dialect=dialect,
+        findings=findings,
+        files=files,
+        directories=directories,
+        by_category=by_category,
+        by_severity=by_severity,
+        analysis_duration_ms=int((time.perf_counter() - start) * 1000),
+    )
@@ -0,0 +1,11 @@
+"""Pytest configuration for SQLFluff tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add sqlfluff tool directory to path so 'scripts' can be imported as a package
+sqlfluff_root = Path(__file__).parent.parent
+sys.path.insert(0, str(sqlfluff_root))
+sys.path.insert(0, str(sqlfluff_root / "scripts"))
@@ -0,0 +1,108 @@
+"""Unit tests for sqlfluff_analyzer discovery, mapping, and aggregation."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from sqlfluff_analyzer import (
+    DEFAULT_CATEGORY,
+    PREFIX_TO_CATEGORY_MAP,
+    build_directory_stats,
+    build_file_stats,
+    categorize_rule,
+    discover_sql_files,
+    map_violation,
+)
+
+
+def _raw_violation(**overrides) -> dict:
+    raw = {
+        "start_line_no": 3,
+        "start_line_pos": 1,
+        "code": "CP01",
+        "name": "capitalisation.keywords",
+        "description": "Keywords must be consistently upper case.",
+        "warning": False,
+    }
+    raw.update(overrides)
+    return raw
+
+
+def test_map_violation_maps_core_fields() -> None:
+    finding = map_violation("sql/schema.sql", _raw_violation())
+
+    assert finding.rule_id == "CP01"
+    assert finding.rule_name == "capitalisation.keywords"
+    assert finding.dd_category == "style"
+    assert finding.file_path == "sql/schema.sql"
+    assert finding.line_start == 3
+    assert finding.column_start == 1
+    assert finding.severity == "MEDIUM"
+
+
+def test_map_violation_warning_is_low_severity() -> None:
+    finding = map_violation("a.sql", _raw_violation(warning=True))
+    assert finding.severity == "LOW"
+
+
+def test_map_violation_parse_error_is_high_severity() -> None:
+    finding = map_violation("a.sql", _raw_violation(code="PRS", name=None))
+    assert finding.severity == "HIGH"
+    assert finding.rule_name is None
+
+
+def test_categorize_rule_by_prefix() -> None:
+    assert categorize_rule("LT01") == "formatting"
+    assert categorize_rule("RF03") == "reference_error"
+    assert categorize_rule("AM04") == "ambiguous_sql"
+    assert categorize_rule("ZZ99") == DEFAULT_CATEGORY
+    assert categorize_rule("") == DEFAULT_CATEGORY
+
+
+def test_category_map_covers_eval_scenarios() -> None:
+    """Prefixes exercised by the synthetic eval corpus must be mapped."""
+    assert PREFIX_TO_CATEGORY_MAP["CP"] == "style"
+    assert PREFIX_TO_CATEGORY_MAP["AL"] == "aliasing"
+    assert PREFIX_TO_CATEGORY_MAP["AM"] == "ambiguous_sql"
+
+
+def test_discover_sql_files_by_extension(tmp_path: Path) -> None:
+    (tmp_path / "migrations").mkdir()
+    (tmp_path / "schema.sql").write_text("SELECT 1;\n")
+    (tmp_path / "migrations" / "001_init.sql").write_text("SELECT 1;\n")
+    (tmp_path / "tables.DDL").write_text("SELECT 1;\n")
+    (tmp_path / "notes.txt").write_text("not sql\n")
+
+    files = discover_sql_files(tmp_path)
+
+    assert files == ["migrations/001_init.sql", "schema.sql", "tables.DDL"]
+
+
+def test_build_file_stats_includes_clean_files(tmp_path: Path) -> None:
+    (tmp_path / "dirty.sql").write_text("select 1;\n")
+    (tmp_path / "clean.sql").write_text("SELECT 1;\n")
+
+    finding = map_violation("dirty.sql", _raw_violation())
+    files = build_file_stats([finding], ["dirty.sql", "clean.sql"], tmp_path)
+
+    by_path = {f.path: f for f in files}
+    assert by_path["dirty.sql"].issue_count == 1
+    assert by_path["dirty.sql"].by_category == {"style": 1}
+    assert by_path["clean.sql"].issue_count == 0
+
+
+def test_build_directory_stats_recursive_gte_direct(tmp_path: Path) -> None:
+    (tmp_path / "db" / "migrations").mkdir(parents=True)
+    (tmp_path / "db" / "schema.sql").write_text("SELECT 1;\n")
+    (tmp_path / "db" / "migrations" / "001.sql").write_text("select 1;\n")
+
+    finding = map_violation("db/migrations/001.sql", _raw_violation())
+    files = build_file_stats([finding], ["db/schema.sql", "db/migrations/001.sql"], tmp_path)
+    directories = build_directory_stats(files)
+
+    by_path = {d.path: d for d in directories}
+    db = by_path["db"]
+    assert db.recursive.issue_count >= db.direct.issue_count
+    assert db.recursive.issue_count == 1
+    assert db.direct.issue_count == 0
+    assert by_path["db/migrations"].direct.issue_count == 1
@@ -0,0 +1,6 @@
+"""Negative control: marker-like identifiers but no debt comments."""
+
+
+def build_todo_list(items):
+    todo_list = [item for item in items if not item.get("done")]
+    return todo_list
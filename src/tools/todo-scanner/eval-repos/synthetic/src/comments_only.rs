@@ -0,0 +1,17 @@
+// This file contains only comments
+// No executable code at all
+
+/*
+Multi-line comment block
+This serves as documentation
+But contains no actual code
+*/
+
+// TODO: This is a todo comment
+// FIXME: This needs fixing
+// NOTE: Important note here
+
+// The purpose of this file is to test how tools handle
+// files that have content but no executable code.
+
+// End of file
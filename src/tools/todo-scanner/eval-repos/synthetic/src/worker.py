@@ -0,0 +1,18 @@
+"""Background worker with deliberately planted debt markers."""
+
+import time
+
+
+def process_queue(items):
+    # TODO: batch these instead of one-by-one
+    results = []
+    for item in items:
+        # FIXME: retries are missing, a transient failure drops the item
+        results.append(handle(item))
+    return results
+
+
+def handle(item):
+    # HACK: sleeping to dodge the rate limiter instead of honoring headers
+    time.sleep(0.1)
+    return {"id": item, "status": "done"}
@@ -0,0 +1,138 @@
+#!/usr/bin/env python3
+"""CLI entry point for TODO/FIXME/HACK comment tracking.
+
+Standard wrapper that translates orchestrator CLI args to
+todo_scanner_analyzer and produces Caldera envelope output format.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+# Add shared src to path for imports
+sys.path.insert(0, str(Path(__file__).resolve().parents[3]))
+from common.cli_parser import add_common_args, validate_common_args
+from common.envelope_formatter import create_envelope, get_current_timestamp
+from common.path_normalization import normalize_file_path, normalize_dir_path
+
+from .todo_scanner_analyzer import AnalysisResult, DebtComment, analyze_repository
+
+TOOL_NAME = "todo-scanner"
+TOOL_VERSION = "1.0.0"
+SCHEMA_VERSION = "1.0.0"
+
+
+def _comment_to_dict(comment: DebtComment, repo_root: Path | None = None) -> dict[str, Any]:
+    return {
+        "path": normalize_file_path(comment.file_path, repo_root),
+        "line": comment.line,
+        "marker": comment.marker,
+        "severity": comment.severity,
+        "text": comment.text,
+        "author_email": comment.author_email,
+        "introduced_at": comment.introduced_at,
+        "age_days": comment.age_days,
+    }
+
+
+def result_to_data_dict(result: AnalysisResult, repo_root: Path | None = None) -> dict[str, Any]:
+    """Convert AnalysisResult to the 'data' portion of envelope format."""
+    files = []
+    for f in result.files:
+        files.append({
+            "path": normalize_file_path(f.path, repo_root),
+            "lines": f.lines,
+            "comment_count": f.comment_count,
+            "by_marker": f.by_marker,
+            "oldest_age_days": f.oldest_age_days,
+            "comments": [_comment_to_dict(c, repo_root) for c in f.comments],
+        })
+
+    directories = []
+    for d in result.directories:
+        directories.append({
+            "path": normalize_dir_path(d.path, repo_root),
+            "direct": {
+                "file_count": d.direct.file_count,
+                "comment_count": d.direct.comment_count,
+                "by_marker": d.direct.by_marker,
+                "oldest_age_days": d.direct.oldest_age_days,
+            },
+            "recursive": {
+                "file_count": d.recursive.file_count,
+                "comment_count": d.recursive.comment_count,
+                "by_marker": d.recursive.by_marker,
+                "oldest_age_days": d.recursive.oldest_age_days,
+            },
+        })
+
+    attributed = sum(1 for c in result.comments if c.author_email is not None)
+    return {
+        "tool": TOOL_NAME,
+        "tool_version": TOOL_VERSION,
+        "summary": {
+            "total_files_with_comments": len(result.files),
+            "total_directories": len(result.directories),
+            "total_comments": len(result.comments),
+            "attributed_comments": attributed,
+            "comments_by_marker": result.by_marker,
+            "oldest_age_days": max(
+                (c.age_days for c in result.comments if c.age_days is not None),
+                default=None,
+            ),
+        },
+        "files": files,
+        "directories": directories,
+        "oldest": [_comment_to_dict(c, repo_root) for c in result.oldest],
+        "analysis_duration_ms": result.analysis_duration_ms,
+    }
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Track TODO/FIXME/HACK comments")
+    add_common_args(parser)
+    parser.add_argument(
+        "--json-only",
+        action="store_true",
+        help="Only output JSON, no summary",
+    )
+    args = parser.parse_args()
+
+    common = validate_common_args(args)
+
+    print(f"Analyzing: {common.repo_path}")
+    result = analyze_repository(common.repo_path, common.repo_name)
+
+    print(f"Files with debt comments: {len(result.files)}")
+    print(f"Comments found: {len(result.comments)}")
+    print(f"Duration: {result.analysis_duration_ms}ms")
+
+    data = result_to_data_dict(result, repo_root=common.repo_path)
+    output_dict = create_envelope(
+        data,
+        tool_name=TOOL_NAME,
+        tool_version=TOOL_VERSION,
+        run_id=common.run_id,
+        repo_id=common.repo_id,
+        branch=common.branch,
+        commit=common.commit,
+        timestamp=get_current_timestamp(),
+        schema_version=SCHEMA_VERSION,
+    )
+
+    common.output_path.write_text(json.dumps(output_dict, indent=2, ensure_ascii=False))
+    print(f"Output: {common.output_path}")
+
+    if not args.json_only:
+        for marker in ("FIXME", "HACK", "XXX", "TODO"):
+            count = result.by_marker.get(marker, 0)
+            if count:
+                print(f"  {marker}: {count}")
+
+
+if __name__ == "__main__":
+    main()
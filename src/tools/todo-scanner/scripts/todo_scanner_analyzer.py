@@ -0,0 +1,295 @@
+"""TODO/FIXME/HACK comment extraction and attribution.
+
+Scans text files for tech-debt markers in comments, attributes each hit
+via ``git blame --line-porcelain`` (author and introduction date, hence
+age), and aggregates counts and oldest items per file and per directory.
+Non-git repositories still get the extraction; attribution fields are
+simply absent.
+"""
+
+from __future__ import annotations
+
+import re
+import subprocess
+import time
+from collections import defaultdict
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+
+# Markers tracked, with the severity attributed to each: a FIXME is an
+# acknowledged defect, a HACK is a known shortcut, a TODO is deferred work.
+MARKER_TO_SEVERITY = {
+    "FIXME": "HIGH",
+    "HACK": "MEDIUM",
+    "XXX": "MEDIUM",
+    "TODO": "LOW",
+}
+
+# A marker only counts inside a comment: it must follow a comment token
+# (possibly with decoration between), not appear in arbitrary code.
+MARKER_PATTERN = re.compile(
+    r"(?:#|//|/\*|\*|<!--|--|;|%|!)[^\S\n]*(?:@)?(TODO|FIXME|HACK|XXX)\b:?\s*(.*)"
+)
+
+EXCLUDED_DIRS = {".git", "vendor", "node_modules"}
+
+# Longest comment text persisted per finding; blame output is unbounded.
+MAX_TEXT_LENGTH = 200
+
+
+@dataclass(frozen=True)
+class DebtComment:
+    """One tech-debt marker found in a comment."""
+    file_path: str
+    line: int
+    marker: str
+    severity: str
+    text: str
+    author_email: str | None = None
+    introduced_at: str | None = None  # YYYY-MM-DD
+    age_days: int | None = None
+
+
+@dataclass
+class FileStats:
+    """Per-file aggregation of debt comments."""
+    path: str
+    lines: int
+    comment_count: int = 0
+    by_marker: dict[str, int] = field(default_factory=dict)
+    oldest_age_days: int | None = None
+    comments: list[DebtComment] = field(default_factory=list)
+
+
+@dataclass
+class DirectoryStats:
+    """Direct or recursive aggregation for one directory."""
+    file_count: int = 0
+    comment_count: int = 0
+    by_marker: dict[str, int] = field(default_factory=dict)
+    oldest_age_days: int | None = None
+
+
+@dataclass
+class DirectoryEntry:
+    """One directory with direct and recursive rollups."""
+    path: str
+    direct: DirectoryStats
+    recursive: DirectoryStats
+
+
+@dataclass
+class AnalysisResult:
+    """Complete debt-comment analysis of a repository."""
+    repo_name: str
+    repo_path: str
+    comments: list[DebtComment] = field(default_factory=list)
+    files: list[FileStats] = field(default_factory=list)
+    directories: list[DirectoryEntry] = field(default_factory=list)
+    by_marker: dict[str, int] = field(default_factory=dict)
+    oldest: list[DebtComment] = field(default_factory=list)
+    analysis_duration_ms: int = 0
+
+
+def discover_text_files(repo_path: Path) -> list[str]:
+    """Find candidate files, repo-relative with POSIX separators.
+
+    Everything that decodes as UTF-8 is a candidate; binary files are
+    filtered out at read time in ``extract_comments``.
+    """
+    files = []
+    for path in sorted(repo_path.rglob("*")):
+        if not path.is_file():
+            continue
+        relative = path.relative_to(repo_path)
+        if any(part in EXCLUDED_DIRS for part in relative.parts):
+            continue
+        files.append(relative.as_posix())
+    return files
+
+
+def extract_comments(repo_path: Path, relative_path: str) -> tuple[list[DebtComment], int]:
+    """Extract debt markers from one file; returns (comments, line_count).
+
+    Unreadable (binary) files yield no comments and zero lines.
+    """
+    try:
+        content = (repo_path / relative_path).read_text(encoding="utf-8")
+    except (UnicodeDecodeError, OSError):
+        return [], 0
+
+    comments = []
+    lines = content.splitlines()
+    for line_number, line in enumerate(lines, start=1):
+        match = MARKER_PATTERN.search(line)
+        if match:
+            marker = match.group(1)
+            comments.append(DebtComment(
+                file_path=relative_path,
+                line=line_number,
+                marker=marker,
+                severity=MARKER_TO_SEVERITY[marker],
+                text=match.group(2).strip()[:MAX_TEXT_LENGTH],
+            ))
+    return comments, len(lines)
+
+
+def get_blame_attribution(
+    repo_path: Path, relative_path: str
+) -> dict[int, tuple[str, int]]:
+    """Map line number -> (author_email, author_epoch) via git blame.
+
+    Returns an empty map when the file cannot be blamed (untracked file,
+    or not a git repository at all).
+    """
+    try:
+        result = subprocess.run(
+            ["git", "-C", str(repo_path), "blame", "--line-porcelain", "--", relative_path],
+            capture_output=True,
+            check=True,
+        )
+    except (subprocess.CalledProcessError, OSError):
+        return {}
+
+    stdout = result.stdout.decode("utf-8", errors="replace")
+    attribution: dict[int, tuple[str, int]] = {}
+    current_email = None
+    current_epoch = None
+    current_line = 0
+
+    for line in stdout.split("\n"):
+        if line.startswith("author-mail "):
+            current_email = line[12:].strip().strip("<>")
+        elif line.startswith("author-time "):
+            try:
+                current_epoch = int(line[12:].strip())
+            except ValueError:
+                current_epoch = None
+        elif line.startswith("\t"):
+            current_line += 1
+            if current_email and current_epoch is not None:
+                attribution[current_line] = (current_email, current_epoch)
+
+    return attribution
+
+
+def attribute_comments(
+    comments: list[DebtComment],
+    attribution: dict[int, tuple[str, int]],
+    now: datetime | None = None,
+) -> list[DebtComment]:
+    """Fill author, introduction date, and age from a blame line map."""
+    now = now or datetime.now(timezone.utc)
+    attributed = []
+    for comment in comments:
+        blame = attribution.get(comment.line)
+        if blame is None:
+            attributed.append(comment)
+            continue
+        email, epoch = blame
+        introduced = datetime.fromtimestamp(epoch, tz=timezone.utc)
+        attributed.append(DebtComment(
+            file_path=comment.file_path,
+            line=comment.line,
+            marker=comment.marker,
+            severity=comment.severity,
+            text=comment.text,
+            author_email=email,
+            introduced_at=introduced.strftime("%Y-%m-%d"),
+            age_days=max((now - introduced).days, 0),
+        ))
+    return attributed
+
+
+def _oldest(ages: list[int | None]) -> int | None:
+    known = [age for age in ages if age is not None]
+    return max(known) if known else None
+
+
+def build_file_stats(
+    relative_path: str, comments: list[DebtComment], line_count: int
+) -> FileStats:
+    """Aggregate one file's comments."""
+    by_marker: dict[str, int] = defaultdict(int)
+    for comment in comments:
+        by_marker[comment.marker] += 1
+    return FileStats(
+        path=relative_path,
+        lines=line_count,
+        comment_count=len(comments),
+        by_marker=dict(by_marker),
+        oldest_age_days=_oldest([c.age_days for c in comments]),
+        comments=comments,
+    )
+
+
+def build_directory_stats(files: list[FileStats]) -> list[DirectoryEntry]:
+    """Roll file aggregates up into direct and recursive directory stats."""
+    direct: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+    recursive: dict[str, DirectoryStats] = defaultdict(DirectoryStats)
+
+    def _accumulate(stats: DirectoryStats, file_stats: FileStats) -> None:
+        stats.file_count += 1
+        stats.comment_count += file_stats.comment_count
+        for marker, count in file_stats.by_marker.items():
+            stats.by_marker[marker] = stats.by_marker.get(marker, 0) + count
+        stats.oldest_age_days = _oldest([stats.oldest_age_days, file_stats.oldest_age_days])
+
+    for file_stats in files:
+        parts = file_stats.path.split("/")
+        parent = "/".join(parts[:-1]) if len(parts) > 1 else "."
+        _accumulate(direct[parent], file_stats)
+        ancestor_parts = parts[:-1]
+        _accumulate(recursive["."], file_stats)
+        for depth in range(1, len(ancestor_parts) + 1):
+            _accumulate(recursive["/".join(ancestor_parts[:depth])], file_stats)
+
+    return [
+        DirectoryEntry(
+            path=path,
+            direct=direct.get(path, DirectoryStats()),
+            recursive=recursive[path],
+        )
+        for path in sorted(recursive)
+    ]
+
+
+def analyze_repository(repo_path: Path, repo_name: str) -> AnalysisResult:
+    """Run the full debt-comment analysis over a repository."""
+    start = time.monotonic()
+    repo_path = repo_path.resolve()
+    now = datetime.now(timezone.utc)
+
+    all_comments: list[DebtComment] = []
+    file_stats: list[FileStats] = []
+
+    for relative_path in discover_text_files(repo_path):
+        comments, line_count = extract_comments(repo_path, relative_path)
+        if not comments:
+            continue
+        attribution = get_blame_attribution(repo_path, relative_path)
+        comments = attribute_comments(comments, attribution, now=now)
+        all_comments.extend(comments)
+        file_stats.append(build_file_stats(relative_path, comments, line_count))
+
+    by_marker: dict[str, int] = defaultdict(int)
+    for comment in all_comments:
+        by_marker[comment.marker] += 1
+
+    oldest = sorted(
+        [c for c in all_comments if c.age_days is not None],
+        key=lambda c: c.age_days,
+        reverse=True,
+    )[:10]
+
+    return AnalysisResult(
+        repo_name=repo_name,
+        repo_path=str(repo_path),
+        comments=all_comments,
+        files=file_stats,
+        directories=build_directory_stats(file_stats),
+        by_marker=dict(by_marker),
+        oldest=oldest,
+        analysis_duration_ms=int((time.monotonic() - start) * 1000),
+    )
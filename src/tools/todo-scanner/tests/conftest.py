@@ -0,0 +1,11 @@
+"""Pytest configuration for todo-scanner tool tests."""
+
+from __future__ import annotations
+
+import sys
+from pathlib import Path
+
+# Add todo-scanner tool directory to path so 'scripts' can be imported as a package
+todo_scanner_root = Path(__file__).parent.parent
+sys.path.insert(0, str(todo_scanner_root))
+sys.path.insert(0, str(todo_scanner_root / "scripts"))
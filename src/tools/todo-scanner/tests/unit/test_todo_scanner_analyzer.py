@@ -0,0 +1,116 @@
+"""Unit tests for todo_scanner_analyzer extraction, attribution, and aggregation."""
+
+from __future__ import annotations
+
+from datetime import datetime, timezone
+from pathlib import Path
+
+from todo_scanner_analyzer import (
+    DebtComment,
+    attribute_comments,
+    build_directory_stats,
+    build_file_stats,
+    discover_text_files,
+    extract_comments,
+)
+
+
+def _comment(**overrides) -> DebtComment:
+    comment = {
+        "file_path": "src/worker.py",
+        "line": 7,
+        "marker": "TODO",
+        "severity": "LOW",
+        "text": "batch these instead of one-by-one",
+    }
+    comment.update(overrides)
+    return DebtComment(**comment)
+
+
+def test_extract_comments_finds_markers(tmp_path: Path) -> None:
+    (tmp_path / "worker.py").write_text(
+        "# TODO: batch\n"
+        "x = 1\n"
+        "# FIXME retries are missing\n"
+        "// HACK: sleep to dodge rate limiter\n"
+    )
+    comments, line_count = extract_comments(tmp_path, "worker.py")
+
+    assert [(c.marker, c.line) for c in comments] == [("TODO", 1), ("FIXME", 3), ("HACK", 4)]
+    assert comments[0].severity == "LOW"
+    assert comments[1].severity == "HIGH"
+    assert comments[2].severity == "MEDIUM"
+    assert comments[0].text == "batch"
+    assert line_count == 4
+
+
+def test_extract_comments_requires_comment_token(tmp_path: Path) -> None:
+    (tmp_path / "clean.py").write_text(
+        "todo_list = []\n"
+        "FIXME = 'not a comment'\n"
+    )
+    comments, _ = extract_comments(tmp_path, "clean.py")
+    assert comments == []
+
+
+def test_extract_comments_skips_binary(tmp_path: Path) -> None:
+    (tmp_path / "blob.bin").write_bytes(b"\xff\xfe# TODO: hidden")
+    comments, line_count = extract_comments(tmp_path, "blob.bin")
+    assert comments == []
+    assert line_count == 0
+
+
+def test_discover_text_files_skips_excluded_dirs(tmp_path: Path) -> None:
+    (tmp_path / "a.py").write_text("# TODO: x\n")
+    vendored = tmp_path / "vendor"
+    vendored.mkdir()
+    (vendored / "b.py").write_text("# TODO: y\n")
+
+    assert discover_text_files(tmp_path) == ["a.py"]
+
+
+def test_attribute_comments_fills_blame_fields() -> None:
+    now = datetime(2026, 8, 26, tzinfo=timezone.utc)
+    introduced = datetime(2026, 8, 16, tzinfo=timezone.utc)
+    attribution = {7: ("dev@example.com", int(introduced.timestamp()))}
+
+    attributed = attribute_comments([_comment()], attribution, now=now)
+
+    assert attributed[0].author_email == "dev@example.com"
+    assert attributed[0].introduced_at == "2026-08-16"
+    assert attributed[0].age_days == 10
+
+
+def test_attribute_comments_without_blame_leaves_fields_none() -> None:
+    attributed = attribute_comments([_comment()], {})
+    assert attributed[0].author_email is None
+    assert attributed[0].introduced_at is None
+    assert attributed[0].age_days is None
+
+
+def test_build_file_stats_counts_and_oldest() -> None:
+    comments = [
+        _comment(age_days=120),
+        _comment(line=9, marker="FIXME", severity="HIGH", age_days=3),
+        _comment(line=12),
+    ]
+    stats = build_file_stats("src/worker.py", comments, 40)
+
+    assert stats.comment_count == 3
+    assert stats.by_marker == {"TODO": 2, "FIXME": 1}
+    assert stats.oldest_age_days == 120
+
+
+def test_build_directory_stats_rollups() -> None:
+    files = [
+        build_file_stats("src/worker.py", [_comment(age_days=120)], 40),
+        build_file_stats("src/jobs/cron.py", [_comment(file_path="src/jobs/cron.py", marker="HACK", severity="MEDIUM", age_days=5)], 10),
+    ]
+    directories = {d.path: d for d in build_directory_stats(files)}
+
+    assert directories["src"].direct.comment_count == 1
+    assert directories["src"].recursive.comment_count == 2
+    assert directories["src"].recursive.by_marker == {"TODO": 1, "HACK": 1}
+    assert directories["src"].recursive.oldest_age_days == 120
+    assert directories["src/jobs"].direct.oldest_age_days == 5
+    assert directories["."].recursive.file_count == 2